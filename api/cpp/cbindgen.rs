@@ -119,6 +119,7 @@ fn builtin_structs(path: &Path) -> anyhow::Result<()> {
     writeln!(structs_priv, "// This file is auto-generated from {}", file!())?;
     writeln!(structs_priv, "#include \"slint_builtin_structs.h\"")?;
     writeln!(structs_priv, "#include \"slint_enums_internal.h\"")?;
+    writeln!(structs_priv, "#include \"slint_point.h\"")?;
     writeln!(structs_priv, "namespace slint::cbindgen_private {{")?;
     writeln!(structs_priv, "enum class KeyEventType : uint8_t;")?;
     macro_rules! struct_file {
@@ -126,6 +127,10 @@ macro_rules! struct_file {
             writeln!(structs_priv, "using slint::StandardListViewItem;")?;
             &mut structs_pub
         }};
+        (TreeViewItem) => {{
+            writeln!(structs_priv, "using slint::TreeViewItem;")?;
+            &mut structs_pub
+        }};
         ($_:ident) => {
             &mut structs_priv
         };
@@ -218,6 +223,8 @@ fn default_config() -> cbindgen::Config {
             ("KeyEventArg".into(), "KeyEvent".into()),
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
+            ("DropEventArg".into(), "DropEvent".into()),
+            ("BoolArg".into(), "bool".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
@@ -282,6 +289,11 @@ fn gen_corelib(
         "TouchArea",
         "FocusScope",
         "SwipeGestureHandler",
+        "PinchGestureHandler",
+        "DragArea",
+        "DropArea",
+        "WindowMoveArea",
+        "WindowResizeArea",
         "Flickable",
         "SimpleText",
         "ComplexText",
@@ -290,7 +302,9 @@ fn gen_corelib(
         "TextInput",
         "Clip",
         "BoxShadow",
+        "Shader",
         "Rotate",
+        "TransformItem",
         "Opacity",
         "Layer",
     ];
@@ -315,8 +329,10 @@ fn gen_corelib(
         "DialogButtonRole",
         "PointerEventKind",
         "PointerEventButton",
+        "PointerEventDevice",
         "PointerEvent",
         "PointerScrollEvent",
+        "DropEvent",
         "Rect",
         "SortOrder",
         "BitmapFont",
@@ -337,6 +353,7 @@ fn gen_corelib(
         "GraphicsAPI",
         "CloseRequestResponse",
         "StandardListViewItem",
+        "TreeViewItem",
         "Rgb8Pixel",
         "Rgba8Pixel",
     ];
@@ -368,6 +385,8 @@ fn gen_corelib(
         "KeyEventArg",
         "PointerEventArg",
         "PointerScrollEventArg",
+        "DropEventArg",
+        "BoolArg",
         "PointArg",
         "Point",
         "slint_color_brighter",
@@ -381,6 +400,9 @@ fn gen_corelib(
         "slint_image_path",
         "slint_image_load_from_path",
         "slint_image_load_from_embedded_data",
+        "slint_image_load_from_url",
+        "slint_image_url_loading",
+        "slint_image_url_error",
         "slint_image_from_embedded_textures",
         "slint_image_compare_equal",
         "slint_image_set_nine_slice_edges",
@@ -495,6 +517,9 @@ fn gen_corelib(
                 "slint_image_path",
                 "slint_image_load_from_path",
                 "slint_image_load_from_embedded_data",
+                "slint_image_load_from_url",
+                "slint_image_url_loading",
+                "slint_image_url_error",
                 "slint_image_from_embedded_textures",
                 "slint_image_compare_equal",
                 "slint_image_set_nine_slice_edges",
@@ -528,7 +553,14 @@ fn gen_corelib(
             "",
         ),
         (
-            vec!["Brush", "LinearGradient", "GradientStop", "RadialGradient"],
+            vec![
+                "Brush",
+                "LinearGradient",
+                "GradientStop",
+                "GradientSpread",
+                "RadialGradient",
+                "ConicGradient",
+            ],
             vec!["Color"],
             "slint_brush_internal.h",
             "",
@@ -563,7 +595,10 @@ fn gen_corelib(
             "slint_windowrc_set_logical_size",
             "slint_windowrc_set_physical_size",
             "slint_windowrc_color_scheme",
+            "slint_windowrc_interaction_mode",
             "slint_windowrc_default_font_size",
+            "slint_windowrc_window_item_width",
+            "slint_windowrc_window_item_height",
             "slint_windowrc_dispatch_pointer_event",
             "slint_windowrc_dispatch_key_event",
             "slint_windowrc_dispatch_event",
@@ -575,6 +610,7 @@ fn gen_corelib(
             "slint_windowrc_is_maximized",
             "slint_new_path_elements",
             "slint_new_path_events",
+            "slint_easing_curve_sampled",
             "slint_color_brighter",
             "slint_color_darker",
             "slint_color_transparentize",
@@ -586,6 +622,9 @@ fn gen_corelib(
             "slint_image_path",
             "slint_image_load_from_path",
             "slint_image_load_from_embedded_data",
+            "slint_image_load_from_url",
+            "slint_image_url_loading",
+            "slint_image_url_error",
             "slint_image_set_nine_slice_edges",
             "slint_image_to_rgb8",
             "slint_image_to_rgba8",
@@ -702,7 +741,17 @@ fn gen_corelib(
     );
     config.export.body.insert(
         "EasingCurve".to_owned(),
-        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    static constexpr EasingCurve spring(float stiffness, float damping, float mass) {
+        EasingCurve curve(Tag::Spring);
+        curve.spring = { stiffness, damping, mass };
+        return curve;
+    }
+    static constexpr EasingCurve steps(uint32_t count) {
+        EasingCurve curve(Tag::Steps);
+        curve.steps = { count };
+        return curve;
+    }".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -303,6 +303,19 @@ public:
         return std::nullopt;
     }
 
+    /// Returns the accessible-enabled of that element, if any.
+    std::optional<bool> accessible_enabled() const
+    {
+        if (auto result = get_accessible_string_property(
+                    cbindgen_private::AccessibleStringProperty::Enabled)) {
+            if (*result == "true")
+                return true;
+            else if (*result == "false")
+                return false;
+        }
+        return std::nullopt;
+    }
+
     /// Returns the accessible-checkable of that element, if any.
     std::optional<bool> accessible_checkable() const
     {
@@ -129,6 +129,23 @@ inline void debug(const SharedString &str)
     cbindgen_private::slint_debug(&str);
 }
 
+/// Called when the condition of an `assert` statement is false. Aborts in debug builds.
+inline void assertion_failed([[maybe_unused]] const SharedString &message)
+{
+#ifndef NDEBUG
+    cbindgen_private::slint_debug(&message);
+    std::abort();
+#endif
+}
+
+inline cbindgen_private::EasingCurve easing_curve_sampled(std::initializer_list<float> samples)
+{
+    cbindgen_private::EasingCurve curve;
+    cbindgen_private::slint_easing_curve_sampled(
+            { const_cast<float *>(samples.begin()), samples.size() }, &curve);
+    return curve;
+}
+
 } // namespace private_api
 
 template<typename T>
@@ -218,6 +235,13 @@ inline bool operator==(const EasingCurve &a, const EasingCurve &b)
         return false;
     } else if (a.tag == EasingCurve::Tag::CubicBezier) {
         return std::equal(a.cubic_bezier._0, a.cubic_bezier._0 + 4, b.cubic_bezier._0);
+    } else if (a.tag == EasingCurve::Tag::Spring) {
+        return a.spring.stiffness == b.spring.stiffness && a.spring.damping == b.spring.damping
+                && a.spring.mass == b.spring.mass;
+    } else if (a.tag == EasingCurve::Tag::Steps) {
+        return a.steps._0 == b.steps._0;
+    } else if (a.tag == EasingCurve::Tag::Custom) {
+        return a.custom._0 == b.custom._0;
     }
     return true;
 }
@@ -1083,6 +1107,9 @@ template<typename C, typename ModelData>
 class Repeater
 {
     private_api::Property<std::shared_ptr<Model<ModelData>>> model;
+    /// When true, an instance that is removed is kept in `retained` to be reused
+    mutable bool retain_instances = false;
+    mutable std::optional<ComponentHandle<C>> retained;
 
     struct RepeaterInner : ModelChangeListener
     {
@@ -1142,10 +1169,27 @@ public:
         model.set_binding(std::forward<F>(binding));
     }
 
+    /// Same as Repeater::set_retain_instances in model.rs
+    void set_retain_instances(bool retain) const
+    {
+        retain_instances = retain;
+        if (!retain) {
+            retained.reset();
+        }
+    }
+
     template<typename Parent>
     void ensure_updated(const Parent *parent) const
     {
         if (model.is_dirty()) {
+            if (retain_instances && inner) {
+                for (auto &c : inner->data) {
+                    if (c.ptr) {
+                        retained = std::move(c.ptr);
+                        break;
+                    }
+                }
+            }
             inner = std::make_shared<RepeaterInner>();
             if (auto m = model.get()) {
                 inner->model = m;
@@ -1161,7 +1205,9 @@ public:
                 for (size_t i = 0; i < count; ++i) {
                     auto &c = inner->data[i];
                     bool created = false;
-                    if (!c.ptr) {
+                    if (!c.ptr && retained) {
+                        c.ptr = std::exchange(retained, std::nullopt);
+                    } else if (!c.ptr) {
                         c.ptr = C::create(parent);
                         created = true;
                     }
@@ -1196,6 +1242,52 @@ public:
         viewport_height->set(h);
     }
 
+    /// Returns the index of the section of the first visible row, and the position of the first
+    /// row of the next section relative to the top of the visible area.
+    /// Same as Repeater::listview_section in model.rs
+    std::pair<int, float> listview_section(const std::shared_ptr<Model<int>> &section_rows,
+                                           float viewport_y) const
+    {
+        std::size_t count = inner ? inner->data.size() : 0;
+        auto geometry = [&](std::size_t row) {
+            auto ref = item_at(int(row));
+            return ref.vtable->item_geometry(ref, 0);
+        };
+        std::size_t first_visible_row = 0;
+        while (first_visible_row + 1 < count) {
+            auto g = geometry(first_visible_row);
+            if (g.y + g.height > -viewport_y) {
+                break;
+            }
+            ++first_visible_row;
+        }
+        float bottom = 0;
+        if (count > 0) {
+            auto g = geometry(count - 1);
+            bottom = g.y + g.height;
+        }
+
+        // The sections are sorted, so look for the first one that starts after the visible row
+        std::size_t low = 0, high = section_rows ? section_rows->row_count() : 0;
+        while (low < high) {
+            auto middle = (low + high) / 2;
+            auto row = section_rows->row_data(middle);
+            if (row && *row > int(first_visible_row)) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        float next_section_y = bottom;
+        if (section_rows) {
+            if (auto row = section_rows->row_data(low);
+                row && *row >= 0 && std::size_t(*row) < count) {
+                next_section_y = geometry(*row).y;
+            }
+        }
+        return { int(low) - 1, next_section_y + viewport_y };
+    }
+
     uint64_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
     {
         for (std::size_t i = 0; i < inner->data.size(); ++i) {
@@ -1285,6 +1377,91 @@ inline void update_all_translations()
 }
 #endif
 
+/// A set of values for the design tokens of the widget styles, which replace the values of the
+/// style for all the widgets once the theme is set with slint::set_theme().
+///
+/// The tokens are the brushes of the `Palette` global (such as `accent-background`), the lengths
+/// `layout-spacing` and `layout-padding`, and the string `default-font-family`. The tokens that
+/// the theme doesn't set keep the value of the style.
+///
+/// Example
+/// ```cpp
+///     slint::set_theme(slint::Theme()
+///             .with_brush("accent-background", slint::Color::from_rgb_uint8(0xd0, 0x40, 0x20))
+///             .with_length("layout-spacing", 12));
+/// ```
+class Theme
+{
+public:
+    /// Sets the brush of the color token \a name, such as `accent-background`.
+    Theme &with_brush(const SharedString &name, const Brush &brush)
+    {
+        brush_names.push_back(name);
+        brushes.push_back(brush);
+        return *this;
+    }
+
+    /// Sets the length of the token \a name, such as `layout-spacing`, in logical pixels.
+    Theme &with_length(const SharedString &name, float length)
+    {
+        length_names.push_back(name);
+        lengths.push_back(length);
+        return *this;
+    }
+
+    /// Sets the string of the token \a name, such as `default-font-family`.
+    Theme &with_string(const SharedString &name, const SharedString &value)
+    {
+        string_names.push_back(name);
+        strings.push_back(value);
+        return *this;
+    }
+
+private:
+    template<typename T>
+    static cbindgen_private::Slice<T> make_slice(std::vector<T> &values)
+    {
+        return { values.data(), values.size() };
+    }
+
+    std::vector<SharedString> brush_names;
+    std::vector<Brush> brushes;
+    std::vector<SharedString> length_names;
+    std::vector<float> lengths;
+    std::vector<SharedString> string_names;
+    std::vector<SharedString> strings;
+    friend void set_theme(Theme theme);
+};
+
+/// Replaces the active theme, which the widgets of the styles follow: all the bindings that use
+/// a token of the previous theme are evaluated again.
+///
+/// Set a default constructed slint::Theme to go back to the values of the style.
+inline void set_theme(Theme theme)
+{
+    cbindgen_private::slint_theme_set(
+            Theme::make_slice(theme.brush_names), Theme::make_slice(theme.brushes),
+            Theme::make_slice(theme.length_names), Theme::make_slice(theme.lengths),
+            Theme::make_slice(theme.string_names), Theme::make_slice(theme.strings));
+}
+
+/// Sets the factor by which the default font size of all the windows is multiplied, such as the
+/// text scale of the accessibility settings of the system.
+///
+/// All the texts that don't set their `font-size`, as well as all the lengths in the `rem` unit,
+/// are resized, including the texts of the widgets of the styles. The lengths in `px` aren't
+/// affected. The default factor is 1.
+inline void set_text_scale_factor(float factor)
+{
+    cbindgen_private::slint_set_text_scale_factor(factor);
+}
+
+/// Returns the text scale factor, which was set with slint::set_text_scale_factor().
+inline float text_scale_factor()
+{
+    return cbindgen_private::slint_text_scale_factor();
+}
+
 #if !defined(DOXYGEN)
 cbindgen_private::Flickable::Flickable()
 {
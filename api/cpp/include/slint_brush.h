@@ -11,8 +11,20 @@ namespace slint {
 
 namespace private_api {
 
+using cbindgen_private::types::GradientSpread;
 using cbindgen_private::types::GradientStop;
 
+/// \private
+/// Returns the spread that is encoded in the color of the fake first stop of a gradient.
+inline GradientSpread decode_spread(const cbindgen_private::types::Color &color)
+{
+    if (color.alpha == 0 && color.red == 0 && color.green == 0
+        && color.blue <= uint8_t(GradientSpread::Reflect)) {
+        return GradientSpread(color.blue);
+    }
+    return GradientSpread::Pad;
+}
+
 /// \private
 /// LinearGradientBrush represents a gradient for a brush that is a linear sequence of color stops,
 /// that are aligned at a specific angle.
@@ -23,8 +35,10 @@ public:
     LinearGradientBrush() = default;
     /// Constructs a new linear gradient with the specified \a angle. The color stops will be
     /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
-    LinearGradientBrush(float angle, const GradientStop *firstStop, int stopCount)
-        : inner(make_linear_gradient(angle, firstStop, stopCount))
+    /// The \a spread describes how the gradient fills the shape beyond its first and last stops.
+    LinearGradientBrush(float angle, const GradientStop *firstStop, int stopCount,
+                        GradientSpread spread = GradientSpread::Pad)
+        : inner(make_linear_gradient(angle, firstStop, stopCount, spread))
     {
     }
 
@@ -35,6 +49,9 @@ public:
         return inner[0].position;
     }
 
+    /// Returns how the gradient fills the shape beyond its first and last stops.
+    GradientSpread spread() const { return decode_spread(inner[0].color); }
+
     /// Returns the number of gradient stops.
     int stopCount() const { return int(inner.size()) - 1; }
 
@@ -50,10 +67,12 @@ private:
     friend class slint::Brush;
 
     static SharedVector<private_api::GradientStop>
-    make_linear_gradient(float angle, const GradientStop *firstStop, int stopCount)
+    make_linear_gradient(float angle, const GradientStop *firstStop, int stopCount,
+                         GradientSpread spread)
     {
         SharedVector<private_api::GradientStop> gradient;
-        gradient.push_back({ Color::from_argb_encoded(0).inner, angle });
+        // The color of the fake stop contains the spread
+        gradient.push_back({ Color::from_argb_encoded(uint32_t(spread)).inner, angle });
         for (int i = 0; i < stopCount; ++i, ++firstStop)
             gradient.push_back(*firstStop);
         return gradient;
@@ -69,16 +88,21 @@ public:
     RadialGradientBrush() = default;
     /// Constructs a new circular radial gradient . The color stops will be
     /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
-    RadialGradientBrush(const GradientStop *firstStop, int stopCount)
-        : inner(make_circle_gradient(firstStop, stopCount))
+    /// The \a spread describes how the gradient fills the shape beyond its first and last stops.
+    RadialGradientBrush(const GradientStop *firstStop, int stopCount,
+                        GradientSpread spread = GradientSpread::Pad)
+        : inner(make_circle_gradient(firstStop, stopCount, spread))
     {
     }
 
+    /// Returns how the gradient fills the shape beyond its first and last stops.
+    GradientSpread spread() const { return decode_spread(inner[0].color); }
+
     /// Returns the number of gradient stops.
-    int stopCount() const { return int(inner.size()); }
+    int stopCount() const { return int(inner.size()) - 1; }
 
     /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
-    const GradientStop *stopsBegin() const { return inner.begin(); }
+    const GradientStop *stopsBegin() const { return inner.begin() + 1; }
     /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
     /// it can only be used for comparison.
     const GradientStop *stopsEnd() const { return inner.end(); }
@@ -89,9 +113,59 @@ private:
     friend class slint::Brush;
 
     static SharedVector<private_api::GradientStop>
-    make_circle_gradient(const GradientStop *firstStop, int stopCount)
+    make_circle_gradient(const GradientStop *firstStop, int stopCount, GradientSpread spread)
     {
         SharedVector<private_api::GradientStop> gradient;
+        // The gradient's first stop is a fake stop to store the spread
+        gradient.push_back({ Color::from_argb_encoded(uint32_t(spread)).inner, 0 });
+        for (int i = 0; i < stopCount; ++i, ++firstStop)
+            gradient.push_back(*firstStop);
+        return gradient;
+    }
+};
+
+/// \private
+/// ConicGradientBrush represents a gradient whose colors sweep clockwise around the center,
+/// starting at a specific angle from the top.
+class ConicGradientBrush
+{
+public:
+    /// Constructs an empty conic gradient with no color stops.
+    ConicGradientBrush() = default;
+    /// Constructs a new conic gradient that starts at the specified \a from_angle. The color stops
+    /// will be constructed from the stops array pointed to be \a firstStop, with the length \a
+    /// stopCount.
+    ConicGradientBrush(float from_angle, const GradientStop *firstStop, int stopCount)
+        : inner(make_conic_gradient(from_angle, firstStop, stopCount))
+    {
+    }
+
+    /// Returns the angle in degrees, clockwise from the top, at which the conic gradient starts.
+    float from_angle() const
+    {
+        // The gradient's first stop is a fake stop to store the angle
+        return inner[0].position;
+    }
+
+    /// Returns the number of gradient stops.
+    int stopCount() const { return int(inner.size()) - 1; }
+
+    /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
+    const GradientStop *stopsBegin() const { return inner.begin() + 1; }
+    /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
+    /// it can only be used for comparison.
+    const GradientStop *stopsEnd() const { return inner.end(); }
+
+private:
+    cbindgen_private::types::ConicGradientBrush inner;
+
+    friend class slint::Brush;
+
+    static SharedVector<private_api::GradientStop>
+    make_conic_gradient(float from_angle, const GradientStop *firstStop, int stopCount)
+    {
+        SharedVector<private_api::GradientStop> gradient;
+        gradient.push_back({ Color::from_argb_encoded(0).inner, from_angle });
         for (int i = 0; i < stopCount; ++i, ++firstStop)
             gradient.push_back(*firstStop);
         return gradient;
@@ -101,7 +175,7 @@ private:
 }
 
 /// Brush is used to declare how to fill or outline shapes, such as rectangles, paths or text. A
-/// brush is either a solid color or a linear gradient.
+/// brush is either a solid color or a gradient.
 class Brush
 {
 public:
@@ -123,6 +197,13 @@ public:
     {
     }
 
+    /// \private
+    /// Constructs a new brush that is the gradient \a gradient.
+    Brush(const private_api::ConicGradientBrush &gradient)
+        : data(Inner::ConicGradient(gradient.inner))
+    {
+    }
+
     /// Returns the color of the brush. If the brush is a gradient, this function returns the color
     /// of the first stop.
     inline Color color() const;
@@ -173,8 +254,13 @@ Color Brush::color() const
         }
         break;
     case Tag::RadialGradient:
-        if (data.radial_gradient._0.size() > 0) {
-            result.inner = data.radial_gradient._0[0].color;
+        if (data.radial_gradient._0.size() > 1) {
+            result.inner = data.radial_gradient._0[1].color;
+        }
+        break;
+    case Tag::ConicGradient:
+        if (data.conic_gradient._0.size() > 1) {
+            result.inner = data.conic_gradient._0[1].color;
         }
         break;
     }
@@ -196,11 +282,17 @@ inline Brush Brush::brighter(float factor) const
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = 1; i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_brighter(&data.radial_gradient._0[i].color, factor,
                                                           &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_brighter(&data.conic_gradient._0[i].color, factor,
+                                                          &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -220,11 +312,17 @@ inline Brush Brush::darker(float factor) const
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = 1; i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_darker(&data.radial_gradient._0[i].color, factor,
                                                         &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_darker(&data.conic_gradient._0[i].color, factor,
+                                                        &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -245,12 +343,19 @@ inline Brush Brush::transparentize(float factor) const
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = 1; i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_transparentize(
                     &data.radial_gradient._0[i].color, factor,
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_transparentize(
+                    &data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -271,12 +376,19 @@ inline Brush Brush::with_alpha(float alpha) const
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = 1; i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_with_alpha(
                     &data.radial_gradient._0[i].color, alpha,
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 1; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_with_alpha(
+                    &data.conic_gradient._0[i].color, alpha,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -292,6 +404,17 @@ inline void Property<slint::Brush>::set_animated_value(
                                                               &animation_data);
 }
 
+template<>
+struct Interpolator<slint::Brush>
+{
+    static slint::Brush interpolate(const slint::Brush &from, const slint::Brush &to, float t)
+    {
+        slint::Brush result;
+        cbindgen_private::slint_interpolate_brush(&from, &to, t, &result);
+        return result;
+    }
+};
+
 } // namespace private_api
 
 } // namespace slint
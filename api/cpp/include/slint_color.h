@@ -12,6 +12,8 @@ namespace slint {
 
 namespace private_api {
 class LinearGradientBrush;
+class RadialGradientBrush;
+class ConicGradientBrush;
 }
 
 class Color;
@@ -217,6 +219,8 @@ public:
 private:
     cbindgen_private::types::Color inner;
     friend class private_api::LinearGradientBrush;
+    friend class private_api::RadialGradientBrush;
+    friend class private_api::ConicGradientBrush;
     friend class Brush;
 };
 
@@ -296,6 +300,17 @@ Property<Color>::set_animated_value(const Color &new_value,
                                                               &animation_data);
 }
 
+template<>
+struct Interpolator<Color>
+{
+    static Color interpolate(const Color &from, const Color &to, float t)
+    {
+        Color result;
+        cbindgen_private::slint_interpolate_color(&from, &to, t, &result);
+        return result;
+    }
+};
+
 } // namespace private_api
 
 } // namespace slint
@@ -292,6 +292,25 @@ inline Image load_image_from_embedded_data(std::span<const uint8_t> data,
     return Image(img);
 }
 
+inline Image load_image_from_url(const SharedString &url, const Image &fallback)
+{
+    cbindgen_private::types::Image img(cbindgen_private::types::Image::ImageInner_None());
+    if (cbindgen_private::types::slint_image_load_from_url(&url, &img)) {
+        return Image(img);
+    }
+    return fallback;
+}
+
+inline bool image_url_loading(const SharedString &url)
+{
+    return cbindgen_private::types::slint_image_url_loading(&url);
+}
+
+inline bool image_url_error(const SharedString &url)
+{
+    return cbindgen_private::types::slint_image_url_error(&url);
+}
+
 inline Image image_from_embedded_textures(const cbindgen_private::types::StaticTextures *textures)
 {
     cbindgen_private::types::Image img(cbindgen_private::types::Image::ImageInner_None());
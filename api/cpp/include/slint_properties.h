@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 #pragma once
+#include <cmath>
 #include <string_view>
 #include <memory>
+#include <tuple>
+#include <utility>
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
@@ -60,6 +63,45 @@ inline void slint_property_set_animated_binding_helper(
             handle, binding, user_data, drop_user_data, animation_data, transition_data);
 }
 
+/// Interpolates the values of the animated properties whose type the core library doesn't
+/// animate, such as the structs, which the generated code interpolates field by field.
+template<typename T>
+struct Interpolator
+{
+    static T interpolate(const T &from, const T &to, float t) { return T::interpolate(from, to, t); }
+};
+
+template<>
+struct Interpolator<float>
+{
+    static float interpolate(float from, float to, float t) { return from + t * (to - from); }
+};
+
+template<>
+struct Interpolator<int32_t>
+{
+    static int32_t interpolate(int32_t from, int32_t to, float t)
+    {
+        return from + int32_t(std::round(t * float(to - from)));
+    }
+};
+
+template<typename... T>
+struct Interpolator<std::tuple<T...>>
+{
+    static std::tuple<T...> interpolate(const std::tuple<T...> &from, const std::tuple<T...> &to,
+                                        float t)
+    {
+        return [&]<std::size_t... I>(std::index_sequence<I...>) {
+            return std::tuple<T...>(
+                    Interpolator<T>::interpolate(std::get<I>(from), std::get<I>(to), t)...);
+        }(std::index_sequence_for<T...> {});
+    }
+};
+
+template<typename T>
+struct InterpolatedAnimation;
+
 template<typename T>
 struct Property
 {
@@ -110,38 +152,58 @@ struct Property
     inline void
     set_animated_binding(F binding, const cbindgen_private::PropertyAnimation &animation_data) const
     {
-        private_api::slint_property_set_animated_binding_helper(
-                &inner,
-                [](void *user_data, T *value) {
-                    *reinterpret_cast<T *>(value) = (*reinterpret_cast<F *>(user_data))();
-                },
-                new F(binding), [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
-                &animation_data, nullptr);
+        if constexpr (!has_animated_binding_helper) {
+            set_interpolated_binding(binding, [animation_data] { return animation_data; });
+        } else {
+            private_api::slint_property_set_animated_binding_helper(
+                    &inner,
+                    [](void *user_data, T *value) {
+                        *reinterpret_cast<T *>(value) = (*reinterpret_cast<F *>(user_data))();
+                    },
+                    new F(binding),
+                    [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
+                    &animation_data, nullptr);
+        }
     }
 
     template<typename F, typename Trans>
     inline void set_animated_binding_for_transition(F binding, Trans animation) const
     {
-        struct UserData
-        {
-            F binding;
-            Trans animation;
-        };
-        private_api::slint_property_set_animated_binding_helper(
-                &inner,
-                [](void *user_data, T *value) {
-                    *reinterpret_cast<T *>(value) =
-                            reinterpret_cast<UserData *>(user_data)->binding();
-                },
-                new UserData { binding, animation },
-                [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); }, nullptr,
-                [](void *user_data, uint64_t *instant) {
-                    return reinterpret_cast<UserData *>(user_data)->animation(instant);
-                });
+        if constexpr (!has_animated_binding_helper) {
+            // The animation starts when the value changes, rather than at the start instant of
+            // the transition
+            set_interpolated_binding(binding, [animation]() mutable {
+                uint64_t instant = 0;
+                return animation(&instant);
+            });
+        } else {
+            struct UserData
+            {
+                F binding;
+                Trans animation;
+            };
+            private_api::slint_property_set_animated_binding_helper(
+                    &inner,
+                    [](void *user_data, T *value) {
+                        *reinterpret_cast<T *>(value) =
+                                reinterpret_cast<UserData *>(user_data)->binding();
+                    },
+                    new UserData { binding, animation },
+                    [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); },
+                    nullptr, [](void *user_data, uint64_t *instant) {
+                        return reinterpret_cast<UserData *>(user_data)->animation(instant);
+                    });
+        }
     }
 
     bool is_dirty() const { return cbindgen_private::slint_property_is_dirty(&inner); }
     void mark_dirty() const { cbindgen_private::slint_property_mark_dirty(&inner); }
+    /// Returns true if the value is animated. The property is evaluated first.
+    bool is_animating() const
+    {
+        get();
+        return cbindgen_private::slint_property_is_animating(&inner);
+    }
 
     static void link_two_way(const Property<T> *p1, const Property<T> *p2)
     {
@@ -185,12 +247,78 @@ struct Property
     }
 
 private:
+    /// Whether the core library animates the values of this type
+    static constexpr bool has_animated_binding_helper = std::is_same_v<T, int>
+            || std::is_same_v<T, float> || std::is_same_v<T, Color> || std::is_same_v<T, Brush>;
+
+    /// Sets a binding that animates the values of a type that the core library doesn't animate,
+    /// by interpolating between the previous and the new value whenever the binding changes.
+    template<typename F, typename A>
+    void set_interpolated_binding(F binding, A animation_data) const
+    {
+        struct State
+        {
+            State(F binding, A animation_data)
+                : binding(std::move(binding)), animation_data(std::move(animation_data))
+            {
+            }
+            F binding;
+            A animation_data;
+            InterpolatedAnimation<T> animation;
+            bool initialized = false;
+        };
+        auto state = std::make_shared<State>(std::move(binding), std::move(animation_data));
+        set_binding([state] {
+            T target = state->binding();
+            if (!state->initialized) {
+                // The first value isn't animated
+                state->initialized = true;
+                state->animation.from = target;
+                state->animation.to = target;
+            } else if (!(target == state->animation.to)) {
+                state->animation.start(state->animation.current(), std::move(target),
+                                       state->animation_data());
+            }
+            return state->animation.current();
+        });
+    }
+
     cbindgen_private::PropertyHandleOpaque inner;
     mutable T value {};
     template<typename F>
     friend void set_state_binding(const Property<StateInfo> &property, F binding);
 };
 
+/// Animates a value of a type that the core library doesn't animate, by animating a progress
+/// from 0 to 1 and interpolating between the two values with Interpolator.
+template<typename T>
+struct InterpolatedAnimation
+{
+    Property<float> progress { 1. };
+    T from {};
+    T to {};
+
+    T current() const { return Interpolator<T>::interpolate(from, to, progress.get()); }
+
+    void start(T from, T to, const cbindgen_private::PropertyAnimation &animation_data)
+    {
+        this->from = std::move(from);
+        this->to = std::move(to);
+        progress.set(0.);
+        progress.set_animated_value(1., animation_data);
+    }
+};
+
+template<typename T>
+inline void
+Property<T>::set_animated_value(const T &new_value,
+                                const cbindgen_private::PropertyAnimation &animation_data) const
+{
+    auto animation = std::make_shared<InterpolatedAnimation<T>>();
+    animation->start(value, new_value, animation_data);
+    set_binding([animation] { return animation->current(); });
+}
+
 template<>
 inline void Property<int32_t>::set_animated_value(
         const int32_t &new_value, const cbindgen_private::PropertyAnimation &animation_data) const
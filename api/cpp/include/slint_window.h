@@ -10,6 +10,11 @@
 #    include <iostream>
 #endif
 
+#ifdef SLINT_FEATURE_BACKEND_QT
+#    include "slint_qt_internal.h"
+class QWidget;
+#endif
+
 namespace slint {
 #if !defined(DOXYGEN)
 namespace platform {
@@ -79,6 +84,11 @@ public:
         return slint_windowrc_color_scheme(&inner);
     }
 
+    cbindgen_private::InteractionMode interaction_mode() const
+    {
+        return slint_windowrc_interaction_mode(&inner);
+    }
+
     bool text_input_focused() const { return slint_windowrc_get_text_input_focused(&inner); }
     void set_text_input_focused(bool value) const
     {
@@ -106,14 +116,17 @@ public:
     }
 
     template<typename Component, typename Parent, typename PosGetter>
-    void show_popup(const Parent *parent_component, PosGetter pos, bool close_on_click,
-                    cbindgen_private::ItemRc parent_item) const
+    void show_popup(const Parent *parent_component, PosGetter pos,
+                    cbindgen_private::PopupClosePolicy close_policy, bool close_on_escape,
+                    cbindgen_private::PopupAnchorEdge anchor_edge, float anchor_offset,
+                    bool anchor_flip, cbindgen_private::ItemRc parent_item) const
     {
         auto popup = Component::create(parent_component);
         cbindgen_private::Point p = pos(popup);
         auto popup_dyn = popup.into_dyn();
-        cbindgen_private::slint_windowrc_show_popup(&inner, &popup_dyn, p, close_on_click,
-                                                    &parent_item);
+        cbindgen_private::slint_windowrc_show_popup(&inner, &popup_dyn, p, close_policy,
+                                                    close_on_escape, anchor_edge, anchor_offset,
+                                                    anchor_flip, &parent_item);
     }
 
     void close_popup() const { cbindgen_private::slint_windowrc_close_popup(&inner); }
@@ -227,6 +240,16 @@ public:
         return cbindgen_private::slint_windowrc_default_font_size(&inner);
     }
 
+    inline float window_item_width() const
+    {
+        return cbindgen_private::slint_windowrc_window_item_width(&inner);
+    }
+
+    inline float window_item_height() const
+    {
+        return cbindgen_private::slint_windowrc_window_item_height(&inner);
+    }
+
     /// \private
     const cbindgen_private::WindowAdapterRcOpaque &handle() const { return inner; }
 
@@ -589,6 +612,18 @@ public:
         cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
     }
 
+    /// A frame was presented on the screen.
+    ///
+    /// The backend should send this event each time the rendered content of the window was
+    /// successfully put on the screen, for example after flushing the frame buffer to the display.
+    void dispatch_frame_presented_event()
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .tag = WindowEvent::Tag::FramePresented };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
     /// Returns true if there is an animation currently active on any property in the Window.
     bool has_active_animations() const
     {
@@ -596,6 +631,23 @@ public:
         return cbindgen_private::slint_windowrc_has_active_animations(&inner.handle());
     }
 
+#if defined(SLINT_FEATURE_BACKEND_QT) || defined(DOXYGEN)
+    /// Returns the QWidget that shows this window with the Qt backend, or nullptr with other
+    /// backends.
+    ///
+    /// Use it to place the Slint content inside an existing Qt user interface, for example by
+    /// adding it to a QLayout, a QSplitter, or a QMdiArea. The window title, flags, and state
+    /// only apply while the widget is a top-level window.
+    ///
+    /// This function is only available if the Qt backend was compiled in.
+    QWidget *qwidget() const
+    {
+        private_api::assert_main_thread();
+        return reinterpret_cast<QWidget *>(cbindgen_private::slint_qt_get_widget(
+                reinterpret_cast<const cbindgen_private::WindowAdapterRc *>(&inner.handle())));
+    }
+#endif
+
     /// \private
     private_api::WindowAdapterRc &window_handle() { return inner; }
     /// \private
@@ -215,7 +215,7 @@ fn try_convert_image<BufferType: AsRef<[u8]> + FromNapiValue>(
                 Ok(Value::Image(Image::from_rgba8(pixel_buffer)))
             }
         }
-        Type::Struct { fields, name: _, node: _, rust_attributes: _ } => {
+        Type::Struct { fields, .. } => {
             let js_object = unknown.coerce_to_object()?;
 
             Ok(Value::Struct(
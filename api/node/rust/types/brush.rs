@@ -1,7 +1,10 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use i_slint_core::{graphics::GradientStop, Brush, Color};
+use i_slint_core::{
+    graphics::{GradientSpread, GradientStop},
+    Brush, Color,
+};
 use napi::{bindgen_prelude::External, Error, Result};
 
 /// RgbaColor represents a color in the Slint run-time, represented using 8-bit channels for red, green, blue and the alpha (opacity).
@@ -291,14 +294,23 @@ pub fn to_string(&self) -> String {
             }
             Brush::LinearGradient(gradient) => {
                 return format!(
-                    "linear-gradient({}deg, {})",
+                    "linear-gradient({}deg, {}{})",
                     gradient.angle(),
-                    gradient_stops_to_string(gradient.stops())
+                    gradient_stops_to_string(gradient.stops()),
+                    gradient_spread_to_string(gradient.spread())
                 );
             }
             Brush::RadialGradient(gradient) => {
                 return format!(
-                    "radial-gradient(circle, {})",
+                    "radial-gradient(circle, {}{})",
+                    gradient_stops_to_string(gradient.stops()),
+                    gradient_spread_to_string(gradient.spread())
+                );
+            }
+            Brush::ConicGradient(gradient) => {
+                return format!(
+                    "conic-gradient(from {}deg, {})",
+                    gradient.from_angle(),
                     gradient_stops_to_string(gradient.stops())
                 );
             }
@@ -307,6 +319,14 @@ pub fn to_string(&self) -> String {
     }
 }
 
+fn gradient_spread_to_string(spread: GradientSpread) -> &'static str {
+    match spread {
+        GradientSpread::Repeat => ", repeat",
+        GradientSpread::Reflect => ", reflect",
+        _ => "",
+    }
+}
+
 fn gradient_stops_to_string<'a>(stops: impl Iterator<Item = &'a GradientStop>) -> String {
     let stops: Vec<String> = stops
         .map(|s| {
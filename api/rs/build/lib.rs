@@ -50,16 +50,21 @@ fn main() {
     forward compatibility with future version of this crate"
 );
 
+mod library_crates;
+
 use std::collections::HashMap;
 use std::env;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
+use i_slint_compiler::build_cache::{BuildCache, CacheKey};
 use i_slint_compiler::diagnostics::BuildDiagnostics;
 
 /// The structure for configuring aspects of the compilation of `.slint` markup files to Rust.
 pub struct CompilerConfiguration {
     config: i_slint_compiler::CompilerConfiguration,
+    /// The names of the crates that provide the libraries, by library name
+    library_crates: HashMap<String, String>,
 }
 
 /// How should the slint compiler embed images and fonts
@@ -83,6 +88,7 @@ fn default() -> Self {
             config: i_slint_compiler::CompilerConfiguration::new(
                 i_slint_compiler::generator::OutputFormat::Rust,
             ),
+            library_crates: HashMap::new(),
         }
     }
 }
@@ -99,7 +105,7 @@ pub fn new() -> Self {
     pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self {
         let mut config = self.config;
         config.include_paths = include_paths;
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Create a new configuration that sets the library paths used for looking up
@@ -132,7 +138,99 @@ pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self
     pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBuf>) -> Self {
         let mut config = self.config;
         config.library_paths = library_paths;
-        Self { config }
+        Self { config, ..self }
+    }
+
+    /// Create a new configuration that sets the crates providing the libraries for `@library`
+    /// imports, in addition to the ones of [`Self::with_library_paths()`].
+    ///
+    /// Each entry maps a library name to the name of a crate that the package being built depends
+    /// on. The library is the one of the version of the crate that Cargo resolved for the package,
+    /// so that widget libraries can be versioned and shared like any other dependency. The crate
+    /// declares its library, either a `.slint` file or a directory like for the library paths,
+    /// relative to its `Cargo.toml`:
+    ///
+    /// ```toml
+    /// [package.metadata.slint]
+    /// library = "ui/lib.slint"
+    /// ```
+    ///
+    /// Compile `ui/main.slint` with the library of the `example-widgets` crate as "example":
+    /// ```rust,no_run
+    /// let library_crates = std::collections::HashMap::from([(
+    ///     "example".to_string(),
+    ///     "example-widgets".to_string(),
+    /// )]);
+    /// let config = slint_build::CompilerConfiguration::new().with_library_crates(library_crates);
+    /// slint_build::compile_with_config("ui/main.slint", config).unwrap();
+    /// ```
+    ///
+    /// The crates are looked up with `cargo metadata`, so this only works in a build script run by
+    /// Cargo.
+    #[must_use]
+    pub fn with_library_crates(self, library_crates: HashMap<String, String>) -> Self {
+        Self { library_crates, ..self }
+    }
+
+    /// Create a new configuration that sets the flags for the `@cfg(flag)` conditions of the
+    /// `.slint` files, to include or exclude parts of the user interface at compile time.
+    ///
+    /// The flags are added to the ones of the comma separated `SLINT_CFG` environment variable.
+    ///
+    /// ```rust,no_run
+    /// let config = slint_build::CompilerConfiguration::new()
+    ///     .with_cfg_flags(vec!["debug-panel".into()]);
+    /// slint_build::compile_with_config("ui/main.slint", config).unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_cfg_flags(self, cfg_flags: Vec<String>) -> Self {
+        let mut config = self.config;
+        config.cfg_flags.extend(cfg_flags);
+        Self { config, ..self }
+    }
+
+    /// Create a new configuration that overlays variant directories over base directories, to
+    /// build several variants of the user interface, such as branded builds, from one source tree.
+    ///
+    /// Each pair consists of a base directory and a variant directory. A file of the variant
+    /// directory replaces the file with the same relative path in the base directory, wherever
+    /// it is imported or referenced with `@image-url`. A replaced `.slint` file still provides
+    /// the exports that the variant file doesn't define, so a variant file may override only
+    /// some components or globals. The later pairs take precedence over the earlier ones.
+    ///
+    /// ```rust,no_run
+    /// let manifest_dir = std::path::PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    /// let config = slint_build::CompilerConfiguration::new()
+    ///     .with_variant_paths(vec![(manifest_dir.join("ui"), manifest_dir.join("variants/acme"))]);
+    /// slint_build::compile_with_config("ui/main.slint", config).unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_variant_paths(
+        self,
+        variant_paths: Vec<(std::path::PathBuf, std::path::PathBuf)>,
+    ) -> Self {
+        let mut config = self.config;
+        config.variant_paths = variant_paths;
+        Self { config, ..self }
+    }
+
+    /// Create a new configuration that declares custom items, native items that the application
+    /// implements in Rust with the `slint::custom_item` API.
+    ///
+    /// Each manifest is a `.slint` file with exported components that only declare properties
+    /// and callbacks, and names the Rust type implementing the item. The items can be used like
+    /// builtin elements in all the `.slint` files, without an import.
+    ///
+    /// ```rust,no_run
+    /// let config = slint_build::CompilerConfiguration::new()
+    ///     .with_custom_items(vec!["ui/custom_items.slint".into()]);
+    /// slint_build::compile_with_config("ui/main.slint", config).unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_custom_items(self, manifests: Vec<std::path::PathBuf>) -> Self {
+        let mut config = self.config;
+        config.custom_item_manifests = manifests;
+        Self { config, ..self }
     }
 
     /// Create a new configuration that selects the style to be used for widgets.
@@ -140,7 +238,7 @@ pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBu
     pub fn with_style(self, style: String) -> Self {
         let mut config = self.config;
         config.style = Some(style);
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Selects how the resources such as images and font are processed.
@@ -160,7 +258,7 @@ pub fn embed_resources(self, kind: EmbedResourcesKind) -> Self {
                 i_slint_compiler::EmbedResourcesKind::EmbedTextures
             }
         };
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Sets the scale factor to be applied to all `px` to `phx` conversions
@@ -171,7 +269,49 @@ pub fn embed_resources(self, kind: EmbedResourcesKind) -> Self {
     pub fn with_scale_factor(self, factor: f32) -> Self {
         let mut config = self.config;
         config.const_scale_factor = factor as f64;
-        Self { config }
+        Self { config, ..self }
+    }
+
+    /// Selects glyphs to pre-render when embedding the fonts with
+    /// [`EmbedResourcesKind::EmbedForSoftwareRenderer`], in addition to the characters of the
+    /// string literals in the `.slint` files. This is needed for the text that only comes from the
+    /// application, such as translations or user data.
+    ///
+    /// The `ranges` of code points are rendered at the font sizes that the `.slint` files use and
+    /// at the additional `pixel_sizes`, in logical pixels. They apply to the embedded fonts of the
+    /// given `family`, or all of them when `family` is `None`. A warning lists the selected code
+    /// points that the fonts have no glyph for.
+    ///
+    /// ```rust,no_run
+    /// let config = slint_build::CompilerConfiguration::new()
+    ///     .embed_resources(slint_build::EmbedResourcesKind::EmbedForSoftwareRenderer)
+    ///     .with_embedded_glyphs(Some("Noto Sans"), vec!['\u{400}'..='\u{4ff}', '€'..='€'], vec![18.]);
+    /// ```
+    #[must_use]
+    pub fn with_embedded_glyphs(
+        self,
+        family: Option<&str>,
+        ranges: Vec<std::ops::RangeInclusive<char>>,
+        pixel_sizes: Vec<f32>,
+    ) -> Self {
+        let mut config = self.config;
+        config.glyph_selections.push(i_slint_compiler::embedded_resources::GlyphSelection {
+            family: family.map(String::from),
+            ranges,
+            pixel_sizes: pixel_sizes.into_iter().map(f64::from).collect(),
+        });
+        Self { config, ..self }
+    }
+
+    /// Compresses the textures that are embedded with
+    /// [`EmbedResourcesKind::EmbedForSoftwareRenderer`], to reduce the flash usage on
+    /// microcontrollers. The textures are decompressed line by line while rendering, which
+    /// costs some rendering time.
+    #[must_use]
+    pub fn with_compressed_textures(self, enabled: bool) -> Self {
+        let mut config = self.config;
+        config.compress_textures = enabled;
+        Self { config, ..self }
     }
 }
 
@@ -188,6 +328,9 @@ pub enum CompileError {
     /// Cannot write the generated file
     #[error("Cannot write the generated file: {0}")]
     SaveError(std::io::Error),
+    /// Cannot find the library of a crate set with [`CompilerConfiguration::with_library_crates()`]
+    #[error("Cannot resolve the library crates: {0}")]
+    LibraryCrateError(String),
 }
 
 struct CodeFormatter<Sink> {
@@ -352,6 +495,10 @@ fn format_code(code: &str) -> String {
 /// about how to use the generated code.
 ///
 /// This function can only be called within a build script run by cargo.
+///
+/// The generated code is cached in the `slint-cache` directory of the target directory, and
+/// reused when neither the `.slint` files nor the configuration changed. Set the
+/// `SLINT_NO_BUILD_CACHE` environment variable to always compile the files.
 pub fn compile(path: impl AsRef<std::path::Path>) -> Result<(), CompileError> {
     compile_with_config(path, CompilerConfiguration::default())
 }
@@ -380,8 +527,20 @@ pub fn compile_with_config(
                 .with_extension("rs"),
         );
 
-    let paths_dependencies =
-        compile_with_output(path, absolute_rust_output_file_path.clone(), config)?;
+    // The code is only generated again if the file, the configuration, or a dependency changed
+    let cache = BuildCache::from_cargo_env().and_then(|cache| {
+        let library_crates =
+            config.library_crates.iter().collect::<std::collections::BTreeMap<_, _>>();
+        let input = format!("{}\n{library_crates:?}", path.display());
+        Some((cache, BuildCache::key(&input, &config.config)?))
+    });
+    let cached = cache.as_ref().and_then(|(cache, key)| cache.load(*key));
+    let paths_dependencies = if let Some((code, dependencies)) = cached {
+        std::fs::write(&absolute_rust_output_file_path, code).map_err(CompileError::SaveError)?;
+        dependencies
+    } else {
+        compile_to_file(path, &absolute_rust_output_file_path, config, cache.as_ref())?
+    };
 
     for path_dependency in paths_dependencies {
         println!("cargo:rerun-if-changed={}", path_dependency.display());
@@ -389,10 +548,13 @@ pub fn compile_with_config(
 
     println!("cargo:rerun-if-env-changed=SLINT_STYLE");
     println!("cargo:rerun-if-env-changed=SLINT_FONT_SIZES");
+    println!("cargo:rerun-if-env-changed=SLINT_EMBED_GLYPHS");
     println!("cargo:rerun-if-env-changed=SLINT_SCALE_FACTOR");
     println!("cargo:rerun-if-env-changed=SLINT_ASSET_SECTION");
     println!("cargo:rerun-if-env-changed=SLINT_EMBED_RESOURCES");
+    println!("cargo:rerun-if-env-changed=SLINT_COMPRESS_TEXTURES");
     println!("cargo:rerun-if-env-changed=SLINT_EMIT_DEBUG_INFO");
+    println!("cargo:rerun-if-env-changed=SLINT_CFG");
 
     println!(
         "cargo:rustc-env=SLINT_INCLUDE_GENERATED={}",
@@ -415,6 +577,17 @@ pub fn compile_with_output(
     input_slint_file_path: impl AsRef<std::path::Path>,
     output_rust_file_path: impl AsRef<std::path::Path>,
     config: CompilerConfiguration,
+) -> Result<Vec<std::path::PathBuf>, CompileError> {
+    compile_to_file(input_slint_file_path, output_rust_file_path, config, None)
+}
+
+/// Implements [`compile_with_output`], and stores the generated code in the `cache` if there
+/// were no warnings, which wouldn't be shown again when the cached code is used.
+fn compile_to_file(
+    input_slint_file_path: impl AsRef<std::path::Path>,
+    output_rust_file_path: impl AsRef<std::path::Path>,
+    config: CompilerConfiguration,
+    cache: Option<&(BuildCache, CacheKey)>,
 ) -> Result<Vec<std::path::PathBuf>, CompileError> {
     let mut diag = BuildDiagnostics::default();
     let syntax_node = i_slint_compiler::parser::parse_file(&input_slint_file_path, &mut diag);
@@ -428,6 +601,13 @@ pub fn compile_with_output(
     let mut compiler_config = config.config;
     compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
 
+    let mut dependencies: Vec<std::path::PathBuf> = Vec::new();
+    if !config.library_crates.is_empty() {
+        let (library_paths, lock_file) = library_crates::resolve(&config.library_crates)?;
+        compiler_config.library_paths.extend(library_paths);
+        dependencies.push(lock_file);
+    }
+
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
 
     // 'spin_on' is ok here because the compiler in single threaded and does not block if there is no blocking future
@@ -445,8 +625,6 @@ pub fn compile_with_output(
     let mut code_formatter = CodeFormatter::new(BufWriter::new(output_file));
     let generated = i_slint_compiler::generator::rust::generate(&doc, &loader.compiler_config);
 
-    let mut dependencies: Vec<std::path::PathBuf> = Vec::new();
-
     for x in &diag.all_loaded_files {
         if x.is_absolute() {
             dependencies.push(x.clone());
@@ -461,7 +639,9 @@ pub fn compile_with_output(
     });
 
     write!(code_formatter, "{}", generated).map_err(CompileError::SaveError)?;
+    code_formatter.flush().map_err(CompileError::SaveError)?;
     dependencies.push(input_slint_file_path.as_ref().to_path_buf());
+    dependencies.extend(loader.compiler_config.custom_item_manifests.iter().cloned());
 
     for resource in doc.embedded_file_resources.borrow().keys() {
         if !resource.starts_with("builtin:") {
@@ -469,6 +649,12 @@ pub fn compile_with_output(
         }
     }
 
+    if let Some((cache, key)) = cache.filter(|_| diag.is_empty()) {
+        if let Ok(code) = std::fs::read_to_string(&output_rust_file_path) {
+            cache.store(*key, &code, &dependencies);
+        }
+    }
+
     Ok(dependencies)
 }
 
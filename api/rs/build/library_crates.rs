@@ -0,0 +1,69 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Lookup of the libraries that the dependencies of the package being built provide, for
+//! [`crate::CompilerConfiguration::with_library_crates()`].
+
+use crate::CompileError;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Returns the path of the library of the crates in `library_crates`, by library name, and the
+/// path of the lock file that decides which version of the crates is used.
+pub(crate) fn resolve(
+    library_crates: &HashMap<String, String>,
+) -> Result<(HashMap<String, PathBuf>, PathBuf), CompileError> {
+    let error = CompileError::LibraryCrateError;
+    let manifest_dir =
+        std::env::var_os("CARGO_MANIFEST_DIR").ok_or(CompileError::NotRunViaCargo)?;
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = std::process::Command::new(cargo)
+        .args(["metadata", "--format-version", "1", "--manifest-path"])
+        .arg(Path::new(&manifest_dir).join("Cargo.toml"))
+        .output()
+        .map_err(|e| error(format!("Cannot run cargo metadata: {e}")))?;
+    if !output.status.success() {
+        return Err(error(format!(
+            "cargo metadata failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| error(format!("Cannot parse the output of cargo metadata: {e}")))?;
+
+    let packages = metadata["packages"].as_array().map(Vec::as_slice).unwrap_or_default();
+    let package = |id: &serde_json::Value| packages.iter().find(|p| p["id"] == *id);
+    // The root of the resolve is the package being built, and its dependencies are the versions
+    // that Cargo picked for it
+    let root = &metadata["resolve"]["root"];
+    let dependencies = metadata["resolve"]["nodes"]
+        .as_array()
+        .and_then(|nodes| nodes.iter().find(|node| node["id"] == *root))
+        .and_then(|node| node["deps"].as_array())
+        .ok_or_else(|| error("Cannot find the package being built in cargo metadata".into()))?;
+
+    let mut library_paths = HashMap::new();
+    for (library, crate_name) in library_crates {
+        let dependency = dependencies
+            .iter()
+            .filter_map(|dependency| package(&dependency["pkg"]))
+            .find(|p| p["name"].as_str() == Some(crate_name))
+            .ok_or_else(|| {
+                error(format!(
+                    "The crate '{crate_name}' of the library '{library}' isn't a dependency"
+                ))
+            })?;
+        let path = dependency["metadata"]["slint"]["library"].as_str().ok_or_else(|| {
+            error(format!(
+                "The crate '{crate_name}' doesn't declare a library in `package.metadata.slint.library`"
+            ))
+        })?;
+        let manifest_path = Path::new(dependency["manifest_path"].as_str().unwrap_or_default());
+        let crate_dir = manifest_path.parent().unwrap_or(manifest_path);
+        library_paths.insert(library.clone(), crate_dir.join(path));
+    }
+
+    let lock_file =
+        Path::new(metadata["workspace_root"].as_str().unwrap_or_default()).join("Cargo.lock");
+    Ok((library_paths, lock_file))
+}
@@ -8,6 +8,7 @@
 
 extern crate proc_macro;
 
+use i_slint_compiler::build_cache::BuildCache;
 use i_slint_compiler::diagnostics::BuildDiagnostics;
 use i_slint_compiler::parser::SyntaxKind;
 use i_slint_compiler::*;
@@ -296,6 +297,25 @@ fn extract_path(literal: proc_macro::Literal) -> std::path::PathBuf {
     path_with_quotes_stripped.into()
 }
 
+/// Returns the path of the file if the macro consists only of `import "file.slint";`,
+/// in which case the file is compiled as if it was the code of the macro.
+fn imported_file(tokens: &[parser::Token]) -> Option<std::path::PathBuf> {
+    let mut tokens =
+        tokens.iter().filter(|t| !matches!(t.kind, SyntaxKind::Whitespace | SyntaxKind::Comment));
+    match (tokens.next(), tokens.next(), tokens.next(), tokens.next()) {
+        (Some(import), Some(path), Some(semicolon), None)
+            if import.kind == SyntaxKind::Identifier
+                && import.text == "import"
+                && path.kind == SyntaxKind::StringLiteral
+                && semicolon.kind == SyntaxKind::Semicolon =>
+        {
+            let path = path.text.strip_prefix('"')?.strip_suffix('"')?;
+            path.ends_with(".slint").then(|| path.into())
+        }
+        _ => None,
+    }
+}
+
 fn extract_compiler_config(
     mut stream: proc_macro::token_stream::IntoIter,
     compiler_config: &mut CompilerConfiguration,
@@ -377,12 +397,22 @@ fn extract_compiler_config(
 /// When `import`ing `.slint` files or loading images with `@image-url`, the specified paths are relative to the
 /// the directory that contains Cargo.toml.
 ///
+/// Instead of inline code, the macro can also contain a single `import` of a `.slint` file, which is then
+/// compiled like [`slint-build`](https://docs.rs/slint-build) would: the components exported by that file are
+/// available, paths in it are relative to the file, and errors are reported with their location in the file.
+/// ```rust,ignore
+/// slint::slint!(import "ui/app.slint";);
+/// ```
+///
+/// In both cases, the crate is rebuilt when any of the `.slint` files or images that were loaded changes.
+///
 /// ### Limitations
 ///
 /// Within `.slint` files, you can interpolate string literals using `\{...}` syntax.
 /// This is not possible in this macro as this wouldn't parse as a Rust string.
 #[proc_macro]
 pub fn slint(stream: TokenStream) -> TokenStream {
+    let input = stream.to_string();
     let token_iter = stream.into_iter();
 
     let mut compiler_config =
@@ -390,24 +420,41 @@ pub fn slint(stream: TokenStream) -> TokenStream {
 
     let token_iter = extract_compiler_config(token_iter, &mut compiler_config);
 
+    // The macro runs whenever the crate is rebuilt, so reuse the code generated for the same
+    // input, if none of the files that were loaded changed
+    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+    let cache = BuildCache::from_cargo_env()
+        .and_then(|cache| Some((BuildCache::key(&input, &compiler_config)?, cache)));
+    if let Some((key, cache)) = &cache {
+        if let Some(result) = cache.load(*key).and_then(|(code, _)| code.parse().ok()) {
+            return result;
+        }
+    }
+
     let mut tokens = vec![];
     fill_token_vec(token_iter, &mut tokens);
 
-    let source_file = if let Some(cargo_manifest) = std::env::var_os("CARGO_MANIFEST_DIR") {
-        let mut path: std::path::PathBuf = cargo_manifest.into();
-        path.push("Cargo.toml");
-        diagnostics::SourceFileInner::from_path_only(path)
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(std::path::PathBuf::from);
+    let mut diag = BuildDiagnostics::default();
+    let main_file =
+        imported_file(&tokens).map(|path| manifest_dir.clone().unwrap_or_default().join(path));
+    let syntax_node = if let Some(main_file) = &main_file {
+        match parser::parse_file(main_file, &mut diag) {
+            Some(syntax_node) if !diag.has_errors() => syntax_node,
+            _ => return diag.report_macro_diagnostic(&tokens),
+        }
     } else {
-        diagnostics::SourceFileInner::from_path_only(Default::default())
+        let source_file = diagnostics::SourceFileInner::from_path_only(
+            manifest_dir.map(|dir| dir.join("Cargo.toml")).unwrap_or_default(),
+        );
+        let syntax_node = parser::parse_tokens(tokens.clone(), source_file, &mut diag);
+        if diag.has_errors() {
+            return diag.report_macro_diagnostic(&tokens);
+        }
+        syntax_node
     };
-    let mut diag = BuildDiagnostics::default();
-    let syntax_node = parser::parse_tokens(tokens.clone(), source_file, &mut diag);
-    if diag.has_errors() {
-        return diag.report_macro_diagnostic(&tokens);
-    }
 
     //println!("{:#?}", syntax_node);
-    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
     let (root_component, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
     //println!("{:#?}", tree);
@@ -418,16 +465,34 @@ pub fn slint(stream: TokenStream) -> TokenStream {
     let mut result = generator::rust::generate(&root_component, &loader.compiler_config);
 
     // Make sure to recompile if any of the external files changes
-    let reload = diag
-        .all_loaded_files
+    let dependencies = main_file
         .iter()
+        .chain(diag.all_loaded_files.iter())
         .filter(|path| path.is_absolute() && !path.ends_with("Cargo.toml"))
+        .cloned()
+        .collect::<Vec<_>>();
+    let reload = dependencies
+        .iter()
         .filter_map(|p| p.to_str())
         .map(|p| quote! {const _ : &'static [u8] = ::core::include_bytes!(#p);});
 
     result.extend(reload);
     result.extend(quote! {const _ : ::core::option::Option<&'static str> = ::core::option_env!("SLINT_STYLE");});
 
+    // The warnings have spans in the macro, so they can't be cached
+    if let Some((key, cache)) = cache.filter(|_| diag.is_empty()) {
+        let resources = root_component.embedded_file_resources.borrow();
+        let resources = resources
+            .keys()
+            .filter(|resource| !resource.starts_with("builtin:"))
+            .map(std::path::PathBuf::from);
+        cache.store(
+            key,
+            &result.to_string(),
+            &dependencies.iter().cloned().chain(resources).collect::<Vec<_>>(),
+        );
+    }
+
     let mut result = TokenStream::from(result);
     if !diag.is_empty() {
         result.extend(diag.report_macro_diagnostic(&tokens));
@@ -32,6 +32,7 @@ pub mod generated_code {
     ///     // note that dashes will be replaced by underscores in the generated code
     ///     in-out property<string> user-name;
     ///     callback hello;
+    ///     callback item-edited(index: int, text: string);
     ///     public function do-something(x: int) -> bool { return x > 0; }
     ///     // ... maybe more elements here
     /// }
@@ -46,6 +47,22 @@ pub fn new() -> Result<Self, crate::PlatformError> {
             unimplemented!()
         }
 
+        /// Creates a new instance like [`Self::new()`], and calls `init` with it before the
+        /// `init` callbacks of the elements run and before the first frame is rendered. Use
+        /// this to set the initial values of properties without showing their default values first.
+        pub fn new_with(init: impl FnOnce(&Self)) -> Result<Self, crate::PlatformError> {
+            unimplemented!()
+        }
+
+        /// Returns a builder with a function for each property that can be set, to create the
+        /// component with initial values, like [`Self::new_with()`]:
+        /// ```ignore
+        ///     let sample = SampleComponent::builder().counter(5).user_name("x").build().unwrap();
+        /// ```
+        pub fn builder() -> SampleComponentBuilder {
+            unimplemented!()
+        }
+
         /// A getter is generated for each property declared at the root of the component.
         /// In this case, this is the getter that returns the value of the `counter`
         /// property declared in the `.slint` design markup.
@@ -56,6 +73,11 @@ pub fn get_counter(&self) -> i32 {
         /// In this case, this is the setter that sets the value of the `counter` property
         /// declared in the `.slint` design markup.
         pub fn set_counter(&self, value: i32) {}
+        /// For each property of a type that can be animated, such as numbers, lengths, and colors,
+        /// a setter that animates the property to the value is generated. This is the function
+        /// that animates the `counter` property with the given [`Animation`](crate::Animation),
+        /// instead of the one declared for the property in the `.slint` design markup.
+        pub fn set_counter_animated(&self, value: i32, animation: crate::Animation) {}
         /// Returns the value of the `user_name` property declared in the `.slint` design markup.
         pub fn get_user_name(&self) -> crate::SharedString {
             unimplemented!()
@@ -83,6 +105,12 @@ pub fn invoke_hello(&self) {}
         /// ```
         pub fn on_hello(&self, f: impl Fn() + 'static) {}
 
+        /// The handler of a callback with several parameters that all have a name gets a struct
+        /// with the arguments, instead of one argument per parameter.
+        pub fn on_item_edited(&self, f: impl FnMut(SampleComponentItemEditedArgs) + 'static) {}
+        /// Calls the `item-edited` callback.
+        pub fn invoke_item_edited(&self, index: i32, text: crate::SharedString) {}
+
         /// For each public function declared at the root of the component, a function to call
         /// that function is generated. This is the function that calls the `do-something` function
         /// declared in the `.slint` design markup.
@@ -91,6 +119,39 @@ pub fn invoke_do_something(&self, d: i32) -> bool {
         }
     }
 
+    /// The arguments of the `item-edited` callback, see [`SampleComponent::on_item_edited()`].
+    /// The name of the struct is the name of the component or global, followed by the name
+    /// of the callback and `Args`.
+    #[derive(Clone)]
+    pub struct SampleComponentItemEditedArgs {
+        /// The `index` argument
+        pub index: i32,
+        /// The `text` argument
+        pub text: crate::SharedString,
+    }
+
+    /// The builder returned by [`SampleComponent::builder()`]. Its name is the name of the
+    /// component followed by `Builder`.
+    #[derive(Default)]
+    #[must_use]
+    pub struct SampleComponentBuilder {
+        _marker: core::marker::PhantomData<*mut ()>,
+    }
+    impl SampleComponentBuilder {
+        /// Sets the initial value of the `counter` property.
+        pub fn counter(self, value: impl Into<i32>) -> Self {
+            unimplemented!()
+        }
+        /// Sets the initial value of the `user_name` property.
+        pub fn user_name(self, value: impl Into<crate::SharedString>) -> Self {
+            unimplemented!()
+        }
+        /// Creates the component with the values that were set.
+        pub fn build(self) -> Result<SampleComponent, crate::PlatformError> {
+            unimplemented!()
+        }
+    }
+
     impl ComponentHandle for SampleComponent {
         #[doc(hidden)]
         type Inner = SampleComponent;
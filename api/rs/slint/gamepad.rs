@@ -0,0 +1,350 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Gamepad and remote control input.
+//!
+//! **Note:** This module is only available with the "gamepad" feature
+//!
+//! [`GamepadInput`] reads the input of the gamepads with the [gilrs](https://crates.io/crates/gilrs) crate,
+//! and maps their buttons to key events for a window, so that TV and console style user interfaces
+//! can be navigated without keyboard:
+//!
+//! | Gamepad                              | Key                                               |
+//! |--------------------------------------|---------------------------------------------------|
+//! | D-pad and left stick                 | `UpArrow`, `DownArrow`, `LeftArrow`, `RightArrow` |
+//! | South button (A on Xbox controllers) | `Return`                                          |
+//! | East button (B on Xbox controllers)  | `Escape`                                          |
+//! | Right and left bumpers               | `Tab` and `Backtab`, which move the focus         |
+//!
+//! The callbacks set with [`GamepadInput::on_button()`] and [`GamepadInput::on_axis()`] receive the
+//! input of all the buttons and axes, for example for a game.
+//!
+//! # Example
+//!
+//! ```no_run
+//! slint::slint! {
+//!     export component MainWindow inherits Window {}
+//! }
+//! let main_window = MainWindow::new().unwrap();
+//! let gamepads = slint::gamepad::GamepadInput::new(main_window.window()).unwrap();
+//! gamepads.on_button(|event| println!("{:?} pressed: {}", event.button, event.pressed));
+//! main_window.run().unwrap();
+//! ```
+
+use crate::platform::{Key, WindowEvent};
+use crate::{PlatformError, Timer, TimerMode, Window};
+use i_slint_core::window::{WindowAdapter, WindowInner};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+/// How often the gamepads are polled
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(16);
+
+/// The value from which a stick presses an arrow key
+const STICK_PRESS_THRESHOLD: f32 = 0.5;
+/// The value under which a stick releases the arrow key again
+const STICK_RELEASE_THRESHOLD: f32 = 0.3;
+
+/// Identifies a gamepad while it's connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GamepadId(usize);
+
+/// The buttons of a gamepad, named after their position on the gamepad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GamepadButton {
+    /// The bottom button of the action pad (A on Xbox controllers, Cross on PlayStation controllers).
+    South,
+    /// The right button of the action pad (B on Xbox controllers, Circle on PlayStation controllers).
+    East,
+    /// The top button of the action pad (Y on Xbox controllers, Triangle on PlayStation controllers).
+    North,
+    /// The left button of the action pad (X on Xbox controllers, Square on PlayStation controllers).
+    West,
+    /// The upper left shoulder button.
+    LeftBumper,
+    /// The lower left shoulder button.
+    LeftTrigger,
+    /// The upper right shoulder button.
+    RightBumper,
+    /// The lower right shoulder button.
+    RightTrigger,
+    /// The left button in the middle of the gamepad, such as Back or Select.
+    Select,
+    /// The right button in the middle of the gamepad, such as Start or Menu.
+    Start,
+    /// The button with the logo of the vendor.
+    Mode,
+    /// The press of the left stick.
+    LeftStick,
+    /// The press of the right stick.
+    RightStick,
+    /// Up on the directional pad.
+    DPadUp,
+    /// Down on the directional pad.
+    DPadDown,
+    /// Left on the directional pad.
+    DPadLeft,
+    /// Right on the directional pad.
+    DPadRight,
+    /// Another button.
+    Other,
+}
+
+impl From<gilrs::Button> for GamepadButton {
+    fn from(button: gilrs::Button) -> Self {
+        match button {
+            gilrs::Button::South => Self::South,
+            gilrs::Button::East => Self::East,
+            gilrs::Button::North => Self::North,
+            gilrs::Button::West => Self::West,
+            gilrs::Button::LeftTrigger => Self::LeftBumper,
+            gilrs::Button::LeftTrigger2 => Self::LeftTrigger,
+            gilrs::Button::RightTrigger => Self::RightBumper,
+            gilrs::Button::RightTrigger2 => Self::RightTrigger,
+            gilrs::Button::Select => Self::Select,
+            gilrs::Button::Start => Self::Start,
+            gilrs::Button::Mode => Self::Mode,
+            gilrs::Button::LeftThumb => Self::LeftStick,
+            gilrs::Button::RightThumb => Self::RightStick,
+            gilrs::Button::DPadUp => Self::DPadUp,
+            gilrs::Button::DPadDown => Self::DPadDown,
+            gilrs::Button::DPadLeft => Self::DPadLeft,
+            gilrs::Button::DPadRight => Self::DPadRight,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The axes of a gamepad. The values are between -1 and 1, and positive towards the right and the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GamepadAxis {
+    /// The horizontal axis of the left stick.
+    LeftStickX,
+    /// The vertical axis of the left stick.
+    LeftStickY,
+    /// The horizontal axis of the right stick.
+    RightStickX,
+    /// The vertical axis of the right stick.
+    RightStickY,
+    /// The horizontal axis of a directional pad that reports it as an axis.
+    DPadX,
+    /// The vertical axis of a directional pad that reports it as an axis.
+    DPadY,
+    /// Another axis.
+    Other,
+}
+
+impl From<gilrs::Axis> for GamepadAxis {
+    fn from(axis: gilrs::Axis) -> Self {
+        match axis {
+            gilrs::Axis::LeftStickX => Self::LeftStickX,
+            gilrs::Axis::LeftStickY => Self::LeftStickY,
+            gilrs::Axis::RightStickX => Self::RightStickX,
+            gilrs::Axis::RightStickY => Self::RightStickY,
+            gilrs::Axis::DPadX => Self::DPadX,
+            gilrs::Axis::DPadY => Self::DPadY,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A button of a gamepad was pressed or released, see [`GamepadInput::on_button()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct GamepadButtonEvent {
+    /// The gamepad of the button.
+    pub gamepad: GamepadId,
+    /// The button.
+    pub button: GamepadButton,
+    /// True when the button was pressed, false when it was released.
+    pub pressed: bool,
+}
+
+/// An axis of a gamepad moved, see [`GamepadInput::on_axis()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct GamepadAxisEvent {
+    /// The gamepad of the axis.
+    pub gamepad: GamepadId,
+    /// The axis.
+    pub axis: GamepadAxis,
+    /// The new value, between -1 and 1.
+    pub value: f32,
+}
+
+/// Reads the input of the gamepads from the event loop, and dispatches the key events that their
+/// buttons are mapped to to a window. See the [module documentation](self) for the mapping.
+///
+/// The gamepads are read as long as this struct is kept alive.
+pub struct GamepadInput {
+    inner: Rc<GamepadInputInner>,
+    _timer: Timer,
+}
+
+struct GamepadInputInner {
+    gilrs: RefCell<gilrs::Gilrs>,
+    window_adapter: Weak<dyn WindowAdapter>,
+    key_events_enabled: Cell<bool>,
+    button_callback: RefCell<Option<Box<dyn FnMut(GamepadButtonEvent)>>>,
+    axis_callback: RefCell<Option<Box<dyn FnMut(GamepadAxisEvent)>>>,
+    /// The arrow keys that are pressed with the left stick, per axis
+    stick_keys: RefCell<HashMap<(GamepadId, GamepadAxis), Key>>,
+}
+
+impl GamepadInput {
+    /// Starts reading the gamepads, and dispatches the key events to the window.
+    ///
+    /// Returns an error if the gamepads of the platform can't be read.
+    pub fn new(window: &Window) -> Result<Self, PlatformError> {
+        let gilrs = gilrs::Gilrs::new()
+            .map_err(|e| PlatformError::from(format!("Error initializing gamepads: {e}")))?;
+        let inner = Rc::new(GamepadInputInner {
+            gilrs: RefCell::new(gilrs),
+            window_adapter: Rc::downgrade(&WindowInner::from_pub(window).window_adapter()),
+            key_events_enabled: Cell::new(true),
+            button_callback: Default::default(),
+            axis_callback: Default::default(),
+            stick_keys: Default::default(),
+        });
+        let timer = Timer::default();
+        let weak = Rc::downgrade(&inner);
+        timer.start(TimerMode::Repeated, POLL_INTERVAL, move || {
+            if let Some(inner) = weak.upgrade() {
+                inner.poll();
+            }
+        });
+        Ok(Self { inner, _timer: timer })
+    }
+
+    /// Sets whether the buttons are mapped to key events. This is enabled by default.
+    pub fn set_key_events_enabled(&self, enabled: bool) {
+        self.inner.key_events_enabled.set(enabled);
+    }
+
+    /// Sets the callback that is invoked when a button of a gamepad is pressed or released.
+    pub fn on_button(&self, callback: impl FnMut(GamepadButtonEvent) + 'static) {
+        *self.inner.button_callback.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback that is invoked when an axis of a gamepad, such as a stick, moves.
+    pub fn on_axis(&self, callback: impl FnMut(GamepadAxisEvent) + 'static) {
+        *self.inner.axis_callback.borrow_mut() = Some(Box::new(callback));
+    }
+}
+
+impl GamepadInputInner {
+    fn poll(&self) {
+        loop {
+            let Some(event) = self.gilrs.borrow_mut().next_event() else { break };
+            let gamepad = GamepadId(event.id.into());
+            match event.event {
+                gilrs::EventType::ButtonPressed(button, _) => {
+                    self.button(gamepad, button.into(), true, false)
+                }
+                gilrs::EventType::ButtonRepeated(button, _) => {
+                    self.button(gamepad, button.into(), true, true)
+                }
+                gilrs::EventType::ButtonReleased(button, _) => {
+                    self.button(gamepad, button.into(), false, false)
+                }
+                gilrs::EventType::AxisChanged(axis, value, _) => {
+                    self.axis(gamepad, axis.into(), value)
+                }
+                gilrs::EventType::Disconnected => {
+                    // Release the arrow keys that the stick of the gamepad pressed
+                    let released: Vec<_> = {
+                        let mut stick_keys = self.stick_keys.borrow_mut();
+                        let released = stick_keys
+                            .iter()
+                            .filter(|((id, _), _)| *id == gamepad)
+                            .map(|(_, &key)| key)
+                            .collect();
+                        stick_keys.retain(|(id, _), _| *id != gamepad);
+                        released
+                    };
+                    for key in released {
+                        self.send_key(WindowEvent::KeyReleased { text: key.into() });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn button(&self, gamepad: GamepadId, button: GamepadButton, pressed: bool, repeat: bool) {
+        if !repeat {
+            invoke(&self.button_callback, GamepadButtonEvent { gamepad, button, pressed });
+        }
+        let key = match button {
+            GamepadButton::DPadUp => Key::UpArrow,
+            GamepadButton::DPadDown => Key::DownArrow,
+            GamepadButton::DPadLeft => Key::LeftArrow,
+            GamepadButton::DPadRight => Key::RightArrow,
+            GamepadButton::South => Key::Return,
+            GamepadButton::East => Key::Escape,
+            GamepadButton::RightBumper => Key::Tab,
+            GamepadButton::LeftBumper => Key::Backtab,
+            _ => return,
+        };
+        let text = key.into();
+        self.send_key(match (pressed, repeat) {
+            (true, false) => WindowEvent::KeyPressed { text },
+            (true, true) => WindowEvent::KeyPressRepeated { text },
+            (false, _) => WindowEvent::KeyReleased { text },
+        });
+    }
+
+    fn axis(&self, gamepad: GamepadId, axis: GamepadAxis, value: f32) {
+        invoke(&self.axis_callback, GamepadAxisEvent { gamepad, axis, value });
+        let (negative_key, positive_key) = match axis {
+            GamepadAxis::LeftStickX => (Key::LeftArrow, Key::RightArrow),
+            GamepadAxis::LeftStickY => (Key::DownArrow, Key::UpArrow),
+            _ => return,
+        };
+        let current_key = self.stick_keys.borrow().get(&(gamepad, axis)).copied();
+        let new_key = if value >= STICK_PRESS_THRESHOLD {
+            Some(positive_key)
+        } else if value <= -STICK_PRESS_THRESHOLD {
+            Some(negative_key)
+        } else if value.abs() < STICK_RELEASE_THRESHOLD {
+            None
+        } else {
+            current_key
+        };
+        if new_key == current_key {
+            return;
+        }
+        match new_key {
+            Some(key) => self.stick_keys.borrow_mut().insert((gamepad, axis), key),
+            None => self.stick_keys.borrow_mut().remove(&(gamepad, axis)),
+        };
+        if let Some(key) = current_key {
+            self.send_key(WindowEvent::KeyReleased { text: key.into() });
+        }
+        if let Some(key) = new_key {
+            self.send_key(WindowEvent::KeyPressed { text: key.into() });
+        }
+    }
+
+    fn send_key(&self, event: WindowEvent) {
+        if !self.key_events_enabled.get() {
+            return;
+        }
+        if let Some(window_adapter) = self.window_adapter.upgrade() {
+            window_adapter.window().dispatch_event(event);
+        }
+    }
+}
+
+/// Invokes the callback, which may set a new callback
+fn invoke<T>(callback: &RefCell<Option<Box<dyn FnMut(T)>>>, arg: T) {
+    let Some(mut f) = callback.borrow_mut().take() else { return };
+    f(arg);
+    let mut callback = callback.borrow_mut();
+    if callback.is_none() {
+        *callback = Some(f);
+    }
+}
@@ -207,6 +207,7 @@ struct MyComponent{ /*...*/ }
 
 pub use slint_macros::slint;
 
+pub use i_slint_core::animations::{Animation, EasingCurve};
 pub use i_slint_core::api::*;
 #[doc(hidden)]
 #[deprecated(note = "Experimental type was made public by mistake")]
@@ -214,15 +215,26 @@ struct MyComponent{ /*...*/ }
 #[cfg(not(target_arch = "wasm32"))]
 pub use i_slint_core::graphics::{BorrowedOpenGLTextureBuilder, BorrowedOpenGLTextureOrigin};
 // keep in sync with internal/interpreter/api.rs
+#[cfg(feature = "std")]
+pub use i_slint_core::graphics::{validate_path_commands, PathCommandsError};
 pub use i_slint_core::graphics::{
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
+#[cfg(target_has_atomic = "ptr")]
+pub use i_slint_core::model::LazyModel;
 pub use i_slint_core::model::{
-    FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    FilterModel, FlattenedTreeModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc,
+    ModelTracker, ReverseModel, SortModel, StandardListViewItem, TableColumn, TreeModel,
+    TreeViewItem, VecModel,
 };
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
+#[cfg(feature = "std")]
+pub use i_slint_core::translations::{
+    select_translation_language, set_pseudo_localization, set_translation_catalog,
+    track_untranslated_strings, untranslated_strings, PseudoLocalization, TranslationCatalog,
+    UntranslatedString,
+};
 pub use i_slint_core::{format, string::SharedString};
 
 pub mod private_unstable_api;
@@ -340,6 +352,57 @@ pub fn spawn_local<F: core::future::Future + 'static>(
         .map_err(|_| EventLoopError::NoEventLoopProvider)?
 }
 
+/// Returns the screens, such as the monitors, on which the windows can be shown. This initializes the
+/// backend if it isn't yet.
+///
+/// The list is empty if the backend doesn't report its screens. The winit and Qt backends do.
+///
+/// ```no_run
+/// for screen in slint::screens().unwrap() {
+///     println!("{}: {:?} at {:?}", screen.name, screen.size, screen.position);
+/// }
+/// ```
+pub fn screens() -> Result<alloc::vec::Vec<Screen>, PlatformError> {
+    i_slint_backend_selector::with_platform(|b| Ok(b.screens()))
+}
+
+/// Sets the callback that is invoked when a screen is connected or disconnected, or when the geometry
+/// or the scale factor of a screen changes, for example to move a window back onto a screen that is
+/// still connected. Call [`screens()`] in the callback to get the updated list.
+/// This initializes the backend if it isn't yet.
+pub fn on_screens_changed(callback: impl FnMut() + 'static) -> Result<(), PlatformError> {
+    i_slint_backend_selector::with_global_context(|ctx| ctx.set_screens_changed_handler(callback))
+}
+
+/// Registers a keyboard shortcut with the operating system, to invoke the callback from the event loop
+/// when it's pressed, even when the windows of the application don't have the focus. This is meant
+/// for tray utilities, or for push-to-talk in applications that run in the background.
+/// This initializes the backend if it isn't yet.
+///
+/// Returns an error if the shortcut is already registered, if it's taken by another application,
+/// or if the backend doesn't support global shortcuts. The winit backend supports them on Windows,
+/// macOS, and X11 when the `global-shortcuts` feature is enabled. Wayland doesn't allow applications
+/// to register global shortcuts.
+///
+/// ```no_run
+/// let shortcut = slint::GlobalShortcut::new(slint::platform::Key::F9).with_control();
+/// slint::register_global_shortcut(shortcut, || println!("Ctrl+F9 pressed")).unwrap();
+/// slint::run_event_loop_until_quit().unwrap();
+/// ```
+pub fn register_global_shortcut(
+    shortcut: GlobalShortcut,
+    callback: impl FnMut() + 'static,
+) -> Result<(), PlatformError> {
+    i_slint_backend_selector::with_global_context(|ctx| {
+        ctx.register_global_shortcut(shortcut, callback)
+    })?
+}
+
+/// Unregisters a shortcut that was registered with [`register_global_shortcut()`].
+pub fn unregister_global_shortcut(shortcut: &GlobalShortcut) -> Result<(), PlatformError> {
+    i_slint_backend_selector::with_global_context(|ctx| ctx.unregister_global_shortcut(shortcut))
+}
+
 /// Include the code generated with the slint-build crate from the build script. After calling `slint_build::compile`
 /// in your `build.rs` build script, the use of this macro includes the generated Rust code and makes the exported types
 /// available for you to instantiate.
@@ -399,6 +462,18 @@ macro_rules! init_translations {
 pub mod platform {
     pub use i_slint_core::platform::*;
 
+    /// Returns which of the optional features, such as the clipboard or multiple windows, the
+    /// backend supports. This initializes the backend if it isn't yet.
+    ///
+    /// ```no_run
+    /// if !slint::platform::capabilities().is_ok_and(|c| c.multiple_windows) {
+    ///     // Show the settings in a tab instead of a separate window
+    /// }
+    /// ```
+    pub fn capabilities() -> Result<Capabilities, PlatformError> {
+        i_slint_backend_selector::with_platform(|b| Ok(b.capabilities()))
+    }
+
     /// This module contains the [`femtovg_renderer::FemtoVGRenderer`] and related types.
     ///
     /// It is only enabled when the `renderer-femtovg` Slint feature is enabled.
@@ -409,6 +484,26 @@ pub mod femtovg_renderer {
     }
 }
 
+/// This module contains the API to implement custom items, native elements that the application
+/// implements in Rust and declares in a manifest passed to `slint-build`.
+///
+/// See [`custom_item::v1::CustomItem`] for the trait to implement.
+pub mod custom_item {
+    pub use i_slint_core::custom_item::*;
+}
+
+/// This module contains helpers to connect telemetry sources, such as CAN buses or serial
+/// ports, to the properties and models of the UI.
+///
+/// See [`telemetry::TelemetryBridge`] for an example.
+#[cfg(feature = "std")]
+pub mod telemetry {
+    pub use i_slint_core::telemetry::*;
+}
+
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
+
 #[cfg(any(
     doc,
     all(
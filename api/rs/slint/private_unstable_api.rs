@@ -127,6 +127,19 @@ pub fn set_callback_handler<
     })
 }
 
+/// Held while a binding that was compiled with debug information is evaluated, so that a panic
+/// in the binding reports its `file:line:column` in the `.slint` file.
+pub struct SourceLocationGuard(pub &'static str);
+
+impl Drop for SourceLocationGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        if std::thread::panicking() {
+            std::eprintln!("The panic happened in the binding at {}", self.0);
+        }
+    }
+}
+
 pub fn debug(s: SharedString) {
     #[cfg(feature = "log")]
     log::debug!("{s}");
@@ -168,6 +181,10 @@ pub fn use_24_hour_format() -> bool {
     i_slint_core::date_time::use_24_hour_format()
 }
 
+pub fn translation_language_matches(locale: &str) -> bool {
+    i_slint_core::translations::translation_language_matches(locale)
+}
+
 /// internal re_exports used by the macro generated
 pub mod re_exports {
     pub use alloc::boxed::Box;
@@ -206,14 +223,19 @@ pub mod re_exports {
     pub use i_slint_core::lengths::{
         logical_position_to_api, LogicalLength, LogicalPoint, LogicalRect,
     };
+    pub use i_slint_core::locale;
     pub use i_slint_core::model::*;
     pub use i_slint_core::properties::{
-        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+        set_state_binding, ChangeTracker, InterpolatedPropertyValue, Property, PropertyTracker,
+        StateInfo,
     };
+    pub use i_slint_core::regex::{regex_capture, regex_matches, regex_replace};
     pub use i_slint_core::slice::Slice;
+    pub use i_slint_core::string::{format_fixed, pad_string};
+    pub use i_slint_core::theme::{theme_brush, theme_length, theme_string};
     pub use i_slint_core::timers::{Timer, TimerMode};
     pub use i_slint_core::window::{
-        InputMethodRequest, WindowAdapter, WindowAdapterRc, WindowInner,
+        popup::PopupOptions, InputMethodRequest, WindowAdapter, WindowAdapterRc, WindowInner,
     };
     pub use i_slint_core::Color;
     pub use i_slint_core::Coord;
@@ -0,0 +1,73 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::{LazyModel, Model};
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// A future that lets the other tasks of the event loop run once
+struct YieldNow(bool);
+
+impl std::future::Future for YieldNow {
+    type Output = ();
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<()> {
+        if std::mem::replace(&mut self.0, true) {
+            std::task::Poll::Ready(())
+        } else {
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn main() {
+    i_slint_backend_testing::init_integration_test_with_mock_time();
+
+    slint::invoke_from_event_loop(|| {
+        let fetched = Rc::new(RefCell::new(Vec::<Range<usize>>::new()));
+        let model = Rc::new(
+            LazyModel::new(120, |row| format!("placeholder {row}"), {
+                let fetched = fetched.clone();
+                move |range: Range<usize>| {
+                    fetched.borrow_mut().push(range.clone());
+                    async move { range.map(|row| format!("row {row}")).collect() }
+                }
+            })
+            .with_page_size(50),
+        );
+
+        assert_eq!(model.row_count(), 120);
+        assert_eq!(model.row_data(60).as_deref(), Some("placeholder 60"));
+        assert_eq!(model.row_data(61).as_deref(), Some("placeholder 61"));
+        assert_eq!(model.row_data(120), None);
+        assert!(!model.is_row_loaded(60));
+        assert_eq!(*fetched.borrow(), [50..100]);
+
+        slint::spawn_local(async move {
+            while !model.is_row_loaded(60) {
+                YieldNow(false).await;
+            }
+            assert_eq!(model.row_data(60).as_deref(), Some("row 60"));
+            assert_eq!(model.row_data(99).as_deref(), Some("row 99"));
+
+            // The last page is shorter
+            assert_eq!(model.row_data(110).as_deref(), Some("placeholder 110"));
+            assert_eq!(*fetched.borrow(), [50..100, 100..120]);
+
+            model.reset();
+            assert!(!model.is_row_loaded(60));
+            assert_eq!(model.row_data(60).as_deref(), Some("placeholder 60"));
+            assert_eq!(fetched.borrow().len(), 3);
+
+            slint::quit_event_loop().unwrap();
+        })
+        .unwrap();
+    })
+    .unwrap();
+    slint::run_event_loop().unwrap();
+}
@@ -106,6 +106,63 @@ fn simple() {
     assert!(!window.draw_if_needed(|_| { unreachable!() }));
 }
 
+#[test]
+fn render_area() {
+    slint::slint! {
+        export component Ui inherits Window {
+            in property <color> c: yellow;
+            background: black;
+            Rectangle {
+                x: 10phx;
+                y: 20phx;
+                width: 30phx;
+                height: 40phx;
+                background: c;
+            }
+            Rectangle {
+                x: 100phx;
+                y: 150phx;
+                width: 20phx;
+                height: 20phx;
+                background: c;
+            }
+        }
+    }
+
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = Ui::new().unwrap();
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(180, 260));
+    ui.show().unwrap();
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 0, 0, 180, 260);
+    }));
+
+    let render_area = |renderer: &SoftwareRenderer, x: i32, y: i32, x2: i32, y2: i32| {
+        let mut buffer = vec![TestPixel(false); 500 * 500];
+        let origin = PhysicalPosition { x, y };
+        let size = PhysicalSize { width: (x2 - x) as _, height: (y2 - y) as _ };
+        let r = renderer.render_area(buffer.as_mut_slice(), 500, origin, size);
+        assert_eq!(r.bounding_box_origin(), origin);
+        assert_eq!(r.bounding_box_size(), size);
+        for py in 0..500 {
+            for px in 0..500 {
+                let in_area = (x..x2).contains(&(px as i32)) && (y..y2).contains(&(py as i32));
+                assert_eq!(buffer[py * 500 + px].0, in_area, "pixel {px},{py}");
+            }
+        }
+    };
+
+    // Only the requested area is rendered, even if it's not dirty
+    ui.set_c(slint::Color::from_rgb_u8(45, 12, 13));
+    assert!(window.draw_if_needed(|renderer| render_area(renderer, 5, 5, 25, 30)));
+    // The dirty parts outside of the area are rendered in the next frame
+    assert!(window.draw_if_needed(|renderer| {
+        do_test_render_region(renderer, 10, 20, 120, 170);
+    }));
+    assert!(!window.draw_if_needed(|_| { unreachable!() }));
+}
+
 #[test]
 fn visibility() {
     slint::slint! {
@@ -38,3 +38,10 @@ fn test_serialize_deserialize_struct() {
     let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
     assert_eq!(data, deserialized);
 }
+
+#[test]
+fn import_file() {
+    i_slint_backend_testing::init_no_event_loop();
+    slint!(import "tests/ui/macro_import.slint";);
+    assert_eq!(ImportedWindow::new().unwrap().get_text(), "imported");
+}
@@ -0,0 +1,399 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This crate serves as a companion crate of the slint crate.
+It provides [`SqliteModel`], a [`Model`] that exposes the rows of a SQLite table, so that they
+can be shown in a `ListView` or `StandardTableView` without loading the whole table into memory.
+
+The rows are read page by page when the view asks for them, and only a few pages are kept in
+memory. Changes made through the model are written to the database and notified to the views.
+
+## Example
+
+```rust,no_run
+# use std::rc::Rc;
+# use slint::{ModelRc, StandardListViewItem};
+use slint_sqlite::SqliteModel;
+
+let connection = Rc::new(rusqlite::Connection::open("contacts.db").unwrap());
+let contacts = SqliteModel::<ModelRc<StandardListViewItem>>::new(
+    connection,
+    "contacts",
+    &["name", "email"],
+)
+.with_order_by("name");
+
+contacts.insert(ModelRc::from(["Jane".into(), "jane@example.com".into()])).unwrap();
+// Pass `ModelRc::new(contacts)` to the `rows` property of a StandardTableView
+```
+
+Only tables with a [rowid](https://www.sqlite.org/rowidtable.html) are supported, because the
+rowid identifies the rows that are updated or removed. Call [`SqliteModel::refresh()`] after
+modifying the table without the model, so that the views show the new content.
+*/
+
+#![warn(missing_docs)]
+
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::Connection;
+use slint::{Model, ModelNotify, ModelRc, ModelTracker, StandardListViewItem};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// The number of rows that are read at once, unless changed with [`SqliteModel::with_page_size()`]
+const DEFAULT_PAGE_SIZE: usize = 64;
+/// The number of pages that are kept in memory
+const CACHED_PAGES: usize = 8;
+
+/// The data of one row of a [`SqliteModel`].
+///
+/// The columns of the row are the ones passed to [`SqliteModel::new()`], in the same order.
+pub trait SqliteRecord: Clone + 'static {
+    /// Creates the record from the columns of the row, using the index of each column.
+    fn from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self>;
+    /// Returns the value of each column, in the order of the columns of the model.
+    fn to_values(&self) -> Vec<Value>;
+}
+
+/// A row of a `StandardTableView`, with one item per column. The values are converted to text
+/// when reading, and written as text.
+impl SqliteRecord for ModelRc<StandardListViewItem> {
+    fn from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self> {
+        // The last column of the query is the rowid, which isn't part of the record
+        let column_count = row.as_ref().column_count() - 1;
+        let items = (0..column_count)
+            .map(|column| {
+                let text = match row.get_ref(column)? {
+                    ValueRef::Null | ValueRef::Blob(_) => String::new(),
+                    ValueRef::Integer(value) => value.to_string(),
+                    ValueRef::Real(value) => value.to_string(),
+                    ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned(),
+                };
+                Ok(StandardListViewItem::from(slint::SharedString::from(text)))
+            })
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(ModelRc::from(Rc::new(slint::VecModel::from(items))))
+    }
+
+    fn to_values(&self) -> Vec<Value> {
+        self.iter().map(|item| Value::Text(item.text.into())).collect()
+    }
+}
+
+struct Page<T> {
+    index: usize,
+    /// The rowid and the record of each row of the page
+    rows: Vec<(i64, T)>,
+}
+
+/// A [`Model`] of the rows of a SQLite table, that reads the rows lazily, page by page.
+///
+/// Use [`Self::insert()`], [`Self::update()`], and [`Self::remove()`] to modify the table. They
+/// write the change to the database and notify the views of the model. [`Model::set_row_data()`]
+/// updates the row like [`Self::update()`], and prints the error, if any.
+pub struct SqliteModel<T> {
+    connection: Rc<Connection>,
+    table: String,
+    columns: Vec<String>,
+    filter: Option<String>,
+    order_by: Option<String>,
+    page_size: usize,
+    row_count: Cell<Option<usize>>,
+    /// The most recently used page first
+    pages: RefCell<VecDeque<Page<T>>>,
+    notify: ModelNotify,
+}
+
+/// Quotes the name of a table or column for use in a SQL statement.
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+impl<T: SqliteRecord> SqliteModel<T> {
+    /// Creates a model of the given columns of the table. The records of the model are read from,
+    /// and written to, these columns.
+    pub fn new(connection: Rc<Connection>, table: &str, columns: &[&str]) -> Self {
+        Self {
+            connection,
+            table: quote_identifier(table),
+            columns: columns.iter().map(|column| quote_identifier(column)).collect(),
+            filter: None,
+            order_by: None,
+            page_size: DEFAULT_PAGE_SIZE,
+            row_count: Cell::new(None),
+            pages: Default::default(),
+            notify: Default::default(),
+        }
+    }
+
+    /// Only includes the rows that match the given SQL condition, as written after `WHERE`.
+    ///
+    /// The condition is inserted into the query as is, so it must not come from untrusted input.
+    pub fn with_filter(mut self, condition: impl Into<String>) -> Self {
+        self.filter = Some(condition.into());
+        self
+    }
+
+    /// Sorts the rows by the given SQL expression, as written after `ORDER BY`, for example
+    /// `"name COLLATE NOCASE, age DESC"`.
+    ///
+    /// The expression is inserted into the query as is, so it must not come from untrusted input.
+    pub fn with_order_by(mut self, ordering: impl Into<String>) -> Self {
+        self.order_by = Some(ordering.into());
+        self
+    }
+
+    /// Sets the number of rows that are read from the database at once.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Returns the connection to the database.
+    pub fn connection(&self) -> &Rc<Connection> {
+        &self.connection
+    }
+
+    /// Inserts the record into the table. The views are notified if the new row matches the
+    /// filter of the model.
+    pub fn insert(&self, data: T) -> rusqlite::Result<()> {
+        let values = data.to_values();
+        let placeholders = vec!["?"; values.len()].join(", ");
+        self.connection.execute(
+            &format!(
+                "INSERT INTO {} ({}) VALUES ({placeholders})",
+                self.table,
+                self.columns.join(", ")
+            ),
+            rusqlite::params_from_iter(values),
+        )?;
+        let position = self.position_of(self.connection.last_insert_rowid())?;
+        self.invalidate();
+        if let Some(position) = position {
+            self.notify.row_added(position, 1);
+        }
+        Ok(())
+    }
+
+    /// Writes the record to the row at the given index. The row moves if it is sorted differently
+    /// afterwards, and is removed from the model if it no longer matches the filter.
+    pub fn update(&self, row: usize, data: T) -> rusqlite::Result<()> {
+        let Some(rowid) = self.rowid(row)? else { return Ok(()) };
+        let values = data.to_values();
+        let assignments = self
+            .columns
+            .iter()
+            .map(|column| format!("{column} = ?"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.connection.execute(
+            &format!("UPDATE {} SET {assignments} WHERE rowid = ?", self.table),
+            rusqlite::params_from_iter(values.into_iter().chain([Value::Integer(rowid)])),
+        )?;
+        let position = self.position_of(rowid)?;
+        self.invalidate();
+        match position {
+            Some(position) if position == row => self.notify.row_changed(row),
+            Some(position) => {
+                self.notify.row_removed(row, 1);
+                self.notify.row_added(position, 1);
+            }
+            None => self.notify.row_removed(row, 1),
+        }
+        Ok(())
+    }
+
+    /// Deletes the row at the given index from the table.
+    pub fn remove(&self, row: usize) -> rusqlite::Result<()> {
+        let Some(rowid) = self.rowid(row)? else { return Ok(()) };
+        self.connection.execute(&format!("DELETE FROM {} WHERE rowid = ?", self.table), [rowid])?;
+        self.invalidate();
+        self.notify.row_removed(row, 1);
+        Ok(())
+    }
+
+    /// Discards the rows read so far and notifies the views that the whole model changed.
+    /// Call this after modifying the table without this model.
+    pub fn refresh(&self) {
+        self.invalidate();
+        self.notify.reset();
+    }
+
+    fn invalidate(&self) {
+        self.row_count.set(None);
+        self.pages.borrow_mut().clear();
+    }
+
+    /// The `WHERE` and `ORDER BY` clauses of the queries
+    fn clauses(&self) -> (String, String) {
+        (
+            self.filter.as_ref().map_or_else(String::new, |filter| format!("WHERE {filter}")),
+            self.order_by.as_ref().map_or_else(String::new, |order| format!("ORDER BY {order}")),
+        )
+    }
+
+    fn query_row_count(&self) -> rusqlite::Result<usize> {
+        let (filter, _) = self.clauses();
+        self.connection
+            .prepare_cached(&format!("SELECT COUNT(*) FROM {} {filter}", self.table))?
+            .query_row([], |row| row.get::<_, i64>(0))
+            .map(|count| count as usize)
+    }
+
+    /// Returns the index of the row with the given rowid in the model, or None if it doesn't
+    /// match the filter.
+    fn position_of(&self, rowid: i64) -> rusqlite::Result<Option<usize>> {
+        let (filter, order_by) = self.clauses();
+        let query = format!(
+            "SELECT position FROM (SELECT rowid AS id, ROW_NUMBER() OVER ({order_by}) - 1 AS \
+             position FROM {} {filter}) WHERE id = ?",
+            self.table
+        );
+        let mut statement = self.connection.prepare_cached(&query)?;
+        let mut rows = statement.query([rowid])?;
+        let position = match rows.next()? {
+            Some(row) => Some(row.get::<_, i64>(0)? as usize),
+            None => None,
+        };
+        Ok(position)
+    }
+
+    /// Reads the page of rows with the given index, unless it is already in memory, and calls
+    /// the function with it.
+    fn with_page<R>(&self, index: usize, f: impl FnOnce(&Page<T>) -> R) -> rusqlite::Result<R> {
+        let mut pages = self.pages.borrow_mut();
+        if let Some(position) = pages.iter().position(|page| page.index == index) {
+            let page = pages.remove(position).unwrap();
+            pages.push_front(page);
+        } else {
+            let (filter, order_by) = self.clauses();
+            let query = format!(
+                "SELECT {}, rowid FROM {} {filter} {order_by} LIMIT ? OFFSET ?",
+                self.columns.join(", "),
+                self.table
+            );
+            let rowid_column = self.columns.len();
+            let mut statement = self.connection.prepare_cached(&query)?;
+            let rows = statement
+                .query_map([self.page_size as i64, (index * self.page_size) as i64], |row| {
+                    Ok((row.get(rowid_column)?, T::from_row(row)?))
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            pages.truncate(CACHED_PAGES - 1);
+            pages.push_front(Page { index, rows });
+        }
+        Ok(f(&pages[0]))
+    }
+
+    fn record(&self, row: usize) -> rusqlite::Result<Option<(i64, T)>> {
+        self.with_page(row / self.page_size, |page| page.rows.get(row % self.page_size).cloned())
+    }
+
+    fn rowid(&self, row: usize) -> rusqlite::Result<Option<i64>> {
+        Ok(self.record(row)?.map(|(rowid, _)| rowid))
+    }
+}
+
+impl<T: SqliteRecord> Model for SqliteModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        if let Some(count) = self.row_count.get() {
+            return count;
+        }
+        let count = self.query_row_count().unwrap_or_else(|err| {
+            eprintln!("slint-sqlite: Error counting the rows of {}: {err}", self.table);
+            0
+        });
+        self.row_count.set(Some(count));
+        count
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.record(row)
+            .unwrap_or_else(|err| {
+                eprintln!("slint-sqlite: Error reading the rows of {}: {err}", self.table);
+                None
+            })
+            .map(|(_, data)| data)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if let Err(err) = self.update(row, data) {
+            eprintln!("slint-sqlite: Error updating the row of {}: {err}", self.table);
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[test]
+fn test_sqlite_model() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Person {
+        name: String,
+        age: i64,
+    }
+
+    impl SqliteRecord for Person {
+        fn from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self> {
+            Ok(Self { name: row.get(0)?, age: row.get(1)? })
+        }
+        fn to_values(&self) -> Vec<Value> {
+            vec![self.name.clone().into(), self.age.into()]
+        }
+    }
+
+    let person = |name: &str, age| Person { name: name.into(), age };
+
+    let connection = Rc::new(Connection::open_in_memory().unwrap());
+    connection.execute_batch("CREATE TABLE people (name TEXT, age INTEGER)").unwrap();
+    for (name, age) in [("Dana", 40), ("Bob", 30), ("Alice", 20), ("Eve", 10), ("Carol", 50)] {
+        connection.execute("INSERT INTO people VALUES (?, ?)", (name, age)).unwrap();
+    }
+
+    let adults = SqliteModel::<Person>::new(connection.clone(), "people", &["name", "age"])
+        .with_filter("age >= 18")
+        .with_order_by("name")
+        .with_page_size(2);
+    assert_eq!(adults.row_count(), 4);
+    let names = adults.iter().map(|p| p.name).collect::<Vec<_>>();
+    assert_eq!(names, ["Alice", "Bob", "Carol", "Dana"]);
+    assert_eq!(adults.row_data(4), None);
+
+    adults.insert(person("Bea", 25)).unwrap();
+    assert_eq!(adults.row_count(), 5);
+    assert_eq!(adults.row_data(1), Some(person("Bea", 25)));
+
+    // A child doesn't match the filter
+    adults.insert(person("Finn", 5)).unwrap();
+    assert_eq!(adults.row_count(), 5);
+
+    adults.update(0, person("Zoe", 20)).unwrap();
+    assert_eq!(adults.row_data(4), Some(person("Zoe", 20)));
+    adults.set_row_data(4, person("Zoe", 12));
+    assert_eq!(adults.row_count(), 4);
+
+    adults.remove(0).unwrap();
+    let names = adults.iter().map(|p| p.name).collect::<Vec<_>>();
+    assert_eq!(names, ["Bob", "Carol", "Dana"]);
+
+    connection.execute("DELETE FROM people WHERE name = 'Carol'", ()).unwrap();
+    adults.refresh();
+    assert_eq!(adults.row_count(), 2);
+
+    let table =
+        SqliteModel::<ModelRc<StandardListViewItem>>::new(connection, "people", &["name", "age"])
+            .with_order_by("age");
+    let first = table.row_data(0).unwrap();
+    assert_eq!(first.row_data(0).unwrap().text, "Finn");
+    assert_eq!(first.row_data(1).unwrap().text, "5");
+}
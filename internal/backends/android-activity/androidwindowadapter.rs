@@ -4,11 +4,11 @@
 use super::*;
 use crate::javahelper::{print_jni_error, JavaHelper};
 use android_activity::input::{
-    ButtonState, InputEvent, KeyAction, Keycode, MotionAction, MotionEvent,
+    ButtonState, InputEvent, KeyAction, Keycode, MotionAction, MotionEvent, ToolType,
 };
 use android_activity::{InputStatus, MainEvent, PollEvent};
 use i_slint_core::api::{LogicalPosition, PhysicalPosition, PhysicalSize, PlatformError, Window};
-use i_slint_core::items::ColorScheme;
+use i_slint_core::items::{ColorScheme, InteractionMode};
 use i_slint_core::platform::{
     Key, PointerEventButton, WindowAdapter, WindowEvent, WindowProperties,
 };
@@ -288,6 +288,12 @@ fn process_inputs(&self) -> Result<(), android_activity::error::AppError> {
                             long_press_timeout,
                         );
                         self.long_press.replace(Some(LongPressDetection { position, _timer }));
+                        WindowInner::from_pub(&self.window).input_device_used(
+                            match motion_event.pointer_at_index(0).tool_type() {
+                                ToolType::Finger => InteractionMode::Touch,
+                                _ => InteractionMode::Pointer,
+                            },
+                        );
                         self.window.dispatch_event(WindowEvent::PointerPressed {
                             position,
                             button: PointerEventButton::Left,
@@ -404,6 +410,7 @@ pub fn do_render(&self) -> Result<(), PlatformError> {
                 PhysicalSize { width: win.width() as _, height: win.height() as _ },
                 None,
             )?;
+            self.window.dispatch_event(WindowEvent::FramePresented);
         }
         Ok(())
     }
@@ -152,6 +152,13 @@ fn clipboard_text(&self, clipboard: Clipboard) -> Option<String> {
             None
         }
     }
+
+    fn capabilities(&self) -> i_slint_core::platform::Capabilities {
+        let mut capabilities = i_slint_core::platform::Capabilities::default();
+        capabilities.clipboard = true;
+        capabilities.input_method = true;
+        capabilities
+    }
 }
 
 enum Event {
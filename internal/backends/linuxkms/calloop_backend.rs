@@ -26,6 +26,8 @@
 )))]
 mod input;
 
+mod backlight;
+
 #[derive(Clone)]
 struct Proxy {
     loop_signal: Arc<Mutex<Option<calloop::LoopSignal>>>,
@@ -70,7 +72,8 @@ fn invoke_from_event_loop(
 pub struct Backend {
     #[cfg(feature = "libseat")]
     seat: Rc<RefCell<libseat::Seat>>,
-    window: RefCell<Option<Rc<FullscreenWindowAdapter>>>,
+    /// The windows, in the order of their creation. Each window is shown on its own output.
+    windows: RefCell<Vec<Rc<FullscreenWindowAdapter>>>,
     user_event_receiver: RefCell<Option<calloop::channel::Channel<Box<dyn FnOnce() + Send>>>>,
     proxy: Proxy,
     renderer_factory: for<'a> fn(
@@ -81,6 +84,10 @@ pub struct Backend {
     >,
     sel_clipboard: RefCell<Option<String>>,
     clipboard: RefCell<Option<String>>,
+    /// Opened on first use
+    backlight: RefCell<Option<backlight::SysfsBacklight>>,
+    /// The window whose output is taken over by the error screen, while it's being created
+    error_screen_window: RefCell<Option<Rc<FullscreenWindowAdapter>>>,
 }
 
 impl Backend {
@@ -142,12 +149,43 @@ pub fn new_with_renderer_by_name(renderer_name: Option<&str>) -> Result<Self, Pl
         Ok(Backend {
             #[cfg(feature = "libseat")]
             seat: Rc::new(RefCell::new(seat)),
-            window: Default::default(),
+            windows: Default::default(),
             user_event_receiver: RefCell::new(Some(user_event_receiver)),
             proxy: Proxy::new(user_event_sender),
             renderer_factory,
             sel_clipboard: Default::default(),
             clipboard: Default::default(),
+            backlight: Default::default(),
+            error_screen_window: Default::default(),
+        })
+    }
+}
+
+impl Backend {
+    /// Shows the error screen in place of the first window, after a panic was caught in the
+    /// event loop. The other windows keep showing their last frame.
+    fn show_error_screen_after_panic(
+        &self,
+        payload: Box<dyn std::any::Any + Send>,
+    ) -> Result<(), PlatformError> {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "The application panicked".into());
+
+        let windows = self.windows.take();
+        *self.error_screen_window.borrow_mut() = windows.first().cloned();
+        let result = i_slint_core::platform::show_error_screen(message);
+        self.error_screen_window.take();
+        // The error screen's window is the first one again, so that it gets the input
+        let mut error_screen_windows = self.windows.take();
+        error_screen_windows.extend(windows.into_iter().skip(1));
+        *self.windows.borrow_mut() = error_screen_windows;
+
+        result.map_err(|err| {
+            // Leave the panic message on the terminal, when the error screen can't be shown either
+            format!("Error showing the error screen after a panic: {err}").into()
         })
     }
 }
@@ -156,6 +194,11 @@ impl i_slint_core::platform::Platform for Backend {
     fn create_window_adapter(
         &self,
     ) -> Result<std::rc::Rc<dyn i_slint_core::window::WindowAdapter>, PlatformError> {
+        if let Some(adapter) = self.error_screen_window.take() {
+            self.windows.borrow_mut().push(adapter.clone());
+            return Ok(adapter);
+        }
+
         #[cfg(feature = "libseat")]
         let device_accessor = |device: &std::path::Path| -> Result<Rc<OwnedFd>, PlatformError> {
             let device = self
@@ -202,7 +245,7 @@ fn create_window_adapter(
         let renderer = (self.renderer_factory)(&device_accessor)?;
         let adapter = FullscreenWindowAdapter::new(renderer, rotation)?;
 
-        *self.window.borrow_mut() = Some(adapter.clone());
+        self.windows.borrow_mut().push(adapter.clone());
 
         Ok(adapter)
     }
@@ -216,8 +259,8 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
         *self.proxy.loop_signal.lock().unwrap() = Some(loop_signal.clone());
         let quit_loop = self.proxy.quit_loop.clone();
 
-        let mouse_position_property = input::LibInputHandler::init(
-            &self.window,
+        input::LibInputHandler::init(
+            &self.windows,
             &event_loop.handle(),
             #[cfg(feature = "libseat")]
             &self.seat,
@@ -251,24 +294,42 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
 
         quit_loop.store(false, std::sync::atomic::Ordering::Release);
 
+        let mut showing_error_screen = false;
+
         while !quit_loop.load(std::sync::atomic::Ordering::Acquire) {
-            i_slint_core::platform::update_timers_and_animations();
+            let mut iterate = || -> Result<(), PlatformError> {
+                i_slint_core::platform::update_timers_and_animations();
 
-            // Only after updating the animation tick, invoke callbacks from invoke_from_event_loop(). They
-            // might set animated properties, which requires an up-to-date start time.
-            for callback in callbacks_to_invoke_per_iteration.take().into_iter() {
-                callback();
-            }
+                // Only after updating the animation tick, invoke callbacks from invoke_from_event_loop(). They
+                // might set animated properties, which requires an up-to-date start time.
+                for callback in callbacks_to_invoke_per_iteration.take().into_iter() {
+                    callback();
+                }
 
-            if let Some(adapter) = self.window.borrow().as_ref() {
-                adapter.register_event_loop(event_loop.handle())?;
-                adapter.clone().render_if_needed(mouse_position_property.as_ref())?;
+                for adapter in self.windows.borrow().clone() {
+                    adapter.register_event_loop(event_loop.handle())?;
+                    adapter.render_if_needed()?;
+                }
+
+                let next_timeout = i_slint_core::platform::duration_until_next_timer_update();
+                event_loop
+                    .dispatch(next_timeout, &mut loop_data)
+                    .map_err(|e| format!("Error dispatch events: {e}"))?;
+                Ok(())
             };
 
-            let next_timeout = i_slint_core::platform::duration_until_next_timer_update();
-            event_loop
-                .dispatch(next_timeout, &mut loop_data)
-                .map_err(|e| format!("Error dispatch events: {e}"))?;
+            if showing_error_screen || !i_slint_core::platform::has_error_screen() {
+                iterate()?;
+                continue;
+            }
+
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(iterate)) {
+                Ok(result) => result?,
+                Err(payload) => {
+                    showing_error_screen = true;
+                    self.show_error_screen_after_panic(payload)?;
+                }
+            }
         }
 
         Ok(())
@@ -287,6 +348,14 @@ fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option
             _ => None,
         }
     }
+    fn set_backlight(&self, percent: f32) -> Result<(), PlatformError> {
+        let mut sysfs_backlight = self.backlight.borrow_mut();
+        if sysfs_backlight.is_none() {
+            *sysfs_backlight = Some(backlight::SysfsBacklight::new()?);
+        }
+        sysfs_backlight.as_ref().unwrap().set(percent)
+    }
+
     fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clipboard) {
         match clipboard {
             i_slint_core::platform::Clipboard::DefaultClipboard => {
@@ -298,6 +367,15 @@ fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clip
             _ => (),
         }
     }
+
+    fn capabilities(&self) -> i_slint_core::platform::Capabilities {
+        let mut capabilities = i_slint_core::platform::Capabilities::default();
+        // The clipboard is only shared within the application
+        capabilities.clipboard = true;
+        // Each window is shown fullscreen on its own output
+        capabilities.multiple_windows = true;
+        capabilities
+    }
 }
 
 #[derive(Default)]
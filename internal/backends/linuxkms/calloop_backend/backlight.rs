@@ -0,0 +1,46 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the code to control the backlight through sysfs, as described in
+//! https://www.kernel.org/doc/html/latest/gpu/backlight.html
+
+use std::path::{Path, PathBuf};
+
+use i_slint_core::platform::PlatformError;
+
+const BACKLIGHT_CLASS_PATH: &str = "/sys/class/backlight";
+
+pub struct SysfsBacklight {
+    path: PathBuf,
+    max_brightness: u32,
+}
+
+impl SysfsBacklight {
+    /// Opens the backlight device selected with the `SLINT_BACKLIGHT` environment variable,
+    /// or the first one found.
+    pub fn new() -> Result<Self, PlatformError> {
+        let path = if let Ok(name) = std::env::var("SLINT_BACKLIGHT") {
+            Path::new(BACKLIGHT_CLASS_PATH).join(name)
+        } else {
+            std::fs::read_dir(BACKLIGHT_CLASS_PATH)
+                .ok()
+                .and_then(|mut entries| entries.find_map(|entry| entry.ok()))
+                .map(|entry| entry.path())
+                .ok_or_else(|| format!("No backlight device found in {BACKLIGHT_CLASS_PATH}"))?
+        };
+
+        let max_brightness = std::fs::read_to_string(path.join("max_brightness"))
+            .map_err(|e| format!("Error reading maximum brightness of {}: {e}", path.display()))?
+            .trim()
+            .parse()
+            .map_err(|e| format!("Invalid maximum brightness of {}: {e}", path.display()))?;
+
+        Ok(Self { path, max_brightness })
+    }
+
+    pub fn set(&self, percent: f32) -> Result<(), PlatformError> {
+        let brightness = (self.max_brightness as f32 * percent / 100.).round() as u32;
+        std::fs::write(self.path.join("brightness"), brightness.to_string())
+            .map_err(|e| format!("Error setting brightness of {}: {e}", self.path.display()).into())
+    }
+}
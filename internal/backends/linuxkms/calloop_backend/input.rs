@@ -4,7 +4,6 @@
 //! This module contains the code to receive input events from libinput
 
 use std::cell::RefCell;
-#[cfg(feature = "libseat")]
 use std::collections::HashMap;
 #[cfg(not(feature = "libseat"))]
 use std::fs::{File, OpenOptions};
@@ -13,18 +12,22 @@
 use std::os::fd::{AsFd, AsRawFd, FromRawFd, IntoRawFd, RawFd};
 #[cfg(not(feature = "libseat"))]
 use std::os::unix::fs::OpenOptionsExt;
-use std::path::Path;
-use std::pin::Pin;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use i_slint_core::api::LogicalPosition;
-use i_slint_core::platform::{PlatformError, PointerEventButton, WindowEvent};
-use i_slint_core::window::WindowAdapter;
-use i_slint_core::{Property, SharedString};
+use i_slint_core::items::InteractionMode;
+use i_slint_core::platform::{
+    PlatformError, PointerDetails, PointerEventButton, PointerEventDevice, WindowEvent,
+};
+use i_slint_core::window::{WindowAdapter, WindowInner};
+use i_slint_core::SharedString;
 use input::LibinputInterface;
 
 use input::event::keyboard::{KeyState, KeyboardEventTrait};
-use input::event::touch::TouchEventPosition;
+use input::event::tablet_tool::{ProximityState, TabletToolEventTrait, TabletToolType, TipState};
+use input::event::touch::{TouchEventPosition, TouchEventSlot};
+use input::event::EventTrait;
 use xkbcommon::*;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
@@ -81,9 +84,9 @@ struct DirectDeviceAccess {}
 
 #[cfg(not(feature = "libseat"))]
 impl DirectDeviceAccess {
-    pub fn new() -> input::Libinput {
+    pub fn new(seat_name: &str) -> input::Libinput {
         let mut libinput = input::Libinput::new_with_udev(Self {});
-        libinput.udev_assign_seat("seat0").unwrap();
+        libinput.udev_assign_seat(seat_name).unwrap();
         libinput
     }
 }
@@ -111,42 +114,124 @@ fn close_restricted(&mut self, fd: OwnedFd) {
     }
 }
 
+/// Maps input devices to the windows they control, as configured with the `SLINT_INPUT_ROUTING`
+/// environment variable.
+///
+/// The variable is a comma separated list of `<device>=<window index>` rules. `<device>` is either
+/// the path of an input device node, such as `/dev/input/event3` or a link in `/dev/input/by-path/`,
+/// or the name of a udev seat, such as `seat1`. Windows are numbered in the order they're created
+/// in, which is also the order in which they take an output from `SLINT_DRM_OUTPUT`. Devices that
+/// don't match any rule control the first window.
+#[derive(Default)]
+struct InputRouting {
+    devices: Vec<(PathBuf, usize)>,
+    seats: Vec<(String, usize)>,
+}
+
+impl InputRouting {
+    fn from_env() -> Result<Self, PlatformError> {
+        let mut routing = Self::default();
+        let Ok(rules) = std::env::var("SLINT_INPUT_ROUTING") else {
+            return Ok(routing);
+        };
+        for rule in rules.split(',').map(str::trim).filter(|rule| !rule.is_empty()) {
+            let (device, window_index) = rule.split_once('=').ok_or_else(|| {
+                format!(
+                    "Invalid SLINT_INPUT_ROUTING rule '{rule}', expected <device>=<window index>"
+                )
+            })?;
+            let window_index = window_index.trim().parse().map_err(|_| {
+                format!("Invalid window index in SLINT_INPUT_ROUTING rule '{rule}'")
+            })?;
+            let device = device.trim();
+            if device.starts_with('/') {
+                let path = std::fs::canonicalize(device).unwrap_or_else(|_| device.into());
+                routing.devices.push((path, window_index));
+            } else {
+                routing.seats.push((device.into(), window_index));
+            }
+        }
+        Ok(routing)
+    }
+
+    fn seats(&self) -> impl Iterator<Item = &str> {
+        self.seats.iter().map(|(seat, _)| seat.as_str())
+    }
+
+    fn window_index(&self, device: &input::Device) -> usize {
+        let sysname = device.sysname();
+        if let Some((_, window_index)) =
+            self.devices.iter().find(|(path, _)| path.file_name().map_or(false, |n| n == sysname))
+        {
+            return *window_index;
+        }
+        let seat = device.seat();
+        let seat_name = seat.physical_name();
+        self.seats.iter().find(|(seat, _)| seat == seat_name).map_or(0, |(_, index)| *index)
+    }
+}
+
+/// The input state that is per window, as each window may be controlled by different devices.
+#[derive(Default)]
+struct WindowInputState {
+    /// The last position of the fingers on the touch screen, by seat slot
+    touch_positions: HashMap<u64, LogicalPosition>,
+    keystate: Option<xkb::State>,
+}
+
 pub struct LibInputHandler<'a> {
     libinput: input::Libinput,
     token: Option<calloop::Token>,
-    mouse_pos: Pin<Rc<Property<Option<LogicalPosition>>>>,
-    last_touch_pos: LogicalPosition,
-    window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
-    keystate: Option<xkb::State>,
+    windows: &'a RefCell<Vec<Rc<FullscreenWindowAdapter>>>,
+    routing: Rc<InputRouting>,
+    window_input_states: HashMap<usize, WindowInputState>,
 }
 
 impl<'a> LibInputHandler<'a> {
     pub fn init<T>(
-        window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
+        windows: &'a RefCell<Vec<Rc<FullscreenWindowAdapter>>>,
         event_loop_handle: &calloop::LoopHandle<'a, T>,
         #[cfg(feature = "libseat")] seat: &'a Rc<RefCell<libseat::Seat>>,
-    ) -> Result<Pin<Rc<Property<Option<LogicalPosition>>>>, PlatformError> {
+    ) -> Result<(), PlatformError> {
+        let routing = Rc::new(InputRouting::from_env()?);
+
+        // One libinput context per udev seat
         #[cfg(feature = "libseat")]
-        let libinput = SeatWrap::new(seat);
+        let libinputs = {
+            let session_seat = seat.borrow_mut().name().to_string();
+            for other_seat in routing.seats().filter(|name| *name != session_seat) {
+                eprintln!(
+                    "slint linuxkms backend: ignoring input routing for seat '{other_seat}', only devices of the session seat '{session_seat}' are accessible with libseat"
+                );
+            }
+            vec![SeatWrap::new(seat)]
+        };
         #[cfg(not(feature = "libseat"))]
-        let libinput = DirectDeviceAccess::new();
-
-        let mouse_pos_property = Rc::pin(Property::new(None));
-
-        let handler = Self {
-            libinput,
-            token: Default::default(),
-            mouse_pos: mouse_pos_property.clone(),
-            last_touch_pos: Default::default(),
-            window,
-            keystate: Default::default(),
+        let libinputs = {
+            let mut seats = vec!["seat0"];
+            for seat in routing.seats() {
+                if !seats.contains(&seat) {
+                    seats.push(seat);
+                }
+            }
+            seats.into_iter().map(DirectDeviceAccess::new).collect::<Vec<_>>()
         };
 
-        event_loop_handle
-            .insert_source(handler, move |_, _, _| {})
-            .map_err(|e| format!("Error registering libinput event source: {e}"))?;
+        for libinput in libinputs {
+            let handler = Self {
+                libinput,
+                token: Default::default(),
+                windows,
+                routing: routing.clone(),
+                window_input_states: Default::default(),
+            };
+
+            event_loop_handle
+                .insert_source(handler, move |_, _, _| {})
+                .map_err(|e| format!("Error registering libinput event source: {e}"))?;
+        }
 
-        Ok(mouse_pos_property)
+        Ok(())
     }
 }
 
@@ -171,27 +256,33 @@ fn process_events<F>(
 
         self.libinput.dispatch()?;
 
-        let Some(adapter) = self.window.borrow().clone() else {
-            return Ok(calloop::PostAction::Continue);
-        };
-        let window = adapter.window();
-        let screen_size = window.size().to_logical(window.scale_factor());
+        // Clone, as dispatching events may create new windows
+        let windows = self.windows.borrow().clone();
 
         for event in &mut self.libinput {
+            let window_index = self.routing.window_index(&event.device());
+            let Some(adapter) = windows.get(window_index) else {
+                continue;
+            };
+            let window = adapter.window();
+            let screen_size = window.size().to_logical(window.scale_factor());
+            let mouse_position = adapter.mouse_position();
+            let input_state = self.window_input_states.entry(window_index).or_default();
+
             match event {
                 input::Event::Pointer(pointer_event) => {
+                    WindowInner::from_pub(window).input_device_used(InteractionMode::Pointer);
                     match pointer_event {
                         input::event::PointerEvent::Motion(motion_event) => {
-                            let mut mouse_pos =
-                                self.mouse_pos.as_ref().get().unwrap_or(LogicalPosition {
-                                    x: screen_size.width / 2.,
-                                    y: screen_size.height / 2.,
-                                });
+                            let mut mouse_pos = mouse_position.get().unwrap_or(LogicalPosition {
+                                x: screen_size.width / 2.,
+                                y: screen_size.height / 2.,
+                            });
                             mouse_pos.x = (mouse_pos.x + motion_event.dx() as f32)
                                 .clamp(0., screen_size.width);
                             mouse_pos.y = (mouse_pos.y + motion_event.dy() as f32)
                                 .clamp(0., screen_size.height);
-                            self.mouse_pos.set(Some(mouse_pos));
+                            mouse_position.set(Some(mouse_pos));
                             let event = WindowEvent::PointerMoved { position: mouse_pos };
                             window.dispatch_event(event);
                         }
@@ -203,7 +294,7 @@ fn process_events<F>(
                                     .absolute_y_transformed(screen_size.height as u32)
                                     as _,
                             };
-                            self.mouse_pos.set(Some(mouse_pos));
+                            mouse_position.set(Some(mouse_pos));
                             let event = WindowEvent::PointerMoved { position: mouse_pos };
                             window.dispatch_event(event);
                         }
@@ -217,7 +308,7 @@ fn process_events<F>(
                                 0x115 => PointerEventButton::Forward,
                                 _ => PointerEventButton::Other,
                             };
-                            let mouse_pos = self.mouse_pos.as_ref().get().unwrap_or_default();
+                            let mouse_pos = mouse_position.get().unwrap_or_default();
                             let event = match button_event.button_state() {
                                 input::event::tablet_pad::ButtonState::Pressed => {
                                     WindowEvent::PointerPressed { position: mouse_pos, button }
@@ -235,39 +326,114 @@ fn process_events<F>(
                     }
                 }
                 input::Event::Touch(touch_event) => {
-                    if let Some(event) = match touch_event {
+                    // Also hides the software cursor
+                    WindowInner::from_pub(window).input_device_used(InteractionMode::Touch);
+                    let event = match touch_event {
                         input::event::TouchEvent::Down(touch_down_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let touch_id = touch_down_event.seat_slot() as u64;
+                            let position = LogicalPosition::new(
                                 touch_down_event.x_transformed(screen_size.width as u32) as _,
                                 touch_down_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerPressed {
-                                position: self.last_touch_pos,
-                                button: PointerEventButton::Left,
-                            })
+                            input_state.touch_positions.insert(touch_id, position);
+                            Some(WindowEvent::TouchPressed { touch_id, position })
+                        }
+                        input::event::TouchEvent::Up(touch_up_event) => {
+                            let touch_id = touch_up_event.seat_slot() as u64;
+                            input_state
+                                .touch_positions
+                                .remove(&touch_id)
+                                .map(|position| WindowEvent::TouchReleased { touch_id, position })
                         }
-                        input::event::TouchEvent::Up(..) => Some(WindowEvent::PointerReleased {
-                            position: self.last_touch_pos,
-                            button: PointerEventButton::Left,
-                        }),
                         input::event::TouchEvent::Motion(touch_motion_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let touch_id = touch_motion_event.seat_slot() as u64;
+                            let position = LogicalPosition::new(
                                 touch_motion_event.x_transformed(screen_size.width as u32) as _,
                                 touch_motion_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerMoved { position: self.last_touch_pos })
+                            input_state.touch_positions.insert(touch_id, position);
+                            Some(WindowEvent::TouchMoved { touch_id, position })
+                        }
+                        input::event::TouchEvent::Cancel(..) => {
+                            // Release all the fingers
+                            for (touch_id, position) in input_state.touch_positions.drain() {
+                                window.dispatch_event(WindowEvent::TouchReleased {
+                                    touch_id,
+                                    position,
+                                });
+                            }
+                            None
                         }
                         _ => None,
-                    } {
+                    };
+                    if let Some(event) = event {
                         window.dispatch_event(event);
                     }
                 }
+                input::Event::Tablet(tablet_event) => {
+                    WindowInner::from_pub(window).input_device_used(InteractionMode::Pointer);
+                    let tool = tablet_event.tool();
+                    let mut details = PointerDetails::new(match tool.tool_type() {
+                        Some(TabletToolType::Eraser) => PointerEventDevice::Eraser,
+                        Some(TabletToolType::Mouse | TabletToolType::Lens) => {
+                            PointerEventDevice::Mouse
+                        }
+                        _ => PointerEventDevice::Pen,
+                    })
+                    .with_tilt(tablet_event.tilt_x() as f32, tablet_event.tilt_y() as f32);
+                    if tool.has_pressure() {
+                        details = details.with_pressure(tablet_event.pressure() as f32);
+                    }
+                    window.dispatch_event(WindowEvent::PointerDetailsChanged { details });
+
+                    let position = LogicalPosition::new(
+                        tablet_event.x_transformed(screen_size.width as u32) as _,
+                        tablet_event.y_transformed(screen_size.height as u32) as _,
+                    );
+                    mouse_position.set(Some(position));
+                    let event = match tablet_event {
+                        input::event::TabletToolEvent::Tip(tip_event) => {
+                            let button = PointerEventButton::Left;
+                            match tip_event.tip_state() {
+                                TipState::Down => WindowEvent::PointerPressed { position, button },
+                                TipState::Up => WindowEvent::PointerReleased { position, button },
+                            }
+                        }
+                        input::event::TabletToolEvent::Button(button_event) => {
+                            // https://github.com/torvalds/linux/blob/0dd2a6fb1e34d6dcb96806bc6b111388ad324722/include/uapi/linux/input-event-codes.h#L412
+                            let button = match button_event.button() {
+                                0x14b => PointerEventButton::Right,
+                                0x14c => PointerEventButton::Middle,
+                                _ => PointerEventButton::Other,
+                            };
+                            match button_event.button_state() {
+                                input::event::tablet_pad::ButtonState::Pressed => {
+                                    WindowEvent::PointerPressed { position, button }
+                                }
+                                input::event::tablet_pad::ButtonState::Released => {
+                                    WindowEvent::PointerReleased { position, button }
+                                }
+                            }
+                        }
+                        input::event::TabletToolEvent::Proximity(proximity_event)
+                            if matches!(proximity_event.proximity_state(), ProximityState::Out) =>
+                        {
+                            // The pen left the tablet, the next events come from the mouse
+                            window.dispatch_event(WindowEvent::PointerDetailsChanged {
+                                details: PointerDetails::default(),
+                            });
+                            WindowEvent::PointerMoved { position }
+                        }
+                        _ => WindowEvent::PointerMoved { position },
+                    };
+                    window.dispatch_event(event);
+                }
                 input::Event::Keyboard(input::event::KeyboardEvent::Key(key_event)) => {
                     // On Linux key codes have a fixed offset of 8: https://docs.rs/xkbcommon/0.6.0/xkbcommon/xkb/struct.Keycode.html
                     let key_code = xkb::Keycode::new(key_event.key() + 8);
                     let state = key_event.key_state();
 
-                    let xkb_key_state = self.keystate.get_or_insert_with(|| {
+                    let xkb_key_state = input_state.keystate.get_or_insert_with(|| {
                         let xkb_context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
                         let keymap =
                             xkb::Keymap::new_from_names(&xkb_context, "", "", "", "", None, 0)
@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::os::fd::{AsFd, BorrowedFd, OwnedFd};
-use std::rc::Rc;
+use std::path::PathBuf;
+use std::rc::{Rc, Weak};
 
 use crate::DeviceOpener;
 use drm::buffer::Buffer;
@@ -29,20 +31,69 @@ enum PageFlipState {
     NoFrameBufferPosted,
     InitialBufferPosted,
     WaitingForPageFlip {
-        _buffer_to_keep_alive_until_flip: Box<dyn Buffer>,
+        _buffer_to_keep_alive_until_flip: Option<Box<dyn Buffer>>,
         ready_for_next_animation_frame: Box<dyn FnOnce()>,
     },
     ReadyForNextBuffer,
 }
 
+/// A DRM device that may drive several outputs, one per window.
+struct DrmDevice {
+    fd: SharedFd,
+    /// The page flip state of each output, by crtc. The page flip events of all outputs are
+    /// received through the same file descriptor.
+    page_flip_states: RefCell<HashMap<drm::control::crtc::Handle, Rc<RefCell<PageFlipState>>>>,
+    claimed_connectors: RefCell<HashSet<drm::control::connector::Handle>>,
+    page_flip_event_source_registered: Cell<bool>,
+}
+
+thread_local! {
+    static OPEN_DRM_DEVICES: RefCell<HashMap<PathBuf, Weak<DrmDevice>>> = Default::default();
+}
+
+impl DrmDevice {
+    /// Returns the already opened device for the path, or opens it. A DRM device can only be
+    /// opened once as master, so all outputs of the same device share one file descriptor.
+    fn open(
+        device_opener: &DeviceOpener,
+        path: &std::path::Path,
+    ) -> Result<Rc<Self>, PlatformError> {
+        if let Some(device) =
+            OPEN_DRM_DEVICES.with(|devices| devices.borrow().get(path).and_then(Weak::upgrade))
+        {
+            return Ok(device);
+        }
+        let device = Rc::new(Self {
+            fd: SharedFd(device_opener(path)?),
+            page_flip_states: Default::default(),
+            claimed_connectors: Default::default(),
+            page_flip_event_source_registered: Cell::new(false),
+        });
+        OPEN_DRM_DEVICES.with(|devices| {
+            devices.borrow_mut().insert(path.to_path_buf(), Rc::downgrade(&device))
+        });
+        Ok(device)
+    }
+}
+
 pub struct DrmOutput {
     pub drm_device: SharedFd,
+    device: Rc<DrmDevice>,
     connector: drm::control::connector::Info,
     mode: drm::control::Mode,
     crtc: drm::control::crtc::Handle,
     last_buffer: Cell<Option<Box<dyn Buffer>>>,
     page_flip_state: Rc<RefCell<PageFlipState>>,
-    page_flip_event_source_registered: Cell<bool>,
+    /// True if the crtc is still showing the framebuffer of a previous client (such as a boot splash)
+    /// in the mode we use, so that the first frame can be page flipped instead of setting the mode.
+    adopt_scanout: Cell<bool>,
+}
+
+impl Drop for DrmOutput {
+    fn drop(&mut self) {
+        self.device.page_flip_states.borrow_mut().remove(&self.crtc);
+        self.device.claimed_connectors.borrow_mut().remove(&self.connector.handle());
+    }
 }
 
 impl DrmOutput {
@@ -65,7 +116,11 @@ fn new_with_path(
         device_opener: &DeviceOpener,
         device: &std::path::Path,
     ) -> Result<Self, PlatformError> {
-        let drm_device = SharedFd(device_opener(device)?);
+        let device = DrmDevice::open(device_opener, device)?;
+        let drm_device = device.fd.clone();
+        let is_claimed = |connector: &drm::control::connector::Info| {
+            device.claimed_connectors.borrow().contains(&connector.handle())
+        };
 
         let resources = drm_device
             .resource_handles()
@@ -88,20 +143,43 @@ fn new_with_path(
                 eprintln!("\nDRM Output List Requested:\n{}\nPlease select an output with the SLINT_DRM_OUTPUT environment variable and re-run the program.", names_and_status.join("\n"));
                 std::process::exit(1);
             } else {
-                let (_, connector, connected) =
-                    connectors.find(|(name, _, _)| name == &requested_connector_name).ok_or_else(
-                        || format!("No output with the name '{}' found", requested_connector_name),
-                    )?;
-
-                if !connected {
-                    return Err(format!(
-                        "Requested output '{}' is not connected",
-                        requested_connector_name
-                    )
-                    .into());
-                };
+                // With several windows, each window takes the first requested output that is not
+                // used by another window yet.
+                let connectors = connectors.collect::<Vec<_>>();
+                let mut last_err = None;
+                let mut found = None;
+                for requested_connector_name in requested_connector_name.split(',').map(str::trim) {
+                    let Some((_, connector, connected)) =
+                        connectors.iter().find(|(name, _, _)| name == requested_connector_name)
+                    else {
+                        last_err = Some(format!(
+                            "No output with the name '{}' found",
+                            requested_connector_name
+                        ));
+                        continue;
+                    };
+
+                    if !connected {
+                        last_err = Some(format!(
+                            "Requested output '{}' is not connected",
+                            requested_connector_name
+                        ));
+                        continue;
+                    };
+
+                    if is_claimed(connector) {
+                        last_err = Some(format!(
+                            "Requested output '{}' is already used by another window",
+                            requested_connector_name
+                        ));
+                        continue;
+                    }
 
-                connector
+                    found = Some(connector.clone());
+                    break;
+                }
+
+                found.ok_or_else(|| last_err.unwrap_or_default())?
             }
         } else {
             resources
@@ -109,14 +187,60 @@ fn new_with_path(
                 .iter()
                 .find_map(|handle| {
                     let connector = drm_device.get_connector(*handle, false).ok()?;
-                    (connector.state() == drm::control::connector::State::Connected)
-                        .then(|| connector)
+                    (connector.state() == drm::control::connector::State::Connected
+                        && !is_claimed(&connector))
+                    .then(|| connector)
                 })
                 .ok_or_else(|| format!("No connected display connector found"))?
         };
 
+        let encoder = connector
+            .current_encoder()
+            .filter(|current| connector.encoders().iter().any(|h| *h == *current))
+            .and_then(|current| drm_device.get_encoder(current).ok());
+
+        let is_crtc_claimed =
+            |crtc: &drm::control::crtc::Handle| device.page_flip_states.borrow().contains_key(crtc);
+
+        let current_crtc =
+            encoder.as_ref().and_then(|encoder| encoder.crtc()).filter(|c| !is_crtc_claimed(c));
+
+        let crtc = if let Some(crtc) = current_crtc {
+            crtc
+        } else if encoder.as_ref().map_or(false, |encoder| encoder.crtc().is_none()) {
+            return Err(format!("no crtc for encoder").into());
+        } else {
+            // No crtc found for current encoder, or it's driving another output? Pick the first possible crtc
+            // as described in https://manpages.debian.org/testing/libdrm-dev/drm-kms.7.en.html#CRTC/Encoder_Selection
+            connector
+                .encoders()
+                .iter()
+                .filter_map(|handle| drm_device.get_encoder(*handle).ok())
+                .flat_map(|encoder| resources.filter_crtcs(encoder.possible_crtcs()))
+                .find(|crtc_handle| {
+                    !is_crtc_claimed(crtc_handle) && drm_device.get_crtc(*crtc_handle).is_ok()
+                })
+                .ok_or_else(|| {
+                    format!(
+                        "Could not find any crtc for any encoder connected to output {}-{}",
+                        connector.interface().as_str(),
+                        connector.interface_id()
+                    )
+                })?
+        };
+
+        // When the crtc is already scanning out to this connector, for example to show a boot splash,
+        // keep its mode to take over the display without a modeset.
+        let inherited_mode = current_crtc
+            .and_then(|crtc| drm_device.get_crtc(crtc).ok())
+            .filter(|crtc_info| crtc_info.framebuffer().is_some())
+            .and_then(|crtc_info| crtc_info.mode());
+
         let mode = std::env::var("SLINT_DRM_MODE").map_or_else(
             |_| {
+                if let Some(inherited_mode) = inherited_mode {
+                    return Ok(inherited_mode);
+                }
                 connector
                     .modes()
                     .iter()
@@ -164,41 +288,25 @@ fn new_with_path(
             },
         )?;
 
-        let encoder = connector
-            .current_encoder()
-            .filter(|current| connector.encoders().iter().any(|h| *h == *current))
-            .and_then(|current| drm_device.get_encoder(current).ok());
+        //eprintln!("mode {}/{}", width, height);
 
-        let crtc = if let Some(encoder) = encoder {
-            encoder.crtc().ok_or_else(|| format!("no crtc for encoder"))?
-        } else {
-            // No crtc found for current encoder? Pick the first possible crtc
-            // as described in https://manpages.debian.org/testing/libdrm-dev/drm-kms.7.en.html#CRTC/Encoder_Selection
-            connector
-                .encoders()
-                .iter()
-                .filter_map(|handle| drm_device.get_encoder(*handle).ok())
-                .flat_map(|encoder| resources.filter_crtcs(encoder.possible_crtcs()))
-                .find(|crtc_handle| drm_device.get_crtc(*crtc_handle).is_ok())
-                .ok_or_else(|| {
-                    format!(
-                        "Could not find any crtc for any encoder connected to output {}-{}",
-                        connector.interface().as_str(),
-                        connector.interface_id()
-                    )
-                })?
-        };
+        let adopt_scanout = inherited_mode.map_or(false, |inherited_mode| {
+            inherited_mode.size() == mode.size() && inherited_mode.vrefresh() == mode.vrefresh()
+        });
 
-        //eprintln!("mode {}/{}", width, height);
+        let page_flip_state = Rc::new(RefCell::new(PageFlipState::default()));
+        device.page_flip_states.borrow_mut().insert(crtc, page_flip_state.clone());
+        device.claimed_connectors.borrow_mut().insert(connector.handle());
 
         Ok(Self {
             drm_device,
+            device,
             connector,
             mode,
             crtc,
             last_buffer: Cell::default(),
-            page_flip_state: Default::default(),
-            page_flip_event_source_registered: Cell::new(false),
+            page_flip_state,
+            adopt_scanout: Cell::new(adopt_scanout),
         })
     }
 
@@ -208,43 +316,68 @@ pub fn present(
         framebuffer_handle: drm::control::framebuffer::Handle,
         ready_for_next_animation_frame: Box<dyn FnOnce()>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        if let Some(last_buffer) = self.last_buffer.replace(Some(Box::new(front_buffer))) {
-            self.drm_device
-                .page_flip(self.crtc, framebuffer_handle, drm::control::PageFlipFlags::EVENT, None)
-                .map_err(|e| format!("Error presenting framebuffer on screen: {e}"))?;
-
-            *self.page_flip_state.borrow_mut() = PageFlipState::WaitingForPageFlip {
-                _buffer_to_keep_alive_until_flip: last_buffer,
-                ready_for_next_animation_frame,
-            };
+        let last_buffer = self.last_buffer.replace(Some(Box::new(front_buffer)));
+        // The first frame replaces the framebuffer of the boot splash with a page flip, so that
+        // there's no black frame in between.
+        let adopt_scanout = last_buffer.is_none() && self.adopt_scanout.take();
+        if last_buffer.is_some() || adopt_scanout {
+            match self.drm_device.page_flip(
+                self.crtc,
+                framebuffer_handle,
+                drm::control::PageFlipFlags::EVENT,
+                None,
+            ) {
+                Ok(()) => {
+                    *self.page_flip_state.borrow_mut() = PageFlipState::WaitingForPageFlip {
+                        _buffer_to_keep_alive_until_flip: last_buffer,
+                        ready_for_next_animation_frame,
+                    };
+                }
+                // The framebuffer may not be compatible with the one of the splash, set the mode instead.
+                Err(_) if adopt_scanout => {
+                    self.set_mode_and_present(framebuffer_handle, ready_for_next_animation_frame)?;
+                }
+                Err(e) => return Err(format!("Error presenting framebuffer on screen: {e}").into()),
+            }
         } else {
-            self.drm_device
-                .set_crtc(
-                    self.crtc,
-                    Some(framebuffer_handle),
-                    (0, 0),
-                    &[self.connector.handle()],
-                    Some(self.mode),
-                )
-                .map_err(|e| format!("Error presenting framebuffer on screen: {e}"))?;
-            *self.page_flip_state.borrow_mut() = PageFlipState::InitialBufferPosted;
-
-            // We can render the next frame right away, if needed, since we have at least two buffers. The callback
-            // will decide (will check if animation is running). However invoke the callback through the event loop
-            // instead of directly, so that if it decides to set `needs_redraw` to true, the event loop will process it.
-            i_slint_core::timers::Timer::single_shot(std::time::Duration::default(), move || {
-                ready_for_next_animation_frame();
-            })
+            self.set_mode_and_present(framebuffer_handle, ready_for_next_animation_frame)?;
         }
 
         Ok(())
     }
 
+    /// Presents the first framebuffer by setting the mode of the crtc.
+    fn set_mode_and_present(
+        &self,
+        framebuffer_handle: drm::control::framebuffer::Handle,
+        ready_for_next_animation_frame: Box<dyn FnOnce()>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.drm_device
+            .set_crtc(
+                self.crtc,
+                Some(framebuffer_handle),
+                (0, 0),
+                &[self.connector.handle()],
+                Some(self.mode),
+            )
+            .map_err(|e| format!("Error presenting framebuffer on screen: {e}"))?;
+        *self.page_flip_state.borrow_mut() = PageFlipState::InitialBufferPosted;
+
+        // We can render the next frame right away, if needed, since we have at least two buffers. The callback
+        // will decide (will check if animation is running). However invoke the callback through the event loop
+        // instead of directly, so that if it decides to set `needs_redraw` to true, the event loop will process it.
+        i_slint_core::timers::Timer::single_shot(std::time::Duration::default(), move || {
+            ready_for_next_animation_frame();
+        });
+
+        Ok(())
+    }
+
     pub fn register_page_flip_handler(
         &self,
         event_loop_handle: crate::calloop_backend::EventLoopHandle,
     ) -> Result<(), PlatformError> {
-        if self.page_flip_event_source_registered.replace(true) {
+        if self.device.page_flip_event_source_registered.replace(true) {
             return Ok(());
         }
 
@@ -256,14 +389,21 @@ pub fn register_page_flip_handler(
 
         event_loop_handle
             .insert_source(source, {
-                let drm_device = self.drm_device.clone();
-                let page_flip_state = self.page_flip_state.clone();
+                let device = Rc::downgrade(&self.device);
 
                 move |_, _, _| {
-                    if drm_device
-                        .receive_events()?
-                        .any(|event| matches!(event, drm::control::Event::PageFlip(..)))
-                    {
+                    let Some(device) = device.upgrade() else {
+                        return Ok(calloop::PostAction::Remove);
+                    };
+                    for event in device.fd.receive_events()? {
+                        let drm::control::Event::PageFlip(page_flip_event) = event else {
+                            continue;
+                        };
+                        let Some(page_flip_state) =
+                            device.page_flip_states.borrow().get(&page_flip_event.crtc).cloned()
+                        else {
+                            continue;
+                        };
                         if let PageFlipState::WaitingForPageFlip {
                             ready_for_next_animation_frame,
                             ..
@@ -8,11 +8,11 @@
 use std::rc::Rc;
 
 use i_slint_core::api::{LogicalPosition, PhysicalSize as PhysicalWindowSize};
-use i_slint_core::graphics::{euclid, Image};
-use i_slint_core::item_rendering::ItemRenderer;
-use i_slint_core::lengths::LogicalRect;
-use i_slint_core::platform::WindowEvent;
+use i_slint_core::graphics::Image;
+use i_slint_core::items::MouseCursor;
+use i_slint_core::platform::{SoftwareCursor, WindowEvent};
 use i_slint_core::slice::Slice;
+use i_slint_core::window::WindowAdapterInternal;
 use i_slint_core::Property;
 use i_slint_core::{platform::PlatformError, window::WindowAdapter};
 
@@ -24,7 +24,6 @@ pub trait FullscreenRenderer {
     fn render_and_present(
         &self,
         rotation: RenderingRotation,
-        draw_mouse_cursor_callback: &dyn Fn(&mut dyn ItemRenderer),
         ready_for_next_animation_frame: Box<dyn FnOnce()>,
     ) -> Result<(), PlatformError>;
     fn size(&self) -> PhysicalWindowSize;
@@ -40,6 +39,10 @@ pub struct FullscreenWindowAdapter {
     redraw_requested: Cell<bool>,
     needs_redraw_after_present: Cell<bool>,
     rotation: RenderingRotation,
+    /// The position of the mouse, if a mouse is routed to the window.
+    mouse_position: Pin<Box<Property<Option<LogicalPosition>>>>,
+    /// The cursor that Slint draws when no custom cursor is set
+    default_cursor: SoftwareCursor,
 }
 
 impl WindowAdapter for FullscreenWindowAdapter {
@@ -69,6 +72,23 @@ fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
         }
         Ok(())
     }
+
+    fn internal(&self, _: i_slint_core::InternalToken) -> Option<&dyn WindowAdapterInternal> {
+        Some(self)
+    }
+}
+
+impl WindowAdapterInternal for FullscreenWindowAdapter {
+    fn set_mouse_cursor(&self, cursor: MouseCursor) {
+        // The named cursors all look like the default one
+        self.window.set_software_cursor(
+            (cursor != MouseCursor::None).then(|| self.default_cursor.clone()),
+        );
+    }
+
+    fn set_custom_mouse_cursor(&self, image: &Image, hotspot: LogicalPosition) {
+        self.window.set_software_cursor(Some(SoftwareCursor { image: image.clone(), hotspot }));
+    }
 }
 
 impl FullscreenWindowAdapter {
@@ -88,48 +108,42 @@ pub fn new(
                 String::new()
             }
         );
-        Ok(Rc::<FullscreenWindowAdapter>::new_cyclic(|self_weak| FullscreenWindowAdapter {
-            window: i_slint_core::api::Window::new(self_weak.clone()),
-            renderer,
-            redraw_requested: Cell::new(true),
-            needs_redraw_after_present: Cell::new(false),
-            rotation,
-        }))
+        let adapter =
+            Rc::<FullscreenWindowAdapter>::new_cyclic(|self_weak| FullscreenWindowAdapter {
+                window: i_slint_core::api::Window::new(self_weak.clone()),
+                renderer,
+                redraw_requested: Cell::new(true),
+                needs_redraw_after_present: Cell::new(false),
+                rotation,
+                mouse_position: Box::pin(Property::new(None)),
+                default_cursor: SoftwareCursor {
+                    image: mouse_cursor_image(),
+                    hotspot: Default::default(),
+                },
+            });
+        // There is no system cursor, so let Slint draw one on top of the window contents
+        adapter.window.set_software_cursor(Some(adapter.default_cursor.clone()));
+        Ok(adapter)
     }
 
-    pub fn render_if_needed(
-        self: Rc<Self>,
-        mouse_position: Pin<&Property<Option<LogicalPosition>>>,
-    ) -> Result<(), PlatformError> {
+    pub fn mouse_position(&self) -> Pin<&Property<Option<LogicalPosition>>> {
+        self.mouse_position.as_ref()
+    }
+
+    pub fn render_if_needed(self: Rc<Self>) -> Result<(), PlatformError> {
         if !self.renderer.is_ready_to_present() {
             return Ok(());
         }
         if self.redraw_requested.replace(false) {
             self.renderer.render_and_present(
                 self.rotation,
-                &|item_renderer| {
-                    if let Some(mouse_position) = mouse_position.get() {
-                        let cursor_image = mouse_cursor_image();
-                        item_renderer.save_state();
-                        item_renderer.translate(
-                            i_slint_core::lengths::logical_point_from_api(mouse_position)
-                                .to_vector(),
-                        );
-                        item_renderer.draw_image_direct(mouse_cursor_image());
-                        item_renderer.restore_state();
-                        let cursor_rect = LogicalRect::new(
-                            euclid::point2(mouse_position.x, mouse_position.y),
-                            euclid::Size2D::from_untyped(cursor_image.size().cast()),
-                        );
-                        self.renderer.as_core_renderer().mark_dirty_region(cursor_rect.into());
-                    }
-                },
                 Box::new({
                     let self_weak = Rc::downgrade(&self);
                     move || {
                         let Some(this) = self_weak.upgrade() else {
                             return;
                         };
+                        this.window.dispatch_event(WindowEvent::FramePresented);
                         if this.needs_redraw_after_present.replace(false) {
                             this.request_redraw();
                         }
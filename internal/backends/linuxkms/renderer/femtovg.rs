@@ -3,7 +3,6 @@
 
 use std::{num::NonZeroU32, rc::Rc};
 
-use i_slint_core::item_rendering::ItemRenderer;
 use i_slint_core::platform::PlatformError;
 use i_slint_renderer_femtovg::FemtoVGRendererExt;
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
@@ -176,7 +175,6 @@ fn is_ready_to_present(&self) -> bool {
     fn render_and_present(
         &self,
         rotation: RenderingRotation,
-        draw_mouse_cursor_callback: &dyn Fn(&mut dyn ItemRenderer),
         ready_for_next_animation_frame: Box<dyn FnOnce()>,
     ) -> Result<(), PlatformError> {
         let size = self.size();
@@ -184,9 +182,7 @@ fn render_and_present(
             rotation.degrees(),
             rotation.translation_after_rotation(size),
             size,
-            Some(&|item_renderer| {
-                draw_mouse_cursor_callback(item_renderer);
-            }),
+            None,
         )?;
         self.gbm_display.present_with_next_frame_callback(ready_for_next_animation_frame)?;
         Ok(())
@@ -6,7 +6,6 @@
 use crate::display::RenderingRotation;
 use crate::drmoutput::DrmOutput;
 use i_slint_core::api::PhysicalSize as PhysicalWindowSize;
-use i_slint_core::item_rendering::ItemRenderer;
 use i_slint_core::platform::PlatformError;
 use i_slint_renderer_skia::skia_safe;
 use i_slint_renderer_skia::SkiaRendererExt;
@@ -138,16 +137,13 @@ fn is_ready_to_present(&self) -> bool {
     fn render_and_present(
         &self,
         rotation: RenderingRotation,
-        draw_mouse_cursor_callback: &dyn Fn(&mut dyn ItemRenderer),
         ready_for_next_animation_frame: Box<dyn FnOnce()>,
     ) -> Result<(), PlatformError> {
         self.renderer.render_transformed_with_post_callback(
             rotation.degrees(),
             rotation.translation_after_rotation(self.size),
             self.size,
-            Some(&|item_renderer| {
-                draw_mouse_cursor_callback(item_renderer);
-            }),
+            None,
         )?;
         self.presenter.present_with_next_frame_callback(ready_for_next_animation_frame)?;
         Ok(())
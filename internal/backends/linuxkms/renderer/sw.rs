@@ -97,7 +97,6 @@ fn is_ready_to_present(&self) -> bool {
     fn render_and_present(
         &self,
         rotation: RenderingRotation,
-        _draw_mouse_cursor_callback: &dyn Fn(&mut dyn i_slint_core::item_rendering::ItemRenderer),
         ready_for_next_animation_frame: Box<dyn FnOnce()>,
     ) -> Result<(), PlatformError> {
         self.display.map_back_buffer(&mut |pixels, age, format| {
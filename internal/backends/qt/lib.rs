@@ -138,6 +138,14 @@ pub fn new() -> Self {
             // before the first calls to QStyle.
             cpp! {unsafe[] {
                 ensure_initialized(true);
+                auto notify_screens_changed = [] {
+                    rust!(Slint_notify_screens_changed [] {
+                        i_slint_core::platform::notify_screens_changed();
+                    });
+                };
+                QObject::connect(qApp, &QGuiApplication::screenAdded, qApp, notify_screens_changed);
+                QObject::connect(qApp, &QGuiApplication::screenRemoved, qApp, notify_screens_changed);
+                QObject::connect(qApp, &QGuiApplication::primaryScreenChanged, qApp, notify_screens_changed);
             }}
         }
         Self {}
@@ -307,4 +315,92 @@ fn click_interval(&self) -> core::time::Duration {
         };
         core::time::Duration::from_millis(duration_ms as u64)
     }
+
+    #[cfg(not(no_qt))]
+    fn settings(&self) -> i_slint_core::platform::Settings {
+        let (cursor_flash_time_ms, wheel_scroll_lines, drag_threshold, long_press_ms) = unsafe {
+            (
+                cpp::cpp! {[] -> i32 as "int" { return qApp->cursorFlashTime(); }},
+                cpp::cpp! {[] -> i32 as "int" { return qApp->wheelScrollLines(); }},
+                cpp::cpp! {[] -> i32 as "int" { return qApp->startDragDistance(); }},
+                cpp::cpp! {[] -> i32 as "int" { return qApp->styleHints()->mousePressAndHoldInterval(); }},
+            )
+        };
+        let mut settings = i_slint_core::platform::Settings::default();
+        settings.click_interval = self.click_interval();
+        settings.cursor_flash_time =
+            core::time::Duration::from_millis(cursor_flash_time_ms.max(0) as u64);
+        settings.wheel_scroll_lines = wheel_scroll_lines.max(0) as u32;
+        settings.drag_threshold = drag_threshold as f32;
+        settings.long_press_duration =
+            core::time::Duration::from_millis(long_press_ms.max(0) as u64);
+        settings
+    }
+
+    #[cfg(not(no_qt))]
+    fn capabilities(&self) -> i_slint_core::platform::Capabilities {
+        let mut capabilities = i_slint_core::platform::Capabilities::default();
+        capabilities.clipboard = true;
+        capabilities.input_method = true;
+        capabilities.multiple_windows = true;
+        capabilities.popup_windows = true;
+        capabilities.fullscreen = true;
+        capabilities.mouse_cursors = true;
+        capabilities
+    }
+
+    #[cfg(not(no_qt))]
+    fn screens(&self) -> Vec<i_slint_core::api::Screen> {
+        use cpp::cpp;
+        let count = cpp! {unsafe [] -> i32 as "int" {
+            ensure_initialized();
+            return QGuiApplication::screens().size();
+        }};
+        (0..count)
+            .map(|index| {
+                let name = cpp! {unsafe [index as "int"] -> qttypes::QString as "QString" {
+                    QScreen *screen = QGuiApplication::screens().value(index);
+                    return screen ? screen->name() : QString();
+                }};
+                let geometry = cpp! {unsafe [index as "int"] -> qttypes::QRectF as "QRectF" {
+                    QScreen *screen = QGuiApplication::screens().value(index);
+                    return screen ? QRectF(screen->geometry()) : QRectF();
+                }};
+                let available_geometry = cpp! {unsafe [index as "int"] -> qttypes::QRectF as "QRectF" {
+                    QScreen *screen = QGuiApplication::screens().value(index);
+                    return screen ? QRectF(screen->availableGeometry()) : QRectF();
+                }};
+                let scale_factor = cpp! {unsafe [index as "int"] -> f64 as "qreal" {
+                    QScreen *screen = QGuiApplication::screens().value(index);
+                    return screen ? screen->devicePixelRatio() : 1.;
+                }};
+                let refresh_rate = cpp! {unsafe [index as "int"] -> f64 as "qreal" {
+                    QScreen *screen = QGuiApplication::screens().value(index);
+                    return screen ? screen->refreshRate() : 0.;
+                }};
+                // Qt reports the geometry in device independent pixels
+                let physical_position = |rect: &qttypes::QRectF| {
+                    i_slint_core::api::PhysicalPosition::new(
+                        (rect.x * scale_factor) as i32,
+                        (rect.y * scale_factor) as i32,
+                    )
+                };
+                let physical_size = |rect: &qttypes::QRectF| {
+                    i_slint_core::api::PhysicalSize::new(
+                        (rect.width * scale_factor) as u32,
+                        (rect.height * scale_factor) as u32,
+                    )
+                };
+                let mut screen = i_slint_core::api::Screen::default();
+                screen.name = name.to_string().into();
+                screen.position = physical_position(&geometry);
+                screen.size = physical_size(&geometry);
+                screen.work_area_position = physical_position(&available_geometry);
+                screen.work_area_size = physical_size(&available_geometry);
+                screen.scale_factor = scale_factor as f32;
+                screen.refresh_rate = (refresh_rate > 0.).then_some(refresh_rate as f32);
+                screen
+            })
+            .collect()
+    }
 }
@@ -246,6 +246,9 @@ fn input_event(
                 debug_assert_ne!(button, PointerEventButton::Left);
                 InputEventResult::EventIgnored
             }
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::PinchGesture { .. } => InputEventResult::EventIgnored,
         };
         data.active_controls = new_control;
 
@@ -200,7 +200,10 @@ fn input_event(
                     }
                     true
                 }
-                MouseEvent::Moved { .. } => false,
+                MouseEvent::Moved { .. }
+                | MouseEvent::DragMove { .. }
+                | MouseEvent::Drop { .. }
+                | MouseEvent::PinchGesture { .. } => false,
                 MouseEvent::Wheel { delta_y, .. } => {
                     if delta_y > 0. {
                         let v = self.value();
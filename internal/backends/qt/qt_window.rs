@@ -4,11 +4,12 @@
 // cSpell: ignore frameless qbrush qpointf qreal qwidgetsize svgz
 
 use cpp::*;
+use i_slint_core::api::LogicalPosition;
 use i_slint_core::graphics::rendering_metrics_collector::{
     RenderingMetrics, RenderingMetricsCollector,
 };
 use i_slint_core::graphics::{
-    euclid, Brush, Color, FontRequest, IntRect, Point, Rgba8Pixel, SharedImageBuffer,
+    euclid, Brush, Color, FontRequest, Image, IntRect, Point, Rgba8Pixel, SharedImageBuffer,
     SharedPixelBuffer,
 };
 use i_slint_core::input::{KeyEvent, KeyEventType, MouseEvent};
@@ -17,8 +18,9 @@
 };
 use i_slint_core::item_tree::{ItemTreeRc, ItemTreeRef};
 use i_slint_core::items::{
-    self, ColorScheme, FillRule, ImageRendering, ItemRc, ItemRef, Layer, MouseCursor, Opacity,
-    PointerEventButton, RenderingResult, TextOverflow, TextStrokeStyle, TextWrap,
+    self, ColorScheme, FillRule, ImageRendering, InteractionMode, ItemRc, ItemRef, Layer,
+    MouseCursor, Opacity, PointerEventButton, RenderingResult, TextCursorShape, TextOverflow,
+    TextStrokeStyle, TextWrap,
 };
 use i_slint_core::layout::Orientation;
 use i_slint_core::lengths::{
@@ -133,9 +135,14 @@ struct SlintWidget : QWidget {
             isMouseButtonDown = true;
             QPoint pos = event->pos();
             int button = event->button();
-            rust!(Slint_mousePressEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", button: u32 as "int" ] {
+            // Mouse events synthesized from touch events
+            bool touch = event->source() != Qt::MouseEventNotSynthesized;
+            rust!(Slint_mousePressEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", button: u32 as "int", touch: bool as "bool" ] {
                 let position = LogicalPoint::new(pos.x as _, pos.y as _);
                 let button = from_qt_button(button);
+                WindowInner::from_pub(&rust_window.window).input_device_used(
+                    if touch { InteractionMode::Touch } else { InteractionMode::Pointer },
+                );
                 rust_window.mouse_event(MouseEvent::Pressed{ position, button, click_count: 0 })
             });
         }
@@ -195,12 +202,23 @@ struct SlintWidget : QWidget {
                 return;
             QPointF pos = event->position();
             QPoint delta = event->pixelDelta();
-            if (delta.isNull()) {
+            bool angle_delta = delta.isNull();
+            if (angle_delta) {
                 delta = event->angleDelta();
             }
-            rust!(Slint_mouseWheelEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPointF as "QPointF", delta: qttypes::QPoint as "QPoint"] {
+            rust!(Slint_mouseWheelEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPointF as "QPointF", delta: qttypes::QPoint as "QPoint", angle_delta: bool as "bool"] {
                 let position = LogicalPoint::new(pos.x as _, pos.y as _);
-                rust_window.mouse_event(MouseEvent::Wheel{position, delta_x: delta.x as _, delta_y: delta.y as _})
+                // A step of the mouse wheel is an angle delta of 120
+                let scale = if angle_delta {
+                    i_slint_core::platform::settings().wheel_scroll_distance() / 120.
+                } else {
+                    1.
+                };
+                rust_window.mouse_event(MouseEvent::Wheel{
+                    position,
+                    delta_x: (delta.x as f32 * scale) as _,
+                    delta_y: (delta.y as f32 * scale) as _,
+                })
             });
         }
         void leaveEvent(QEvent *) override {
@@ -357,7 +375,8 @@ struct SlintWidget : QWidget {
     // Helper function used for the TextInput layouting
     //
     // if line_for_y_pos > 0, then the function will return the line at this y position
-    static int do_text_layout(QTextLayout &layout, int flags, const QRectF &rect, int line_for_y_pos = -1) {
+    // if line_height > 0, then the lines have that height, with the text centered in them
+    static int do_text_layout(QTextLayout &layout, int flags, const QRectF &rect, int line_for_y_pos = -1, qreal line_height = 0) {
         QTextOption options;
         options.setWrapMode((flags & Qt::TextWordWrap) ? QTextOption::WordWrap : ((flags & Qt::TextWrapAnywhere) ? QTextOption::WrapAnywhere : QTextOption::NoWrap));
         if (flags & Qt::AlignHCenter)
@@ -379,9 +398,14 @@ struct SlintWidget : QWidget {
             if (!line.isValid())
                 break;
             line.setLineWidth(rect.width());
-            height += leading;
-            line.setPosition(QPointF(0, height));
-            height += line.height();
+            if (line_height > 0) {
+                line.setPosition(QPointF(0, height + (line_height - line.height()) / 2.));
+                height += line_height;
+            } else {
+                height += leading;
+                line.setPosition(QPointF(0, height));
+                height += line.height();
+            }
             if (line_for_y_pos >= 0 && height > line_for_y_pos) {
                 return count;
             }
@@ -542,17 +566,22 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
                 g.angle(),
                 [width as f32, height as f32].into(),
             );
+            // Qt spreads the gradient beyond the line, so the line goes from the first to the last stop
+            let (first, last, stops) = i_slint_core::graphics::normalize_gradient_stops(g.stops());
+            let (start, end) = (start.lerp(end, first), start.lerp(end, last));
             let p1 = qttypes::QPointF { x: start.x as _, y: start.y as _ };
             let p2 = qttypes::QPointF { x: end.x as _, y: end.y as _ };
+            let spread = to_qt_spread(g.spread());
             cpp_class!(unsafe struct QLinearGradient as "QLinearGradient");
             let mut qlg = cpp! {
-                unsafe [p1 as "QPointF", p2 as "QPointF"] -> QLinearGradient as "QLinearGradient" {
+                unsafe [p1 as "QPointF", p2 as "QPointF", spread as "int"] -> QLinearGradient as "QLinearGradient" {
                     QLinearGradient qlg(p1, p2);
+                    qlg.setSpread(QGradient::Spread(spread));
                     return qlg;
                 }
             };
             let count = g.stops().count();
-            for (idx, s) in g.stops().enumerate() {
+            for (idx, s) in stops.enumerate() {
                 let pos: f32 = mangle_position(s.position, idx, count);
                 let color: u32 = s.color.as_argb_encoded();
                 cpp! {unsafe [mut qlg as "QLinearGradient", pos as "float", color as "QRgb"] {
@@ -564,15 +593,22 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
             }}
         }
         i_slint_core::Brush::RadialGradient(g) => {
+            // The gradient is spread beyond the circles of the first and the last stop
+            let (first, last, stops) = i_slint_core::graphics::normalize_gradient_stops(g.stops());
+            let (first, last) = (first.max(0.) as qttypes::qreal, last as qttypes::qreal);
+            let spread = to_qt_spread(g.spread());
             cpp_class!(unsafe struct QRadialGradient as "QRadialGradient");
             let mut qrg = cpp! {
-                unsafe [width as "qreal", height as "qreal"] -> QRadialGradient as "QRadialGradient" {
-                    QRadialGradient qrg(width / 2, height / 2, (width + height) / 4);
+                unsafe [width as "qreal", height as "qreal", first as "qreal", last as "qreal", spread as "int"] -> QRadialGradient as "QRadialGradient" {
+                    QPointF center(width / 2, height / 2);
+                    qreal radius = (width + height) / 4;
+                    QRadialGradient qrg(center, radius * last, center, radius * first);
+                    qrg.setSpread(QGradient::Spread(spread));
                     return qrg;
                 }
             };
             let count = g.stops().count();
-            for (idx, s) in g.stops().enumerate() {
+            for (idx, s) in stops.enumerate() {
                 let pos: f32 = mangle_position(s.position, idx, count);
                 let color: u32 = s.color.as_argb_encoded();
                 cpp! {unsafe [mut qrg as "QRadialGradient", pos as "float", color as "QRgb"] {
@@ -583,10 +619,43 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
                 return QBrush(qrg);
             }}
         }
+        i_slint_core::Brush::ConicGradient(g) => {
+            // Qt's conical gradients go counter-clockwise from the right, so the start angle and
+            // the positions of the stops are mirrored
+            let start_angle: qttypes::qreal = 90. - g.from_angle() as qttypes::qreal;
+            cpp_class!(unsafe struct QConicalGradient as "QConicalGradient");
+            let mut qcg = cpp! {
+                unsafe [width as "qreal", height as "qreal", start_angle as "qreal"] -> QConicalGradient as "QConicalGradient" {
+                    QConicalGradient qcg(width / 2, height / 2, start_angle);
+                    return qcg;
+                }
+            };
+            let stops = g.stops().collect::<Vec<_>>();
+            let count = stops.len();
+            for (idx, s) in stops.iter().rev().enumerate() {
+                let pos: f32 = mangle_position(1. - s.position, idx, count);
+                let color: u32 = s.color.as_argb_encoded();
+                cpp! {unsafe [mut qcg as "QConicalGradient", pos as "float", color as "QRgb"] {
+                    qcg.setColorAt(pos, QColor::fromRgba(color));
+                }};
+            }
+            cpp! {unsafe [qcg as "QConicalGradient"] -> qttypes::QBrush as "QBrush" {
+                return QBrush(qcg);
+            }}
+        }
         _ => qttypes::QBrush::default(),
     }
 }
 
+/// Returns the value of the `QGradient::Spread` enum
+fn to_qt_spread(spread: i_slint_core::graphics::GradientSpread) -> i32 {
+    match spread {
+        i_slint_core::graphics::GradientSpread::Reflect => 1,
+        i_slint_core::graphics::GradientSpread::Repeat => 2,
+        _ => 0,
+    }
+}
+
 fn from_qt_button(qt_button: u32) -> PointerEventButton {
     match qt_button {
         // https://doc.qt.io/qt-6/qt.html#MouseButton-enum
@@ -645,14 +714,56 @@ fn draw_border_rectangle(
         size: LogicalSize,
         _: &CachedRenderingData,
     ) {
+        let geometry = check_geometry!(size);
+        let Some(edges) = rect.border_edges() else {
+            Self::draw_rectangle_impl(
+                &mut self.painter,
+                geometry,
+                rect.background(),
+                rect.border_color(),
+                rect.border_width().get(),
+                rect.border_radius(),
+            );
+            return;
+        };
+
+        // Draw the background, and then each edge of the border on top of it
         Self::draw_rectangle_impl(
             &mut self.painter,
-            check_geometry!(size),
+            geometry,
             rect.background(),
-            rect.border_color(),
-            rect.border_width().get(),
+            Brush::default(),
+            0.,
+            rect.border_radius(),
+        );
+        let polygons = i_slint_core::graphics::border_edge_polygons(
+            size,
             rect.border_radius(),
+            edges.widths(),
         );
+        for (edge, polygons) in edges.to_array().into_iter().zip(polygons) {
+            if edge.color.is_transparent() {
+                continue;
+            }
+            let mut painter_path = QPainterPath::default();
+            painter_path.set_fill_rule(key_generated::Qt_FillRule_OddEvenFill);
+            for polygon in polygons {
+                let mut points =
+                    polygon.into_iter().map(|p| qttypes::QPointF { x: p.x as _, y: p.y as _ });
+                let Some(first) = points.next() else { continue };
+                painter_path.move_to(first);
+                for point in points {
+                    painter_path.line_to(point);
+                }
+                painter_path.close();
+            }
+            let brush: qttypes::QBrush =
+                into_qbrush(edge.color.clone(), geometry.width, geometry.height);
+            let painter: &mut QPainterPtr = &mut self.painter;
+            cpp! { unsafe [painter as "QPainterPtr*", painter_path as "QPainterPath", brush as "QBrush"] {
+                (*painter)->fillPath(painter_path, brush);
+            }}
+        }
     }
 
     fn draw_image(
@@ -675,7 +786,9 @@ fn draw_text(
         let rect: qttypes::QRectF = check_geometry!(size);
         let fill_brush: qttypes::QBrush = into_qbrush(text.color(), rect.width, rect.height);
         let mut string: qttypes::QString = text.text().as_str().into();
-        let font: QFont = get_font(text.font_request(WindowInner::from_pub(self.window)));
+        let font_request = text.font_request(WindowInner::from_pub(self.window));
+        let line_height: f32 = font_request.line_height.map_or(0., |height| height.get());
+        let font: QFont = get_font(font_request);
         let (horizontal_alignment, vertical_alignment) = text.alignment();
         let alignment = match horizontal_alignment {
             TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
@@ -698,7 +811,7 @@ fn draw_text(
             TextStrokeStyle::Center => stroke_width.get(),
         };
         let painter: &mut QPainterPtr = &mut self.painter;
-        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", stroke_brush as "QBrush", mut string as "QString", font as "QFont", elide as "bool", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", stroke_visible as "bool", stroke_outside as "bool", stroke_width as "float"] {
+        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", stroke_brush as "QBrush", mut string as "QString", font as "QFont", elide as "bool", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", stroke_visible as "bool", stroke_outside as "bool", stroke_width as "float", line_height as "float"] {
             QString elided;
             if (!elide) {
                 elided = string;
@@ -740,7 +853,7 @@ fn draw_text(
                         break;
                     }
                     line.setLineWidth(rect.width());
-                    height += leading + line.height();
+                    height += line_height > 0 ? line_height : leading + line.height();
                     if (height > rect.height()) {
                         break;
                     }
@@ -754,7 +867,7 @@ fn draw_text(
                 }
             }
 
-            if (!stroke_visible) {
+            if (!stroke_visible && line_height <= 0) {
                 int flags = alignment;
                 if (wrap) {
                     if (word_wrap) {
@@ -785,6 +898,14 @@ fn draw_text(
                 }
                 document.setDefaultTextOption(options);
 
+                if (line_height > 0) {
+                    QTextBlockFormat block_format;
+                    block_format.setLineHeight(line_height, QTextBlockFormat::FixedHeight);
+                    QTextCursor block_cursor(&document);
+                    block_cursor.select(QTextCursor::Document);
+                    block_cursor.mergeBlockFormat(block_format);
+                }
+
                 // Workaround for https://bugreports.qt.io/browse/QTBUG-13467
                 float dy = 0;
                 if (!(alignment & Qt::AlignTop)) {
@@ -819,6 +940,14 @@ fn draw_text(
                 (*painter)->save();
                 (*painter)->translate(0, dy);
 
+                if (!stroke_visible) {
+                    format.setForeground(fill_brush);
+                    cursor.mergeCharFormat(format);
+                    document.drawContents((*painter).get(), rect);
+                    (*painter)->restore();
+                    return;
+                }
+
                 if (stroke_outside) {
                     format.setForeground(Qt::NoBrush);
                     format.setTextOutline(stroke_pen);
@@ -919,8 +1048,18 @@ fn draw_text_input(
             } else {
                 (0., 0)
             };
+        let cursor_color = visual_representation.cursor_color.as_argb_encoded();
+        let cursor_shape: i32 = match visual_representation.cursor_shape {
+            TextCursorShape::Bar => 0,
+            TextCursorShape::Block => 1,
+            TextCursorShape::Underline => 2,
+        };
+        let cursor_character_end: i32 = visual_representation
+            .cursor_character_end()
+            .map_or(-1, |end| utf8_byte_offset_to_utf16_units(text.as_str(), end) as i32);
 
         let single_line: bool = text_input.single_line();
+        let line_height: f32 = text_input.line_height().get();
 
         let painter: &mut QPainterPtr = &mut self.painter;
         cpp! { unsafe [
@@ -937,12 +1076,16 @@ fn draw_text_input(
                 selection_start_position as "int",
                 selection_end_position as "int",
                 cursor_position as "int",
-                text_cursor_width as "float"] {
+                cursor_character_end as "int",
+                cursor_color as "QRgb",
+                cursor_shape as "int",
+                text_cursor_width as "float",
+                line_height as "float"] {
             if (!single_line) {
                 string.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(string, font);
-            do_text_layout(layout, flags, rect);
+            do_text_layout(layout, flags, rect, -1, line_height);
             (*painter)->setPen(QPen(fill_brush, 0));
             QVector<QTextLayout::FormatRange> selections;
             if (selection_end_position != selection_start_position) {
@@ -964,7 +1107,22 @@ fn draw_text_input(
             }
             layout.draw(painter->get(), rect.topLeft(), selections);
             if (text_cursor_width > 0) {
-                layout.drawCursor(painter->get(), rect.topLeft(), cursor_position, text_cursor_width);
+                (*painter)->setPen(QPen(QColor::fromRgba(cursor_color), 0));
+                QTextLine line = layout.lineForTextPosition(cursor_position);
+                if (cursor_shape == 0 || !line.isValid()) {
+                    layout.drawCursor(painter->get(), rect.topLeft(), cursor_position, text_cursor_width);
+                } else {
+                    // Block or underline, as wide as the character after the cursor
+                    qreal x = line.cursorToX(cursor_position);
+                    qreal width = cursor_character_end >= 0 ? line.cursorToX(cursor_character_end) - x : 0;
+                    if (width <= 0) {
+                        width = line.height() / 2;
+                    }
+                    QRectF cursor_rect = cursor_shape == 1
+                        ? QRectF(x, line.y(), width, line.height())
+                        : QRectF(x, line.y() + line.height() - text_cursor_width, width, text_cursor_width);
+                    (*painter)->fillRect(cursor_rect.translated(rect.topLeft()), QColor::fromRgba(cursor_color));
+                }
             }
         }}
     }
@@ -1269,6 +1427,13 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         }}
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        let painter: &mut QPainterPtr = &mut self.painter;
+        cpp! { unsafe [painter as "QPainterPtr*", x_factor as "float", y_factor as "float"] {
+            (*painter)->scale(x_factor, y_factor);
+        }}
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         let painter: &mut QPainterPtr = &mut self.painter;
         cpp! { unsafe [painter as "QPainterPtr*", opacity as "float"] {
@@ -1622,6 +1787,9 @@ pub struct QtWindow {
     tree_structure_changed: RefCell<bool>,
 
     color_scheme: OnceCell<Pin<Box<Property<ColorScheme>>>>,
+
+    /// The image and hotspot of the custom mouse cursor that is set, to set it only once
+    custom_cursor: RefCell<Option<(Image, LogicalPosition)>>,
 }
 
 impl Drop for QtWindow {
@@ -1656,6 +1824,7 @@ pub fn new() -> Rc<Self> {
                 cache: Default::default(),
                 tree_structure_changed: RefCell::new(false),
                 color_scheme: Default::default(),
+                custom_cursor: Default::default(),
             }
         });
         let widget_ptr = rc.widget_ptr();
@@ -1699,6 +1868,7 @@ fn paint_event(&self, painter: QPainterPtr) {
                 self.request_redraw();
             }
         });
+        self.window.dispatch_event(WindowEvent::FramePresented);
 
         // Update the accessibility tree (if the component tree has changed)
         if self.tree_structure_changed.replace(false) {
@@ -1835,9 +2005,7 @@ fn position(&self) -> Option<i_slint_core::api::PhysicalPosition> {
             return widget_ptr->pos();
         }};
         // Qt returns logical coordinates, so scale those!
-        i_slint_core::api::LogicalPosition::new(qp.x as _, qp.y as _)
-            .to_physical(self.window().scale_factor())
-            .into()
+        LogicalPosition::new(qp.x as _, qp.y as _).to_physical(self.window().scale_factor()).into()
     }
 
     fn set_position(&self, position: i_slint_core::api::WindowPosition) {
@@ -1886,7 +2054,7 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
         let Some(window_item) = WindowInner::from_pub(&self.window).window_item() else { return };
         let window_item = window_item.as_pin_ref();
         let no_frame = window_item.no_frame();
-        let always_on_top = window_item.always_on_top();
+        let always_on_top = properties.is_always_on_top();
         let mut size = qttypes::QSize {
             width: window_item.width().get().ceil() as _,
             height: window_item.height().get().ceil() as _,
@@ -1931,28 +2099,32 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
                 widget_ptr->resize(size.expandedTo({1, 1}));
             }
 
-            widget_ptr->setWindowFlag(Qt::FramelessWindowHint, no_frame);
-            widget_ptr->setWindowFlag(Qt::WindowStaysOnTopHint, always_on_top);
+            // When the widget is embedded in another widget, the window properties belong to the
+            // top-level window, and changing the flags would hide the widget.
+            if (widget_ptr->isWindow()) {
+                widget_ptr->setWindowFlag(Qt::FramelessWindowHint, no_frame);
+                widget_ptr->setWindowFlag(Qt::WindowStaysOnTopHint, always_on_top);
 
-            {
-                // Depending on the request, we either set or clear the bits.
-                // See also: https://doc.qt.io/qt-6/qt.html#WindowState-enum
-                auto state = widget_ptr->windowState();
+                {
+                    // Depending on the request, we either set or clear the bits.
+                    // See also: https://doc.qt.io/qt-6/qt.html#WindowState-enum
+                    auto state = widget_ptr->windowState();
 
-                if (fullscreen != widget_ptr->isFullScreen()) {
-                    state = state ^ Qt::WindowFullScreen;
-                }
-                if (minimized != widget_ptr->isMinimized()) {
-                    state = state ^ Qt::WindowMinimized;
-                }
-                if (maximized != widget_ptr->isMaximized()) {
-                    state = state ^ Qt::WindowMaximized;
+                    if (fullscreen != widget_ptr->isFullScreen()) {
+                        state = state ^ Qt::WindowFullScreen;
+                    }
+                    if (minimized != widget_ptr->isMinimized()) {
+                        state = state ^ Qt::WindowMinimized;
+                    }
+                    if (maximized != widget_ptr->isMaximized()) {
+                        state = state ^ Qt::WindowMaximized;
+                    }
+
+                    widget_ptr->setWindowState(state);
                 }
 
-                widget_ptr->setWindowState(state);
+                widget_ptr->setWindowTitle(title);
             }
-
-            widget_ptr->setWindowTitle(title);
             auto pal = widget_ptr->palette();
 
             #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
@@ -2065,11 +2237,28 @@ fn set_mouse_cursor(&self, cursor: MouseCursor) {
             MouseCursor::NeswResize => key_generated::Qt_CursorShape_SizeBDiagCursor,
             MouseCursor::NwseResize => key_generated::Qt_CursorShape_SizeFDiagCursor,
         };
+        self.custom_cursor.replace(None);
         cpp! {unsafe [widget_ptr as "QWidget*", cursor_shape as "Qt::CursorShape"] {
             widget_ptr->setCursor(QCursor{cursor_shape});
         }};
     }
 
+    fn set_custom_mouse_cursor(&self, image: &Image, hotspot: LogicalPosition) {
+        let cursor = Some((image.clone(), hotspot));
+        if *self.custom_cursor.borrow() == cursor {
+            return;
+        }
+        let Some(pixmap) = image_to_pixmap(image.into(), None) else {
+            return self.set_mouse_cursor(MouseCursor::Default);
+        };
+        self.custom_cursor.replace(cursor);
+        let widget_ptr = self.widget_ptr();
+        let (hot_x, hot_y) = (hotspot.x as i32, hotspot.y as i32);
+        cpp! {unsafe [widget_ptr as "QWidget*", pixmap as "QPixmap", hot_x as "int", hot_y as "int"] {
+            widget_ptr->setCursor(QCursor{pixmap, hot_x, hot_y});
+        }};
+    }
+
     fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest) {
         let widget_ptr = self.widget_ptr();
         let props = match request {
@@ -2152,6 +2341,57 @@ fn bring_to_front(&self) -> Result<(), i_slint_core::platform::PlatformError> {
         }};
         Ok(())
     }
+
+    fn start_window_move(&self) -> bool {
+        let widget_ptr = self.widget_ptr();
+        cpp! {unsafe [widget_ptr as "QWidget*"] -> bool as "bool" {
+            auto window = widget_ptr->window()->windowHandle();
+            return window && window->startSystemMove();
+        }}
+    }
+
+    fn start_window_resize(&self, edge: i_slint_core::items::WindowResizeEdge) {
+        use i_slint_core::items::WindowResizeEdge;
+        // The values of Qt::Edge
+        let (top, left, right, bottom) = (0x1, 0x2, 0x4, 0x8);
+        let edges: u32 = match edge {
+            WindowResizeEdge::BottomRight => bottom | right,
+            WindowResizeEdge::BottomLeft => bottom | left,
+            WindowResizeEdge::TopRight => top | right,
+            WindowResizeEdge::TopLeft => top | left,
+            WindowResizeEdge::Top => top,
+            WindowResizeEdge::Bottom => bottom,
+            WindowResizeEdge::Left => left,
+            WindowResizeEdge::Right => right,
+        };
+        let widget_ptr = self.widget_ptr();
+        cpp! {unsafe [widget_ptr as "QWidget*", edges as "uint"] {
+            if (auto window = widget_ptr->window()->windowHandle()) {
+                window->startSystemResize(Qt::Edges(edges));
+            }
+        }};
+    }
+
+    fn set_parent_window(&self, parent: Option<Rc<dyn WindowAdapter>>, modal: bool) {
+        let widget_ptr = self.widget_ptr();
+        let parent_ptr = parent
+            .as_ref()
+            .and_then(|parent| {
+                parent.internal(i_slint_core::InternalToken)?.as_any().downcast_ref::<QtWindow>()
+            })
+            .map_or(std::ptr::null_mut(), |parent| parent.widget_ptr().as_ptr());
+        cpp! {unsafe [widget_ptr as "QWidget*", parent_ptr as "QWidget*", modal as "bool"] {
+            // Use a transient parent instead of a parent widget, because the parent would own the widget
+            widget_ptr->winId();
+            QWindow *parent_window = nullptr;
+            if (parent_ptr) {
+                parent_ptr->winId();
+                parent_window = parent_ptr->windowHandle();
+            }
+            widget_ptr->windowHandle()->setTransientParent(parent_window);
+            widget_ptr->setWindowModality(parent_window && modal ? Qt::WindowModal : Qt::NonModal);
+        }};
+    }
 }
 
 impl i_slint_core::renderer::RendererSealed for QtWindow {
@@ -2163,11 +2403,24 @@ fn text_size(
         _scale_factor: ScaleFactor,
         text_wrap: TextWrap,
     ) -> LogicalSize {
-        get_font(font_request).font_metrics().text_size(
+        let line_height = font_request.line_height;
+        let font_metrics = get_font(font_request).font_metrics();
+        let size = font_metrics.text_size(
             text,
             max_width.map(|logical_width| logical_width.get()),
             text_wrap,
-        )
+        );
+        match line_height {
+            Some(line_height) => {
+                // The bounding rect contains the leading between the lines, but not after the last one
+                let line_count = ((size.height + font_metrics.leading())
+                    / font_metrics.line_spacing())
+                .round()
+                .max(1.);
+                LogicalSize::new(size.width, line_count * line_height.get())
+            }
+            None => size,
+        }
     }
 
     fn font_metrics(
@@ -2217,15 +2470,16 @@ fn text_input_byte_offset_for_position(
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
         let single_line: bool = text_input.single_line();
+        let line_height: f32 = text_input.line_height().get();
         let byte_offset = cpp! { unsafe [font as "QFont", string as "QString", pos as "QPointF", flags as "int",
-                rect as "QRectF", single_line as "bool"] -> usize as "size_t" {
+                rect as "QRectF", single_line as "bool", line_height as "float"] -> usize as "size_t" {
             // we need to do the \n replacement in a copy because the original need to be kept to know the utf8 offset
             auto copy = string;
             if (!single_line) {
                 copy.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(copy, font);
-            auto line = do_text_layout(layout, flags, rect, pos.y());
+            auto line = do_text_layout(layout, flags, rect, pos.y(), line_height);
             if (line < 0 || layout.lineCount() <= line)
                 return string.toUtf8().size();
             QTextLine textLine = layout.lineAt(line);
@@ -2275,13 +2529,14 @@ fn text_input_cursor_rect_for_byte_offset(
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
         let single_line: bool = text_input.single_line();
-        let r = cpp! { unsafe [font as "QFont", mut string as "QString", offset as "int", flags as "int", rect as "QRectF", single_line as "bool"]
+        let line_height: f32 = text_input.line_height().get();
+        let r = cpp! { unsafe [font as "QFont", mut string as "QString", offset as "int", flags as "int", rect as "QRectF", single_line as "bool", line_height as "float"]
                 -> qttypes::QRectF as "QRectF" {
             if (!single_line) {
                 string.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(string, font);
-            do_text_layout(layout, flags, rect);
+            do_text_layout(layout, flags, rect, -1, line_height);
 
             QTextLine textLine = layout.lineForTextPosition(offset);
             if (!textLine.isValid())
@@ -2466,6 +2721,20 @@ fn x_height(&self) -> f32 {
             return self->xHeight();
         }}
     }
+
+    fn leading(&self) -> f32 {
+        cpp! { unsafe [self as "const QFontMetricsF*"]
+                -> f32 as "float" {
+            return self->leading();
+        }}
+    }
+
+    fn line_spacing(&self) -> f32 {
+        cpp! { unsafe [self as "const QFontMetricsF*"]
+                -> f32 as "float" {
+            return self->lineSpacing();
+        }}
+    }
 }
 
 cpp_class! {pub unsafe struct QFont as "QFont"}
@@ -588,6 +588,17 @@ pub fn accessible_checkable(&self) -> Option<bool> {
             .and_then(|item| item.parse().ok())
     }
 
+    /// Returns the value of the `accessible-enabled` property, if present
+    pub fn accessible_enabled(&self) -> Option<bool> {
+        if self.element_index != 0 {
+            return None;
+        }
+        self.item
+            .upgrade()
+            .and_then(|item| item.accessible_string_property(AccessibleStringProperty::Enabled))
+            .and_then(|item| item.parse().ok())
+    }
+
     /// Returns the size of the element in logical pixels. This corresponds to the value of the `width` and
     /// `height` properties in Slint code. Returns a zero size if the element is not valid.
     pub fn size(&self) -> i_slint_core::api::LogicalSize {
@@ -687,7 +698,7 @@ pub async fn single_click(&self, button: i_slint_core::platform::PointerEventBut
     pub async fn double_click(&self, button: i_slint_core::platform::PointerEventButton) {
         let Ok(click_interval) = i_slint_core::with_global_context(
             || Err(i_slint_core::platform::PlatformError::NoPlatform),
-            |ctx| ctx.platform().click_interval(),
+            |_| i_slint_core::platform::settings().click_interval,
         ) else {
             return;
         };
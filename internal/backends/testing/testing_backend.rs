@@ -46,6 +46,7 @@ fn create_window_adapter(
             size: Default::default(),
             ime_requests: Default::default(),
             mouse_cursor: Default::default(),
+            custom_mouse_cursor: Default::default(),
         }))
     }
 
@@ -75,6 +76,21 @@ fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option
         }
     }
 
+    fn capabilities(&self) -> i_slint_core::platform::Capabilities {
+        let mut capabilities = i_slint_core::platform::Capabilities::default();
+        capabilities.clipboard = true;
+        capabilities.multiple_windows = true;
+        capabilities
+    }
+
+    fn register_global_shortcut(
+        &self,
+        _shortcut: &i_slint_core::api::GlobalShortcut,
+    ) -> Result<(), PlatformError> {
+        // The tests press the shortcuts with `slint::platform::notify_global_shortcut_pressed()`
+        Ok(())
+    }
+
     fn run_event_loop(&self) -> Result<(), PlatformError> {
         let queue = match self.queue.as_ref() {
             Some(queue) => queue.clone(),
@@ -109,6 +125,9 @@ pub struct TestingWindow {
     size: Cell<PhysicalSize>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
     pub mouse_cursor: Cell<i_slint_core::items::MouseCursor>,
+    /// The image and hotspot of the custom mouse cursor, if one is shown instead of `mouse_cursor`
+    pub custom_mouse_cursor:
+        RefCell<Option<(i_slint_core::graphics::Image, i_slint_core::api::LogicalPosition)>>,
 }
 
 impl WindowAdapterInternal for TestingWindow {
@@ -122,6 +141,16 @@ fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest
 
     fn set_mouse_cursor(&self, cursor: i_slint_core::items::MouseCursor) {
         self.mouse_cursor.set(cursor);
+        self.custom_mouse_cursor.replace(None);
+    }
+
+    fn set_custom_mouse_cursor(
+        &self,
+        image: &i_slint_core::graphics::Image,
+        hotspot: i_slint_core::api::LogicalPosition,
+    ) {
+        self.mouse_cursor.set(i_slint_core::items::MouseCursor::Default);
+        self.custom_mouse_cursor.replace(Some((image.clone(), hotspot)));
     }
 }
 
@@ -403,6 +403,13 @@ fn build_node_without_children(&self, item: &ItemRc, scale_factor: ScaleFactor)
             builder.set_toggled(if is_checked { Toggled::True } else { Toggled::False });
         }
 
+        if item
+            .accessible_string_property(AccessibleStringProperty::Enabled)
+            .is_some_and(|x| x == "false")
+        {
+            builder.set_disabled();
+        }
+
         if let Some(description) =
             item.accessible_string_property(AccessibleStringProperty::Description)
         {
@@ -13,8 +13,8 @@
 use crate::WinitWindowEventResult;
 use corelib::api::EventLoopError;
 use corelib::graphics::euclid;
-use corelib::input::{KeyEvent, KeyEventType, MouseEvent};
-use corelib::items::{ColorScheme, PointerEventButton};
+use corelib::input::{KeyEvent, KeyEventType, MouseEvent, TouchPhase};
+use corelib::items::{ColorScheme, InteractionMode, PointerEventButton, PointerEventDevice};
 use corelib::lengths::LogicalPoint;
 use corelib::platform::PlatformError;
 use corelib::window::*;
@@ -204,6 +204,42 @@ fn default() -> Self {
     pub(crate) static GLOBAL_PROXY: RefCell<Option<GlobalEventLoopProxyOrEventQueue>> = RefCell::new(None)
 }
 
+/// Returns the screens of the monitors that winit knows about
+pub(crate) fn screens() -> Vec<corelib::api::Screen> {
+    with_window_target(|event_loop| {
+        Ok(match event_loop.event_loop() {
+            ActiveOrInactiveEventLoop::Active(event_loop) => {
+                screens_from_monitors(event_loop.available_monitors())
+            }
+            ActiveOrInactiveEventLoop::Inactive(event_loop) => {
+                screens_from_monitors(event_loop.available_monitors())
+            }
+        })
+    })
+    .unwrap_or_default()
+}
+
+fn screens_from_monitors(
+    monitors: impl Iterator<Item = winit::monitor::MonitorHandle>,
+) -> Vec<corelib::api::Screen> {
+    monitors
+        .map(|monitor| {
+            let mut screen = corelib::api::Screen::default();
+            screen.name = monitor.name().unwrap_or_default().into();
+            let position = monitor.position();
+            screen.position = corelib::api::PhysicalPosition::new(position.x, position.y);
+            let size = monitor.size();
+            screen.size = corelib::api::PhysicalSize::new(size.width, size.height);
+            // winit doesn't tell what part of the monitor is covered by task bars and docks
+            screen.work_area_position = screen.position;
+            screen.work_area_size = screen.size;
+            screen.scale_factor = monitor.scale_factor() as f32;
+            screen.refresh_rate = monitor.refresh_rate_millihertz().map(|mhz| mhz as f32 / 1000.);
+            screen
+        })
+        .collect()
+}
+
 pub(crate) fn with_window_target<T>(
     callback: impl FnOnce(
         &dyn EventLoopInterface,
@@ -269,14 +305,17 @@ pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
-    current_touch_id: Option<u64>,
+    // true after the first of the files dragged together was dropped
+    files_dropped: bool,
 
     loop_error: Option<PlatformError>,
     current_resize_direction: Option<ResizeDirection>,
+    // the screens seen when they were last checked, to notify about changes
+    screens: Option<Vec<corelib::api::Screen>>,
 }
 
 impl winit::application::ApplicationHandler<SlintUserEvent> for EventLoopState {
-    fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         ALL_WINDOWS.with(|ws| {
             for (_, window_weak) in ws.borrow().iter() {
                 if let Some(w) = window_weak.upgrade() {
@@ -285,7 +324,8 @@ fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
                     }
                 }
             }
-        })
+        });
+        self.check_screens_changed(event_loop);
     }
 
     fn window_event(
@@ -438,7 +478,10 @@ macro_rules! winit_key_to_char {
             }
             WindowEvent::MouseWheel { delta, .. } => {
                 let (delta_x, delta_y) = match delta {
-                    winit::event::MouseScrollDelta::LineDelta(lx, ly) => (lx * 60., ly * 60.),
+                    winit::event::MouseScrollDelta::LineDelta(lx, ly) => {
+                        let step = corelib::platform::settings().wheel_scroll_distance();
+                        (lx * step, ly * step)
+                    }
                     winit::event::MouseScrollDelta::PixelDelta(d) => {
                         let d = d.to_logical(runtime_window.scale_factor() as f64);
                         (d.x, d.y)
@@ -472,6 +515,7 @@ macro_rules! winit_key_to_char {
                         }
 
                         self.pressed = true;
+                        runtime_window.input_device_used(InteractionMode::Pointer);
                         MouseEvent::Pressed { position: self.cursor_pos, button, click_count: 0 }
                     }
                     winit::event::ElementState::Released => {
@@ -482,34 +526,36 @@ macro_rules! winit_key_to_char {
                 runtime_window.process_mouse_input(ev);
             }
             WindowEvent::Touch(touch) => {
-                if Some(touch.id) == self.current_touch_id || self.current_touch_id.is_none() {
-                    let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-                    let position = euclid::point2(location.x, location.y);
-                    let ev = match touch.phase {
-                        winit::event::TouchPhase::Started => {
-                            self.pressed = true;
-                            if self.current_touch_id.is_none() {
-                                self.current_touch_id = Some(touch.id);
-                            }
-                            MouseEvent::Pressed {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
-                            self.pressed = false;
-                            self.current_touch_id = None;
-                            MouseEvent::Released {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
+                let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
+                let position = euclid::point2(location.x, location.y);
+                let phase = match touch.phase {
+                    winit::event::TouchPhase::Started => {
+                        self.pressed = true;
+                        TouchPhase::Started
+                    }
+                    winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                        self.pressed = false;
+                        TouchPhase::Ended
+                    }
+                    winit::event::TouchPhase::Moved => TouchPhase::Moved,
+                };
+                if let Some(force) = touch.force {
+                    // The Apple Pencil reports its angle to the screen
+                    let device = match force {
+                        winit::event::Force::Calibrated { altitude_angle: Some(_), .. } => {
+                            PointerEventDevice::Pen
                         }
-                        winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
+                        _ => PointerEventDevice::Touch,
                     };
-                    runtime_window.process_mouse_input(ev);
+                    window.window().dispatch_event(
+                        corelib::platform::WindowEvent::PointerDetailsChanged {
+                            details: corelib::platform::PointerDetails::new(device)
+                                .with_pressure(force.normalized() as f32),
+                        },
+                    );
                 }
+                // Also sets the interaction mode to touch
+                runtime_window.process_touch_input(touch.id, position, phase);
             }
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
@@ -521,11 +567,49 @@ macro_rules! winit_key_to_char {
                     // TODO: send a resize event or try to keep the logical size the same.
                     //window.resize_event(inner_size_writer.???)?;
                 }
+                self.check_screens_changed(event_loop);
+            }
+            WindowEvent::Moved(_) => {
+                // winit has no event for added or removed monitors, but windows are typically
+                // moved when that happens
+                self.check_screens_changed(event_loop);
             }
             WindowEvent::ThemeChanged(theme) => window.set_color_scheme(match theme {
                 winit::window::Theme::Dark => ColorScheme::Dark,
                 winit::window::Theme::Light => ColorScheme::Light,
             }),
+            WindowEvent::HoveredFile(path) => {
+                // Files that are dragged together are hovered one after the other
+                let uris = match runtime_window.drag_data() {
+                    Some(drag) if drag.mime_type == "text/uri-list" => {
+                        format!("{}\r\n{}", drag.data, file_uri(&path))
+                    }
+                    _ => file_uri(&path),
+                };
+                self.files_dropped = false;
+                runtime_window.start_drag("text/uri-list".into(), uris.into(), None);
+                runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
+            }
+            WindowEvent::DroppedFile(path) => {
+                // The first event drops all the hovered files
+                if !std::mem::replace(&mut self.files_dropped, true) {
+                    if runtime_window.drag_data().is_none() {
+                        runtime_window.start_drag(
+                            "text/uri-list".into(),
+                            file_uri(&path).into(),
+                            None,
+                        );
+                    }
+                    runtime_window.process_mouse_input(MouseEvent::Released {
+                        position: self.cursor_pos,
+                        button: PointerEventButton::Left,
+                        click_count: 0,
+                    });
+                }
+            }
+            WindowEvent::HoveredFileCancelled => {
+                runtime_window.process_mouse_input(MouseEvent::Exit);
+            }
             WindowEvent::Occluded(x) => {
                 window.renderer.occluded(x);
 
@@ -649,6 +733,17 @@ fn memory_warning(&mut self, event_loop: &ActiveEventLoop) {
 }
 
 impl EventLoopState {
+    /// Calls the handler of [`corelib::platform::notify_screens_changed()`] when the screens
+    /// differ from the ones seen last time.
+    fn check_screens_changed(&mut self, event_loop: &ActiveEventLoop) {
+        let screens = screens_from_monitors(event_loop.available_monitors());
+        let changed = self.screens.as_ref().is_some_and(|old| *old != screens);
+        self.screens = Some(screens);
+        if changed {
+            corelib::platform::notify_screens_changed();
+        }
+    }
+
     /// Runs the event loop and renders the items in the provided `component` in its
     /// own window.
     #[allow(unused_mut)] // mut need changes for wasm
@@ -760,6 +855,22 @@ pub fn pump_events(
 }
 
 #[cfg(target_arch = "wasm32")]
+/// Returns the `file://` URI of the path, for the `text/uri-list` data of the files that are
+/// dragged over a window
+fn file_uri(path: &std::path::Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut uri = String::from(if path.starts_with('/') { "file://" } else { "file:///" });
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    uri
+}
+
 pub fn spawn() -> Result<(), corelib::platform::PlatformError> {
     use winit::platform::web::EventLoopExtWebSys;
     let not_running_loop_instance = MAYBE_LOOP_INSTANCE
@@ -0,0 +1,133 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Registration of the global shortcuts with the `global-hotkey` crate. The hot key events may come
+//! from another thread, so they are forwarded to the event loop, where the callback registered with
+//! `slint::register_global_shortcut()` is invoked.
+
+use global_hotkey::hotkey::{Code, HotKey, Modifiers};
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use i_slint_core::api::GlobalShortcut;
+use i_slint_core::platform::PlatformError;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+struct GlobalShortcuts {
+    manager: GlobalHotKeyManager,
+    /// The registered shortcuts, by the id of their hot key
+    shortcuts: HashMap<u32, (HotKey, GlobalShortcut)>,
+}
+
+thread_local! {
+    // The manager must be created and used on the thread of the event loop
+    static GLOBAL_SHORTCUTS: RefCell<Option<GlobalShortcuts>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn register(shortcut: &GlobalShortcut) -> Result<(), PlatformError> {
+    let hot_key = hot_key(shortcut)?;
+    GLOBAL_SHORTCUTS.with(|global_shortcuts| {
+        let mut global_shortcuts = global_shortcuts.borrow_mut();
+        let global_shortcuts = match global_shortcuts.as_mut() {
+            Some(global_shortcuts) => global_shortcuts,
+            None => {
+                let manager = GlobalHotKeyManager::new().map_err(|e| {
+                    PlatformError::from(format!("Error initializing global shortcuts: {e}"))
+                })?;
+                GlobalHotKeyEvent::set_event_handler(Some(|event: GlobalHotKeyEvent| {
+                    if event.state() == HotKeyState::Pressed {
+                        let id = event.id();
+                        i_slint_core::api::invoke_from_event_loop(move || pressed(id)).ok();
+                    }
+                }));
+                global_shortcuts.insert(GlobalShortcuts { manager, shortcuts: HashMap::new() })
+            }
+        };
+        global_shortcuts.manager.register(hot_key).map_err(|e| {
+            PlatformError::from(format!("Error registering the global shortcut {shortcut:?}: {e}"))
+        })?;
+        global_shortcuts.shortcuts.insert(hot_key.id(), (hot_key, shortcut.clone()));
+        Ok(())
+    })
+}
+
+pub(crate) fn unregister(shortcut: &GlobalShortcut) {
+    GLOBAL_SHORTCUTS.with(|global_shortcuts| {
+        let mut global_shortcuts = global_shortcuts.borrow_mut();
+        let Some(global_shortcuts) = global_shortcuts.as_mut() else { return };
+        global_shortcuts.shortcuts.retain(|_, (hot_key, s)| {
+            if s != shortcut {
+                return true;
+            }
+            global_shortcuts.manager.unregister(*hot_key).ok();
+            false
+        });
+    })
+}
+
+fn pressed(id: u32) {
+    let shortcut = GLOBAL_SHORTCUTS.with(|global_shortcuts| {
+        let global_shortcuts = global_shortcuts.borrow();
+        global_shortcuts.as_ref()?.shortcuts.get(&id).map(|(_, shortcut)| shortcut.clone())
+    });
+    if let Some(shortcut) = shortcut {
+        i_slint_core::platform::notify_global_shortcut_pressed(&shortcut);
+    }
+}
+
+fn hot_key(shortcut: &GlobalShortcut) -> Result<HotKey, PlatformError> {
+    let code = key_code(&shortcut.key).ok_or_else(|| {
+        PlatformError::from(format!(
+            "The key {:?} can't be used in a global shortcut",
+            shortcut.key.as_str()
+        ))
+    })?;
+    let mut modifiers = Modifiers::empty();
+    // Slint's control modifier is the Command key on macOS, and its meta modifier the Control key
+    let (control, meta) = if cfg!(target_os = "macos") {
+        (Modifiers::SUPER, Modifiers::CONTROL)
+    } else {
+        (Modifiers::CONTROL, Modifiers::SUPER)
+    };
+    modifiers.set(Modifiers::ALT, shortcut.alt);
+    modifiers.set(control, shortcut.control);
+    modifiers.set(Modifiers::SHIFT, shortcut.shift);
+    modifiers.set(meta, shortcut.meta);
+    Ok(HotKey::new(Some(modifiers), code))
+}
+
+/// Returns the physical key that produces the text of a key event without modifiers, on a US layout
+fn key_code(key: &str) -> Option<Code> {
+    let mut chars = key.chars();
+    let ch = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+
+    macro_rules! special_key_name {
+        ($($char:literal # $name:ident # $($_qt:ident)|* # $($winit:ident $(($_pos:ident))?)|* # $($_xkb:ident)|* ;)*) => {
+            match ch {
+                $($char => None$(.or(Some(stringify!($winit))))*,)*
+                _ => None,
+            }
+        };
+    }
+    let name = match ch {
+        'a'..='z' | 'A'..='Z' => format!("Key{}", ch.to_ascii_uppercase()),
+        '0'..='9' => format!("Digit{ch}"),
+        ' ' => "Space".into(),
+        '-' => "Minus".into(),
+        '=' => "Equal".into(),
+        ',' => "Comma".into(),
+        '.' => "Period".into(),
+        '/' => "Slash".into(),
+        ';' => "Semicolon".into(),
+        '\'' => "Quote".into(),
+        '[' => "BracketLeft".into(),
+        ']' => "BracketRight".into(),
+        '\\' => "Backslash".into(),
+        '`' => "Backquote".into(),
+        // The names of the special keys in winit are the same as the names of the physical keys
+        _ => i_slint_common::for_each_special_keys!(special_key_name)?.into(),
+    };
+    name.parse().ok()
+}
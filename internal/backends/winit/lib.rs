@@ -18,6 +18,8 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod clipboard;
 mod drag_resize_window;
+#[cfg(enable_global_shortcuts)]
+mod global_shortcuts;
 mod winitwindowadapter;
 
 use i_slint_core::platform::PlatformError;
@@ -451,6 +453,34 @@ fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option
         let mut pair = clipboard_pair.borrow_mut();
         clipboard::select_clipboard(&mut pair, clipboard).and_then(|c| c.get_contents().ok())
     }
+
+    fn capabilities(&self) -> i_slint_core::platform::Capabilities {
+        let mut capabilities = i_slint_core::platform::Capabilities::default();
+        capabilities.clipboard = true;
+        capabilities.input_method = true;
+        // On the web, each window needs its own canvas element
+        capabilities.multiple_windows = !cfg!(target_arch = "wasm32");
+        capabilities.fullscreen = true;
+        capabilities.mouse_cursors = true;
+        capabilities
+    }
+
+    fn screens(&self) -> Vec<i_slint_core::api::Screen> {
+        crate::event_loop::screens()
+    }
+
+    #[cfg(enable_global_shortcuts)]
+    fn register_global_shortcut(
+        &self,
+        shortcut: &i_slint_core::api::GlobalShortcut,
+    ) -> Result<(), PlatformError> {
+        global_shortcuts::register(shortcut)
+    }
+
+    #[cfg(enable_global_shortcuts)]
+    fn unregister_global_shortcut(&self, shortcut: &i_slint_core::api::GlobalShortcut) {
+        global_shortcuts::unregister(shortcut)
+    }
 }
 
 /// Spawn the event loop, using [`winit::platform::web::EventLoopExtWebSys::spawn()`]
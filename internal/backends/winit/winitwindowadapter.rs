@@ -174,6 +174,40 @@ fn set_fullscreen(&self, fullscreen: Option<winit::window::Fullscreen>) {
         }
     }
 
+    fn set_transparent(&self, transparent: bool) {
+        match self {
+            Self::HasWindow(window) => window.set_transparent(transparent),
+            // The window is always created with an alpha channel, so that it can become transparent later
+            Self::None(..) => {}
+        }
+    }
+
+    fn set_blur(&self, blur: bool) {
+        #[cfg(target_family = "windows")]
+        let backdrop = if blur {
+            // Acrylic
+            winit::platform::windows::BackdropType::TransientWindow
+        } else {
+            winit::platform::windows::BackdropType::Auto
+        };
+        match self {
+            Self::HasWindow(window) => {
+                window.set_blur(blur);
+                #[cfg(target_family = "windows")]
+                window.set_system_backdrop(backdrop);
+            }
+            Self::None(attributes) => {
+                let mut attributes = attributes.borrow_mut();
+                attributes.blur = blur;
+                #[cfg(target_family = "windows")]
+                {
+                    use winit::platform::windows::WindowAttributesExtWindows;
+                    *attributes = std::mem::take(&mut *attributes).with_system_backdrop(backdrop);
+                }
+            }
+        }
+    }
+
     fn set_window_level(&self, level: winit::window::WindowLevel) {
         match self {
             Self::HasWindow(window) => window.set_window_level(level),
@@ -275,6 +309,14 @@ pub struct WinitWindowAdapter {
     >,
 
     winit_window_or_none: RefCell<WinitWindowOrNone>,
+
+    /// The custom mouse cursor that was set last, so that it's only created once
+    custom_cursor:
+        RefCell<Option<(Image, corelib::api::LogicalPosition, winit::window::CustomCursor)>>,
+
+    /// The window set with `set_parent_window`, and whether this window is modal
+    parent_window: RefCell<Option<Weak<dyn WindowAdapter>>>,
+    modal: Cell<bool>,
 }
 
 impl WinitWindowAdapter {
@@ -312,6 +354,9 @@ pub(crate) fn new(
             )
             .into(),
             window_event_filter: Cell::new(None),
+            custom_cursor: Default::default(),
+            parent_window: Default::default(),
+            modal: Default::default(),
         });
 
         debug_assert!(!self_rc.renderer.is_suspended());
@@ -338,6 +383,7 @@ pub fn ensure_window(&self) -> Result<Rc<winit::window::Window>, PlatformError>
             WinitWindowOrNone::HasWindow(window_rc) => return Ok(window_rc.clone()),
             WinitWindowOrNone::None(attributes) => attributes.borrow().clone(),
         };
+        let window_attributes = self.with_parent_window_attributes(window_attributes);
         let mut winit_window_or_none = self.winit_window_or_none.borrow_mut();
 
         let winit_window = self.renderer.resume(window_attributes)?;
@@ -352,6 +398,35 @@ pub fn ensure_window(&self) -> Result<Rc<winit::window::Window>, PlatformError>
         Ok(winit_window)
     }
 
+    /// Returns the winit window of the parent window, if it's a winit window too
+    fn parent_winit_window(&self) -> Option<Rc<winit::window::Window>> {
+        let parent = self.parent_window.borrow().as_ref()?.upgrade()?;
+        let parent = parent.internal(corelib::InternalToken)?.as_any().downcast_ref::<Self>()?;
+        parent.winit_window()
+    }
+
+    /// winit can only set the owner of a window when it's created
+    #[allow(unused_mut)]
+    fn with_parent_window_attributes(&self, mut attributes: WindowAttributes) -> WindowAttributes {
+        #[cfg(any(target_family = "windows", target_os = "macos"))]
+        if let Some(parent) = self.parent_winit_window() {
+            use raw_window_handle::HasWindowHandle;
+            if let Ok(handle) = parent.window_handle() {
+                #[cfg(target_family = "windows")]
+                if let raw_window_handle::RawWindowHandle::Win32(handle) = handle.as_raw() {
+                    use winit::platform::windows::WindowAttributesExtWindows;
+                    attributes = attributes.with_owner_window(handle.hwnd.get());
+                }
+                #[cfg(target_os = "macos")]
+                {
+                    // Safety: the parent window is alive, and windows are only created on the main thread
+                    attributes = unsafe { attributes.with_parent_window(Some(handle.as_raw())) };
+                }
+            }
+        }
+        attributes
+    }
+
     fn suspend(&self) -> Result<(), PlatformError> {
         let mut winit_window_or_none = self.winit_window_or_none.borrow_mut();
         match *winit_window_or_none {
@@ -446,6 +521,7 @@ pub fn draw(&self) -> Result<(), PlatformError> {
 
         let renderer = self.renderer();
         renderer.render(self.window())?;
+        self.window().dispatch_event(WindowEvent::FramePresented);
 
         Ok(())
     }
@@ -632,6 +708,14 @@ fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
 
             winit_window.set_visible(true);
 
+            // A modal window disables its parent
+            #[cfg(target_family = "windows")]
+            if self.modal.get() {
+                if let Some(parent) = self.parent_winit_window() {
+                    parent.set_enable(false);
+                }
+            }
+
             // Make sure the dark color scheme property is up-to-date, as it may have been queried earlier when
             // the window wasn't mapped yet.
             if let Some(color_scheme_prop) = self.color_scheme.get() {
@@ -653,6 +737,14 @@ fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
 
             Ok(())
         } else {
+            // Enable the parent before hiding, so that it becomes the active window again
+            #[cfg(target_family = "windows")]
+            if self.modal.get() {
+                if let Some(parent) = self.parent_winit_window() {
+                    parent.set_enable(true);
+                }
+            }
+
             crate::event_loop::with_window_target(|event_loop| {
                 // Wayland doesn't support hiding a window, only destroying it entirely.
                 if event_loop.is_wayland()
@@ -750,7 +842,10 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
         winit_window_or_none.set_decorations(
             !window_item.no_frame() || winit_window_or_none.fullscreen().is_some(),
         );
-        let new_window_level = if window_item.always_on_top() {
+        // The windowing system can skip the windows below an opaque window when compositing
+        winit_window_or_none.set_transparent(!properties.background().is_opaque());
+        winit_window_or_none.set_blur(window_item.blur_behind());
+        let new_window_level = if properties.is_always_on_top() {
             winit::window::WindowLevel::AlwaysOnTop
         } else {
             winit::window::WindowLevel::Normal
@@ -922,6 +1017,51 @@ fn set_mouse_cursor(&self, cursor: MouseCursor) {
         }
     }
 
+    fn set_custom_mouse_cursor(&self, image: &Image, hotspot: corelib::api::LogicalPosition) {
+        let Some(winit_window) = self.winit_window_or_none.borrow().as_window() else {
+            return;
+        };
+        let mut custom_cursor = self.custom_cursor.borrow_mut();
+        if custom_cursor.as_ref().map_or(true, |(i, h, _)| i != image || *h != hotspot) {
+            let Some(pixels) = image.to_rgba8() else {
+                return self.set_mouse_cursor(MouseCursor::Default);
+            };
+            let (width, height) = (pixels.width() as u16, pixels.height() as u16);
+            let source = match winit::window::CustomCursor::from_rgba(
+                pixels.as_bytes().to_vec(),
+                width,
+                height,
+                (hotspot.x.max(0.) as u16).min(width.saturating_sub(1)),
+                (hotspot.y.max(0.) as u16).min(height.saturating_sub(1)),
+            ) {
+                Ok(source) => source,
+                Err(err) => {
+                    i_slint_core::debug_log!("Error creating the custom mouse cursor: {err}");
+                    return self.set_mouse_cursor(MouseCursor::Default);
+                }
+            };
+            let cursor = crate::event_loop::with_window_target(|event_loop| {
+                Ok(match event_loop.event_loop() {
+                    crate::event_loop::ActiveOrInactiveEventLoop::Active(event_loop) => {
+                        event_loop.create_custom_cursor(source)
+                    }
+                    #[allow(deprecated)]
+                    crate::event_loop::ActiveOrInactiveEventLoop::Inactive(event_loop) => {
+                        event_loop.create_custom_cursor(source)
+                    }
+                })
+            });
+            match cursor {
+                Ok(cursor) => *custom_cursor = Some((image.clone(), hotspot, cursor)),
+                Err(_) => return self.set_mouse_cursor(MouseCursor::Default),
+            }
+        }
+        if let Some((_, _, cursor)) = custom_cursor.as_ref() {
+            winit_window.set_cursor_visible(true);
+            winit_window.set_cursor(cursor.clone());
+        }
+    }
+
     fn input_method_request(&self, request: corelib::window::InputMethodRequest) {
         #[cfg(not(target_arch = "wasm32"))]
         if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
@@ -1042,6 +1182,48 @@ fn bring_to_front(&self) -> Result<(), PlatformError> {
         }
         Ok(())
     }
+
+    fn start_window_move(&self) -> bool {
+        self.winit_window_or_none
+            .borrow()
+            .as_window()
+            .is_some_and(|winit_window| winit_window.drag_window().is_ok())
+    }
+
+    fn start_window_resize(&self, edge: corelib::items::WindowResizeEdge) {
+        use corelib::items::WindowResizeEdge;
+        use winit::window::ResizeDirection;
+        let direction = match edge {
+            WindowResizeEdge::BottomRight => ResizeDirection::SouthEast,
+            WindowResizeEdge::BottomLeft => ResizeDirection::SouthWest,
+            WindowResizeEdge::TopRight => ResizeDirection::NorthEast,
+            WindowResizeEdge::TopLeft => ResizeDirection::NorthWest,
+            WindowResizeEdge::Top => ResizeDirection::North,
+            WindowResizeEdge::Bottom => ResizeDirection::South,
+            WindowResizeEdge::Left => ResizeDirection::West,
+            WindowResizeEdge::Right => ResizeDirection::East,
+        };
+        if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
+            let _ = winit_window.drag_resize_window(direction);
+        }
+    }
+
+    fn set_parent_window(&self, parent: Option<Rc<dyn WindowAdapter>>, modal: bool) {
+        let parent = parent.as_ref().map(Rc::downgrade);
+        let parent_changed = match (&*self.parent_window.borrow(), &parent) {
+            (Some(old), Some(new)) => !old.ptr_eq(new),
+            (old, new) => old.is_some() != new.is_some(),
+        };
+        self.parent_window.replace(parent);
+        self.modal.set(modal);
+        // Re-create the window with the new owner the next time it's shown
+        if parent_changed
+            && !self.shown.get()
+            && cfg!(any(target_family = "windows", target_os = "macos"))
+        {
+            let _ = self.suspend();
+        }
+    }
 }
 
 impl Drop for WinitWindowAdapter {
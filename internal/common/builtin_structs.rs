@@ -65,6 +65,22 @@ struct PointerEvent {
                     kind: PointerEventKind,
                     /// The keyboard modifiers pressed during the event
                     modifiers: KeyboardModifiers,
+                    /// The number of clicks before this press or release in quick succession at the same place,
+                    /// so 1 for a double click, 2 for a triple click, and so on. 0 for move and cancel events.
+                    click_count: i32,
+                    /// The position of the pointer, relative to the TouchArea. For cancel events, this is the last known position.
+                    position: LogicalPosition,
+                    /// The kind of device that generated the event, such as a mouse or a pen.
+                    device: PointerEventDevice,
+                    /// The pressure of a pen or a finger, between 0 and 1. For devices that don't report it,
+                    /// such as a mouse, it's 0.5 while a button is pressed and 0 otherwise.
+                    pressure: f32,
+                    /// The angle of a pen in degrees, between -90 and 90, from the normal of the screen
+                    /// towards the right side. 0 for other devices.
+                    tilt_x: f32,
+                    /// The angle of a pen in degrees, between -90 and 90, from the normal of the screen
+                    /// towards the bottom side. 0 for other devices.
+                    tilt_y: f32,
                 }
                 private {
                 }
@@ -86,6 +102,22 @@ struct PointerScrollEvent {
                 }
             }
 
+            /// Represents the data that is dragged with a `DragArea`, or from another application.
+            /// This structure is passed to the callbacks of the `DropArea` element.
+            struct DropEvent {
+                @name = "slint::private_api::DropEvent"
+                export {
+                    /// The type of the data, such as `"text/plain"`, or `"text/uri-list"` for files dragged from another application
+                    mime_type: SharedString,
+                    /// The dragged data
+                    data: SharedString,
+                    /// The position of the pointer, relative to the `DropArea`
+                    position: LogicalPosition,
+                }
+                private {
+                }
+            }
+
             /// This structure is generated and passed to the key press and release callbacks of the `FocusScope` element.
             struct KeyEvent {
                 @name = "slint::private_api::KeyEvent"
@@ -127,6 +159,24 @@ struct StandardListViewItem {
                 }
             }
 
+            /// Represents an item in a TreeView, which shows a tree as a flat list of its visible nodes.
+            #[non_exhaustive]
+            struct TreeViewItem {
+                @name = "slint::TreeViewItem"
+                export {
+                    /// The text content of the item
+                    text: SharedString,
+                    /// The depth of the node in the tree, 0 for the root nodes
+                    depth: i32,
+                    /// Whether the node can be expanded
+                    has_children: bool,
+                    /// Whether the children of the node are shown in the rows that follow it
+                    expanded: bool,
+                }
+                private {
+                }
+            }
+
             /// This is used to define the column and the column header of a TableView
             #[non_exhaustive]
             struct TableColumn {
@@ -142,6 +192,8 @@ struct TableColumn {
                     sort_order: SortOrder,
                     /// the actual width of the column (logical length)
                     width: Coord,
+                    /// The maximum column width (logical length). Zero means that the width is not limited.
+                    max_width: Coord,
                 }
                 private {
                 }
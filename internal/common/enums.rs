@@ -143,6 +143,19 @@ enum PointerEventKind {
                 Move,
             }
 
+            /// This enum describes the kind of device that generated a pointer event.
+            #[non_exhaustive]
+            enum PointerEventDevice {
+                /// A mouse, a touchpad, or another device that moves a cursor.
+                Mouse,
+                /// A finger on a touch screen.
+                Touch,
+                /// The tip of a pen, such as a stylus on a graphics tablet.
+                Pen,
+                /// The eraser at the end of a pen.
+                Eraser,
+            }
+
             /// This enum describes the different types of buttons for a pointer event,
             /// typically on a mouse or a pencil.
             #[non_exhaustive]
@@ -232,6 +245,26 @@ enum MouseCursor {
                 //zoom_out,
             }
 
+            /// This enum describes the edge or the corner of the window that a [`WindowResizeArea`](elements.md#windowresizearea) resizes.
+            enum WindowResizeEdge {
+                /// The bottom right corner.
+                BottomRight,
+                /// The bottom left corner.
+                BottomLeft,
+                /// The top right corner.
+                TopRight,
+                /// The top left corner.
+                TopLeft,
+                /// The top edge.
+                Top,
+                /// The bottom edge.
+                Bottom,
+                /// The left edge.
+                Left,
+                /// The right edge.
+                Right,
+            }
+
             /// This enum defines how the source image shall fit into an [`Image`](elements.md#image) element.
             enum ImageFit {
                 /// Scales and stretches the source image to fit the width and height of the [`Image`](elements.md#image) element.
@@ -298,6 +331,24 @@ enum InputType {
                 Decimal,
             }
 
+            /// This enum describes the shape of the text cursor of a [`TextInput`](elements.md#textinput).
+            enum TextCursorShape {
+                /// A vertical bar in front of the character at the cursor position, as wide as `text-cursor-width`.
+                Bar,
+                /// A block that covers the character at the cursor position.
+                Block,
+                /// A line below the character at the cursor position, as high as `text-cursor-width`.
+                Underline,
+            }
+
+            /// This enum describes how the text cursor of a [`TextInput`](elements.md#textinput) blinks.
+            enum TextCursorBlink {
+                /// The cursor is alternately shown and hidden.
+                Toggle,
+                /// The cursor fades out and in again.
+                Fade,
+            }
+
             /// Enum representing the [alignment](../concepts/layouting.md#alignment) property of a
             /// [`HorizontalBox`](../widgets/horizontalbox.md), a [`VerticalBox`](../widgets/verticalbox.md),
             /// a [`HorizontalLayout`, or `VerticalLayout`](elements.md#verticallayout-and-horizontallayout).
@@ -410,6 +461,39 @@ enum ColorScheme {
                 Light,
             }
 
+            /// This enum describes how the user interacts with the window. The widgets of the styles
+            /// enlarge their scroll bars and make their content scrollable by dragging in the touch mode.
+            enum InteractionMode {
+                /// The user interacts with a mouse, a touchpad, or a pen.
+                Pointer,
+                /// The user interacts with a touch screen.
+                Touch,
+            }
+
+            /// This enum describes when a `PopupWindow` closes by itself.
+            enum PopupClosePolicy {
+                /// The popup closes when the user clicks inside or outside of it.
+                CloseOnClick,
+                /// The popup closes when the user clicks outside of it, clicks inside of it keep it open.
+                CloseOnClickOutside,
+                /// The popup stays open until its `close()` function is called.
+                StayOpen,
+            }
+
+            /// This enum describes the edge of the parent element along which a `PopupWindow` is placed.
+            enum PopupAnchorEdge {
+                /// The popup is placed at its `x` and `y` position, relative to the parent element.
+                None,
+                /// The popup is placed above the parent element.
+                Top,
+                /// The popup is placed below the parent element.
+                Bottom,
+                /// The popup is placed to the left of the parent element.
+                Left,
+                /// The popup is placed to the right of the parent element.
+                Right,
+            }
+
             /// This enum describes the direction of an animation.
             enum AnimationDirection {
                 /// The ["normal" direction as defined in CSS](https://developer.mozilla.org/en-US/docs/Web/CSS/animation-direction#normal).
@@ -0,0 +1,259 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+ Report of the bindings that depend on inputs that change very often, such as the animation tick
+ or the position of the pointer.
+
+ Such bindings are re-evaluated for every frame of an animation or for every movement of the mouse,
+ so expensive expressions in them are the first suspects when an application doesn't run smoothly.
+ The cost that is reported for each binding is a rough estimation of the work done by one evaluation,
+ in arbitrary units, which is only meant to rank the bindings against each other.
+*/
+
+use crate::expression_tree::{BuiltinFunction, Expression};
+use crate::namedreference::NamedReference;
+use crate::object_tree::{
+    recurse_elem_including_sub_components, visit_named_references_in_expression, Document,
+    ElementRc,
+};
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write;
+
+/// A binding that is re-evaluated whenever one of its frequently changing inputs changes.
+#[derive(Debug, Clone)]
+pub struct HotBinding {
+    /// The property that has the binding
+    pub property: NamedReference,
+    /// The name of the component that contains the element of the property
+    pub component: String,
+    /// `file:line:column` of the binding in the source, or None if it was generated by the compiler
+    pub location: Option<String>,
+    /// The estimated cost of one evaluation of the binding
+    pub cost: usize,
+    /// Descriptions of the frequently changing inputs the binding depends on, directly or through
+    /// other bindings
+    pub inputs: BTreeSet<String>,
+}
+
+/// Finds the bindings of all the components of the document that depend on frequently changing
+/// inputs, sorted from the most expensive to the cheapest.
+pub fn hot_bindings(doc: &Document) -> Vec<HotBinding> {
+    let mut analysis = Analysis::default();
+    let mut result = Vec::new();
+    doc.visit_all_used_components(|component| {
+        recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+            let names = elem
+                .borrow()
+                .bindings
+                .keys()
+                .filter(|name| {
+                    !matches!(
+                        elem.borrow().lookup_property(name).property_type,
+                        crate::langtype::Type::Callback { .. }
+                            | crate::langtype::Type::Function { .. }
+                    )
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            for name in names {
+                let property = NamedReference::new(elem, &name);
+                let inputs = analysis.inputs_of_property(&property);
+                if inputs.is_empty() {
+                    continue;
+                }
+                let (cost, location) = {
+                    let binding = elem.borrow().bindings[&name].borrow().clone();
+                    (
+                        estimate_cost(&binding.expression),
+                        binding.span.as_ref().and_then(|s| s.file_line_column()),
+                    )
+                };
+                result.push(HotBinding {
+                    property,
+                    component: enclosing_component_name(elem),
+                    location,
+                    cost,
+                    inputs,
+                });
+            }
+        });
+    });
+    result.sort_by(|a, b| b.cost.cmp(&a.cost));
+    result
+}
+
+/// Writes the report of the [`hot_bindings()`] of the document, one binding per line.
+pub fn generate(doc: &Document, destination: &mut impl Write) -> std::io::Result<()> {
+    let bindings = hot_bindings(doc);
+    if bindings.is_empty() {
+        return writeln!(destination, "No binding depends on frequently changing inputs");
+    }
+    for binding in bindings {
+        writeln!(
+            destination,
+            "{}: {}::{:?}: estimated cost {}, changes with {}",
+            binding.location.as_deref().unwrap_or("<generated>"),
+            binding.component,
+            binding.property,
+            binding.cost,
+            binding.inputs.iter().map(String::as_str).collect::<Vec<_>>().join(", "),
+        )?;
+    }
+    Ok(())
+}
+
+fn enclosing_component_name(elem: &ElementRc) -> String {
+    elem.borrow().enclosing_component.upgrade().map_or_else(String::new, |c| c.id.clone())
+}
+
+/// The properties of the native items that change with the pointer or while scrolling
+fn native_input(elem: &ElementRc, name: &str) -> Option<&'static str> {
+    let native_class = elem.borrow().native_class()?;
+    match (native_class.class_name.as_str(), name) {
+        ("TouchArea", "mouse-x" | "mouse-y") => Some("pointer position"),
+        ("SwipeGestureHandler" | "DragArea", "current-position") => Some("pointer position"),
+        ("SwipeGestureHandler", "velocity") => Some("pointer position"),
+        ("PinchGestureHandler", "scale" | "rotation" | "center") => Some("pinch gesture"),
+        ("Flickable", "viewport-x" | "viewport-y") => Some("scroll position"),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct Analysis {
+    /// The frequently changing inputs of each property that was visited. A property is inserted
+    /// with no inputs before visiting its binding, so that binding loops terminate.
+    inputs: HashMap<NamedReference, BTreeSet<String>>,
+}
+
+impl Analysis {
+    fn inputs_of_property(&mut self, property: &NamedReference) -> BTreeSet<String> {
+        if let Some(inputs) = self.inputs.get(property) {
+            return inputs.clone();
+        }
+        self.inputs.insert(property.clone(), Default::default());
+
+        let mut inputs = BTreeSet::new();
+        let elem = property.element();
+        if let Some(input) = native_input(&elem, property.name()) {
+            inputs.insert(format!("{input} ({property:?})"));
+        }
+        let binding = elem.borrow().bindings.get(property.name()).map(|b| {
+            let b = b.borrow();
+            (b.expression.clone(), b.animation.is_some(), b.two_way_bindings.clone())
+        });
+        if let Some((mut expression, animated, two_way_bindings)) = binding {
+            if animated {
+                inputs.insert(format!("animation of {property:?}"));
+            }
+            expression.visit_recursive(&mut |e| {
+                if matches!(
+                    e,
+                    Expression::BuiltinFunctionReference(BuiltinFunction::AnimationTick, _)
+                ) {
+                    inputs.insert("animation-tick()".into());
+                }
+            });
+            let mut dependencies = two_way_bindings;
+            visit_named_references_in_expression(&mut expression, &mut |nr| {
+                dependencies.push(nr.clone())
+            });
+            for dependency in dependencies {
+                inputs.extend(self.inputs_of_property(&dependency));
+            }
+        }
+
+        self.inputs.insert(property.clone(), inputs.clone());
+        inputs
+    }
+}
+
+/// Estimates the cost of evaluating the expression once, by weighting the operations that
+/// allocate or that call into the run-time library more than the arithmetic.
+fn estimate_cost(expression: &Expression) -> usize {
+    let own_cost = match expression {
+        Expression::Invalid
+        | Expression::Uncompiled(_)
+        | Expression::StringLiteral(_)
+        | Expression::NumberLiteral(..)
+        | Expression::BoolLiteral(_)
+        | Expression::EnumerationValue(_)
+        | Expression::BuiltinFunctionReference(..)
+        | Expression::BuiltinMacroReference(..)
+        | Expression::ElementReference(_)
+        | Expression::EmptyComponentFactory => 0,
+        Expression::FunctionReference(..) | Expression::CallbackReference(..) => 0,
+        Expression::PropertyReference(_)
+        | Expression::RepeaterIndexReference { .. }
+        | Expression::RepeaterModelReference { .. }
+        | Expression::FunctionParameterReference { .. }
+        | Expression::ReadLocalVariable { .. }
+        | Expression::StoreLocalVariable { .. }
+        | Expression::LayoutCacheAccess { .. } => 1,
+        Expression::StructFieldAccess { .. }
+        | Expression::Cast { .. }
+        | Expression::CodeBlock(_)
+        | Expression::SelfAssignment { .. }
+        | Expression::BinaryExpression { .. }
+        | Expression::UnaryOp { .. }
+        | Expression::Condition { .. }
+        | Expression::MinMax { .. }
+        | Expression::ReturnStatement(_)
+        | Expression::MemberFunction { .. } => 1,
+        Expression::ArrayIndex { .. } => 5,
+        Expression::FunctionCall { .. } => 10,
+        Expression::Array { .. }
+        | Expression::Struct { .. }
+        | Expression::ImageReference { .. }
+        | Expression::PathData(_)
+        | Expression::EasingCurve(_)
+        | Expression::LinearGradient { .. }
+        | Expression::RadialGradient { .. }
+        | Expression::ConicGradient { .. } => 10,
+        Expression::ComputeLayoutInfo(..) => 50,
+        Expression::SolveLayout(..) => 100,
+        Expression::ModelAdapter { .. } => 100,
+    };
+    let mut cost = own_cost;
+    expression.visit(|sub| cost += estimate_cost(sub));
+    cost
+}
+
+#[test]
+fn test_hot_bindings() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        r#"
+export component Foo {
+    ta := TouchArea {}
+    out property <length> follow: ta.mouse-x * 2;
+    out property <string> label: "x: " + follow / 1px;
+    out property <float> pulse: sin(animation-tick() / 1s * 360deg);
+    out property <length> fixed: 42px;
+}
+"#
+        .into(),
+        Some(std::path::Path::new("HELLO")),
+        &mut test_diags,
+    );
+    let (doc, diag, _) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config));
+    assert!(!diag.has_errors());
+
+    let bindings = hot_bindings(&doc);
+    let find = |name: &str| bindings.iter().find(|b| b.property.name() == name);
+    let follow = find("follow").unwrap();
+    assert!(follow.inputs.iter().all(|input| input.starts_with("pointer position")));
+    let label = find("label").unwrap();
+    assert_eq!(label.inputs, follow.inputs);
+    assert!(label.cost > follow.cost);
+    assert_eq!(
+        find("pulse").unwrap().inputs.iter().collect::<Vec<_>>(),
+        [&"animation-tick()".to_string()]
+    );
+    assert!(find("fixed").is_none());
+}
@@ -0,0 +1,224 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+ Cache of the Rust code generated by `slint-build` and the `slint!` macro.
+
+ The compilation of the `.slint` files dominates the incremental builds of large projects: the
+ build script runs again when any of its inputs changes, and the macro runs again whenever the
+ crate that uses it is rebuilt. The cache keeps the generated code in the target directory of
+ Cargo, so that the compilation is skipped when nothing that it depends on changed.
+
+ An entry is found by a key that hashes the input of the compilation, the configuration of the
+ compiler, the version of Slint, and the `SLINT_*` environment variables. The entry records the
+ files that the compilation read, with a hash of their content, and is only used if none of them
+ changed. Set the `SLINT_NO_BUILD_CACHE` environment variable to disable the cache.
+*/
+
+use crate::CompilerConfiguration;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// The key of an entry of the [`BuildCache`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheKey(u64);
+
+/// The directory that holds the entries of the cache
+#[derive(Debug, Clone)]
+pub struct BuildCache {
+    directory: PathBuf,
+}
+
+impl BuildCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+
+    /// Returns the cache in the `slint-cache` directory of the target directory of Cargo, which is
+    /// found from the environment of the build script or of the crate that uses the macro.
+    /// Returns None if the target directory isn't found or if the cache is disabled.
+    pub fn from_cargo_env() -> Option<Self> {
+        if std::env::var_os("SLINT_NO_BUILD_CACHE").is_some() {
+            return None;
+        }
+        Some(Self::new(cargo_target_dir()?.join("slint-cache")))
+    }
+
+    /// Returns the key of the entry for the compilation of `input` with `config`.
+    ///
+    /// `input` describes what is compiled, such as the path of the main file or the code of the
+    /// macro. Returns None if the compilation can't be cached because the configuration has
+    /// callbacks.
+    pub fn key(input: &str, config: &CompilerConfiguration) -> Option<CacheKey> {
+        let mut hasher = DefaultHasher::new();
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        input.hash(&mut hasher);
+        config_fingerprint(config)?.hash(&mut hasher);
+        // The passes and the generator read some of the SLINT_* variables themselves
+        std::env::vars_os()
+            .filter(|(name, _)| {
+                name.to_str().map_or(false, |name| {
+                    name.starts_with("SLINT_")
+                        || name.starts_with("DEP_MCU_BOARD_SUPPORT_")
+                        || name == "CARGO_PKG_NAME"
+                })
+            })
+            .collect::<BTreeMap<_, _>>()
+            .hash(&mut hasher);
+        Some(CacheKey(hasher.finish()))
+    }
+
+    /// Returns the code of the entry and the files that it was generated from, if there is an
+    /// entry for `key` and none of these files changed since.
+    pub fn load(&self, key: CacheKey) -> Option<(String, Vec<PathBuf>)> {
+        let entry = std::fs::read_to_string(self.entry_path(key)).ok()?;
+        let (header, code) = entry.split_once("\n\n")?;
+        let mut dependencies = Vec::new();
+        for line in header.lines() {
+            let (hash, path) = line.split_once(' ')?;
+            let path = PathBuf::from(path);
+            if u64::from_str_radix(hash, 16).ok()? != file_hash(&path)? {
+                return None;
+            }
+            dependencies.push(path);
+        }
+        Some((code.into(), dependencies))
+    }
+
+    /// Stores the `code` that was generated from the files in `dependencies`.
+    ///
+    /// The cache is only an optimization, so the errors are ignored and the entry is just missing.
+    pub fn store(&self, key: CacheKey, code: &str, dependencies: &[PathBuf]) {
+        let mut entry = String::new();
+        for path in dependencies {
+            let (Some(hash), Some(path_str)) = (file_hash(path), path.to_str()) else { return };
+            if path_str.contains('\n') {
+                return;
+            }
+            entry += &format!("{hash:016x} {path_str}\n");
+        }
+        entry += "\n";
+        entry += code;
+
+        if std::fs::create_dir_all(&self.directory).is_err() {
+            return;
+        }
+        // Write to a temporary file first, so that concurrent builds never read a partial entry
+        let path = self.entry_path(key);
+        let temporary_path = path.with_extension(format!("tmp{}", std::process::id()));
+        if std::fs::write(&temporary_path, entry).is_err()
+            || std::fs::rename(&temporary_path, &path).is_err()
+        {
+            let _ = std::fs::remove_file(&temporary_path);
+        }
+    }
+
+    fn entry_path(&self, key: CacheKey) -> PathBuf {
+        self.directory.join(format!("{:016x}.rs", key.0))
+    }
+}
+
+/// Returns the target directory of Cargo, which contains the `CACHEDIR.TAG` file that Cargo
+/// creates in it.
+fn cargo_target_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os("CARGO_TARGET_DIR") {
+        return Some(dir.into());
+    }
+    let is_target_dir = |dir: &Path| dir.join("CACHEDIR.TAG").is_file();
+    // The OUT_DIR of the build scripts is inside the target directory
+    if let Some(out_dir) = std::env::var_os("OUT_DIR") {
+        if let Some(dir) = Path::new(&out_dir).ancestors().find(|dir| is_target_dir(dir)) {
+            return Some(dir.into());
+        }
+    }
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")?;
+    Path::new(&manifest_dir)
+        .ancestors()
+        .map(|dir| dir.join("target"))
+        .find(|dir| is_target_dir(dir))
+}
+
+fn file_hash(path: &Path) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    std::fs::read(path).ok()?.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Returns a description of all the settings of `config` that change the generated code, or None
+/// if it has callbacks, whose behavior can't be known.
+fn config_fingerprint(config: &CompilerConfiguration) -> Option<String> {
+    // Destructure all the fields, so that a new field can't be forgotten here
+    let CompilerConfiguration {
+        embed_resources,
+        include_paths,
+        library_paths,
+        variant_paths,
+        cfg_flags,
+        custom_item_manifests,
+        style,
+        open_import_fallback,
+        resource_url_mapper,
+        inline_all_elements,
+        const_scale_factor,
+        compress_textures,
+        glyph_selections,
+        accessibility,
+        enable_experimental,
+        translation_domain,
+        cpp_namespace,
+        debug_info,
+        components_to_generate,
+        #[cfg(feature = "software-renderer")]
+            font_cache: _,
+    } = config;
+    if open_import_fallback.is_some() || resource_url_mapper.is_some() {
+        return None;
+    }
+    Some(format!(
+        "{:?}",
+        (
+            embed_resources,
+            include_paths,
+            library_paths.iter().collect::<BTreeMap<_, _>>(),
+            variant_paths,
+            cfg_flags.iter().collect::<BTreeSet<_>>(),
+            custom_item_manifests,
+            style,
+            const_scale_factor,
+            glyph_selections,
+            (
+                inline_all_elements,
+                compress_textures,
+                accessibility,
+                enable_experimental,
+                debug_info
+            ),
+            (translation_domain, cpp_namespace),
+            components_to_generate,
+        )
+    ))
+}
+
+#[test]
+fn test_build_cache() {
+    let directory = std::env::temp_dir().join(format!("slint-build-cache-{}", std::process::id()));
+    let dependency = directory.join("input.slint");
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(&dependency, "export component A {}").unwrap();
+
+    let cache = BuildCache::new(directory.join("cache"));
+    let config = CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    let key = BuildCache::key("input", &config).unwrap();
+    assert_ne!(Some(key), BuildCache::key("other input", &config));
+    assert_eq!(cache.load(key), None);
+
+    cache.store(key, "generated\n\ncode", &[dependency.clone()]);
+    assert_eq!(cache.load(key), Some(("generated\n\ncode".into(), vec![dependency.clone()])));
+
+    std::fs::write(&dependency, "export component B {}").unwrap();
+    assert_eq!(cache.load(key), None);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
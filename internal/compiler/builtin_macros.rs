@@ -28,6 +28,7 @@ pub fn lower_macro(
         BuiltinMacroFunction::Mod => mod_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Abs => abs_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Debug => debug_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Format => format_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::CubicBezier => {
             let mut has_error = None;
             let expected_argument_type_error =
@@ -63,8 +64,103 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Spring => spring_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Steps => steps_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Sampled => sampled_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Hsv => hsv_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::ArrayFilter | BuiltinMacroFunction::ArraySortBy => {
+            unreachable!(
+                "filter() and sort-by() are resolved with their lambda when resolving the call"
+            )
+        }
+    }
+}
+
+fn spring_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 2 || args.len() > 3 {
+        diag.push_error(
+            "spring() needs two or three arguments: the stiffness, the damping, and the mass"
+                .into(),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut values = [0., 0., 1.];
+    for ((expr, n), value) in args.into_iter().zip(values.iter_mut()) {
+        match number_literal(&expr) {
+            Some(val) if val > 0. => *value = val as f32,
+            Some(_) => {
+                diag.push_error("Arguments to spring() must be positive".into(), &n);
+                return Expression::Invalid;
+            }
+            None => {
+                diag.push_error("Arguments to spring() must be number literals".into(), &n);
+                return Expression::Invalid;
+            }
+        }
+    }
+    let [stiffness, damping, mass] = values;
+    Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass))
+}
+
+fn steps_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() != 1 {
+        diag.push_error("steps() needs one argument: the number of steps".into(), &node);
+        return Expression::Invalid;
+    }
+    let (expr, n) = args.into_iter().next().unwrap();
+    match number_literal(&expr) {
+        Some(count) if count >= 1. && count.fract() == 0. && count <= u32::MAX as f64 => {
+            Expression::EasingCurve(EasingCurve::Steps(count as u32))
+        }
+        _ => {
+            diag.push_error(
+                "The argument to steps() must be a positive integer literal".into(),
+                &n,
+            );
+            Expression::Invalid
+        }
+    }
+}
+
+fn sampled_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 2 {
+        diag.push_error("sampled() needs at least two values".into(), &node);
+        return Expression::Invalid;
+    }
+    let mut samples = Vec::with_capacity(args.len());
+    for (expr, n) in args {
+        let Some(val) = number_literal(&expr) else {
+            diag.push_error("Arguments to sampled() must be number literals".into(), &n);
+            return Expression::Invalid;
+        };
+        samples.push(val as f32);
+    }
+    Expression::EasingCurve(EasingCurve::Sampled(samples))
+}
+
+/// Returns the value of a number literal without unit, which may be negative
+fn number_literal(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(val, Unit::None) => Some(*val),
+        Expression::UnaryOp { sub, op: '-' } => match **sub {
+            Expression::NumberLiteral(val, Unit::None) => Some(-val),
+            _ => None,
+        },
+        _ => None,
     }
 }
 
@@ -328,6 +424,275 @@ fn debug_macro(
     }
 }
 
+fn format_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    let mut args = args.into_iter();
+    let Some((format_string, format_node)) = args.next() else {
+        diag.push_error("format() needs a format string as first argument".into(), &node);
+        return Expression::Invalid;
+    };
+    let Expression::StringLiteral(format_string) = format_string else {
+        diag.push_error(
+            "The first argument of format() must be a string literal".into(),
+            &format_node,
+        );
+        return Expression::Invalid;
+    };
+    let pieces = match parse_format_string(&format_string) {
+        Ok(pieces) => pieces,
+        Err(message) => {
+            diag.push_error(message, &format_node);
+            return Expression::Invalid;
+        }
+    };
+
+    // The arguments are stored in local variables, so that they are evaluated once each
+    let args = args.collect::<Vec<_>>();
+    let counter = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let local_name = |index: usize| format!("format_arg{counter}_{index}");
+    let mut used = vec![false; args.len()];
+    let mut string = None;
+    for piece in pieces {
+        let value = match piece {
+            FormatPiece::Literal(literal) => Expression::StringLiteral(literal),
+            FormatPiece::Placeholder(placeholder) => {
+                let Some((expr, arg_node)) = args.get(placeholder.argument) else {
+                    diag.push_error(
+                        format!(
+                            "Not enough arguments for the format string, which refers to the argument {}",
+                            placeholder.argument
+                        ),
+                        &format_node,
+                    );
+                    return Expression::Invalid;
+                };
+                used[placeholder.argument] = true;
+                let value = Expression::ReadLocalVariable {
+                    name: local_name(placeholder.argument),
+                    ty: expr.ty(),
+                };
+                format_placeholder(value, &placeholder, arg_node.clone(), diag)
+            }
+        };
+        string = Some(match string {
+            None => value,
+            Some(string) => Expression::BinaryExpression {
+                lhs: Box::new(string),
+                op: '+',
+                rhs: Box::new(value),
+            },
+        });
+    }
+
+    let mut code = Vec::with_capacity(args.len() + 1);
+    for (index, ((expr, arg_node), used)) in args.into_iter().zip(used).enumerate() {
+        if !used {
+            diag.push_error("This argument isn't used in the format string".into(), &arg_node);
+        }
+        code.push(Expression::StoreLocalVariable {
+            name: local_name(index),
+            value: Box::new(expr),
+        });
+    }
+    code.push(string.unwrap_or_else(|| Expression::StringLiteral(String::new())));
+    if code.len() == 1 {
+        code.pop().unwrap()
+    } else {
+        Expression::CodeBlock(code)
+    }
+}
+
+/// A placeholder of the format string of `format()`, such as `{0:*>8.2}`
+struct FormatPlaceholder {
+    argument: usize,
+    fill: char,
+    /// The alignment argument of `PadString`, None for the default of the type
+    alignment: Option<i32>,
+    width: usize,
+    precision: Option<usize>,
+}
+
+enum FormatPiece {
+    Literal(String),
+    Placeholder(FormatPlaceholder),
+}
+
+fn parse_format_string(string: &str) -> Result<Vec<FormatPiece>, String> {
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut next_argument = 0;
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '}' => return Err("Unmatched '}' in the format string. Use '}}' to write a '}'".into()),
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => {
+                            return Err(
+                                "Unterminated placeholder in the format string. Use '{{' to write a '{'"
+                                    .into(),
+                            )
+                        }
+                    }
+                }
+                if !literal.is_empty() {
+                    pieces.push(FormatPiece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(FormatPiece::Placeholder(parse_format_placeholder(
+                    &placeholder,
+                    &mut next_argument,
+                )?));
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(FormatPiece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+/// Parses the inside of a placeholder: `[index][:[[fill]<|^|>][0][width][.precision]]`
+fn parse_format_placeholder(
+    placeholder: &str,
+    next_argument: &mut usize,
+) -> Result<FormatPlaceholder, String> {
+    let parse_number = |digits: &str| {
+        (!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| digits.parse::<usize>().ok())
+            .flatten()
+    };
+    let (index, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+    let argument = if index.is_empty() {
+        *next_argument += 1;
+        *next_argument - 1
+    } else {
+        parse_number(index)
+            .ok_or_else(|| format!("Invalid argument index '{index}' in the format string"))?
+    };
+
+    let invalid = || {
+        format!(
+            "Invalid format specifier '{spec}'. The syntax is [[fill]<|^|>][0][width][.precision]"
+        )
+    };
+    let alignment_of = |c: char| match c {
+        '<' => Some(0),
+        '^' => Some(1),
+        '>' => Some(2),
+        _ => None,
+    };
+    let mut fill = ' ';
+    let mut alignment = None;
+    let mut rest = spec;
+    let mut chars = spec.chars();
+    match (chars.next(), chars.next()) {
+        (Some(f), Some(a)) if alignment_of(a).is_some() => {
+            fill = f;
+            alignment = alignment_of(a);
+            rest = &spec[f.len_utf8() + 1..];
+        }
+        (Some(a), _) if alignment_of(a).is_some() => {
+            alignment = alignment_of(a);
+            rest = &spec[1..];
+        }
+        _ => {}
+    }
+    if let Some(r) = rest.strip_prefix('0') {
+        if r.starts_with(|c: char| c.is_ascii_digit()) {
+            // Like in Rust, the zero padding goes after the sign, regardless of the alignment
+            fill = '0';
+            alignment = Some(3);
+            rest = r;
+        }
+    }
+    let (width, precision) = rest.split_once('.').map_or((rest, None), |(w, p)| (w, Some(p)));
+    let width = if width.is_empty() { 0 } else { parse_number(width).ok_or_else(invalid)? };
+    let precision = precision.map(|p| parse_number(p).ok_or_else(invalid)).transpose()?;
+    Ok(FormatPlaceholder { argument, fill, alignment, width, precision })
+}
+
+fn format_placeholder(
+    value: Expression,
+    placeholder: &FormatPlaceholder,
+    node: Option<NodeOrToken>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    let ty = value.ty();
+    let is_number = matches!(
+        ty,
+        Type::Float32
+            | Type::Int32
+            | Type::Duration
+            | Type::PhysicalLength
+            | Type::LogicalLength
+            | Type::Rem
+            | Type::Angle
+            | Type::Percent
+            | Type::UnitProduct(_)
+    );
+    let string = if let Some(precision) = placeholder.precision {
+        if !is_number {
+            diag.push_error("Only numbers can be formatted with a precision".into(), &node);
+            return Expression::Invalid;
+        }
+        let digits = Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::FormatFixed,
+                None,
+            )),
+            arguments: vec![
+                Expression::Cast { from: Box::new(value), to: Type::Float32 },
+                Expression::NumberLiteral(precision as f64, Unit::None),
+            ],
+            source_location: None,
+        };
+        match ty.as_unit_product() {
+            Some(unit) if !matches!(ty, Type::Float32 | Type::Int32) => {
+                Expression::BinaryExpression {
+                    lhs: Box::new(digits),
+                    op: '+',
+                    rhs: Box::new(Expression::StringLiteral(Type::UnitProduct(unit).to_string())),
+                }
+            }
+            _ => digits,
+        }
+    } else {
+        to_debug_string(value, node, diag)
+    };
+    if placeholder.width == 0 {
+        return string;
+    }
+    Expression::FunctionCall {
+        function: Box::new(Expression::BuiltinFunctionReference(BuiltinFunction::PadString, None)),
+        arguments: vec![
+            string,
+            Expression::NumberLiteral(placeholder.width as f64, Unit::None),
+            Expression::StringLiteral(placeholder.fill.to_string()),
+            Expression::NumberLiteral(
+                placeholder.alignment.unwrap_or(if is_number { 2 } else { 0 }) as f64,
+                Unit::None,
+            ),
+        ],
+        source_location: None,
+    }
+}
+
 fn to_debug_string(
     expr: Expression,
     node: Option<NodeOrToken>,
@@ -346,7 +711,7 @@ fn to_debug_string(
         | Type::LayoutCache
         | Type::Model
         | Type::PathData => {
-            diag.push_error("Cannot debug this expression".into(), &node);
+            diag.push_error("Cannot convert this expression to a string".into(), &node);
             Expression::Invalid
         }
         Type::Float32 | Type::Int32 => expr.maybe_convert_to(Type::String, &node, diag),
@@ -192,6 +192,15 @@ pub struct SourceLocation {
     pub span: Span,
 }
 
+impl SourceLocation {
+    /// Returns `path:line:column` of the location, or None if it isn't in a source file
+    pub fn file_line_column(&self) -> Option<String> {
+        let source_file = self.source_file.as_ref()?;
+        let (line, column) = source_file.line_column(self.span.offset);
+        Some(format!("{}:{line}:{column}", source_file.path().display()))
+    }
+}
+
 impl Spanned for SourceLocation {
     fn span(&self) -> Span {
         self.span.clone()
@@ -370,7 +379,6 @@ pub fn is_empty(&self) -> bool {
     fn call_diagnostics<Output>(
         self,
         output: &mut Output,
-        mut handle_no_source: Option<&mut dyn FnMut(Diagnostic)>,
         emitter_factory: impl for<'b> FnOnce(
             &'b mut Output,
             Option<&'b codemap::CodeMap>,
@@ -386,16 +394,10 @@ fn call_diagnostics<Output>(
         let diags: Vec<_> = self
             .inner
             .into_iter()
-            .filter_map(|d| {
+            .map(|d| {
                 let spans = if !d.span.span.is_valid() {
                     vec![]
                 } else if let Some(sf) = &d.span.source_file {
-                    if let Some(ref mut handle_no_source) = handle_no_source {
-                        if sf.source.is_none() {
-                            handle_no_source(d);
-                            return None;
-                        }
-                    }
                     let path: String = sf.path.to_string_lossy().into();
                     let file = codemap_files.entry(path).or_insert_with(|| {
                         codemap.add_file(
@@ -414,12 +416,12 @@ fn call_diagnostics<Output>(
                 } else {
                     vec![]
                 };
-                Some(codemap_diagnostic::Diagnostic {
+                codemap_diagnostic::Diagnostic {
                     level: d.level.into(),
                     message: d.message,
                     code: None,
                     spans,
-                })
+                }
             })
             .collect();
 
@@ -432,7 +434,7 @@ fn call_diagnostics<Output>(
     #[cfg(feature = "display-diagnostics")]
     /// Print the diagnostics on the console
     pub fn print(self) {
-        self.call_diagnostics(&mut (), None, |_, codemap| {
+        self.call_diagnostics(&mut (), |_, codemap| {
             codemap_diagnostic::Emitter::stderr(codemap_diagnostic::ColorConfig::Always, codemap)
         });
     }
@@ -441,7 +443,7 @@ pub fn print(self) {
     /// Print into a string
     pub fn diagnostics_as_string(self) -> String {
         let mut output = Vec::new();
-        self.call_diagnostics(&mut output, None, |output, codemap| {
+        self.call_diagnostics(&mut output, |output, codemap| {
             codemap_diagnostic::Emitter::vec(output, codemap)
         });
 
@@ -458,49 +460,59 @@ pub fn report_macro_diagnostic(
     ) -> proc_macro::TokenStream {
         let mut result = proc_macro::TokenStream::default();
         let mut needs_error = self.has_errors();
-        self.call_diagnostics(
-            &mut (),
-            Some(&mut |diag| {
-                let span = diag.span.span.span.or_else(|| {
-                    //let pos =
-                    //span_map.binary_search_by_key(d.span.offset, |x| x.0).unwrap_or_else(|x| x);
-                    //d.span.span = span_map.get(pos).as_ref().map(|x| x.1);
-                    let mut offset = 0;
-                    span_map.iter().find_map(|t| {
-                        if diag.span.span.offset <= offset {
-                            t.span
-                        } else {
-                            offset += t.text.len();
-                            None
-                        }
-                    })
-                });
-                let message = &diag.message;
-                match diag.level {
-                    DiagnosticLevel::Error => {
-                        needs_error = false;
-                        result.extend(proc_macro::TokenStream::from(if let Some(span) = span {
-                            quote::quote_spanned!(span.into()=> compile_error!{ #message })
-                        } else {
-                            quote::quote!(compile_error! { #message })
-                        }));
-                    }
-                    DiagnosticLevel::Warning => {
-                        result.extend(proc_macro::TokenStream::from(if let Some(span) = span {
-                            quote::quote_spanned!(span.into()=> const _ : () = { #[deprecated(note = #message)] const WARNING: () = (); WARNING };)
-                        } else {
-                            quote::quote!(const _ : () = { #[deprecated(note = #message)] const WARNING: () = (); WARNING };)
-                        }));
-                    },
+        for diag in self.inner {
+            let (span, message) = match &diag.span.source_file {
+                // Diagnostics in the code of the macro itself
+                Some(sf) if sf.source.is_none() => {
+                    let span = diag.span.span.span.or_else(|| {
+                        let mut offset = 0;
+                        span_map.iter().find_map(|t| {
+                            if diag.span.span.offset <= offset {
+                                t.span
+                            } else {
+                                offset += t.text.len();
+                                None
+                            }
+                        })
+                    });
+                    (span, diag.message.clone())
                 }
-            }),
-            |_, codemap| {
-                codemap_diagnostic::Emitter::stderr(
-                    codemap_diagnostic::ColorConfig::Always,
-                    codemap,
-                )
-            },
-        );
+                // Diagnostics in imported files are reported on the import that refers to the file,
+                // with the location within the file in the message
+                Some(sf) => {
+                    let span = span_map
+                        .iter()
+                        .filter(|t| t.kind == crate::parser::SyntaxKind::StringLiteral)
+                        .find_map(|t| {
+                            let path = t.text.trim_matches('"').trim_start_matches("./");
+                            (!path.is_empty() && sf.path.ends_with(path))
+                                .then_some(t.span)
+                                .flatten()
+                        });
+                    let (line, column) = diag.line_column();
+                    (span, format!("{}:{line}:{column}: {}", sf.path.display(), diag.message))
+                }
+                None => (None, diag.message.clone()),
+            };
+            let message = &message;
+            match diag.level {
+                DiagnosticLevel::Error => {
+                    needs_error = false;
+                    result.extend(proc_macro::TokenStream::from(if let Some(span) = span {
+                        quote::quote_spanned!(span.into()=> compile_error!{ #message })
+                    } else {
+                        quote::quote!(compile_error! { #message })
+                    }));
+                }
+                DiagnosticLevel::Warning => {
+                    result.extend(proc_macro::TokenStream::from(if let Some(span) = span {
+                        quote::quote_spanned!(span.into()=> const _ : () = { #[deprecated(note = #message)] const WARNING: () = (); WARNING };)
+                    } else {
+                        quote::quote!(const _ : () = { #[deprecated(note = #message)] const WARNING: () = (); WARNING };)
+                    }));
+                }
+            }
+        }
         if needs_error {
             result.extend(proc_macro::TokenStream::from(quote::quote!(
                 compile_error! { "Error occurred" }
@@ -22,6 +22,15 @@ pub enum PixelFormat {
     AlphaMap([u8; 3]),
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, strum::Display)]
+pub enum TextureCompression {
+    // The rows of pixels follow each other
+    #[default]
+    None,
+    // The offset of each row as little-endian u32, followed by the run-length encoded rows
+    Rle,
+}
+
 #[cfg(feature = "software-renderer")]
 #[derive(Debug, Clone)]
 pub struct Texture {
@@ -30,6 +39,7 @@ pub struct Texture {
     pub rect: Rect,
     pub data: Vec<u8>,
     pub format: PixelFormat,
+    pub compression: TextureCompression,
 }
 
 #[cfg(feature = "software-renderer")]
@@ -41,6 +51,7 @@ pub fn new_empty() -> Self {
             rect: Rect::from_xywh(0, 0, 1, 1).unwrap(),
             data: vec![0, 0, 0, 0],
             format: PixelFormat::Rgba,
+            compression: TextureCompression::None,
         }
     }
 }
@@ -85,6 +96,160 @@ pub struct BitmapFont {
     pub italic: bool,
 }
 
+/// Glyphs to pre-render when embedding the fonts for the software renderer, in addition to the
+/// characters of the string literals and the sizes of the `font-size` bindings.
+///
+/// It's parsed from a comma separated list of code points such as `U+20AC`, ranges of code
+/// points such as `U+0400-U+04FF`, and pixel sizes such as `18px`, optionally preceded by the
+/// family name of the font and `=`, for example `Noto Sans=U+0400-U+04FF,U+20AC,18px`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GlyphSelection {
+    /// The family name of the fonts it applies to, or `None` for all the embedded fonts
+    pub family: Option<String>,
+    /// The ranges of code points to embed. A single code point is a range of one.
+    pub ranges: Vec<std::ops::RangeInclusive<char>>,
+    /// The sizes to render the glyphs at, in logical pixels
+    pub pixel_sizes: Vec<f64>,
+}
+
+impl GlyphSelection {
+    /// Returns true if the selection applies to the fonts of the given family
+    pub fn matches_family(&self, family: &str) -> bool {
+        self.family.as_ref().map_or(true, |f| f.eq_ignore_ascii_case(family))
+    }
+
+    /// Returns the selected characters
+    pub fn characters(&self) -> impl Iterator<Item = char> + '_ {
+        self.ranges.iter().flat_map(|range| range.clone())
+    }
+}
+
+impl std::str::FromStr for GlyphSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (family, items) = match s.split_once('=') {
+            Some((family, items)) => (Some(family.trim()).filter(|f| *f != "*"), items),
+            None => (None, s),
+        };
+        let mut selection =
+            GlyphSelection { family: family.map(String::from), ..Default::default() };
+        let code_point = |item: &str| {
+            let hex = item.strip_prefix("U+").or_else(|| item.strip_prefix("u+")).unwrap_or(item);
+            u32::from_str_radix(hex, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("Invalid code point '{item}'"))
+        };
+        for item in items.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            if let Some(size) = item.strip_suffix("px") {
+                let size = size
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|size| *size > 0.)
+                    .ok_or_else(|| format!("Invalid pixel size '{item}'"))?;
+                selection.pixel_sizes.push(size);
+            } else if let Some((start, end)) = item.split_once('-') {
+                let (start, end) = (code_point(start.trim())?, code_point(end.trim())?);
+                if start > end {
+                    return Err(format!("Invalid range of code points '{item}'"));
+                }
+                selection.ranges.push(start..=end);
+            } else {
+                let c = code_point(item)?;
+                selection.ranges.push(c..=c);
+            }
+        }
+        Ok(selection)
+    }
+}
+
+/// Formats the characters as a list of code points and ranges of code points, such as
+/// `U+0041-U+005A, U+20AC`
+pub fn format_code_points(characters: impl IntoIterator<Item = char>) -> String {
+    let mut characters = characters.into_iter().collect::<Vec<_>>();
+    characters.sort_unstable();
+    characters.dedup();
+    let mut ranges = Vec::<std::ops::RangeInclusive<char>>::new();
+    for c in characters {
+        match ranges.last_mut() {
+            Some(range) if *range.end() as u32 + 1 == c as u32 => *range = *range.start()..=c,
+            _ => ranges.push(c..=c),
+        }
+    }
+    ranges
+        .iter()
+        .map(|range| {
+            if range.start() == range.end() {
+                format!("U+{:04X}", *range.start() as u32)
+            } else {
+                format!("U+{:04X}-U+{:04X}", *range.start() as u32, *range.end() as u32)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Writes the family, the pixel sizes, and the code points of the glyphs of each font that is
+/// embedded for the software renderer.
+#[cfg(feature = "software-renderer")]
+pub fn write_glyph_report(
+    doc: &crate::object_tree::Document,
+    out: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    let resources = doc.embedded_file_resources.borrow();
+    let mut fonts = resources
+        .values()
+        .filter_map(|resource| match &resource.kind {
+            EmbeddedResourcesKind::BitmapFontData(font) => Some((resource.id, font)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if fonts.is_empty() {
+        return writeln!(out, "No font is embedded");
+    }
+    fonts.sort_by_key(|(id, _)| *id);
+    for (_, font) in fonts {
+        let style = if font.italic { ", italic" } else { "" };
+        let sizes = font
+            .glyphs
+            .iter()
+            .map(|glyphs| format!("{}px", glyphs.pixel_size))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(out, "{} (weight {}{style}): {sizes}", font.family_name, font.weight)?;
+        writeln!(
+            out,
+            "    {} glyphs: {}",
+            font.character_map.len(),
+            format_code_points(font.character_map.iter().map(|entry| entry.code_point))
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_glyph_selection() {
+    let selection = "Noto Sans = U+0400-U+04FF, U+20AC, 18px".parse::<GlyphSelection>().unwrap();
+    assert_eq!(selection.family.as_deref(), Some("Noto Sans"));
+    assert_eq!(selection.ranges, vec!['\u{400}'..='\u{4ff}', '€'..='€']);
+    assert_eq!(selection.pixel_sizes, vec![18.]);
+    assert!(selection.matches_family("noto sans"));
+    assert!(!selection.matches_family("DejaVu Sans"));
+
+    let selection = "*=U+41-5A".parse::<GlyphSelection>().unwrap();
+    assert_eq!(selection.family, None);
+    assert_eq!(
+        format_code_points(selection.characters().chain(['€', '_'])),
+        "U+0041-U+005A, U+005F, U+20AC"
+    );
+
+    assert!("U+0400-U+03FF".parse::<GlyphSelection>().is_err());
+    assert!("U+D800".parse::<GlyphSelection>().is_err());
+    assert!("0px".parse::<GlyphSelection>().is_err());
+}
+
 #[derive(Debug, Clone)]
 pub enum EmbeddedResourcesKind {
     /// Just put the file content as a resource
@@ -21,6 +21,8 @@
 pub enum BuiltinFunction {
     GetWindowScaleFactor,
     GetWindowDefaultFontSize,
+    GetWindowWidth,
+    GetWindowHeight,
     AnimationTick,
     Debug,
     Mod,
@@ -43,6 +45,8 @@ pub enum BuiltinFunction {
     ShowPopupWindow,
     ClosePopupWindow,
     SetSelectionOffsets,
+    /// TextInput's move-selection-handle(anchor, x, y)
+    MoveSelectionHandle,
     /// A function that belongs to an item (such as TextInput's select-all function).
     ItemMemberFunction(String),
     ItemFontMetrics,
@@ -50,6 +54,17 @@ pub enum BuiltinFunction {
     StringToFloat,
     /// the "42".is_float()
     StringIsFloat,
+    /// The `{:.2}` specifier of `format()`: (value, precision)
+    FormatFixed,
+    /// The `{:>5}` specifier of `format()`: (text, width, fill, alignment), see
+    /// `i_slint_core::string::pad_string()`
+    PadString,
+    /// "text".matches(pattern)
+    RegexMatches,
+    /// "text".replace(pattern, replacement)
+    RegexReplace,
+    /// "text".capture(pattern, group)
+    RegexCapture,
     ColorRgbaStruct,
     ColorHsvaStruct,
     ColorBrighter,
@@ -58,21 +73,43 @@ pub enum BuiltinFunction {
     ColorMix,
     ColorWithAlpha,
     ImageSize,
+    /// The image of the `source-url` of an `Image`: (url, fallback)
+    ImageFromUrl,
+    ImageUrlLoading,
+    ImageUrlError,
     ArrayLength,
     Rgb,
     Hsv,
     ColorScheme,
+    InteractionMode,
+    /// The value of a design token of the active theme: (name, default)
+    ThemeBrush,
+    ThemeLength,
+    ThemeString,
     Use24HourFormat,
+    TranslationLanguageMatches,
     MonthDayCount,
     MonthOffset,
     FormatDate,
     DateNow,
     ValidDate,
     ParseDate,
+    /// The functions of the `Locale` namespace
+    LocaleFormatNumber,
+    LocaleParseNumber,
+    LocaleFormatDate,
+    LocaleParseDate,
+    LocaleFormatTime,
+    LocaleParseTime,
     TextInputFocused,
     SetTextInputFocused,
     ImplicitLayoutInfo(Orientation),
     ItemAbsolutePosition,
+    ItemIsVisible,
+    /// Whether the property that is the argument is running an animation
+    PropertyIsAnimating,
+    /// Reports the failure of an `assert` statement, with the message that is the argument
+    AssertionFailed,
     RegisterCustomFontByPath,
     RegisterCustomFontByMemory,
     RegisterBitmapFont,
@@ -98,12 +135,26 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Abs,
     CubicBezier,
+    /// The arguments are the stiffness, the damping, and optionally the mass of the spring,
+    /// which defaults to 1
+    Spring,
+    /// The argument is the number of steps
+    Steps,
+    /// The arguments are the values of the curve at equally spaced points
+    Sampled,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
     Hsv,
     /// transform `debug(a, b, c)` into debug `a + " " + b + " " + c`
     Debug,
+    /// transform `format("{} of {}", a, b)` into `a + " of " + b`, with the placeholders
+    /// formatted like in `debug()`, and padded or rounded according to their specifiers
+    Format,
+    /// `array.filter(item => condition)`, resolved into a [`Expression::ModelAdapter`]
+    ArrayFilter,
+    /// `array.sort-by(item => key)`, resolved into a [`Expression::ModelAdapter`]
+    ArraySortBy,
 }
 
 impl BuiltinFunction {
@@ -113,7 +164,9 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::UnitProduct(vec![(Unit::Phx, 1), (Unit::Px, -1)])),
                 args: vec![],
             },
-            BuiltinFunction::GetWindowDefaultFontSize => {
+            BuiltinFunction::GetWindowDefaultFontSize
+            | BuiltinFunction::GetWindowWidth
+            | BuiltinFunction::GetWindowHeight => {
                 Type::Function { return_type: Box::new(Type::LogicalLength), args: vec![] }
             }
             BuiltinFunction::AnimationTick => {
@@ -164,6 +217,15 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference, Type::Int32, Type::Int32],
             },
+            BuiltinFunction::MoveSelectionHandle => Type::Function {
+                return_type: Box::new(Type::Void),
+                args: vec![
+                    Type::ElementReference,
+                    Type::Bool,
+                    Type::LogicalLength,
+                    Type::LogicalLength,
+                ],
+            },
             BuiltinFunction::ItemMemberFunction(..) => Type::Function {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference],
@@ -178,6 +240,26 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::StringIsFloat => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![Type::String] }
             }
+            BuiltinFunction::FormatFixed => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Float32, Type::Int32],
+            },
+            BuiltinFunction::PadString => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::Int32, Type::String, Type::Int32],
+            },
+            BuiltinFunction::RegexMatches => Type::Function {
+                return_type: Box::new(Type::Bool),
+                args: vec![Type::String, Type::String],
+            },
+            BuiltinFunction::RegexReplace => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::String, Type::String],
+            },
+            BuiltinFunction::RegexCapture => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::String, Type::Int32],
+            },
             BuiltinFunction::ImplicitLayoutInfo(_) => Type::Function {
                 return_type: Box::new(crate::layout::layout_info_type()),
                 args: vec![Type::ElementReference],
@@ -194,6 +276,7 @@ pub fn ty(&self) -> Type {
                     name: Some("Color".into()),
                     node: None,
                     rust_attributes: None,
+                    functions: None,
                 }),
                 args: vec![Type::Color],
             },
@@ -209,6 +292,7 @@ pub fn ty(&self) -> Type {
                     name: Some("Color".into()),
                     node: None,
                     rust_attributes: None,
+                    functions: None,
                 }),
                 args: vec![Type::Color],
             },
@@ -242,9 +326,17 @@ pub fn ty(&self) -> Type {
                     name: Some("Size".to_string()),
                     node: None,
                     rust_attributes: None,
+                    functions: None,
                 }),
                 args: vec![Type::Image],
             },
+            BuiltinFunction::ImageFromUrl => Type::Function {
+                return_type: Box::new(Type::Image),
+                args: vec![Type::String, Type::Image],
+            },
+            BuiltinFunction::ImageUrlLoading | BuiltinFunction::ImageUrlError => {
+                Type::Function { return_type: Box::new(Type::Bool), args: vec![Type::String] }
+            }
             BuiltinFunction::ArrayLength => {
                 Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::Model] }
             }
@@ -262,6 +354,24 @@ pub fn ty(&self) -> Type {
                 )),
                 args: vec![],
             },
+            BuiltinFunction::InteractionMode => Type::Function {
+                return_type: Box::new(Type::Enumeration(
+                    crate::typeregister::BUILTIN_ENUMS.with(|e| e.InteractionMode.clone()),
+                )),
+                args: vec![],
+            },
+            BuiltinFunction::ThemeBrush => Type::Function {
+                return_type: Box::new(Type::Brush),
+                args: vec![Type::String, Type::Brush],
+            },
+            BuiltinFunction::ThemeLength => Type::Function {
+                return_type: Box::new(Type::LogicalLength),
+                args: vec![Type::String, Type::LogicalLength],
+            },
+            BuiltinFunction::ThemeString => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::String],
+            },
             BuiltinFunction::MonthDayCount => Type::Function {
                 return_type: Box::new(Type::Int32),
                 args: vec![Type::Int32, Type::Int32],
@@ -289,6 +399,50 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Array(Box::new(Type::Int32))),
                 args: vec![Type::String, Type::String],
             },
+            BuiltinFunction::LocaleFormatNumber => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Float32, Type::Int32],
+            },
+            BuiltinFunction::LocaleParseNumber => {
+                Type::Function { return_type: Box::new(Type::Float32), args: vec![Type::String] }
+            }
+            BuiltinFunction::LocaleFormatDate => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Int32, Type::Int32, Type::Int32],
+            },
+            BuiltinFunction::LocaleParseDate => Type::Function {
+                return_type: Box::new(Type::Struct {
+                    fields: IntoIterator::into_iter([
+                        ("day".to_string(), Type::Int32),
+                        ("month".to_string(), Type::Int32),
+                        ("year".to_string(), Type::Int32),
+                    ])
+                    .collect(),
+                    name: None,
+                    node: None,
+                    rust_attributes: None,
+                    functions: None,
+                }),
+                args: vec![Type::String],
+            },
+            BuiltinFunction::LocaleFormatTime => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Int32, Type::Int32],
+            },
+            BuiltinFunction::LocaleParseTime => Type::Function {
+                return_type: Box::new(Type::Struct {
+                    fields: IntoIterator::into_iter([
+                        ("hour".to_string(), Type::Int32),
+                        ("minute".to_string(), Type::Int32),
+                    ])
+                    .collect(),
+                    name: None,
+                    node: None,
+                    rust_attributes: None,
+                    functions: None,
+                }),
+                args: vec![Type::String],
+            },
             BuiltinFunction::SetTextInputFocused => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![Type::Bool] }
             }
@@ -296,6 +450,16 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(crate::typeregister::logical_point_type()),
                 args: vec![Type::ElementReference],
             },
+            BuiltinFunction::ItemIsVisible => Type::Function {
+                return_type: Box::new(Type::Bool),
+                args: vec![Type::ElementReference],
+            },
+            BuiltinFunction::PropertyIsAnimating => {
+                Type::Function { return_type: Box::new(Type::Bool), args: vec![Type::Invalid] }
+            }
+            BuiltinFunction::AssertionFailed => {
+                Type::Function { return_type: Box::new(Type::Void), args: vec![Type::String] }
+            }
             BuiltinFunction::RegisterCustomFontByPath => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![Type::String] }
             }
@@ -318,6 +482,9 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::Use24HourFormat => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![] }
             }
+            BuiltinFunction::TranslationLanguageMatches => {
+                Type::Function { return_type: Box::new(Type::Bool), args: vec![Type::String] }
+            }
             BuiltinFunction::UpdateTimers => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![] }
             }
@@ -329,14 +496,27 @@ fn is_const(&self) -> bool {
         match self {
             BuiltinFunction::GetWindowScaleFactor => false,
             BuiltinFunction::GetWindowDefaultFontSize => false,
+            BuiltinFunction::GetWindowWidth | BuiltinFunction::GetWindowHeight => false,
             BuiltinFunction::AnimationTick => false,
             BuiltinFunction::ColorScheme => false,
+            BuiltinFunction::InteractionMode => false,
+            // The theme can be replaced at run-time
+            BuiltinFunction::ThemeBrush
+            | BuiltinFunction::ThemeLength
+            | BuiltinFunction::ThemeString => false,
             BuiltinFunction::MonthDayCount => false,
             BuiltinFunction::MonthOffset => false,
             BuiltinFunction::FormatDate => false,
             BuiltinFunction::DateNow => false,
             BuiltinFunction::ValidDate => false,
             BuiltinFunction::ParseDate => false,
+            // The locale changes with the language of the translations
+            BuiltinFunction::LocaleFormatNumber
+            | BuiltinFunction::LocaleParseNumber
+            | BuiltinFunction::LocaleFormatDate
+            | BuiltinFunction::LocaleParseDate
+            | BuiltinFunction::LocaleFormatTime
+            | BuiltinFunction::LocaleParseTime => false,
             // Even if it is not pure, we optimize it away anyway
             BuiltinFunction::Debug => true,
             BuiltinFunction::Mod
@@ -356,10 +536,14 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::ATan2 => true,
             BuiltinFunction::SetFocusItem | BuiltinFunction::ClearFocusItem => false,
             BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => false,
-            BuiltinFunction::SetSelectionOffsets => false,
+            BuiltinFunction::SetSelectionOffsets | BuiltinFunction::MoveSelectionHandle => false,
             BuiltinFunction::ItemMemberFunction(..) => false,
             BuiltinFunction::ItemFontMetrics => false, // depends also on Window's font properties
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
+            BuiltinFunction::FormatFixed | BuiltinFunction::PadString => true,
+            BuiltinFunction::RegexMatches
+            | BuiltinFunction::RegexReplace
+            | BuiltinFunction::RegexCapture => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
             | BuiltinFunction::ColorBrighter
@@ -375,6 +559,10 @@ fn is_const(&self) -> bool {
             BuiltinFunction::ImageSize => true,
             #[cfg(target_arch = "wasm32")]
             BuiltinFunction::ImageSize => false,
+            // The image is loaded asynchronously
+            BuiltinFunction::ImageFromUrl
+            | BuiltinFunction::ImageUrlLoading
+            | BuiltinFunction::ImageUrlError => false,
             BuiltinFunction::ArrayLength => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::Hsv => true,
@@ -382,11 +570,15 @@ fn is_const(&self) -> bool {
             BuiltinFunction::TextInputFocused => false,
             BuiltinFunction::ImplicitLayoutInfo(_) => false,
             BuiltinFunction::ItemAbsolutePosition => true,
+            BuiltinFunction::ItemIsVisible => false,
+            BuiltinFunction::PropertyIsAnimating => false,
+            BuiltinFunction::AssertionFailed => false,
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
             BuiltinFunction::Translate => false,
             BuiltinFunction::Use24HourFormat => false,
+            BuiltinFunction::TranslationLanguageMatches => false,
             BuiltinFunction::UpdateTimers => false,
         }
     }
@@ -396,14 +588,25 @@ pub fn is_pure(&self) -> bool {
         match self {
             BuiltinFunction::GetWindowScaleFactor => true,
             BuiltinFunction::GetWindowDefaultFontSize => true,
+            BuiltinFunction::GetWindowWidth | BuiltinFunction::GetWindowHeight => true,
             BuiltinFunction::AnimationTick => true,
             BuiltinFunction::ColorScheme => true,
+            BuiltinFunction::InteractionMode => true,
+            BuiltinFunction::ThemeBrush
+            | BuiltinFunction::ThemeLength
+            | BuiltinFunction::ThemeString => true,
             BuiltinFunction::MonthDayCount => true,
             BuiltinFunction::MonthOffset => true,
             BuiltinFunction::FormatDate => true,
             BuiltinFunction::DateNow => true,
             BuiltinFunction::ValidDate => true,
             BuiltinFunction::ParseDate => true,
+            BuiltinFunction::LocaleFormatNumber
+            | BuiltinFunction::LocaleParseNumber
+            | BuiltinFunction::LocaleFormatDate
+            | BuiltinFunction::LocaleParseDate
+            | BuiltinFunction::LocaleFormatTime
+            | BuiltinFunction::LocaleParseTime => true,
             // Even if it has technically side effect, we still consider it as pure for our purpose
             BuiltinFunction::Debug => true,
             BuiltinFunction::Mod
@@ -423,10 +626,14 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ATan2 => true,
             BuiltinFunction::SetFocusItem | BuiltinFunction::ClearFocusItem => false,
             BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => false,
-            BuiltinFunction::SetSelectionOffsets => false,
+            BuiltinFunction::SetSelectionOffsets | BuiltinFunction::MoveSelectionHandle => false,
             BuiltinFunction::ItemMemberFunction(..) => false,
             BuiltinFunction::ItemFontMetrics => true,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
+            BuiltinFunction::FormatFixed | BuiltinFunction::PadString => true,
+            BuiltinFunction::RegexMatches
+            | BuiltinFunction::RegexReplace
+            | BuiltinFunction::RegexCapture => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
             | BuiltinFunction::ColorBrighter
@@ -435,11 +642,17 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ColorMix
             | BuiltinFunction::ColorWithAlpha => true,
             BuiltinFunction::ImageSize => true,
+            BuiltinFunction::ImageFromUrl
+            | BuiltinFunction::ImageUrlLoading
+            | BuiltinFunction::ImageUrlError => true,
             BuiltinFunction::ArrayLength => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::Hsv => true,
             BuiltinFunction::ImplicitLayoutInfo(_) => true,
             BuiltinFunction::ItemAbsolutePosition => true,
+            BuiltinFunction::ItemIsVisible => true,
+            BuiltinFunction::PropertyIsAnimating => true,
+            BuiltinFunction::AssertionFailed => false,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => true,
             BuiltinFunction::RegisterCustomFontByPath
@@ -447,6 +660,7 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::RegisterBitmapFont => false,
             BuiltinFunction::Translate => true,
             BuiltinFunction::Use24HourFormat => true,
+            BuiltinFunction::TranslationLanguageMatches => true,
             BuiltinFunction::UpdateTimers => false,
         }
     }
@@ -534,6 +748,10 @@ pub fn normalize(self, x: f64) -> f64 {
     Pt = "pt" -> LogicalLength * 96./72.,
     /// Logical pixels multiplied with the window's default-font-size
     Rem = "rem" -> Rem,
+    /// Percent of the width of the window. Replaced by a multiplication with the width when resolving.
+    Vw = "vw" -> LogicalLength,
+    /// Percent of the height of the window. Replaced by a multiplication with the height when resolving.
+    Vh = "vh" -> LogicalLength,
 
     // durations
 
@@ -566,6 +784,14 @@ pub enum MinMaxOp {
     Max,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelAdapterKind {
+    /// Only keep the rows for which the expression is true
+    Filter,
+    /// Sort the rows by the value of the expression, in ascending order
+    SortBy,
+}
+
 /// The Expression is hold by properties, so it should not hold any strong references to node from the object_tree
 #[derive(Debug, Clone, Default)]
 pub enum Expression {
@@ -728,11 +954,20 @@ pub enum Expression {
         angle: Box<Expression>,
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
+        spread: GradientSpread,
     },
 
     RadialGradient {
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
+        spread: GradientSpread,
+    },
+
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position
+        /// as a fraction of a turn
+        stops: Vec<(Expression, Expression)>,
     },
 
     EnumerationValue(EnumerationValue),
@@ -759,6 +994,19 @@ pub enum Expression {
     },
 
     EmptyComponentFactory,
+
+    /// A model that filters or sorts the rows of another model, such as `model.filter(item => item.checked)`
+    ///
+    /// The expression is evaluated for each row with the row in the local variable `variable`.
+    /// It may only read that variable, and the `captures`: local variables that are evaluated
+    /// once, before the model is created, from the sub-expressions that don't depend on the row.
+    ModelAdapter {
+        kind: ModelAdapterKind,
+        model: Box<Expression>,
+        captures: Vec<(String, Expression)>,
+        variable: String,
+        expression: Box<Expression>,
+    },
 }
 
 impl Expression {
@@ -879,6 +1127,7 @@ pub fn ty(&self) -> Type {
             Expression::EasingCurve(_) => Type::Easing,
             Expression::LinearGradient { .. } => Type::Brush,
             Expression::RadialGradient { .. } => Type::Brush,
+            Expression::ConicGradient { .. } => Type::Brush,
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
@@ -887,6 +1136,7 @@ pub fn ty(&self) -> Type {
             Expression::SolveLayout(..) => Type::LayoutCache,
             Expression::MinMax { ty, .. } => ty.clone(),
             Expression::EmptyComponentFactory => Type::ComponentFactory,
+            Expression::ModelAdapter { model, .. } => model.ty(),
         }
     }
 
@@ -963,14 +1213,21 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::StoreLocalVariable { value, .. } => visitor(value),
             Expression::ReadLocalVariable { .. } => {}
             Expression::EasingCurve(_) => {}
-            Expression::LinearGradient { angle, stops } => {
+            Expression::LinearGradient { angle, stops, .. } => {
                 visitor(angle);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { stops, .. } => {
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
+            Expression::ConicGradient { from_angle, stops } => {
+                visitor(from_angle);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
@@ -990,6 +1247,11 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                 visitor(rhs);
             }
             Expression::EmptyComponentFactory => {}
+            Expression::ModelAdapter { model, captures, expression, .. } => {
+                visitor(model);
+                captures.iter().for_each(|(_, capture)| visitor(capture));
+                visitor(expression);
+            }
         }
     }
 
@@ -1068,14 +1330,21 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::StoreLocalVariable { value, .. } => visitor(value),
             Expression::ReadLocalVariable { .. } => {}
             Expression::EasingCurve(_) => {}
-            Expression::LinearGradient { angle, stops } => {
+            Expression::LinearGradient { angle, stops, .. } => {
                 visitor(angle);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { stops, .. } => {
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
+            Expression::ConicGradient { from_angle, stops } => {
+                visitor(from_angle);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
@@ -1095,6 +1364,11 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                 visitor(rhs);
             }
             Expression::EmptyComponentFactory => {}
+            Expression::ModelAdapter { model, captures, expression, .. } => {
+                visitor(model);
+                captures.iter_mut().for_each(|(_, capture)| visitor(capture));
+                visitor(expression);
+            }
         }
     }
 
@@ -1158,12 +1432,16 @@ pub fn is_constant(&self) -> bool {
             // we should somehow find out if this is constant or not
             Expression::ReadLocalVariable { .. } => false,
             Expression::EasingCurve(_) => true,
-            Expression::LinearGradient { angle, stops } => {
+            Expression::LinearGradient { angle, stops, .. } => {
                 angle.is_constant() && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { stops, .. } => {
                 stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
+            Expression::ConicGradient { from_angle, stops } => {
+                from_angle.is_constant()
+                    && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            }
             Expression::EnumerationValue(_) => true,
             Expression::ReturnStatement(expr) => {
                 expr.as_ref().map_or(true, |expr| expr.is_constant())
@@ -1174,6 +1452,8 @@ pub fn is_constant(&self) -> bool {
             Expression::SolveLayout(..) => false,
             Expression::MinMax { lhs, rhs, .. } => lhs.is_constant() && rhs.is_constant(),
             Expression::EmptyComponentFactory => true,
+            // Just like arrays, the adapted model may change even if the source model doesn't
+            Expression::ModelAdapter { .. } => false,
         }
     }
 
@@ -1429,6 +1709,9 @@ pub fn try_set_rw(
                         node,
                     );
                     false
+                } else if lookup.declared_pure == Some(true) {
+                    ctx.diag.push_error(format!("{what} on a pure property"), node);
+                    false
                 } else if lookup.is_valid_for_assignment() {
                     if !nr
                         .element()
@@ -1631,10 +1914,34 @@ pub enum EasingCurve {
     EaseInBounce,
     EaseOutBounce,
     EaseInOutBounce,
+    /// stiffness, damping, mass
+    Spring(f32, f32, f32),
+    Steps(u32),
+    Sampled(Vec<f32>),
     // CubicBezierNonConst([Box<Expression>; 4]),
     // Custom(Box<dyn Fn(f32)->f32>),
 }
 
+/// How a linear or radial gradient fills the shape beyond its first and last stops
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum GradientSpread {
+    #[default]
+    Pad,
+    Repeat,
+    Reflect,
+}
+
+impl GradientSpread {
+    /// The keyword that ends the gradient when it's written in the `.slint` syntax, with the comma before it
+    pub fn suffix(self) -> &'static str {
+        match self {
+            GradientSpread::Pad => "",
+            GradientSpread::Repeat => ", repeat",
+            GradientSpread::Reflect => ", reflect",
+        }
+    }
+}
+
 // The compiler generates ResourceReference::AbsolutePath for all references like @image-url("foo.png")
 // and the resource lowering path may change this to EmbeddedData if configured.
 #[derive(Clone, Debug)]
@@ -1757,7 +2064,7 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
         }
         Expression::PathData(data) => write!(f, "{:?}", data),
         Expression::EasingCurve(e) => write!(f, "{:?}", e),
-        Expression::LinearGradient { angle, stops } => {
+        Expression::LinearGradient { angle, stops, spread } => {
             write!(f, "@linear-gradient(")?;
             pretty_print(f, angle)?;
             for (c, s) in stops {
@@ -1766,9 +2073,9 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
                 write!(f, "  ")?;
                 pretty_print(f, s)?;
             }
-            write!(f, ")")
+            write!(f, "{})", spread.suffix())
         }
-        Expression::RadialGradient { stops } => {
+        Expression::RadialGradient { stops, spread } => {
             write!(f, "@radial-gradient(circle")?;
             for (c, s) in stops {
                 write!(f, ", ")?;
@@ -1776,6 +2083,17 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
                 write!(f, "  ")?;
                 pretty_print(f, s)?;
             }
+            write!(f, "{})", spread.suffix())
+        }
+        Expression::ConicGradient { from_angle, stops } => {
+            write!(f, "@conic-gradient(from ")?;
+            pretty_print(f, from_angle)?;
+            for (c, s) in stops {
+                write!(f, ", ")?;
+                pretty_print(f, c)?;
+                write!(f, "  ")?;
+                pretty_print(f, s)?;
+            }
             write!(f, ")")
         }
         Expression::EnumerationValue(e) => match e.enumeration.values.get(e.value) {
@@ -1808,5 +2126,20 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             write!(f, ")")
         }
         Expression::EmptyComponentFactory => write!(f, "<empty-component-factory>"),
+        Expression::ModelAdapter { kind, model, captures, variable, expression } => {
+            pretty_print(f, model)?;
+            match kind {
+                ModelAdapterKind::Filter => write!(f, ".filter(")?,
+                ModelAdapterKind::SortBy => write!(f, ".sort-by(")?,
+            }
+            for (name, capture) in captures {
+                write!(f, "{} = ", name)?;
+                pretty_print(f, capture)?;
+                write!(f, "; ")?;
+            }
+            write!(f, "{} => ", variable)?;
+            pretty_print(f, expression)?;
+            write!(f, ")")
+        }
     }
 }
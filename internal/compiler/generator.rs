@@ -74,6 +74,12 @@ pub fn generate(
     match format {
         #[cfg(feature = "cpp")]
         OutputFormat::Cpp(config) => {
+            if !compiler_config.custom_item_manifests.is_empty() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "Unsupported output format: Custom items are only supported with Rust.",
+                ));
+            }
             let output = cpp::generate(doc, config, compiler_config)?;
             write!(destination, "{}", output)?;
         }
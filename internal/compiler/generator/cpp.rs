@@ -422,8 +422,10 @@ pub fn escape_string(str: &str) -> String {
     }
 }
 
-use crate::expression_tree::{BuiltinFunction, EasingCurve, MinMaxOp};
-use crate::langtype::{Enumeration, EnumerationValue, NativeClass, Type};
+use crate::expression_tree::{
+    BuiltinFunction, EasingCurve, GradientSpread, MinMaxOp, ModelAdapterKind,
+};
+use crate::langtype::{ElementType, Enumeration, EnumerationValue, NativeClass, Type};
 use crate::layout::Orientation;
 use crate::llr::{
     self, EvaluationContext as llr_EvaluationContext, ParentCtx as llr_ParentCtx,
@@ -563,6 +565,18 @@ fn handle_property_init(
 ) {
     let prop_access = access_member(prop, ctx);
     let prop_type = ctx.property_ty(prop);
+    // With debug information, the code of the binding refers to its location in the .slint file
+    let (location_begin, location_end) = binding_expression
+        .source_location
+        .as_ref()
+        .filter(|_| ctx.compilation_unit.has_debug_info)
+        .and_then(|location| {
+            let source_file = location.source_file.as_ref()?;
+            let (line, _) = source_file.line_column(location.span.offset);
+            let directive = line_directive(line, &source_file.path().to_string_lossy());
+            Some((format!("\n{directive}"), format!("\n{LINE_DIRECTIVE_END}")))
+        })
+        .unwrap_or_default();
     if let Type::Callback { args, return_type, .. } = &prop_type {
         let mut ctx2 = ctx.clone();
         ctx2.argument_types = args;
@@ -574,8 +588,8 @@ fn handle_property_init(
         init.push(format!(
             "{prop_access}.set_handler(
                     [this]({params}) {{
-                        [[maybe_unused]] auto self = this;
-                        {code};
+                        [[maybe_unused]] auto self = this;{location_begin}
+                        {code};{location_end}
                     }});",
             prop_access = prop_access,
             params = params.join(", "),
@@ -593,8 +607,8 @@ fn handle_property_init(
         } else {
             let binding_code = format!(
                 "[this]() {{
-                            [[maybe_unused]] auto self = this;
-                            return {init};
+                            [[maybe_unused]] auto self = this;{location_begin}
+                            return {init};{location_end}
                         }}",
                 init = init_expr
             );
@@ -631,6 +645,50 @@ fn handle_property_init(
     }
 }
 
+/// Marks the end of the code that follows a `#line` directive for a `.slint` file. It's replaced
+/// by the `#line` directive that goes back to the generated file, whose name is only known when
+/// the file is written.
+const LINE_DIRECTIVE_END: &str = "#line __slint_generated_file__";
+
+fn line_directive(line: usize, file_name: &str) -> String {
+    format!("#line {line} \"{}\"", escape_string(file_name))
+}
+
+/// Replaces the [`LINE_DIRECTIVE_END`] markers in the generated `code` with the `#line` directive
+/// for the following line of the generated file. Without a `file_name`, all the `#line`
+/// directives are removed instead.
+fn resolve_line_directives(code: String, file_name: &str) -> String {
+    if !code.contains(LINE_DIRECTIVE_END) {
+        return code;
+    }
+    let mut result = String::with_capacity(code.len());
+    for (index, line) in code.lines().enumerate() {
+        let directive = line.trim_start();
+        if file_name.is_empty() && directive.starts_with("#line ") {
+            continue;
+        }
+        if directive == LINE_DIRECTIVE_END {
+            // The directive sets the number of the line after it, and lines are counted from 1
+            result.push_str(&line_directive(index + 2, file_name));
+        } else {
+            result.push_str(line);
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[test]
+fn resolve_line_directives_test() {
+    let code = format!("a\n#line 3 \"x.slint\"\nb\n  {LINE_DIRECTIVE_END}\nc\n");
+    assert_eq!(
+        resolve_line_directives(code.clone(), "x.h"),
+        "a\n#line 3 \"x.slint\"\nb\n#line 5 \"x.h\"\nc\n"
+    );
+    assert_eq!(resolve_line_directives(code, ""), "a\nb\nc\n");
+    assert_eq!(resolve_line_directives("a\n#line 1\n".into(), ""), "a\n#line 1\n");
+}
+
 /// Returns the text of the C++ code produced by the given root component
 pub fn generate(
     doc: &Document,
@@ -650,7 +708,11 @@ pub fn generate(
     for ty in doc.used_types.borrow().structs_and_enums.iter() {
         match ty {
             Type::Struct { fields, name: Some(name), node: Some(node), .. } => {
-                generate_struct(&mut file, name, fields, node);
+                let is_animatable = matches!(
+                    doc.local_registry.property_animation_type_for_property(ty.clone()),
+                    ElementType::Builtin(_)
+                );
+                generate_struct(&mut file, name, fields, node, is_animatable);
             }
             Type::Enumeration(en) => {
                 generate_enum(&mut file, en);
@@ -792,14 +854,16 @@ pub fn generate(
         file.includes.push("<cmath>".into());
     }
 
+    let header_file_name = config.header_include.clone();
     let cpp_files = file.split_off_cpp_files(config.header_include, config.cpp_files.len());
 
     for (cpp_file_name, cpp_file) in config.cpp_files.iter().zip(cpp_files) {
         use std::io::Write;
-        write!(&mut BufWriter::new(std::fs::File::create(&cpp_file_name)?), "{}", cpp_file)?;
+        let code = resolve_line_directives(cpp_file.to_string(), &cpp_file_name.to_string_lossy());
+        write!(&mut BufWriter::new(std::fs::File::create(&cpp_file_name)?), "{}", code)?;
     }
 
-    Ok(file)
+    Ok(resolve_line_directives(file.to_string(), &header_file_name))
 }
 
 fn embed_resource(
@@ -840,6 +904,7 @@ fn embed_resource(
                 data,
                 format,
                 rect,
+                compression,
                 total_size: crate::embedded_resources::Size { width, height },
                 original_size:
                     crate::embedded_resources::Size { width: unscaled_width, height: unscaled_height },
@@ -873,6 +938,7 @@ fn embed_resource(
                             .format = slint::cbindgen_private::types::PixelFormat::{format},
                             .color = {color},
                             .index = 0,
+                            .compression = slint::cbindgen_private::types::TextureCompression::{compression},
                             }}"
                 )),
                 ..Default::default()
@@ -1024,11 +1090,14 @@ fn generate_struct(
     name: &str,
     fields: &BTreeMap<String, Type>,
     node: &syntax_nodes::ObjectType,
+    is_animatable: bool,
 ) {
     let name = ident(name);
-    let mut members = node
-        .ObjectTypeMember()
-        .map(|n| crate::parser::identifier_text(&n).unwrap())
+    let field_names =
+        node.ObjectTypeMember().map(|n| crate::parser::identifier_text(&n).unwrap()).collect_vec();
+    let mut members = field_names
+        .iter()
+        .cloned()
         .map(|name| {
             (
                 Access::Public,
@@ -1052,6 +1121,32 @@ fn generate_struct(
         }),
     ));
 
+    if is_animatable {
+        // Used by the animations of the properties of this type, which interpolate each field
+        let mut statements = vec![format!("{name} result;")];
+        statements.extend(field_names.iter().map(|field| {
+            let ty = fields.get(field).unwrap().cpp_type().unwrap();
+            let field = ident(field);
+            format!(
+                "result.{field} = slint::private_api::Interpolator<{ty}>::interpolate(from.{field}, to.{field}, t);"
+            )
+        }));
+        statements.push("return result;".into());
+        members.push((
+            Access::Public,
+            Declaration::Function(Function {
+                name: "interpolate".to_owned(),
+                signature: format!(
+                    "(const class {0} &from, const class {0} &to, float t) -> {0}",
+                    name
+                ),
+                is_static: true,
+                statements: Some(statements),
+                ..Function::default()
+            }),
+        ));
+    }
+
     file.declarations.push(Declaration::Struct(Struct { name, members, ..Default::default() }))
 }
 
@@ -1684,9 +1779,12 @@ fn generate_item_tree(
         }),
     ));
 
-    let destructor = vec![format!(
-        "if (auto &window = globals->m_window) window->window_handle().unregister_item_tree(this, item_array());"
-    )];
+    let destructor = vec![
+        "user_destroy();".into(),
+        format!(
+            "if (auto &window = globals->m_window) window->window_handle().unregister_item_tree(this, item_array());"
+        ),
+    ];
 
     target_struct.members.push((
         Access::Public,
@@ -1830,6 +1928,7 @@ fn generate_sub_component(
     }
 
     let mut user_init = vec!["[[maybe_unused]] auto self = this;".into()];
+    let mut user_destroy = vec!["[[maybe_unused]] auto self = this;".into()];
 
     let mut children_visitor_cases = Vec::new();
     let mut subtrees_ranges_cases = Vec::new();
@@ -1858,6 +1957,7 @@ fn generate_sub_component(
             field_name, global_index, global_children
         ));
         user_init.push(format!("this->{}.user_init();", field_name));
+        user_destroy.push(format!("this->{}.user_destroy();", field_name));
 
         let sub_component_repeater_count = sub.ty.repeater_count();
         if sub_component_repeater_count > 0 {
@@ -1965,6 +2065,9 @@ fn generate_sub_component(
             repeater_id = repeater_id,
             model = model,
         ));
+        if repeated.retain_instances {
+            properties_init_code.push(format!("self->{repeater_id}.set_retain_instances(true);"));
+        }
 
         let ensure_updated = if let Some(listview) = &repeated.listview {
             let vp_y = access_member(&listview.viewport_y, &ctx);
@@ -1973,9 +2076,19 @@ fn generate_sub_component(
             let vp_w = access_member(&listview.viewport_width, &ctx);
             let lv_w = access_member(&listview.listview_width, &ctx);
 
+            let update_sections = listview.sections.as_ref().map_or(String::new(), |sections| {
+                format!(
+                    " {{ auto [current_section, next_section_y] = self->{}.listview_section({}.get(), {}.get()); {}.set(current_section); {}.set(next_section_y); }}",
+                    repeater_id,
+                    access_member(&sections.section_rows, &ctx),
+                    vp_y,
+                    access_member(&sections.current_section, &ctx),
+                    access_member(&sections.next_section_y, &ctx),
+                )
+            });
             format!(
-                "self->{}.ensure_updated_listview(self, &{}, &{}, &{}, {}.get(), {}.get());",
-                repeater_id, vp_w, vp_h, vp_y, lv_w, lv_h
+                "self->{}.ensure_updated_listview(self, &{}, &{}, &{}, {}.get(), {}.get());{}",
+                repeater_id, vp_w, vp_h, vp_y, lv_w, lv_h, update_sections
             )
         } else {
             format!("self->{id}.ensure_updated(self);", id = repeater_id)
@@ -2032,6 +2145,16 @@ fn generate_sub_component(
         expr_str
     }));
 
+    // Runs before the destructor code of the sub components, after the `self` declaration
+    user_destroy.splice(
+        1..1,
+        component.destructor_code.iter().map(|e| {
+            let mut expr_str = compile_expression(&e.borrow(), &ctx);
+            expr_str.push(';');
+            expr_str
+        }),
+    );
+
     user_init.extend(component.change_callbacks.iter().enumerate().map(|(idx, (p, e))| {
         let code = compile_expression(&e.borrow(), &ctx);
         let prop = compile_expression(&llr::Expression::PropertyReference(p.clone()), &ctx);
@@ -2094,6 +2217,16 @@ fn generate_sub_component(
         }),
     ));
 
+    target_struct.members.push((
+        field_access,
+        Declaration::Function(Function {
+            name: "user_destroy".to_owned(),
+            signature: "() -> void".into(),
+            statements: Some(user_destroy),
+            ..Default::default()
+        }),
+    ));
+
     target_struct.members.push((
         field_access,
         Declaration::Function(Function {
@@ -3021,6 +3154,16 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                         events, points
                     )
                 }
+                (Type::Array(..), Type::LayoutCache) => {
+                    // The uniforms of the Shader element, see the lower_shaders pass
+                    let values = match from.as_ref() {
+                        Expression::Array { values, .. } => values
+                            .iter()
+                            .map(|value| format!("float({})", compile_expression(value, ctx))),
+                        _ => unreachable!(),
+                    };
+                    format!("slint::SharedVector<float>{{ {} }}", values.join(", "))
+                }
                 _ => f,
             }
         }
@@ -3190,7 +3333,17 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         Expression::EasingCurve(EasingCurve::EaseInBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInBounce".into(),
         Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
-        Expression::LinearGradient{angle, stops} => {
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => format!(
+            "slint::cbindgen_private::EasingCurve::spring({stiffness}, {damping}, {mass})"
+        ),
+        Expression::EasingCurve(EasingCurve::Steps(count)) => format!(
+            "slint::cbindgen_private::EasingCurve::steps({count})"
+        ),
+        Expression::EasingCurve(EasingCurve::Sampled(samples)) => format!(
+            "slint::private_api::easing_curve_sampled({{ {} }})",
+            samples.iter().map(|s| format!("{s:?}f")).join(", ")
+        ),
+        Expression::LinearGradient{angle, stops, spread} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
@@ -3198,19 +3351,31 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 format!("slint::private_api::GradientStop{{ {}, {}, }}", color, position)
             });
             format!(
-                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::LinearGradientBrush({}, stops, {})); }}()",
-                stops_it.join(", "), angle, stops.len()
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::LinearGradientBrush({}, stops, {}, {})); }}()",
+                stops_it.join(", "), angle, stops.len(), compile_gradient_spread(*spread)
+            )
+        }
+        Expression::RadialGradient{ stops, spread } => {
+            let mut stops_it = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                format!("slint::private_api::GradientStop{{ {}, {}, }}", color, position)
+            });
+            format!(
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::RadialGradientBrush(stops, {}, {})); }}()",
+                stops_it.join(", "), stops.len(), compile_gradient_spread(*spread)
             )
         }
-        Expression::RadialGradient{ stops} => {
+        Expression::ConicGradient{from_angle, stops} => {
+            let from_angle = compile_expression(from_angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
                 let position = compile_expression(stop, ctx);
                 format!("slint::private_api::GradientStop{{ {}, {}, }}", color, position)
             });
             format!(
-                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::RadialGradientBrush(stops, {})); }}()",
-                stops_it.join(", "), stops.len()
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::ConicGradientBrush({}, stops, {})); }}()",
+                stops_it.join(", "), from_angle, stops.len()
             )
         }
         Expression::EnumerationValue(value) => {
@@ -3276,6 +3441,36 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             )
         }
         Expression::EmptyComponentFactory => panic!("component-factory not yet supported in C++"),
+        Expression::ModelAdapter { kind, model, captures, variable, expression } => {
+            let Type::Array(element_ty) = model.ty(ctx) else { unreachable!() };
+            let ty = element_ty.cpp_type().unwrap();
+            let captures = captures
+                .iter()
+                .map(|(name, e)| format!("auto {} = {};", ident(name), compile_expression(e, ctx)))
+                .join(" ");
+            let model = compile_expression(model, ctx);
+            let variable = ident(variable);
+            let expression = compile_expression(expression, ctx);
+            let adapter = match kind {
+                ModelAdapterKind::Filter => format!(
+                    "std::make_shared<slint::FilterModel<{ty}>>({model}, [=](const {ty} &{variable}) -> bool {{ return {expression}; }})"
+                ),
+                ModelAdapterKind::SortBy => format!(
+                    "std::make_shared<slint::SortModel<{ty}>>({model}, [=](const {ty} &a, const {ty} &b) {{ \
+                        auto key = [&](const {ty} &{variable}) {{ return {expression}; }}; \
+                        return key(a) < key(b); }})"
+                ),
+            };
+            format!("[&]{{ {captures} return std::shared_ptr<slint::Model<{ty}>>({adapter}); }}()")
+        }
+    }
+}
+
+fn compile_gradient_spread(spread: GradientSpread) -> &'static str {
+    match spread {
+        GradientSpread::Pad => "slint::private_api::GradientSpread::Pad",
+        GradientSpread::Repeat => "slint::private_api::GradientSpread::Repeat",
+        GradientSpread::Reflect => "slint::private_api::GradientSpread::Reflect",
     }
 }
 
@@ -3294,6 +3489,12 @@ fn compile_builtin_function_call(
         BuiltinFunction::GetWindowDefaultFontSize => {
             format!("{}.default_font_size()", access_window_field(ctx))
         }
+        BuiltinFunction::GetWindowWidth => {
+            format!("{}.window_item_width()", access_window_field(ctx))
+        }
+        BuiltinFunction::GetWindowHeight => {
+            format!("{}.window_item_height()", access_window_field(ctx))
+        }
         BuiltinFunction::AnimationTick => "slint::cbindgen_private::slint_animation_tick()".into(),
         BuiltinFunction::Debug => {
             ctx.generator_state.conditional_includes.iostream.set(true);
@@ -3395,6 +3596,31 @@ fn compile_builtin_function_call(
             ctx.generator_state.conditional_includes.cstdlib.set(true);
             format!("[](const auto &a){{ float res = 0; slint::cbindgen_private::slint_string_to_float(&a, &res); return res; }}({})", a.next().unwrap())
         }
+        BuiltinFunction::RegexMatches => {
+            format!("[](const auto &text, const auto &pattern) {{ return slint::cbindgen_private::slint_regex_matches(&text, &pattern); }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::RegexReplace => {
+            format!("[](const auto &text, const auto &pattern, const auto &replacement) {{ slint::SharedString out; slint::cbindgen_private::slint_regex_replace(&text, &pattern, &replacement, &out); return out; }}({}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::RegexCapture => {
+            format!("[](const auto &text, const auto &pattern, int group) {{ slint::SharedString out; slint::cbindgen_private::slint_regex_capture(&text, &pattern, group, &out); return out; }}({}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::FormatFixed => {
+            format!("[](double value, int precision) {{ slint::SharedString out; slint::cbindgen_private::slint_shared_string_format_fixed(value, precision, &out); return out; }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::PadString => {
+            format!("[](const auto &text, int width, const auto &fill, int alignment) {{ slint::SharedString out; slint::cbindgen_private::slint_shared_string_pad(&text, width, &fill, alignment, &out); return out; }}({}, {}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
         BuiltinFunction::ColorRgbaStruct => {
             format!("{}.to_argb_uint()", a.next().unwrap())
         }
@@ -3419,6 +3645,15 @@ fn compile_builtin_function_call(
         BuiltinFunction::ImageSize => {
             format!("{}.size()", a.next().unwrap())
         }
+        BuiltinFunction::ImageFromUrl => {
+            format!("slint::private_api::load_image_from_url({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ImageUrlLoading => {
+            format!("slint::private_api::image_url_loading({})", a.next().unwrap())
+        }
+        BuiltinFunction::ImageUrlError => {
+            format!("slint::private_api::image_url_error({})", a.next().unwrap())
+        }
         BuiltinFunction::ArrayLength => {
             format!("slint::private_api::model_length({})", a.next().unwrap())
         }
@@ -3441,9 +3676,24 @@ fn compile_builtin_function_call(
         BuiltinFunction::ColorScheme => {
             format!("{}.color_scheme()", access_window_field(ctx))
         }
+        BuiltinFunction::InteractionMode => {
+            format!("{}.interaction_mode()", access_window_field(ctx))
+        }
+        BuiltinFunction::ThemeBrush => {
+            format!("[](const slint::SharedString &name, const slint::Brush &fallback) {{ slint::Brush out; slint::cbindgen_private::slint_theme_brush(&name, &fallback, &out); return out; }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ThemeLength => {
+            format!("[](const slint::SharedString &name, float fallback) {{ return slint::cbindgen_private::slint_theme_length(&name, fallback); }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ThemeString => {
+            format!("[](const slint::SharedString &name, const slint::SharedString &fallback) {{ slint::SharedString out; slint::cbindgen_private::slint_theme_string(&name, &fallback, &out); return out; }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
         BuiltinFunction::Use24HourFormat => {
             format!("slint::cbindgen_private::slint_date_time_use_24_hour_format()")
         }
+        BuiltinFunction::TranslationLanguageMatches => {
+            format!("[](const slint::SharedString &locale) {{ return slint::cbindgen_private::slint_translations_language_matches(&locale); }}({})", a.next().unwrap())
+        }
         BuiltinFunction::MonthDayCount => {
             format!("slint::cbindgen_private::slint_date_time_month_day_count({}, {})", a.next().unwrap(), a.next().unwrap())
         }
@@ -3470,6 +3720,30 @@ fn compile_builtin_function_call(
                 a.next().unwrap(), a.next().unwrap()
             )
         }
+        BuiltinFunction::LocaleFormatNumber => {
+            format!("[](double value, int decimals) {{ slint::SharedString out; slint::cbindgen_private::slint_locale_format_number(value, decimals, &out); return out; }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::LocaleParseNumber => {
+            format!("[](const auto &text) {{ double value = 0; slint::cbindgen_private::slint_locale_parse_number(&text, &value); return float(value); }}({})", a.next().unwrap())
+        }
+        BuiltinFunction::LocaleFormatDate => {
+            format!("[](int d, int m, int y) {{ slint::SharedString out; slint::cbindgen_private::slint_locale_format_date(d, m, y, &out); return out; }}({}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::LocaleParseDate => {
+            format!("[](const auto &text) {{ int32_t d=-1, m=-1, y=-1; slint::cbindgen_private::slint_locale_parse_date(&text, &d, &m, &y); return std::make_tuple(d, m, y); }}({})", a.next().unwrap())
+        }
+        BuiltinFunction::LocaleFormatTime => {
+            format!("[](int h, int m) {{ slint::SharedString out; slint::cbindgen_private::slint_locale_format_time(h, m, &out); return out; }}({}, {})",
+                a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::LocaleParseTime => {
+            format!("[](const auto &text) {{ int32_t h=-1, m=-1; slint::cbindgen_private::slint_locale_parse_time(&text, &h, &m); return std::make_tuple(h, m); }}({})", a.next().unwrap())
+        }
         BuiltinFunction::SetTextInputFocused => {
             format!("{}.set_text_input_focused({})", access_window_field(ctx), a.next().unwrap())
         }
@@ -3477,7 +3751,7 @@ fn compile_builtin_function_call(
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [llr::Expression::NumberLiteral(popup_index), close_on_click, llr::Expression::PropertyReference(parent_ref)] =
+            if let [llr::Expression::NumberLiteral(popup_index), close_policy, close_on_escape, anchor_edge, anchor_offset, anchor_flip, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -3503,9 +3777,13 @@ fn compile_builtin_function_call(
                     Some(ParentCtx::new(&ctx, None)),
                 );
                 let position = compile_expression(&popup.position.borrow(), &popup_ctx);
-                let close_on_click = compile_expression(close_on_click, ctx);
+                let close_policy = compile_expression(close_policy, ctx);
+                let close_on_escape = compile_expression(close_on_escape, ctx);
+                let anchor_edge = compile_expression(anchor_edge, ctx);
+                let anchor_offset = compile_expression(anchor_offset, ctx);
+                let anchor_flip = compile_expression(anchor_flip, ctx);
                 format!(
-                    "{window}.show_popup<{popup_window_id}>({component_access}, [=](auto self) {{ return {position}; }}, {close_on_click}, {{ {parent_component} }})"
+                    "{window}.show_popup<{popup_window_id}>({component_access}, [=](auto self) {{ return {position}; }}, {close_policy}, {close_on_escape}, {anchor_edge}, {anchor_offset}, {anchor_flip}, {{ {parent_component} }})"
                 )
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
@@ -3528,6 +3806,20 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to set-selection-offsets {:?}", arguments)
             }
         }
+        BuiltinFunction::MoveSelectionHandle => {
+            if let [llr::Expression::PropertyReference(pr), anchor, x, y] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let window = access_window_field(ctx);
+                let anchor = compile_expression(anchor, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+
+                format!("slint_textinput_move_selection_handle(&{item}, &{window}.handle(), &{item_rc}, {anchor}, {x}, {y})")
+            } else {
+                panic!("internal error: invalid args to move-selection-handle {:?}", arguments)
+            }
+        }
         BuiltinFunction::ItemMemberFunction(name) => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -3563,6 +3855,24 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ItemAbsolutePosition {:?}", arguments)
             }
         }
+        BuiltinFunction::ItemIsVisible => {
+            if let [llr::Expression::PropertyReference(pr)] = arguments {
+                let item_rc = access_item_rc(pr, ctx);
+                format!("slint::cbindgen_private::slint_item_is_visible(&{item_rc})")
+            } else {
+                panic!("internal error: invalid args to ItemIsVisible {:?}", arguments)
+            }
+        }
+        BuiltinFunction::PropertyIsAnimating => {
+            if let [llr::Expression::PropertyReference(pr)] = arguments {
+                format!("{}.is_animating()", access_member(pr, ctx))
+            } else {
+                panic!("internal error: invalid args to PropertyIsAnimating {:?}", arguments)
+            }
+        }
+        BuiltinFunction::AssertionFailed => {
+            format!("slint::private_api::assertion_failed({});", a.next().unwrap())
+        }
         BuiltinFunction::RegisterCustomFontByPath => {
             if let [llr::Expression::StringLiteral(path)] = arguments {
                 let window = access_window_field(ctx);
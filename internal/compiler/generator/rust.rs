@@ -12,8 +12,10 @@
     this is usually a local variable to the init code that shouldn't rbe relied upon by the binding code.
 */
 
-use crate::expression_tree::{BuiltinFunction, EasingCurve, MinMaxOp, OperatorClass};
-use crate::langtype::{Enumeration, EnumerationValue, Type};
+use crate::expression_tree::{
+    BuiltinFunction, EasingCurve, GradientSpread, MinMaxOp, ModelAdapterKind, OperatorClass,
+};
+use crate::langtype::{ElementType, Enumeration, EnumerationValue, NativeClass, Type};
 use crate::layout::Orientation;
 use crate::llr::{
     self, EvaluationContext as llr_EvaluationContext, Expression, ParentCtx as llr_ParentCtx,
@@ -75,6 +77,17 @@ fn to_tokens(&self, tokens: &mut TokenStream) {
     }
 }
 
+impl quote::ToTokens for crate::embedded_resources::TextureCompression {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        use crate::embedded_resources::TextureCompression::*;
+        let tks = match self {
+            None => quote!(sp::TextureCompression::None),
+            Rle => quote!(sp::TextureCompression::Rle),
+        };
+        tokens.extend(tks);
+    }
+}
+
 fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
     match ty {
         Type::Void => Some(quote!(())),
@@ -153,8 +166,12 @@ pub fn generate(doc: &Document, compiler_config: &CompilerConfiguration) -> Toke
         .structs_and_enums
         .iter()
         .filter_map(|ty| match ty {
-            Type::Struct { fields, name: Some(name), node: Some(_), rust_attributes } => {
-                Some((ident(name), generate_struct(name, fields, rust_attributes)))
+            Type::Struct { fields, name: Some(name), node: Some(_), rust_attributes, .. } => {
+                let is_animatable = matches!(
+                    doc.local_registry.property_animation_type_for_property(ty.clone()),
+                    ElementType::Builtin(_)
+                );
+                Some((ident(name), generate_struct(name, fields, rust_attributes, is_animatable)))
             }
             Type::Enumeration(en) => Some((ident(&en.name), generate_enum(en))),
             _ => None,
@@ -246,11 +263,15 @@ fn generate_public_component(
     };
 
     let property_and_callback_accessors = public_api(
+        &llr.name,
         &llr.public_properties,
         &llr.private_properties,
         quote!(sp::VRc::as_pin_ref(&self.0)),
         &ctx,
     );
+    let callback_args_structs = callback_args_structs(&llr.name, &llr.public_properties);
+
+    let builder = public_component_builder(&public_component_id, &llr.public_properties);
 
     quote!(
         #component
@@ -258,15 +279,26 @@ fn generate_public_component(
 
         impl #public_component_id {
             pub fn new() -> core::result::Result<Self, slint::PlatformError> {
+                Self::new_with(|_| {})
+            }
+
+            #[allow(dead_code)]
+            pub fn new_with(init: impl FnOnce(&Self)) -> core::result::Result<Self, slint::PlatformError> {
                 let inner = #inner_component_id::new()?;
                 inner.globals.get().unwrap().init();
-                #inner_component_id::user_init(sp::VRc::map(inner.clone(), |x| x));
-                core::result::Result::Ok(Self(inner))
+                let this = Self(inner);
+                init(&this);
+                #inner_component_id::user_init(sp::VRc::map(this.0.clone(), |x| x));
+                core::result::Result::Ok(this)
             }
 
             #property_and_callback_accessors
         }
 
+        #builder
+
+        #callback_args_structs
+
         impl From<#public_component_id> for sp::VRc<sp::ItemTreeVTable, #inner_component_id> {
             fn from(value: #public_component_id) -> Self {
                 value.0
@@ -384,6 +416,7 @@ fn generate_struct(
     name: &str,
     fields: &BTreeMap<String, Type>,
     rust_attributes: &Option<Vec<String>>,
+    is_animatable: bool,
 ) -> TokenStream {
     let component_id = struct_name_to_tokens(name);
     let (declared_property_vars, declared_property_types): (Vec<_>, Vec<_>) =
@@ -400,12 +433,30 @@ fn generate_struct(
         quote! {}
     };
 
+    // The animations of the properties of this type interpolate each field
+    let interpolated_property_value = is_animatable.then(|| {
+        quote! {
+            impl sp::InterpolatedPropertyValue for #component_id {
+                fn interpolate(&self, target_value: &Self, t: f32) -> Self {
+                    Self {
+                        #(#declared_property_vars: sp::InterpolatedPropertyValue::interpolate(
+                            &self.#declared_property_vars,
+                            &target_value.#declared_property_vars,
+                            t,
+                        )),*
+                    }
+                }
+            }
+        }
+    });
+
     quote! {
         #attributes
         #[derive(Default, PartialEq, Debug, Clone)]
         pub struct #component_id {
             #(pub #declared_property_vars : #declared_property_types),*
         }
+        #interpolated_property_value
     }
 }
 
@@ -453,6 +504,16 @@ fn handle_property_init(
         quote!(let _self = self_rc.as_pin_ref();)
     };
 
+    // With debug information, a panic in the binding reports where the binding is in the .slint file
+    let location_guard = binding_expression
+        .source_location
+        .as_ref()
+        .filter(|_| ctx.compilation_unit.has_debug_info)
+        .and_then(|location| location.file_line_column())
+        .map(|location| {
+            quote!(let _location = slint::private_unstable_api::SourceLocationGuard(#location);)
+        });
+
     if let Type::Callback { args, return_type } = &prop_type {
         let mut ctx2 = ctx.clone();
         ctx2.argument_types = args;
@@ -468,6 +529,7 @@ fn handle_property_init(
             slint::private_unstable_api::set_callback_handler(#rust_property, &self_rc, {
                 move |self_rc, args| {
                     #init_self_pin_ref
+                    #location_guard
                     (#tokens_for_expression) #as_
                 }
             });
@@ -492,6 +554,7 @@ fn handle_property_init(
 
             let binding_tokens = quote!(move |self_rc| {
                 #init_self_pin_ref
+                #location_guard
                 (#tokens_for_expression) #maybe_cast_to_property_type
             });
 
@@ -531,7 +594,99 @@ fn handle_property_init(
 }
 
 /// Public API for Global and root component
+/// Generates the `builder()` function of a public component, which returns a builder
+/// with one method per property that can be set, to set initial values before the component is built.
+fn public_component_builder(
+    public_component_id: &proc_macro2::Ident,
+    public_properties: &llr::PublicProperties,
+) -> TokenStream {
+    let builder_id = format_ident!("{}Builder", public_component_id);
+    let properties = public_properties
+        .iter()
+        .filter(|p| !p.read_only && !matches!(p.ty, Type::Callback { .. } | Type::Function { .. }))
+        .map(|p| (ident(&p.name), rust_primitive_type(&p.ty).unwrap()))
+        .collect::<Vec<_>>();
+    let fields = properties.iter().map(|(name, ty)| quote!(#name: core::option::Option<#ty>));
+    let methods = properties.iter().map(|(name, ty)| {
+        quote!(
+            #[allow(dead_code)]
+            pub fn #name(mut self, value: impl core::convert::Into<#ty>) -> Self {
+                self.#name = core::option::Option::Some(value.into());
+                self
+            }
+        )
+    });
+    let set_values = properties.iter().map(|(name, _)| {
+        let setter_ident = format_ident!("set_{}", name);
+        quote!(
+            if let core::option::Option::Some(value) = self.#name {
+                component.#setter_ident(value);
+            }
+        )
+    });
+
+    quote!(
+        #[derive(Default)]
+        #[must_use]
+        #[allow(dead_code)]
+        pub struct #builder_id {
+            #(#fields,)*
+        }
+
+        impl #builder_id {
+            #(#methods)*
+
+            #[allow(dead_code)]
+            pub fn build(self) -> core::result::Result<#public_component_id, slint::PlatformError> {
+                #public_component_id::new_with(|component| {
+                    #(#set_values)*
+                })
+            }
+        }
+
+        impl #public_component_id {
+            #[allow(dead_code)]
+            pub fn builder() -> #builder_id {
+                #builder_id::default()
+            }
+        }
+    )
+}
+
+/// The names of the arguments of a callback that gets a struct with its arguments in the public API,
+/// which is the case for callbacks with several arguments that all have names
+fn callback_args_struct_fields(p: &llr::PublicProperty) -> Option<Vec<Ident>> {
+    let Type::Callback { args, .. } = &p.ty else { return None };
+    if args.len() < 2 {
+        return None;
+    }
+    p.arg_names.iter().map(|name| name.as_deref().map(ident)).collect()
+}
+
+fn callback_args_struct_id(owner: &str, callback: &str) -> Ident {
+    format_ident!("{}{}Args", ident(owner), crate::generator::to_pascal_case(callback))
+}
+
+/// Generates the structs that hold the arguments of the public callbacks with named arguments
+fn callback_args_structs(owner: &str, public_properties: &llr::PublicProperties) -> TokenStream {
+    let structs = public_properties.iter().filter_map(|p| {
+        let fields = callback_args_struct_fields(p)?;
+        let Type::Callback { args, .. } = &p.ty else { return None };
+        let types = args.iter().map(|a| rust_primitive_type(a).unwrap());
+        let struct_id = callback_args_struct_id(owner, &p.name);
+        Some(quote!(
+            #[derive(Clone)]
+            #[allow(dead_code)]
+            pub struct #struct_id {
+                #(pub #fields: #types,)*
+            }
+        ))
+    });
+    quote!(#(#structs)*)
+}
+
 fn public_api(
+    owner: &str,
     public_properties: &llr::PublicProperties,
     private_properties: &llr::PrivateProperties,
     self_init: TokenStream,
@@ -558,6 +713,20 @@ pub fn #caller_ident(&self, #(#args_name : #callback_args,)*) -> #return_type {
             ));
             let on_ident = format_ident!("on_{}", prop_ident);
             let args_index = (0..callback_args.len()).map(proc_macro2::Literal::usize_unsuffixed);
+            if let Some(fields) = callback_args_struct_fields(p) {
+                let struct_id = callback_args_struct_id(owner, &p.name);
+                property_and_callback_accessors.push(quote!(
+                    #[allow(dead_code)]
+                    pub fn #on_ident(&self, mut f: impl FnMut(#struct_id) -> #return_type + 'static) {
+                        let _self = #self_init;
+                        #[allow(unused)]
+                        #prop.set_handler(
+                            move |args| f(#struct_id { #(#fields: args.#args_index.clone(),)* })
+                        )
+                    }
+                ));
+                continue;
+            }
             property_and_callback_accessors.push(quote!(
                 #[allow(dead_code)]
                 pub fn #on_ident(&self, mut f: impl FnMut(#(#callback_args),*) -> #return_type + 'static) {
@@ -609,6 +778,21 @@ pub fn #setter_ident(&self, value: #rust_property_type) {
                         #set_value
                     }
                 ));
+                if is_animatable(&p.ty) {
+                    let animated_setter_ident = format_ident!("set_{}_animated", prop_ident);
+                    let value_tokens = set_primitive_property_value(&p.ty, quote!(value));
+                    let set_animated_value = access_member(&p.prop, ctx).then(|prop| {
+                        quote!(#prop.set_animated_value(#value_tokens as _, animation.into()))
+                    });
+                    property_and_callback_accessors.push(quote!(
+                        #[allow(dead_code)]
+                        pub fn #animated_setter_ident(&self, value: #rust_property_type, animation: slint::Animation) {
+                            #[allow(unused_imports)]
+                            let _self = #self_init;
+                            #set_animated_value
+                        }
+                    ));
+                }
             } else {
                 property_and_callback_accessors.push(quote!(
                     #[allow(dead_code)] fn #setter_ident(&self, _read_only_property : ()) { }
@@ -704,7 +888,7 @@ fn generate_sub_component(
 
     for item in &component.items {
         item_names.push(ident(&item.name));
-        item_types.push(ident(&item.ty.class_name));
+        item_types.push(native_item_type(&item.ty));
         #[cfg(slint_debug_property)]
         {
             let mut it = Some(&item.ty);
@@ -758,18 +942,32 @@ fn generate_sub_component(
                 }
             });
         });
+        if repeated.retain_instances {
+            init.push(quote!(_self.#repeater_id.set_retain_instances(true);));
+        }
         let ensure_updated = if let Some(listview) = &repeated.listview {
             let vp_y = access_member(&listview.viewport_y, &ctx).unwrap();
             let vp_h = access_member(&listview.viewport_height, &ctx).unwrap();
             let lv_h = access_member(&listview.listview_height, &ctx).unwrap();
             let vp_w = access_member(&listview.viewport_width, &ctx).unwrap();
             let lv_w = access_member(&listview.listview_width, &ctx).unwrap();
+            let update_sections = listview.sections.as_ref().map(|sections| {
+                let rows = access_member(&sections.section_rows, &ctx).unwrap();
+                let current = access_member(&sections.current_section, &ctx).unwrap();
+                let next_y = access_member(&sections.next_section_y, &ctx).unwrap();
+                quote! {
+                    let (current_section, next_section_y) = #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).listview_section(&#rows.get(), #vp_y.get());
+                    #current.set(current_section);
+                    #next_y.set(next_section_y);
+                }
+            });
 
             quote! {
                 #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_listview(
                     || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).unwrap().into() },
                     #vp_w, #vp_h, #vp_y, #lv_w.get(), #lv_h
                 );
+                #update_sections
             }
         } else {
             quote! {
@@ -892,6 +1090,14 @@ fn generate_sub_component(
         .collect::<Vec<_>>();
 
     let mut user_init_code: Vec<TokenStream> = Vec::new();
+    let mut user_destroy_code: Vec<TokenStream> = component
+        .destructor_code
+        .iter()
+        .map(|e| {
+            let code = compile_expression(&e.borrow(), &ctx);
+            quote!(#code;)
+        })
+        .collect();
 
     let mut sub_component_names: Vec<Ident> = vec![];
     let mut sub_component_types: Vec<Ident> = vec![];
@@ -925,6 +1131,8 @@ fn generate_sub_component(
         user_init_code.push(quote!(#sub_component_id::user_init(
             sp::VRcMapped::map(self_rc.clone(), |x| #sub_compo_field.apply_pin(x)),
         );));
+        user_destroy_code
+            .push(quote!(#sub_component_id::user_destroy(#sub_compo_field.apply_pin(_self));));
 
         let sub_component_repeater_count = sub.ty.repeater_count();
         if sub_component_repeater_count > 0 {
@@ -1100,7 +1308,7 @@ fn update_timers(self: ::core::pin::Pin<&Self>) {
         #pin_macro
         #visibility
         struct #inner_component_id {
-            #(#item_names : sp::#item_types,)*
+            #(#item_names : #item_types,)*
             #(#sub_component_names : #sub_component_types,)*
             #(#declared_property_vars : sp::Property<#declared_property_types>,)*
             #(#declared_callbacks : sp::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
@@ -1133,6 +1341,12 @@ fn user_init(self_rc: sp::VRcMapped<sp::ItemTreeVTable, Self>) {
                 #(#user_init_code)*
             }
 
+            fn user_destroy(self: ::core::pin::Pin<&Self>) {
+                #![allow(unused)]
+                let _self = self;
+                #(#user_destroy_code)*
+            }
+
             fn visit_dynamic_children(
                 self: ::core::pin::Pin<&Self>,
                 dyn_index: u32,
@@ -1358,11 +1572,13 @@ fn generate_global(global: &llr::GlobalComponent, root: &llr::CompilationUnit) -
 
     let public_interface = global.exported.then(|| {
         let property_and_callback_accessors = public_api(
+            &global.name,
             &global.public_properties,
             &global.private_properties,
             quote!(self.0.as_ref()),
             &ctx,
         );
+        let callback_args_structs = callback_args_structs(&global.name, &global.public_properties);
         let public_component_id = ident(&global.name);
         let global_id = format_ident!("global_{}", public_component_id);
         let aliases = global.aliases.iter().map(|name| ident(name));
@@ -1386,6 +1602,7 @@ impl<'a> #public_component_id<'a> {
             }
             #(pub type #aliases<'a> = #public_component_id<'a>;)*
             #(#getters)*
+            #callback_args_structs
         )
     });
 
@@ -1556,6 +1773,7 @@ fn item_array() -> &'static [sp::VOffset<Self, sp::ItemVTable, sp::AllowPin>] {
 
         impl sp::PinnedDrop for #inner_component_id {
             fn drop(self: core::pin::Pin<&mut #inner_component_id>) {
+                self.as_ref().user_destroy();
                 sp::vtable::new_vref!(let vref : VRef<sp::ItemTreeVTable> for sp::ItemTree = self.as_ref().get_ref());
                 if let Some(wa) = self.globals.get().unwrap().maybe_window_adapter_impl() {
                     sp::unregister_item_tree(self.as_ref(), vref, Self::item_array(), &wa);
@@ -1786,6 +2004,21 @@ fn global_inner_name(g: &llr::GlobalComponent) -> TokenStream {
     }
 }
 
+/// Returns true if the properties of this type can be animated, and have a `set_<name>_animated()`
+/// function in the public API
+fn is_animatable(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Float32
+            | Type::Int32
+            | Type::Color
+            | Type::PhysicalLength
+            | Type::LogicalLength
+            | Type::Brush
+            | Type::Angle
+    )
+}
+
 fn property_set_value_tokens(
     property: &llr::PropertyReference,
     value_tokens: TokenStream,
@@ -1823,8 +2056,8 @@ fn in_native_item(
             quote!((#compo_path #item_field).apply_pin(#path))
         } else {
             let property_name = ident(prop_name);
-            let item_ty = ident(&sub_component.items[item_index as usize].ty.class_name);
-            quote!((#compo_path #item_field + sp::#item_ty::FIELD_OFFSETS.#property_name).apply_pin(#path))
+            let item_ty = native_item_type(&sub_component.items[item_index as usize].ty);
+            quote!((#compo_path #item_field + #item_ty::FIELD_OFFSETS.#property_name).apply_pin(#path))
         }
     }
     match reference {
@@ -2158,6 +2391,16 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 (Type::String, Type::PathData) => {
                     quote!(sp::PathData::Commands(#f))
                 }
+                (Type::Array(..), Type::LayoutCache) => {
+                    // The uniforms of the Shader element, see the lower_shaders pass
+                    let values = match from.as_ref() {
+                        Expression::Array { values, .. } => {
+                            values.iter().map(|value| compile_expression(value, ctx))
+                        }
+                        _ => unreachable!(),
+                    };
+                    quote!(sp::SharedVector::<f32>::from_slice(&[#((#values) as f32),*]))
+                }
                 _ => f,
             }
         }
@@ -2442,25 +2685,47 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => {
             quote!(sp::EasingCurve::EaseInOutBounce)
         }
-        Expression::LinearGradient { angle, stops } => {
+        Expression::EasingCurve(EasingCurve::Steps(count)) => {
+            quote!(sp::EasingCurve::Steps(#count))
+        }
+        Expression::EasingCurve(EasingCurve::Sampled(samples)) => {
+            quote!(sp::EasingCurve::sampled(&[#(#samples),*]))
+        }
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => {
+            quote!(sp::EasingCurve::Spring { stiffness: #stiffness, damping: #damping, mass: #mass })
+        }
+        Expression::LinearGradient { angle, stops, spread } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
                 let position = compile_expression(stop, ctx);
                 quote!(sp::GradientStop{ color: #color, position: #position as _ })
             });
+            let spread = compile_gradient_spread(*spread);
             quote!(slint::Brush::LinearGradient(
-                sp::LinearGradientBrush::new(#angle as _, [#(#stops),*])
+                sp::LinearGradientBrush::new(#angle as _, [#(#stops),*])#spread
             ))
         }
-        Expression::RadialGradient { stops } => {
+        Expression::RadialGradient { stops, spread } => {
             let stops = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
                 let position = compile_expression(stop, ctx);
                 quote!(sp::GradientStop{ color: #color, position: #position as _ })
             });
+            let spread = compile_gradient_spread(*spread);
             quote!(slint::Brush::RadialGradient(
-                sp::RadialGradientBrush::new_circle([#(#stops),*])
+                sp::RadialGradientBrush::new_circle([#(#stops),*])#spread
+            ))
+        }
+        Expression::ConicGradient { from_angle, stops } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let stops = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                quote!(sp::GradientStop{ color: #color, position: #position as _ })
+            });
+            quote!(slint::Brush::ConicGradient(
+                sp::ConicGradientBrush::new(#from_angle as _, [#(#stops),*])
             ))
         }
         Expression::EnumerationValue(value) => {
@@ -2532,6 +2797,37 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             }
         }
         Expression::EmptyComponentFactory => quote!(slint::ComponentFactory::default()),
+        Expression::ModelAdapter { kind, model, captures, variable, expression } => {
+            let model = compile_expression(model, ctx);
+            let capture_names = captures.iter().map(|(name, _)| ident(name));
+            let capture_values = captures.iter().map(|(_, e)| compile_expression(e, ctx));
+            let variable = ident(variable);
+            let expression = compile_expression(expression, ctx);
+            let adapter = match kind {
+                ModelAdapterKind::Filter => {
+                    quote!(sp::FilterModel::new(#model, move |#variable| -> bool { #expression }))
+                }
+                ModelAdapterKind::SortBy => quote!(sp::SortModel::new(#model, move |a, b| {
+                    let a = { let #variable = a; #expression };
+                    let b = { let #variable = b; #expression };
+                    ::core::cmp::PartialOrd::partial_cmp(&a, &b)
+                        .unwrap_or(::core::cmp::Ordering::Equal)
+                })),
+            };
+            quote!({
+                #(let #capture_names = #capture_values;)*
+                sp::ModelRc::new(#adapter)
+            })
+        }
+    }
+}
+
+/// Returns the call that sets the spread of a gradient brush, if it's not the default
+fn compile_gradient_spread(spread: GradientSpread) -> Option<TokenStream> {
+    match spread {
+        GradientSpread::Pad => None,
+        GradientSpread::Repeat => Some(quote!(.with_spread(sp::GradientSpread::Repeat))),
+        GradientSpread::Reflect => Some(quote!(.with_spread(sp::GradientSpread::Reflect))),
     }
 }
 
@@ -2565,7 +2861,7 @@ fn compile_builtin_function_call(
             }
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [Expression::NumberLiteral(popup_index), close_on_click, Expression::PropertyReference(parent_ref)] =
+            if let [Expression::NumberLiteral(popup_index), close_policy, close_on_escape, anchor_edge, anchor_offset, anchor_flip, Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2590,7 +2886,11 @@ fn compile_builtin_function_call(
                 );
                 let position = compile_expression(&popup.position.borrow(), &popup_ctx);
 
-                let close_on_click = compile_expression(close_on_click, ctx);
+                let close_policy = compile_expression(close_policy, ctx);
+                let close_on_escape = compile_expression(close_on_escape, ctx);
+                let anchor_edge = compile_expression(anchor_edge, ctx);
+                let anchor_offset = compile_expression(anchor_offset, ctx);
+                let anchor_flip = compile_expression(anchor_flip, ctx);
                 let window_adapter_tokens = access_window_adapter_field(ctx);
                 quote!({
                     let popup_instance = #popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone()).unwrap();
@@ -2600,7 +2900,13 @@ fn compile_builtin_function_call(
                     sp::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
                         &sp::VRc::into_dyn(popup_instance.into()),
                         position,
-                        #close_on_click,
+                        sp::PopupOptions {
+                            close_policy: #close_policy,
+                            close_on_escape: #close_on_escape,
+                            anchor_edge: #anchor_edge,
+                            anchor_offset: #anchor_offset as sp::Coord,
+                            anchor_flip: #anchor_flip,
+                        },
                         #parent_component
                     )
                 })
@@ -2629,6 +2935,22 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to set-selection-offsets {:?}", arguments)
             }
         }
+        BuiltinFunction::MoveSelectionHandle => {
+            if let [llr::Expression::PropertyReference(pr), anchor, x, y] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                let anchor = compile_expression(anchor, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+
+                item.then(|item| quote!(
+                    #item.move_selection_handle(#window_adapter_tokens, #item_rc, #anchor, sp::LogicalPoint::new(#x as sp::Coord, #y as sp::Coord))
+                ))
+            } else {
+                panic!("internal error: invalid args to move-selection-handle {:?}", arguments)
+            }
+        }
         BuiltinFunction::ItemMemberFunction(name) => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -2704,7 +3026,15 @@ fn compile_builtin_function_call(
         }
         BuiltinFunction::GetWindowDefaultFontSize => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
-            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).window_item().unwrap().as_pin_ref().default_font_size().get())
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).window_item().unwrap().as_pin_ref().resolved_default_font_size().get())
+        }
+        BuiltinFunction::GetWindowWidth => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).window_item().unwrap().as_pin_ref().width().get())
+        }
+        BuiltinFunction::GetWindowHeight => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).window_item().unwrap().as_pin_ref().height().get())
         }
         BuiltinFunction::AnimationTick => {
             quote!(sp::animation_tick())
@@ -2741,6 +3071,27 @@ fn compile_builtin_function_call(
             quote!(#(#a)*.as_str().parse::<f64>().unwrap_or_default())
         }
         BuiltinFunction::StringIsFloat => quote!(#(#a)*.as_str().parse::<f64>().is_ok()),
+        BuiltinFunction::RegexMatches => {
+            let (t, p) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::regex_matches(#t.as_str(), #p.as_str()))
+        }
+        BuiltinFunction::RegexReplace => {
+            let (t, p, r) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::regex_replace(#t.as_str(), #p.as_str(), #r.as_str()))
+        }
+        BuiltinFunction::RegexCapture => {
+            let (t, p, g) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::regex_capture(#t.as_str(), #p.as_str(), #g as i32))
+        }
+        BuiltinFunction::FormatFixed => {
+            let (v, p) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::format_fixed(#v as f64, #p as i32))
+        }
+        BuiltinFunction::PadString => {
+            let (t, w, f, al) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::pad_string(#t.as_str(), #w as i32, #f.chars().next().unwrap_or(' '), #al as i32))
+        }
         BuiltinFunction::ColorRgbaStruct => quote!( #(#a)*.to_argb_u8()),
         BuiltinFunction::ColorHsvaStruct => quote!( #(#a)*.to_hsva()),
         BuiltinFunction::ColorBrighter => {
@@ -2770,6 +3121,12 @@ fn compile_builtin_function_call(
             quote!(#x.with_alpha(#alpha as f32))
         }
         BuiltinFunction::ImageSize => quote!( #(#a)*.size()),
+        BuiltinFunction::ImageFromUrl => {
+            let (url, fallback) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::load_image_from_url(&#url).unwrap_or_else(|| #fallback))
+        }
+        BuiltinFunction::ImageUrlLoading => quote!(sp::image_url_loading(&#(#a)*)),
+        BuiltinFunction::ImageUrlError => quote!(sp::image_url_error(&#(#a)*)),
         BuiltinFunction::ArrayLength => {
             quote!(match &#(#a)* { x => {
                 x.model_tracker().track_row_count_changes();
@@ -2803,6 +3160,22 @@ fn compile_builtin_function_call(
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).color_scheme())
         }
+        BuiltinFunction::InteractionMode => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).interaction_mode())
+        }
+        BuiltinFunction::ThemeBrush => {
+            let (name, default) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::theme_brush(&#name, #default))
+        }
+        BuiltinFunction::ThemeLength => {
+            let (name, default) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::theme_length(&#name, #default as sp::Coord))
+        }
+        BuiltinFunction::ThemeString => {
+            let (name, default) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::theme_string(&#name, #default))
+        }
         BuiltinFunction::MonthDayCount => {
             let (m, y) = (a.next().unwrap(), a.next().unwrap());
             quote!(sp::month_day_count(#m as u32, #y as i32).unwrap_or(0))
@@ -2827,6 +3200,36 @@ fn compile_builtin_function_call(
         BuiltinFunction::DateNow => {
             quote!(sp::ModelRc::new(sp::VecModel::from_slice(&sp::date_now())))
         }
+        BuiltinFunction::LocaleFormatNumber => {
+            let (v, d) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::locale::format_number(#v as f64, #d as i32))
+        }
+        BuiltinFunction::LocaleParseNumber => {
+            let t = a.next().unwrap();
+            quote!(sp::locale::parse_number(#t.as_str()).unwrap_or_default() as f32)
+        }
+        BuiltinFunction::LocaleFormatDate => {
+            let (d, m, y) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::locale::format_date(#d as u32, #m as u32, #y as i32))
+        }
+        BuiltinFunction::LocaleParseDate => {
+            let t = a.next().unwrap();
+            quote!({
+                let [d, m, y] = sp::locale::parse_date(#t.as_str()).unwrap_or([-1; 3]);
+                (d, m, y)
+            })
+        }
+        BuiltinFunction::LocaleFormatTime => {
+            let (h, m) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::locale::format_time(#h as u32, #m as u32))
+        }
+        BuiltinFunction::LocaleParseTime => {
+            let t = a.next().unwrap();
+            quote!({
+                let [h, m] = sp::locale::parse_time(#t.as_str()).unwrap_or([-1; 2]);
+                (h, m)
+            })
+        }
         BuiltinFunction::TextInputFocused => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).text_input_focused())
@@ -2841,6 +3244,10 @@ fn compile_builtin_function_call(
         BuiltinFunction::Use24HourFormat => {
             quote!(slint::private_unstable_api::use_24_hour_format())
         }
+        BuiltinFunction::TranslationLanguageMatches => {
+            let locale = a.next().unwrap();
+            quote!(slint::private_unstable_api::translation_language_matches(&#locale))
+        }
         BuiltinFunction::ItemAbsolutePosition => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item_rc = access_item_rc(pr, ctx);
@@ -2851,12 +3258,44 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to MapPointToWindow {:?}", arguments)
             }
         }
+        BuiltinFunction::ItemIsVisible => {
+            if let [Expression::PropertyReference(pr)] = arguments {
+                let item_rc = access_item_rc(pr, ctx);
+                quote!((*#item_rc).is_visible())
+            } else {
+                panic!("internal error: invalid args to ItemIsVisible {:?}", arguments)
+            }
+        }
+        BuiltinFunction::PropertyIsAnimating => {
+            if let [Expression::PropertyReference(pr)] = arguments {
+                access_member(pr, ctx).map_or_default(|prop| quote!(#prop.is_animating()))
+            } else {
+                panic!("internal error: invalid args to PropertyIsAnimating {:?}", arguments)
+            }
+        }
+        BuiltinFunction::AssertionFailed => {
+            let message = a.next().unwrap();
+            quote!(if ::core::cfg!(debug_assertions) {
+                ::core::panic!("{}", #message)
+            })
+        }
         BuiltinFunction::UpdateTimers => {
             quote!(_self.update_timers())
         }
     }
 }
 
+/// The Rust type of a native item: a type of the Slint runtime, or the type of a custom item
+fn native_item_type(class: &NativeClass) -> TokenStream {
+    match &class.rust_type {
+        Some(path) => path.parse().unwrap(),
+        None => {
+            let class_name = ident(&class.class_name);
+            quote!(sp::#class_name)
+        }
+    }
+}
+
 /// Return a TokenStream for a name (as in [`Type::Struct::name`])
 fn struct_name_to_tokens(name: &str) -> TokenStream {
     // the name match the C++ signature so we need to change that to the rust namespace
@@ -2966,7 +3405,7 @@ fn generate_resources(doc: &Document) -> Vec<TokenStream> {
                 }
                 #[cfg(feature = "software-renderer")]
                 crate::embedded_resources::EmbeddedResourcesKind::TextureData(crate::embedded_resources::Texture {
-                    data, format, rect,
+                    data, format, rect, compression,
                     total_size: crate::embedded_resources::Size{width, height},
                     original_size: crate::embedded_resources::Size{width: unscaled_width, height: unscaled_height},
                 }) => {
@@ -2992,6 +3431,7 @@ fn generate_resources(doc: &Document) -> Vec<TokenStream> {
                                     format: #format,
                                     color: #color,
                                     index: 0,
+                                    compression: #compression,
                                 }
                             ])
                         };
@@ -4,7 +4,7 @@
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Display;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use itertools::Itertools;
 
@@ -65,6 +65,9 @@ pub enum Type {
         node: Option<syntax_nodes::ObjectType>,
         /// derived
         rust_attributes: Option<Vec<String>>,
+        /// When the struct is declared in .slint with functions, the global component that holds
+        /// them. These functions take the struct as their first argument.
+        functions: Option<Weak<Component>>,
     },
     Enumeration(Rc<Enumeration>),
 
@@ -110,8 +113,8 @@ fn eq(&self, other: &Self) -> bool {
             Type::Easing => matches!(other, Type::Easing),
             Type::Brush => matches!(other, Type::Brush),
             Type::Array(a) => matches!(other, Type::Array(b) if a == b),
-            Type::Struct { fields, name, node: _, rust_attributes: _ } => {
-                matches!(other, Type::Struct{fields:f,name:n,node:_, rust_attributes: _ } if fields == f && name == n)
+            Type::Struct { fields, name, .. } => {
+                matches!(other, Type::Struct { fields: f, name: n, .. } if fields == f && name == n)
             }
             Type::Enumeration(lhs) => matches!(other, Type::Enumeration(rhs) if lhs == rhs),
             Type::UnitProduct(a) => matches!(other, Type::UnitProduct(b) if a == b),
@@ -683,6 +686,9 @@ pub struct NativeClass {
     pub deprecated_aliases: HashMap<String, String>,
     pub cpp_type: Option<String>,
     pub rust_type_constructor: Option<String>,
+    /// The path of the Rust type of a custom item declared in a manifest, instead of a type of
+    /// the Slint runtime
+    pub rust_type: Option<String>,
 }
 
 impl NativeClass {
@@ -862,7 +868,7 @@ pub fn unit_product_length_conversion(
     a: &[(Unit, i8)],
     b: &[(Unit, i8)],
 ) -> Option<LengthConversionPowers> {
-    let mut units = [0i8; 16];
+    let mut units = [0i8; 32];
     for (u, count) in a {
         units[*u as usize] += count;
     }
@@ -496,6 +496,7 @@ pub fn layout_info_type() -> Type {
         name: Some("slint::private_api::LayoutInfo".into()),
         node: None,
         rust_attributes: None,
+        functions: None,
     }
 }
 
@@ -12,9 +12,11 @@
 use core::future::Future;
 use core::pin::Pin;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
+pub mod binding_report;
+pub mod build_cache;
 pub mod builtin_macros;
 pub mod diagnostics;
 pub mod embedded_resources;
@@ -94,6 +96,21 @@ pub struct CompilerConfiguration {
     pub include_paths: Vec<std::path::PathBuf>,
     /// The compiler will look in these paths for library imports.
     pub library_paths: HashMap<String, std::path::PathBuf>,
+    /// Pairs of a base directory and a variant directory, which overlays the base directory.
+    ///
+    /// A file of the variant directory replaces the file with the same relative path in the base
+    /// directory, wherever it is imported or referenced with `@image-url`. The exports of the
+    /// replaced `.slint` file that the variant file doesn't define itself remain available, so a
+    /// variant file may override only some components of the base file. The later pairs take
+    /// precedence over the earlier ones.
+    pub variant_paths: Vec<(std::path::PathBuf, std::path::PathBuf)>,
+    /// The flags that are set for the `@cfg(flag)` conditions, to include or exclude parts of
+    /// the `.slint` files at compile time. Also set with the comma separated `SLINT_CFG`
+    /// environment variable.
+    pub cfg_flags: HashSet<String>,
+    /// Manifests that declare custom items, native items implemented in the application with the
+    /// `slint::custom_item` API. The declared items are available in all the files without import.
+    pub custom_item_manifests: Vec<std::path::PathBuf>,
     /// the name of the style. (eg: "native")
     pub style: Option<String>,
 
@@ -120,6 +137,16 @@ pub struct CompilerConfiguration {
     /// If != 1.0 then the scale factor will be set on the `slint::Window`.
     pub const_scale_factor: f64,
 
+    /// Run-length encode the textures that are embedded with [`EmbedResourcesKind::EmbedTextures`],
+    /// to reduce their size in flash. They are decompressed line by line when rendering.
+    /// Also set with the `SLINT_COMPRESS_TEXTURES` environment variable.
+    pub compress_textures: bool,
+
+    /// The glyphs to pre-render for the fonts that are embedded with
+    /// [`EmbedResourcesKind::EmbedTextures`], in addition to the ones that are found in the
+    /// `.slint` files. Also set with the `;` separated `SLINT_EMBED_GLYPHS` environment variable.
+    pub glyph_selections: Vec<embedded_resources::GlyphSelection>,
+
     /// expose the accessible role and properties
     pub accessibility: bool,
 
@@ -183,10 +210,23 @@ pub fn new(output_format: OutputFormat) -> Self {
             .filter(|f| *f > 0.)
             .unwrap_or(1.);
 
+        let compress_textures = std::env::var_os("SLINT_COMPRESS_TEXTURES").is_some();
+
         let enable_experimental = std::env::var_os("SLINT_ENABLE_EXPERIMENTAL_FEATURES").is_some();
 
         let debug_info = std::env::var_os("SLINT_EMIT_DEBUG_INFO").is_some();
 
+        let cfg_flags = std::env::var("SLINT_CFG")
+            .map(|flags| {
+                flags
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|flag| !flag.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
         let cpp_namespace = match output_format {
             #[cfg(feature = "cpp")]
             OutputFormat::Cpp(config) => match config.namespace {
@@ -203,11 +243,16 @@ pub fn new(output_format: OutputFormat) -> Self {
             embed_resources,
             include_paths: Default::default(),
             library_paths: Default::default(),
+            variant_paths: Default::default(),
+            cfg_flags,
+            custom_item_manifests: Default::default(),
             style: Default::default(),
             open_import_fallback: None,
             resource_url_mapper: None,
             inline_all_elements,
             const_scale_factor,
+            compress_textures,
+            glyph_selections: Default::default(),
             accessibility: true,
             enable_experimental,
             translation_domain: None,
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use super::PropertyReference;
-use crate::expression_tree::{BuiltinFunction, MinMaxOp, OperatorClass};
+use crate::expression_tree::{BuiltinFunction, MinMaxOp, ModelAdapterKind, OperatorClass};
 use crate::langtype::Type;
 use crate::layout::Orientation;
 use core::num::NonZeroUsize;
@@ -144,11 +144,19 @@ pub enum Expression {
         angle: Box<Expression>,
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
+        spread: crate::expression_tree::GradientSpread,
     },
 
     RadialGradient {
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
+        spread: crate::expression_tree::GradientSpread,
+    },
+
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position
+        stops: Vec<(Expression, Expression)>,
     },
 
     EnumerationValue(crate::langtype::EnumerationValue),
@@ -189,6 +197,17 @@ pub enum Expression {
     },
 
     EmptyComponentFactory,
+
+    /// A model that filters or sorts the rows of `model`. The `expression` is evaluated for each
+    /// row, which is in the local variable `variable`, and may only read that variable and the
+    /// `captures`, which are evaluated once when creating the model.
+    ModelAdapter {
+        kind: ModelAdapterKind,
+        model: Box<Expression>,
+        captures: Vec<(String, Expression)>,
+        variable: String,
+        expression: Box<Expression>,
+    },
 }
 
 impl Expression {
@@ -296,6 +315,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::EasingCurve(_) => Type::Easing,
             Self::LinearGradient { .. } => Type::Brush,
             Self::RadialGradient { .. } => Type::Brush,
+            Self::ConicGradient { .. } => Type::Brush,
             Self::EnumerationValue(e) => Type::Enumeration(e.enumeration.clone()),
             Self::LayoutCacheAccess { .. } => Type::LogicalLength,
             Self::BoxLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
@@ -304,6 +324,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             }
             Self::MinMax { ty, .. } => ty.clone(),
             Self::EmptyComponentFactory => Type::ComponentFactory,
+            Self::ModelAdapter { model, .. } => model.ty(ctx),
         }
     }
 }
@@ -354,14 +375,21 @@ macro_rules! visit_impl {
             Expression::Array { values, .. } => values.$iter().for_each($visitor),
             Expression::Struct { values, .. } => values.$values().for_each($visitor),
             Expression::EasingCurve(_) => {}
-            Expression::LinearGradient { angle, stops } => {
+            Expression::LinearGradient { angle, stops, .. } => {
                 $visitor(angle);
                 for (a, b) in stops {
                     $visitor(a);
                     $visitor(b);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { stops, .. } => {
+                for (a, b) in stops {
+                    $visitor(a);
+                    $visitor(b);
+                }
+            }
+            Expression::ConicGradient { from_angle, stops } => {
+                $visitor(from_angle);
                 for (a, b) in stops {
                     $visitor(a);
                     $visitor(b);
@@ -386,6 +414,11 @@ macro_rules! visit_impl {
                 $visitor(rhs);
             }
             Expression::EmptyComponentFactory => {}
+            Expression::ModelAdapter { model, captures, expression, .. } => {
+                $visitor(model);
+                captures.$iter().for_each(|(_, capture)| $visitor(capture));
+                $visitor(expression);
+            }
         }
     };
 }
@@ -668,6 +701,10 @@ fn property_ty(&self, prop: &PropertyReference) -> &Type {
                     // The `Path::elements` property is not in the NativeClass
                     return &Type::PathData;
                 }
+                if prop_name == "uniform-values" {
+                    // The `Shader::uniform-values` property is not in the NativeClass
+                    return &Type::LayoutCache;
+                }
 
                 let mut sub_component = self.current_sub_component.unwrap();
                 for i in sub_component_path {
@@ -42,6 +42,12 @@ pub struct BindingExpression {
     /// When true, the expression is a "state binding".  Despite the type of the expression being a integer
     /// the property is of type StateInfo and the `set_state_binding` ned to be used on the property
     pub is_state_info: bool,
+    /// When true, the binding is the one of a `pure` property of a global, which is computed once
+    /// and shared by all its users, so it must not be inlined in their expressions
+    pub is_memoized: bool,
+    /// The location of the binding in the `.slint` file, which the generators reference in
+    /// the debug information
+    pub source_location: Option<crate::diagnostics::SourceLocation>,
 
     /// The amount of time this binding is used
     /// This property is only valid after the [`count_property_use`](super::optim_passes::count_property_use) pass
@@ -132,6 +138,16 @@ pub struct ListViewInfo {
     pub prop_width: PropertyReference,
     // In the repeated component context
     pub prop_height: PropertyReference,
+
+    pub sections: Option<ListViewSectionsInfo>,
+}
+
+#[derive(Debug, Clone)]
+/// The properties of a ListView with section headers, in the parent context
+pub struct ListViewSectionsInfo {
+    pub section_rows: PropertyReference,
+    pub current_section: PropertyReference,
+    pub next_section_y: PropertyReference,
 }
 
 #[derive(Debug)]
@@ -146,6 +162,8 @@ pub struct RepeatedElement {
     pub index_in_tree: u32,
 
     pub listview: Option<ListViewInfo>,
+    /// The instance is kept to be reused when the model becomes empty (`retain if`)
+    pub retain_instances: bool,
 }
 
 #[derive(Debug)]
@@ -250,6 +268,8 @@ pub struct SubComponent {
     pub const_properties: Vec<PropertyReference>,
     /// Code that is run in the sub component constructor, after property initializations
     pub init_code: Vec<MutExpression>,
+    /// Code that is run when the sub component is destroyed
+    pub destructor_code: Vec<MutExpression>,
 
     /// For each node, an expression that returns a `{x: length, y: length, width: length, height: length}`
     pub geometries: Vec<Option<MutExpression>>,
@@ -410,7 +430,7 @@ pub fn for_each_expression<'a>(
         visitor: &mut dyn FnMut(&'a super::MutExpression, &EvaluationContext<'_>),
     ) {
         self.for_each_sub_components(&mut |sc, ctx| {
-            for e in &sc.init_code {
+            for e in sc.init_code.iter().chain(&sc.destructor_code) {
                 visitor(e, ctx);
             }
             for (_, e) in &sc.property_init {
@@ -443,6 +463,8 @@ pub struct PublicProperty {
     pub ty: Type,
     pub prop: PropertyReference,
     pub read_only: bool,
+    /// For callbacks and functions, the declared names of the arguments
+    pub arg_names: Vec<Option<String>>,
 }
 pub type PublicProperties = Vec<PublicProperty>;
 pub type PrivateProperties = Vec<(String, Type)>;
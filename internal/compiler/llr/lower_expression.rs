@@ -184,14 +184,25 @@ pub fn lower_expression(
         },
         tree_Expression::PathData(data) => compile_path(data, ctx),
         tree_Expression::EasingCurve(x) => llr_Expression::EasingCurve(x.clone()),
-        tree_Expression::LinearGradient { angle, stops } => llr_Expression::LinearGradient {
-            angle: Box::new(lower_expression(angle, ctx)),
+        tree_Expression::LinearGradient { angle, stops, spread } => {
+            llr_Expression::LinearGradient {
+                angle: Box::new(lower_expression(angle, ctx)),
+                stops: stops
+                    .iter()
+                    .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                    .collect::<_>(),
+                spread: *spread,
+            }
+        }
+        tree_Expression::RadialGradient { stops, spread } => llr_Expression::RadialGradient {
             stops: stops
                 .iter()
                 .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
                 .collect::<_>(),
+            spread: *spread,
         },
-        tree_Expression::RadialGradient { stops } => llr_Expression::RadialGradient {
+        tree_Expression::ConicGradient { from_angle, stops } => llr_Expression::ConicGradient {
+            from_angle: Box::new(lower_expression(from_angle, ctx)),
             stops: stops
                 .iter()
                 .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
@@ -217,6 +228,18 @@ pub fn lower_expression(
             rhs: Box::new(lower_expression(rhs, ctx)),
         },
         tree_Expression::EmptyComponentFactory => llr_Expression::EmptyComponentFactory,
+        tree_Expression::ModelAdapter { kind, model, captures, variable, expression } => {
+            llr_Expression::ModelAdapter {
+                kind: *kind,
+                model: Box::new(lower_expression(model, ctx)),
+                captures: captures
+                    .iter()
+                    .map(|(name, e)| (name.clone(), lower_expression(e, ctx)))
+                    .collect(),
+                variable: variable.clone(),
+                expression: Box::new(lower_expression(expression, ctx)),
+            }
+        }
     }
 }
 
@@ -381,7 +404,11 @@ fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Ex
             function: BuiltinFunction::ShowPopupWindow,
             arguments: vec![
                 llr_Expression::NumberLiteral(popup_index as _),
-                llr_Expression::BoolLiteral(popup.close_on_click),
+                llr_Expression::EnumerationValue(popup.close_policy.clone()),
+                llr_Expression::BoolLiteral(popup.close_on_escape),
+                llr_Expression::EnumerationValue(popup.anchor_edge.clone()),
+                llr_Expression::NumberLiteral(popup.anchor_offset),
+                llr_Expression::BoolLiteral(popup.anchor_flip),
                 item_ref,
             ],
         }
@@ -425,6 +452,7 @@ fn animation_ty() -> Type {
             name: Some("slint::private_api::PropertyAnimation".into()),
             node: None,
             rust_attributes: None,
+            functions: None,
         }
     }
 
@@ -463,6 +491,7 @@ fn animation_ty() -> Type {
                     name: None,
                     node: None,
                     rust_attributes: None,
+                    functions: None,
                 },
                 values: IntoIterator::into_iter([
                     ("0".to_string(), get_anim),
@@ -690,6 +719,7 @@ fn box_layout_data(
         name: Some("BoxLayoutCellData".into()),
         node: None,
         rust_attributes: None,
+        functions: None,
     };
 
     if repeater_count == 0 {
@@ -777,6 +807,7 @@ pub(super) fn grid_layout_cell_data_ty() -> Type {
         name: Some("GridLayoutCellData".into()),
         node: None,
         rust_attributes: None,
+        functions: None,
     }
 }
 
@@ -874,6 +905,7 @@ fn llr_path_elements(elements: Vec<llr_Expression>) -> llr_Expression {
                     name: Some("PathElement".to_owned()),
                     node: None,
                     rust_attributes: None,
+                    functions: None,
                 },
                 values: elements,
                 as_model: false,
@@ -898,6 +930,7 @@ fn llr_path_elements(elements: Vec<llr_Expression>) -> llr_Expression {
                         name: element.element_type.native_class.cpp_type.clone(),
                         node: None,
                         rust_attributes: None,
+                        functions: None,
                     };
 
                     llr_Expression::Struct {
@@ -950,6 +983,7 @@ fn llr_path_elements(elements: Vec<llr_Expression>) -> llr_Expression {
                         name: None,
                         node: None,
                         rust_attributes: None,
+                        functions: None,
                     },
                     values: IntoIterator::into_iter([
                         (
@@ -999,6 +1033,7 @@ pub fn make_struct(
             name: Some(format!("slint::private_api::{name}")),
             node: None,
             rust_attributes: None,
+            functions: None,
         },
         values,
     }
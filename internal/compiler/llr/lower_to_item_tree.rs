@@ -213,6 +213,7 @@ fn lower_sub_component(
         two_way_bindings: Default::default(),
         const_properties: Default::default(),
         init_code: Default::default(),
+        destructor_code: Default::default(),
         geometries: Default::default(),
         // just initialize to dummy expression right now and it will be set later
         layout_info_h: super::Expression::BoolLiteral(false).into(),
@@ -402,6 +403,8 @@ fn lower_sub_component(
                     animation,
                     is_constant,
                     is_state_info,
+                    is_memoized: false,
+                    source_location: binding.span.clone(),
                     use_count: 0.into(),
                 },
             ));
@@ -465,6 +468,13 @@ fn lower_sub_component(
         .iter()
         .map(|e| super::lower_expression::lower_expression(e, &ctx).into())
         .collect();
+    sub_component.destructor_code = component
+        .init_code
+        .borrow()
+        .destructor_code
+        .iter()
+        .map(|e| super::lower_expression::lower_expression(e, &ctx).into())
+        .collect();
 
     sub_component.layout_info_h = super::lower_expression::get_layout_info(
         &component.root_element,
@@ -551,7 +561,7 @@ fn lower_geometry(
             .insert(f.into(), super::Expression::PropertyReference(ctx.map_property_reference(v)));
     }
     super::Expression::Struct {
-        ty: Type::Struct { fields, name: None, node: None, rust_attributes: None },
+        ty: Type::Struct { fields, name: None, node: None, rust_attributes: None, functions: None },
         values,
     }
 }
@@ -609,6 +619,11 @@ fn lower_repeated_component(
         prop_y: sc.mapping.map_property_reference(&geom.y, ctx.state),
         prop_width: sc.mapping.map_property_reference(&geom.width, ctx.state),
         prop_height: sc.mapping.map_property_reference(&geom.height, ctx.state),
+        sections: lv.sections.as_ref().map(|s| ListViewSectionsInfo {
+            section_rows: ctx.map_property_reference(&s.section_rows),
+            current_section: ctx.map_property_reference(&s.current_section),
+            next_section_y: ctx.map_property_reference(&s.next_section_y),
+        }),
     });
 
     RepeatedElement {
@@ -622,6 +637,7 @@ fn lower_repeated_component(
         data_prop: (!repeated.is_conditional_element).then_some(0),
         index_in_tree: *e.item_index.get().unwrap(),
         listview,
+        retain_instances: repeated.is_retained,
     }
 }
 
@@ -785,11 +801,19 @@ fn lower_global(
             _ => unreachable!(),
         };
         let is_constant = binding.borrow().analysis.as_ref().map_or(false, |a| a.is_const);
+        let is_memoized = global
+            .root_element
+            .borrow()
+            .property_declarations
+            .get(prop)
+            .map_or(false, |decl| decl.pure == Some(true));
         init_values[property_index] = Some(BindingExpression {
             expression: expression.into(),
             animation: None,
             is_constant,
             is_state_info: false,
+            is_memoized,
+            source_location: binding.borrow().span.clone(),
             use_count: 0.into(),
         });
     }
@@ -910,6 +934,7 @@ fn public_properties(
                 ty: c.property_type.clone(),
                 prop: property_reference,
                 read_only: c.visibility == PropertyVisibility::Output,
+                arg_names: c.argument_names(),
             }
         })
         .collect()
@@ -59,7 +59,7 @@ pub fn count_property_use(root: &CompilationUnit) {
             }
         }
         // 3. the init code
-        for expr in &sc.init_code {
+        for expr in sc.init_code.iter().chain(&sc.destructor_code) {
             expr.borrow().visit_property_references(ctx, &mut visit_property);
         }
         // 4. the models
@@ -71,6 +71,11 @@ pub fn count_property_use(root: &CompilationUnit) {
                 visit_property(&lv.viewport_height, ctx);
                 visit_property(&lv.listview_width, ctx);
                 visit_property(&lv.listview_height, ctx);
+                if let Some(sections) = &lv.sections {
+                    visit_property(&sections.section_rows, ctx);
+                    visit_property(&sections.current_section, ctx);
+                    visit_property(&sections.next_section_y, ctx);
+                }
 
                 let rep_ctx = EvaluationContext::new_sub_component(
                     root,
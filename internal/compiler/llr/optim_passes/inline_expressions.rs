@@ -56,12 +56,15 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::EasingCurve(_) => 1,
         Expression::LinearGradient { .. } => ALLOC_COST,
         Expression::RadialGradient { .. } => ALLOC_COST,
+        Expression::ConicGradient { .. } => ALLOC_COST,
         Expression::EnumerationValue(_) => 0,
         Expression::LayoutCacheAccess { .. } => PROPERTY_ACCESS_COST,
         Expression::BoxLayoutFunction { .. } => return isize::MAX,
         Expression::ComputeDialogLayoutCells { .. } => return isize::MAX,
         Expression::MinMax { .. } => 10,
         Expression::EmptyComponentFactory => 10,
+        // Just like an array, the adapted model needs to keep its identity
+        Expression::ModelAdapter { .. } => return isize::MAX,
     };
 
     exp.visit(|e| cost = cost.saturating_add(expression_cost(e, ctx)));
@@ -78,6 +81,7 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
     match function {
         BuiltinFunction::GetWindowScaleFactor => PROPERTY_ACCESS_COST,
         BuiltinFunction::GetWindowDefaultFontSize => PROPERTY_ACCESS_COST,
+        BuiltinFunction::GetWindowWidth | BuiltinFunction::GetWindowHeight => PROPERTY_ACCESS_COST,
         BuiltinFunction::AnimationTick => PROPERTY_ACCESS_COST,
         BuiltinFunction::Debug => isize::MAX,
         BuiltinFunction::Mod => 10,
@@ -97,11 +101,16 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::Pow => 10,
         BuiltinFunction::SetFocusItem | BuiltinFunction::ClearFocusItem => isize::MAX,
         BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => isize::MAX,
-        BuiltinFunction::SetSelectionOffsets => isize::MAX,
+        BuiltinFunction::SetSelectionOffsets | BuiltinFunction::MoveSelectionHandle => isize::MAX,
         BuiltinFunction::ItemMemberFunction(..) => isize::MAX,
         BuiltinFunction::ItemFontMetrics => PROPERTY_ACCESS_COST,
         BuiltinFunction::StringToFloat => 50,
         BuiltinFunction::StringIsFloat => 50,
+        BuiltinFunction::FormatFixed => 50,
+        BuiltinFunction::PadString => 50,
+        BuiltinFunction::RegexMatches => isize::MAX,
+        BuiltinFunction::RegexReplace => isize::MAX,
+        BuiltinFunction::RegexCapture => isize::MAX,
         BuiltinFunction::ColorRgbaStruct => 50,
         BuiltinFunction::ColorHsvaStruct => 50,
         BuiltinFunction::ColorBrighter => 50,
@@ -110,25 +119,42 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::ColorMix => 50,
         BuiltinFunction::ColorWithAlpha => 50,
         BuiltinFunction::ImageSize => 50,
+        BuiltinFunction::ImageFromUrl => isize::MAX,
+        BuiltinFunction::ImageUrlLoading => isize::MAX,
+        BuiltinFunction::ImageUrlError => isize::MAX,
         BuiltinFunction::ArrayLength => 50,
         BuiltinFunction::Rgb => 50,
         BuiltinFunction::Hsv => 50,
         BuiltinFunction::ImplicitLayoutInfo(_) => isize::MAX,
         BuiltinFunction::ItemAbsolutePosition => isize::MAX,
+        BuiltinFunction::ItemIsVisible => isize::MAX,
+        BuiltinFunction::PropertyIsAnimating => isize::MAX,
+        BuiltinFunction::AssertionFailed => isize::MAX,
         BuiltinFunction::RegisterCustomFontByPath => isize::MAX,
         BuiltinFunction::RegisterCustomFontByMemory => isize::MAX,
         BuiltinFunction::RegisterBitmapFont => isize::MAX,
         BuiltinFunction::ColorScheme => isize::MAX,
+        BuiltinFunction::InteractionMode => isize::MAX,
+        BuiltinFunction::ThemeBrush => isize::MAX,
+        BuiltinFunction::ThemeLength => isize::MAX,
+        BuiltinFunction::ThemeString => isize::MAX,
         BuiltinFunction::MonthDayCount => isize::MAX,
         BuiltinFunction::MonthOffset => isize::MAX,
         BuiltinFunction::FormatDate => isize::MAX,
         BuiltinFunction::DateNow => isize::MAX,
         BuiltinFunction::ValidDate => isize::MAX,
         BuiltinFunction::ParseDate => isize::MAX,
+        BuiltinFunction::LocaleFormatNumber
+        | BuiltinFunction::LocaleParseNumber
+        | BuiltinFunction::LocaleFormatDate
+        | BuiltinFunction::LocaleParseDate
+        | BuiltinFunction::LocaleFormatTime
+        | BuiltinFunction::LocaleParseTime => isize::MAX,
         BuiltinFunction::SetTextInputFocused => PROPERTY_ACCESS_COST,
         BuiltinFunction::TextInputFocused => PROPERTY_ACCESS_COST,
         BuiltinFunction::Translate => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
         BuiltinFunction::Use24HourFormat => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
+        BuiltinFunction::TranslationLanguageMatches => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
         BuiltinFunction::UpdateTimers => 10,
     }
 }
@@ -147,6 +173,8 @@ fn inline_simple_expressions_in_expression(expr: &mut Expression, ctx: &Evaluati
                 if binding.animation.is_none()
                     // State info binding are special and the binding cannot be inlined or used.
                     && !binding.is_state_info
+                    // Pure properties of globals are computed once for all their users
+                    && !binding.is_memoized
                 {
                     let mapped_ctx = map.map_context(ctx);
                     let cost = expression_cost(&binding.expression.borrow(), &mapped_ctx);
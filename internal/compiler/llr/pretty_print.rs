@@ -5,7 +5,7 @@
 
 use itertools::Itertools;
 
-use crate::expression_tree::MinMaxOp;
+use crate::expression_tree::{MinMaxOp, ModelAdapterKind};
 
 use super::{
     CompilationUnit, EvaluationContext, Expression, ParentCtx, PropertyReference, SubComponent,
@@ -309,15 +309,23 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 values.iter().map(|(k, v)| format!("{}: {}", k, e(v))).join(", ")
             ),
             Expression::EasingCurve(x) => write!(f, "{:?}", x),
-            Expression::LinearGradient { angle, stops } => write!(
+            Expression::LinearGradient { angle, stops, spread } => write!(
                 f,
-                "@linear-gradient({}, {})",
+                "@linear-gradient({}, {}{})",
                 e(angle),
-                stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
+                stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", "),
+                spread.suffix()
+            ),
+            Expression::RadialGradient { stops, spread } => write!(
+                f,
+                "@radial-gradient(circle, {}{})",
+                stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", "),
+                spread.suffix()
             ),
-            Expression::RadialGradient { stops } => write!(
+            Expression::ConicGradient { from_angle, stops } => write!(
                 f,
-                "@radial-gradient(circle, {})",
+                "@conic-gradient(from {}, {})",
+                e(from_angle),
                 stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
             ),
             Expression::EnumerationValue(x) => write!(f, "{}", x),
@@ -340,6 +348,17 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 MinMaxOp::Max => write!(f, "max({}, {})", e(lhs), e(rhs)),
             },
             Expression::EmptyComponentFactory => write!(f, "<empty-component-factory>",),
+            Expression::ModelAdapter { kind, model, captures, variable, expression } => {
+                write!(f, "{}.", e(model))?;
+                match kind {
+                    ModelAdapterKind::Filter => write!(f, "filter(")?,
+                    ModelAdapterKind::SortBy => write!(f, "sort-by(")?,
+                }
+                for (name, capture) in captures {
+                    write!(f, "{name} = {}; ", e(capture))?;
+                }
+                write!(f, "{variable} => {})", e(expression))
+            }
         }
     }
 }
@@ -7,8 +7,10 @@
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::Path;
 use std::rc::Rc;
 
+use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::{BuiltinFunction, Expression};
 use crate::langtype::{
     BuiltinElement, BuiltinPropertyDefault, BuiltinPropertyInfo, DefaultSizeBinding, ElementType,
@@ -71,26 +73,9 @@ pub(crate) fn load_builtins(register: &mut TypeRegister) {
                         register,
                     ));
 
-                    info.property_visibility = PropertyVisibility::Private;
-
-                    for token in p.children_with_tokens() {
-                        if token.kind() != SyntaxKind::Identifier {
-                            continue;
-                        }
-                        match (token.as_token().unwrap().text(), info.property_visibility) {
-                            ("in", PropertyVisibility::Private) => {
-                                info.property_visibility = PropertyVisibility::Input
-                            }
-                            ("out", PropertyVisibility::Private) => {
-                                info.property_visibility = PropertyVisibility::Output
-                            }
-                            ("in-out", PropertyVisibility::Private) => {
-                                info.property_visibility = PropertyVisibility::InOut
-                            }
-                            ("property", _) => (),
-                            _ => unreachable!("invalid property keyword when parsing builtin file for property {id}::{prop_name}"),
-                        }
-                    }
+                    info.property_visibility = property_visibility(&p).unwrap_or_else(|| {
+                        unreachable!("invalid property keyword when parsing builtin file for property {id}::{prop_name}")
+                    });
 
                     if let Some(e) = p.BindingExpression() {
                         let ty = info.ty.clone();
@@ -232,6 +217,179 @@ enum Base {
     }
 }
 
+/// Parse a manifest that declares custom items, and add them to the type registry.
+///
+/// The manifest uses the syntax of builtins.slint. Each exported component is a custom item that
+/// must declare the Rust type that implements it with `//-rust_type:path::to::Type`, and the version
+/// of the `slint::custom_item` API that this type implements with `//-custom_item_api:1`.
+pub(crate) fn load_custom_items(
+    path: &Path,
+    register: &mut TypeRegister,
+    diag: &mut BuildDiagnostics,
+) {
+    let Some(node) = crate::parser::parse_file(path, diag) else { return };
+    let doc: syntax_nodes::Document = node.into();
+    let ElementType::Builtin(empty) = register.empty_type() else {
+        unreachable!("internal error: the Empty element is not builtin")
+    };
+
+    for c in doc.Component() {
+        diag.push_error(
+            "The custom items of a manifest must be exported".into(),
+            &c.DeclaredIdentifier(),
+        );
+    }
+
+    for c in doc.ExportsList().filter_map(|e| e.Component()) {
+        let Some(id) = identifier_text(&c.DeclaredIdentifier()) else { continue };
+        let e = c.Element();
+        match parse_annotation("custom_item_api", &e) {
+            Some(Some(version)) if version.trim() == CUSTOM_ITEM_API_VERSION => (),
+            Some(Some(version)) => {
+                diag.push_error(
+                    format!(
+                        "Unsupported custom item API version '{}'. The supported version is {CUSTOM_ITEM_API_VERSION}",
+                        version.trim()
+                    ),
+                    &c.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            _ => {
+                diag.push_error(
+                    format!("The custom item '{id}' must declare the version of the custom item API with '//-custom_item_api:{CUSTOM_ITEM_API_VERSION}'"),
+                    &c.DeclaredIdentifier(),
+                );
+                continue;
+            }
+        }
+        let Some(Some(rust_type)) = parse_annotation("rust_type", &e) else {
+            diag.push_error(
+                format!("The custom item '{id}' must declare the Rust type that implements it with '//-rust_type:path::to::Type'"),
+                &c.DeclaredIdentifier(),
+            );
+            continue;
+        };
+        if !is_rust_path(rust_type.trim()) {
+            diag.push_error(
+                format!("'{}' is not a path to a Rust type", rust_type.trim()),
+                &c.DeclaredIdentifier(),
+            );
+            continue;
+        }
+        if register.lookup_element(&id).is_ok() {
+            diag.push_error(
+                format!("The custom item '{id}' has the name of a builtin element"),
+                &c.DeclaredIdentifier(),
+            );
+            continue;
+        }
+        if let Some(base) = e.QualifiedName() {
+            diag.push_error("Custom items can't inherit from other elements".into(), &base);
+        }
+        for f in e.Function() {
+            diag.push_error("Custom items can't declare functions".into(), &f.DeclaredIdentifier());
+        }
+
+        let mut properties = Vec::new();
+        for p in e.PropertyDeclaration() {
+            if p.TwoWayBinding().is_some() {
+                diag.push_error("Custom items can't declare aliases".into(), &p);
+                continue;
+            }
+            let Some(prop_name) = identifier_text(&p.DeclaredIdentifier()) else { continue };
+            let Some(ty) = p.Type() else {
+                diag.push_error(format!("The property '{prop_name}' must have a type"), &p);
+                continue;
+            };
+            let mut info =
+                BuiltinPropertyInfo::new(object_tree::type_from_node(ty, diag, register));
+            let Some(visibility) = property_visibility(&p) else {
+                diag.push_error(format!("Invalid visibility of the property '{prop_name}'"), &p);
+                continue;
+            };
+            info.property_visibility = visibility;
+            if let Some(e) = p.BindingExpression() {
+                let ty = info.ty.clone();
+                let expr = Expression::from_binding_expression_node(
+                    e.clone().into(),
+                    &mut crate::lookup::LookupCtx::empty_context(register, diag),
+                )
+                .maybe_convert_to(ty, &e, diag);
+                info.default_value = BuiltinPropertyDefault::Expr(expr);
+            }
+            properties.push((prop_name, info));
+        }
+        for s in e.CallbackDeclaration() {
+            let Some(name) = identifier_text(&s.DeclaredIdentifier()) else { continue };
+            let args = s
+                .CallbackDeclarationParameter()
+                .map(|a| object_tree::type_from_node(a.Type(), diag, register))
+                .collect();
+            let return_type = s
+                .ReturnType()
+                .map(|a| Box::new(object_tree::type_from_node(a.Type(), diag, register)));
+            properties.push((name, BuiltinPropertyInfo::new(Type::Callback { args, return_type })));
+        }
+
+        let mut n = NativeClass::new_with_properties(&id, properties);
+        n.parent = Some(empty.native_class.clone());
+        n.rust_type = Some(rust_type.trim().into());
+        let mut builtin = BuiltinElement::new(Rc::new(n));
+        builtin
+            .properties
+            .extend(builtin.native_class.properties.iter().map(|(k, v)| (k.clone(), v.clone())));
+        builtin.default_size_binding = match parse_annotation("default_size_binding", &e) {
+            None => DefaultSizeBinding::ExpandsToParentGeometry,
+            Some(Some(size_type)) if size_type == "expands_to_parent_geometry" => {
+                DefaultSizeBinding::ExpandsToParentGeometry
+            }
+            Some(Some(size_type)) if size_type == "implicit_size" => {
+                DefaultSizeBinding::ImplicitSize
+            }
+            Some(Some(size_type)) if size_type == "none" => DefaultSizeBinding::None,
+            Some(_) => {
+                diag.push_error(
+                    "The default size binding must be 'expands_to_parent_geometry', 'implicit_size', or 'none'".into(),
+                    &c.DeclaredIdentifier(),
+                );
+                DefaultSizeBinding::ExpandsToParentGeometry
+            }
+        };
+        register.add_builtin(Rc::new(builtin));
+    }
+}
+
+/// Returns true for paths like `crate::module::Type`
+fn is_rust_path(path: &str) -> bool {
+    path.strip_prefix("::").unwrap_or(path).split("::").all(|segment| {
+        let mut chars = segment.chars();
+        chars.next().map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// The version of the `slint::custom_item` API that the generated code uses for custom items
+const CUSTOM_ITEM_API_VERSION: &str = "1";
+
+/// The visibility from the keywords of a property declaration, or None if they are invalid
+fn property_visibility(p: &syntax_nodes::PropertyDeclaration) -> Option<PropertyVisibility> {
+    let mut visibility = PropertyVisibility::Private;
+    for token in p.children_with_tokens() {
+        if token.kind() != SyntaxKind::Identifier {
+            continue;
+        }
+        match (token.as_token().unwrap().text(), visibility) {
+            ("in", PropertyVisibility::Private) => visibility = PropertyVisibility::Input,
+            ("out", PropertyVisibility::Private) => visibility = PropertyVisibility::Output,
+            ("in-out", PropertyVisibility::Private) => visibility = PropertyVisibility::InOut,
+            ("property", _) => (),
+            _ => return None,
+        }
+    }
+    Some(visibility)
+}
+
 /// Compile an expression, knowing that the expression is basic (does not have lookup to other things)
 fn compiled(
     node: syntax_nodes::BindingExpression,
@@ -3,7 +3,7 @@
 
 //! Helper to do lookup in expressions
 
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use crate::diagnostics::{BuildDiagnostics, Spanned};
 use crate::expression_tree::{
@@ -11,7 +11,7 @@
 };
 use crate::langtype::{ElementType, Enumeration, EnumerationValue, Type};
 use crate::namedreference::NamedReference;
-use crate::object_tree::{ElementRc, PropertyVisibility};
+use crate::object_tree::{Component, ElementRc, PropertyVisibility};
 use crate::parser::NodeOrToken;
 use crate::typeregister::TypeRegister;
 use std::cell::RefCell;
@@ -45,6 +45,10 @@ pub struct LookupCtx<'a> {
 
     /// The token currently processed
     pub current_token: Option<NodeOrToken>,
+
+    /// The variables declared by the enclosing lambdas, such as `item` in `filter(item => ...)`
+    /// (the last one is looked up first)
+    pub local_variables: Vec<(String, Type)>,
 }
 
 impl<'a> LookupCtx<'a> {
@@ -59,6 +63,7 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
             type_register,
             type_loader: None,
             current_token: None,
+            local_variables: Default::default(),
         }
     }
 
@@ -102,6 +107,7 @@ pub enum BuiltinNamespace {
     Colors,
     Math,
     Key,
+    Locale,
     SlintInternal,
 }
 
@@ -165,6 +171,9 @@ fn for_each_entry<R>(
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.for_each_entry(ctx, f),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.for_each_entry(ctx, f),
+            LookupResult::Namespace(BuiltinNamespace::Locale) => {
+                LocaleFunctions.for_each_entry(ctx, f)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.for_each_entry(ctx, f)
             }
@@ -180,6 +189,7 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.lookup(ctx, name),
+            LookupResult::Namespace(BuiltinNamespace::Locale) => LocaleFunctions.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.lookup(ctx, name)
             }
@@ -209,6 +219,24 @@ fn for_each_entry<R>(
     }
 }
 
+struct LocalVariableLookup;
+impl LookupObject for LocalVariableLookup {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        for (name, ty) in ctx.local_variables.iter().rev() {
+            if let Some(r) =
+                f(name, Expression::ReadLocalVariable { name: name.clone(), ty: ty.clone() }.into())
+            {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
 struct SpecialIdLookup;
 impl LookupObject for SpecialIdLookup {
     fn for_each_entry<R>(
@@ -754,6 +782,36 @@ fn for_each_entry<R>(
                     Expression::EasingCurve(EasingCurve::EaseInOutBounce).into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "spring",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Spring,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "steps",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Steps,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "sampled",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Sampled,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
     }
 }
 
@@ -808,6 +866,25 @@ fn for_each_entry<R>(
     }
 }
 
+struct LocaleFunctions;
+impl LookupObject for LocaleFunctions {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        use Expression::BuiltinFunctionReference as BFR;
+        let sl = || ctx.current_token.as_ref().map(|t| t.to_source_location());
+        let mut f = |n, e: Expression| f(n, e.into());
+        None.or_else(|| f("format-number", BFR(BuiltinFunction::LocaleFormatNumber, sl())))
+            .or_else(|| f("parse-number", BFR(BuiltinFunction::LocaleParseNumber, sl())))
+            .or_else(|| f("format-date", BFR(BuiltinFunction::LocaleFormatDate, sl())))
+            .or_else(|| f("parse-date", BFR(BuiltinFunction::LocaleParseDate, sl())))
+            .or_else(|| f("format-time", BFR(BuiltinFunction::LocaleFormatTime, sl())))
+            .or_else(|| f("parse-time", BFR(BuiltinFunction::LocaleParseTime, sl())))
+    }
+}
+
 struct SlintInternal;
 impl LookupObject for SlintInternal {
     fn for_each_entry<R>(
@@ -837,6 +914,17 @@ fn for_each_entry<R>(
                 .into(),
             )
         })
+        .or_else(|| {
+            f(
+                "interaction-mode",
+                Expression::FunctionCall {
+                    function: BFR(BuiltinFunction::InteractionMode, None).into(),
+                    arguments: vec![],
+                    source_location: sl(),
+                }
+                .into(),
+            )
+        })
         .or_else(|| {
             f(
                 "use-24-hour-format",
@@ -854,6 +942,9 @@ fn for_each_entry<R>(
         .or_else(|| f("date-now", BFR(BuiltinFunction::DateNow, sl()).into()))
         .or_else(|| f("valid-date", BFR(BuiltinFunction::ValidDate, sl()).into()))
         .or_else(|| f("parse-date", BFR(BuiltinFunction::ParseDate, sl()).into()))
+        .or_else(|| f("theme-brush", BFR(BuiltinFunction::ThemeBrush, sl()).into()))
+        .or_else(|| f("theme-length", BFR(BuiltinFunction::ThemeLength, sl()).into()))
+        .or_else(|| f("theme-string", BFR(BuiltinFunction::ThemeString, sl()).into()))
     }
 }
 
@@ -892,6 +983,16 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "format",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Format,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
             .or_else(|| {
                 f(
                     "animation-tick",
@@ -915,6 +1016,7 @@ fn for_each_entry<R>(
         None.or_else(|| f("Colors", LookupResult::Namespace(BuiltinNamespace::Colors)))
             .or_else(|| f("Math", LookupResult::Namespace(BuiltinNamespace::Math)))
             .or_else(|| f("Key", LookupResult::Namespace(BuiltinNamespace::Key)))
+            .or_else(|| f("Locale", LookupResult::Namespace(BuiltinNamespace::Locale)))
             .or_else(|| {
                 if ctx.type_register.expose_internal_types {
                     f("SlintInternal", LookupResult::Namespace(BuiltinNamespace::SlintInternal))
@@ -927,16 +1029,22 @@ fn for_each_entry<R>(
 
 pub fn global_lookup() -> impl LookupObject {
     (
-        ArgumentsLookup,
+        LocalVariableLookup,
         (
-            SpecialIdLookup,
+            ArgumentsLookup,
             (
-                IdLookup,
+                SpecialIdLookup,
                 (
-                    InScopeLookup,
+                    IdLookup,
                     (
-                        LookupType,
-                        (BuiltinNamespaceLookup, (ReturnTypeSpecificLookup, BuiltinFunctionLookup)),
+                        InScopeLookup,
+                        (
+                            LookupType,
+                            (
+                                BuiltinNamespaceLookup,
+                                (ReturnTypeSpecificLookup, BuiltinFunctionLookup),
+                            ),
+                        ),
                     ),
                 ),
             ),
@@ -953,7 +1061,7 @@ fn for_each_entry<R>(
         match self {
             Expression::ElementReference(e) => e.upgrade().unwrap().for_each_entry(ctx, f),
             _ => match self.ty() {
-                Type::Struct { fields, .. } => {
+                Type::Struct { fields, functions, .. } => {
                     for name in fields.keys() {
                         if let Some(r) = f(
                             name,
@@ -966,7 +1074,7 @@ fn for_each_entry<R>(
                             return Some(r);
                         }
                     }
-                    None
+                    StructFunctions(self, functions).for_each_entry(ctx, f)
                 }
                 Type::String => StringExpression(self).for_each_entry(ctx, f),
                 Type::Brush | Type::Color => ColorExpression(self).for_each_entry(ctx, f),
@@ -987,12 +1095,16 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
         match self {
             Expression::ElementReference(e) => e.upgrade().unwrap().lookup(ctx, name),
             _ => match self.ty() {
-                Type::Struct { fields, .. } => fields.contains_key(name).then(|| {
-                    LookupResult::from(Expression::StructFieldAccess {
-                        base: Box::new(self.clone()),
-                        name: name.to_string(),
-                    })
-                }),
+                Type::Struct { fields, functions, .. } => {
+                    if fields.contains_key(name) {
+                        Some(LookupResult::from(Expression::StructFieldAccess {
+                            base: Box::new(self.clone()),
+                            name: name.to_string(),
+                        }))
+                    } else {
+                        StructFunctions(self, functions).lookup(ctx, name)
+                    }
+                }
                 Type::String => StringExpression(self).lookup(ctx, name),
                 Type::Brush | Type::Color => ColorExpression(self).lookup(ctx, name),
                 Type::Image => ImageExpression(self).lookup(ctx, name),
@@ -1009,6 +1121,36 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
     }
 }
 
+/// The functions declared in the struct of an expression
+struct StructFunctions<'a>(&'a Expression, Option<Weak<Component>>);
+impl<'a> LookupObject for StructFunctions<'a> {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let root = self.1.as_ref()?.upgrade()?.root_element.clone();
+        let names = root.borrow().property_declarations.keys().cloned().collect::<Vec<_>>();
+        for name in names {
+            let member = Expression::FunctionReference(
+                NamedReference::new(&root, &name),
+                ctx.current_token.clone(),
+            );
+            if let Some(r) = f(
+                &name,
+                LookupResult::from(Expression::MemberFunction {
+                    base: Box::new(self.0.clone()),
+                    base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                    member: Box::new(member),
+                }),
+            ) {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
 struct StringExpression<'a>(&'a Expression);
 impl<'a> LookupObject for StringExpression<'a> {
     fn for_each_entry<R>(
@@ -1028,6 +1170,9 @@ fn for_each_entry<R>(
         };
         None.or_else(|| f("is-float", member_function(BuiltinFunction::StringIsFloat)))
             .or_else(|| f("to-float", member_function(BuiltinFunction::StringToFloat)))
+            .or_else(|| f("matches", member_function(BuiltinFunction::RegexMatches)))
+            .or_else(|| f("replace", member_function(BuiltinFunction::RegexReplace)))
+            .or_else(|| f("capture", member_function(BuiltinFunction::RegexCapture)))
     }
 }
 struct ColorExpression<'a>(&'a Expression);
@@ -1125,7 +1270,16 @@ fn for_each_entry<R>(
                 arguments: vec![self.0.clone()],
             })
         };
+        let member_macro = |m: BuiltinMacroFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinMacroReference(m, ctx.current_token.clone())),
+            })
+        };
         None.or_else(|| f("length", member_function(BuiltinFunction::ArrayLength)))
+            .or_else(|| f("filter", member_macro(BuiltinMacroFunction::ArrayFilter)))
+            .or_else(|| f("sort-by", member_macro(BuiltinMacroFunction::ArraySortBy)))
     }
 }
 
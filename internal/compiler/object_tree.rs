@@ -9,7 +9,9 @@
 
 use crate::diagnostics::{BuildDiagnostics, SourceLocation, Spanned};
 use crate::expression_tree::{self, BindingExpression, Expression, Unit};
-use crate::langtype::{BuiltinElement, BuiltinPropertyDefault, Enumeration, NativeClass, Type};
+use crate::langtype::{
+    BuiltinElement, BuiltinPropertyDefault, Enumeration, EnumerationValue, NativeClass, Type,
+};
 use crate::langtype::{ElementType, PropertyLookupResult};
 use crate::layout::{LayoutConstraints, Orientation};
 use crate::namedreference::NamedReference;
@@ -79,24 +81,32 @@ pub fn from_node(
              local_registry: &mut TypeRegister| {
                 let compo = Component::from_node(n, diag, local_registry);
                 local_registry.add(compo.clone());
-                inner_components.push(compo);
+                if compo.generic_parameters.is_none() {
+                    inner_components.push(compo);
+                }
+            };
+        let mut struct_functions = vec![];
+        let process_struct =
+            |n: syntax_nodes::StructDeclaration,
+             diag: &mut BuildDiagnostics,
+             local_registry: &mut TypeRegister,
+             inner_types: &mut Vec<Type>,
+             struct_functions: &mut Vec<Rc<Component>>| {
+                let rust_attributes = n.AtRustAttr().map(|child| vec![child.text().to_string()]);
+                let mut ty =
+                    type_struct_from_node(n.ObjectType(), diag, local_registry, rust_attributes);
+                if let Type::Struct { name, .. } = &mut ty {
+                    *name = parser::identifier_text(&n.DeclaredIdentifier());
+                } else {
+                    assert!(diag.has_errors());
+                    return;
+                }
+                if n.ObjectType().Function().next().is_some() {
+                    struct_functions.extend(struct_functions_global(&mut ty, diag, local_registry));
+                }
+                local_registry.insert_type(ty.clone());
+                inner_types.push(ty);
             };
-        let process_struct = |n: syntax_nodes::StructDeclaration,
-                              diag: &mut BuildDiagnostics,
-                              local_registry: &mut TypeRegister,
-                              inner_types: &mut Vec<Type>| {
-            let rust_attributes = n.AtRustAttr().map(|child| vec![child.text().to_string()]);
-            let mut ty =
-                type_struct_from_node(n.ObjectType(), diag, local_registry, rust_attributes);
-            if let Type::Struct { name, .. } = &mut ty {
-                *name = parser::identifier_text(&n.DeclaredIdentifier());
-            } else {
-                assert!(diag.has_errors());
-                return;
-            }
-            local_registry.insert_type(ty.clone());
-            inner_types.push(ty);
-        };
         let process_enum = |n: syntax_nodes::EnumDeclaration,
                             diag: &mut BuildDiagnostics,
                             local_registry: &mut TypeRegister,
@@ -133,9 +143,13 @@ pub fn from_node(
         for n in node.children() {
             match n.kind() {
                 SyntaxKind::Component => process_component(n.into(), diag, &mut local_registry),
-                SyntaxKind::StructDeclaration => {
-                    process_struct(n.into(), diag, &mut local_registry, &mut inner_types)
-                }
+                SyntaxKind::StructDeclaration => process_struct(
+                    n.into(),
+                    diag,
+                    &mut local_registry,
+                    &mut inner_types,
+                    &mut struct_functions,
+                ),
                 SyntaxKind::EnumDeclaration => {
                     process_enum(n.into(), diag, &mut local_registry, &mut inner_types)
                 }
@@ -150,6 +164,7 @@ pub fn from_node(
                                 diag,
                                 &mut local_registry,
                                 &mut inner_types,
+                                &mut struct_functions,
                             ),
                             SyntaxKind::EnumDeclaration => {
                                 process_enum(n.into(), diag, &mut local_registry, &mut inner_types)
@@ -163,6 +178,10 @@ pub fn from_node(
         }
         let mut exports = Exports::from_node(&node, &inner_components, &local_registry, diag);
         exports.add_reexports(reexports, diag);
+        // The globals holding the functions of the structs and the specializations of the generic
+        // components come first, so that they are never taken for the component to export by default
+        let specializations = take_new_specializations(&inner_components);
+        inner_components.splice(0..0, struct_functions.into_iter().chain(specializations));
 
         let custom_fonts = foreign_imports
             .into_iter()
@@ -240,7 +259,11 @@ pub fn from_node(
     }
 
     pub fn exported_roots(&self) -> impl DoubleEndedIterator<Item = Rc<Component>> + '_ {
-        self.exports.iter().filter_map(|e| e.1.as_ref().left()).filter(|c| !c.is_global()).cloned()
+        self.exports
+            .iter()
+            .filter_map(|e| e.1.as_ref().left())
+            .filter(|c| !c.is_global() && c.generic_parameters.is_none())
+            .cloned()
     }
 
     /// This is the component that is going to be instantiated by the interpreter
@@ -248,7 +271,7 @@ pub fn last_exported_component(&self) -> Option<Rc<Component>> {
         self.exports
             .iter()
             .filter_map(|e| Some((&e.0.name_ident, e.1.as_ref().left()?)))
-            .filter(|(_, c)| !c.is_global())
+            .filter(|(_, c)| !c.is_global() && c.generic_parameters.is_none())
             .max_by_key(|(n, _)| n.text_range().end())
             .map(|(_, c)| c.clone())
     }
@@ -273,7 +296,12 @@ pub struct PopupWindow {
     pub component: Rc<Component>,
     pub x: NamedReference,
     pub y: NamedReference,
-    pub close_on_click: bool,
+    pub close_policy: EnumerationValue,
+    pub close_on_escape: bool,
+    pub anchor_edge: EnumerationValue,
+    /// The value of `anchor-offset`, in logical pixels
+    pub anchor_offset: f64,
+    pub anchor_flip: bool,
     pub parent_element: ElementRc,
 }
 
@@ -286,6 +314,19 @@ pub struct Timer {
 
 type ChildrenInsertionPoint = (ElementRc, usize, syntax_nodes::ChildrenPlaceholder);
 
+/// The `@children` placeholders found while creating the elements of a component
+#[derive(Default)]
+pub struct ChildrenInsertionPoints {
+    pub default: Option<ChildrenInsertionPoint>,
+    pub named: BTreeMap<String, ChildrenInsertionPoint>,
+}
+
+impl ChildrenInsertionPoints {
+    fn first_placeholder(&self) -> Option<&syntax_nodes::ChildrenPlaceholder> {
+        self.default.iter().chain(self.named.values()).map(|(_, _, node)| node).next()
+    }
+}
+
 /// Used sub types for a root component
 #[derive(Debug, Default)]
 pub struct UsedSubTypes {
@@ -310,6 +351,10 @@ pub struct InitCode {
     /// Code inserted from inlined components, ordered by offset of the place where it was inlined from. This way
     /// we can preserve the order across multiple inlining passes.
     pub inlined_init_code: BTreeMap<usize, Expression>,
+
+    /// Code from before-destroy callbacks collected from elements, run when the component is destroyed.
+    /// It's not part of the iterators, which only return the code run at initialization.
+    pub destructor_code: Vec<Expression>,
 }
 
 impl InitCode {
@@ -351,6 +396,9 @@ pub struct Component {
     /// the element pointer to by this field.
     pub child_insertion_point: RefCell<Option<ChildrenInsertionPoint>>,
 
+    /// Same as `child_insertion_point` for the children that fill the named `@children(name)` placeholders
+    pub named_child_insertion_points: RefCell<BTreeMap<String, ChildrenInsertionPoint>>,
+
     pub init_code: RefCell<InitCode>,
 
     pub popup_windows: RefCell<Vec<PopupWindow>>,
@@ -366,6 +414,34 @@ pub struct Component {
     /// The list of properties (name and type) declared as private in the component.
     /// This is used to issue better error in the generated code if the property is used.
     pub private_properties: RefCell<Vec<(String, Type)>>,
+
+    /// The type parameters of a generic component, such as `T` in `component Selector<T>`.
+    /// Such a component is only a template that isn't part of any document: the elements that
+    /// use it are given a specialization of it for their type arguments.
+    pub generic_parameters: Option<GenericParameters>,
+
+    /// Set when this component is the specialization of a generic component
+    pub specialization: Option<Specialization>,
+}
+
+/// The type parameters of a generic component and its specializations
+#[derive(Debug)]
+pub struct GenericParameters {
+    pub names: Vec<String>,
+    /// The types and elements that are visible where the component is declared
+    pub(crate) type_register: Rc<RefCell<TypeRegister>>,
+    /// The specializations created so far, with their type arguments
+    pub(crate) specializations: RefCell<Vec<(Vec<Type>, Rc<Component>)>>,
+}
+
+/// The component created for the type arguments of a generic component
+#[derive(Debug)]
+pub struct Specialization {
+    /// The register in which the types and the expressions of the component are looked up, where
+    /// the type parameters are the type arguments
+    pub type_register: TypeRegister,
+    /// Set once the component was added to the inner components of a document
+    pub(crate) in_document: Cell<bool>,
 }
 
 impl Component {
@@ -374,9 +450,20 @@ pub fn from_node(
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
     ) -> Rc<Self> {
-        let mut child_insertion_point = None;
+        if let Some(parameters) = node.GenericParameters() {
+            return Rc::new(Self::generic_from_node(node, parameters, diag, tr));
+        }
+        Self::from_node_without_parameters(node, diag, tr).into_rc()
+    }
+
+    fn from_node_without_parameters(
+        node: syntax_nodes::Component,
+        diag: &mut BuildDiagnostics,
+        tr: &TypeRegister,
+    ) -> Self {
+        let mut child_insertion_points = ChildrenInsertionPoints::default();
         let is_legacy_syntax = node.child_token(SyntaxKind::ColonEqual).is_some();
-        let c = Component {
+        Component {
             node: Some(node.clone().into()),
             id: parser::identifier_text(&node.DeclaredIdentifier()).unwrap_or_default(),
             root_element: Element::from_node(
@@ -387,15 +474,19 @@ pub fn from_node(
                 } else {
                     ElementType::Error
                 },
-                &mut child_insertion_point,
+                &mut child_insertion_points,
                 is_legacy_syntax,
                 diag,
                 tr,
             ),
-            child_insertion_point: RefCell::new(child_insertion_point),
+            child_insertion_point: RefCell::new(child_insertion_points.default),
+            named_child_insertion_points: RefCell::new(child_insertion_points.named),
             ..Default::default()
-        };
-        let c = Rc::new(c);
+        }
+    }
+
+    fn into_rc(self) -> Rc<Self> {
+        let c = Rc::new(self);
         let weak = Rc::downgrade(&c);
         recurse_elem(&c.root_element, &(), &mut |e, _| {
             e.borrow_mut().enclosing_component = weak.clone();
@@ -408,6 +499,96 @@ pub fn from_node(
         c
     }
 
+    fn generic_from_node(
+        node: syntax_nodes::Component,
+        parameters: syntax_nodes::GenericParameters,
+        diag: &mut BuildDiagnostics,
+        tr: &TypeRegister,
+    ) -> Self {
+        let mut names = vec![];
+        for parameter in parameters.DeclaredIdentifier() {
+            let name = unwrap_or_continue!(parser::identifier_text(&parameter); diag);
+            if names.contains(&name) {
+                diag.push_error(format!("Duplicated type parameter '{name}'"), &parameter);
+                continue;
+            }
+            names.push(name);
+        }
+        Component {
+            node: Some(node.clone().into()),
+            id: parser::identifier_text(&node.DeclaredIdentifier()).unwrap_or_default(),
+            generic_parameters: Some(GenericParameters {
+                names,
+                type_register: Rc::new(RefCell::new(tr.clone())),
+                specializations: Default::default(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the specialization of this generic component for the type `arguments` of an
+    /// element whose base is `base_node`
+    fn specialize(
+        self: &Rc<Self>,
+        arguments: Option<syntax_nodes::GenericArguments>,
+        base_node: &syntax_nodes::QualifiedName,
+        diag: &mut BuildDiagnostics,
+        tr: &TypeRegister,
+    ) -> ElementType {
+        let generic = self.generic_parameters.as_ref().unwrap();
+        let Some(arguments) = arguments else {
+            diag.push_error(
+                format!(
+                    "'{0}' is a generic component. Specify its types, such as '{0}<{1}>'",
+                    self.id,
+                    generic.names.join(", ")
+                ),
+                base_node,
+            );
+            return ElementType::Error;
+        };
+        let types = arguments.Type().map(|ty| type_from_node(ty, diag, tr)).collect::<Vec<_>>();
+        if types.len() != generic.names.len() {
+            diag.push_error(
+                format!(
+                    "'{}' expects {} type arguments, but {} are provided",
+                    self.id,
+                    generic.names.len(),
+                    types.len()
+                ),
+                &arguments,
+            );
+            return ElementType::Error;
+        }
+        if types.contains(&Type::Invalid) {
+            debug_assert!(diag.has_errors());
+            return ElementType::Error;
+        }
+
+        if let Some((_, specialization)) =
+            generic.specializations.borrow().iter().find(|(t, _)| *t == types)
+        {
+            return ElementType::Component(specialization.clone());
+        }
+        let mut type_register = TypeRegister::new(&generic.type_register);
+        for (name, ty) in generic.names.iter().zip(&types) {
+            type_register.insert_type_with_name(ty.clone(), name.clone());
+        }
+        let node = syntax_nodes::Component::from(self.node.clone().unwrap());
+        let mut specialization = Self::from_node_without_parameters(node, diag, &type_register);
+        specialization.specialization =
+            Some(Specialization { type_register, in_document: Cell::new(false) });
+        let specialization = specialization.into_rc();
+        generic.specializations.borrow_mut().push((types, specialization.clone()));
+        ElementType::Component(specialization)
+    }
+
+    /// The register in which the types and the expressions of this component are looked up,
+    /// given the one of its document
+    pub fn type_register<'a>(&'a self, document_register: &'a TypeRegister) -> &'a TypeRegister {
+        self.specialization.as_ref().map_or(document_register, |s| &s.type_register)
+    }
+
     /// This component is a global component introduced with the "global" keyword
     pub fn is_global(&self) -> bool {
         match &self.root_element.borrow().base_type {
@@ -485,6 +666,7 @@ pub struct PropertyDeclaration {
     pub is_alias: Option<NamedReference>,
     pub visibility: PropertyVisibility,
     /// For function or callback: whether it is declared as `pure` (None for private function for which this has to be deduced)
+    /// For property: Some(true) for a `pure` property of a global, whose binding is not inlined
     pub pure: Option<bool>,
 }
 
@@ -498,6 +680,48 @@ pub fn type_node(&self) -> Option<SyntaxNode> {
             node.clone().into()
         }
     }
+
+    /// For a callback or a function, the names of the arguments, or None for the arguments of
+    /// callbacks that are declared without name
+    pub fn argument_names(&self) -> Vec<Option<String>> {
+        let Some(node) = &self.node else { return vec![] };
+        if let Some(callback) = syntax_nodes::CallbackDeclaration::new(node.clone()) {
+            callback
+                .CallbackDeclarationParameter()
+                .map(|p| p.DeclaredIdentifier().and_then(|n| parser::identifier_text(&n)))
+                .collect()
+        } else if let Some(function) = syntax_nodes::Function::new(node.clone()) {
+            is_struct_function(&function)
+                .then(|| Some("self".into()))
+                .into_iter()
+                .chain(
+                    function
+                        .ArgumentDeclaration()
+                        .map(|a| parser::identifier_text(&a.DeclaredIdentifier())),
+                )
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    /// For a callback, whether it is declared as `required`, so that it must be set where the
+    /// component is used
+    pub fn is_required(&self) -> bool {
+        self.node
+            .as_ref()
+            .and_then(|n| syntax_nodes::CallbackDeclaration::new(n.clone()))
+            .is_some_and(|c| has_callback_qualifier(&c, "required"))
+    }
+}
+
+/// Returns true if the callback declaration has the `pure` or `required` qualifier `qualifier`
+fn has_callback_qualifier(node: &syntax_nodes::CallbackDeclaration, qualifier: &str) -> bool {
+    node.children_with_tokens()
+        .filter_map(|n| n.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .take_while(|t| t.text() != "callback")
+        .any(|t| t.text() == qualifier)
 }
 
 impl From<Type> for PropertyDeclaration {
@@ -656,9 +880,16 @@ pub struct Element {
     pub repeated: Option<RepeatedElementInfo>,
     /// This element is a placeholder to embed an Component at
     pub is_component_placeholder: bool,
+    /// The name of the `@children(name)` placeholder of the base component of the parent that
+    /// this element fills. Cleared when the parent is inlined.
+    pub children_slot: Option<String>,
 
     pub states: Vec<State>,
     pub transitions: Vec<Transition>,
+    /// The `on Global.event => {}` handlers, lowered by the lower_event_handlers pass
+    pub event_handlers: Vec<EventHandler>,
+    /// The `assert` statements, lowered by the lower_assertions pass
+    pub assertions: Vec<Assertion>,
 
     /// true when this item's geometry is handled by a layout
     pub child_of_layout: bool,
@@ -860,6 +1091,18 @@ pub struct ListViewInfo {
     pub listview_height: NamedReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: NamedReference,
+    /// The properties for the section headers, if the ListView has them
+    pub sections: Option<ListViewSectionsInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListViewSectionsInfo {
+    /// The first row of each section
+    pub section_rows: NamedReference,
+    /// Set to the section of the first visible row
+    pub current_section: NamedReference,
+    /// Set to the position of the next section, relative to the top of the visible area
+    pub next_section_y: NamedReference,
 }
 
 #[derive(Debug, Clone)]
@@ -872,6 +1115,9 @@ pub struct RepeatedElementInfo {
     ///
     /// When this is true, the model is of type boolean instead of Model
     pub is_conditional_element: bool,
+    /// A conditional element declared with `retain if`: its instance is kept when the
+    /// condition becomes false, and shown again when it becomes true
+    pub is_retained: bool,
     /// When the for is the delegate of a ListView
     pub is_listview: Option<ListViewInfo>,
 }
@@ -891,7 +1137,7 @@ pub fn from_node(
         node: syntax_nodes::Element,
         id: String,
         parent_type: ElementType,
-        component_child_insertion_point: &mut Option<ChildrenInsertionPoint>,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
         is_legacy_syntax: bool,
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
@@ -907,7 +1153,18 @@ pub fn from_node(
                     );
                     ElementType::Error
                 }
-                Ok(ty) => ty,
+                Ok(ElementType::Component(c)) if c.generic_parameters.is_some() => {
+                    c.specialize(node.GenericArguments(), &base_node, diag, tr)
+                }
+                Ok(ty) => {
+                    if let Some(arguments) = node.GenericArguments() {
+                        diag.push_error(
+                            format!("'{base_string}' is not a generic component"),
+                            &arguments,
+                        );
+                    }
+                    ty
+                }
                 Err(err) => {
                     diag.push_error(err, &base_node);
                     ElementType::Error
@@ -923,6 +1180,7 @@ pub fn from_node(
             if let Some(n) = node.ChildrenPlaceholder() {
                 error_on(&n, "sub elements");
             }
+            node.ChildrenSlot().for_each(|n| error_on(&n, "sub elements"));
             node.PropertyAnimation().for_each(|n| error_on(&n, "animations"));
             node.States().for_each(|n| error_on(&n, "states"));
             node.Transitions().for_each(|n| error_on(&n, "transitions"));
@@ -932,9 +1190,19 @@ pub fn from_node(
                     error_on(&cb, "an 'init' callback")
                 }
             });
+            let declared_callbacks = node
+                .CallbackDeclaration()
+                .filter_map(|cb| parser::identifier_text(&cb.DeclaredIdentifier()))
+                .collect::<Vec<_>>();
             node.CallbackConnection().for_each(|cb| {
-                if parser::identifier_text(&cb).map_or(false, |s| s == "init") {
-                    error_on(&cb, "an 'init' callback")
+                match parser::identifier_text(&cb).as_deref() {
+                    Some("init") => error_on(&cb, "an 'init' callback"),
+                    Some(name @ ("before-destroy" | "shown" | "hidden"))
+                        if !declared_callbacks.iter().any(|c| c == name) =>
+                    {
+                        error_on(&cb, &format!("a '{name}' callback"))
+                    }
+                    _ => {}
                 }
             });
 
@@ -980,7 +1248,7 @@ pub fn from_node(
                 resolved_name: prop_name,
                 property_type: maybe_existing_prop_type,
                 ..
-            } = r.lookup_property(&unresolved_prop_name);
+            } = r.lookup_property_for_declaration(&unresolved_prop_name);
             match maybe_existing_prop_type {
                 Type::Callback { .. } => {
                     diag.push_error(
@@ -1010,11 +1278,16 @@ pub fn from_node(
             }
 
             let mut visibility = None;
+            let mut pure = None;
             for token in prop_decl.children_with_tokens() {
                 if token.kind() != SyntaxKind::Identifier {
                     continue;
                 }
                 match (token.as_token().unwrap().text(), visibility) {
+                    ("pure", _) if pure.is_some() => {
+                        diag.push_error("Extra 'pure' keyword".into(), &token)
+                    }
+                    ("pure", _) => pure = Some(true),
                     ("in", None) => visibility = Some(PropertyVisibility::Input),
                     ("in", Some(_)) => diag.push_error("Extra 'in' keyword".into(), &token),
                     ("out", None) => visibility = Some(PropertyVisibility::Output),
@@ -1038,12 +1311,35 @@ pub fn from_node(
                 }
             });
 
+            if pure.is_some() {
+                if r.base_type != ElementType::Global {
+                    diag.push_error(
+                        "Only properties of a global can be declared 'pure'".into(),
+                        &prop_decl,
+                    );
+                } else if prop_decl.BindingExpression().is_none() {
+                    diag.push_error(
+                        "A 'pure' property must be declared with a binding expression".into(),
+                        &prop_decl,
+                    );
+                } else if matches!(
+                    visibility,
+                    PropertyVisibility::Input | PropertyVisibility::InOut
+                ) {
+                    diag.push_error(
+                        "A 'pure' property cannot be 'in' or 'in-out'".into(),
+                        &prop_decl,
+                    );
+                }
+            }
+
             r.property_declarations.insert(
                 prop_name.to_string(),
                 PropertyDeclaration {
                     property_type: prop_type,
                     node: Some(prop_decl.clone().into()),
                     visibility,
+                    pure,
                     ..Default::default()
                 },
             );
@@ -1094,15 +1390,13 @@ pub fn from_node(
             let name =
                 unwrap_or_continue!(parser::identifier_text(&sig_decl.DeclaredIdentifier()); diag);
 
-            let pure = Some(
-                sig_decl.child_token(SyntaxKind::Identifier).map_or(false, |t| t.text() == "pure"),
-            );
+            let pure = Some(has_callback_qualifier(&sig_decl, "pure"));
 
             let PropertyLookupResult {
                 resolved_name: existing_name,
                 property_type: maybe_existing_prop_type,
                 ..
-            } = r.lookup_property(&name);
+            } = r.lookup_property_for_declaration(&name);
             if !matches!(maybe_existing_prop_type, Type::Invalid) {
                 if matches!(maybe_existing_prop_type, Type::Callback { .. }) {
                     if r.property_declarations.contains_key(&name) {
@@ -1144,15 +1438,20 @@ pub fn from_node(
                 continue;
             }
 
+            let mut arg_names = vec![];
             let args = sig_decl
                 .CallbackDeclarationParameter()
                 .map(|p| {
                     if let Some(n) = p.DeclaredIdentifier() {
-                        if !diag.enable_experimental && !tr.expose_internal_types {
-                            diag.push_error("Callback named parameters are experimental and not yet supported in this version of Slint".into(), &n);
+                        if let Some(name) = parser::identifier_text(&n) {
+                            if arg_names.contains(&name) {
+                                diag.push_error(format!("Duplicated argument name '{name}'"), &n);
+                            }
+                            arg_names.push(name);
                         }
                     }
-                    type_from_node(p.Type(), diag, tr)})
+                    type_from_node(p.Type(), diag, tr)
+                })
                 .collect();
             let return_type = sig_decl
                 .ReturnType()
@@ -1177,7 +1476,7 @@ pub fn from_node(
                 resolved_name: existing_name,
                 property_type: maybe_existing_prop_type,
                 ..
-            } = r.lookup_property(&name);
+            } = r.lookup_property_for_declaration(&name);
             if !matches!(maybe_existing_prop_type, Type::Invalid) {
                 if matches!(maybe_existing_prop_type, Type::Callback { .. } | Type::Function { .. })
                 {
@@ -1403,78 +1702,112 @@ pub fn from_node(
         }
 
         let mut children_placeholder = None;
+        let mut named_children_placeholders = Vec::new();
         let r = r.make_rc();
 
         for se in node.children() {
-            if se.kind() == SyntaxKind::SubElement {
+            if matches!(
+                se.kind(),
+                SyntaxKind::SubElement
+                    | SyntaxKind::RepeatedElement
+                    | SyntaxKind::ConditionalElement
+            ) {
                 let parent_type = r.borrow().base_type.clone();
-                r.borrow_mut().children.push(Element::from_sub_element_node(
-                    se.into(),
-                    parent_type,
-                    component_child_insertion_point,
-                    is_legacy_syntax,
-                    diag,
-                    tr,
-                ));
-            } else if se.kind() == SyntaxKind::RepeatedElement {
-                let mut sub_child_insertion_point = None;
-                let rep = Element::from_repeated_node(
-                    se.into(),
+                if let Some(child) = Element::from_child_node(
+                    se,
                     &r,
-                    &mut sub_child_insertion_point,
+                    parent_type,
+                    component_child_insertion_points,
                     is_legacy_syntax,
                     diag,
                     tr,
-                );
-                if let Some((_, _, se)) = sub_child_insertion_point {
-                    diag.push_error(
-                        "The @children placeholder cannot appear in a repeated element".into(),
-                        &se,
-                    )
+                ) {
+                    r.borrow_mut().children.push(child);
                 }
-                r.borrow_mut().children.push(rep);
-            } else if se.kind() == SyntaxKind::ConditionalElement {
-                let mut sub_child_insertion_point = None;
-                let rep = Element::from_conditional_node(
-                    se.into(),
-                    r.borrow().base_type.clone(),
-                    &mut sub_child_insertion_point,
-                    is_legacy_syntax,
-                    diag,
-                    tr,
-                );
-                if let Some((_, _, se)) = sub_child_insertion_point {
-                    diag.push_error(
-                        "The @children placeholder cannot appear in a conditional element".into(),
-                        &se,
-                    )
+            } else if se.kind() == SyntaxKind::ChildrenSlot {
+                let slot = syntax_nodes::ChildrenSlot::from(se);
+                let name = parser::identifier_text(&slot.DeclaredIdentifier()).unwrap_or_default();
+                let base_type = r.borrow().base_type.clone();
+                let parent_type = match lookup_named_child_insertion_element(&base_type, &name) {
+                    Some(insertion_element) => insertion_element.borrow().base_type.clone(),
+                    None => {
+                        if base_type != ElementType::Error {
+                            diag.push_error(
+                                format!(
+                                    "'{base_type}' doesn't have a @children({name}) placeholder"
+                                ),
+                                &slot.DeclaredIdentifier(),
+                            );
+                        }
+                        ElementType::Error
+                    }
+                };
+                for se in slot.children() {
+                    if let Some(child) = Element::from_child_node(
+                        se,
+                        &r,
+                        parent_type.clone(),
+                        component_child_insertion_points,
+                        is_legacy_syntax,
+                        diag,
+                        tr,
+                    ) {
+                        child.borrow_mut().children_slot = Some(name.clone());
+                        r.borrow_mut().children.push(child);
+                    }
                 }
-                r.borrow_mut().children.push(rep);
             } else if se.kind() == SyntaxKind::ChildrenPlaceholder {
-                if children_placeholder.is_some() {
+                let placeholder = syntax_nodes::ChildrenPlaceholder::from(se);
+                // The index doesn't count the children that fill the named placeholders of the base
+                let index = r
+                    .borrow()
+                    .children
+                    .iter()
+                    .filter(|c| c.borrow().children_slot.is_none())
+                    .count();
+                if let Some(name) =
+                    placeholder.DeclaredIdentifier().and_then(|n| parser::identifier_text(&n))
+                {
+                    named_children_placeholders.push((name, placeholder, index));
+                } else if children_placeholder.is_some() {
                     diag.push_error(
                         "The @children placeholder can only appear once in an element".into(),
-                        &se,
+                        &placeholder,
                     )
                 } else {
-                    children_placeholder = Some((se.clone().into(), r.borrow().children.len()));
+                    children_placeholder = Some((placeholder, index));
                 }
             }
         }
 
         if let Some((children_placeholder, index)) = children_placeholder {
-            if component_child_insertion_point.is_some() {
+            if component_child_insertion_points.default.is_some() {
                 diag.push_error(
                     "The @children placeholder can only appear once in an element hierarchy".into(),
                     &children_placeholder,
                 )
             } else {
-                *component_child_insertion_point = Some((r.clone(), index, children_placeholder));
+                component_child_insertion_points.default =
+                    Some((r.clone(), index, children_placeholder));
+            }
+        }
+        for (name, children_placeholder, index) in named_children_placeholders {
+            if component_child_insertion_points.named.contains_key(&name) {
+                diag.push_error(
+                    format!(
+                        "The @children({name}) placeholder can only appear once in an element hierarchy"
+                    ),
+                    &children_placeholder,
+                )
+            } else {
+                component_child_insertion_points
+                    .named
+                    .insert(name, (r.clone(), index, children_placeholder));
             }
         }
 
         for state in node.States().flat_map(|s| s.State()) {
-            let s = State {
+            let mut s = State {
                 id: parser::identifier_text(&state.DeclaredIdentifier()).unwrap_or_default(),
                 condition: state.Expression().map(|e| Expression::Uncompiled(e.into())),
                 property_changes: state
@@ -1492,7 +1825,60 @@ pub fn from_node(
                             })
                     })
                     .collect(),
+                entered: None,
+                exited: None,
+                event_transitions: vec![],
             };
+            for cb in state.StateCallback() {
+                let name = parser::identifier_text(&cb).unwrap_or_default();
+                let code = if name == "entered" { &mut s.entered } else { &mut s.exited };
+                if code.is_some() {
+                    diag.push_error(format!("Duplicated '{name}' callback"), &cb);
+                } else {
+                    *code = Some(Expression::Uncompiled(cb.into()));
+                }
+            }
+            for et in state.StateEventTransition() {
+                let event =
+                    unwrap_or_continue!(parser::identifier_text(&et.DeclaredIdentifier()); diag);
+                let existing = r.borrow().lookup_property(&event);
+                let is_other_event =
+                    r.borrow().property_declarations.get(&event).map_or(false, |d| {
+                        d.node
+                            .as_ref()
+                            .map_or(false, |n| n.kind() == SyntaxKind::StateEventTransition)
+                    });
+                if existing.property_type != Type::Invalid && !is_other_event {
+                    diag.push_error(
+                        format!("Cannot declare event '{event}' because '{event}' already exists"),
+                        &et.DeclaredIdentifier(),
+                    );
+                    continue;
+                } else if !is_other_event {
+                    let mut r = r.borrow_mut();
+                    r.property_declarations.insert(
+                        event.clone(),
+                        PropertyDeclaration {
+                            property_type: Type::Function {
+                                return_type: Type::Void.into(),
+                                args: vec![],
+                            },
+                            node: Some(et.clone().into()),
+                            visibility: PropertyVisibility::Public,
+                            ..Default::default()
+                        },
+                    );
+                    // The body of the function is generated by the lower_states pass
+                    r.bindings
+                        .insert(event.clone(), RefCell::new(Expression::CodeBlock(vec![]).into()));
+                }
+                s.event_transitions.push(StateEventTransition {
+                    event,
+                    guard: et.Expression().map(|e| Expression::Uncompiled(e.into())),
+                    target: QualifiedTypeName::from_node(et.QualifiedName()).to_string(),
+                    node: et,
+                });
+            }
             for trs in state.Transition() {
                 let mut t = Transition::from_node(trs, &r, tr, diag);
                 t.state_id.clone_from(&s.id);
@@ -1511,6 +1897,59 @@ pub fn from_node(
             }
         }
 
+        for ev in node.EventDeclaration() {
+            let name = unwrap_or_continue!(parser::identifier_text(&ev.DeclaredIdentifier()); diag);
+            if r.borrow().base_type != ElementType::Global {
+                diag.push_error("Events can only be declared in a global".into(), &ev);
+                continue;
+            }
+            if r.borrow().lookup_property_for_declaration(&name).property_type != Type::Invalid {
+                diag.push_error(
+                    format!("Cannot declare event '{name}' because '{name}' already exists"),
+                    &ev.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            let args = ev.Type().map(|t| type_from_node(t, diag, tr)).collect::<Vec<_>>();
+            declare_event(&r, name, args, ev);
+        }
+
+        for handler in node.EventHandler() {
+            if let Some(event) = lookup_event(handler.QualifiedName(), tr, diag) {
+                let Type::Function { args, .. } = event.ty() else { unreachable!() };
+                let num_arg = handler.DeclaredIdentifier().count();
+                if num_arg > args.len() {
+                    diag.push_error(
+                        format!(
+                            "'{}' only has {} arguments, but {} were provided",
+                            QualifiedTypeName::from_node(handler.QualifiedName()),
+                            args.len(),
+                            num_arg
+                        ),
+                        &handler.QualifiedName(),
+                    );
+                }
+                r.borrow_mut().event_handlers.push(EventHandler {
+                    event,
+                    code: Expression::Uncompiled(handler.clone().into()),
+                    node: handler,
+                });
+            }
+        }
+
+        for assert_node in node.AssertStatement() {
+            let condition = assert_node.Expression();
+            let message = assert_node
+                .child_text(SyntaxKind::StringLiteral)
+                .and_then(|s| crate::literals::unescape_string(&s))
+                .unwrap_or_else(|| condition.text().to_string().trim().to_owned());
+            r.borrow_mut().assertions.push(Assertion {
+                condition: Expression::Uncompiled(condition.into()),
+                message,
+                node: assert_node,
+            });
+        }
+
         if r.borrow().base_type.to_string() == "ListView" {
             let mut seen_for = false;
             for se in node.children() {
@@ -1522,6 +1961,7 @@ pub fn from_node(
                         | SyntaxKind::ConditionalElement
                         | SyntaxKind::RepeatedElement
                         | SyntaxKind::ChildrenPlaceholder
+                        | SyntaxKind::ChildrenSlot
                 ) {
                     diag.push_error("A ListView can just have a single 'for' as children. Anything else is not supported".into(), &se)
                 }
@@ -1531,10 +1971,76 @@ pub fn from_node(
         r
     }
 
+    /// Create the element of a `SubElement`, `RepeatedElement` or `ConditionalElement` node that
+    /// is a child of `parent`. Returns None if the node is excluded by its `@cfg` condition.
+    fn from_child_node(
+        node: SyntaxNode,
+        parent: &ElementRc,
+        parent_type: ElementType,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
+        is_legacy_syntax: bool,
+        diag: &mut BuildDiagnostics,
+        tr: &TypeRegister,
+    ) -> Option<ElementRc> {
+        match node.kind() {
+            SyntaxKind::SubElement => Some(Element::from_sub_element_node(
+                node.into(),
+                parent_type,
+                component_child_insertion_points,
+                is_legacy_syntax,
+                diag,
+                tr,
+            )),
+            SyntaxKind::RepeatedElement => {
+                let mut sub_child_insertion_points = ChildrenInsertionPoints::default();
+                let rep = Element::from_repeated_node(
+                    node.into(),
+                    parent,
+                    parent_type,
+                    &mut sub_child_insertion_points,
+                    is_legacy_syntax,
+                    diag,
+                    tr,
+                );
+                if let Some(se) = sub_child_insertion_points.first_placeholder() {
+                    diag.push_error(
+                        "The @children placeholder cannot appear in a repeated element".into(),
+                        se,
+                    )
+                }
+                Some(rep)
+            }
+            SyntaxKind::ConditionalElement => {
+                let condition = syntax_nodes::ConditionalElement::from(node.clone()).Expression();
+                if cfg_condition(&condition, tr) == Some(false) {
+                    // Excluded at compile time, so it doesn't even need to be valid
+                    return None;
+                }
+                let mut sub_child_insertion_points = ChildrenInsertionPoints::default();
+                let rep = Element::from_conditional_node(
+                    node.into(),
+                    parent_type,
+                    &mut sub_child_insertion_points,
+                    is_legacy_syntax,
+                    diag,
+                    tr,
+                );
+                if let Some(se) = sub_child_insertion_points.first_placeholder() {
+                    diag.push_error(
+                        "The @children placeholder cannot appear in a conditional element".into(),
+                        se,
+                    )
+                }
+                Some(rep)
+            }
+            _ => None,
+        }
+    }
+
     fn from_sub_element_node(
         node: syntax_nodes::SubElement,
         parent_type: ElementType,
-        component_child_insertion_point: &mut Option<ChildrenInsertionPoint>,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
         is_in_legacy_component: bool,
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
@@ -1551,7 +2057,7 @@ fn from_sub_element_node(
             node.Element(),
             id,
             parent_type,
-            component_child_insertion_point,
+            component_child_insertion_points,
             is_in_legacy_component,
             diag,
             tr,
@@ -1561,7 +2067,8 @@ fn from_sub_element_node(
     fn from_repeated_node(
         node: syntax_nodes::RepeatedElement,
         parent: &ElementRc,
-        component_child_insertion_point: &mut Option<ChildrenInsertionPoint>,
+        parent_type: ElementType,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
         is_in_legacy_component: bool,
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
@@ -1573,6 +2080,13 @@ fn from_repeated_node(
                 viewport_width: NamedReference::new(parent, "viewport-width"),
                 listview_height: NamedReference::new(parent, "visible-height"),
                 listview_width: NamedReference::new(parent, "visible-width"),
+                sections: (parent.borrow().lookup_property("section-rows").property_type
+                    != Type::Invalid)
+                    .then(|| ListViewSectionsInfo {
+                        section_rows: NamedReference::new(parent, "section-rows"),
+                        current_section: NamedReference::new(parent, "current-section"),
+                        next_section_y: NamedReference::new(parent, "next-section-y"),
+                    }),
             })
         } else {
             None
@@ -1588,12 +2102,13 @@ fn from_repeated_node(
                 .and_then(|r| parser::identifier_text(&r))
                 .unwrap_or_default(),
             is_conditional_element: false,
+            is_retained: false,
             is_listview,
         };
         let e = Element::from_sub_element_node(
             node.SubElement(),
-            parent.borrow().base_type.clone(),
-            component_child_insertion_point,
+            parent_type,
+            component_child_insertion_points,
             is_in_legacy_component,
             diag,
             tr,
@@ -1605,7 +2120,7 @@ fn from_repeated_node(
     fn from_conditional_node(
         node: syntax_nodes::ConditionalElement,
         parent_type: ElementType,
-        component_child_insertion_point: &mut Option<ChildrenInsertionPoint>,
+        component_child_insertion_points: &mut ChildrenInsertionPoints,
         is_in_legacy_component: bool,
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
@@ -1615,12 +2130,15 @@ fn from_conditional_node(
             model_data_id: String::new(),
             index_id: String::new(),
             is_conditional_element: true,
+            is_retained: node
+                .child_token(SyntaxKind::Identifier)
+                .map_or(false, |t| t.text() == "retain"),
             is_listview: None,
         };
         let e = Element::from_sub_element_node(
             node.SubElement(),
             parent_type,
-            component_child_insertion_point,
+            component_child_insertion_points,
             is_in_legacy_component,
             diag,
             tr,
@@ -1651,6 +2169,16 @@ pub fn lookup_property<'a>(&self, name: &'a str) -> PropertyLookupResult<'a> {
         )
     }
 
+    /// Same as [`Self::lookup_property`], but ignores the reserved callbacks that can be shadowed
+    /// by a declaration with the same name.
+    fn lookup_property_for_declaration<'a>(&self, name: &'a str) -> PropertyLookupResult<'a> {
+        let mut r = self.lookup_property(name);
+        if crate::typeregister::is_shadowable_reserved_callback(&r) {
+            r.property_type = Type::Invalid;
+        }
+        r
+    }
+
     fn parse_bindings(
         &mut self,
         bindings: impl Iterator<Item = (crate::parser::SyntaxToken, SyntaxNode)>,
@@ -1883,6 +2411,12 @@ pub fn type_from_node(
         }
         prop_type
     } else if let Some(object_node) = node.ObjectType() {
+        for function in object_node.Function() {
+            diag.push_error(
+                "Functions can only be declared in a struct declaration".into(),
+                &function.DeclaredIdentifier(),
+            );
+        }
         type_struct_from_node(object_node, diag, tr, None)
     } else if let Some(array_node) = node.ArrayType() {
         Type::Array(Box::new(type_from_node(array_node.Type(), diag, tr)))
@@ -1908,7 +2442,149 @@ pub fn type_struct_from_node(
             )
         })
         .collect();
-    Type::Struct { fields, name: None, node: Some(object_node), rust_attributes }
+    Type::Struct { fields, name: None, node: Some(object_node), rust_attributes, functions: None }
+}
+
+/// Create the global component that holds the functions declared in the struct `ty`, and make
+/// the struct refer to it. The functions are pure, and take the struct as their first argument,
+/// which they call `self`. The struct is registered in `tr`.
+fn struct_functions_global(
+    ty: &mut Type,
+    diag: &mut BuildDiagnostics,
+    tr: &mut TypeRegister,
+) -> Option<Rc<Component>> {
+    let Type::Struct { fields, name: Some(name), node: Some(object_node), functions, .. } = ty
+    else {
+        assert!(diag.has_errors());
+        return None;
+    };
+    let global = Rc::new(Component {
+        id: format!("{name}-functions"),
+        root_element: Element {
+            id: "root".into(),
+            base_type: ElementType::Global,
+            ..Default::default()
+        }
+        .make_rc(),
+        ..Default::default()
+    });
+    global.root_element.borrow_mut().enclosing_component = Rc::downgrade(&global);
+    *functions = Some(Rc::downgrade(&global));
+    let (fields, object_node) = (fields.clone(), object_node.clone());
+    // The functions may take or return the struct itself
+    tr.insert_type(ty.clone());
+
+    let mut root = global.root_element.borrow_mut();
+    for func in object_node.Function() {
+        let name = unwrap_or_continue!(parser::identifier_text(&func.DeclaredIdentifier()); diag);
+        if fields.contains_key(&name) {
+            diag.push_error(
+                format!("Cannot declare function '{name}' when a field with the same name exists"),
+                &func.DeclaredIdentifier(),
+            );
+            continue;
+        }
+        let mut args = vec![ty.clone()];
+        let mut arg_names = vec![];
+        for a in func.ArgumentDeclaration() {
+            args.push(type_from_node(a.Type(), diag, tr));
+            let name = unwrap_or_continue!(parser::identifier_text(&a.DeclaredIdentifier()); diag);
+            if name == "self" || arg_names.contains(&name) {
+                diag.push_error(
+                    format!("Duplicated argument name '{name}'"),
+                    &a.DeclaredIdentifier(),
+                );
+            }
+            arg_names.push(name);
+        }
+        let return_type = Box::new(
+            func.ReturnType().map_or(Type::Void, |ret_ty| type_from_node(ret_ty.Type(), diag, tr)),
+        );
+        if root
+            .bindings
+            .insert(name.clone(), BindingExpression::new_uncompiled(func.clone().into()).into())
+            .is_some()
+        {
+            diag.push_error(format!("Duplicated function '{name}'"), &func.DeclaredIdentifier());
+            continue;
+        }
+        root.property_declarations.insert(
+            name,
+            PropertyDeclaration {
+                property_type: Type::Function { return_type, args },
+                node: Some(func.into()),
+                visibility: PropertyVisibility::Public,
+                pure: Some(true),
+                ..Default::default()
+            },
+        );
+    }
+    drop(root);
+    Some(global)
+}
+
+/// Returns the name of the flag of `@cfg(flag)`
+pub fn cfg_flag(node: &syntax_nodes::AtCfg) -> Option<String> {
+    node.children_with_tokens()
+        .filter_map(|n| n.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .nth(1)
+        .map(|t| parser::normalize_identifier(t.text()))
+}
+
+/// Returns the value of a condition that consists only of `@cfg(flag)`, `!`, `&&`, `||`, and
+/// parentheses, or None if it depends on something else.
+fn cfg_condition(node: &syntax_nodes::Expression, tr: &TypeRegister) -> Option<bool> {
+    if let Some(at_cfg) = node.AtCfg() {
+        Some(cfg_flag(&at_cfg).is_some_and(|flag| tr.is_cfg_flag_set(&flag)))
+    } else if let Some(inner) = node.Expression() {
+        cfg_condition(&inner, tr)
+    } else if let Some(unary) = node.UnaryOpExpression() {
+        unary.child_token(SyntaxKind::Bang)?;
+        cfg_condition(&unary.Expression(), tr).map(|value| !value)
+    } else if let Some(binary) = node.BinaryExpression() {
+        let (lhs, rhs) = binary.Expression();
+        let (lhs, rhs) = (cfg_condition(&lhs, tr)?, cfg_condition(&rhs, tr)?);
+        if binary.child_token(SyntaxKind::AndAnd).is_some() {
+            Some(lhs && rhs)
+        } else if binary.child_token(SyntaxKind::OrOr).is_some() {
+            Some(lhs || rhs)
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+/// Returns the specializations of generic components that the `components` use, directly or
+/// through other specializations, and that aren't part of a document yet.
+fn take_new_specializations(components: &[Rc<Component>]) -> Vec<Rc<Component>> {
+    fn visit(component: &Rc<Component>, result: &mut Vec<Rc<Component>>) {
+        recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            if let ElementType::Component(base) = &elem.borrow().base_type {
+                if base.specialization.as_ref().is_some_and(|s| !s.in_document.replace(true)) {
+                    result.push(base.clone());
+                }
+            }
+        });
+    }
+    let mut result = vec![];
+    for component in components {
+        visit(component, &mut result);
+    }
+    let mut index = 0;
+    while let Some(specialization) = result.get(index).cloned() {
+        visit(&specialization, &mut result);
+        index += 1;
+    }
+    result
+}
+
+/// Returns true if the function is declared in a struct, in which case its first argument is the
+/// struct, called `self`.
+pub fn is_struct_function(function: &syntax_nodes::Function) -> bool {
+    function.parent().map_or(false, |parent| parent.kind() == SyntaxKind::ObjectType)
 }
 
 fn animation_element_from_node(
@@ -1939,6 +2615,39 @@ fn animation_element_from_node(
             diag,
         );
 
+        for con_node in anim.CallbackConnection() {
+            let unresolved_name = unwrap_or_continue!(parser::identifier_text(&con_node); diag);
+            let name_token = con_node.child_token(SyntaxKind::Identifier).unwrap();
+            if let Type::Callback { args, .. } =
+                &anim_element.lookup_property(&unresolved_name).property_type
+            {
+                let num_arg = con_node.DeclaredIdentifier().count();
+                if num_arg > args.len() {
+                    diag.push_error(
+                        format!(
+                            "'{}' only has {} arguments, but {} were provided",
+                            unresolved_name,
+                            args.len(),
+                            num_arg
+                        ),
+                        &name_token,
+                    );
+                }
+            } else {
+                diag.push_error(
+                    format!("'{unresolved_name}' is not a callback of animations"),
+                    &name_token,
+                );
+                continue;
+            }
+            match anim_element.bindings.entry(unresolved_name) {
+                Entry::Vacant(e) => {
+                    e.insert(BindingExpression::new_uncompiled(con_node.clone().into()).into());
+                }
+                Entry::Occupied(_) => diag.push_error("Duplicated callback".into(), &name_token),
+            }
+        }
+
         apply_default_type_properties(&mut anim_element);
 
         Some(Rc::new(RefCell::new(anim_element)))
@@ -1968,6 +2677,81 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
+/// Declare the event `name` in the global `elem`: a public function that stores its arguments
+/// in private properties and increments a counter, which the [`EventHandler`]s observe.
+fn declare_event(
+    elem: &ElementRc,
+    name: String,
+    args: Vec<Type>,
+    node: syntax_nodes::EventDeclaration,
+) {
+    let count = format!("{name}-event-count");
+    let mut code = vec![];
+    for (index, ty) in args.iter().enumerate() {
+        let arg = format!("{name}-event-arg{index}");
+        elem.borrow_mut()
+            .property_declarations
+            .insert(arg.clone(), PropertyDeclaration::from(ty.clone()));
+        code.push(Expression::SelfAssignment {
+            lhs: Box::new(Expression::PropertyReference(NamedReference::new(elem, &arg))),
+            rhs: Box::new(Expression::FunctionParameterReference { index, ty: ty.clone() }),
+            op: '=',
+            node: None,
+        });
+    }
+    elem.borrow_mut()
+        .property_declarations
+        .insert(count.clone(), PropertyDeclaration::from(Type::Int32));
+    code.push(Expression::SelfAssignment {
+        lhs: Box::new(Expression::PropertyReference(NamedReference::new(elem, &count))),
+        rhs: Box::new(Expression::NumberLiteral(1., Unit::None)),
+        op: '+',
+        node: None,
+    });
+    let mut elem_mut = elem.borrow_mut();
+    elem_mut.property_declarations.insert(
+        name.clone(),
+        PropertyDeclaration {
+            property_type: Type::Function { return_type: Type::Void.into(), args },
+            node: Some(node.into()),
+            visibility: PropertyVisibility::Public,
+            pure: Some(false),
+            ..Default::default()
+        },
+    );
+    elem_mut.bindings.insert(name, RefCell::new(Expression::CodeBlock(code).into()));
+}
+
+/// Returns the reference to the event function for `Global.event` in an event handler
+fn lookup_event(
+    node: syntax_nodes::QualifiedName,
+    tr: &TypeRegister,
+    diag: &mut BuildDiagnostics,
+) -> Option<NamedReference> {
+    let qualname = QualifiedTypeName::from_node(node.clone());
+    let [global_name, event] = qualname.members.as_slice() else {
+        diag.push_error(format!("'{qualname}' is not an event of a global"), &node);
+        return None;
+    };
+    let global = match tr.lookup_element(global_name) {
+        Ok(ElementType::Component(c)) if c.is_global() => c,
+        _ => {
+            diag.push_error(format!("'{global_name}' is not a global"), &node);
+            return None;
+        }
+    };
+    let root = global.root_element.clone();
+    let event = crate::parser::normalize_identifier(event);
+    let is_event = root.borrow().property_declarations.get(&event).map_or(false, |d| {
+        d.node.as_ref().map_or(false, |n| n.kind() == SyntaxKind::EventDeclaration)
+    });
+    if !is_event {
+        diag.push_error(format!("'{event}' is not an event of '{global_name}'"), &node);
+        return None;
+    }
+    Some(NamedReference::new(&root, &event))
+}
+
 /// Return a NamedReference for a qualified name used in a state (or transition),
 /// if the reference is invalid, there will be a diagnostic
 fn lookup_property_from_qualified_name_for_state(
@@ -2028,6 +2812,17 @@ fn lookup_property_from_qualified_name_for_state(
     }
 }
 
+/// Returns the element of the `@children(name)` placeholder of the component `base_type`, or of
+/// the component that it inherits from.
+fn lookup_named_child_insertion_element(base_type: &ElementType, name: &str) -> Option<ElementRc> {
+    let ElementType::Component(component) = base_type else { return None };
+    if let Some((insertion_element, ..)) = component.named_child_insertion_points.borrow().get(name)
+    {
+        return Some(insertion_element.clone());
+    }
+    lookup_named_child_insertion_element(&component.root_element.borrow().base_type, name)
+}
+
 /// FIXME: this is duplicated the resolving pass. Also, we should use a hash table
 fn find_element_by_id(e: &ElementRc, name: &str) -> Option<ElementRc> {
     if e.borrow().id == name {
@@ -2207,6 +3002,14 @@ fn visit_element_expressions_simple(
                 ne.element().borrow().lookup_property(ne.name()).property_type
             });
         }
+        for code in s.entered.iter_mut().chain(s.exited.iter_mut()) {
+            vis(code, None, &|| Type::Void);
+        }
+        for t in &mut s.event_transitions {
+            if let Some(guard) = t.guard.as_mut() {
+                vis(guard, None, &|| Type::Bool)
+            }
+        }
     }
     elem.borrow_mut().states = states;
 
@@ -2218,11 +3021,29 @@ fn visit_element_expressions_simple(
     }
     elem.borrow_mut().transitions = transitions;
 
+    let mut event_handlers = std::mem::take(&mut elem.borrow_mut().event_handlers);
+    for h in &mut event_handlers {
+        vis(&mut h.code, None, &|| match h.event.ty() {
+            Type::Function { args, .. } => Type::Callback { return_type: None, args },
+            _ => Type::Invalid,
+        });
+    }
+    elem.borrow_mut().event_handlers = event_handlers;
+
+    let mut assertions = std::mem::take(&mut elem.borrow_mut().assertions);
+    for a in &mut assertions {
+        vis(&mut a.condition, None, &|| Type::Bool);
+    }
+    elem.borrow_mut().assertions = assertions;
+
     let component = elem.borrow().enclosing_component.upgrade().unwrap();
     if Rc::ptr_eq(&component.root_element, elem) {
         for e in component.init_code.borrow_mut().iter_mut() {
             vis(e, None, &|| Type::Void);
         }
+        for e in component.init_code.borrow_mut().destructor_code.iter_mut() {
+            vis(e, None, &|| Type::Void);
+        }
     }
 }
 
@@ -2275,6 +3096,11 @@ pub fn visit_all_named_references_in_element(
         }
     }
     elem.borrow_mut().transitions = transitions;
+    let mut event_handlers = std::mem::take(&mut elem.borrow_mut().event_handlers);
+    for h in &mut event_handlers {
+        vis(&mut h.event);
+    }
+    elem.borrow_mut().event_handlers = event_handlers;
     let mut repeated = std::mem::take(&mut elem.borrow_mut().repeated);
     if let Some(r) = &mut repeated {
         if let Some(lv) = &mut r.is_listview {
@@ -2283,6 +3109,11 @@ pub fn visit_all_named_references_in_element(
             vis(&mut lv.viewport_width);
             vis(&mut lv.listview_height);
             vis(&mut lv.listview_width);
+            if let Some(sections) = &mut lv.sections {
+                vis(&mut sections.section_rows);
+                vis(&mut sections.current_section);
+                vis(&mut sections.next_section_y);
+            }
         }
     }
     elem.borrow_mut().repeated = repeated;
@@ -2370,6 +3201,38 @@ pub struct State {
     pub id: String,
     pub condition: Option<Expression>,
     pub property_changes: Vec<(NamedReference, Expression, syntax_nodes::StatePropertyChange)>,
+    /// The code of the `entered` callback
+    pub entered: Option<Expression>,
+    /// The code of the `exited` callback
+    pub exited: Option<Expression>,
+    pub event_transitions: Vec<StateEventTransition>,
+}
+
+/// `on Global.event(args) => { ... }`
+#[derive(Debug, Clone)]
+pub struct EventHandler {
+    /// The function of the event in the global
+    pub event: NamedReference,
+    pub code: Expression,
+    pub node: syntax_nodes::EventHandler,
+}
+
+/// `assert condition, "message";`
+#[derive(Debug, Clone)]
+pub struct Assertion {
+    pub condition: Expression,
+    /// The message given after the condition, or else the source code of the condition
+    pub message: String,
+    pub node: syntax_nodes::AssertStatement,
+}
+
+/// `on event when guard => target;` in a state
+#[derive(Debug, Clone)]
+pub struct StateEventTransition {
+    pub event: String,
+    pub guard: Option<Expression>,
+    pub target: String,
+    pub node: syntax_nodes::StateEventTransition,
 }
 
 #[derive(Debug, Clone)]
@@ -332,13 +332,18 @@ fn source_file(&self) -> Option<&SourceFile> {
     {
         Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration ],
         /// `DeclaredIdentifier := Element { ... }`
-        Component -> [ DeclaredIdentifier, Element ],
+        Component -> [ DeclaredIdentifier, ?GenericParameters, Element ],
+        /// `<T, U>` after the name of a generic component
+        GenericParameters -> [ *DeclaredIdentifier ],
         /// `id := Element { ... }`
         SubElement -> [ Element ],
-        Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
+        Element -> [ ?QualifiedName, ?GenericArguments, *PropertyDeclaration, *Binding, *CallbackConnection,
                      *CallbackDeclaration, *ConditionalElement, *Function, *SubElement,
                      *RepeatedElement, *PropertyAnimation, *PropertyChangedCallback,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *TwoWayBinding, *States, *Transitions, *EventDeclaration, *EventHandler,
+                     *AssertStatement, ?ChildrenPlaceholder, *ChildrenSlot ],
+        /// `<int, string>` after the name of the generic component of an element
+        GenericArguments -> [ *Type ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
@@ -353,14 +358,23 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
-        PropertyAnimation-> [ *QualifiedName, *Binding ],
+        PropertyAnimation-> [ *QualifiedName, *Binding, *CallbackConnection ],
         /// `changed xxx => {...}`  where `xxx` is the DeclaredIdentifier
         PropertyChangedCallback-> [ DeclaredIdentifier, CodeBlock ],
+        /// `event xxx(type, ...);` in a global
+        EventDeclaration -> [ DeclaredIdentifier, *Type ],
+        /// `on Global.event(args) => {...}`
+        EventHandler -> [ QualifiedName, *DeclaredIdentifier, CodeBlock ],
+        /// `assert condition;` or `assert condition, "message";`
+        AssertStatement -> [ Expression ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
         QualifiedName-> [],
         /// Wraps single identifier (to disambiguate when there are other identifier in the production)
         DeclaredIdentifier -> [],
-        ChildrenPlaceholder -> [],
+        /// `@children` or `@children(name)`
+        ChildrenPlaceholder -> [ ?DeclaredIdentifier ],
+        /// `@children(name) { ... }` with the elements that fill the placeholder `name`
+        ChildrenSlot -> [ DeclaredIdentifier, *SubElement, *RepeatedElement, *ConditionalElement ],
         Binding-> [ BindingExpression ],
         /// `xxx <=> something`
         TwoWayBinding -> [ Expression ],
@@ -372,28 +386,40 @@ fn source_file(&self) -> Option<&SourceFile> {
         // FIXME: the test should test that as alternative rather than several of them (but it can also be a literal)
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
-                       ?UnaryOpExpression, ?CodeBlock, ?StringTemplate, ?AtImageUrl, ?AtGradient, ?AtTr,
-                       ?MemberAccess ],
+                       ?UnaryOpExpression, ?CodeBlock, ?StringTemplate, ?AtImageUrl, ?AtGradient, ?AtTr, ?AtCfg,
+                       ?MemberAccess, ?Lambda, ?MatchExpression ],
         /// Concatenate the Expressions to make a string (usually expended from a template string)
         StringTemplate -> [*Expression],
         /// `@image-url("foo.png")`
         AtImageUrl -> [],
-        /// `@linear-gradient(...)` or `@radial-gradient(...)`
+        /// `@linear-gradient(...)`, `@radial-gradient(...)` or `@conic-gradient(...)`
         AtGradient -> [*Expression],
         /// `@tr("foo", ...)`  // the string is a StringLiteral
         AtTr -> [?TrContext, ?TrPlural, *Expression],
+        /// `@cfg(debug)`, where `debug` is a flag of the compiler configuration
+        AtCfg -> [],
         /// `"foo" =>`  in a `AtTr` node
         TrContext -> [],
         /// `| "foo" % n`  in a `AtTr` node
         TrPlural -> [Expression],
         /// expression()
-        FunctionCallExpression -> [*Expression],
+        FunctionCallExpression -> [*Expression, *NamedArgument],
+        /// `foo: bar` in the arguments of a FunctionCallExpression
+        NamedArgument -> [ Expression ],
+        /// `item => expression`, as the argument of `filter()` or `sort-by()`
+        Lambda -> [DeclaredIdentifier, Expression],
         /// `expression[index]`
         IndexExpression -> [2 Expression],
         /// `expression += expression`
         SelfAssignment -> [2 Expression],
         /// `condition ? first : second`
         ConditionalExpression -> [3 Expression],
+        /// `match value { pattern => expression, else => expression }`
+        MatchExpression -> [Expression, *MatchArm, ?MatchElse],
+        /// `pattern => expression` in a MatchExpression
+        MatchArm -> [2 Expression],
+        /// `else => expression` in a MatchExpression
+        MatchElse -> [Expression],
         /// `expr + expr`
         BinaryExpression -> [2 Expression],
         /// `- expr`
@@ -409,9 +435,14 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `states: [...]`
         States -> [*State],
         /// The DeclaredIdentifier is the state name. The Expression, if any, is the condition.
-        State -> [DeclaredIdentifier, ?Expression, *StatePropertyChange, *Transition],
+        State -> [DeclaredIdentifier, ?Expression, *StatePropertyChange, *Transition, *StateCallback, *StateEventTransition],
         /// binding within a state
         StatePropertyChange -> [ QualifiedName, BindingExpression ],
+        /// There is an identifier "entered" or "exited"
+        StateCallback -> [ CodeBlock ],
+        /// `on event when guard => target;` The DeclaredIdentifier is the event, the Expression, if any, is the guard,
+        /// and the QualifiedName is the target state
+        StateEventTransition -> [ DeclaredIdentifier, ?Expression, QualifiedName ],
         /// `transitions: [...]`
         Transitions -> [*Transition],
         /// There is an identifier "in" or "out", the DeclaredIdentifier is the state name
@@ -426,7 +457,10 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `export ... from "foo"`. The import uri is stored as string literal.
         ExportModule -> [],
         /// import { foo, bar, baz } from "blah"; The import uri is stored as string literal.
-        ImportSpecifier -> [ ?ImportIdentifierList ],
+        ImportSpecifier -> [ ?ImportIdentifierList, ?ImportStyle ],
+        /// `style "material"` after the import uri, to use that style instead of the one of the application.
+        /// The style is stored as string literal.
+        ImportStyle -> [],
         ImportIdentifierList -> [ *ImportIdentifier ],
         /// { foo as bar } or just { foo }
         ImportIdentifier -> [ ExternalName, ?InternalName ],
@@ -435,7 +469,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// The representation of a type
         Type -> [ ?QualifiedName, ?ObjectType, ?ArrayType ],
         /// `{foo: string, bar: string} `
-        ObjectType ->[ *ObjectTypeMember ],
+        /// (The functions can only be declared in a StructDeclaration)
+        ObjectType ->[ *ObjectTypeMember, *Function ],
         /// `foo: type` inside an ObjectType
         ObjectTypeMember -> [ Type ],
         /// `[ type ]`
@@ -99,6 +99,8 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
 /// global Struct { property<int> xx; }
 /// component C { property<int> xx; }
 /// component C inherits D { }
+/// component C<T> { in property <T> value; }
+/// component C<T, U> inherits D<T> { }
 /// ```
 pub fn parse_component(p: &mut impl Parser) -> bool {
     let simple_component = p.nth(1).kind() == SyntaxKind::ColonEqual;
@@ -118,6 +120,9 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
         drop(p.start_node(SyntaxKind::Element));
         return false;
     }
+    if is_new_component && p.peek().kind() == SyntaxKind::LAngle {
+        parse_generic_parameters(&mut *p);
+    }
     if is_global {
         if p.peek().kind() == SyntaxKind::ColonEqual {
             p.warning("':=' to declare a global is deprecated. Remove the ':='");
@@ -154,6 +159,29 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
     parse_element(&mut *p)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,GenericParameters
+/// <T>
+/// <T, U>
+/// <T,>
+/// ```
+fn parse_generic_parameters(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::GenericParameters);
+    p.expect(SyntaxKind::LAngle);
+    while p.nth(0).kind() != SyntaxKind::RAngle {
+        {
+            let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+            if !p.expect(SyntaxKind::Identifier) {
+                return;
+            }
+        }
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RAngle);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,QualifiedName
 /// Rectangle
@@ -281,6 +309,7 @@ fn parse_export_specifier(p: &mut impl Parser) -> bool {
 /// ```test,ImportSpecifier
 /// import { Type1, Type2 } from "somewhere";
 /// import "something.ttf";
+/// import { Button } from "std-widgets.slint" style "fluent";
 /// ```
 fn parse_import_specifier(p: &mut impl Parser) -> bool {
     debug_assert_eq!(p.peek().as_str(), "import");
@@ -307,6 +336,13 @@ fn parse_import_specifier(p: &mut impl Parser) -> bool {
         return false;
     }
     p.consume();
+    if p.peek().as_str() == "style" {
+        let mut p = p.start_node(SyntaxKind::ImportStyle);
+        p.consume(); // "style"
+        if !p.expect(SyntaxKind::StringLiteral) {
+            return false;
+        }
+    }
     p.expect(SyntaxKind::Semicolon)
 }
 
@@ -14,10 +14,17 @@
 /// Item { }
 /// Item { property: value; SubElement { } }
 /// Item { if true: Rectangle {} }
+/// Selector<string> { Item<[int], {a: int}> { } }
 /// ```
 pub fn parse_element(p: &mut impl Parser) -> bool {
     let mut p = p.start_node(SyntaxKind::Element);
-    if !(parse_qualified_name(&mut *p) && p.expect(SyntaxKind::LBrace)) {
+    if !parse_qualified_name(&mut *p) {
+        return false;
+    }
+    if p.peek().kind() == SyntaxKind::LAngle && !parse_generic_arguments(&mut *p) {
+        return false;
+    }
+    if !p.expect(SyntaxKind::LBrace) {
         return false;
     }
 
@@ -26,21 +33,46 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
     p.expect(SyntaxKind::RBrace)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,GenericArguments
+/// <int>
+/// <string, [int]>
+/// <{a: int, b: string},>
+/// ```
+fn parse_generic_arguments(p: &mut impl Parser) -> bool {
+    let mut p = p.start_node(SyntaxKind::GenericArguments);
+    p.expect(SyntaxKind::LAngle);
+    while p.nth(0).kind() != SyntaxKind::RAngle {
+        parse_type(&mut *p);
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RAngle)
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test
 /// property1: value; property2: value;
 /// sub := Sub { }
 /// for xx in model: Sub {}
 /// if condition : Sub {}
+/// retain if condition : Sub {}
 /// clicked => {}
 /// callback foobar;
 /// property<int> width;
 /// animate someProp { }
 /// animate * { }
 /// @children
+/// @children(header)
+/// @children(header) { Sub { } }
 /// double_binding <=> element.property;
+/// Generic<int> { }
 /// public pure function foo() {}
 /// changed foo => {}
+/// event foo(int);
+/// on Foo.bar(x) => {}
+/// assert foo > 0, "foo must be positive";
 /// ```
 pub fn parse_element_content(p: &mut impl Parser) {
     let mut had_parse_error = false;
@@ -49,6 +81,7 @@ pub fn parse_element_content(p: &mut impl Parser) {
             SyntaxKind::RBrace => return,
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
+                _ if is_assert_statement(&mut *p) => parse_assert_statement(&mut *p),
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
                 SyntaxKind::ColonEqual | SyntaxKind::LBrace => parse_sub_element(&mut *p),
                 SyntaxKind::FatArrow | SyntaxKind::LParent if p.peek().as_str() != "if" => {
@@ -60,7 +93,11 @@ pub fn parse_element_content(p: &mut impl Parser) {
                 }
                 SyntaxKind::Identifier
                     if p.peek().as_str() == "callback"
-                        || (p.peek().as_str() == "pure" && p.nth(1).as_str() == "callback") =>
+                        || (matches!(p.peek().as_str(), "pure" | "required")
+                            && p.nth(1).as_str() == "callback")
+                        || (matches!(p.peek().as_str(), "pure" | "required")
+                            && matches!(p.nth(1).as_str(), "pure" | "required")
+                            && p.nth(2).as_str() == "callback") =>
                 {
                     parse_callback_declaration(&mut *p);
                 }
@@ -79,21 +116,36 @@ pub fn parse_element_content(p: &mut impl Parser) {
                 SyntaxKind::Identifier if p.peek().as_str() == "changed" => {
                     parse_changed_callback(&mut *p);
                 }
+                SyntaxKind::Identifier if p.peek().as_str() == "event" => {
+                    parse_event_declaration(&mut *p);
+                }
+                SyntaxKind::Identifier
+                    if p.peek().as_str() == "on" && p.nth(2).kind() == SyntaxKind::Dot =>
+                {
+                    parse_event_handler(&mut *p);
+                }
                 SyntaxKind::LAngle | SyntaxKind::Identifier if p.peek().as_str() == "property" => {
                     parse_property_declaration(&mut *p);
                 }
+                SyntaxKind::LAngle => parse_sub_element(&mut *p),
                 SyntaxKind::Identifier
-                    if p.nth(1).as_str() == "property"
+                    if (p.nth(1).as_str() == "property"
                         && matches!(
                             p.peek().as_str(),
-                            "in" | "out" | "in_out" | "in-out" | "private"
-                        ) =>
+                            "in" | "out" | "in_out" | "in-out" | "private" | "pure"
+                        ))
+                        || (p.nth(1).as_str() == "pure" && p.nth(2).as_str() == "property") =>
                 {
                     parse_property_declaration(&mut *p);
                 }
                 _ if p.peek().as_str() == "if" => {
                     parse_if_element(&mut *p);
                 }
+                SyntaxKind::Identifier
+                    if p.peek().as_str() == "retain" && p.nth(1).as_str() == "if" =>
+                {
+                    parse_if_element(&mut *p);
+                }
                 SyntaxKind::LBracket if p.peek().as_str() == "states" => {
                     parse_states(&mut *p);
                 }
@@ -117,9 +169,21 @@ pub fn parse_element_content(p: &mut impl Parser) {
                 let checkpoint = p.checkpoint();
                 p.consume();
                 if p.peek().as_str() == "children" {
-                    let mut p =
-                        p.start_node_at(checkpoint.clone(), SyntaxKind::ChildrenPlaceholder);
-                    p.consume()
+                    if p.nth(1).kind() == SyntaxKind::LParent
+                        && p.nth(4).kind() == SyntaxKind::LBrace
+                    {
+                        let mut p = p.start_node_at(checkpoint.clone(), SyntaxKind::ChildrenSlot);
+                        p.consume(); // "children"
+                        parse_children_name(&mut *p);
+                        parse_children_slot_content(&mut *p);
+                    } else {
+                        let mut p =
+                            p.start_node_at(checkpoint.clone(), SyntaxKind::ChildrenPlaceholder);
+                        p.consume(); // "children"
+                        if p.nth(0).kind() == SyntaxKind::LParent {
+                            parse_children_name(&mut *p);
+                        }
+                    }
                 } else {
                     p.test(SyntaxKind::Identifier);
                     p.error("Parse error: Expected @children")
@@ -136,6 +200,55 @@ pub fn parse_element_content(p: &mut impl Parser) {
     }
 }
 
+/// Parses the `(name)` of a named `@children` placeholder or slot
+fn parse_children_name(p: &mut impl Parser) {
+    p.expect(SyntaxKind::LParent);
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::RParent);
+}
+
+/// Parses the `{ ... }` with the elements that fill a named `@children` placeholder
+fn parse_children_slot_content(p: &mut impl Parser) {
+    p.expect(SyntaxKind::LBrace);
+    let mut had_parse_error = false;
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace | SyntaxKind::Eof => break,
+            SyntaxKind::Identifier
+                if p.peek().as_str() == "for" && p.nth(1).kind() == SyntaxKind::Identifier =>
+            {
+                parse_repeated_element(&mut *p)
+            }
+            SyntaxKind::Identifier
+                if (p.peek().as_str() == "if"
+                    && !matches!(p.nth(1).kind(), SyntaxKind::LBrace | SyntaxKind::ColonEqual))
+                    || (p.peek().as_str() == "retain" && p.nth(1).as_str() == "if") =>
+            {
+                parse_if_element(&mut *p)
+            }
+            SyntaxKind::Identifier
+                if matches!(
+                    p.nth(1).kind(),
+                    SyntaxKind::LBrace | SyntaxKind::ColonEqual | SyntaxKind::LAngle
+                ) =>
+            {
+                parse_sub_element(&mut *p)
+            }
+            _ => {
+                if !had_parse_error {
+                    p.error("Parse error: Only elements can fill a @children placeholder");
+                    had_parse_error = true;
+                }
+                p.consume();
+            }
+        }
+    }
+    p.expect(SyntaxKind::RBrace);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,SubElement
 /// Bar {}
@@ -192,11 +305,15 @@ fn parse_repeated_element(p: &mut impl Parser) {
 /// if (foo ? bar : xx) : Elem { foo:bar; Elem {}}
 /// if (true) : foo := Elem {}
 /// if true && true : Elem {}
+/// retain if (condition) : Elem { }
 /// ```
 /// Must consume at least one token
 fn parse_if_element(p: &mut impl Parser) {
-    debug_assert_eq!(p.peek().as_str(), "if");
+    debug_assert!(matches!(p.peek().as_str(), "if" | "retain"));
     let mut p = p.start_node(SyntaxKind::ConditionalElement);
+    if p.peek().as_str() == "retain" {
+        p.consume(); // "retain"
+    }
     p.expect(SyntaxKind::Identifier); // "if"
     parse_expression(&mut *p);
     if !p.expect(SyntaxKind::Colon) {
@@ -305,6 +422,8 @@ fn parse_two_way_binding(p: &mut impl Parser) {
 /// callback foo(int, string);
 /// callback foo(foo: int, string, xx: { a: string });
 /// pure callback one_arg({ a: string, b: string});
+/// required callback clicked();
+/// required pure callback compute(int) -> int;
 /// callback end_coma(a, b, c,);
 /// callback with_return(a, b) -> int;
 /// callback with_return2({a: string}) -> { a: string };
@@ -313,7 +432,7 @@ fn parse_two_way_binding(p: &mut impl Parser) {
 /// Must consume at least one token
 fn parse_callback_declaration(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::CallbackDeclaration);
-    if p.peek().as_str() == "pure" {
+    while matches!(p.peek().as_str(), "pure" | "required") {
         p.consume();
     }
     debug_assert_eq!(p.peek().as_str(), "callback");
@@ -372,10 +491,11 @@ fn parse_callback_declaration(p: &mut impl Parser) {
 /// property<string> text: "Something";
 /// property<string> text <=> two.way;
 /// property alias <=> two.way;
+/// out pure property <int> total: a + b;
 /// ```
 fn parse_property_declaration(p: &mut impl Parser) {
     let checkpoint = p.checkpoint();
-    while matches!(p.peek().as_str(), "in" | "out" | "in-out" | "in_out" | "private") {
+    while matches!(p.peek().as_str(), "in" | "out" | "in-out" | "in_out" | "private" | "pure") {
         p.consume();
     }
     if p.peek().as_str() != "property" {
@@ -421,6 +541,7 @@ fn parse_property_declaration(p: &mut impl Parser) {
 /// animate x { duration: 1000; }
 /// animate x, foo.y {  }
 /// animate * {  }
+/// animate x { duration: 1000; finished => { foo(); } started(property) => {} }
 /// ```
 fn parse_property_animation(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().as_str(), "animate");
@@ -446,14 +567,15 @@ fn parse_property_animation(p: &mut impl Parser) {
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
+                SyntaxKind::FatArrow | SyntaxKind::LParent => parse_callback_connection(&mut *p),
                 _ => {
                     p.consume();
-                    p.error("Only bindings are allowed in animations");
+                    p.error("Only bindings and callbacks are allowed in animations");
                 }
             },
             _ => {
                 p.consume();
-                p.error("Only bindings are allowed in animations");
+                p.error("Only bindings and callbacks are allowed in animations");
             }
         }
     }
@@ -475,6 +597,103 @@ fn parse_changed_callback(p: &mut impl Parser) {
     parse_code_block(&mut *p);
 }
 
+/// Returns true if the element content starting with `assert` is an assert statement, and not the
+/// binding or the callback connection of a property or callback called `assert`
+fn is_assert_statement(p: &mut impl Parser) -> bool {
+    if p.peek().as_str() != "assert" {
+        return false;
+    }
+    match p.nth(1).kind() {
+        SyntaxKind::Colon
+        | SyntaxKind::ColonEqual
+        | SyntaxKind::LBrace
+        | SyntaxKind::FatArrow
+        | SyntaxKind::DoubleArrow => false,
+        SyntaxKind::LParent => {
+            // `assert(a, b) => { ... }` is a callback connection
+            let mut n = 2;
+            while p.nth(n).kind() == SyntaxKind::Identifier
+                && p.nth(n + 1).kind() == SyntaxKind::Comma
+            {
+                n += 2;
+            }
+            if p.nth(n).kind() == SyntaxKind::Identifier {
+                n += 1;
+            }
+            !(p.nth(n).kind() == SyntaxKind::RParent && p.nth(n + 1).kind() == SyntaxKind::FatArrow)
+        }
+        _ => true,
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,AssertStatement
+/// assert value >= 0;
+/// assert (value >= minimum && value <= maximum), "value out of range";
+/// assert model.length > 0, "the model can't be empty";
+/// ```
+fn parse_assert_statement(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "assert");
+    let mut p = p.start_node(SyntaxKind::AssertStatement);
+    p.expect(SyntaxKind::Identifier); // "assert"
+    parse_expression(&mut *p);
+    if p.test(SyntaxKind::Comma) {
+        p.expect(SyntaxKind::StringLiteral);
+    }
+    p.expect(SyntaxKind::Semicolon);
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,EventDeclaration
+/// event refresh;
+/// event item-selected(int, string);
+/// ```
+fn parse_event_declaration(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "event");
+    let mut p = p.start_node(SyntaxKind::EventDeclaration);
+    p.expect(SyntaxKind::Identifier); // "event"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.test(SyntaxKind::LParent) {
+        while p.peek().kind() != SyntaxKind::RParent {
+            parse_type(&mut *p);
+            if !p.test(SyntaxKind::Comma) {
+                break;
+            }
+        }
+        p.expect(SyntaxKind::RParent);
+    }
+    p.expect(SyntaxKind::Semicolon);
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,EventHandler
+/// on Bus.refresh => {}
+/// on Bus.item-selected(index, name) => { debug(index); }
+/// ```
+fn parse_event_handler(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "on");
+    let mut p = p.start_node(SyntaxKind::EventHandler);
+    p.expect(SyntaxKind::Identifier); // "on"
+    parse_qualified_name(&mut *p);
+    if p.test(SyntaxKind::LParent) {
+        while p.peek().kind() != SyntaxKind::RParent {
+            {
+                let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+                p.expect(SyntaxKind::Identifier);
+            }
+            if !p.test(SyntaxKind::Comma) {
+                break;
+            }
+        }
+        p.expect(SyntaxKind::RParent);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,States
 /// states []
@@ -495,6 +714,8 @@ fn parse_states(p: &mut impl Parser) {
 /// foo when bar == 1:  { color: blue; foo.color: red;   }
 /// a when b:  { color: blue; in { animate color { duration: 120s; } }   }
 /// a when b:  { out { animate foo.bar { } } foo.bar: 42;  }
+/// idle : { on start => running; entered => { debug("idle"); } }
+/// running : { on stop when !busy => idle; exited => { } color: red; }
 /// ```
 fn parse_state(p: &mut impl Parser) -> bool {
     if p.nth(0).kind() != SyntaxKind::Identifier {
@@ -522,6 +743,22 @@ fn parse_state(p: &mut impl Parser) -> bool {
             }
             SyntaxKind::Eof => return false,
             _ => {
+                if p.nth(1).kind() == SyntaxKind::FatArrow
+                    && matches!(p.peek().as_str(), "entered" | "exited")
+                {
+                    let mut p = p.start_node(SyntaxKind::StateCallback);
+                    p.consume(); // "entered" or "exited"
+                    p.expect(SyntaxKind::FatArrow);
+                    parse_code_block(&mut *p);
+                    continue;
+                }
+                if p.peek().as_str() == "on"
+                    && p.nth(1).kind() == SyntaxKind::Identifier
+                    && (p.nth(2).kind() == SyntaxKind::FatArrow || p.nth(2).as_str() == "when")
+                {
+                    parse_state_event_transition(&mut *p);
+                    continue;
+                }
                 if p.nth(1).kind() == SyntaxKind::LBrace
                     && matches!(p.peek().as_str(), "in" | "out")
                 {
@@ -547,6 +784,28 @@ fn parse_state(p: &mut impl Parser) -> bool {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,StateEventTransition
+/// on start => running;
+/// on submit when root.valid && !busy => sending;
+/// ```
+fn parse_state_event_transition(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "on");
+    let mut p = p.start_node(SyntaxKind::StateEventTransition);
+    p.expect(SyntaxKind::Identifier); // "on"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.peek().as_str() == "when" {
+        p.consume();
+        parse_expression(&mut *p);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_qualified_name(&mut *p);
+    p.expect(SyntaxKind::Semicolon);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,Transitions
 /// transitions []
@@ -617,7 +876,7 @@ fn parse_transition_inner(p: &mut impl Parser) -> bool {
 /// public function aa(x: int, b: {a: int}, c: int) {}
 /// protected pure function fff() {}
 /// ```
-fn parse_function(p: &mut impl Parser) {
+pub fn parse_function(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::Function);
     if matches!(p.peek().as_str(), "public" | "protected") {
         p.consume();
@@ -30,6 +30,8 @@
 /// array[index]
 /// {object:42}
 /// "foo".bar.something().something.xx({a: 1.foo}.a)
+/// model.filter(item => item.checked)
+/// match mode { Mode.dark => black, else => white }
 /// ```
 pub fn parse_expression(p: &mut impl Parser) -> bool {
     p.peek(); // consume the whitespace so they aren't part of the Expression node
@@ -56,6 +58,24 @@ fn parse_expression_helper(p: &mut impl Parser, precedence: OperatorPrecedence)
     let mut p = p.start_node(SyntaxKind::Expression);
     let checkpoint = p.checkpoint();
     match p.nth(0).kind() {
+        SyntaxKind::Identifier if p.nth(1).kind() == SyntaxKind::FatArrow => {
+            parse_lambda(&mut *p);
+            return true;
+        }
+        SyntaxKind::Identifier
+            if p.peek().as_str() == "match"
+                && !matches!(
+                    p.nth(1).kind(),
+                    SyntaxKind::Dot
+                        | SyntaxKind::Comma
+                        | SyntaxKind::Semicolon
+                        | SyntaxKind::RBrace
+                        | SyntaxKind::RBracket
+                        | SyntaxKind::RParent
+                ) =>
+        {
+            parse_match_expression(&mut *p);
+        }
         SyntaxKind::Identifier => {
             parse_qualified_name(&mut *p);
         }
@@ -232,13 +252,19 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "radial-gradient" | "radial_gradient" => {
             parse_gradient(p);
         }
+        "conic-gradient" | "conic_gradient" => {
+            parse_gradient(p);
+        }
         "tr" => {
             parse_tr(p);
         }
+        "cfg" => {
+            parse_cfg(p);
+        }
         _ => {
             p.consume();
             p.test(SyntaxKind::Identifier); // consume the identifier, so that autocomplete works
-            p.error("Expected 'image-url', 'tr', 'linear-gradient' or 'radial-gradient' after '@'");
+            p.error("Expected 'image-url', 'tr', 'cfg', 'linear-gradient', 'radial-gradient' or 'conic-gradient' after '@'");
         }
     }
 }
@@ -263,6 +289,58 @@ fn parse_array(p: &mut impl Parser) {
     p.expect(SyntaxKind::RBracket);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Lambda
+/// item => item.checked
+/// x => x.name == "foo" && x.count > 3
+/// ```
+fn parse_lambda(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::Lambda);
+    p.start_node(SyntaxKind::DeclaredIdentifier).expect(SyntaxKind::Identifier);
+    p.expect(SyntaxKind::FatArrow);
+    parse_expression(&mut *p);
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,MatchExpression
+/// match mode { Mode.dark => black, Mode.light => white }
+/// match mode { dark => black, else => white, }
+/// match (a + b) { 1 => "one", 2 => "two", else => "many" }
+/// match x { }
+/// ```
+fn parse_match_expression(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::MatchExpression);
+    debug_assert_eq!(p.peek().as_str(), "match");
+    p.expect(SyntaxKind::Identifier); // "match"
+    parse_expression(&mut *p);
+    p.expect(SyntaxKind::LBrace);
+
+    while !matches!(p.nth(0).kind(), SyntaxKind::RBrace | SyntaxKind::Eof) {
+        if p.peek().as_str() == "else" && p.nth(1).kind() == SyntaxKind::FatArrow {
+            let mut p = p.start_node(SyntaxKind::MatchElse);
+            p.consume(); // "else"
+            p.consume(); // "=>"
+            parse_expression(&mut *p);
+        } else {
+            let mut p = p.start_node(SyntaxKind::MatchArm);
+            if p.nth(0).kind() == SyntaxKind::Identifier && p.nth(1).kind() == SyntaxKind::FatArrow
+            {
+                // A single identifier, that parse_expression would take for a lambda
+                let mut p = p.start_node(SyntaxKind::Expression);
+                parse_qualified_name(&mut *p);
+            } else {
+                parse_expression(&mut *p);
+            }
+            p.expect(SyntaxKind::FatArrow);
+            parse_expression(&mut *p);
+        }
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RBrace);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,ObjectLiteral
 /// {}
@@ -292,12 +370,20 @@ fn parse_object_notation(p: &mut impl Parser) {
 /// (foo)
 /// (foo, bar, foo)
 /// (foo, bar(), xx+xx,)
+/// (foo, bar: 42, xx: a+b)
 /// ```
 fn parse_function_arguments(p: &mut impl Parser) {
     p.expect(SyntaxKind::LParent);
 
     while p.nth(0).kind() != SyntaxKind::RParent {
-        parse_expression(&mut *p);
+        if p.nth(0).kind() == SyntaxKind::Identifier && p.nth(1).kind() == SyntaxKind::Colon {
+            let mut p = p.start_node(SyntaxKind::NamedArgument);
+            p.expect(SyntaxKind::Identifier);
+            p.expect(SyntaxKind::Colon);
+            parse_expression(&mut *p);
+        } else {
+            parse_expression(&mut *p);
+        }
         if !p.test(SyntaxKind::Comma) {
             break;
         }
@@ -340,6 +426,8 @@ fn parse_template_string(p: &mut impl Parser) {
 /// @linear-gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @linear_gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @radial-gradient(circle, #e66465, blue 50%, #9198e5)
+/// @conic-gradient(red, yellow 90deg, blue 50%, red)
+/// @conic-gradient(from 45deg, #e66465, #9198e5)
 /// ```
 fn parse_gradient(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::AtGradient);
@@ -357,6 +445,22 @@ fn parse_gradient(p: &mut impl Parser) {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,AtCfg
+/// @cfg(debug)
+/// @cfg(debug-panel)
+/// ```
+fn parse_cfg(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::AtCfg);
+    p.consume(); // "@"
+    p.consume(); // "cfg"
+    if !(p.expect(SyntaxKind::LParent) && p.expect(SyntaxKind::Identifier)) {
+        p.until(SyntaxKind::RParent);
+        return;
+    }
+    p.expect(SyntaxKind::RParent);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,AtTr
 /// @tr("foo")
@@ -419,6 +523,8 @@ fn consume_literal(p: &mut impl Parser) -> bool {
 /// @image-url("foo.png",)
 /// @image-url("foo.png", nine-slice(1 2 3 4))
 /// @image-url("foo.png", nine-slice(1))
+/// @image-url("foo.png", locale)
+/// @image-url("foo.png", locale, nine-slice(1 2))
 /// ```
 fn parse_image_url(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::AtImageUrl);
@@ -449,8 +555,21 @@ fn parse_image_url(p: &mut impl Parser) {
     if p.test(SyntaxKind::RParent) {
         return;
     }
+    if p.peek().as_str() == "locale" {
+        p.consume();
+        if !p.test(SyntaxKind::Comma) {
+            if !p.test(SyntaxKind::RParent) {
+                p.error("Expected ')' or ','");
+                p.until(SyntaxKind::RParent);
+            }
+            return;
+        }
+        if p.test(SyntaxKind::RParent) {
+            return;
+        }
+    }
     if p.peek().as_str() != "nine-slice" {
-        p.error("Expected 'nine-slice(...)' argument");
+        p.error("Expected 'locale' or 'nine-slice(...)' argument");
         p.until(SyntaxKind::RParent);
         return;
     }
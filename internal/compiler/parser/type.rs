@@ -4,6 +4,7 @@
 //! Module containing the parsing functions for type names
 
 use super::document::parse_qualified_name;
+use super::element::parse_function;
 use super::prelude::*;
 
 #[cfg_attr(test, parser_test)]
@@ -30,6 +31,9 @@ pub fn parse_type(p: &mut impl Parser) {
 /// {a: string}
 /// {a: string,}
 /// {a: { foo: string, bar: int, }, q: {} }
+/// {function: string, pure: int}
+/// {a: int, function double() -> int { self.a * 2 } }
+/// {a: int, pure function add(b: int) -> int { self.a + b } function zero() -> int { 0 } }
 /// ```
 pub fn parse_type_object(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::ObjectType);
@@ -37,6 +41,13 @@ pub fn parse_type_object(p: &mut impl Parser) {
         return;
     }
     while p.nth(0).kind() != SyntaxKind::RBrace {
+        if (p.peek().as_str() == "function" && p.nth(1).kind() == SyntaxKind::Identifier)
+            || (p.peek().as_str() == "pure" && p.nth(1).as_str() == "function")
+        {
+            parse_function(&mut *p);
+            p.test(SyntaxKind::Comma);
+            continue;
+        }
         let mut p = p.start_node(SyntaxKind::ObjectTypeMember);
         p.expect(SyntaxKind::Identifier);
         p.expect(SyntaxKind::Colon);
@@ -72,6 +83,7 @@ pub fn parse_type_array(p: &mut impl Parser) {
 /// struct Bar := {}
 /// struct Foo { foo: bar, xxx: { aaa: bbb, } }
 /// struct Bar {}
+/// struct Money { amount: int, currency: string, function format() -> string { self.amount + self.currency } }
 /// ```
 pub fn parse_struct_declaration<P: Parser>(p: &mut P, checkpoint: Option<P::Checkpoint>) -> bool {
     debug_assert_eq!(p.peek().as_str(), "struct");
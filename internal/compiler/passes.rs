@@ -15,11 +15,13 @@
 mod collect_subcomponents;
 mod compile_paths;
 mod const_propagation;
+mod debug_output_locations;
 mod deduplicate_property_read;
 mod default_geometry;
 #[cfg(feature = "software-renderer")]
 mod embed_glyphs;
 mod embed_images;
+mod enabled;
 mod ensure_window;
 mod flickable;
 mod focus_handling;
@@ -28,10 +30,16 @@
 mod inlining;
 mod lower_absolute_coordinates;
 mod lower_accessibility;
+mod lower_animation_callbacks;
+mod lower_assertions;
 mod lower_component_container;
+mod lower_event_handlers;
+mod lower_image_source_url;
 mod lower_layout;
 mod lower_popups;
 mod lower_property_to_element;
+mod lower_selectable_text;
+mod lower_shaders;
 mod lower_shadows;
 mod lower_states;
 mod lower_tabwidget;
@@ -96,6 +104,7 @@ pub async fn run_passes(
     });
     lower_tabwidget::lower_tabwidget(doc, type_loader, diag).await;
     collect_subcomponents::collect_subcomponents(doc);
+    lower_assertions::check_assertions(doc, diag);
 
     doc.visit_all_used_components(|component| {
         apply_default_properties_from_style::apply_default_properties_from_style(
@@ -104,12 +113,21 @@ pub async fn run_passes(
             &palette,
             diag,
         );
+        lower_event_handlers::lower_event_handlers(component);
         lower_states::lower_states(component, &doc.local_registry, diag);
+        enabled::handle_enabled(component);
         lower_text_input_interface::lower_text_input_interface(component);
         repeater_component::process_repeater_components(component);
         lower_popups::lower_popups(component, &doc.local_registry, diag);
         collect_init_code::collect_init_code(component);
+        lower_assertions::lower_assertions(component);
         lower_timers::lower_timers(component, diag);
+        lower_animation_callbacks::lower_animation_callbacks(component);
+        lower_image_source_url::lower_image_source_url(component, diag);
+        lower_shaders::lower_shaders(component, diag);
+        if type_loader.compiler_config.debug_info {
+            debug_output_locations::debug_output_locations(component);
+        }
     });
 
     inlining::inline(doc, inlining::InlineSelection::InlineOnlyRequiredComponents, diag);
@@ -172,10 +190,43 @@ pub async fn run_passes(
             &global_type_registry.borrow(),
             diag,
         );
+        lower_property_to_element::lower_properties_to_element(
+            component,
+            &crate::typeregister::RESERVED_TRANSFORM_PROPERTIES[..5]
+                .iter()
+                .map(|(prop_name, _)| *prop_name)
+                .collect::<Vec<_>>(),
+            crate::typeregister::RESERVED_TRANSFORM_PROPERTIES[5..]
+                .iter()
+                .map(|(prop_name, _)| *prop_name),
+            Some(&|e, prop| match prop {
+                "transform-scale-x" | "transform-scale-y" => {
+                    Expression::NumberLiteral(1., Default::default())
+                }
+                "transform-origin-x" | "transform-origin-y" => Expression::BinaryExpression {
+                    lhs: Expression::PropertyReference(NamedReference::new(
+                        e,
+                        if prop == "transform-origin-x" { "width" } else { "height" },
+                    ))
+                    .into(),
+                    op: '/',
+                    rhs: Expression::NumberLiteral(2., Default::default()).into(),
+                },
+                _ => Expression::Invalid,
+            }),
+            "TransformItem",
+            &global_type_registry.borrow(),
+            diag,
+        );
         clip::handle_clip(component, &global_type_registry.borrow(), diag);
         if type_loader.compiler_config.accessibility {
             lower_accessibility::lower_accessibility_properties(component, diag);
         }
+        lower_selectable_text::lower_selectable_text(
+            component,
+            &global_type_registry.borrow(),
+            diag,
+        );
         materialize_fake_properties::materialize_fake_properties(component);
     });
     for root_component in doc.exported_roots() {
@@ -232,6 +283,7 @@ pub async fn run_passes(
         doc,
         type_loader.compiler_config.embed_resources,
         type_loader.compiler_config.const_scale_factor,
+        type_loader.compiler_config.compress_textures,
         &type_loader.compiler_config.resource_url_mapper,
         diag,
     )
@@ -68,6 +68,28 @@ pub fn apply_default_properties_from_style(
                         .into(),
                         to: Type::Brush,
                     });
+                    if elem.is_binding_set("selectable", false) {
+                        elem.set_binding_if_not_set("selection-background-color".into(), || {
+                            Expression::Cast {
+                                from: Expression::PropertyReference(NamedReference::new(
+                                    &palette.root_element,
+                                    "selection-background",
+                                ))
+                                .into(),
+                                to: Type::Color,
+                            }
+                        });
+                        elem.set_binding_if_not_set("selection-foreground-color".into(), || {
+                            Expression::Cast {
+                                from: Expression::PropertyReference(NamedReference::new(
+                                    &palette.root_element,
+                                    "selection-foreground",
+                                ))
+                                .into(),
+                                to: Type::Color,
+                            }
+                        });
+                    }
                 }
                 "Dialog" | "Window" => {
                     elem.set_binding_if_not_set("background".into(), || Expression::Cast {
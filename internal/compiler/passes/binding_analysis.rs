@@ -168,7 +168,8 @@ fn analyze_element(
 
     if let Some(component) = elem.borrow().enclosing_component.upgrade() {
         if Rc::ptr_eq(&component.root_element, elem) {
-            for e in component.init_code.borrow().iter() {
+            let init_code = component.init_code.borrow();
+            for e in init_code.iter().chain(init_code.destructor_code.iter()) {
                 recurse_expression(e, &mut |prop, r| {
                     process_property(prop, r, context, reverse_aliases, diag);
                 });
@@ -186,6 +187,10 @@ fn analyze_element(
             process_property(&lv.viewport_width.clone().into(), P, context, reverse_aliases, diag);
             process_property(&lv.listview_height.clone().into(), P, context, reverse_aliases, diag);
             process_property(&lv.listview_width.clone().into(), P, context, reverse_aliases, diag);
+            if let Some(sections) = &lv.sections {
+                let section_rows = sections.section_rows.clone().into();
+                process_property(&section_rows, P, context, reverse_aliases, diag);
+            }
         }
     }
     if let Some((h, v)) = &elem.borrow().layout_info_prop {
@@ -432,6 +437,17 @@ fn recurse_expression(expr: &Expression, vis: &mut impl FnMut(&PropertyPath, Rea
                     }
                 }
             }
+            Expression::BuiltinFunctionReference(BuiltinFunction::ItemIsVisible, _) => {
+                if let Some(Expression::ElementReference(item)) = arguments.first() {
+                    let mut item = Some(item.upgrade().unwrap());
+                    while let Some(elem) = item {
+                        for prop in ["x", "y", "width", "height"] {
+                            vis(&NamedReference::new(&elem, prop).into(), ReadType::NativeRead);
+                        }
+                        item = find_parent_element(&elem);
+                    }
+                }
+            }
             _ => {}
         },
         _ => {}
@@ -1,7 +1,8 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-//! Pass that applies the default border-radius to border-top|bottom-left|right-radius.
+//! Pass that applies the default border-radius to border-top|bottom-left|right-radius,
+//! and the default border-width and border-color to border-top|right|bottom|left-width|color.
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::{Expression, NamedReference};
@@ -15,6 +16,18 @@
     "border-bottom-left-radius",
 ];
 
+/// The properties of the edges of the border, with the property they default to
+const BORDER_EDGE_PROPERTIES: [(&str, &str); 8] = [
+    ("border-top-width", "border-width"),
+    ("border-right-width", "border-width"),
+    ("border-bottom-width", "border-width"),
+    ("border-left-width", "border-width"),
+    ("border-top-color", "border-color"),
+    ("border-right-color", "border-color"),
+    ("border-bottom-color", "border-color"),
+    ("border-left-color", "border-color"),
+];
+
 pub fn handle_border_radius(root_component: &Rc<Component>, _diag: &mut BuildDiagnostics) {
     crate::object_tree::recurse_elem_including_sub_components_no_borrow(
         root_component,
@@ -34,6 +47,19 @@ pub fn handle_border_radius(root_component: &Rc<Component>, _diag: &mut BuildDia
                     });
                 }
             }
+            // The renderers only draw the edges separately when one of them is set, so set them all
+            if bty.name == "Rectangle"
+                && BORDER_EDGE_PROPERTIES
+                    .iter()
+                    .any(|(property_name, _)| elem.borrow().is_binding_set(property_name, true))
+            {
+                for (property_name, default_property_name) in BORDER_EDGE_PROPERTIES {
+                    let default = NamedReference::new(elem, default_property_name);
+                    elem.borrow_mut().set_binding_if_not_set(property_name.to_string(), || {
+                        Expression::PropertyReference(default)
+                    });
+                }
+            }
         },
     )
 }
@@ -11,6 +11,7 @@
 /// Check the validity of expressions
 ///
 /// - Make sure that there is no uncalled member function or macro
+/// - Make sure that the literal patterns of the regular expression functions are valid
 pub fn check_expressions(doc: &crate::object_tree::Document, diag: &mut BuildDiagnostics) {
     for component in &doc.inner_components {
         visit_all_expressions(component, |e, _| check_expression(component, e, diag));
@@ -40,6 +41,40 @@ fn check_expression(component: &Rc<Component>, e: &Expression, diag: &mut BuildD
                 diag.push_error("Cannot convert between rem and logical length in a global component, because the default font size is not known".into(), loc);
             }
         }
+        Expression::BuiltinFunctionReference(
+            BuiltinFunction::GetWindowWidth | BuiltinFunction::GetWindowHeight,
+            loc,
+        ) => {
+            if component.is_global() {
+                diag.push_error("Cannot use the vw and vh units in a global component, because the size of the window is not known".into(), loc);
+            }
+        }
+        Expression::FunctionCall { function, arguments, .. } => {
+            if let (
+                Expression::BuiltinFunctionReference(
+                    BuiltinFunction::RegexMatches
+                    | BuiltinFunction::RegexReplace
+                    | BuiltinFunction::RegexCapture,
+                    loc,
+                ),
+                Some(Expression::StringLiteral(pattern)),
+            ) = (&**function, arguments.get(1))
+            {
+                if let Err(err) = regex_syntax::Parser::new().parse(pattern) {
+                    // The last line of the error is the description, after the position in the pattern
+                    let message = err.to_string();
+                    let message = message.lines().last().unwrap_or_default();
+                    diag.push_error(
+                        format!(
+                            "Invalid regular expression: {}",
+                            message.trim_start_matches("error: ")
+                        ),
+                        loc,
+                    );
+                }
+            }
+            e.visit(|e| check_expression(component, e, diag))
+        }
         _ => e.visit(|e| check_expression(component, e, diag)),
     }
 }
@@ -1,12 +1,16 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-//! Passe that collects the code from init callbacks from elements and moves it into the component's init_code.
+//! Passe that collects the code from init and before-destroy callbacks from elements and moves it into the component's init_code.
+//!
+//! The shown and hidden callbacks are lowered to a property tracking whether the element is visible on screen,
+//! with a change callback invoking them.
 
 use std::rc::Rc;
 
-use crate::langtype::ElementType;
-use crate::object_tree::{recurse_elem, Component};
+use crate::expression_tree::{BindingExpression, BuiltinFunction, Expression, NamedReference};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::{recurse_elem, Component, ElementRc, PropertyDeclaration};
 
 pub fn collect_init_code(component: &Rc<Component>) {
     recurse_elem(&component.root_element, &(), &mut |elem, _| {
@@ -25,8 +29,71 @@ pub fn collect_init_code(component: &Rc<Component>) {
                 .constructor_code
                 .push(init_callback.into_inner().expression);
         }
+
+        if let Some(shown_code) = lower_shown_and_hidden(elem) {
+            component.init_code.borrow_mut().constructor_code.push(shown_code);
+        }
+
+        if let Some(destroy_callback) = elem.borrow_mut().bindings.remove("before-destroy") {
+            component
+                .init_code
+                .borrow_mut()
+                .destructor_code
+                .push(destroy_callback.into_inner().expression);
+        }
     });
     for popup in component.popup_windows.borrow().iter() {
         collect_init_code(&popup.component);
     }
 }
+
+/// Replace the shown and hidden callbacks of the element by a change callback on a property that
+/// tells if the element is visible. Returns the code that calls shown at initialization if the
+/// element is visible.
+fn lower_shown_and_hidden(elem: &ElementRc) -> Option<Expression> {
+    let take_callback = |name: &str| {
+        let lookup = elem.borrow().lookup_property(name);
+        if !crate::typeregister::is_shadowable_reserved_callback(&lookup) {
+            return None;
+        }
+        elem.borrow_mut().bindings.remove(name).map(|b| b.into_inner().expression)
+    };
+    let shown = take_callback("shown");
+    let hidden = take_callback("hidden");
+    if shown.is_none() && hidden.is_none() {
+        return None;
+    }
+    let shown = shown.unwrap_or(Expression::CodeBlock(vec![]));
+    let hidden = hidden.unwrap_or(Expression::CodeBlock(vec![]));
+
+    let prop_name = "visible-on-screen";
+    let mut elem_mut = elem.borrow_mut();
+    elem_mut.property_declarations.insert(prop_name.into(), PropertyDeclaration::from(Type::Bool));
+    elem_mut.bindings.insert(
+        prop_name.into(),
+        BindingExpression::from(Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::ItemIsVisible,
+                None,
+            )),
+            arguments: vec![Expression::ElementReference(Rc::downgrade(elem))],
+            source_location: None,
+        })
+        .into(),
+    );
+    drop(elem_mut);
+
+    let visible = Box::new(Expression::PropertyReference(NamedReference::new(elem, prop_name)));
+    elem.borrow_mut().change_callbacks.entry(prop_name.into()).or_default().borrow_mut().push(
+        Expression::Condition {
+            condition: visible.clone(),
+            true_expr: Box::new(shown.clone()),
+            false_expr: Box::new(hidden),
+        },
+    );
+    Some(Expression::Condition {
+        condition: visible,
+        true_expr: Box::new(shown),
+        false_expr: Box::new(Expression::CodeBlock(vec![])),
+    })
+}
@@ -160,6 +160,7 @@ fn compile_path_from_string_literal(
         name: Some("slint::private_api::Point".into()),
         node: None,
         rust_attributes: None,
+        functions: None,
     };
 
     let mut points = Vec::new();
@@ -0,0 +1,36 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Passe that prefixes the output of the `debug()` calls with their location in the `.slint` file,
+//! when the compiler emits debug information.
+
+use crate::expression_tree::{BuiltinFunction, Expression};
+use crate::object_tree::{visit_all_expressions, Component};
+
+pub fn debug_output_locations(component: &Component) {
+    visit_all_expressions(component, |expr, _| {
+        expr.visit_recursive_mut(&mut |expr| {
+            let Expression::FunctionCall { function, arguments, source_location: Some(location) } =
+                expr
+            else {
+                return;
+            };
+            if !matches!(
+                **function,
+                Expression::BuiltinFunctionReference(BuiltinFunction::Debug, _)
+            ) {
+                return;
+            }
+            let (Some(prefix), Some(message)) =
+                (location.file_line_column(), arguments.first_mut())
+            else {
+                return;
+            };
+            *message = Expression::BinaryExpression {
+                lhs: Box::new(Expression::StringLiteral(format!("{prefix}: "))),
+                op: '+',
+                rhs: Box::new(std::mem::take(message)),
+            };
+        })
+    })
+}
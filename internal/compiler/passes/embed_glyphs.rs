@@ -3,7 +3,9 @@
 
 use crate::diagnostics::BuildDiagnostics;
 #[cfg(not(target_arch = "wasm32"))]
-use crate::embedded_resources::{BitmapFont, BitmapGlyph, BitmapGlyphs, CharacterMapEntry};
+use crate::embedded_resources::{
+    format_code_points, BitmapFont, BitmapGlyph, BitmapGlyphs, CharacterMapEntry, GlyphSelection,
+};
 #[cfg(not(target_arch = "wasm32"))]
 use crate::expression_tree::BuiltinFunction;
 use crate::expression_tree::{Expression, Unit};
@@ -83,13 +85,31 @@ pub fn embed_glyphs<'a>(
         }
     }
 
+    let mut glyph_selections = compiler_config.glyph_selections.clone();
+    if let Ok(selections_str) = std::env::var("SLINT_EMBED_GLYPHS") {
+        for selection_str in selections_str.split(';').filter(|s| !s.trim().is_empty()) {
+            match selection_str.parse::<GlyphSelection>() {
+                Ok(selection) => glyph_selections.push(selection),
+                Err(err) => {
+                    diag.push_error(
+                        format!("{err} in the glyphs specified in `SLINT_EMBED_GLYPHS`"),
+                        &generic_diag_location,
+                    );
+                    return;
+                }
+            }
+        }
+    }
+
     sharedfontdb::FONT_DB.with(|db| {
         embed_glyphs_with_fontdb(
             compiler_config,
             &db,
             doc,
+            scale_factor,
             pixel_sizes,
             characters_seen,
+            &glyph_selections,
             all_docs,
             diag,
             generic_diag_location,
@@ -101,8 +121,10 @@ fn embed_glyphs_with_fontdb<'a>(
     compiler_config: &CompilerConfiguration,
     fontdb: &RefCell<sharedfontdb::FontDatabase>,
     doc: &Document,
+    scale_factor: f64,
     pixel_sizes: Vec<i16>,
     characters_seen: HashSet<char>,
+    glyph_selections: &[GlyphSelection],
     all_docs: impl Iterator<Item = &'a crate::object_tree::Document> + 'a,
     diag: &mut BuildDiagnostics,
     generic_diag_location: Option<crate::diagnostics::SourceLocation>,
@@ -138,12 +160,8 @@ fn embed_glyphs_with_fontdb<'a>(
                 .bindings
                 .get("default-font-family")
                 .and_then(|binding| {
-                    match &binding.borrow().expression {
-                        Expression::StringLiteral(family) => {
-                            Some((Some(family.clone()), binding.borrow().span.clone()))
-                        }
-                        _ => None,
-                    }
+                    let family = default_font_family(&binding.borrow().expression)?;
+                    Some((Some(family), binding.borrow().span.clone()))
                 })
                 .unwrap_or_default();
 
@@ -201,6 +219,8 @@ fn embed_glyphs_with_fontdb<'a>(
         return;
     }
 
+    let mut used_glyph_selections = vec![false; glyph_selections.len()];
+
     let mut embed_font_by_path_and_face_id = |path: &std::path::Path, face_id| {
         let fontdue_font = match compiler_config.load_font_by_id(face_id) {
             Ok(font) => font,
@@ -244,12 +264,49 @@ fn embed_glyphs_with_fontdb<'a>(
             return;
         };
 
+        let mut font_pixel_sizes = pixel_sizes.clone();
+        let mut characters = characters_seen.clone();
+        let mut selected_characters = HashSet::new();
+        for (selection, used) in glyph_selections.iter().zip(used_glyph_selections.iter_mut()) {
+            if !selection.matches_family(&family_name) {
+                continue;
+            }
+            *used = true;
+            for size in &selection.pixel_sizes {
+                let size = (size * scale_factor) as i16;
+                if let Err(pos) = font_pixel_sizes.binary_search(&size) {
+                    font_pixel_sizes.insert(pos, size)
+                }
+            }
+            selected_characters.extend(selection.characters());
+        }
+
+        let missing_characters = selected_characters
+            .iter()
+            .filter(|c| {
+                core::iter::once(&fontdue_font)
+                    .chain(fallback_fonts.iter())
+                    .all(|font| !font.chars().contains_key(*c))
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        if !missing_characters.is_empty() {
+            diag.push_warning(
+                format!(
+                    "The font '{family_name}' and the fallback fonts have no glyph for the selected code points {}",
+                    format_code_points(missing_characters)
+                ),
+                &generic_diag_location,
+            );
+        }
+        characters.extend(selected_characters);
+
         let embedded_bitmap_font = embed_font(
             &fontdb,
             family_name,
             Font { id: face_id, fontdue_font, metrics },
-            &pixel_sizes,
-            characters_seen.iter().cloned(),
+            &font_pixel_sizes,
+            characters.into_iter(),
             &fallback_fonts,
         );
 
@@ -286,6 +343,15 @@ fn embed_glyphs_with_fontdb<'a>(
     for (path, face_id) in &fonts {
         embed_font_by_path_and_face_id(path, *face_id);
     }
+
+    for (selection, used) in glyph_selections.iter().zip(used_glyph_selections) {
+        if let (Some(family), false) = (&selection.family, used) {
+            diag.push_warning(
+                format!("No embedded font has the family '{family}' of the selected glyphs"),
+                &generic_diag_location,
+            );
+        }
+    }
 }
 
 #[inline(never)] // workaround https://github.com/rust-lang/rust/issues/104099
@@ -337,6 +403,30 @@ fn get_fallback_fonts(
     fallback_fonts
 }
 
+/// Returns the font family of the `default-font-family` binding of a window, which the style
+/// lets the theme replace at run-time: the glyphs are embedded for the family of the style.
+#[cfg(not(target_arch = "wasm32"))]
+fn default_font_family(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::StringLiteral(family) => Some(family.clone()).filter(|f| !f.is_empty()),
+        Expression::Cast { from, .. } => default_font_family(from),
+        Expression::PropertyReference(nr) => {
+            let element = nr.element();
+            let binding = element.borrow().bindings.get(nr.name())?.borrow().expression.clone();
+            default_font_family(&binding)
+        }
+        Expression::FunctionCall { function, arguments, .. }
+            if matches!(
+                **function,
+                Expression::BuiltinFunctionReference(BuiltinFunction::ThemeString, _)
+            ) =>
+        {
+            default_font_family(arguments.get(1)?)
+        }
+        _ => None,
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn embed_font(
     fontdb: &fontdb::Database,
@@ -18,6 +18,7 @@ pub async fn embed_images(
     doc: &Document,
     embed_files: EmbedResourcesKind,
     scale_factor: f64,
+    compress_textures: bool,
     resource_url_mapper: &Option<Rc<dyn Fn(&str) -> Pin<Box<dyn Future<Output = Option<String>>>>>>,
     diag: &mut BuildDiagnostics,
 ) {
@@ -60,6 +61,7 @@ pub async fn embed_images(
                 global_embedded_resources,
                 embed_files,
                 scale_factor,
+                compress_textures,
                 diag,
             )
         });
@@ -82,6 +84,7 @@ fn embed_images_from_expression(
     global_embedded_resources: &RefCell<HashMap<String, EmbeddedResources>>,
     embed_files: EmbedResourcesKind,
     scale_factor: f64,
+    compress_textures: bool,
     diag: &mut BuildDiagnostics,
 ) {
     if let Expression::ImageReference { ref mut resource_ref, source_location, nine_slice: _ } = e {
@@ -99,6 +102,7 @@ fn embed_images_from_expression(
                     embed_files,
                     path,
                     scale_factor,
+                    compress_textures,
                     diag,
                     source_location,
                 );
@@ -113,6 +117,7 @@ fn embed_images_from_expression(
             global_embedded_resources,
             embed_files,
             scale_factor,
+            compress_textures,
             diag,
         )
     });
@@ -123,6 +128,7 @@ fn embed_image(
     _embed_files: EmbedResourcesKind,
     path: &str,
     _scale_factor: f64,
+    _compress_textures: bool,
     diag: &mut BuildDiagnostics,
     source_location: &Option<crate::diagnostics::SourceLocation>,
 ) -> ImageReference {
@@ -139,11 +145,11 @@ fn embed_image(
                 if _embed_files == EmbedResourcesKind::EmbedTextures {
                     match load_image(_file, _scale_factor) {
                         Ok((img, source_format, original_size)) => {
-                            kind = EmbeddedResourcesKind::TextureData(generate_texture(
-                                img,
-                                source_format,
-                                original_size,
-                            ))
+                            let mut texture = generate_texture(img, source_format, original_size);
+                            if _compress_textures {
+                                compress_texture(&mut texture);
+                            }
+                            kind = EmbeddedResourcesKind::TextureData(texture)
                         }
                         Err(err) => {
                             diag.push_error(
@@ -301,6 +307,49 @@ enum ColorState {
         rect,
         data: convert_image(image, source_format, format, rect),
         format,
+        compression: TextureCompression::None,
+    }
+}
+
+/// Run-length encodes each row of the texture, as described by `TextureCompression::Rle` in
+/// i-slint-core, unless that doesn't make the texture smaller.
+#[cfg(feature = "software-renderer")]
+fn compress_texture(texture: &mut Texture) {
+    let bpp = match texture.format {
+        PixelFormat::Rgb => 3,
+        PixelFormat::Rgba | PixelFormat::RgbaPremultiplied => 4,
+        PixelFormat::AlphaMap(_) => 1,
+    };
+    let height = texture.rect.height() as usize;
+    let mut data = vec![0; height * 4];
+    for (y, row) in texture.data.chunks_exact(texture.rect.width() as usize * bpp).enumerate() {
+        let offset = data.len() as u32;
+        data[y * 4..(y + 1) * 4].copy_from_slice(&offset.to_le_bytes());
+        let pixels = row.chunks_exact(bpp).collect::<Vec<_>>();
+        let is_run = |i: usize| i + 1 < pixels.len() && pixels[i] == pixels[i + 1];
+        let mut i = 0;
+        while i < pixels.len() {
+            let start = i;
+            if is_run(i) {
+                while i < pixels.len() && i - start < 128 && pixels[i] == pixels[start] {
+                    i += 1;
+                }
+                data.push(0x80 | (i - start - 1) as u8);
+                data.extend_from_slice(pixels[start]);
+            } else {
+                // The pixels are copied as-is until the next run
+                i += 1;
+                while i < pixels.len() && i - start < 128 && !is_run(i) {
+                    i += 1;
+                }
+                data.push((i - start - 1) as u8);
+                data.extend(pixels[start..i].iter().copied().flatten());
+            }
+        }
+    }
+    if data.len() < texture.data.len() {
+        texture.data = data;
+        texture.compression = TextureCompression::Rle;
     }
 }
 
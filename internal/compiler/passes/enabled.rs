@@ -0,0 +1,166 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that makes the `enabled` property of an element cascade to its descendants, and that
+//! creates the bindings of the `effectively-enabled` properties.
+//!
+//! Every element that has an `enabled` property (a built-in element like TouchArea or FocusScope,
+//! or a component) is only enabled if all its ancestors in the same component that are components
+//! with an `enabled` property are enabled too.
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use crate::expression_tree::{BindingExpression, Expression, NamedReference};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::{
+    recurse_elem, recurse_elem_no_borrow, visit_all_named_references, Component, ElementRc,
+    PropertyVisibility,
+};
+
+pub fn handle_enabled(component: &Rc<Component>) {
+    let mut effectively_enabled = HashSet::new();
+    visit_all_named_references(component, &mut |nr| {
+        if nr.name() == "effectively-enabled" {
+            effectively_enabled.insert(nr.clone());
+        }
+    });
+
+    // The `enabled` properties that are linked with a two-way binding can't get another binding
+    let mut two_way_bound = HashSet::new();
+    recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        for (name, binding) in &elem.borrow().bindings {
+            let binding = binding.borrow();
+            if name == "enabled" && !binding.two_way_bindings.is_empty() {
+                two_way_bound.insert(NamedReference::new(elem, "enabled"));
+            }
+            for nr in &binding.two_way_bindings {
+                if nr.name() == "enabled" {
+                    two_way_bound.insert(nr.clone());
+                }
+            }
+        }
+    });
+
+    // The state is the list of the `enabled` properties that must all be true for the element
+    // to be enabled.
+    recurse_elem_no_borrow(&component.root_element, &Vec::new(), &mut |elem, ancestors| {
+        let mut own_state = ancestors.clone();
+        let lookup = elem.borrow().lookup_property("enabled");
+        if lookup.property_type == Type::Bool
+            && (lookup.property_visibility != PropertyVisibility::Private
+                || lookup.is_local_to_component)
+        {
+            let enabled = NamedReference::new(elem, "enabled");
+            let can_cascade = !ancestors.is_empty()
+                && !two_way_bound.contains(&enabled)
+                && (lookup.is_valid_for_assignment()
+                    || elem.borrow().property_declarations.contains_key("enabled"));
+            if can_cascade && cascade_enabled(elem, ancestors) {
+                own_state = vec![enabled];
+            } else {
+                own_state.push(enabled);
+            }
+        }
+
+        let nr = NamedReference::new(elem, "effectively-enabled");
+        if effectively_enabled.contains(&nr) {
+            elem.borrow_mut().bindings.insert(
+                nr.name().to_string(),
+                BindingExpression::from(all_enabled(&own_state)).into(),
+            );
+        }
+
+        // The `enabled` property of built-in elements doesn't apply to their children: a
+        // disabled TouchArea or FocusScope still lets the children handle the events.
+        if declares_enabled(elem) {
+            own_state
+        } else {
+            ancestors.clone()
+        }
+    });
+}
+
+/// Returns true if the `enabled` property of the element is declared in a component, and not
+/// the one of a built-in element.
+fn declares_enabled(elem: &ElementRc) -> bool {
+    let elem = elem.borrow();
+    elem.property_declarations.contains_key("enabled")
+        || match &elem.base_type {
+            ElementType::Component(c) => declares_enabled(&c.root_element),
+            _ => false,
+        }
+}
+
+/// Only keep the `enabled` property of the element true if all the ancestors are enabled.
+/// Returns false if the value of the property couldn't be determined.
+fn cascade_enabled(elem: &ElementRc, ancestors: &[NamedReference]) -> bool {
+    let mut elem = elem.borrow_mut();
+    let own_value = match elem.bindings.get("enabled") {
+        Some(binding) => binding.borrow().expression.clone(),
+        None => match default_enabled(&elem.base_type) {
+            Some(value) => value,
+            None => return false,
+        },
+    };
+    if matches!(own_value, Expression::Invalid) {
+        return false;
+    }
+    let expression = Expression::Condition {
+        condition: Box::new(all_enabled(ancestors)),
+        true_expr: Box::new(own_value),
+        false_expr: Box::new(Expression::BoolLiteral(false)),
+    };
+    match elem.bindings.get("enabled") {
+        Some(binding) => binding.borrow_mut().expression = expression,
+        None => {
+            elem.bindings.insert("enabled".into(), BindingExpression::from(expression).into());
+        }
+    }
+    true
+}
+
+/// The value of the `enabled` property of an element that has no binding for it, or None if it
+/// is not a constant.
+fn default_enabled(base_type: &ElementType) -> Option<Expression> {
+    match base_type {
+        ElementType::Component(c) => constant_value(&c.root_element, "enabled", MAX_ALIAS_DEPTH),
+        _ => Some(Expression::BoolLiteral(false)),
+    }
+}
+
+/// The number of aliases followed to find the default value of an `enabled` property
+const MAX_ALIAS_DEPTH: usize = 8;
+
+/// The value of a boolean property if it's a constant. When the property is an alias to another
+/// property (like `in property <bool> enabled <=> touch-area.enabled;`), the value of the other
+/// property is used, up to `depth` times.
+fn constant_value(elem: &ElementRc, name: &str, depth: usize) -> Option<Expression> {
+    let elem = elem.borrow();
+    let Some(binding) = elem.bindings.get(name) else {
+        return match &elem.base_type {
+            ElementType::Component(c) => constant_value(&c.root_element, name, depth),
+            _ => Some(Expression::BoolLiteral(false)),
+        };
+    };
+    let binding = binding.borrow();
+    match (&binding.expression, binding.two_way_bindings.as_slice()) {
+        (Expression::BoolLiteral(_), []) => Some(binding.expression.clone()),
+        (Expression::Invalid, [nr]) if depth > 0 => {
+            constant_value(&nr.element(), nr.name(), depth - 1)
+        }
+        _ => None,
+    }
+}
+
+fn all_enabled(properties: &[NamedReference]) -> Expression {
+    properties
+        .iter()
+        .map(|nr| Expression::PropertyReference(nr.clone()))
+        .reduce(|lhs, rhs| Expression::BinaryExpression {
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            op: '&',
+        })
+        .unwrap_or(Expression::BoolLiteral(true))
+}
@@ -42,6 +42,7 @@ pub fn ensure_window(
         bindings: Default::default(),
         change_callbacks: Default::default(),
         is_component_placeholder: false,
+        children_slot: None,
         property_analysis: Default::default(),
         children: std::mem::take(&mut win_elem_mut.children),
         enclosing_component: win_elem_mut.enclosing_component.clone(),
@@ -50,6 +51,8 @@ pub fn ensure_window(
         repeated: Default::default(),
         states: Default::default(),
         transitions: Default::default(),
+        event_handlers: Default::default(),
+        assertions: Default::default(),
         child_of_layout: false,
         has_popup_child: false,
         layout_info_prop: Default::default(),
@@ -76,8 +76,11 @@ fn create_viewport_element(flickable: &ElementRc, native_empty: &Rc<NativeClass>
         .is_set_externally = true;
 
     let enclosing_component = flickable.borrow().enclosing_component.upgrade().unwrap();
-    if let Some((insertion_point, _, _)) =
-        &mut *enclosing_component.child_insertion_point.borrow_mut()
+    for (insertion_point, _, _) in enclosing_component
+        .child_insertion_point
+        .borrow_mut()
+        .iter_mut()
+        .chain(enclosing_component.named_child_insertion_points.borrow_mut().values_mut())
     {
         if std::rc::Rc::ptr_eq(insertion_point, flickable) {
             *insertion_point = viewport.clone()
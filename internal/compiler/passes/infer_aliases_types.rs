@@ -22,6 +22,7 @@
 pub fn resolve_aliases(doc: &Document, diag: &mut BuildDiagnostics) {
     for component in doc.inner_components.iter() {
         let scope = ComponentScope(vec![]);
+        let type_register = component.type_register(&doc.local_registry);
         crate::object_tree::recurse_elem_no_borrow(
             &component.root_element,
             &scope,
@@ -39,7 +40,7 @@ pub fn resolve_aliases(doc: &Document, diag: &mut BuildDiagnostics) {
                 // make it deterministic
                 need_resolving.sort();
                 for n in need_resolving {
-                    resolve_alias(elem, &n, &new_scope, &doc.local_registry, diag);
+                    resolve_alias(elem, &n, &new_scope, type_register, diag);
                 }
                 new_scope
             },
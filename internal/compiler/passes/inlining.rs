@@ -7,9 +7,10 @@
 use crate::expression_tree::{BindingExpression, Expression, NamedReference};
 use crate::langtype::{ElementType, Type};
 use crate::object_tree::*;
+use crate::parser::syntax_nodes;
 use by_address::ByAddress;
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::rc::Rc;
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -122,41 +123,81 @@ fn inline_element(
         }),
     );
 
-    let mut move_children_into_popup = None;
-
-    match inlined_component.child_insertion_point.borrow().as_ref() {
-        Some((insertion_element, index, cip_node)) => {
-            let children = std::mem::take(&mut elem_mut.children);
-            let old_count = children.len();
-            if let Some(insertion_element) = mapping.get(&element_key(insertion_element.clone())) {
-                if !Rc::ptr_eq(elem, insertion_element) {
-                    debug_assert!(std::rc::Weak::ptr_eq(
-                        &insertion_element.borrow().enclosing_component,
-                        &elem_mut.enclosing_component,
-                    ));
-                    insertion_element.borrow_mut().children.splice(index..index, children);
-                } else {
-                    new_children.splice(index..index, children);
-                }
-                let mut cip = root_component.child_insertion_point.borrow_mut();
-                if let Some(cip) = cip.as_mut() {
-                    if Rc::ptr_eq(&cip.0, elem) {
-                        *cip = (insertion_element.clone(), index + cip.1, cip_node.clone());
-                    }
-                } else if Rc::ptr_eq(elem, &root_component.root_element) {
-                    *cip = Some((insertion_element.clone(), *index + old_count, cip_node.clone()));
-                };
-            } else {
-                // @children was into a PopupWindow
-                debug_assert!(inlined_component.popup_windows.borrow().iter().any(|p| Rc::ptr_eq(
-                    &p.component,
-                    &insertion_element.borrow().enclosing_component.upgrade().unwrap()
-                )));
-                move_children_into_popup = Some(children);
-            };
-        }
-        _ => {
-            new_children.append(&mut elem_mut.children);
+    let (children, slot_children): (Vec<_>, Vec<_>) = std::mem::take(&mut elem_mut.children)
+        .into_iter()
+        .partition(|c| c.borrow().children_slot.is_none());
+
+    let mut insertions = Vec::new();
+    insertions.push(match inlined_component.child_insertion_point.borrow().as_ref() {
+        Some((insertion_element, index, cip_node)) => Insertion {
+            name: None,
+            insertion_element: insertion_element.clone(),
+            index: *index,
+            cip_node: Some(cip_node.clone()),
+            children,
+        },
+        // Without @children, the children are appended to the children of the root element
+        None => Insertion {
+            name: None,
+            insertion_element: inlined_component.root_element.clone(),
+            index: inlined_component.root_element.borrow().children.len(),
+            cip_node: None,
+            children,
+        },
+    });
+    let mut slot_children_by_name = BTreeMap::<String, Vec<ElementRc>>::new();
+    for c in slot_children {
+        let name = c.borrow_mut().children_slot.take().unwrap();
+        slot_children_by_name.entry(name).or_default().push(c);
+    }
+    for (name, (insertion_element, index, cip_node)) in
+        inlined_component.named_child_insertion_points.borrow().iter()
+    {
+        insertions.push(Insertion {
+            name: Some(name.clone()),
+            insertion_element: insertion_element.clone(),
+            index: *index,
+            cip_node: Some(cip_node.clone()),
+            children: slot_children_by_name.remove(name).unwrap_or_default(),
+        });
+    }
+    for (name, children) in slot_children_by_name {
+        diag.push_error(
+            format!("'{}' doesn't have a @children({name}) placeholder", inlined_component.id),
+            &*children[0].borrow(),
+        );
+    }
+    // Insert in the order of the placeholders, so that the ones at the same index keep their order
+    insertions
+        .sort_by_key(|i| (i.index, i.cip_node.as_ref().map_or(usize::MAX, |n| n.span().offset)));
+
+    // The number of children already inserted into each element, which shifts the next insertions
+    let mut inserted_count = HashMap::<ByAddress<ElementRc>, usize>::new();
+    let mut move_children_into_popup = Vec::new();
+    for insertion in insertions {
+        let Some(insertion_element) =
+            mapping.get(&element_key(insertion.insertion_element.clone())).cloned()
+        else {
+            // @children was into a PopupWindow
+            debug_assert!(inlined_component.popup_windows.borrow().iter().any(|p| Rc::ptr_eq(
+                &p.component,
+                &insertion.insertion_element.borrow().enclosing_component.upgrade().unwrap()
+            )));
+            move_children_into_popup.push(insertion);
+            continue;
+        };
+        let count = inserted_count.entry(element_key(insertion_element.clone())).or_default();
+        let index = insertion.index + *count;
+        *count += insertion.children.len();
+        update_root_insertion_points(elem, root_component, &insertion, &insertion_element, index);
+        if !Rc::ptr_eq(elem, &insertion_element) {
+            debug_assert!(std::rc::Weak::ptr_eq(
+                &insertion_element.borrow().enclosing_component,
+                &elem_mut.enclosing_component,
+            ));
+            insertion_element.borrow_mut().children.splice(index..index, insertion.children);
+        } else {
+            new_children.splice(index..index, insertion.children);
         }
     }
 
@@ -186,11 +227,9 @@ fn inline_element(
     root_component.timers.borrow_mut().extend(inlined_component.timers.borrow().iter().cloned());
 
     let mut moved_into_popup = HashSet::new();
-    if let Some(children) = move_children_into_popup {
-        let child_insertion_point = inlined_component.child_insertion_point.borrow();
-        let (insertion_element, index, cip_node) = child_insertion_point.as_ref().unwrap();
-
-        let insertion_element = mapping.get(&element_key(insertion_element.clone())).unwrap();
+    for insertion in move_children_into_popup {
+        let insertion_element =
+            mapping.get(&element_key(insertion.insertion_element.clone())).unwrap().clone();
         debug_assert!(!std::rc::Weak::ptr_eq(
             &insertion_element.borrow().enclosing_component,
             &elem_mut.enclosing_component,
@@ -199,22 +238,18 @@ fn inline_element(
             &p.component,
             &insertion_element.borrow().enclosing_component.upgrade().unwrap()
         )));
-        for c in &children {
+        for c in &insertion.children {
             recurse_elem(c, &(), &mut |e, _| {
                 e.borrow_mut().enclosing_component =
                     insertion_element.borrow().enclosing_component.clone();
                 moved_into_popup.insert(element_key(e.clone()));
             });
         }
-        insertion_element.borrow_mut().children.splice(index..index, children);
-        let mut cip = root_component.child_insertion_point.borrow_mut();
-        if let Some(cip) = cip.as_mut() {
-            if Rc::ptr_eq(&cip.0, elem) {
-                *cip = (insertion_element.clone(), index + cip.1, cip_node.clone());
-            }
-        } else {
-            *cip = Some((insertion_element.clone(), *index, cip_node.clone()));
-        };
+        let count = inserted_count.entry(element_key(insertion_element.clone())).or_default();
+        let index = insertion.index + *count;
+        *count += insertion.children.len();
+        update_root_insertion_points(elem, root_component, &insertion, &insertion_element, index);
+        insertion_element.borrow_mut().children.splice(index..index, insertion.children);
     }
 
     for (k, val) in inlined_component.root_element.borrow().bindings.iter() {
@@ -276,6 +311,16 @@ fn inline_element(
         .inlined_init_code
         .insert(elem.borrow().span().offset, Expression::CodeBlock(inlined_init_code));
 
+    let inlined_destructor_code = inlined_component
+        .init_code
+        .borrow()
+        .destructor_code
+        .iter()
+        .cloned()
+        .map(fixup_init_expression)
+        .collect::<Vec<_>>();
+    root_component.init_code.borrow_mut().destructor_code.extend(inlined_destructor_code);
+
     // Now fixup all binding and reference
     for e in mapping.values() {
         visit_all_named_references_in_element(e, |nr| fixup_reference(nr, &mapping));
@@ -304,6 +349,54 @@ fn inline_element(
     }
 }
 
+/// The children of an inlined element that go to one of the `@children` placeholders of its component
+struct Insertion {
+    /// The name of the `@children(name)` placeholder, or None for the default placeholder
+    name: Option<String>,
+    /// The element of the inlined component that receives the children
+    insertion_element: ElementRc,
+    index: usize,
+    /// None when the inlined component doesn't have a default placeholder
+    cip_node: Option<syntax_nodes::ChildrenPlaceholder>,
+    children: Vec<ElementRc>,
+}
+
+/// Update the `@children` placeholders of the root component after the children of `insertion`
+/// were inserted at `index` in `insertion_element`, the copy of its insertion element.
+fn update_root_insertion_points(
+    elem: &ElementRc,
+    root_component: &Rc<Component>,
+    insertion: &Insertion,
+    insertion_element: &ElementRc,
+    index: usize,
+) {
+    let mut cip = root_component.child_insertion_point.borrow_mut();
+    let mut named_cips = root_component.named_child_insertion_points.borrow_mut();
+    if insertion.name.is_none() {
+        // The placeholders of the root component that are among the children of elem move with them
+        for cip in cip.iter_mut().chain(named_cips.values_mut()) {
+            if Rc::ptr_eq(&cip.0, elem) {
+                *cip = (insertion_element.clone(), index + cip.1, cip.2.clone());
+            }
+        }
+    }
+    if Rc::ptr_eq(elem, &root_component.root_element) {
+        // The root component inherits the placeholders of its base that it doesn't have itself
+        if let Some(cip_node) = &insertion.cip_node {
+            let inherited =
+                (insertion_element.clone(), index + insertion.children.len(), cip_node.clone());
+            match &insertion.name {
+                None => {
+                    cip.get_or_insert(inherited);
+                }
+                Some(name) => {
+                    named_cips.entry(name.clone()).or_insert(inherited);
+                }
+            }
+        }
+    }
+}
+
 // Duplicate the element elem and all its children. And fill the mapping to point from the old to the new
 fn duplicate_element_with_mapping(
     element: &ElementRc,
@@ -331,9 +424,12 @@ fn duplicate_element_with_mapping(
             .collect(),
         repeated: elem.repeated.clone(),
         is_component_placeholder: elem.is_component_placeholder,
+        children_slot: elem.children_slot.clone(),
         debug: elem.debug.clone(),
         enclosing_component: Rc::downgrade(root_component),
         states: elem.states.clone(),
+        event_handlers: elem.event_handlers.clone(),
+        assertions: elem.assertions.clone(),
         transitions: elem
             .transitions
             .iter()
@@ -398,12 +494,15 @@ fn duplicate_sub_component(
         ),
         root_constraints: component_to_duplicate.root_constraints.clone(),
         child_insertion_point: component_to_duplicate.child_insertion_point.clone(),
+        named_child_insertion_points: component_to_duplicate.named_child_insertion_points.clone(),
         init_code: component_to_duplicate.init_code.clone(),
         popup_windows: Default::default(),
         timers: component_to_duplicate.timers.clone(),
         exported_global_names: component_to_duplicate.exported_global_names.clone(),
         private_properties: Default::default(),
         inherits_popup_window: core::cell::Cell::new(false),
+        generic_parameters: None,
+        specialization: None,
     };
 
     let new_component = Rc::new(new_component);
@@ -444,7 +543,11 @@ fn duplicate_popup(p: &PopupWindow, mapping: &mut Mapping, priority_delta: i32)
     PopupWindow {
         x: p.x.clone(),
         y: p.y.clone(),
-        close_on_click: p.close_on_click,
+        close_policy: p.close_policy.clone(),
+        close_on_escape: p.close_on_escape,
+        anchor_edge: p.anchor_edge.clone(),
+        anchor_offset: p.anchor_offset,
+        anchor_flip: p.anchor_flip,
         component: duplicate_sub_component(&p.component, &parent, mapping, priority_delta),
         parent_element: mapping
             .get(&element_key(p.parent_element.clone()))
@@ -583,6 +686,7 @@ fn component_requires_inlining(component: &Rc<Component>) -> bool {
         // on the top level of a component. This could be changed in the future.
         if prop.starts_with("drop-shadow-")
             || prop == "opacity"
+            || prop.starts_with("transform-")
             || prop == "cache-rendering-hint"
             || prop == "visible"
         {
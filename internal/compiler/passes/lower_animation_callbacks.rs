@@ -0,0 +1,102 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that replaces the `started` and `finished` callbacks of the animations by a change
+//! callback on a property that tells if the animated property is running its animation.
+//!
+//! The argument of the callbacks is the name of the animated property, which is known here.
+
+use crate::expression_tree::{BindingExpression, BuiltinFunction, Expression, NamedReference};
+use crate::langtype::Type;
+use crate::object_tree::*;
+use std::rc::Rc;
+
+pub fn lower_animation_callbacks(component: &Rc<Component>) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let animated_properties = elem
+            .borrow()
+            .bindings
+            .iter()
+            .filter(|(_, b)| b.borrow().animation.is_some())
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        for prop_name in animated_properties {
+            lower_animation_callbacks_for_property(elem, &prop_name);
+        }
+    })
+}
+
+fn lower_animation_callbacks_for_property(elem: &ElementRc, prop_name: &str) {
+    let take_callback = |animation: &ElementRc, name: &str| {
+        let mut code = animation.borrow_mut().bindings.remove(name)?.into_inner().expression;
+        code.visit_recursive_mut(&mut |e| {
+            if matches!(e, Expression::FunctionParameterReference { index: 0, .. }) {
+                *e = Expression::StringLiteral(prop_name.into());
+            }
+        });
+        Some(code)
+    };
+
+    let (started, finished) = match &elem.borrow().bindings[prop_name].borrow().animation {
+        Some(PropertyAnimation::Static(animation)) => {
+            (take_callback(animation, "started"), take_callback(animation, "finished"))
+        }
+        Some(PropertyAnimation::Transition { state_ref, animations }) => {
+            // Only run the code of the animations of the active transition
+            let take_callbacks = |name: &str| {
+                let code = animations
+                    .iter()
+                    .filter_map(|a| {
+                        Some(Expression::Condition {
+                            condition: Box::new(a.condition(state_ref.clone())),
+                            true_expr: Box::new(take_callback(&a.animation, name)?),
+                            false_expr: Box::new(Expression::CodeBlock(vec![])),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                (!code.is_empty()).then_some(Expression::CodeBlock(code))
+            };
+            (take_callbacks("started"), take_callbacks("finished"))
+        }
+        None => return,
+    };
+    if started.is_none() && finished.is_none() {
+        return;
+    }
+
+    let mut is_animating_name = format!("{prop_name}-is-animating");
+    while elem.borrow().lookup_property(&is_animating_name).property_type != Type::Invalid {
+        is_animating_name += "-";
+    }
+    let mut elem_mut = elem.borrow_mut();
+    elem_mut
+        .property_declarations
+        .insert(is_animating_name.clone(), PropertyDeclaration::from(Type::Bool));
+    elem_mut.bindings.insert(
+        is_animating_name.clone(),
+        BindingExpression::from(Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::PropertyIsAnimating,
+                None,
+            )),
+            arguments: vec![Expression::PropertyReference(NamedReference::new(elem, prop_name))],
+            source_location: None,
+        })
+        .into(),
+    );
+    drop(elem_mut);
+
+    elem.borrow_mut()
+        .change_callbacks
+        .entry(is_animating_name.clone())
+        .or_default()
+        .borrow_mut()
+        .push(Expression::Condition {
+            condition: Box::new(Expression::PropertyReference(NamedReference::new(
+                elem,
+                &is_animating_name,
+            ))),
+            true_expr: Box::new(started.unwrap_or(Expression::CodeBlock(vec![]))),
+            false_expr: Box::new(finished.unwrap_or(Expression::CodeBlock(vec![]))),
+        });
+}
@@ -0,0 +1,401 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Passes for the `assert` statements and the `required` callbacks.
+//!
+//! [`check_assertions`] reports the assertions whose condition is known to be false at compile time,
+//! both where they're written and where their component is used, as well as the required callbacks
+//! that aren't set where the component is used. The callbacks of the exported components are set
+//! from the native code, so they aren't checked.
+//!
+//! [`lower_assertions`] then turns the assertions into a property bound to the condition, with a
+//! change callback and some init code that report the failure at run time, in debug builds.
+
+use crate::diagnostics::{BuildDiagnostics, Spanned};
+use crate::expression_tree::{BindingExpression, BuiltinFunction, Expression, NamedReference};
+use crate::langtype::{ElementType, EnumerationValue, Type};
+use crate::object_tree::*;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// How deep the bindings are followed to evaluate a condition
+const MAX_DEPTH: usize = 16;
+
+pub fn check_assertions(doc: &Document, diag: &mut BuildDiagnostics) {
+    let mut dynamic_properties = HashSet::new();
+    for component in &doc.inner_components {
+        collect_dynamic_properties(component, &mut dynamic_properties);
+    }
+    doc.visit_all_used_components(|component| {
+        collect_dynamic_properties(component, &mut dynamic_properties)
+    });
+
+    let definition = Evaluator { dynamic_properties: &dynamic_properties, instance: None };
+    for component in &doc.inner_components {
+        recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+            check_required_declarations(component, elem, diag);
+            for a in &elem.borrow().assertions {
+                if definition.evaluate(&a.condition, 0) == Some(Value::Bool(false)) {
+                    diag.push_error(format!("Assertion failed: {}", a.message), &a.node);
+                }
+            }
+            if !Rc::ptr_eq(&component.root_element, elem) {
+                check_instance(elem, &dynamic_properties, diag);
+            }
+        })
+    }
+}
+
+pub fn lower_assertions(component: &Rc<Component>) {
+    recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        if elem.borrow().repeated.is_some() {
+            if let ElementType::Component(base) = &elem.borrow().base_type {
+                if base.parent_element.upgrade().is_some() {
+                    lower_assertions(base);
+                }
+            }
+        }
+
+        let assertions = std::mem::take(&mut elem.borrow_mut().assertions);
+        for assertion in assertions {
+            lower_assertion(component, elem, assertion);
+        }
+    });
+    for popup in component.popup_windows.borrow().iter() {
+        lower_assertions(&popup.component);
+    }
+}
+
+fn lower_assertion(component: &Rc<Component>, elem: &ElementRc, assertion: Assertion) {
+    let mut prop_name = "assertion".to_owned();
+    while elem.borrow().lookup_property(&prop_name).property_type != Type::Invalid {
+        prop_name += "-";
+    }
+    let mut elem_mut = elem.borrow_mut();
+    elem_mut.property_declarations.insert(prop_name.clone(), PropertyDeclaration::from(Type::Bool));
+    elem_mut.bindings.insert(
+        prop_name.clone(),
+        BindingExpression::new_with_span(assertion.condition, assertion.node.to_source_location())
+            .into(),
+    );
+    drop(elem_mut);
+
+    let check = Expression::Condition {
+        condition: Box::new(Expression::PropertyReference(NamedReference::new(elem, &prop_name))),
+        true_expr: Box::new(Expression::CodeBlock(vec![])),
+        false_expr: Box::new(Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::AssertionFailed,
+                None,
+            )),
+            arguments: vec![Expression::StringLiteral(format!(
+                "Assertion failed: {}",
+                assertion.message
+            ))],
+            source_location: Some(assertion.node.to_source_location()),
+        }),
+    };
+    elem.borrow_mut()
+        .change_callbacks
+        .entry(prop_name)
+        .or_default()
+        .borrow_mut()
+        .push(check.clone());
+    component.init_code.borrow_mut().constructor_code.push(check);
+}
+
+/// Collects the properties whose value can change at run time even though their binding is
+/// constant: the ones that are assigned, changed by a state, or part of a two-way binding.
+fn collect_dynamic_properties(component: &Component, result: &mut HashSet<NamedReference>) {
+    recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+        for (name, binding) in &elem.borrow().bindings {
+            let binding = binding.borrow();
+            if !binding.two_way_bindings.is_empty() {
+                result.insert(NamedReference::new(elem, name));
+                result.extend(binding.two_way_bindings.iter().cloned());
+            }
+        }
+        for state in &elem.borrow().states {
+            result.extend(state.property_changes.iter().map(|(nr, _, _)| nr.clone()));
+        }
+        visit_element_expressions(elem, |expr, _, _| {
+            expr.visit_recursive(&mut |e| {
+                if let Expression::SelfAssignment { lhs, .. } = e {
+                    let mut lhs = &**lhs;
+                    while let Expression::StructFieldAccess { base: sub, .. }
+                    | Expression::ArrayIndex { array: sub, .. } = lhs
+                    {
+                        lhs = &**sub;
+                    }
+                    if let Expression::PropertyReference(nr) = lhs {
+                        result.insert(nr.clone());
+                    }
+                }
+            })
+        });
+    });
+}
+
+/// Reports the `required` qualifiers of the callbacks that aren't declared on the root element
+/// of a component.
+fn check_required_declarations(
+    component: &Component,
+    elem: &ElementRc,
+    diag: &mut BuildDiagnostics,
+) {
+    let is_root = Rc::ptr_eq(&component.root_element, elem);
+    for decl in elem.borrow().property_declarations.values() {
+        if !decl.is_required() {
+            continue;
+        }
+        let node = decl.node.as_ref().unwrap();
+        if component.is_global() {
+            diag.push_error("The callbacks of a global can't be required".into(), node);
+        } else if !is_root {
+            diag.push_error(
+                "Only the callbacks of the root element of a component can be required".into(),
+                node,
+            );
+        }
+    }
+}
+
+/// Checks the assertions and the required callbacks of the components that `instance` inherits
+/// from, with the bindings set on `instance`.
+fn check_instance(
+    instance: &ElementRc,
+    dynamic_properties: &HashSet<NamedReference>,
+    diag: &mut BuildDiagnostics,
+) {
+    let definition = Evaluator { dynamic_properties, instance: None };
+    let evaluator = Evaluator { dynamic_properties, instance: Some(instance) };
+    let mut base = instance.borrow().base_type.clone();
+    while let ElementType::Component(base_component) = base {
+        recurse_elem_including_sub_components(&base_component, &(), &mut |elem, _| {
+            for a in &elem.borrow().assertions {
+                if definition.evaluate(&a.condition, 0).is_none()
+                    && evaluator.evaluate(&a.condition, 0) == Some(Value::Bool(false))
+                {
+                    diag.push_error(
+                        format!("Assertion of '{}' failed: {}", base_component.id, a.message),
+                        &*instance.borrow(),
+                    );
+                }
+            }
+        });
+
+        let base_root = &base_component.root_element;
+        for (name, decl) in &base_root.borrow().property_declarations {
+            if decl.is_required() && !is_set_before(instance, base_root, name) {
+                diag.push_error(
+                    format!("The required callback '{name}' of '{}' isn't set", base_component.id),
+                    &*instance.borrow(),
+                );
+            }
+        }
+
+        base = base_root.borrow().base_type.clone();
+    }
+}
+
+/// Returns true if the element, or one of its bases up to `until` excluded, has a binding for the
+/// property or callback
+fn is_set_before(elem: &ElementRc, until: &ElementRc, name: &str) -> bool {
+    let mut current = elem.clone();
+    while !Rc::ptr_eq(&current, until) {
+        if current.borrow().bindings.contains_key(name) {
+            return true;
+        }
+        let ElementType::Component(base) = current.borrow().base_type.clone() else {
+            return false;
+        };
+        current = base.root_element.clone();
+    }
+    false
+}
+
+/// Returns true if `elem` is `instance` or the root element of one of the components it
+/// inherits from
+fn is_in_base_chain(instance: &ElementRc, elem: &ElementRc) -> bool {
+    let mut current = instance.clone();
+    loop {
+        if Rc::ptr_eq(&current, elem) {
+            return true;
+        }
+        let ElementType::Component(base) = current.borrow().base_type.clone() else {
+            return false;
+        };
+        current = base.root_element.clone();
+    }
+}
+
+/// A value known at compile time
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    /// Normalized to the default unit of the type, and with the integers as floats
+    Number(f64, Type),
+    Bool(bool),
+    String(String),
+    Enumeration(EnumerationValue),
+    /// The number of rows of an array
+    Array(usize),
+}
+
+fn number(value: f64, ty: Type) -> Value {
+    Value::Number(value, if ty == Type::Int32 { Type::Float32 } else { ty })
+}
+
+impl Value {
+    /// Whether the values can be compared with `==` and `!=`
+    fn is_comparable_with(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Number(_, a), Value::Number(_, b)) => a == b,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+struct Evaluator<'a> {
+    dynamic_properties: &'a HashSet<NamedReference>,
+    /// The element where the component of the assertion is used, to take its bindings into
+    /// account, or None to only consider the component itself
+    instance: Option<&'a ElementRc>,
+}
+
+impl Evaluator<'_> {
+    /// Returns the value of the expression, or None if it isn't known at compile time
+    fn evaluate(&self, expr: &Expression, depth: usize) -> Option<Value> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let eval = |e: &Expression| self.evaluate(e, depth);
+        Some(match expr {
+            Expression::NumberLiteral(x, unit) => number(unit.normalize(*x), unit.ty()),
+            Expression::BoolLiteral(b) => Value::Bool(*b),
+            Expression::StringLiteral(s) => Value::String(s.clone()),
+            Expression::EnumerationValue(v) => Value::Enumeration(v.clone()),
+            Expression::Array { values, .. } => Value::Array(values.len()),
+            Expression::PropertyReference(nr) => return self.property_value(nr, depth),
+            Expression::Cast { from, to } => return cast(eval(from)?, to),
+            Expression::UnaryOp { sub, op } => match (*op, eval(sub)?) {
+                ('!', Value::Bool(b)) => Value::Bool(!b),
+                ('-', Value::Number(x, ty)) => Value::Number(-x, ty),
+                ('+', x @ Value::Number(..)) => x,
+                _ => return None,
+            },
+            Expression::BinaryExpression { lhs, rhs, op } => {
+                return binary_operation(*op, eval(lhs), eval(rhs))
+            }
+            Expression::Condition { condition, true_expr, false_expr } => match eval(condition)? {
+                Value::Bool(true) => return eval(true_expr),
+                Value::Bool(false) => return eval(false_expr),
+                _ => return None,
+            },
+            Expression::CodeBlock(sub) if sub.len() == 1 => return eval(&sub[0]),
+            Expression::FunctionCall { function, arguments, .. } => {
+                match (&**function, arguments.as_slice()) {
+                    (
+                        Expression::BuiltinFunctionReference(BuiltinFunction::ArrayLength, _),
+                        [array],
+                    ) => match eval(array)? {
+                        Value::Array(len) => number(len as f64, Type::Int32),
+                        _ => return None,
+                    },
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    /// Returns the value of the property if it's declared in a .slint file and can only have the
+    /// value of its binding, or of its default value.
+    fn property_value(&self, nr: &NamedReference, depth: usize) -> Option<Value> {
+        let elem = nr.element();
+        let name = nr.name();
+        let component = elem.borrow().enclosing_component.upgrade()?;
+        if component.is_global() {
+            return None;
+        }
+        let start = match self.instance {
+            Some(instance) if is_in_base_chain(instance, &elem) => instance.clone(),
+            _ => elem,
+        };
+        let start_component = start.borrow().enclosing_component.upgrade()?;
+        if Rc::ptr_eq(&start_component.root_element, &start)
+            && matches!(
+                start.borrow().lookup_property(name).property_visibility,
+                PropertyVisibility::Input | PropertyVisibility::InOut
+            )
+        {
+            // Set from where the component is used
+            return None;
+        }
+
+        let mut chain = vec![start];
+        loop {
+            let base = chain.last().unwrap().borrow().base_type.clone();
+            let ElementType::Component(base) = base else { break };
+            chain.push(base.root_element.clone());
+        }
+        let declaration = chain
+            .iter()
+            .find_map(|e| e.borrow().property_declarations.get(name).cloned())
+            .filter(|d| d.is_alias.is_none())?;
+
+        for e in &chain {
+            if self.dynamic_properties.contains(&NamedReference::new(e, name)) {
+                return None;
+            }
+        }
+        for e in &chain {
+            if let Some(binding) = e.borrow().bindings.get(name) {
+                let binding = binding.borrow();
+                if !matches!(binding.expression, Expression::Invalid) {
+                    return self.evaluate(&binding.expression, depth + 1);
+                }
+            }
+        }
+        self.evaluate(&Expression::default_value_for_type(&declaration.property_type), depth + 1)
+    }
+}
+
+fn cast(value: Value, to: &Type) -> Option<Value> {
+    Some(match (value, to) {
+        (Value::Number(x, Type::Float32), Type::Int32) => number(x.trunc(), Type::Int32),
+        (Value::Number(x, ty), to) if ty == *to => Value::Number(x, ty),
+        (Value::Number(x, Type::Float32), Type::String) => Value::String(x.to_string()),
+        (Value::Number(x, Type::Float32), to)
+            if to.default_unit().is_some() || *to == Type::Percent =>
+        {
+            Value::Number(x, to.clone())
+        }
+        (Value::Array(len), Type::Array(_)) => Value::Array(len),
+        _ => return None,
+    })
+}
+
+fn binary_operation(op: char, lhs: Option<Value>, rhs: Option<Value>) -> Option<Value> {
+    use Value::*;
+    Some(match (op, lhs, rhs) {
+        ('&', Some(Bool(false)), _) | ('&', _, Some(Bool(false))) => Bool(false),
+        ('|', Some(Bool(true)), _) | ('|', _, Some(Bool(true))) => Bool(true),
+        (op, Some(lhs), Some(rhs)) => match (op, lhs, rhs) {
+            ('&' | '|', Bool(a), Bool(b)) => Bool(if op == '&' { a && b } else { a || b }),
+            ('=' | '!', a, b) if a.is_comparable_with(&b) => Bool((a == b) == (op == '=')),
+            ('+', String(a), String(b)) => String(a + &b),
+            ('+', Number(a, t1), Number(b, t2)) if t1 == t2 => Number(a + b, t1),
+            ('-', Number(a, t1), Number(b, t2)) if t1 == t2 => Number(a - b, t1),
+            ('*', Number(a, t1), Number(b, Type::Float32)) => Number(a * b, t1),
+            ('*', Number(a, Type::Float32), Number(b, t2)) => Number(a * b, t2),
+            ('/', Number(a, t1), Number(b, Type::Float32)) => Number(a / b, t1),
+            ('/', Number(a, t1), Number(b, t2)) if t1 == t2 => Number(a / b, Type::Float32),
+            ('<', Number(a, t1), Number(b, t2)) if t1 == t2 => Bool(a < b),
+            ('>', Number(a, t1), Number(b, t2)) if t1 == t2 => Bool(a > b),
+            ('≤', Number(a, t1), Number(b, t2)) if t1 == t2 => Bool(a <= b),
+            ('≥', Number(a, t1), Number(b, t2)) if t1 == t2 => Bool(a >= b),
+            _ => return None,
+        },
+        _ => return None,
+    })
+}
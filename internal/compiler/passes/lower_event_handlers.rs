@@ -0,0 +1,53 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Passe that lowers the `on Global.event(args) => { ... }` handlers.
+//!
+//! Emitting an event stores its arguments in properties of the global and increments a counter.
+//! Each handler becomes a change callback on a property bound to that counter, so that it goes
+//! away with the element that declares it.
+
+use crate::expression_tree::{BindingExpression, Expression, NamedReference};
+use crate::langtype::Type;
+use crate::object_tree::*;
+use std::rc::Rc;
+
+pub fn lower_event_handlers(component: &Rc<Component>) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let handlers = std::mem::take(&mut elem.borrow_mut().event_handlers);
+        for (index, handler) in handlers.into_iter().enumerate() {
+            lower_event_handler(elem, index, handler);
+        }
+    })
+}
+
+fn lower_event_handler(elem: &ElementRc, index: usize, handler: EventHandler) {
+    let global = handler.event.element();
+    let event = handler.event.name();
+    let mut code = handler.code;
+    replace_arguments(&mut code, &global, event);
+
+    let prop_name = format!("event-handler{index}-count");
+    let count = NamedReference::new(&global, &format!("{event}-event-count"));
+    let mut elem_mut = elem.borrow_mut();
+    elem_mut
+        .property_declarations
+        .insert(prop_name.clone(), PropertyDeclaration::from(Type::Int32));
+    elem_mut.bindings.insert(
+        prop_name.clone(),
+        BindingExpression::from(Expression::PropertyReference(count)).into(),
+    );
+    elem_mut.change_callbacks.entry(prop_name).or_default().borrow_mut().push(code);
+}
+
+/// The arguments of the handler are the last arguments the event was emitted with
+fn replace_arguments(expr: &mut Expression, global: &ElementRc, event: &str) {
+    if let Expression::FunctionParameterReference { index, .. } = expr {
+        *expr = Expression::PropertyReference(NamedReference::new(
+            global,
+            &format!("{event}-event-arg{index}"),
+        ));
+        return;
+    }
+    expr.visit_mut(|e| replace_arguments(e, global, event));
+}
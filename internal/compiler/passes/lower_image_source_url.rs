@@ -0,0 +1,58 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Passe that makes the `source`, `loading`, and `error` properties of the Image elements that
+//! have a `source-url` follow the image loaded from that URL at run-time.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BuiltinFunction, Expression, NamedReference};
+use crate::object_tree::*;
+use std::cell::RefCell;
+use std::collections::btree_map::Entry;
+use std::rc::Rc;
+
+pub fn lower_image_source_url(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let is_image = elem
+            .borrow()
+            .builtin_type()
+            .map_or(false, |b| b.native_class.class_name == "ClippedImage");
+        if is_image && elem.borrow().bindings.contains_key("source-url") {
+            lower_image(elem, diag);
+        }
+    })
+}
+
+fn lower_image(elem: &ElementRc, diag: &mut BuildDiagnostics) {
+    if let Some(source) = elem.borrow().bindings.get("source") {
+        if source.borrow().has_binding() {
+            diag.push_error(
+                "Cannot set both 'source' and 'source-url'. Use 'fallback' for the image to show while 'source-url' is loading".into(),
+                &*source.borrow(),
+            );
+            return;
+        }
+    }
+
+    let call = |function: BuiltinFunction, arguments: &[&str]| Expression::FunctionCall {
+        function: Expression::BuiltinFunctionReference(function, None).into(),
+        arguments: arguments
+            .iter()
+            .map(|name| Expression::PropertyReference(NamedReference::new(elem, name)))
+            .collect(),
+        source_location: None,
+    };
+    let bindings = [
+        ("source", call(BuiltinFunction::ImageFromUrl, &["source-url", "fallback"])),
+        ("loading", call(BuiltinFunction::ImageUrlLoading, &["source-url"])),
+        ("error", call(BuiltinFunction::ImageUrlError, &["source-url"])),
+    ];
+    for (name, expression) in bindings {
+        match elem.borrow_mut().bindings.entry(name.into()) {
+            Entry::Vacant(entry) => {
+                entry.insert(RefCell::new(expression.into()));
+            }
+            Entry::Occupied(mut entry) => entry.get_mut().get_mut().expression = expression,
+        }
+    }
+}
@@ -5,9 +5,9 @@
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::{Expression, NamedReference};
-use crate::langtype::{ElementType, Type};
+use crate::langtype::{ElementType, Enumeration, Type};
 use crate::object_tree::*;
-use crate::typeregister::TypeRegister;
+use crate::typeregister::{TypeRegister, BUILTIN_ENUMS};
 use std::rc::{Rc, Weak};
 
 pub fn lower_popups(
@@ -75,37 +75,49 @@ fn lower_popup_window(
         popup_window_element.borrow_mut().base_type = window_type.clone();
     }
 
-    const CLOSE_ON_CLICK: &str = "close-on-click";
-    let close_on_click = popup_window_element.borrow_mut().bindings.remove(CLOSE_ON_CLICK);
-    let close_on_click = close_on_click
-        .map(|b| {
-            let b = b.into_inner();
-            (b.expression, b.span)
-        })
-        .or_else(|| {
-            let mut base = popup_window_element.borrow().base_type.clone();
-            while let ElementType::Component(b) = base {
-                base = b.root_element.borrow().base_type.clone();
-                if let Some(binding) = b.root_element.borrow().bindings.get(CLOSE_ON_CLICK) {
-                    let b = binding.borrow();
-                    return Some((b.expression.clone(), b.span.clone()));
-                }
-            }
-            None
-        });
+    let Ok(close_on_click) = take_constant_binding(popup_window_element, "close-on-click", diag)
+    else {
+        return;
+    };
+    let Ok(close_policy) = take_constant_binding(popup_window_element, "close-policy", diag) else {
+        return;
+    };
+    let Ok(close_on_escape) = take_constant_binding(popup_window_element, "close-on-escape", diag)
+    else {
+        return;
+    };
+    let Ok(anchor_edge) = take_constant_binding(popup_window_element, "anchor-edge", diag) else {
+        return;
+    };
+    let Ok(anchor_offset) = take_constant_binding(popup_window_element, "anchor-offset", diag)
+    else {
+        return;
+    };
+    let Ok(anchor_flip) = take_constant_binding(popup_window_element, "anchor-flip", diag) else {
+        return;
+    };
 
-    let close_on_click = match close_on_click {
-        Some((expr, location)) => match expr {
-            Expression::BoolLiteral(value) => value,
-            _ => {
-                diag.push_error(
-                    "The close-on-click property only supports constants at the moment".into(),
-                    &location,
-                );
-                return;
-            }
-        },
-        None => true,
+    let enum_value = |value: Option<Expression>, enumeration: Rc<Enumeration>| match value {
+        Some(Expression::EnumerationValue(value)) => value,
+        _ => enumeration.default_value(),
+    };
+    let bool_value = |value: Option<Expression>, default| match value {
+        Some(Expression::BoolLiteral(value)) => value,
+        _ => default,
+    };
+
+    // `close-on-click: false` predates `close-policy`, which takes precedence
+    let close_policies = BUILTIN_ENUMS.with(|e| e.PopupClosePolicy.clone());
+    let close_policy = match (close_policy, close_on_click) {
+        (None, Some(Expression::BoolLiteral(false))) => {
+            close_policies.try_value_from_string("stay-open").unwrap()
+        }
+        (close_policy, _) => enum_value(close_policy, close_policies),
+    };
+    let anchor_edge = enum_value(anchor_edge, BUILTIN_ENUMS.with(|e| e.PopupAnchorEdge.clone()));
+    let anchor_offset = match anchor_offset {
+        Some(Expression::NumberLiteral(value, unit)) => unit.normalize(value),
+        _ => 0.,
     };
 
     let popup_comp = Rc::new(Component {
@@ -165,11 +177,58 @@ fn lower_popup_window(
         component: popup_comp,
         x: coord_x,
         y: coord_y,
-        close_on_click,
+        close_policy,
+        close_on_escape: bool_value(close_on_escape, true),
+        anchor_edge,
+        anchor_offset,
+        anchor_flip: bool_value(anchor_flip, true),
         parent_element: parent_element.clone(),
     });
 }
 
+/// Removes the binding of a property of the PopupWindow that must be a constant, and returns its
+/// value, which may also come from the base component. Returns an error if it's not a constant.
+fn take_constant_binding(
+    popup_window_element: &ElementRc,
+    name: &str,
+    diag: &mut BuildDiagnostics,
+) -> Result<Option<Expression>, ()> {
+    let binding = popup_window_element.borrow_mut().bindings.remove(name);
+    let binding = binding
+        .map(|b| {
+            let b = b.into_inner();
+            (b.expression, b.span)
+        })
+        .or_else(|| {
+            let mut base = popup_window_element.borrow().base_type.clone();
+            while let ElementType::Component(b) = base {
+                base = b.root_element.borrow().base_type.clone();
+                if let Some(binding) = b.root_element.borrow().bindings.get(name) {
+                    let b = binding.borrow();
+                    return Some((b.expression.clone(), b.span.clone()));
+                }
+            }
+            None
+        });
+
+    match binding {
+        None => Ok(None),
+        Some((
+            expr @ (Expression::BoolLiteral(_)
+            | Expression::EnumerationValue(_)
+            | Expression::NumberLiteral(..)),
+            _,
+        )) => Ok(Some(expr)),
+        Some((_, location)) => {
+            diag.push_error(
+                format!("The {name} property only supports constants at the moment"),
+                &location,
+            );
+            Err(())
+        }
+    }
+}
+
 fn check_element(
     element: &ElementRc,
     popup_comp: &Weak<Component>,
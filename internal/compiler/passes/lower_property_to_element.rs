@@ -24,14 +24,38 @@ pub(crate) fn lower_property_to_element(
     type_register: &TypeRegister,
     diag: &mut BuildDiagnostics,
 ) {
-    if let Some(b) = component.root_element.borrow().bindings.get(property_name) {
-        diag.push_warning(
-            format!(
-                "The {} property cannot be used on the root element, it will not be applied",
-                property_name
-            ),
-            &*b.borrow(),
-        );
+    lower_properties_to_element(
+        component,
+        &[property_name],
+        extra_properties,
+        default_value_for_extra_properties,
+        element_name,
+        type_register,
+        diag,
+    )
+}
+
+/// Same as [`lower_property_to_element`], but the element is created if any element in
+/// `component` declares a binding to any of the `property_names`, which are all mapped.
+pub(crate) fn lower_properties_to_element(
+    component: &Rc<Component>,
+    property_names: &[&'static str],
+    extra_properties: impl Iterator<Item = &'static str> + Clone,
+    default_value_for_extra_properties: Option<&dyn Fn(&ElementRc, &str) -> Expression>,
+    element_name: &str,
+    type_register: &TypeRegister,
+    diag: &mut BuildDiagnostics,
+) {
+    for property_name in property_names {
+        if let Some(b) = component.root_element.borrow().bindings.get(*property_name) {
+            diag.push_warning(
+                format!(
+                    "The {} property cannot be used on the root element, it will not be applied",
+                    property_name
+                ),
+                &*b.borrow(),
+            );
+        }
     }
 
     object_tree::recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
@@ -46,13 +70,15 @@ pub(crate) fn lower_property_to_element(
         };
 
         let has_property_binding = |e: &ElementRc| {
-            e.borrow().base_type.lookup_property(property_name).property_type != Type::Invalid
-                && (e.borrow().bindings.contains_key(property_name)
-                    || e.borrow()
-                        .property_analysis
-                        .borrow()
-                        .get(property_name)
-                        .map_or(false, |a| a.is_set || a.is_linked))
+            property_names.iter().any(|property_name| {
+                e.borrow().base_type.lookup_property(property_name).property_type != Type::Invalid
+                    && (e.borrow().bindings.contains_key(*property_name)
+                        || e.borrow()
+                            .property_analysis
+                            .borrow()
+                            .get(*property_name)
+                            .map_or(false, |a| a.is_set || a.is_linked))
+            })
         };
 
         for mut child in old_children {
@@ -63,7 +89,7 @@ pub(crate) fn lower_property_to_element(
                         &child,
                         create_property_element(
                             &root_elem,
-                            property_name,
+                            property_names,
                             extra_properties.clone(),
                             default_value_for_extra_properties,
                             element_name,
@@ -74,7 +100,7 @@ pub(crate) fn lower_property_to_element(
             } else if has_property_binding(&child) {
                 let new_child = create_property_element(
                     &child,
-                    property_name,
+                    property_names,
                     extra_properties.clone(),
                     default_value_for_extra_properties,
                     element_name,
@@ -92,13 +118,15 @@ pub(crate) fn lower_property_to_element(
 
 fn create_property_element(
     child: &ElementRc,
-    property_name: &'static str,
+    property_names: &[&'static str],
     extra_properties: impl Iterator<Item = &'static str>,
     default_value_for_extra_properties: Option<&dyn Fn(&ElementRc, &str) -> Expression>,
     element_name: &str,
     type_register: &TypeRegister,
 ) -> ElementRc {
-    let bindings = core::iter::once(property_name)
+    let bindings = property_names
+        .iter()
+        .copied()
         .chain(extra_properties)
         .map(|property_name| {
             let mut bind =
@@ -113,7 +141,7 @@ fn create_property_element(
         .collect();
 
     let element = Element {
-        id: format!("{}-{}", child.borrow().id, property_name),
+        id: format!("{}-{}", child.borrow().id, property_names[0]),
         base_type: type_register.lookup_element(element_name).unwrap(),
         enclosing_component: child.borrow().enclosing_component.clone(),
         bindings,
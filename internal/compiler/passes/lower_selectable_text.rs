@@ -0,0 +1,85 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers `Text` elements with a `selectable` binding to a read-only `TextInput`.
+//!
+//! The TextInput already implements selecting with the mouse, copying to the clipboard and
+//! rendering the selection. The `selectable` property is mapped to the `enabled` property.
+//!
+//! This pass must be run after default_geometry, so that the element keeps the implicit
+//! size of a `Text`, and after lower_accessibility, so that it keeps the accessible role of a `Text`.
+
+use crate::diagnostics::{BuildDiagnostics, Spanned};
+use crate::expression_tree::{BindingExpression, Expression, NamedReference};
+use crate::langtype::ElementType;
+use crate::object_tree::{Component, ElementRc, PropertyDeclaration};
+use crate::typeregister::TypeRegister;
+use std::rc::Rc;
+
+/// Properties of the Text element that cannot be lowered to a TextInput
+const UNSUPPORTED_PROPERTIES: &[&str] = &["overflow", "stroke", "stroke-width", "stroke-style"];
+
+pub fn lower_selectable_text(
+    component: &Rc<Component>,
+    type_register: &TypeRegister,
+    diag: &mut BuildDiagnostics,
+) {
+    let text_input_type = type_register.lookup_builtin_element("TextInput").unwrap();
+    crate::object_tree::recurse_elem_including_sub_components_no_borrow(
+        component,
+        &(),
+        &mut |elem, _| {
+            if is_selectable_text(elem) {
+                lower_text(elem, &text_input_type, diag);
+            }
+        },
+    )
+}
+
+fn is_selectable_text(elem: &ElementRc) -> bool {
+    let elem = elem.borrow();
+    matches!(&elem.base_type, ElementType::Builtin(b) if b.name == "Text")
+        && (elem.bindings.contains_key("selectable")
+            || elem
+                .property_analysis
+                .borrow()
+                .get("selectable")
+                .map_or(false, |a| a.is_set || a.is_linked))
+}
+
+fn lower_text(elem: &ElementRc, text_input_type: &ElementType, diag: &mut BuildDiagnostics) {
+    let ElementType::Builtin(text_type) = elem.borrow().base_type.clone() else { unreachable!() };
+
+    for prop in UNSUPPORTED_PROPERTIES {
+        if let Some(b) = elem.borrow().bindings.get(*prop) {
+            diag.push_error(
+                format!("The '{prop}' property is not supported on a selectable Text"),
+                &*b.borrow(),
+            );
+        }
+    }
+
+    let selectable = NamedReference::new(elem, "selectable");
+    let span = elem.borrow().to_source_location();
+    let mut elem_mut = elem.borrow_mut();
+
+    // Keep the properties that only exist on Text as plain properties, so that references to them stay valid
+    for (name, info) in text_type.properties.iter().chain(text_type.reserved_properties.iter()) {
+        if !text_input_type.lookup_property(name).is_valid() {
+            elem_mut
+                .property_declarations
+                .insert(name.clone(), PropertyDeclaration::from(info.ty.clone()));
+        }
+    }
+
+    for (prop, expr) in [
+        ("enabled", Expression::PropertyReference(selectable)),
+        ("read-only", Expression::BoolLiteral(true)),
+        ("single-line", Expression::BoolLiteral(false)),
+    ] {
+        elem_mut
+            .bindings
+            .insert(prop.into(), BindingExpression::new_with_span(expr, span.clone()).into());
+    }
+    elem_mut.base_type = text_input_type.clone();
+}
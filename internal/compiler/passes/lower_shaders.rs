@@ -0,0 +1,88 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that binds the properties declared in the Shader elements to the uniforms of their shader.
+//!
+//! The properties are packed in the order of their declaration into the `uniform-values` property
+//! of the native item, as floats: one for each number and four for each color.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BuiltinFunction, Expression, NamedReference, Unit};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub fn lower_shaders(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let is_shader = matches!(&elem.borrow().base_type,
+            ElementType::Builtin(b) if b.native_class.class_name == "Shader");
+        if is_shader {
+            lower_shader(elem, diag);
+        }
+    })
+}
+
+fn lower_shader(elem: &ElementRc, diag: &mut BuildDiagnostics) {
+    let mut declarations = elem
+        .borrow()
+        .property_declarations
+        .iter()
+        .filter(|(_, decl)| {
+            !matches!(decl.property_type, Type::Callback { .. } | Type::Function { .. })
+        })
+        .map(|(name, decl)| (name.clone(), decl.clone()))
+        .collect::<Vec<_>>();
+    declarations.sort_by_key(|(_, decl)| {
+        decl.node.as_ref().map_or(u32::MAX, |n| n.text_range().start().into())
+    });
+
+    let mut values = Vec::new();
+    for (name, decl) in declarations {
+        let property = Expression::PropertyReference(NamedReference::new(elem, &name));
+        match &decl.property_type {
+            Type::Float32
+            | Type::Int32
+            | Type::Duration
+            | Type::PhysicalLength
+            | Type::LogicalLength
+            | Type::Rem
+            | Type::Angle
+            | Type::Percent => {
+                values.push(Expression::Cast { from: property.into(), to: Type::Float32 })
+            }
+            Type::Color => {
+                for field in ["red", "green", "blue", "alpha"] {
+                    let component = Expression::StructFieldAccess {
+                        base: Expression::FunctionCall {
+                            function: Expression::BuiltinFunctionReference(
+                                BuiltinFunction::ColorRgbaStruct,
+                                None,
+                            )
+                            .into(),
+                            arguments: vec![property.clone()],
+                            source_location: None,
+                        }
+                        .into(),
+                        name: field.into(),
+                    };
+                    values.push(Expression::BinaryExpression {
+                        lhs: Expression::Cast { from: component.into(), to: Type::Float32 }.into(),
+                        rhs: Expression::NumberLiteral(255., Unit::None).into(),
+                        op: '/',
+                    });
+                }
+            }
+            ty => diag.push_error(
+                format!("The properties of a Shader are passed to its uniforms, and can't be of type '{ty}'. Use numbers or colors"),
+                &decl.type_node(),
+            ),
+        }
+    }
+
+    let uniform_values = Expression::Cast {
+        from: Expression::Array { element_ty: Type::Float32, values }.into(),
+        to: Type::LayoutCache,
+    };
+    elem.borrow_mut().bindings.insert("uniform-values".into(), RefCell::new(uniform_values.into()));
+}
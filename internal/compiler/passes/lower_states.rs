@@ -2,6 +2,9 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 //! Pass that create a state property, and change all the binding to depend on that property
+//!
+//! When the states have event transitions, they form a state machine: the current state is stored in a
+//! property that is changed by the functions generated for the events.
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::diagnostics::SourceLocation;
@@ -11,7 +14,7 @@
 use crate::langtype::Type;
 use crate::object_tree::*;
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::rc::{Rc, Weak};
 
 pub fn lower_states(
@@ -35,7 +38,7 @@ fn lower_state_in_element(
         return;
     }
     let has_transitions = !root_element.borrow().transitions.is_empty();
-    let state_property_name = compute_state_property_name(root_element);
+    let state_property_name = compute_state_property_name(root_element, "state");
     let state_property =
         Expression::PropertyReference(NamedReference::new(root_element, &state_property_name));
     let state_property_ref = if has_transitions {
@@ -51,8 +54,17 @@ fn lower_state_in_element(
     let mut states_id = HashMap::new();
     let mut state_value = Expression::NumberLiteral(0., Unit::None);
     let states = std::mem::take(&mut root_element.borrow_mut().states);
+    let first_event_transition =
+        states.iter().flat_map(|s| s.event_transitions.first()).next().map(|t| t.node.clone());
+    let mut state_callbacks = HashMap::new();
+    let mut event_transitions = Vec::new();
     for (idx, state) in states.into_iter().enumerate().rev() {
-        if let Some(condition) = &state.condition {
+        if let Some(node) = first_event_transition.as_ref().filter(|_| state.condition.is_some()) {
+            diag.push_error(
+                format!("The state '{}' cannot have a 'when' condition because the states have event transitions", state.id),
+                node,
+            );
+        } else if let Some(condition) = &state.condition {
             state_value = Expression::Condition {
                 condition: Box::new(condition.clone()),
                 true_expr: Box::new(Expression::NumberLiteral((idx + 1) as _, Unit::None)),
@@ -99,8 +111,31 @@ fn lower_state_in_element(
                 }
             };
         }
+        if state.entered.is_some() || state.exited.is_some() {
+            state_callbacks.insert(idx as i32 + 1, (state.entered, state.exited));
+        }
+        event_transitions
+            .extend(state.event_transitions.into_iter().rev().map(|t| (idx as i32 + 1, t)));
         states_id.insert(state.id, idx as i32 + 1);
     }
+    event_transitions.reverse();
+
+    if first_event_transition.is_some() {
+        state_value = lower_state_machine(
+            root_element,
+            &states_id,
+            &state_callbacks,
+            event_transitions,
+            diag,
+        );
+    } else if !state_callbacks.is_empty() {
+        lower_state_callbacks(
+            root_element,
+            &state_property_name,
+            state_property_ref.clone(),
+            &state_callbacks,
+        );
+    }
 
     root_element.borrow_mut().property_declarations.insert(
         state_property_name.clone(),
@@ -182,9 +217,165 @@ fn lower_transitions_in_element(
     }
 }
 
-/// Returns a suitable unique name for the "state" property
-fn compute_state_property_name(root_element: &ElementRc) -> String {
-    let mut property_name = "state".to_owned();
+/// The `entered` and `exited` callbacks of the states, by state id
+type StateCallbacks = HashMap<i32, (Option<Expression>, Option<Expression>)>;
+
+/// Creates the property that holds the current state of a state machine, and the body of the
+/// functions of the events. Returns the expression for the current state.
+fn lower_state_machine(
+    elem: &ElementRc,
+    states_id: &HashMap<String, i32>,
+    state_callbacks: &StateCallbacks,
+    event_transitions: Vec<(i32, StateEventTransition)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    let current_name = compute_state_property_name(elem, "current-state");
+    let current = NamedReference::new(elem, &current_name);
+    // The initial state is the first state
+    elem.borrow_mut().property_declarations.insert(current_name.clone(), Type::Int32.into());
+    elem.borrow_mut()
+        .bindings
+        .insert(current_name, RefCell::new(Expression::NumberLiteral(1., Unit::None).into()));
+
+    let callback = |id: i32, entered: bool| {
+        state_callbacks.get(&id).and_then(|(e, x)| if entered { e.clone() } else { x.clone() })
+    };
+
+    // Maps the event name to the list of (source state, target state, guard)
+    let mut events = BTreeMap::<String, Vec<(i32, i32, Option<Expression>)>>::new();
+    for (from, transition) in event_transitions {
+        let Some(to) = states_id.get(&transition.target) else {
+            diag.push_error(
+                format!("State '{}' does not exist", transition.target),
+                &transition.node.QualifiedName(),
+            );
+            continue;
+        };
+        events.entry(transition.event).or_default().push((from, *to, transition.guard));
+    }
+
+    for (event, transitions) in events {
+        let mut code = Expression::CodeBlock(vec![]);
+        for (from, to, guard) in transitions.into_iter().rev() {
+            let mut condition = is_state(Expression::PropertyReference(current.clone()), from);
+            if let Some(guard) = guard {
+                condition = Expression::BinaryExpression {
+                    lhs: Box::new(condition),
+                    rhs: Box::new(guard),
+                    op: '&',
+                };
+            }
+            let change_state = Expression::SelfAssignment {
+                lhs: Box::new(Expression::PropertyReference(current.clone())),
+                rhs: Box::new(Expression::NumberLiteral(to as _, Unit::None)),
+                op: '=',
+                node: None,
+            };
+            let transition_code = callback(from, false)
+                .into_iter()
+                .chain(std::iter::once(change_state))
+                .chain(callback(to, true))
+                .collect();
+            code = Expression::Condition {
+                condition: Box::new(condition),
+                true_expr: Box::new(Expression::CodeBlock(transition_code)),
+                false_expr: Box::new(code),
+            };
+        }
+        if let Some(binding) = elem.borrow().bindings.get(&event) {
+            binding.borrow_mut().expression = code;
+        }
+    }
+
+    if let Some(entered) = callback(1, true) {
+        add_init_code(elem, entered);
+    }
+
+    Expression::PropertyReference(current)
+}
+
+/// Invokes the `entered` and `exited` callbacks of states that depend on `when` conditions when
+/// the state changes.
+fn lower_state_callbacks(
+    elem: &ElementRc,
+    state_property_name: &str,
+    current_state: Expression,
+    state_callbacks: &StateCallbacks,
+) {
+    let previous_name = compute_state_property_name(elem, "previous-state");
+    let previous = Expression::PropertyReference(NamedReference::new(elem, &previous_name));
+    elem.borrow_mut().property_declarations.insert(previous_name, Type::Int32.into());
+
+    let mut exited_code = vec![];
+    let mut entered_code = vec![];
+    let mut ids = state_callbacks.keys().copied().collect::<Vec<_>>();
+    ids.sort();
+    for id in ids {
+        let (entered, exited) = &state_callbacks[&id];
+        if let Some(exited) = exited {
+            exited_code.push(Expression::Condition {
+                condition: Box::new(is_state(previous.clone(), id)),
+                true_expr: Box::new(exited.clone()),
+                false_expr: Box::new(Expression::CodeBlock(vec![])),
+            });
+        }
+        if let Some(entered) = entered {
+            entered_code.push(Expression::Condition {
+                condition: Box::new(is_state(current_state.clone(), id)),
+                true_expr: Box::new(entered.clone()),
+                false_expr: Box::new(Expression::CodeBlock(vec![])),
+            });
+        }
+    }
+    let store_previous = Expression::SelfAssignment {
+        lhs: Box::new(previous),
+        rhs: Box::new(current_state),
+        op: '=',
+        node: None,
+    };
+
+    let init_code =
+        entered_code.iter().cloned().chain(std::iter::once(store_previous.clone())).collect();
+    add_init_code(elem, Expression::CodeBlock(init_code));
+
+    let change_code = exited_code
+        .into_iter()
+        .chain(entered_code)
+        .chain(std::iter::once(store_previous))
+        .collect();
+    elem.borrow_mut()
+        .change_callbacks
+        .entry(state_property_name.into())
+        .or_default()
+        .borrow_mut()
+        .push(Expression::CodeBlock(change_code));
+}
+
+fn is_state(state: Expression, id: i32) -> Expression {
+    Expression::BinaryExpression {
+        lhs: Box::new(state),
+        rhs: Box::new(Expression::NumberLiteral(id as _, Unit::None)),
+        op: '=',
+    }
+}
+
+/// Appends the code to the `init` callback of the element
+fn add_init_code(elem: &ElementRc, code: Expression) {
+    match elem.borrow_mut().bindings.entry("init".into()) {
+        std::collections::btree_map::Entry::Occupied(mut e) => {
+            let binding = e.get_mut().get_mut();
+            binding.expression =
+                Expression::CodeBlock(vec![std::mem::take(&mut binding.expression), code]);
+        }
+        std::collections::btree_map::Entry::Vacant(e) => {
+            e.insert(RefCell::new(code.into()));
+        }
+    }
+}
+
+/// Returns a suitable unique name for the "state" property, or another property of the states
+fn compute_state_property_name(root_element: &ElementRc, name: &str) -> String {
+    let mut property_name = name.to_owned();
     while root_element.borrow().lookup_property(property_name.as_ref()).property_type
         != Type::Invalid
     {
@@ -8,47 +8,84 @@
 //! Must be done before inlining and many other passes because the lowered code must
 //! be further inlined as it may expends to native widget that needs inlining
 
-use crate::diagnostics::BuildDiagnostics;
+use crate::diagnostics::{BuildDiagnostics, Spanned};
 use crate::expression_tree::{BindingExpression, Expression, MinMaxOp, NamedReference, Unit};
 use crate::langtype::{ElementType, Type};
 use crate::object_tree::*;
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 pub async fn lower_tabwidget(
     doc: &Document,
     type_loader: &mut crate::typeloader::TypeLoader,
     diag: &mut BuildDiagnostics,
 ) {
-    // Ignore import errors
-    let mut build_diags_to_ignore = BuildDiagnostics::default();
-    let tabwidget_impl = type_loader
-        .import_component("std-widgets.slint", "TabWidgetImpl", &mut build_diags_to_ignore)
-        .await
-        .expect("can't load TabWidgetImpl from std-widgets.slint");
-    let tab_impl = type_loader
-        .import_component("std-widgets.slint", "TabImpl", &mut build_diags_to_ignore)
+    let default_impls = load_tabwidget_impls(type_loader, "std-widgets.slint")
         .await
-        .expect("can't load TabImpl from std-widgets.slint");
-    let tabbar_impl = type_loader
-        .import_component("std-widgets.slint", "TabBarImpl", &mut build_diags_to_ignore)
-        .await
-        .expect("can't load TabBarImpl from std-widgets.slint");
+        .expect("can't load TabWidgetImpl, TabImpl and TabBarImpl from std-widgets.slint");
     let empty_type = type_loader.global_type_registry.borrow().empty_type();
 
+    let mut tabwidgets = Vec::new();
     doc.visit_all_used_components(|component| {
         recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
             if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "TabWidget") {
-                process_tabwidget(
-                    elem,
-                    ElementType::Component(tabwidget_impl.clone()),
-                    ElementType::Component(tab_impl.clone()),
-                    ElementType::Component(tabbar_impl.clone()),
-                    &empty_type,
-                    diag,
-                );
+                tabwidgets.push(elem.clone());
             }
         })
     });
+
+    // The TabWidget of a style that was imported with `style "..."` uses the implementation of
+    // that style, and not the one of the application style
+    let mut style_impls = HashMap::new();
+    for elem in tabwidgets {
+        let impls = match style_file(&elem) {
+            Some(style_file) => {
+                if !style_impls.contains_key(&style_file) {
+                    let impls =
+                        load_tabwidget_impls(type_loader, &style_file.to_string_lossy()).await;
+                    style_impls.insert(style_file.clone(), impls);
+                }
+                style_impls[&style_file].clone().unwrap_or_else(|| default_impls.clone())
+            }
+            None => default_impls.clone(),
+        };
+        let (tabwidget_impl, tab_impl, tabbar_impl) = impls;
+        process_tabwidget(&elem, tabwidget_impl, tab_impl, tabbar_impl, &empty_type, diag);
+    }
+}
+
+async fn load_tabwidget_impls(
+    type_loader: &mut crate::typeloader::TypeLoader,
+    file: &str,
+) -> Option<(ElementType, ElementType, ElementType)> {
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let tabwidget_impl =
+        type_loader.import_component(file, "TabWidgetImpl", &mut build_diags_to_ignore).await?;
+    let tab_impl =
+        type_loader.import_component(file, "TabImpl", &mut build_diags_to_ignore).await?;
+    let tabbar_impl =
+        type_loader.import_component(file, "TabBarImpl", &mut build_diags_to_ignore).await?;
+    Some((
+        ElementType::Component(tabwidget_impl),
+        ElementType::Component(tab_impl),
+        ElementType::Component(tabbar_impl),
+    ))
+}
+
+/// Returns the std-widgets.slint file next to the TabWidget of the style that the element is based on
+fn style_file(elem: &ElementRc) -> Option<PathBuf> {
+    let mut base_type = elem.borrow().base_type.clone();
+    while let ElementType::Component(component) = base_type {
+        let next = component.root_element.borrow().base_type.clone();
+        if matches!(next, ElementType::Builtin(_)) {
+            let source_file = component.node.as_ref()?.source_file()?;
+            return Some(source_file.path().with_file_name("std-widgets.slint"));
+        }
+        base_type = next;
+    }
+    None
 }
 
 fn process_tabwidget(
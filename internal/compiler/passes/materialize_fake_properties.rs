@@ -109,9 +109,19 @@ fn should_materialize(
         let ty = crate::typeregister::reserved_property(prop).property_type;
         if ty != Type::Invalid {
             return Some(ty);
-        } else if prop == "close-on-click" {
-            // PopupWindow::close-on-click
+        } else if matches!(prop, "close-on-click" | "close-on-escape" | "anchor-flip") {
+            // The constant properties of PopupWindow
             return Some(Type::Bool);
+        } else if prop == "anchor-offset" {
+            return Some(Type::LogicalLength);
+        } else if prop == "close-policy" {
+            return Some(Type::Enumeration(
+                crate::typeregister::BUILTIN_ENUMS.with(|e| e.PopupClosePolicy.clone()),
+            ));
+        } else if prop == "anchor-edge" {
+            return Some(Type::Enumeration(
+                crate::typeregister::BUILTIN_ENUMS.with(|e| e.PopupAnchorEdge.clone()),
+            ));
         }
     }
     None
@@ -151,6 +161,7 @@ pub fn initialize(elem: &ElementRc, name: &str) -> Option<Expression> {
         "horizontal-stretch" => layout_constraint_prop(elem, "stretch", Orientation::Horizontal),
         "vertical-stretch" => layout_constraint_prop(elem, "stretch", Orientation::Vertical),
         "opacity" => Expression::NumberLiteral(1., Unit::None),
+        "transform-scale-x" | "transform-scale-y" => Expression::NumberLiteral(1., Unit::None),
         "visible" => Expression::BoolLiteral(true),
         _ => return None,
     };
@@ -104,6 +104,9 @@ fn do_move_declarations(component: &Rc<Component>) {
     component.init_code.borrow_mut().iter_mut().for_each(|expr| {
         visit_named_references_in_expression(expr, &mut fixup_reference);
     });
+    component.init_code.borrow_mut().destructor_code.iter_mut().for_each(|expr| {
+        visit_named_references_in_expression(expr, &mut fixup_reference);
+    });
     for pd in decl.property_declarations.values_mut() {
         pd.is_alias.as_mut().map(fixup_reference);
     }
@@ -73,6 +73,16 @@ pub fn remove_aliases(doc: &Document, diag: &mut BuildDiagnostics) {
                     diag.push_error("Property cannot alias to itself".into(), &*binding.borrow());
                     continue 'bindings;
                 }
+                if binding.borrow().animation.is_some()
+                    && !Rc::ptr_eq(
+                        &e.borrow().enclosing_component.upgrade().unwrap(),
+                        &other_e.borrow().enclosing_component.upgrade().unwrap(),
+                    )
+                {
+                    // The animation can't be moved to a property of another component, such as
+                    // a global, so keep the property and link it at run-time
+                    continue;
+                }
                 property_sets.add_link(NamedReference::new(e, name), nr.clone());
             }
         }
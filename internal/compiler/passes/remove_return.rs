@@ -428,7 +428,13 @@ fn make_struct(it: impl Iterator<Item = (&'static str, Type, Expression)>) -> Ex
     codeblock_with_expr(
         voids,
         Expression::Struct {
-            ty: Type::Struct { fields, name: None, node: None, rust_attributes: None },
+            ty: Type::Struct {
+                fields,
+                name: None,
+                node: None,
+                rust_attributes: None,
+                functions: None,
+            },
             values,
         },
     )
@@ -47,9 +47,12 @@ fn create_repeater_components(component: &Rc<Component>) {
                 named_references: Default::default(),
                 repeated: None,
                 is_component_placeholder: false,
+                children_slot: None,
                 debug: elem.debug.clone(),
                 enclosing_component: Default::default(),
                 states: std::mem::take(&mut elem.states),
+                event_handlers: std::mem::take(&mut elem.event_handlers),
+                assertions: std::mem::take(&mut elem.assertions),
                 transitions: std::mem::take(&mut elem.transitions),
                 child_of_layout: elem.child_of_layout || is_listview.is_some(),
                 layout_info_prop: elem.layout_info_prop.take(),
@@ -86,6 +89,10 @@ fn create_repeater_components(component: &Rc<Component>) {
             }
 
             NamedReference::new(&comp.root_element, "y").mark_as_set();
+            if let Some(sections) = &listview.sections {
+                sections.current_section.mark_as_set();
+                sections.next_section_y.mark_as_set();
+            }
         }
 
         let weak = Rc::downgrade(&comp);
@@ -160,4 +160,12 @@ fn select_minimal_class() {
         .class_name,
         "BorderRectangle",
     );
+    assert_eq!(
+        select_minimal_class_based_on_property_usage(
+            &rect.native_class,
+            ["border-width".to_owned(), "border-left-color".to_owned()].iter()
+        )
+        .class_name,
+        "BorderRectangle",
+    );
 }
@@ -19,6 +19,24 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 
+/// The names of the arguments of the callback or function, as declared in its element or in a base component
+fn declared_argument_names(nr: &NamedReference) -> Vec<Option<String>> {
+    let mut elem = nr.element();
+    loop {
+        let base = {
+            let e = elem.borrow();
+            if let Some(declaration) = e.property_declarations.get(nr.name()) {
+                return declaration.argument_names();
+            }
+            match &e.base_type {
+                ElementType::Component(c) => c.root_element.clone(),
+                _ => return vec![],
+            }
+        };
+        elem = base;
+    }
+}
+
 /// This represents a scope for the Component, where Component is the repeated component, but
 /// does not represent a component in the .slint file
 #[derive(Clone)]
@@ -43,6 +61,7 @@ fn resolve_expression(
             type_register,
             type_loader: Some(type_loader),
             current_token: None,
+            local_variables: vec![],
         };
 
         let new_expr = match node.kind() {
@@ -62,6 +81,13 @@ fn resolve_expression(
                 syntax_nodes::PropertyChangedCallback::from(node.clone()).CodeBlock(),
                 &mut lookup_ctx,
             ),
+            SyntaxKind::EventHandler => {
+                Expression::from_event_handler(node.clone().into(), &mut lookup_ctx)
+            }
+            SyntaxKind::StateCallback => Expression::from_codeblock_node(
+                syntax_nodes::StateCallback::from(node.clone()).CodeBlock(),
+                &mut lookup_ctx,
+            ),
             SyntaxKind::TwoWayBinding => {
                 assert!(diag.has_errors(), "Two way binding should have been resolved already  (property: {property_name:?})");
                 Expression::Invalid
@@ -84,6 +110,7 @@ pub fn resolve_expressions(
 
     for component in doc.inner_components.iter() {
         let scope = ComponentScope(vec![]);
+        let type_register = component.type_register(&doc.local_registry);
 
         recurse_elem(&component.root_element, &scope, &mut |elem, scope| {
             let mut new_scope = scope.clone();
@@ -101,7 +128,7 @@ pub fn resolve_expressions(
                         property_name,
                         property_type(),
                         scope,
-                        &doc.local_registry,
+                        type_register,
                         type_loader,
                         diag,
                     );
@@ -112,7 +139,7 @@ pub fn resolve_expressions(
                         property_name,
                         property_type(),
                         &new_scope,
-                        &doc.local_registry,
+                        type_register,
                         type_loader,
                         diag,
                     )
@@ -171,6 +198,28 @@ pub fn from_binding_expression_node(node: SyntaxNode, ctx: &mut LookupCtx) -> Se
         }
     }
 
+    /// Replace a number in the `vw` or `vh` unit, which is a percentage of the width or height of
+    /// the window, with a multiplication of the size of the window.
+    fn lower_window_relative_unit(e: Expression, node: &syntax_nodes::Expression) -> Expression {
+        let (value, function) = match e {
+            Expression::NumberLiteral(value, Unit::Vw) => (value, BuiltinFunction::GetWindowWidth),
+            Expression::NumberLiteral(value, Unit::Vh) => (value, BuiltinFunction::GetWindowHeight),
+            e => return e,
+        };
+        Expression::BinaryExpression {
+            lhs: Box::new(Expression::FunctionCall {
+                function: Box::new(Expression::BuiltinFunctionReference(
+                    function,
+                    Some(node.to_source_location()),
+                )),
+                arguments: vec![],
+                source_location: Some(node.to_source_location()),
+            }),
+            rhs: Box::new(Expression::NumberLiteral(value / 100., Unit::None)),
+            op: '*',
+        }
+    }
+
     fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Expression {
         debug_assert_eq!(node.kind(), SyntaxKind::CodeBlock);
 
@@ -241,10 +290,20 @@ fn from_callback_connection(
         )
     }
 
+    fn from_event_handler(node: syntax_nodes::EventHandler, ctx: &mut LookupCtx) -> Expression {
+        ctx.arguments =
+            node.DeclaredIdentifier().map(|x| identifier_text(&x).unwrap_or_default()).collect();
+        Self::from_codeblock_node(node.CodeBlock(), ctx)
+    }
+
     fn from_function(node: syntax_nodes::Function, ctx: &mut LookupCtx) -> Expression {
-        ctx.arguments = node
-            .ArgumentDeclaration()
-            .map(|x| identifier_text(&x.DeclaredIdentifier()).unwrap_or_default())
+        ctx.arguments = crate::object_tree::is_struct_function(&node)
+            .then(|| "self".to_string())
+            .into_iter()
+            .chain(
+                node.ArgumentDeclaration()
+                    .map(|x| identifier_text(&x.DeclaredIdentifier()).unwrap_or_default()),
+            )
             .collect();
         Self::from_codeblock_node(node.CodeBlock(), ctx).maybe_convert_to(
             ctx.return_type().clone(),
@@ -259,6 +318,12 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
             .or_else(|| node.AtImageUrl().map(|n| Self::from_at_image_url_node(n, ctx)))
             .or_else(|| node.AtGradient().map(|n| Self::from_at_gradient(n, ctx)))
             .or_else(|| node.AtTr().map(|n| Self::from_at_tr(n, ctx)))
+            .or_else(|| {
+                node.AtCfg().map(|n| {
+                    let flag = crate::object_tree::cfg_flag(&n);
+                    Self::BoolLiteral(flag.is_some_and(|f| ctx.type_register.is_cfg_flag_set(&f)))
+                })
+            })
             .or_else(|| {
                 node.QualifiedName().map(|n| {
                     let exp =
@@ -287,7 +352,10 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
             })
             .or_else(|| {
                 node.child_text(SyntaxKind::NumberLiteral)
-                    .map(crate::literals::parse_number_literal)
+                    .map(|s| {
+                        crate::literals::parse_number_literal(s)
+                            .map(|e| Self::lower_window_relative_unit(e, &node))
+                    })
                     .transpose()
                     .unwrap_or_else(|e| {
                         ctx.diag.push_error(e, &node);
@@ -320,10 +388,21 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
             .or_else(|| {
                 node.ConditionalExpression().map(|n| Self::from_conditional_expression_node(n, ctx))
             })
+            .or_else(|| node.MatchExpression().map(|n| Self::from_match_expression_node(n, ctx)))
             .or_else(|| node.ObjectLiteral().map(|n| Self::from_object_literal_node(n, ctx)))
             .or_else(|| node.Array().map(|n| Self::from_array_node(n, ctx)))
             .or_else(|| node.CodeBlock().map(|n| Self::from_codeblock_node(n, ctx)))
             .or_else(|| node.StringTemplate().map(|n| Self::from_string_template_node(n, ctx)))
+            .or_else(|| {
+                node.Lambda().map(|n| {
+                    ctx.diag.push_error(
+                        "'item => expression' can only be used as the argument of filter() or sort-by()"
+                            .into(),
+                        &n,
+                    );
+                    Self::Invalid
+                })
+            })
             .unwrap_or(Self::Invalid)
     }
 
@@ -400,22 +479,52 @@ fn from_at_image_url_node(node: syntax_nodes::AtImageUrl, ctx: &mut LookupCtx) -
             }
         };
 
-        Expression::ImageReference {
-            resource_ref: ImageReference::AbsolutePath(absolute_source_path),
+        let image = |path: String| Expression::ImageReference {
+            resource_ref: ImageReference::AbsolutePath(path),
             source_location: Some(node.to_source_location()),
             nine_slice,
+        };
+
+        let localized = node
+            .children_with_tokens()
+            .filter_map(|n| n.into_token())
+            .any(|t| t.kind() == SyntaxKind::Identifier && t.text() == "locale");
+        if !localized {
+            return image(absolute_source_path);
         }
+
+        // Test the variants with a region first, so that `logo.de_CH.png` is preferred over
+        // `logo.de.png`, and fall back to the image without a locale
+        let variants = locale_variants(&absolute_source_path);
+        variants.into_iter().fold(image(absolute_source_path), |fallback, (locale, path)| {
+            Expression::Condition {
+                condition: Expression::FunctionCall {
+                    function: Expression::BuiltinFunctionReference(
+                        BuiltinFunction::TranslationLanguageMatches,
+                        None,
+                    )
+                    .into(),
+                    arguments: vec![Expression::StringLiteral(locale)],
+                    source_location: Some(node.to_source_location()),
+                }
+                .into(),
+                true_expr: image(path).into(),
+                false_expr: fallback.into(),
+            }
+        })
     }
 
     fn from_at_gradient(node: syntax_nodes::AtGradient, ctx: &mut LookupCtx) -> Self {
         enum GradKind {
             Linear { angle: Box<Expression> },
             Radial,
+            Conic { from_angle: Box<Expression> },
         }
 
         let mut subs = node
             .children_with_tokens()
-            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression));
+            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression))
+            .peekable();
 
         let grad_token = node.child_token(SyntaxKind::Identifier).unwrap();
         let grad_text = grad_token.text();
@@ -464,11 +573,60 @@ enum GradKind {
                 return Expression::Invalid;
             }
             GradKind::Radial
+        } else if grad_text.starts_with("conic") {
+            let from_angle = if matches!(subs.peek(), Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == "from")
+            {
+                subs.next();
+                let angle_expr = match subs.next() {
+                    Some(e) if e.kind() == SyntaxKind::Expression => {
+                        syntax_nodes::Expression::from(e.into_node().unwrap())
+                    }
+                    _ => {
+                        ctx.diag.push_error("Expected angle expression after 'from'".into(), &node);
+                        return Expression::Invalid;
+                    }
+                };
+                let comma = subs.next();
+                if comma.as_ref().map_or(false, |s| s.kind() != SyntaxKind::Comma) {
+                    ctx.diag.push_error(
+                        "'from' angle must be followed by a comma".into(),
+                        comma.as_ref().map_or(&node, |x| x as &dyn Spanned),
+                    );
+                    return Expression::Invalid;
+                }
+                Expression::from_expression_node(angle_expr.clone(), ctx).maybe_convert_to(
+                    Type::Angle,
+                    &angle_expr,
+                    ctx.diag,
+                )
+            } else {
+                Expression::NumberLiteral(0., Unit::Deg)
+            };
+            GradKind::Conic { from_angle: Box::new(from_angle) }
         } else {
-            // Parser should have ensured we have one of the linear or radial gradient
+            // Parser should have ensured we have one of the linear, radial or conic gradient
             panic!("Not a gradient {grad_text:?}");
         };
 
+        // Linear and radial gradients can end with a keyword for their spread, after a comma
+        let mut subs = subs.collect::<Vec<_>>();
+        let spread = match subs.as_slice() {
+            [.., comma, NodeOrToken::Node(n)]
+                if comma.kind() == SyntaxKind::Comma
+                    && !matches!(grad_kind, GradKind::Conic { .. }) =>
+            {
+                match n.text().to_string().trim() {
+                    "repeat" => GradientSpread::Repeat,
+                    "reflect" => GradientSpread::Reflect,
+                    _ => GradientSpread::Pad,
+                }
+            }
+            _ => GradientSpread::Pad,
+        };
+        if spread != GradientSpread::Pad {
+            subs.truncate(subs.len() - 2);
+        }
+
         let mut stops = vec![];
         enum Stop {
             Empty,
@@ -511,7 +669,19 @@ enum Stop {
                         break;
                     }
                     Stop::Color(col) => {
-                        stops.push((col, e.maybe_convert_to(Type::Float32, &n, ctx.diag)))
+                        // The stops of a conic gradient can also be angles, which are a fraction of a turn
+                        let position = if matches!(grad_kind, GradKind::Conic { .. })
+                            && e.ty() == Type::Angle
+                        {
+                            Expression::BinaryExpression {
+                                lhs: Box::new(e),
+                                rhs: Box::new(Expression::NumberLiteral(360., Unit::Deg)),
+                                op: '/',
+                            }
+                        } else {
+                            e.maybe_convert_to(Type::Float32, &n, ctx.diag)
+                        };
+                        stops.push((col, position))
                     }
                 }
             }
@@ -569,8 +739,9 @@ enum Stop {
         }
 
         match grad_kind {
-            GradKind::Linear { angle } => Expression::LinearGradient { angle, stops },
-            GradKind::Radial => Expression::RadialGradient { stops },
+            GradKind::Linear { angle } => Expression::LinearGradient { angle, stops, spread },
+            GradKind::Radial => Expression::RadialGradient { stops, spread },
+            GradKind::Conic { from_angle } => Expression::ConicGradient { from_angle, stops },
         }
     }
 
@@ -895,6 +1066,35 @@ fn from_function_call_node(
                 .unwrap_or_else(|| Self::from_expression_node(n, ctx))
         });
 
+        let named_arguments = node.NamedArgument().collect::<Vec<_>>();
+        if !matches!(
+            function,
+            Expression::CallbackReference(..) | Expression::FunctionReference(..)
+        ) {
+            for named in &named_arguments {
+                ctx.diag.push_error(
+                    "Named arguments can only be used to call callbacks and functions".into(),
+                    named,
+                );
+            }
+        }
+
+        if let Expression::MemberFunction { member, .. } = &function {
+            let kind = match **member {
+                Expression::BuiltinMacroReference(BuiltinMacroFunction::ArrayFilter, _) => {
+                    Some(ModelAdapterKind::Filter)
+                }
+                Expression::BuiltinMacroReference(BuiltinMacroFunction::ArraySortBy, _) => {
+                    Some(ModelAdapterKind::SortBy)
+                }
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                let Expression::MemberFunction { base, .. } = function else { unreachable!() };
+                return Self::from_model_adapter_call(node, kind, *base, sub_expr.collect(), ctx);
+            }
+        }
+
         let sub_expr = sub_expr.map(|n| {
             (Self::from_expression_node(n.clone(), ctx), Some(NodeOrToken::from((*n).clone())))
         });
@@ -923,6 +1123,13 @@ fn from_function_call_node(
             _ => Box::new(function),
         };
         arguments.extend(sub_expr);
+        if let Expression::CallbackReference(nr, _) | Expression::FunctionReference(nr, _) =
+            &*function
+        {
+            if !named_arguments.is_empty() {
+                arguments = Self::place_named_arguments(&node, nr, arguments, named_arguments, ctx);
+            }
+        }
 
         let arguments = match function.ty() {
             Type::Function { args, .. } | Type::Callback { args, .. } => {
@@ -961,6 +1168,129 @@ fn from_function_call_node(
         }
     }
 
+    /// Puts the named arguments of a call of `callee`, such as `foo(a, c: 3, b: 2)`, after
+    /// the positional ones, in the order of the declaration of the arguments
+    fn place_named_arguments(
+        call: &syntax_nodes::FunctionCallExpression,
+        callee: &NamedReference,
+        arguments: Vec<(Expression, Option<NodeOrToken>)>,
+        named_arguments: Vec<syntax_nodes::NamedArgument>,
+        ctx: &mut LookupCtx,
+    ) -> Vec<(Expression, Option<NodeOrToken>)> {
+        let names = declared_argument_names(callee);
+        let mut placed = arguments.into_iter().map(Some).collect::<Vec<_>>();
+        if placed.len() < names.len() {
+            placed.resize_with(names.len(), || None);
+        }
+        for named in named_arguments {
+            let Some(name) = identifier_text(&named) else { continue };
+            let value = named.Expression();
+            let value = (
+                Self::from_expression_node(value.clone(), ctx),
+                Some(NodeOrToken::from((*value).clone())),
+            );
+            match names.iter().position(|n| n.as_deref() == Some(name.as_str())) {
+                None => ctx.diag.push_error(
+                    format!("'{}' has no argument named '{name}'", callee.name()),
+                    &named,
+                ),
+                Some(index) if placed[index].is_some() => ctx.diag.push_error(
+                    format!("The argument '{name}' is provided more than once"),
+                    &named,
+                ),
+                Some(index) => placed[index] = Some(value),
+            }
+        }
+        let mut arguments = Vec::with_capacity(placed.len());
+        for (index, argument) in placed.into_iter().enumerate() {
+            arguments.push(argument.unwrap_or_else(|| {
+                let name = names[index]
+                    .as_ref()
+                    .map_or_else(|| format!("{}", index + 1), |name| format!("'{name}'"));
+                ctx.diag.push_error(
+                    format!("Missing argument {name} in the call of '{}'", callee.name()),
+                    call,
+                );
+                (Expression::Invalid, None)
+            }));
+        }
+        arguments
+    }
+
+    /// Resolve `model.filter(item => condition)` and `model.sort-by(item => key)`
+    fn from_model_adapter_call(
+        node: syntax_nodes::FunctionCallExpression,
+        kind: ModelAdapterKind,
+        model: Expression,
+        arguments: Vec<syntax_nodes::Expression>,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let name = match kind {
+            ModelAdapterKind::Filter => "filter",
+            ModelAdapterKind::SortBy => "sort-by",
+        };
+        let lambda = match arguments.as_slice() {
+            [argument] => argument.Lambda(),
+            _ => None,
+        };
+        let Some(lambda) = lambda else {
+            ctx.diag.push_error(
+                format!("{name}() expects one argument of the form 'item => expression'"),
+                &node,
+            );
+            return Expression::Invalid;
+        };
+        let element_ty = match model.ty() {
+            Type::Array(element_ty) => *element_ty,
+            _ => {
+                debug_assert!(ctx.diag.has_errors());
+                return Expression::Invalid;
+            }
+        };
+        let variable = identifier_text(&lambda.DeclaredIdentifier()).unwrap_or_default();
+
+        ctx.local_variables.push((variable.clone(), element_ty));
+        let expression = Self::from_expression_node(lambda.Expression(), ctx);
+        ctx.local_variables.pop();
+
+        let mut expression = match kind {
+            ModelAdapterKind::Filter => {
+                expression.maybe_convert_to(Type::Bool, &lambda.Expression(), ctx.diag)
+            }
+            ModelAdapterKind::SortBy => {
+                let ty = expression.ty();
+                if !matches!(ty, Type::String | Type::Bool | Type::Invalid)
+                    && ty.as_unit_product().is_none()
+                {
+                    ctx.diag.push_error(
+                        format!(
+                            "Cannot sort by a value of type {ty}. The key must be a number, a string or a bool"
+                        ),
+                        &lambda.Expression(),
+                    );
+                }
+                expression
+            }
+        };
+
+        let mut captures = vec![];
+        hoist_model_adapter_captures(
+            &mut expression,
+            &variable,
+            &mut captures,
+            &lambda.Expression(),
+            ctx.diag,
+        );
+
+        Expression::ModelAdapter {
+            kind,
+            model: Box::new(model),
+            captures,
+            variable,
+            expression: Box::new(expression),
+        }
+    }
+
     fn from_member_access_node(
         node: syntax_nodes::MemberAccess,
         ctx: &mut LookupCtx,
@@ -1167,6 +1497,102 @@ fn from_conditional_expression_node(
         }
     }
 
+    fn from_match_expression_node(
+        node: syntax_nodes::MatchExpression,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let matched = Self::from_expression_node(node.Expression(), ctx);
+        let ty = matched.ty();
+        let arms = node
+            .MatchArm()
+            .map(|arm| {
+                let (pattern_n, result_n) = arm.Expression();
+                // Look the pattern up like a value of the matched type, so that enum values
+                // don't need the name of the enum
+                let old_property_type = std::mem::replace(&mut ctx.property_type, ty.clone());
+                let pattern = Self::from_expression_node(pattern_n.clone(), ctx);
+                ctx.property_type = old_property_type;
+                let pattern = pattern.maybe_convert_to(ty.clone(), &pattern_n, ctx.diag);
+                (pattern, Self::from_expression_node(result_n.clone(), ctx), result_n)
+            })
+            .collect::<Vec<_>>();
+        let else_arm = node.MatchElse().map(|else_arm| {
+            let result_n = else_arm.Expression();
+            (Self::from_expression_node(result_n.clone(), ctx), result_n)
+        });
+
+        if else_arm.is_none() {
+            let missing = match &ty {
+                Type::Enumeration(enumeration) => enumeration
+                    .values
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| {
+                        !arms.iter().any(|(pattern, ..)| {
+                            matches!(pattern, Expression::EnumerationValue(v) if v.value == *index)
+                        })
+                    })
+                    .map(|(_, value)| value.as_str())
+                    .collect::<Vec<_>>(),
+                Type::Invalid => Vec::new(),
+                _ => {
+                    ctx.diag.push_error(
+                        "The match expression needs an 'else =>' arm, unless it handles every value of an enum".into(),
+                        &node,
+                    );
+                    return Expression::Invalid;
+                }
+            };
+            if !missing.is_empty() {
+                ctx.diag.push_error(
+                    format!(
+                        "The match expression doesn't handle {}. Add an arm for them or an 'else =>' arm",
+                        missing.join(", ")
+                    ),
+                    &node,
+                );
+                return Expression::Invalid;
+            }
+        }
+
+        let result_ty = Self::common_target_type_for_type_list(
+            arms.iter()
+                .map(|(_, result, _)| result.ty())
+                .chain(else_arm.iter().map(|(e, _)| e.ty())),
+        );
+        let mut arms = arms
+            .into_iter()
+            .map(|(pattern, result, result_n)| {
+                (pattern, result.maybe_convert_to(result_ty.clone(), &result_n, ctx.diag))
+            })
+            .collect::<Vec<_>>();
+        // Without an else arm, the last arm handles the last remaining value
+        let mut result = match else_arm {
+            Some((result, result_n)) => result.maybe_convert_to(result_ty, &result_n, ctx.diag),
+            None => arms.pop().map_or(Expression::Invalid, |(_, result)| result),
+        };
+
+        let name = format!("match_value_{}", node.span().offset);
+        for (pattern, value) in arms.into_iter().rev() {
+            result = Expression::Condition {
+                condition: Box::new(Expression::BinaryExpression {
+                    lhs: Box::new(Expression::ReadLocalVariable {
+                        name: name.clone(),
+                        ty: ty.clone(),
+                    }),
+                    rhs: Box::new(pattern),
+                    op: '=',
+                }),
+                true_expr: Box::new(value),
+                false_expr: Box::new(result),
+            };
+        }
+        Expression::CodeBlock(vec![
+            Expression::StoreLocalVariable { name, value: Box::new(matched) },
+            result,
+        ])
+    }
+
     fn from_index_expression_node(
         node: syntax_nodes::IndexExpression,
         ctx: &mut LookupCtx,
@@ -1204,6 +1630,7 @@ fn from_object_literal_node(
             name: None,
             node: None,
             rust_attributes: None,
+            functions: None,
         };
         Expression::Struct { ty, values }
     }
@@ -1268,12 +1695,14 @@ pub fn common_target_type_for_type_list(types: impl Iterator<Item = Type>) -> Ty
                             name: result_name,
                             node: result_node,
                             rust_attributes,
+                            functions: result_functions,
                         },
                         Type::Struct {
                             fields: elem_fields,
                             name: elem_name,
                             node: elem_node,
                             rust_attributes: derived,
+                            functions: elem_functions,
                         },
                     ) => {
                         for (elem_name, elem_ty) in elem_fields.into_iter() {
@@ -1296,6 +1725,7 @@ pub fn common_target_type_for_type_list(types: impl Iterator<Item = Type>) -> Ty
                             fields: result_fields,
                             node: result_node.or(elem_node),
                             rust_attributes: rust_attributes.or(derived),
+                            functions: result_functions.or(elem_functions),
                         }
                     }
                     (Type::Array(lhs), Type::Array(rhs)) => Type::Array(if *lhs == Type::Void {
@@ -1326,6 +1756,65 @@ pub fn common_target_type_for_type_list(types: impl Iterator<Item = Type>) -> Ty
     }
 }
 
+/// Replace the sub-expressions of the expression of a [`Expression::ModelAdapter`] that don't
+/// depend on the row variable with local variables, which are evaluated once when creating the
+/// model. So the expression that is evaluated for each row only needs the row itself.
+fn hoist_model_adapter_captures(
+    expr: &mut Expression,
+    variable: &str,
+    captures: &mut Vec<(String, Expression)>,
+    node: &dyn Spanned,
+    diag: &mut BuildDiagnostics,
+) {
+    let mut uses_variable = false;
+    expr.visit_recursive(&mut |e| {
+        uses_variable |= matches!(e, Expression::ReadLocalVariable { name, .. } if name == variable)
+    });
+    if !uses_variable {
+        if !matches!(
+            expr,
+            Expression::Invalid
+                | Expression::StringLiteral(_)
+                | Expression::NumberLiteral(..)
+                | Expression::BoolLiteral(_)
+                | Expression::EnumerationValue(_)
+                | Expression::BuiltinFunctionReference(..)
+                | Expression::ReadLocalVariable { .. }
+        ) {
+            let name = format!("{variable}-capture-{}", captures.len());
+            let ty = expr.ty();
+            let capture =
+                std::mem::replace(expr, Expression::ReadLocalVariable { name: name.clone(), ty });
+            captures.push((name, capture));
+        }
+        return;
+    }
+    match expr {
+        Expression::FunctionCall { function, .. }
+            if !(matches!(**function, Expression::BuiltinFunctionReference(..))
+                && function.is_constant()) =>
+        {
+            diag.push_error(
+                format!(
+                    "The expression of filter() or sort-by() cannot call functions or callbacks with '{variable}'"
+                ),
+                node,
+            );
+            return;
+        }
+        Expression::ModelAdapter { .. } => {
+            diag.push_error(
+                "filter() and sort-by() cannot be nested in the expression of filter() or sort-by()"
+                    .into(),
+                node,
+            );
+            return;
+        }
+        _ => {}
+    }
+    expr.visit_mut(|e| hoist_model_adapter_captures(e, variable, captures, node, diag));
+}
+
 fn continue_lookup_within_element(
     elem: &ElementRc,
     it: &mut impl Iterator<Item = crate::parser::SyntaxToken>,
@@ -1522,6 +2011,7 @@ fn resolve_two_way_bindings(
 ) {
     for component in doc.inner_components.iter() {
         let scope = ComponentScope(vec![]);
+        let type_register = component.type_register(type_register);
 
         recurse_elem(&component.root_element, &scope, &mut |elem, scope| {
             let mut new_scope = scope.clone();
@@ -1545,6 +2035,7 @@ fn resolve_two_way_bindings(
                             type_register,
                             type_loader: None,
                             current_token: Some(node.clone().into()),
+                            local_variables: vec![],
                         };
 
                         binding.expression = Expression::Invalid;
@@ -1702,3 +2193,41 @@ pub fn resolve_two_way_binding(
         }
     }
 }
+
+/// Returns the locales and the paths of the files next to the image that have a locale before the
+/// extension, such as `logo.de.png` or `logo.pt_BR.png` for `logo.png`. They are sorted with the
+/// locales without region first.
+fn locale_variants(path: &str) -> Vec<(String, String)> {
+    if path.starts_with("builtin:") {
+        return Vec::new();
+    }
+    let path = std::path::Path::new(path);
+    let (Some(directory), Some(stem)) = (path.parent(), path.file_stem().and_then(|s| s.to_str()))
+    else {
+        return Vec::new();
+    };
+    let extension = path.extension().and_then(|e| e.to_str());
+    let Ok(entries) = std::fs::read_dir(directory) else { return Vec::new() };
+    let is_locale = |locale: &str| {
+        let mut parts = locale.split(['_', '-']);
+        let language = parts.next().unwrap_or_default();
+        (2..=3).contains(&language.len())
+            && language.chars().all(|c| c.is_ascii_alphabetic())
+            && parts.all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric()))
+    };
+    let mut variants = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let file_name = entry.file_name().into_string().ok()?;
+            let rest = file_name.strip_prefix(stem)?.strip_prefix('.')?;
+            let locale = match extension {
+                Some(extension) => rest.strip_suffix(extension)?.strip_suffix('.')?,
+                None => rest,
+            };
+            is_locale(locale)
+                .then(|| (locale.to_string(), entry.path().to_string_lossy().to_string()))
+        })
+        .collect::<Vec<_>>();
+    variants.sort_by(|a, b| (a.0.len(), &a.0).cmp(&(b.0.len(), &b.0)));
+    variants
+}
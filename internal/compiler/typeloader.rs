@@ -289,6 +289,14 @@ pub(crate) fn create_component(
                     .as_ref()
                     .map(|(e, s, n)| (self.use_element(e), *s, n.clone())),
             );
+            let named_child_insertion_points = RefCell::new(
+                component
+                    .named_child_insertion_points
+                    .borrow()
+                    .iter()
+                    .map(|(name, (e, s, n))| (name.clone(), (self.use_element(e), *s, n.clone())))
+                    .collect(),
+            );
 
             let popup_windows = RefCell::new(
                 component
@@ -305,10 +313,33 @@ pub(crate) fn create_component(
                 self.snapshot_layout_constraints(&component.root_constraints.borrow()),
             );
 
+            let generic_parameters =
+                component.generic_parameters.as_ref().map(|g| object_tree::GenericParameters {
+                    names: g.names.clone(),
+                    type_register: self.snapshot_type_register(&g.type_register),
+                    specializations: RefCell::new(
+                        g.specializations
+                            .borrow()
+                            .iter()
+                            .filter_map(|(types, c)| {
+                                let c = self.component_map.get(&by_address::ByAddress(c.clone()));
+                                Some((types.clone(), c?.upgrade()?))
+                            })
+                            .collect(),
+                    ),
+                });
+            // The register of a specialization is only used while its component is being built
+            let specialization =
+                component.specialization.as_ref().map(|s| object_tree::Specialization {
+                    type_register: s.type_register.clone(),
+                    in_document: std::cell::Cell::new(s.in_document.get()),
+                });
+
             object_tree::Component {
                 node: component.node.clone(),
                 id: component.id.clone(),
                 child_insertion_point,
+                named_child_insertion_points,
                 exported_global_names: RefCell::new(
                     component.exported_global_names.borrow().clone(),
                 ),
@@ -321,6 +352,8 @@ pub(crate) fn create_component(
                 private_properties: RefCell::new(component.private_properties.borrow().clone()),
                 root_constraints,
                 root_element,
+                generic_parameters,
+                specialization,
             }
         });
         self.keep_alive.push((component.clone(), result.clone()));
@@ -437,6 +470,18 @@ fn snapshot_element(
                         (nr, expr, spc.clone())
                     })
                     .collect(),
+                entered: s.entered.as_ref().map(|e| self.snapshot_expression(e)),
+                exited: s.exited.as_ref().map(|e| self.snapshot_expression(e)),
+                event_transitions: s
+                    .event_transitions
+                    .iter()
+                    .map(|t| object_tree::StateEventTransition {
+                        event: t.event.clone(),
+                        guard: t.guard.as_ref().map(|e| self.snapshot_expression(e)),
+                        target: t.target.clone(),
+                        node: t.node.clone(),
+                    })
+                    .collect(),
             })
             .collect();
         target_element.repeated =
@@ -445,12 +490,18 @@ fn snapshot_element(
                 model_data_id: r.model_data_id.clone(),
                 index_id: r.index_id.clone(),
                 is_conditional_element: r.is_conditional_element,
+                is_retained: r.is_retained,
                 is_listview: r.is_listview.as_ref().map(|lv| object_tree::ListViewInfo {
                     viewport_y: lv.viewport_y.snapshot(self),
                     viewport_height: lv.viewport_height.snapshot(self),
                     viewport_width: lv.viewport_width.snapshot(self),
                     listview_height: lv.listview_height.snapshot(self),
                     listview_width: lv.listview_width.snapshot(self),
+                    sections: lv.sections.as_ref().map(|s| object_tree::ListViewSectionsInfo {
+                        section_rows: s.section_rows.snapshot(self),
+                        current_section: s.current_section.snapshot(self),
+                        next_section_y: s.next_section_y.snapshot(self),
+                    }),
                 }),
             });
 
@@ -484,11 +535,22 @@ fn snapshot_element(
         target_element.property_analysis = RefCell::new(elem.property_analysis.borrow().clone());
 
         target_element.change_callbacks = elem.change_callbacks.clone();
+        target_element.event_handlers = elem.event_handlers.clone();
+        target_element.assertions = elem
+            .assertions
+            .iter()
+            .map(|a| object_tree::Assertion {
+                condition: self.snapshot_expression(&a.condition),
+                message: a.message.clone(),
+                node: a.node.clone(),
+            })
+            .collect();
         target_element.child_of_layout = elem.child_of_layout;
         target_element.default_fill_parent = elem.default_fill_parent;
         target_element.has_popup_child = elem.has_popup_child;
         target_element.inline_depth = elem.inline_depth;
         target_element.is_component_placeholder = elem.is_component_placeholder;
+        target_element.children_slot = elem.children_slot.clone();
         target_element.is_flickable_viewport = elem.is_flickable_viewport;
         target_element.is_legacy_syntax = elem.is_legacy_syntax;
         target_element.item_index = elem.item_index.clone();
@@ -545,6 +607,16 @@ pub(crate) fn snapshot_element_type(
     ) -> langtype::ElementType {
         // Components need to get adapted, the rest is fine I think...
         match element_type {
+            // Generic components aren't part of the inner components of the documents, so they
+            // are created when they are first used
+            langtype::ElementType::Component(component)
+                if component.generic_parameters.is_some()
+                    && !self
+                        .component_map
+                        .contains_key(&by_address::ByAddress(component.clone())) =>
+            {
+                langtype::ElementType::Component(self.create_component(component))
+            }
             langtype::ElementType::Component(component) => {
                 // Some components that will get compiled out later...
                 langtype::ElementType::Component(
@@ -587,7 +659,11 @@ fn snapshot_popup_window(
                 .expect("Looking at a known component"),
             x: popup_window.x.snapshot(self),
             y: popup_window.y.snapshot(self),
-            close_on_click: popup_window.close_on_click,
+            close_policy: popup_window.close_policy.clone(),
+            close_on_escape: popup_window.close_on_escape,
+            anchor_edge: popup_window.anchor_edge.clone(),
+            anchor_offset: popup_window.anchor_offset,
+            anchor_flip: popup_window.anchor_flip,
             parent_element: self.use_element(&popup_window.parent_element),
         }
     }
@@ -755,14 +831,23 @@ fn snapshot_expression(
                     expression_tree::Path::Commands(Box::new(self.snapshot_expression(ex)))
                 }
             }),
-            Expression::LinearGradient { angle, stops } => Expression::LinearGradient {
+            Expression::LinearGradient { angle, stops, spread } => Expression::LinearGradient {
                 angle: Box::new(self.snapshot_expression(angle)),
                 stops: stops
                     .iter()
                     .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
                     .collect(),
+                spread: *spread,
             },
-            Expression::RadialGradient { stops } => Expression::RadialGradient {
+            Expression::RadialGradient { stops, spread } => Expression::RadialGradient {
+                stops: stops
+                    .iter()
+                    .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
+                    .collect(),
+                spread: *spread,
+            },
+            Expression::ConicGradient { from_angle, stops } => Expression::ConicGradient {
+                from_angle: Box::new(self.snapshot_expression(from_angle)),
                 stops: stops
                     .iter()
                     .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
@@ -786,6 +871,18 @@ fn snapshot_expression(
                 rhs: Box::new(self.snapshot_expression(rhs)),
                 op: *op,
             },
+            Expression::ModelAdapter { kind, model, captures, variable, expression } => {
+                Expression::ModelAdapter {
+                    kind: *kind,
+                    model: Box::new(self.snapshot_expression(model)),
+                    captures: captures
+                        .iter()
+                        .map(|(name, e)| (name.clone(), self.snapshot_expression(e)))
+                        .collect(),
+                    variable: variable.clone(),
+                    expression: Box::new(self.snapshot_expression(expression)),
+                }
+            }
             _ => expr.clone(),
         }
     }
@@ -819,6 +916,20 @@ pub fn new(
             style = get_native_style(&mut diag.all_loaded_files);
         }
 
+        global_type_registry.borrow_mut().cfg_flags = compiler_config
+            .cfg_flags
+            .iter()
+            .map(|flag| parser::normalize_identifier(flag))
+            .collect();
+
+        for manifest in &compiler_config.custom_item_manifests {
+            crate::load_builtins::load_custom_items(
+                manifest,
+                &mut global_type_registry.borrow_mut(),
+                diag,
+            );
+        }
+
         let myself = Self {
             global_type_registry,
             compiler_config,
@@ -996,11 +1107,13 @@ pub fn resolve_import_path(
         import_token: Option<&NodeOrToken>,
         maybe_relative_path_or_url: &str,
     ) -> Option<(PathBuf, Option<&'static [u8]>)> {
-        if let Some(maybe_library_import) = maybe_relative_path_or_url.strip_prefix('@') {
+        let referencing_file_or_url =
+            import_token.and_then(|tok| tok.source_file().map(|s| s.path()));
+        let resolved = if let Some(maybe_library_import) =
+            maybe_relative_path_or_url.strip_prefix('@')
+        {
             self.find_file_in_library_path(maybe_library_import)
         } else {
-            let referencing_file_or_url =
-                import_token.and_then(|tok| tok.source_file().map(|s| s.path()));
             self.find_file_in_include_path(referencing_file_or_url, maybe_relative_path_or_url)
                 .or_else(|| {
                     referencing_file_or_url
@@ -1013,9 +1126,98 @@ pub fn resolve_import_path(
                         .filter(|p| p.exists())
                         .map(|p| (p, None))
                 })
+                .or_else(|| {
+                    // The files of a variant directory may refer to the ones of the base directory
+                    let base_location = self.variant_base_location(referencing_file_or_url?)?;
+                    self.find_file_in_include_path(Some(&base_location), maybe_relative_path_or_url)
+                })
+        };
+        resolved.map(|(path, builtin)| match builtin {
+            None => {
+                (self.find_variant_override(&path, referencing_file_or_url).unwrap_or(path), None)
+            }
+            Some(_) => (path, builtin),
+        })
+    }
+
+    /// Returns the file of a variant directory that replaces the given file, if any.
+    ///
+    /// A variant file that refers to the file it replaces gets the file of the next lower layer.
+    fn find_variant_override(
+        &self,
+        path: &Path,
+        referencing_file: Option<&Path>,
+    ) -> Option<PathBuf> {
+        let candidates = self
+            .compiler_config
+            .variant_paths
+            .iter()
+            .rev()
+            .filter_map(|(base, variant)| {
+                let relative = path.strip_prefix(absolute_path(base)).ok()?;
+                fileaccess::load_file(&absolute_path(variant).join(relative))
+                    .map(|virtual_file| virtual_file.canon_path)
+            })
+            .collect::<Vec<_>>();
+        match candidates.iter().position(|candidate| Some(candidate.as_path()) == referencing_file)
+        {
+            Some(position) => candidates.into_iter().nth(position + 1),
+            None => candidates.into_iter().next(),
         }
     }
 
+    /// Returns where the given file of a variant directory would be in the base directory.
+    fn variant_base_location(&self, path: &Path) -> Option<PathBuf> {
+        self.compiler_config.variant_paths.iter().find_map(|(base, variant)| {
+            let relative = path.strip_prefix(absolute_path(variant)).ok()?;
+            Some(absolute_path(base).join(relative))
+        })
+    }
+
+    /// Returns the file that the given file of a variant directory replaces, if any.
+    fn variant_base_file(&self, path: &Path) -> Option<PathBuf> {
+        let base = fileaccess::load_file(&self.variant_base_location(path)?)?.canon_path;
+        Some(self.find_variant_override(&base, Some(path)).unwrap_or(base))
+    }
+
+    /// Makes the exports of the file that a variant file replaces available from the variant
+    /// file, except for the ones the variant file defines itself.
+    async fn add_variant_base_exports<'a>(
+        state: &'a RefCell<BorrowedTypeLoader<'a>>,
+        variant_file: &Path,
+        import_stack: HashSet<PathBuf>,
+    ) {
+        let Some(base_file) = state.borrow().tl.variant_base_file(variant_file) else { return };
+        let import_token = state
+            .borrow()
+            .tl
+            .all_documents
+            .docs
+            .get(variant_file)
+            .and_then(|doc| doc.node.clone())
+            .map(|node| NodeOrToken::from((*node).clone()));
+        let Some(base_file) = Box::pin(Self::ensure_document_loaded(
+            state,
+            &base_file.to_string_lossy(),
+            import_token,
+            import_stack,
+        ))
+        .await
+        else {
+            return;
+        };
+
+        let mut state = state.borrow_mut();
+        let state = &mut *state;
+        let base_exports = state.tl.all_documents.docs[&base_file].exports.to_vec();
+        let Some(doc) = state.tl.all_documents.docs.get_mut(variant_file) else { return };
+        let inherited_exports = base_exports
+            .into_iter()
+            .filter(|(name, _)| doc.exports.find(name).is_none())
+            .collect::<Vec<_>>();
+        doc.exports.add_reexports(inherited_exports, state.diag);
+    }
+
     async fn ensure_document_loaded<'a: 'b, 'b>(
         state: &'a RefCell<BorrowedTypeLoader<'a>>,
         file_to_import: &'b str,
@@ -1119,6 +1321,10 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
                 )
                 .await;
 
+                if builtin.is_none() {
+                    Self::add_variant_base_exports(state, &path_canon, import_stack.clone()).await;
+                }
+
                 true
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
@@ -1330,6 +1536,49 @@ fn register_imported_types(
         }
     }
 
+    /// Returns the std-widgets.slint file of the style selected by an import with `style "..."`,
+    /// so that a library keeps its style regardless of the style of the application.
+    fn resolve_import_style(
+        &self,
+        path_to_import: &str,
+        import_style: &syntax_nodes::ImportStyle,
+        diag: &mut BuildDiagnostics,
+    ) -> Option<String> {
+        let style_token = import_style.child_token(SyntaxKind::StringLiteral)?;
+        let style = crate::literals::unescape_string(style_token.text())?;
+        if path_to_import != "std-widgets.slint" {
+            diag.push_error(
+                "Only imports of \"std-widgets.slint\" can select a style".into(),
+                import_style,
+            );
+            return None;
+        }
+        let style = match style.as_str() {
+            "native" => get_native_style(&mut diag.all_loaded_files),
+            "inherit" => self.style.clone(),
+            _ => style.into(),
+        };
+        if fileaccess::styles().contains(&style.as_str()) {
+            return Some(format!("builtin:/{style}/std-widgets.slint"));
+        }
+        match self.find_file_in_include_path(None, &format!("{style}/std-widgets.slint")) {
+            Some((path, _)) => Some(path.to_string_lossy().into()),
+            None => {
+                let mut known_styles = fileaccess::styles();
+                known_styles.extend(["native", "inherit"]);
+                diag.push_error(
+                    format!(
+                        "Style {} in not known. Use one of the builtin styles [{}] or make sure your custom style is found in the include directories",
+                        &style,
+                        known_styles.join(", ")
+                    ),
+                    &style_token,
+                );
+                None
+            }
+        }
+    }
+
     /// Lookup a library and filename and try to find the absolute filename based on the library path
     fn find_file_in_library_path(
         &self,
@@ -1419,6 +1668,19 @@ fn collect_dependencies<'a: 'b, 'b>(
                     return None;
                 }
 
+                let import_style = match &type_specifier {
+                    ImportKind::ImportList(import) => import.ImportStyle(),
+                    ImportKind::ModuleReexport(_) => None,
+                };
+                let path_to_import = match import_style {
+                    Some(import_style) => {
+                        let mut state = state.borrow_mut();
+                        let state = &mut *state;
+                        state.tl.resolve_import_style(&path_to_import, &import_style, state.diag)?
+                    }
+                    None => path_to_import,
+                };
+
                 Some(ImportedTypes {
                     import_uri_token: import_uri,
                     import_kind: type_specifier,
@@ -1488,6 +1750,14 @@ fn get_native_style(all_loaded_files: &mut Vec<PathBuf>) -> String {
     i_slint_common::get_native_style(false, &std::env::var("TARGET").unwrap_or_default()).into()
 }
 
+/// Makes a path of the configuration absolute, like the paths of the loaded files.
+fn absolute_path(path: &Path) -> PathBuf {
+    crate::pathutils::clean_path(
+        &crate::pathutils::join(&std::env::current_dir().ok().unwrap_or_default(), path)
+            .unwrap_or_else(|| path.to_path_buf()),
+    )
+}
+
 /// return the base directory from which imports are loaded
 ///
 /// For a .slint file, this is the parent directory.
@@ -1774,6 +2044,54 @@ fn test_unknown_style() {
     assert!(diags[0].starts_with("Style FooBar in not known. Use one of the builtin styles ["));
 }
 
+#[test]
+fn test_import_style() {
+    let mut compiler_config =
+        CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        r#"
+import { Button } from "std-widgets.slint" style "material";
+import { Button as AppButton } from "std-widgets.slint" style "inherit";
+import { Palette } from "std-widgets.slint";
+import { Slider } from "std-widgets.slint" style "unknown";
+import { Foo } from "foo.slint" style "material";
+"#
+        .into(),
+        Some(std::path::Path::new("HELLO")),
+        &mut test_diags,
+    );
+
+    let doc_node: syntax_nodes::Document = doc_node.into();
+    let global_registry = TypeRegister::builtin();
+    let registry = Rc::new(RefCell::new(TypeRegister::new(&global_registry)));
+    let mut build_diagnostics = BuildDiagnostics::default();
+    let mut loader = TypeLoader::new(global_registry, compiler_config, &mut build_diagnostics);
+    spin_on::spin_on(loader.load_dependencies_recursively(
+        &doc_node,
+        &mut build_diagnostics,
+        &registry,
+    ));
+    assert!(!test_diags.has_errors());
+
+    let diags = build_diagnostics.to_string_vec();
+    assert_eq!(diags.len(), 2);
+    assert!(diags[0].starts_with("HELLO:5: Style unknown in not known."));
+    assert_eq!(diags[1], "HELLO:6: Only imports of \"std-widgets.slint\" can select a style");
+
+    let component_file = |name: &str| {
+        let ty = registry.borrow().lookup_element(name).unwrap();
+        let langtype::ElementType::Component(c) = ty else { panic!("{name} is not a component") };
+        c.node.source_file().unwrap().path().to_path_buf()
+    };
+    assert!(component_file("Button").starts_with("builtin:/material"));
+    assert!(component_file("AppButton").starts_with("builtin:/fluent"));
+    assert!(loader.all_files().any(|path| path.starts_with("builtin:/material")));
+    assert!(loader.all_files().any(|path| path.starts_with("builtin:/fluent")));
+}
+
 #[test]
 fn test_library_import() {
     let test_source_path: PathBuf =
@@ -1875,6 +2193,62 @@ fn test_library_import_errors() {
     );
 }
 
+#[test]
+fn test_variant_paths() {
+    let test_source_path: PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "tests", "typeloader", "variant"].iter().collect();
+    let base_dir = test_source_path.join("base");
+    let brand_dir = test_source_path.join("brand");
+
+    let mut compiler_config =
+        CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.include_paths = vec![base_dir.clone()];
+    compiler_config.variant_paths = vec![(base_dir.clone(), brand_dir.clone())];
+    compiler_config.style = Some("fluent".into());
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+
+    let doc_node = crate::parser::parse(
+        r#"
+/* ... */
+import { Screen } from "screen.slint";
+import { Logo, Theme } from "theme.slint";
+"#
+        .into(),
+        Some(std::path::Path::new("HELLO")),
+        &mut test_diags,
+    );
+
+    let doc_node: syntax_nodes::Document = doc_node.into();
+    let global_registry = TypeRegister::builtin();
+    let registry = Rc::new(RefCell::new(TypeRegister::new(&global_registry)));
+    let mut build_diagnostics = BuildDiagnostics::default();
+    let mut loader = TypeLoader::new(global_registry, compiler_config, &mut build_diagnostics);
+    spin_on::spin_on(loader.load_dependencies_recursively(
+        &doc_node,
+        &mut build_diagnostics,
+        &registry,
+    ));
+    assert!(!test_diags.has_errors());
+    assert!(!build_diagnostics.has_errors(), "{:?}", build_diagnostics.to_string_vec());
+
+    let base_theme = loader.get_document(&absolute_path(&base_dir.join("theme.slint"))).unwrap();
+    let brand_theme = loader.get_document(&absolute_path(&brand_dir.join("theme.slint"))).unwrap();
+    let component = |doc: &Document, name: &str| doc.exports.find(name).unwrap().left().unwrap();
+    // The Theme is overridden by the variant, but the Logo is inherited from the base file
+    assert!(!Rc::ptr_eq(&component(base_theme, "Theme"), &component(brand_theme, "Theme")));
+    assert!(Rc::ptr_eq(&component(base_theme, "Logo"), &component(brand_theme, "Logo")));
+    // The variant file imports colors.slint from the base directory
+    assert!(loader.get_document(&absolute_path(&base_dir.join("colors.slint"))).is_some());
+    // The screen of the base directory uses the Theme of the variant
+    let screen = loader.get_document(&absolute_path(&base_dir.join("screen.slint"))).unwrap();
+    match screen.local_registry.lookup_element("Theme") {
+        Ok(langtype::ElementType::Component(theme)) => {
+            assert!(Rc::ptr_eq(&theme, &component(brand_theme, "Theme")))
+        }
+        _ => panic!("Theme not found in screen.slint"),
+    }
+}
+
 #[test]
 fn test_snapshotting() {
     let mut type_loader = TypeLoader::new(
@@ -1909,3 +2283,52 @@ fn test_snapshotting() {
     let root_element = c.root_element.clone();
     assert_eq!(root_element.borrow().base_type.to_string(), "Rectangle");
 }
+
+#[test]
+fn test_custom_items() {
+    let manifest: PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "tests", "typeloader", "custom_items", "manifest.slint"]
+            .iter()
+            .collect();
+
+    let mut compiler_config = CompilerConfiguration::new(crate::generator::OutputFormat::Rust);
+    compiler_config.custom_item_manifests = vec![manifest];
+    compiler_config.style = Some("fluent".into());
+
+    let global_registry = TypeRegister::builtin();
+    let mut build_diagnostics = BuildDiagnostics::default();
+    let mut type_loader =
+        TypeLoader::new(global_registry.clone(), compiler_config, &mut build_diagnostics);
+
+    let diags = build_diagnostics.to_string_vec();
+    let messages = diags.iter().map(|d| d.split_once(": ").unwrap().1).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "The custom item 'Unversioned' must declare the version of the custom item API with '//-custom_item_api:1'",
+            "Unsupported custom item API version '2'. The supported version is 1",
+            "The custom item 'Untyped' must declare the Rust type that implements it with '//-rust_type:path::to::Type'",
+            "The custom item 'Rectangle' has the name of a builtin element",
+        ]
+    );
+
+    let langtype::ElementType::Builtin(gauge) =
+        global_registry.borrow().lookup_element("Gauge").unwrap()
+    else {
+        panic!("Gauge is not a builtin element")
+    };
+    assert_eq!(gauge.native_class.rust_type.as_deref(), Some("crate::items::Gauge"));
+    assert_eq!(gauge.properties["value"].ty, langtype::Type::Float32);
+    assert!(global_registry.borrow().lookup_element("Unversioned").is_err());
+
+    let path = PathBuf::from("/tmp/test.slint");
+    let mut diag = BuildDiagnostics::default();
+    spin_on::spin_on(type_loader.load_file(
+        &path,
+        &path,
+        "export component Foo { Gauge { value: 42; clicked(x) => { debug(x); } } }".to_string(),
+        false,
+        &mut diag,
+    ));
+    assert!(!diag.has_errors());
+}
@@ -100,6 +100,19 @@ fn fill_register(&self, register: &mut TypeRegister) {
     ("rotation-origin-y", Type::LogicalLength),
 ];
 
+pub const RESERVED_TRANSFORM_PROPERTIES: &[(&str, Type)] = &[
+    ("transform-rotation", Type::Angle),
+    ("transform-scale-x", Type::Float32),
+    ("transform-scale-y", Type::Float32),
+    ("transform-translate-x", Type::LogicalLength),
+    ("transform-translate-y", Type::LogicalLength),
+    ("transform-origin-x", Type::LogicalLength),
+    ("transform-origin-y", Type::LogicalLength),
+];
+
+/// The callbacks invoked by the runtime during the life of an element
+const RESERVED_LIFECYCLE_CALLBACKS: &[&str] = &["init", "shown", "hidden", "before-destroy"];
+
 pub fn reserved_accessibility_properties() -> impl Iterator<Item = (&'static str, Type)> {
     [
         //("accessible-role", ...)
@@ -107,6 +120,8 @@ pub fn reserved_accessibility_properties() -> impl Iterator<Item = (&'static str
         ("accessible-checked", Type::Bool),
         ("accessible-delegate-focus", Type::Int32),
         ("accessible-description", Type::String),
+        ("accessible-enabled", Type::Bool),
+        ("accessible-id", Type::String),
         ("accessible-label", Type::String),
         ("accessible-value", Type::String),
         ("accessible-value-maximum", Type::Float32),
@@ -132,6 +147,7 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
         .chain(RESERVED_OTHER_PROPERTIES.iter())
         .chain(RESERVED_DROP_SHADOW_PROPERTIES.iter())
         .chain(RESERVED_ROTATION_PROPERTIES.iter())
+        .chain(RESERVED_TRANSFORM_PROPERTIES.iter())
         .map(|(k, v)| (*k, v.clone(), PropertyVisibility::Input))
         .chain(reserved_accessibility_properties().map(|(k, v)| (k, v, PropertyVisibility::Input)))
         .chain(
@@ -141,6 +157,7 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
         )
         .chain(IntoIterator::into_iter([
             ("absolute-position", logical_point_type(), PropertyVisibility::Output),
+            ("effectively-enabled", Type::Bool, PropertyVisibility::Output),
             ("forward-focus", Type::ElementReference, PropertyVisibility::Constexpr),
             ("focus", BuiltinFunction::SetFocusItem.ty(), PropertyVisibility::Public),
             ("clear-focus", BuiltinFunction::ClearFocusItem.ty(), PropertyVisibility::Public),
@@ -155,11 +172,18 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
                 PropertyVisibility::Constexpr,
             ),
         ]))
-        .chain(std::iter::once((
-            "init",
-            Type::Callback { return_type: None, args: vec![] },
-            PropertyVisibility::Private,
-        )))
+        .chain(RESERVED_LIFECYCLE_CALLBACKS.iter().map(|name| {
+            (*name, Type::Callback { return_type: None, args: vec![] }, PropertyVisibility::Private)
+        }))
+}
+
+/// Returns true if the property is the reserved `shown` or `hidden` callback. These can be shadowed
+/// by a property or callback of the same name declared in a component, since they used to be free
+/// to use.
+pub fn is_shadowable_reserved_callback(lookup: &PropertyLookupResult) -> bool {
+    matches!(lookup.resolved_name.as_ref(), "shown" | "hidden")
+        && lookup.property_visibility == PropertyVisibility::Private
+        && matches!(lookup.property_type, Type::Callback { .. })
 }
 
 /// lookup reserved property injected in every item
@@ -219,7 +243,7 @@ pub fn reserved_member_function(name: &str) -> Option<BuiltinFunction> {
     None
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct TypeRegister {
     /// The set of property types.
     types: HashMap<String, Type>,
@@ -234,6 +258,8 @@ pub struct TypeRegister {
     parent_registry: Option<Rc<RefCell<TypeRegister>>>,
     /// If the lookup function should return types that are marked as internal
     pub(crate) expose_internal_types: bool,
+    /// The flags that are set for `@cfg(...)`. They're only set in the global register.
+    pub(crate) cfg_flags: HashSet<String>,
 }
 
 impl TypeRegister {
@@ -255,6 +281,7 @@ pub(crate) fn snapshot(&self, snapshotter: &mut typeloader::Snapshotter) -> Self
                 .as_ref()
                 .map(|tr| snapshotter.snapshot_type_register(tr)),
             expose_internal_types: self.expose_internal_types,
+            cfg_flags: self.cfg_flags.clone(),
         }
     }
 
@@ -305,6 +332,7 @@ macro_rules! map_type {
             ($pub_type:ident, SharedString) => { Type::String };
             ($pub_type:ident, Coord) => { Type::LogicalLength };
             ($pub_type:ident, KeyboardModifiers) => { $pub_type.clone() };
+            ($pub_type:ident, LogicalPosition) => { logical_point_type() };
             ($pub_type:ident, $_:ident) => {
                 BUILTIN_ENUMS.with(|e| Type::Enumeration(e.$pub_type.clone()))
             };
@@ -333,7 +361,7 @@ struct $Name:ident {
                     ]),
                     name: Some(format!("{}", $inner_name)),
                     node: None,
-                    rust_attributes: None,
+                    rust_attributes: None, functions: None,
                 };
                 register.insert_type_with_name(maybe_clone!($Name, $Name), stringify!($Name).to_string());
             )* };
@@ -364,8 +392,17 @@ struct $Name:ident {
                 );
                 popup.member_functions.insert("close".into(), BuiltinFunction::ClosePopupWindow);
 
-                popup.properties.get_mut("close-on-click").unwrap().property_visibility =
-                    PropertyVisibility::Constexpr;
+                for prop in [
+                    "close-on-click",
+                    "close-policy",
+                    "close-on-escape",
+                    "anchor-edge",
+                    "anchor-offset",
+                    "anchor-flip",
+                ] {
+                    popup.properties.get_mut(prop).unwrap().property_visibility =
+                        PropertyVisibility::Constexpr;
+                }
             }
 
             _ => unreachable!(),
@@ -400,6 +437,13 @@ struct $Name:ident {
                 text_input
                     .member_functions
                     .insert("set-selection-offsets".into(), BuiltinFunction::SetSelectionOffsets);
+                text_input.properties.insert(
+                    "move-selection-handle".into(),
+                    BuiltinPropertyInfo::new(BuiltinFunction::MoveSelectionHandle.ty()),
+                );
+                text_input
+                    .member_functions
+                    .insert("move-selection-handle".into(), BuiltinFunction::MoveSelectionHandle);
                 text_input
                     .reserved_properties
                     .insert("font-metrics".into(), font_metrics_prop.clone());
@@ -412,6 +456,25 @@ struct $Name:ident {
             ElementType::Builtin(ref mut b) => {
                 let text = Rc::get_mut(b).unwrap();
                 text.reserved_properties.insert("font-metrics".into(), font_metrics_prop);
+                // Handled by the lower_selectable_text pass
+                text.reserved_properties.insert(
+                    "selectable".into(),
+                    BuiltinPropertyInfo {
+                        ty: Type::Bool,
+                        property_visibility: PropertyVisibility::Input,
+                        default_value: BuiltinPropertyDefault::None,
+                    },
+                );
+                for color_prop in ["selection-foreground-color", "selection-background-color"] {
+                    text.reserved_properties.insert(
+                        color_prop.into(),
+                        BuiltinPropertyInfo {
+                            ty: Type::Color,
+                            property_visibility: PropertyVisibility::Input,
+                            default_value: BuiltinPropertyDefault::None,
+                        },
+                    );
+                }
             }
 
             _ => unreachable!(),
@@ -441,6 +504,7 @@ pub fn builtin() -> Rc<RefCell<Self>> {
         let mut register = Self::builtin_internal();
 
         register.elements.remove("ComponentContainer");
+        register.elements.remove("Shader");
         register.types.remove("component-factory");
 
         Rc::new(RefCell::new(register))
@@ -454,6 +518,12 @@ pub fn new(parent: &Rc<RefCell<TypeRegister>>) -> Self {
         }
     }
 
+    /// Returns true if the `flag` of `@cfg(flag)` is set in the compiler configuration
+    pub fn is_cfg_flag_set(&self, flag: &str) -> bool {
+        self.cfg_flags.contains(flag)
+            || self.parent_registry.as_ref().is_some_and(|r| r.borrow().is_cfg_flag_set(flag))
+    }
+
     pub fn lookup(&self, name: &str) -> Type {
         self.types
             .get(name)
@@ -528,6 +598,23 @@ pub fn add_builtin(&mut self, builtin: Rc<BuiltinElement>) {
     }
 
     pub fn property_animation_type_for_property(&self, property_type: Type) -> ElementType {
+        if let Type::Struct { fields, name, node, .. } = &property_type {
+            // The structs are interpolated field by field. The generated code can only do that
+            // for the structs declared in .slint, and for the anonymous structs, which are tuples
+            // of up to 8 fields in Rust.
+            let supported = if name.is_some() { node.is_some() } else { fields.len() <= 8 };
+            if !supported || fields.is_empty() {
+                return ElementType::default();
+            }
+            let mut animation_type = ElementType::default();
+            for field_type in fields.values() {
+                animation_type = self.property_animation_type_for_property(field_type.clone());
+                if !matches!(animation_type, ElementType::Builtin(..)) {
+                    return ElementType::default();
+                }
+            }
+            return animation_type;
+        }
         if self.supported_property_animation_types.contains(&property_type.to_string()) {
             self.property_animation_type.clone()
         } else {
@@ -578,6 +665,7 @@ pub fn logical_point_type() -> Type {
         name: Some("slint::LogicalPosition".into()),
         node: None,
         rust_attributes: None,
+        functions: None,
     }
 }
 
@@ -593,5 +681,6 @@ pub fn font_metrics_type() -> Type {
         name: Some("slint::private_api::FontMetrics".into()),
         node: None,
         rust_attributes: None,
+        functions: None,
     }
 }
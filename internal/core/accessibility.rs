@@ -19,6 +19,8 @@ pub enum AccessibleStringProperty {
     Checked,
     DelegateFocus,
     Description,
+    Enabled,
+    Id,
     Label,
     PlaceholderText,
     Value,
@@ -6,7 +6,10 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
-use core::cell::Cell;
+use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
@@ -160,7 +163,173 @@ pub enum EasingCurve {
     EaseOutBounce,
     /// Easing curve as defined at: <https://easings.net/#easeInOutBounce>
     EaseInOutBounce,
-    // Custom(Box<dyn Fn(f32) -> f32>),
+    /// The motion of a mass attached to a damped spring, released at the start value and
+    /// settling at the end value. See [`spring_settling_time()`].
+    Spring {
+        /// The stiffness of the spring. The stiffer the spring, the faster it moves.
+        stiffness: f32,
+        /// The damping of the spring. The lower the damping, the more it oscillates.
+        damping: f32,
+        /// The mass attached to the spring. The heavier the mass, the slower it moves.
+        mass: f32,
+    },
+    /// Jumps in the given number of equal steps, at the end of each step, as `steps(n)` in CSS
+    Steps(u32),
+    /// A curve created with [`EasingCurve::custom()`] or [`EasingCurve::sampled()`]. The value
+    /// identifies the curve in the thread.
+    Custom(u32),
+}
+
+enum CustomEasingCurve {
+    Sampled(Vec<f32>),
+    Function(Rc<dyn Fn(f32) -> f32>),
+}
+
+thread_local! {
+    static CUSTOM_EASING_CURVES: RefCell<Vec<CustomEasingCurve>> = const { RefCell::new(Vec::new()) }
+}
+
+impl EasingCurve {
+    /// Creates an easing curve that maps the progress of the animation, between 0 and 1, with
+    /// the given function. The function should return 0 for 0 and 1 for 1, and may over- or
+    /// undershoot in between.
+    ///
+    /// The curve can only be used in the thread that created it, and stays allocated until the
+    /// thread ends.
+    pub fn custom(function: impl Fn(f32) -> f32 + 'static) -> Self {
+        CUSTOM_EASING_CURVES.with(|curves| {
+            let mut curves = curves.borrow_mut();
+            curves.push(CustomEasingCurve::Function(Rc::new(function)));
+            Self::Custom((curves.len() - 1) as u32)
+        })
+    }
+
+    /// Creates an easing curve that goes through the given values at equally spaced points of
+    /// the progress of the animation, and interpolates linearly between them: the first value is
+    /// used at the start, and the last one at the end. With less than two values, the curve is
+    /// linear.
+    ///
+    /// Creating a curve with the same values again returns the same curve. The curve can only be
+    /// used in the thread that created it.
+    pub fn sampled(samples: &[f32]) -> Self {
+        if samples.len() < 2 {
+            return Self::Linear;
+        }
+        CUSTOM_EASING_CURVES.with(|curves| {
+            let mut curves = curves.borrow_mut();
+            let existing = curves.iter().position(
+                |curve| matches!(curve, CustomEasingCurve::Sampled(s) if s.as_slice() == samples),
+            );
+            let index = existing.unwrap_or_else(|| {
+                curves.push(CustomEasingCurve::Sampled(samples.into()));
+                curves.len() - 1
+            });
+            Self::Custom(index as u32)
+        })
+    }
+
+    /// The `ease` curve of the Slint language, which accelerates slightly and slows down
+    pub const fn ease() -> Self {
+        Self::CubicBezier([0.25, 0.1, 0.25, 1.0])
+    }
+
+    /// The `ease-in` curve of the Slint language, which starts slowly
+    pub const fn ease_in() -> Self {
+        Self::CubicBezier([0.42, 0.0, 1.0, 1.0])
+    }
+
+    /// The `ease-out` curve of the Slint language, which ends slowly
+    pub const fn ease_out() -> Self {
+        Self::CubicBezier([0.0, 0.0, 0.58, 1.0])
+    }
+
+    /// The `ease-in-out` curve of the Slint language, which starts and ends slowly
+    pub const fn ease_in_out() -> Self {
+        Self::CubicBezier([0.42, 0.0, 0.58, 1.0])
+    }
+}
+
+/// The parameters of an animation that is started from code, for example with the
+/// `set_<property>_animated()` functions of the generated components.
+///
+/// ```rust
+/// # use i_slint_core::animations::{Animation, EasingCurve};
+/// use core::time::Duration;
+/// let animation = Animation::new(Duration::from_millis(300))
+///     .with_easing(EasingCurve::ease_in_out())
+///     .with_delay(Duration::from_millis(100));
+/// assert_eq!(animation.duration(), Duration::from_millis(300));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Animation {
+    duration: core::time::Duration,
+    delay: core::time::Duration,
+    easing: EasingCurve,
+    iteration_count: f32,
+}
+
+impl Animation {
+    /// Creates a linear animation that lasts for the given duration. A duration of zero with a
+    /// [`EasingCurve::Spring`] easing lasts until the spring settles.
+    pub fn new(duration: core::time::Duration) -> Self {
+        Self {
+            duration,
+            delay: Default::default(),
+            easing: EasingCurve::Linear,
+            iteration_count: 1.,
+        }
+    }
+
+    /// Returns the animation with the given easing curve.
+    pub fn with_easing(mut self, easing: EasingCurve) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the animation with a delay before the animation starts.
+    pub fn with_delay(mut self, delay: core::time::Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the animation with the given number of iterations. A negative number repeats the
+    /// animation forever.
+    pub fn with_iteration_count(mut self, iteration_count: f32) -> Self {
+        self.iteration_count = iteration_count;
+        self
+    }
+
+    /// The duration of the animation
+    pub fn duration(&self) -> core::time::Duration {
+        self.duration
+    }
+
+    /// The delay before the animation starts
+    pub fn delay(&self) -> core::time::Duration {
+        self.delay
+    }
+
+    /// The easing curve of the animation
+    pub fn easing(&self) -> EasingCurve {
+        self.easing
+    }
+
+    /// The number of iterations of the animation
+    pub fn iteration_count(&self) -> f32 {
+        self.iteration_count
+    }
+}
+
+impl From<Animation> for crate::items::PropertyAnimation {
+    fn from(animation: Animation) -> Self {
+        Self {
+            delay: animation.delay.as_millis() as i32,
+            duration: animation.duration.as_millis() as i32,
+            iteration_count: animation.iteration_count,
+            easing: animation.easing,
+            ..Default::default()
+        }
+    }
 }
 
 /// Represent an instant, in milliseconds since the AnimationDriver's initial_instant
@@ -215,7 +384,7 @@ pub fn now() -> Self {
         Self(Self::duration_since_start().as_millis() as u64)
     }
 
-    fn duration_since_start() -> core::time::Duration {
+    pub(crate) fn duration_since_start() -> core::time::Duration {
         crate::context::GLOBAL_CONTEXT
             .with(|p| p.get().map(|p| p.platform().duration_since_start()))
             .unwrap_or_default()
@@ -315,6 +484,61 @@ fn ease_out_bounce_curve(value: f32) -> f32 {
     }
 }
 
+/// The displacement from the end value of a mass released without velocity at distance 1 from
+/// it, after `time` seconds. Returns None if the parameters don't describe a spring that settles.
+fn spring_displacement(stiffness: f32, damping: f32, mass: f32, time: f32) -> Option<f32> {
+    if !(stiffness > 0. && damping > 0. && mass > 0.) {
+        return None;
+    }
+    let omega0 = (stiffness / mass).sqrt();
+    let zeta = damping / (2. * (stiffness * mass).sqrt());
+    Some(if zeta < 1. {
+        // Under-damped: oscillates around the end value
+        let omega_d = omega0 * (1. - zeta * zeta).sqrt();
+        (-zeta * omega0 * time).exp()
+            * ((omega_d * time).cos() + (zeta * omega0 / omega_d) * (omega_d * time).sin())
+    } else if zeta == 1. {
+        // Critically damped
+        (-omega0 * time).exp() * (1. + omega0 * time)
+    } else {
+        // Over-damped
+        let root = (zeta * zeta - 1.).sqrt();
+        let r1 = -omega0 * (zeta - root);
+        let r2 = -omega0 * (zeta + root);
+        (r2 * (r1 * time).exp() - r1 * (r2 * time).exp()) / (r2 - r1)
+    })
+}
+
+/// Returns the time it takes for the spring of an [`EasingCurve::Spring`] to settle, that is
+/// for the motion to stay within 0.1% of the distance around the end value.
+///
+/// An `animate` block with a spring easing and without a `duration` lasts that long. With a
+/// `duration`, the motion is sped up or slowed down to fit in it.
+///
+/// Returns None if the parameters don't describe a spring that settles: they must all be positive.
+pub fn spring_settling_time(
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+) -> Option<core::time::Duration> {
+    const THRESHOLD: f32 = 0.001;
+    if !(stiffness > 0. && damping > 0. && mass > 0.) {
+        return None;
+    }
+    let omega0 = (stiffness / mass).sqrt();
+    let zeta = damping / (2. * (stiffness * mass).sqrt());
+    // The rate at which the envelope of the motion decays
+    let decay = if zeta < 1. { zeta * omega0 } else { omega0 * (zeta - (zeta * zeta - 1.).sqrt()) };
+    let mut time = (1. / THRESHOLD).ln() / decay;
+    if zeta >= 1. {
+        // The motion without oscillation decays slower than its exponential factor
+        while spring_displacement(stiffness, damping, mass, time)?.abs() > THRESHOLD {
+            time *= 1.1;
+        }
+    }
+    Some(core::time::Duration::from_secs_f32(time))
+}
+
 /// map a value between 0 and 1 to another value between 0 and 1 according to the curve
 pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
     match curve {
@@ -378,6 +602,37 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
                 (1.0 + ease_out_bounce_curve(2.0 * value - 1.0)) / 2.0
             }
         }
+        EasingCurve::Spring { stiffness, damping, mass } => {
+            let Some(settling_time) = spring_settling_time(*stiffness, *damping, *mass) else {
+                return value;
+            };
+            if value >= 1.0 {
+                return 1.0;
+            }
+            spring_displacement(*stiffness, *damping, *mass, value * settling_time.as_secs_f32())
+                .map_or(value, |displacement| 1.0 - displacement)
+        }
+        EasingCurve::Steps(count) => {
+            if *count == 0 || value >= 1.0 {
+                return value.min(1.0);
+            }
+            (value.max(0.0) * *count as f32).floor() / *count as f32
+        }
+        EasingCurve::Custom(index) => {
+            let function =
+                CUSTOM_EASING_CURVES.with(|curves| match curves.borrow().get(*index as usize) {
+                    Some(CustomEasingCurve::Function(function)) => Err(function.clone()),
+                    Some(CustomEasingCurve::Sampled(samples)) => {
+                        let position = value.clamp(0.0, 1.0) * (samples.len() - 1) as f32;
+                        let i = (position.floor() as usize).min(samples.len() - 2);
+                        let t = position - i as f32;
+                        Ok(samples[i] + (samples[i + 1] - samples[i]) * t)
+                    }
+                    None => Ok(value),
+                });
+            // Call the function without borrowing the curves, in case it creates some
+            function.unwrap_or_else(|function| function(value))
+        }
     }
 }
 
@@ -412,6 +667,43 @@ fn test_curve(name: &str, curve: &EasingCurve) {
 }
 */
 
+#[test]
+fn easing_steps_and_custom_curves() {
+    let steps = EasingCurve::Steps(4);
+    assert_eq!(easing_curve(&steps, 0.0), 0.0);
+    assert_eq!(easing_curve(&steps, 0.2), 0.0);
+    assert_eq!(easing_curve(&steps, 0.3), 0.25);
+    assert_eq!(easing_curve(&steps, 0.99), 0.75);
+    assert_eq!(easing_curve(&steps, 1.0), 1.0);
+
+    let sampled = EasingCurve::sampled(&[0.0, 0.8, 1.0]);
+    assert_eq!(easing_curve(&sampled, 0.0), 0.0);
+    assert_eq!(easing_curve(&sampled, 0.25), 0.4);
+    assert_eq!(easing_curve(&sampled, 0.5), 0.8);
+    assert_eq!(easing_curve(&sampled, 1.0), 1.0);
+    assert_eq!(EasingCurve::sampled(&[0.0, 0.8, 1.0]), sampled);
+    assert_ne!(EasingCurve::sampled(&[0.0, 0.2, 1.0]), sampled);
+    assert_eq!(EasingCurve::sampled(&[0.5]), EasingCurve::Linear);
+
+    let custom = EasingCurve::custom(|t| t * t);
+    assert_eq!(easing_curve(&custom, 0.5), 0.25);
+    assert_ne!(EasingCurve::custom(|t| t * t), custom);
+}
+
+#[cfg(feature = "ffi")]
+pub(crate) mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+    use crate::slice::Slice;
+
+    /// Creates a curve with [`EasingCurve::sampled()`]
+    #[no_mangle]
+    pub extern "C" fn slint_easing_curve_sampled(samples: Slice<f32>, out: &mut EasingCurve) {
+        *out = EasingCurve::sampled(samples.as_slice());
+    }
+}
+
 /// Update the global animation time to the current time
 pub fn update_animations() {
     CURRENT_ANIMATION_DRIVER.with(|driver| {
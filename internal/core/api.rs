@@ -408,6 +408,159 @@ pub enum CloseRequestResponse {
     KeepWindowShown = 1,
 }
 
+/// This struct describes a frame that was presented on the screen.
+/// It is the argument of the callback provided to [Window::on_frame_presented].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct FramePresentedInfo {
+    /// The number of frames presented in this window so far, starting with 1 for the first frame.
+    pub frame_number: u64,
+    /// The time at which the frame was presented, relative to the start of the application
+    /// (see [`Platform::duration_since_start()`](crate::platform::Platform::duration_since_start)).
+    pub timestamp: core::time::Duration,
+    /// The time between the moment the window received the oldest input event whose effect this
+    /// frame shows, and the presentation of the frame. `None` if the frame doesn't show the effect
+    /// of any input event, for example when it renders an animation.
+    pub input_latency: Option<core::time::Duration>,
+}
+
+/// This struct holds statistics about the end-to-end input latency of a window: the time between the
+/// moment the window receives an input event and the presentation of the first frame that shows its effect.
+/// It is returned by [Window::input_latency_statistics].
+///
+/// Each presented frame that shows the effect of input events is measured once, from the oldest of
+/// these events. Input events are timestamped when the backend delivers them to the window, and the
+/// measurement requires a backend that reports presented frames with
+/// [`WindowEvent::FramePresented`](crate::platform::WindowEvent::FramePresented).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct InputLatencyStatistics {
+    /// The number of measured frames
+    pub count: u64,
+    /// The lowest measured latency
+    pub min: core::time::Duration,
+    /// The highest measured latency
+    pub max: core::time::Duration,
+    /// The sum of all the measured latencies
+    pub total: core::time::Duration,
+}
+
+impl InputLatencyStatistics {
+    /// Returns the average latency, or zero if no frame was measured.
+    pub fn average(&self) -> core::time::Duration {
+        if self.count == 0 {
+            core::time::Duration::ZERO
+        } else {
+            self.total.div_f64(self.count as f64)
+        }
+    }
+
+    pub(crate) fn add(&mut self, latency: core::time::Duration) {
+        self.min = if self.count == 0 { latency } else { self.min.min(latency) };
+        self.max = self.max.max(latency);
+        self.total += latency;
+        self.count += 1;
+    }
+}
+
+/// This struct describes a screen, such as a monitor, on which windows are shown.
+/// It is returned by `slint::screens()` and [`Window::screen()`].
+///
+/// The positions and sizes are in physical pixels, in the coordinate system of
+/// [`Window::position()`], so that a window can be placed on a screen with [`Window::set_position()`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Screen {
+    /// The name of the screen, which identifies it across restarts of the application,
+    /// such as the name of the monitor or of its connector. Empty when it's unknown.
+    pub name: SharedString,
+    /// The position of the top-left corner of the screen.
+    pub position: PhysicalPosition,
+    /// The size of the screen.
+    pub size: PhysicalSize,
+    /// The position of the top-left corner of the area of the screen that isn't covered by
+    /// task bars, docks, or panels. Same as `position` when it's unknown.
+    pub work_area_position: PhysicalPosition,
+    /// The size of the area of the screen that isn't covered by task bars, docks, or panels.
+    /// Same as `size` when it's unknown.
+    pub work_area_size: PhysicalSize,
+    /// The scale factor of the windows on the screen.
+    pub scale_factor: f32,
+    /// The refresh rate of the screen in Hertz, if it's known.
+    pub refresh_rate: Option<f32>,
+}
+
+impl Screen {
+    /// Returns the area of the screen that the rectangle at `position` with `size` overlaps
+    fn overlap(&self, position: PhysicalPosition, size: PhysicalSize) -> i64 {
+        let overlap = |start: i32, length: u32, screen_start: i32, screen_length: u32| {
+            let end =
+                (start as i64 + length as i64).min(screen_start as i64 + screen_length as i64);
+            (end - (start as i64).max(screen_start as i64)).max(0)
+        };
+        overlap(position.x, size.width, self.position.x, self.size.width)
+            * overlap(position.y, size.height, self.position.y, self.size.height)
+    }
+}
+
+/// This struct describes a keyboard shortcut that is registered with the operating system with
+/// `slint::register_global_shortcut()`, so that it's recognized even when the windows of the
+/// application don't have the focus.
+///
+/// The `key` is the text that the key produces, or one of the special keys of
+/// [`Key`](crate::platform::Key), as in [`WindowEvent::KeyPressed`](crate::platform::WindowEvent::KeyPressed).
+///
+/// ```
+/// # use i_slint_core::api::GlobalShortcut;
+/// // Ctrl+Shift+F9
+/// let shortcut = GlobalShortcut::new(i_slint_core::platform::Key::F9).with_control().with_shift();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct GlobalShortcut {
+    /// The key that is pressed with the modifiers.
+    pub key: SharedString,
+    /// The Alt key must be pressed.
+    pub alt: bool,
+    /// The Control key must be pressed, except on macOS, where it is the Command key (⌘).
+    pub control: bool,
+    /// The Shift key must be pressed.
+    pub shift: bool,
+    /// The Control key must be pressed on macOS, and the Windows key on Windows.
+    pub meta: bool,
+}
+
+impl GlobalShortcut {
+    /// Creates a shortcut for the key, without modifiers.
+    pub fn new(key: impl Into<SharedString>) -> Self {
+        Self { key: key.into(), ..Default::default() }
+    }
+
+    /// Returns the shortcut with the Alt modifier.
+    pub fn with_alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Returns the shortcut with the Control modifier.
+    pub fn with_control(mut self) -> Self {
+        self.control = true;
+        self
+    }
+
+    /// Returns the shortcut with the Shift modifier.
+    pub fn with_shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Returns the shortcut with the Meta modifier.
+    pub fn with_meta(mut self) -> Self {
+        self.meta = true;
+        self
+    }
+}
+
 impl Window {
     /// Create a new window from a window adapter
     ///
@@ -476,6 +629,61 @@ pub fn on_close_requested(&self, callback: impl FnMut() -> CloseRequestResponse
         self.0.on_close_requested(callback);
     }
 
+    /// This function allows registering a callback that's invoked each time a frame of this window was
+    /// successfully presented on the screen.
+    ///
+    /// Embedded systems can use this as a heartbeat to kick a hardware watchdog only while the user
+    /// interface is actually alive and rendering.
+    ///
+    /// Note that frames are only presented when the content of the window changes.
+    ///
+    /// Backends report presented frames with [`WindowEvent::FramePresented`](crate::platform::WindowEvent::FramePresented).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// # use std::cell::Cell;
+    /// # use slint::platform::{WindowAdapter, WindowEvent, Renderer};
+    /// # use slint::{Window, PhysicalSize};
+    /// # struct MyWindowAdapter { window: Window }
+    /// # impl WindowAdapter for MyWindowAdapter {
+    /// #    fn window(&self) -> &Window { &self.window }
+    /// #    fn size(&self) -> PhysicalSize { unimplemented!() }
+    /// #    fn renderer(&self) -> &dyn Renderer { unimplemented!() }
+    /// # }
+    /// # let adapter = Rc::<MyWindowAdapter>::new_cyclic(|weak| MyWindowAdapter { window: Window::new(weak.clone()) });
+    /// # let window = adapter.window();
+    /// let last_frame = Rc::new(Cell::new(0));
+    /// window.on_frame_presented({
+    ///     let last_frame = last_frame.clone();
+    ///     move |info| {
+    ///         // kick the hardware watchdog here
+    ///         last_frame.set(info.frame_number);
+    ///     }
+    /// });
+    /// window.dispatch_event(WindowEvent::FramePresented);
+    /// window.dispatch_event(WindowEvent::FramePresented);
+    /// assert_eq!(last_frame.get(), 2);
+    /// ```
+    pub fn on_frame_presented(&self, callback: impl FnMut(FramePresentedInfo) + 'static) {
+        self.0.on_frame_presented(callback);
+    }
+
+    /// Returns the statistics of the input latency of this window, measured since the window was
+    /// created or since the last call to [`Self::reset_input_latency_statistics()`].
+    ///
+    /// The latency of individual frames is also available in the `input_latency` field of the
+    /// [`FramePresentedInfo`] passed to the callback set with [`Self::on_frame_presented()`].
+    pub fn input_latency_statistics(&self) -> InputLatencyStatistics {
+        self.0.input_latency_statistics()
+    }
+
+    /// Clears the statistics returned by [`Self::input_latency_statistics()`], for example to
+    /// measure the latency of a specific interaction.
+    pub fn reset_input_latency_statistics(&self) {
+        self.0.reset_input_latency_statistics()
+    }
+
     /// This function issues a request to the windowing system to redraw the contents of the window.
     pub fn request_redraw(&self) {
         self.0.window_adapter().request_redraw()
@@ -514,6 +722,23 @@ pub fn set_size(&self, size: impl Into<WindowSize>) {
         crate::window::WindowAdapter::set_size(&*self.0.window_adapter(), size);
     }
 
+    /// Returns the screen that shows the largest part of the window, or `None` if the platform
+    /// doesn't report its screens (see `slint::screens()`) or the window isn't on any of them.
+    ///
+    /// Store the [`Screen::name`] together with the position of the window, to restore the window
+    /// onto the same screen when the application is started again, if that screen is still connected.
+    pub fn screen(&self) -> Option<Screen> {
+        let position = self.position();
+        let size = self.size();
+        let screens = self.0.ctx.platform().screens();
+        screens
+            .into_iter()
+            .map(|screen| (screen.overlap(position, size), screen))
+            .filter(|(overlap, _)| *overlap > 0)
+            .max_by_key(|(overlap, _)| *overlap)
+            .map(|(_, screen)| screen)
+    }
+
     /// Returns if the window is currently fullscreen
     pub fn is_fullscreen(&self) -> bool {
         self.0.is_fullscreen()
@@ -544,6 +769,94 @@ pub fn set_minimized(&self, minimized: bool) {
         self.0.set_minimized(minimized);
     }
 
+    /// Returns if the window is placed above all other windows, with this function or the
+    /// `always-on-top` property of the `Window` element.
+    pub fn is_always_on_top(&self) -> bool {
+        self.0.is_always_on_top()
+    }
+
+    /// Places the window above all other windows on window managers supporting it, such as for an
+    /// overlay or an on-screen display. The window also stays on top while its `always-on-top`
+    /// property is true.
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        self.0.set_always_on_top(always_on_top);
+    }
+
+    /// Makes this window a child window of `parent`, such as a dialog or a tool palette, or a
+    /// top-level window again with `None`.
+    ///
+    /// The windowing system keeps a child window on top of its parent, and the child window is
+    /// hidden when its parent is hidden. The call is ignored if `parent` is this window or one
+    /// of its child windows.
+    pub fn set_parent_window(&self, parent: Option<&Window>) {
+        self.0.set_parent_window(parent.map(|parent| parent.0.window_adapter()).as_ref());
+    }
+
+    /// Returns true if the window is modal.
+    pub fn is_modal(&self) -> bool {
+        self.0.is_modal()
+    }
+
+    /// Sets whether the window is modal. While a modal child window is shown, its parent
+    /// window doesn't receive any input, and clicking on it brings the modal window to the front.
+    ///
+    /// This has no effect on windows without a parent window, see [`Self::set_parent_window()`].
+    pub fn set_modal(&self, modal: bool) {
+        self.0.set_modal(modal);
+    }
+
+    /// Returns whether the widgets are adapted to a touch screen or to a pointing device.
+    ///
+    /// By default, the mode follows the device that produced the last input event, so that
+    /// the widgets adapt when the user of a convertible laptop switches between the touch screen
+    /// and the touchpad.
+    pub fn interaction_mode(&self) -> InteractionMode {
+        self.0.interaction_mode()
+    }
+
+    /// Forces the interaction mode of the widgets, or let it follow the input events again with `None`.
+    pub fn set_interaction_mode(&self, mode: Option<InteractionMode>) {
+        self.0.set_interaction_mode(mode);
+    }
+
+    /// Returns the `accessible-id` of the element that has the keyboard focus, or of the closest
+    /// element containing it that has an `accessible-id`. Returns `None` if no element has the
+    /// focus or none of them has an `accessible-id`.
+    pub fn focused_accessible_id(&self) -> Option<SharedString> {
+        self.0.focused_accessible_id()
+    }
+
+    /// Sets the keyboard focus to the element with the given `accessible-id`. If that element
+    /// doesn't accept the focus itself, such as a widget, the focus goes to the first element
+    /// inside of it that does.
+    ///
+    /// Returns `false` if no element of the window has this `accessible-id`.
+    pub fn set_focus_to_accessible_id(&self, id: &str) -> bool {
+        self.0.set_focus_to_accessible_id(id)
+    }
+
+    /// Moves the keyboard focus to the next element, like the tab key does.
+    ///
+    /// This can be used to implement custom navigation, for example with a remote control.
+    pub fn focus_next_element(&self) {
+        self.0.focus_next_item();
+    }
+
+    /// Moves the keyboard focus to the previous element, like shift+tab does.
+    pub fn focus_previous_element(&self) {
+        self.0.focus_previous_item();
+    }
+
+    /// Sets the mouse cursor that Slint draws on top of the window contents, or stops drawing
+    /// it with `None`.
+    ///
+    /// This is only drawn on platforms that don't have a system mouse cursor, such as the
+    /// `linuxkms` backend, which sets a default arrow cursor. The cursor is hidden while
+    /// the user interacts with a touch screen.
+    pub fn set_software_cursor(&self, cursor: Option<crate::platform::SoftwareCursor>) {
+        self.0.set_software_cursor(cursor);
+    }
+
     /// Dispatch a window event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -582,6 +895,30 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
+            crate::platform::WindowEvent::TouchPressed { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    crate::input::TouchPhase::Started,
+                );
+            }
+            crate::platform::WindowEvent::TouchMoved { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    crate::input::TouchPhase::Moved,
+                );
+            }
+            crate::platform::WindowEvent::TouchReleased { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    crate::input::TouchPhase::Ended,
+                );
+            }
+            crate::platform::WindowEvent::PointerDetailsChanged { details } => {
+                self.0.pointer_details.set(details);
+            }
 
             crate::platform::WindowEvent::KeyPressed { text } => {
                 self.0.process_key_input(crate::input::KeyEvent {
@@ -623,6 +960,7 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                 }
             }
             crate::platform::WindowEvent::WindowActiveChanged(bool) => self.0.set_active(bool),
+            crate::platform::WindowEvent::FramePresented => self.0.frame_presented(),
         }
     }
 
@@ -668,8 +1006,14 @@ pub fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformErr
     }
 }
 
+pub use crate::items::InteractionMode;
 pub use crate::SharedString;
 
+#[cfg(feature = "std")]
+pub use crate::graphics::set_font_fallback_families;
+
+pub use crate::theme::{active_theme, set_text_scale_factor, set_theme, text_scale_factor, Theme};
+
 /// This trait is used to obtain references to global singletons exported in `.slint`
 /// markup. Alternatively, you can use [`ComponentHandle::global`] to obtain access.
 ///
@@ -0,0 +1,236 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Control of the backlight of the screen, for devices with a built-in display.
+
+    The brightness is applied by the platform in [`Platform::set_backlight()`](crate::platform::Platform::set_backlight).
+    On top of that, this module implements fading between brightness levels, and dimming
+    the backlight when the user didn't interact with the device for a while.
+*/
+
+#![warn(missing_docs)]
+
+use crate::animations::Instant;
+use crate::api::PlatformError;
+use crate::timers::{Timer, TimerMode};
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+use core::cell::Cell;
+use core::time::Duration;
+
+/// The interval at which the brightness is updated while fading.
+const FADE_STEP: Duration = Duration::from_millis(16);
+
+/// The configuration of the automatic dimming of the backlight, see [`set_backlight_idle_dimming()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IdleDimming {
+    /// The duration without user input after which the backlight is dimmed.
+    pub timeout: Duration,
+    /// The brightness of the dimmed backlight, in percent.
+    pub dimmed_percent: f32,
+    /// The duration of the fade when dimming and when restoring the brightness.
+    pub fade_duration: Duration,
+}
+
+impl Default for IdleDimming {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            dimmed_percent: 10.,
+            fade_duration: Duration::from_millis(500),
+        }
+    }
+}
+
+struct BacklightState {
+    /// The brightness that was last applied by the platform
+    current: Cell<f32>,
+    /// The brightness requested by the application, restored on user input when dimmed
+    requested: Cell<f32>,
+    fade_timer: Timer,
+    idle_dimming: Cell<Option<IdleDimming>>,
+    idle_timer: Timer,
+    last_user_input: Cell<Instant>,
+    dimmed: Cell<bool>,
+}
+
+thread_local! {
+    static BACKLIGHT: BacklightState = BacklightState {
+        current: Cell::new(100.),
+        requested: Cell::new(100.),
+        fade_timer: Timer::default(),
+        idle_dimming: Cell::new(None),
+        idle_timer: Timer::default(),
+        last_user_input: Cell::new(Instant::default()),
+        dimmed: Cell::new(false),
+    }
+}
+
+impl BacklightState {
+    fn apply(&self, percent: f32) -> Result<(), PlatformError> {
+        crate::context::GLOBAL_CONTEXT.with(|ctx| match ctx.get() {
+            Some(ctx) => ctx.platform().set_backlight(percent),
+            None => Err(PlatformError::NoPlatform),
+        })?;
+        self.current.set(percent);
+        Ok(())
+    }
+
+    fn fade_to(&self, percent: f32, duration: Duration) -> Result<(), PlatformError> {
+        self.fade_timer.stop();
+        let from = self.current.get();
+        if duration.is_zero() {
+            return self.apply(percent);
+        }
+        // Report an unsupported platform right away rather than from the timer
+        self.apply(from)?;
+        let start = crate::animations::current_tick();
+        self.fade_timer.start(TimerMode::Repeated, FADE_STEP, move || {
+            let elapsed = crate::animations::current_tick() - start;
+            let progress = (elapsed.as_secs_f32() / duration.as_secs_f32()).min(1.);
+            BACKLIGHT.with(|state| {
+                state.apply(from + (percent - from) * progress).ok();
+                if progress >= 1. {
+                    state.fade_timer.stop();
+                }
+            })
+        });
+        Ok(())
+    }
+
+    fn start_idle_timer(&self, timeout: Duration) {
+        self.idle_timer.start(TimerMode::SingleShot, timeout, || {
+            BACKLIGHT.with(|state| state.idle_timeout_reached())
+        });
+    }
+
+    fn idle_timeout_reached(&self) {
+        let Some(dimming) = self.idle_dimming.get() else { return };
+        let idle_for = crate::animations::current_tick() - self.last_user_input.get();
+        if idle_for < dimming.timeout {
+            self.start_idle_timer(dimming.timeout - idle_for);
+        } else if !self.dimmed.get() && self.requested.get() > dimming.dimmed_percent {
+            self.dimmed.set(true);
+            self.fade_to(dimming.dimmed_percent, dimming.fade_duration).ok();
+        }
+    }
+}
+
+/// Sets the brightness of the backlight, from `0.` (off) to `100.` percent, and stops any
+/// ongoing fade.
+///
+/// Returns an error if the platform doesn't support controlling the backlight.
+pub fn set_backlight(percent: f32) -> Result<(), PlatformError> {
+    fade_backlight(percent, Duration::ZERO)
+}
+
+/// Changes the brightness of the backlight gradually to `percent`, over `duration`.
+///
+/// Returns an error if the platform doesn't support controlling the backlight.
+pub fn fade_backlight(percent: f32, duration: Duration) -> Result<(), PlatformError> {
+    let percent = percent.clamp(0., 100.);
+    BACKLIGHT.with(|state| {
+        state.requested.set(percent);
+        state.dimmed.set(false);
+        state.fade_to(percent, duration)
+    })
+}
+
+/// Returns the brightness of the backlight that was last applied, in percent.
+pub fn backlight() -> f32 {
+    BACKLIGHT.with(|state| state.current.get())
+}
+
+/// Enables dimming the backlight when there was no user input for the duration configured
+/// in `dimming`, or disables it when `None`. When the user interacts with the dimmed display,
+/// the brightness set with [`set_backlight()`] is restored.
+///
+/// ## Example
+/// ```rust,no_run
+/// use slint::platform::{set_backlight_idle_dimming, IdleDimming};
+/// set_backlight_idle_dimming(Some(IdleDimming {
+///     timeout: std::time::Duration::from_secs(60),
+///     ..Default::default()
+/// }));
+/// ```
+pub fn set_backlight_idle_dimming(dimming: Option<IdleDimming>) {
+    BACKLIGHT.with(|state| {
+        state.idle_dimming.set(dimming);
+        state.last_user_input.set(crate::animations::current_tick());
+        match dimming {
+            Some(dimming) => state.start_idle_timer(dimming.timeout),
+            None => {
+                state.idle_timer.stop();
+                if state.dimmed.replace(false) {
+                    state.fade_to(state.requested.get(), Duration::ZERO).ok();
+                }
+            }
+        }
+    })
+}
+
+/// Called by the window for every mouse or keyboard event.
+pub(crate) fn notify_user_input() {
+    let _ = BACKLIGHT.try_with(|state| {
+        let Some(dimming) = state.idle_dimming.get() else { return };
+        state.last_user_input.set(crate::animations::current_tick());
+        if state.dimmed.replace(false) {
+            state.fade_to(state.requested.get(), dimming.fade_duration).ok();
+        }
+        if !state.idle_timer.running() {
+            state.start_idle_timer(dimming.timeout);
+        }
+    });
+}
+
+/**
+```rust
+use i_slint_core::platform::*;
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+struct MockBackend(Rc<RefCell<Vec<f32>>>);
+impl Platform for MockBackend {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Err(PlatformError::Other("not implemented".into()))
+    }
+    fn duration_since_start(&self) -> Duration {
+        Duration::from_millis(i_slint_core::tests::slint_get_mocked_time())
+    }
+    fn set_backlight(&self, percent: f32) -> Result<(), PlatformError> {
+        self.0.borrow_mut().push(percent);
+        Ok(())
+    }
+}
+
+let applied = Rc::new(RefCell::new(Vec::new()));
+set_platform(Box::new(MockBackend(applied.clone()))).unwrap();
+
+set_backlight(150.).unwrap();
+assert_eq!(*applied.borrow(), vec![100.]);
+
+applied.borrow_mut().clear();
+fade_backlight(50., Duration::from_millis(100)).unwrap();
+i_slint_core::tests::slint_mock_elapsed_time(50);
+let last = *applied.borrow().last().unwrap();
+assert!(last < 100. && last > 50., "{last}");
+i_slint_core::tests::slint_mock_elapsed_time(100);
+assert_eq!(*applied.borrow().last().unwrap(), 50.);
+assert_eq!(backlight(), 50.);
+
+set_backlight_idle_dimming(Some(IdleDimming {
+    timeout: Duration::from_secs(1),
+    dimmed_percent: 5.,
+    fade_duration: Duration::ZERO,
+}));
+i_slint_core::tests::slint_mock_elapsed_time(500);
+assert_eq!(backlight(), 50.);
+i_slint_core::tests::slint_mock_elapsed_time(600);
+assert_eq!(backlight(), 5.);
+
+set_backlight_idle_dimming(None);
+assert_eq!(backlight(), 50.);
+```
+ */
+#[cfg(doctest)]
+const _BACKLIGHT_FADE_AND_DIM: () = ();
@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use crate::api::PlatformError;
+use crate::api::{GlobalShortcut, PlatformError};
 use crate::platform::{EventLoopProxy, Platform};
 #[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
 use crate::thread_local;
@@ -9,6 +9,8 @@
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 thread_local! {
     pub(crate) static GLOBAL_CONTEXT : once_cell::unsync::OnceCell<SlintContext>
@@ -18,11 +20,18 @@
 pub(crate) struct SlintContextInner {
     platform: Box<dyn Platform>,
     pub(crate) window_count: core::cell::RefCell<isize>,
+    /// The windows that are shown, with their parent and modal relationships
+    pub(crate) window_registry: crate::window::registry::WindowRegistry,
     /// This property is read by all translations, and marked dirty when the language change
     /// so that every translated string gets re-translated
     pub(crate) translations_dirty: core::pin::Pin<Box<Property<()>>>,
     pub(crate) window_shown_hook:
         core::cell::RefCell<Option<Box<dyn FnMut(&Rc<dyn crate::platform::WindowAdapter>)>>>,
+    /// The callback set with [`SlintContext::set_screens_changed_handler`]
+    pub(crate) screens_changed_handler: core::cell::RefCell<Option<Box<dyn FnMut()>>>,
+    /// The shortcuts registered with [`SlintContext::register_global_shortcut`], with their callback
+    pub(crate) global_shortcuts:
+        core::cell::RefCell<Vec<(GlobalShortcut, Rc<core::cell::RefCell<dyn FnMut()>>)>>,
 }
 
 /// This context is meant to hold the state and the backend.
@@ -37,8 +46,11 @@ pub fn new(platform: Box<dyn Platform + 'static>) -> Self {
         Self(Rc::new(SlintContextInner {
             platform,
             window_count: 0.into(),
+            window_registry: Default::default(),
             translations_dirty: Box::pin(Property::new_named((), "SlintContext::translations")),
             window_shown_hook: Default::default(),
+            screens_changed_handler: Default::default(),
+            global_shortcuts: Default::default(),
         }))
     }
 
@@ -65,6 +77,44 @@ pub fn spawn_local<F: core::future::Future + 'static>(
     pub fn run_event_loop(&self) -> Result<(), PlatformError> {
         self.0.platform.run_event_loop()
     }
+
+    /// Sets the callback that [`crate::platform::notify_screens_changed`] invokes
+    pub fn set_screens_changed_handler(&self, handler: impl FnMut() + 'static) {
+        self.0.screens_changed_handler.replace(Some(Box::new(handler)));
+    }
+
+    /// Registers the shortcut with the platform, so that
+    /// [`crate::platform::notify_global_shortcut_pressed`] invokes the callback.
+    /// Returns an error if the shortcut is already registered, or if the platform couldn't register it.
+    pub fn register_global_shortcut(
+        &self,
+        shortcut: GlobalShortcut,
+        callback: impl FnMut() + 'static,
+    ) -> Result<(), PlatformError> {
+        if self.0.global_shortcuts.borrow().iter().any(|(s, _)| *s == shortcut) {
+            return Err(
+                alloc::format!("The global shortcut {shortcut:?} is already registered").into()
+            );
+        }
+        self.0.platform.register_global_shortcut(&shortcut)?;
+        let callback: Rc<core::cell::RefCell<dyn FnMut()>> =
+            Rc::new(core::cell::RefCell::new(callback));
+        self.0.global_shortcuts.borrow_mut().push((shortcut, callback));
+        Ok(())
+    }
+
+    /// Unregisters a shortcut that was registered with [`Self::register_global_shortcut`]
+    pub fn unregister_global_shortcut(&self, shortcut: &GlobalShortcut) {
+        let removed = {
+            let mut shortcuts = self.0.global_shortcuts.borrow_mut();
+            let len = shortcuts.len();
+            shortcuts.retain(|(s, _)| s != shortcut);
+            shortcuts.len() != len
+        };
+        if removed {
+            self.0.platform.unregister_global_shortcut(shortcut);
+        }
+    }
 }
 
 /// Internal function to access the context.
@@ -0,0 +1,385 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    API to implement custom native items outside of Slint.
+
+    A custom item is a Rust type that is used as an element in .slint files, like the builtin
+    elements `Rectangle` or `TouchArea`. It is declared in a manifest, a .slint file with exported
+    components that only declare properties and callbacks:
+
+    ```slint,ignore
+    export component Gauge {
+        //-custom_item_api:1
+        //-rust_type:crate::Gauge
+        in property <float> value;
+        in property <brush> color: blue;
+        callback clicked();
+    }
+    ```
+
+    The manifest is passed to the compiler with `slint_build::CompilerConfiguration::with_custom_items()`.
+    The Rust type has one field of the matching type for each property and callback, and implements
+    [`v1::CustomItem`]:
+
+    ```rust,ignore
+    use slint::custom_item::v1::*;
+
+    #[repr(C)]
+    #[derive(FieldOffsets, Default)]
+    #[const_field_offset(const_field_offset)]
+    #[pin]
+    pub struct Gauge {
+        pub value: Property<f32>,
+        pub color: Property<slint::Brush>,
+        pub clicked: Callback<()>,
+        pub cached_rendering_data: CachedRenderingData,
+    }
+
+    impl CustomItem for Gauge {
+        fn render(self: core::pin::Pin<&Self>, renderer: &mut Renderer, size: slint::LogicalSize) {
+            // ...
+        }
+    }
+
+    declare_custom_item!(Gauge);
+    ```
+
+    The API is versioned: the items of the module [`v1`] remain compatible within the Slint 1.x releases,
+    even though the internal item API that the builtin elements use changes.
+    The manifest names the version it was written for in the `custom_item_api` annotation.
+*/
+
+#![warn(missing_docs)]
+
+/// Version 1 of the custom item API.
+///
+/// A property of the type `int` has the type `Property<i32>` in Rust, `float` is `Property<f32>`,
+/// `length` is `Property<LogicalLength>`, `brush` is `Property<slint::Brush>`, `string` is
+/// `Property<slint::SharedString>`, and `[T]` is `Property<slint::ModelRc<T>>`.
+/// A callback `name(int) -> bool` is `Callback<(i32,), bool>`.
+pub mod v1 {
+    use crate::api::{LogicalPosition, LogicalSize};
+    use crate::item_rendering::ItemRenderer;
+    use crate::lengths::{
+        logical_point_from_api, logical_size_from_api, LogicalBorderRadius, LogicalRect,
+    };
+    use core::pin::Pin;
+
+    pub use crate::callbacks::Callback;
+    pub use crate::input::PointerEventButton;
+    pub use crate::item_rendering::CachedRenderingData;
+    pub use crate::items::Orientation;
+    pub use crate::lengths::LogicalLength;
+    pub use crate::properties::Property;
+    pub use const_field_offset::{self, FieldOffsets};
+
+    #[doc(inline)]
+    pub use crate::declare_custom_item_v1 as declare_custom_item;
+
+    /// The size constraints of a custom item in one orientation, see [`CustomItem::layout_info()`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct LayoutInfo {
+        /// The minimum size, in logical pixels.
+        pub min: f32,
+        /// The maximum size, in logical pixels.
+        pub max: f32,
+        /// The preferred size, in logical pixels.
+        pub preferred: f32,
+        /// How much the item grows in a layout, relative to the other items.
+        pub stretch: f32,
+    }
+
+    impl Default for LayoutInfo {
+        fn default() -> Self {
+            Self { min: 0., max: f32::MAX, preferred: 0., stretch: 1. }
+        }
+    }
+
+    /// A pointer event sent to a custom item, see [`CustomItem::pointer_event()`].
+    /// The positions are relative to the item.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[non_exhaustive]
+    pub enum PointerInput {
+        /// A button was pressed.
+        Pressed {
+            /// The position of the pointer.
+            position: LogicalPosition,
+            /// The button that was pressed.
+            button: PointerEventButton,
+            /// The number of clicks in quick succession, starting at 1.
+            click_count: u8,
+        },
+        /// A button was released.
+        Released {
+            /// The position of the pointer.
+            position: LogicalPosition,
+            /// The button that was released.
+            button: PointerEventButton,
+            /// The number of clicks in quick succession, starting at 1.
+            click_count: u8,
+        },
+        /// The pointer was moved.
+        Moved {
+            /// The position of the pointer.
+            position: LogicalPosition,
+        },
+        /// The mouse wheel was turned, or the user scrolled on a touchpad.
+        Wheel {
+            /// The position of the pointer.
+            position: LogicalPosition,
+            /// The amount of logical pixels to scroll in horizontal direction.
+            delta_x: f32,
+            /// The amount of logical pixels to scroll in vertical direction.
+            delta_y: f32,
+        },
+        /// The pointer left the item, or the grab of the pointer was cancelled.
+        Exit,
+    }
+
+    /// The result of [`CustomItem::pointer_event()`].
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum InputResult {
+        /// The item doesn't handle the event, it is sent to the items below.
+        #[default]
+        Ignored,
+        /// The item handled the event.
+        Accepted,
+        /// The item handled the event, and all further pointer events are sent to this item
+        /// until it returns another value.
+        GrabPointer,
+    }
+
+    /// The renderer passed to [`CustomItem::render()`].
+    ///
+    /// The coordinates are in logical pixels, relative to the item, until the state is changed
+    /// with [`Self::translate()`] or [`Self::clip()`].
+    pub struct Renderer<'a>(&'a mut dyn ItemRenderer);
+
+    impl Renderer<'_> {
+        /// Returns the ratio between physical and logical pixels.
+        pub fn scale_factor(&self) -> f32 {
+            self.0.scale_factor()
+        }
+
+        /// Moves the origin of the following drawing operations.
+        pub fn translate(&mut self, offset: LogicalPosition) {
+            self.0.translate(logical_point_from_api(offset).to_vector());
+        }
+
+        /// Restricts the following drawing operations to the given rectangle.
+        /// Returns false if the clip area became empty, so there is nothing left to draw.
+        pub fn clip(&mut self, origin: LogicalPosition, size: LogicalSize) -> bool {
+            self.0.combine_clip(
+                LogicalRect::new(logical_point_from_api(origin), logical_size_from_api(size)),
+                LogicalBorderRadius::zero(),
+                LogicalLength::zero(),
+            )
+        }
+
+        /// Saves the translation and clip, to restore them with [`Self::restore_state()`].
+        pub fn save_state(&mut self) {
+            self.0.save_state();
+        }
+
+        /// Restores the translation and clip that were saved last with [`Self::save_state()`].
+        pub fn restore_state(&mut self) {
+            self.0.restore_state();
+        }
+
+        /// Draws the image at the origin, in its size in logical pixels.
+        pub fn draw_image(&mut self, image: crate::graphics::Image) {
+            self.0.draw_image_direct(image);
+        }
+
+        /// Draws the text at the origin, with the default font.
+        pub fn draw_text(&mut self, text: &str, color: crate::Color) {
+            self.0.draw_string(text, color);
+        }
+
+        /// Returns the renderer of the backend, to draw with its own API, for example the
+        /// `femtovg::Canvas` or the `skia_safe::Canvas`. This is `None` if the renderer
+        /// doesn't provide one.
+        pub fn native_renderer(&mut self) -> Option<&mut dyn core::any::Any> {
+            self.0.as_any()
+        }
+    }
+
+    /// The trait to implement by a custom item. Use [`declare_custom_item!`] to make the type
+    /// usable as an element.
+    ///
+    /// The item is pinned, and sets its properties through its [`Property`] fields.
+    pub trait CustomItem {
+        /// Returns the size constraints of the item in the given orientation.
+        fn layout_info(self: Pin<&Self>, orientation: Orientation) -> LayoutInfo {
+            let _ = orientation;
+            LayoutInfo::default()
+        }
+
+        /// Draws the item, with the given size. The children of the item are drawn afterwards.
+        fn render(self: Pin<&Self>, renderer: &mut Renderer, size: LogicalSize) {
+            let _ = (renderer, size);
+        }
+
+        /// Handles a pointer event that occurred within the item, or any pointer event while the
+        /// item grabs the pointer. The children of the item get the event first.
+        fn pointer_event(self: Pin<&Self>, event: PointerInput) -> InputResult {
+            let _ = event;
+            InputResult::Ignored
+        }
+    }
+
+    /// Used by [`declare_custom_item!`], not part of the API.
+    #[doc(hidden)]
+    #[allow(missing_docs)]
+    pub mod __private {
+        use super::{CustomItem, InputResult, PointerInput, Renderer};
+        use crate::item_rendering::ItemRenderer;
+        use crate::lengths::{logical_position_to_api, logical_size_to_api};
+        use crate::Coord;
+
+        pub use crate::input::{
+            FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+            KeyEventResult, MouseEvent,
+        };
+        pub use crate::item_rendering::CachedRenderingData;
+        pub use crate::item_tree::ItemRc;
+        pub use crate::items::{Item, ItemConsts, ItemVTable, Orientation, RenderingResult};
+        pub use crate::layout::LayoutInfo;
+        pub use crate::lengths::LogicalSize;
+        pub use crate::window::WindowAdapterRc;
+        pub use crate::ItemVTable_static;
+        pub use core::pin::Pin;
+        pub use vtable::{self, VRef};
+
+        /// Same as in the items module, the vtable generates the signatures from it
+        pub type ItemRendererRef<'a> = &'a mut dyn ItemRenderer;
+
+        pub fn layout_info<T: CustomItem>(item: Pin<&T>, orientation: Orientation) -> LayoutInfo {
+            let info = item.layout_info(orientation);
+            LayoutInfo {
+                min: info.min as Coord,
+                max: info.max as Coord,
+                preferred: info.preferred as Coord,
+                stretch: info.stretch,
+                ..Default::default()
+            }
+        }
+
+        pub fn input_event<T: CustomItem>(item: Pin<&T>, event: MouseEvent) -> InputEventResult {
+            let event = match event {
+                MouseEvent::Pressed { position, button, click_count } => PointerInput::Pressed {
+                    position: logical_position_to_api(position),
+                    button,
+                    click_count,
+                },
+                MouseEvent::Released { position, button, click_count } => PointerInput::Released {
+                    position: logical_position_to_api(position),
+                    button,
+                    click_count,
+                },
+                MouseEvent::Moved { position } => {
+                    PointerInput::Moved { position: logical_position_to_api(position) }
+                }
+                MouseEvent::Wheel { position, delta_x, delta_y } => PointerInput::Wheel {
+                    position: logical_position_to_api(position),
+                    delta_x: delta_x as f32,
+                    delta_y: delta_y as f32,
+                },
+                MouseEvent::Exit => PointerInput::Exit,
+                _ => return InputEventResult::EventIgnored,
+            };
+            match item.pointer_event(event) {
+                InputResult::Ignored => InputEventResult::EventIgnored,
+                InputResult::Accepted => InputEventResult::EventAccepted,
+                InputResult::GrabPointer => InputEventResult::GrabMouse,
+            }
+        }
+
+        pub fn render<T: CustomItem>(
+            item: Pin<&T>,
+            backend: &mut ItemRendererRef,
+            size: LogicalSize,
+        ) -> RenderingResult {
+            item.render(&mut Renderer(&mut **backend), logical_size_to_api(size));
+            RenderingResult::ContinueRenderingChildren
+        }
+    }
+}
+
+/// Makes a type that implements [`custom_item::v1::CustomItem`](crate::custom_item::v1::CustomItem)
+/// usable as an element. The type must have a `cached_rendering_data` field.
+#[macro_export]
+macro_rules! declare_custom_item_v1 {
+    ($ty:ty) => {
+        const _: () = {
+            use $crate::custom_item::v1::__private::*;
+
+            impl Item for $ty {
+                fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+                fn layout_info(
+                    self: Pin<&Self>,
+                    orientation: Orientation,
+                    _window_adapter: &WindowAdapterRc,
+                ) -> LayoutInfo {
+                    layout_info(self, orientation)
+                }
+
+                fn input_event_filter_before_children(
+                    self: Pin<&Self>,
+                    _: MouseEvent,
+                    _window_adapter: &WindowAdapterRc,
+                    _self_rc: &ItemRc,
+                ) -> InputEventFilterResult {
+                    InputEventFilterResult::ForwardEvent
+                }
+
+                fn input_event(
+                    self: Pin<&Self>,
+                    event: MouseEvent,
+                    _window_adapter: &WindowAdapterRc,
+                    _self_rc: &ItemRc,
+                ) -> InputEventResult {
+                    input_event(self, event)
+                }
+
+                fn key_event(
+                    self: Pin<&Self>,
+                    _: &KeyEvent,
+                    _window_adapter: &WindowAdapterRc,
+                    _self_rc: &ItemRc,
+                ) -> KeyEventResult {
+                    KeyEventResult::EventIgnored
+                }
+
+                fn focus_event(
+                    self: Pin<&Self>,
+                    _: &FocusEvent,
+                    _window_adapter: &WindowAdapterRc,
+                    _self_rc: &ItemRc,
+                ) -> FocusEventResult {
+                    FocusEventResult::FocusIgnored
+                }
+
+                fn render(
+                    self: Pin<&Self>,
+                    backend: &mut ItemRendererRef,
+                    _self_rc: &ItemRc,
+                    size: LogicalSize,
+                ) -> RenderingResult {
+                    render(self, backend, size)
+                }
+            }
+
+            impl ItemConsts for $ty {
+                const cached_rendering_data_offset: vtable::FieldOffset<$ty, CachedRenderingData> =
+                    <$ty>::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+            }
+
+            ItemVTable_static! {
+                static VT for $ty
+            }
+        };
+    };
+}
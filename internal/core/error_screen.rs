@@ -0,0 +1,124 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    A fallback user interface that is shown when the application can't show its own one.
+
+    Devices without a desktop, such as kiosks, would otherwise be left with a dead screen when the
+    main component panics or fails to instantiate. Register a minimal component with
+    [`set_error_screen()`], for example one showing the error message or a QR code with
+    diagnostics, and it is shown by [`show_error_screen()`].
+*/
+
+#![warn(missing_docs)]
+
+use crate::api::{ComponentHandle, PlatformError};
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+use crate::SharedString;
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use core::cell::RefCell;
+
+/// Information about the error that caused the error screen to be shown.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ErrorScreenInfo {
+    /// A description of the error, for example the message of the panic.
+    pub message: SharedString,
+}
+
+/// Type erased handle of the shown error screen, that hides it when called.
+type ShownErrorScreen = Box<dyn FnOnce()>;
+
+type ErrorScreenFactory = Box<dyn Fn(&ErrorScreenInfo) -> Result<ShownErrorScreen, PlatformError>>;
+
+#[derive(Default)]
+struct ErrorScreenState {
+    factory: Option<ErrorScreenFactory>,
+    /// The instance that is currently shown, until the next error screen replaces it
+    shown: Option<ShownErrorScreen>,
+}
+
+thread_local! {
+    static ERROR_SCREEN: RefCell<ErrorScreenState> = RefCell::new(Default::default());
+}
+
+/// Registers the component that is shown when the application can't show its own user interface.
+///
+/// The `factory` receives the [`ErrorScreenInfo`] and returns a new instance of the component,
+/// which is then shown. Keep the component simple: it may be instantiated after the application
+/// panicked, so it shouldn't depend on the state of the application. A static image can be shown
+/// with a component that consists of just an `Image` element.
+///
+/// The LinuxKMS backend shows the error screen on the first output when the application panics
+/// while running the event loop. This requires the application to be built with `panic = "unwind"`,
+/// which is the default. Call [`show_error_screen()`] to show it in other cases, for example when
+/// the main component fails to instantiate.
+///
+/// ## Example
+/// ```rust,no_run
+/// # use slint::ComponentHandle;
+/// slint::slint! {
+///     export component ErrorScreen inherits Window {
+///         in property <string> message;
+///         background: darkred;
+///         Text { text: "Something went wrong:\n" + message; color: white; }
+///     }
+///     export component MainWindow inherits Window {}
+/// }
+///
+/// slint::platform::set_error_screen(|info| {
+///     let error_screen = ErrorScreen::new()?;
+///     error_screen.set_message(info.message.clone());
+///     Ok(error_screen)
+/// });
+///
+/// match MainWindow::new() {
+///     Ok(main_window) => main_window.run().unwrap(),
+///     Err(err) => {
+///         slint::platform::show_error_screen(err.to_string()).unwrap();
+///         slint::run_event_loop().unwrap();
+///     }
+/// }
+/// ```
+pub fn set_error_screen<Component: ComponentHandle + 'static>(
+    factory: impl Fn(&ErrorScreenInfo) -> Result<Component, PlatformError> + 'static,
+) {
+    ERROR_SCREEN.with(|state| {
+        state.borrow_mut().factory = Some(Box::new(move |info| {
+            let component = factory(info)?;
+            component.show()?;
+            Ok(Box::new(move || {
+                component.hide().ok();
+            }) as ShownErrorScreen)
+        }))
+    })
+}
+
+/// Returns true if an error screen was registered with [`set_error_screen()`].
+pub fn has_error_screen() -> bool {
+    ERROR_SCREEN.with(|state| state.borrow().factory.is_some())
+}
+
+/// Instantiates and shows the error screen registered with [`set_error_screen()`], with the given
+/// error message. An error screen that is already shown is replaced.
+///
+/// Returns an error if no error screen was registered, or if it couldn't be shown.
+pub fn show_error_screen(message: impl Into<SharedString>) -> Result<(), PlatformError> {
+    let info = ErrorScreenInfo { message: message.into() };
+    // Take the factory out while calling it, so that it may register a different error screen
+    let factory =
+        ERROR_SCREEN.with(|state| state.borrow_mut().factory.take()).ok_or_else(|| {
+            PlatformError::Other("No error screen was registered with set_error_screen()".into())
+        })?;
+    if let Some(hide_previous) = ERROR_SCREEN.with(|state| state.borrow_mut().shown.take()) {
+        hide_previous();
+    }
+    let result = factory(&info);
+    ERROR_SCREEN.with(|state| {
+        let mut state = state.borrow_mut();
+        state.factory.get_or_insert(factory);
+        result.map(|shown| state.shown = Some(shown))
+    })
+}
@@ -56,6 +56,9 @@
 pub mod border_radius;
 pub use border_radius::*;
 
+pub mod border_edges;
+pub use border_edges::*;
+
 /// CachedGraphicsData allows the graphics backend to store an arbitrary piece of data associated with
 /// an item, which is typically computed by accessing properties. The dependency_tracker is used to allow
 /// for a lazy computation. Typically back ends store either compute intensive data or handles that refer to
@@ -148,8 +151,95 @@ pub struct FontRequest {
     /// The additional spacing (or shrinking if negative) between glyphs. This is usually not submitted to
     /// the font-subsystem but collected here for API convenience
     pub letter_spacing: Option<LogicalLength>,
+    /// The height of the lines of a paragraph, instead of the height of the font. Like the letter spacing,
+    /// this is not submitted to the font-subsystem.
+    pub line_height: Option<LogicalLength>,
     /// Whether to select an italic face of the font family.
     pub italic: bool,
+    /// The positions on the variation axes of a variable font, such as `wdth` for the width.
+    /// Axes that are not listed keep their default value.
+    pub variations: alloc::vec::Vec<FontVariation>,
+}
+
+/// The position on one variation axis of an OpenType variable font.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontVariation {
+    /// The four letter tag of the axis, such as `wght`, `wdth`, `slnt`, or the tag of a custom axis.
+    pub tag: [u8; 4],
+    /// The value on the axis, in the units of the axis.
+    pub value: f32,
+}
+
+impl FontVariation {
+    /// Parses a comma separated list of axis tags and values, in the format of the CSS
+    /// `font-variation-settings` property, such as `"'wdth' 75, 'slnt' -10"`. The quotes
+    /// around the tags are optional. Invalid entries are ignored.
+    pub fn parse_settings(settings: &str) -> alloc::vec::Vec<Self> {
+        settings
+            .split(',')
+            .filter_map(|setting| {
+                let (tag, value) = setting.trim().rsplit_once(char::is_whitespace)?;
+                let tag = tag.trim().trim_matches(|c| c == '\'' || c == '"');
+                Some(Self { tag: tag.as_bytes().try_into().ok()?, value: value.parse().ok()? })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static FONT_FALLBACK_FAMILIES: core::cell::RefCell<alloc::vec::Vec<SharedString>> =
+        core::cell::RefCell::new(
+            std::env::var("SLINT_FONT_FALLBACK")
+                .map(|families| {
+                    families
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|family| !family.is_empty())
+                        .map(SharedString::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        );
+}
+
+/// Sets the font families that are used, in the given order, to render characters for which the
+/// requested font family has no glyph, such as CJK characters or emoji. These families are tried
+/// before the fallback fonts of the system.
+///
+/// By default, the list is read from the `SLINT_FONT_FALLBACK` environment variable, as a comma
+/// separated list of family names.
+///
+/// Fonts that were already used for rendering text are not affected, so this function should
+/// be called before showing any window.
+///
+/// Note: This is supported by the FemtoVG and Skia renderers.
+///
+/// ## Example
+/// ```rust,no_run
+/// slint::set_font_fallback_families(["Noto Sans CJK JP", "Noto Color Emoji"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn set_font_fallback_families(families: impl IntoIterator<Item = impl Into<SharedString>>) {
+    let families = families.into_iter().map(Into::into).collect();
+    FONT_FALLBACK_FAMILIES.with(|fallback_families| *fallback_families.borrow_mut() = families);
+}
+
+#[cfg(feature = "std")]
+impl FontRequest {
+    /// Returns the font families to try, in order, for characters that are missing in the requested
+    /// family, as configured with [`set_font_fallback_families()`]. The requested family itself is
+    /// not part of the list.
+    pub fn fallback_families(&self) -> alloc::vec::Vec<SharedString> {
+        FONT_FALLBACK_FAMILIES.with(|fallback_families| {
+            fallback_families
+                .borrow()
+                .iter()
+                .filter(|family| self.family.as_ref() != Some(*family))
+                .cloned()
+                .collect()
+        })
+    }
 }
 
 #[cfg(feature = "shared-fontdb")]
@@ -236,3 +326,20 @@ pub fn physical_position_to_api(
         crate::api::PhysicalPosition::from_euclid(position)
     }
 }
+
+#[test]
+fn test_parse_font_variation_settings() {
+    assert_eq!(
+        FontVariation::parse_settings("'wdth' 75, \"slnt\" -10.5,GRAD 20"),
+        [
+            FontVariation { tag: *b"wdth", value: 75. },
+            FontVariation { tag: *b"slnt", value: -10.5 },
+            FontVariation { tag: *b"GRAD", value: 20. },
+        ]
+    );
+    assert_eq!(FontVariation::parse_settings(""), []);
+    assert_eq!(
+        FontVariation::parse_settings("toolong 10, wdth, wght 500"),
+        [FontVariation { tag: *b"wght", value: 500. }]
+    );
+}
@@ -0,0 +1,210 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the types and the geometry of the borders whose edges have their own
+width and color, for the run-time library.
+*/
+
+use super::{BorderRadius, Brush};
+use crate::lengths::{LogicalLength, LogicalPx};
+use crate::Coord;
+use alloc::vec::Vec;
+use core::f32::consts::{FRAC_PI_2, PI};
+use euclid::{Point2D, SideOffsets2D, Size2D};
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
+
+/// The width and the brush of one edge of a border.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BorderEdge {
+    /// The width of the edge.
+    pub width: LogicalLength,
+    /// The brush that fills the edge.
+    pub color: Brush,
+}
+
+/// The top, right, bottom, and left edges of a border.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BorderEdges {
+    /// The top edge.
+    pub top: BorderEdge,
+    /// The right edge.
+    pub right: BorderEdge,
+    /// The bottom edge.
+    pub bottom: BorderEdge,
+    /// The left edge.
+    pub left: BorderEdge,
+}
+
+impl BorderEdges {
+    /// Returns the edges in top, right, bottom, left order, following CSS's convention.
+    pub fn to_array(&self) -> [&BorderEdge; 4] {
+        [&self.top, &self.right, &self.bottom, &self.left]
+    }
+
+    /// Returns the width of each edge.
+    pub fn widths(&self) -> SideOffsets2D<Coord, LogicalPx> {
+        SideOffsets2D::from_lengths(
+            self.top.width,
+            self.right.width,
+            self.bottom.width,
+            self.left.width,
+        )
+    }
+}
+
+/// Returns the area that each edge of the border of a rounded rectangle of the given `size` covers,
+/// in top, right, bottom, left order.
+///
+/// Each area is described by two convex polygons: the outline of the rectangle and the outline of
+/// the inside of the border, both cut to the part of the rectangle that belongs to the edge. The
+/// edge is what lies between them, so the two polygons are meant to be filled together with the
+/// even-odd fill rule. Like in CSS, adjacent edges meet on the line between the outer and the
+/// inner corner of the border. The polygons of an edge without width are empty.
+pub fn border_edge_polygons<U>(
+    size: Size2D<f32, U>,
+    radius: BorderRadius<f32, U>,
+    widths: SideOffsets2D<f32, U>,
+) -> [[Vec<Point2D<f32, U>>; 2]; 4] {
+    let (width, height) = (size.width, size.height);
+    let max_radius = width.min(height).max(0.) / 2.;
+    let radii = [radius.top_left, radius.top_right, radius.bottom_right, radius.bottom_left]
+        .map(|r| r.max(0.).min(max_radius));
+    let [top, right, bottom, left] =
+        [widths.top, widths.right, widths.bottom, widths.left].map(|w| w.max(0.));
+
+    let outer = rounded_rect_polygon(
+        Point2D::new(0., 0.),
+        Point2D::new(width, height),
+        radii.map(|r| Size2D::new(r, r)),
+    );
+    let inner_min = Point2D::new(left, top);
+    let inner_max = Point2D::new(width - right, height - bottom);
+    let inner = if inner_min.x < inner_max.x && inner_min.y < inner_max.y {
+        rounded_rect_polygon(
+            inner_min,
+            inner_max,
+            [
+                (radii[0] - left, radii[0] - top),
+                (radii[1] - right, radii[1] - top),
+                (radii[2] - right, radii[2] - bottom),
+                (radii[3] - left, radii[3] - bottom),
+            ]
+            .map(|(rx, ry)| Size2D::new(rx.max(0.), ry.max(0.))),
+        )
+    } else {
+        Vec::new()
+    };
+
+    // The distance of a point to each edge, as the coefficients (a, b, c) of a * x + b * y + c
+    let edges = [
+        (top, (0., 1., 0.)),
+        (right, (-1., 0., width)),
+        (bottom, (0., -1., height)),
+        (left, (1., 0., 0.)),
+    ];
+    core::array::from_fn(|index| {
+        let (edge_width, (a, b, c)) = edges[index];
+        if edge_width <= 0. {
+            return [Vec::new(), Vec::new()];
+        }
+        // A point belongs to the edge it's the closest to, relative to the width of the edges.
+        // That's the half-plane where distance / edge_width <= other_distance / other_width.
+        let half_planes = edges.iter().enumerate().filter(|(i, _)| *i != index).map(
+            |(_, &(other_width, (oa, ob, oc)))| {
+                let coefficient = |edge: f32, other: f32| other * edge_width - edge * other_width;
+                (coefficient(a, oa), coefficient(b, ob), coefficient(c, oc))
+            },
+        );
+        [outer.clone(), inner.clone()]
+            .map(|polygon| half_planes.clone().fold(polygon, clip_polygon))
+    })
+}
+
+/// Returns the outline of the rectangle from `min` to `max` whose corners are rounded with the
+/// elliptic `radii`, in top-left, top-right, bottom-right, bottom-left order.
+fn rounded_rect_polygon<U>(
+    min: Point2D<f32, U>,
+    max: Point2D<f32, U>,
+    radii: [Size2D<f32, U>; 4],
+) -> Vec<Point2D<f32, U>> {
+    // The center of each corner and the angle at which its arc starts, going clockwise
+    let corners = [
+        (min.x + radii[0].width, min.y + radii[0].height, PI),
+        (max.x - radii[1].width, min.y + radii[1].height, PI + FRAC_PI_2),
+        (max.x - radii[2].width, max.y - radii[2].height, 0.),
+        (min.x + radii[3].width, max.y - radii[3].height, FRAC_PI_2),
+    ];
+    let mut points = Vec::new();
+    for ((center_x, center_y, start_angle), radius) in corners.into_iter().zip(radii) {
+        let segments = if radius.width > 0. && radius.height > 0. {
+            (radius.width.max(radius.height).sqrt() * 2.).ceil().min(32.) as u32
+        } else {
+            0
+        };
+        for i in 0..=segments {
+            let angle = start_angle + FRAC_PI_2 * i as f32 / segments.max(1) as f32;
+            points.push(Point2D::new(
+                center_x + radius.width * angle.cos(),
+                center_y + radius.height * angle.sin(),
+            ));
+        }
+    }
+    points
+}
+
+/// Clips the convex `polygon` to the half-plane where a * x + b * y + c >= 0
+fn clip_polygon<U>(
+    polygon: Vec<Point2D<f32, U>>,
+    (a, b, c): (f32, f32, f32),
+) -> Vec<Point2D<f32, U>> {
+    let distance = |p: Point2D<f32, U>| a * p.x + b * p.y + c;
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &point) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        let (d, next_d) = (distance(point), distance(next));
+        if d >= 0. {
+            clipped.push(point);
+        }
+        if (d >= 0.) != (next_d >= 0.) {
+            clipped.push(point.lerp(next, d / (d - next_d)));
+        }
+    }
+    clipped
+}
+
+#[test]
+fn test_border_edge_polygons() {
+    use euclid::num::Zero;
+    type Point = euclid::default::Point2D<f32>;
+    fn area(polygon: &[Point]) -> f32 {
+        let n = polygon.len();
+        (0..n).map(|i| polygon[i].to_vector().cross(polygon[(i + 1) % n].to_vector())).sum::<f32>()
+            / 2.
+    }
+    fn edge_areas(widths: SideOffsets2D<f32, euclid::UnknownUnit>) -> [f32; 4] {
+        border_edge_polygons(Size2D::new(10., 10.), BorderRadius::zero(), widths)
+            .map(|[outer, inner]| area(&outer) - area(&inner))
+    }
+
+    fn assert_areas(widths: SideOffsets2D<f32, euclid::UnknownUnit>, expected: [f32; 4]) {
+        let areas = edge_areas(widths);
+        assert!(areas.iter().zip(expected).all(|(a, e)| (a - e).abs() < 0.01), "{areas:?}");
+    }
+
+    // The edges are trapezoids that meet on the diagonals
+    assert_areas(SideOffsets2D::new_all_same(1.), [9.; 4]);
+    assert_areas(SideOffsets2D::new(2., 0., 0., 0.), [20., 0., 0., 0.]);
+    assert_areas(SideOffsets2D::new(2., 0., 0., 2.), [18., 0., 0., 18.]);
+    // The edges cover the whole rectangle when they're wider than it
+    assert_areas(SideOffsets2D::new_all_same(6.), [25.; 4]);
+
+    let [[outer, inner], _, _, _] = border_edge_polygons(
+        Size2D::new(10., 10.),
+        BorderRadius::new_uniform(5.),
+        SideOffsets2D::new_all_same(1.),
+    );
+    let expected = core::f32::consts::PI * (25. - 16.) / 4.;
+    assert!((area(&outer) - area(&inner) - expected).abs() < 0.1);
+}
@@ -29,6 +29,9 @@ pub enum Brush {
     /// The radial gradient variant of a brush describes a circle variant centered
     /// in the middle
     RadialGradient(RadialGradientBrush),
+    /// The conic gradient variant of a brush describes the gradient stops for a fill
+    /// where the colors sweep clockwise around the center, starting at the specified angle.
+    ConicGradient(ConicGradientBrush),
 }
 
 /// Construct a brush with transparent color
@@ -50,6 +53,9 @@ pub fn color(&self) -> Color {
             Brush::RadialGradient(gradient) => {
                 gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
             }
+            Brush::ConicGradient(gradient) => {
+                gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
+            }
         }
     }
 
@@ -66,6 +72,7 @@ pub fn is_transparent(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 0,
             Brush::LinearGradient(_) => false,
             Brush::RadialGradient(_) => false,
+            Brush::ConicGradient(_) => false,
         }
     }
 
@@ -82,6 +89,7 @@ pub fn is_opaque(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 255,
             Brush::LinearGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
             Brush::RadialGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::ConicGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
         }
     }
 
@@ -92,18 +100,30 @@ pub fn is_opaque(&self) -> bool {
     pub fn brighter(&self, factor: f32) -> Self {
         match self {
             Brush::SolidColor(c) => Brush::SolidColor(c.brighter(factor)),
-            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
-                g.angle(),
+            Brush::LinearGradient(g) => Brush::LinearGradient(
+                LinearGradientBrush::new(
+                    g.angle(),
+                    g.stops().map(|s| GradientStop {
+                        color: s.color.brighter(factor),
+                        position: s.position,
+                    }),
+                )
+                .with_spread(g.spread()),
+            ),
+            Brush::RadialGradient(g) => Brush::RadialGradient(
+                RadialGradientBrush::new_circle(g.stops().map(|s| GradientStop {
+                    color: s.color.brighter(factor),
+                    position: s.position,
+                }))
+                .with_spread(g.spread()),
+            ),
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
                 g.stops().map(|s| GradientStop {
                     color: s.color.brighter(factor),
                     position: s.position,
                 }),
             )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.brighter(factor), position: s.position }
-                })))
-            }
         }
     }
 
@@ -114,12 +134,27 @@ pub fn brighter(&self, factor: f32) -> Self {
     pub fn darker(&self, factor: f32) -> Self {
         match self {
             Brush::SolidColor(c) => Brush::SolidColor(c.darker(factor)),
-            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
-                g.angle(),
-                g.stops()
-                    .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
-            )),
-            Brush::RadialGradient(g) => Brush::RadialGradient(RadialGradientBrush::new_circle(
+            Brush::LinearGradient(g) => Brush::LinearGradient(
+                LinearGradientBrush::new(
+                    g.angle(),
+                    g.stops().map(|s| GradientStop {
+                        color: s.color.darker(factor),
+                        position: s.position,
+                    }),
+                )
+                .with_spread(g.spread()),
+            ),
+            Brush::RadialGradient(g) => {
+                Brush::RadialGradient(
+                    RadialGradientBrush::new_circle(g.stops().map(|s| GradientStop {
+                        color: s.color.darker(factor),
+                        position: s.position,
+                    }))
+                    .with_spread(g.spread()),
+                )
+            }
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
                 g.stops()
                     .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
             )),
@@ -135,18 +170,30 @@ pub fn darker(&self, factor: f32) -> Self {
     pub fn transparentize(&self, amount: f32) -> Self {
         match self {
             Brush::SolidColor(c) => Brush::SolidColor(c.transparentize(amount)),
-            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
-                g.angle(),
+            Brush::LinearGradient(g) => Brush::LinearGradient(
+                LinearGradientBrush::new(
+                    g.angle(),
+                    g.stops().map(|s| GradientStop {
+                        color: s.color.transparentize(amount),
+                        position: s.position,
+                    }),
+                )
+                .with_spread(g.spread()),
+            ),
+            Brush::RadialGradient(g) => Brush::RadialGradient(
+                RadialGradientBrush::new_circle(g.stops().map(|s| GradientStop {
+                    color: s.color.transparentize(amount),
+                    position: s.position,
+                }))
+                .with_spread(g.spread()),
+            ),
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
                 g.stops().map(|s| GradientStop {
                     color: s.color.transparentize(amount),
                     position: s.position,
                 }),
             )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.transparentize(amount), position: s.position }
-                })))
-            }
         }
     }
 
@@ -156,20 +203,138 @@ pub fn transparentize(&self, amount: f32) -> Self {
     pub fn with_alpha(&self, alpha: f32) -> Self {
         match self {
             Brush::SolidColor(c) => Brush::SolidColor(c.with_alpha(alpha)),
-            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
-                g.angle(),
+            Brush::LinearGradient(g) => Brush::LinearGradient(
+                LinearGradientBrush::new(
+                    g.angle(),
+                    g.stops().map(|s| GradientStop {
+                        color: s.color.with_alpha(alpha),
+                        position: s.position,
+                    }),
+                )
+                .with_spread(g.spread()),
+            ),
+            Brush::RadialGradient(g) => Brush::RadialGradient(
+                RadialGradientBrush::new_circle(g.stops().map(|s| GradientStop {
+                    color: s.color.with_alpha(alpha),
+                    position: s.position,
+                }))
+                .with_spread(g.spread()),
+            ),
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush::new(
+                g.from_angle(),
                 g.stops().map(|s| GradientStop {
                     color: s.color.with_alpha(alpha),
                     position: s.position,
                 }),
             )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.with_alpha(alpha), position: s.position }
-                })))
+        }
+    }
+}
+
+/// How a gradient fills the shape before its first stop and after its last stop.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum GradientSpread {
+    /// The color of the first stop is used before it, and the color of the last stop after it.
+    #[default]
+    Pad,
+    /// The stops are repeated, so that the gradient starts again at the first stop after the last one.
+    Repeat,
+    /// The stops are repeated, going back and forth between the first and the last stop.
+    Reflect,
+}
+
+impl GradientSpread {
+    fn encode(self) -> Color {
+        Color::from_argb_encoded(self as u32)
+    }
+
+    fn decode(color: Color) -> Self {
+        match color.as_argb_encoded() {
+            1 => Self::Repeat,
+            2 => Self::Reflect,
+            _ => Self::Pad,
+        }
+    }
+
+    /// Returns the position between `first` and `last` that has the same color as `position`
+    /// in a gradient whose first and last stops are at these positions.
+    fn map_position(self, position: f32, first: f32, last: f32) -> f32 {
+        let length = last - first;
+        if length <= 0. {
+            return position;
+        }
+        match self {
+            Self::Pad => position,
+            Self::Repeat => {
+                let t = (position - first) / length;
+                first + (t - t.floor()) * length
             }
+            Self::Reflect => {
+                let t = (position - first) / (2. * length);
+                let t = (t - t.floor()) * 2.;
+                let t = if t > 1. { 2. - t } else { t };
+                first + t * length
+            }
+        }
+    }
+}
+
+/// Returns the color at `position` of a gradient with these stops, which are sorted by position
+fn color_at_position<'a>(stops: impl Iterator<Item = &'a GradientStop>, position: f32) -> Color {
+    let mut previous: Option<&GradientStop> = None;
+    for stop in stops {
+        if position < stop.position {
+            return match previous {
+                Some(p) if p.position < stop.position => p.color.interpolate(
+                    &stop.color,
+                    (position - p.position) / (stop.position - p.position),
+                ),
+                _ => stop.color,
+            };
         }
+        previous = Some(stop);
     }
+    previous.map(|stop| stop.color).unwrap_or_default()
+}
+
+fn gradient_color_at<'a>(
+    stops: impl Iterator<Item = &'a GradientStop> + Clone,
+    spread: GradientSpread,
+    position: f32,
+) -> Color {
+    let position = if spread == GradientSpread::Pad {
+        position
+    } else {
+        let mut positions = stops.clone().map(|s| s.position);
+        let first = positions.next().unwrap_or(0.);
+        let last = positions.last().unwrap_or(first);
+        spread.map_position(position, first, last)
+    };
+    color_at_position(stops, position)
+}
+
+/// Returns the positions of the first and the last stop of a gradient, which is the range that's
+/// repeated by the [`GradientSpread::Repeat`] and [`GradientSpread::Reflect`] spreads, and the
+/// stops with their positions relative to this range.
+///
+/// This is used by the renderers that repeat gradients over the range from 0 to 1.
+pub fn normalize_gradient_stops<'a>(
+    stops: impl Iterator<Item = &'a GradientStop> + Clone,
+) -> (f32, f32, impl Iterator<Item = GradientStop> + 'a) {
+    let mut positions = stops.clone().map(|s| s.position);
+    let first = positions.next().unwrap_or(0.);
+    let last = positions.last().unwrap_or(first);
+    let (first, last) = if last > first { (first, last) } else { (0., 1.) };
+    (
+        first,
+        last,
+        stops.map(move |s| GradientStop {
+            color: s.color,
+            position: (s.position - first) / (last - first),
+        }),
+    )
 }
 
 /// The LinearGradientBrush describes a way of filling a shape with different colors, which
@@ -192,16 +357,32 @@ pub fn new(angle: f32, stops: impl IntoIterator<Item = GradientStop>) -> Self {
         encoded_angle_and_stops.extend(stop_iter);
         Self(encoded_angle_and_stops)
     }
+    /// Returns a copy of this gradient with the specified spread.
+    #[must_use]
+    pub fn with_spread(mut self, spread: GradientSpread) -> Self {
+        self.0.make_mut_slice()[0].color = spread.encode();
+        self
+    }
     /// Returns the angle of the linear gradient in degrees.
     pub fn angle(&self) -> f32 {
         self.0[0].position
     }
+    /// Returns how the gradient fills the shape beyond its first and last stops.
+    pub fn spread(&self) -> GradientSpread {
+        // The color of the fake stop contains the spread
+        GradientSpread::decode(self.0[0].color)
+    }
     /// Returns the color stops of the linear gradient.
     /// The stops are sorted by positions.
     pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
         // skip the first fake stop that just contains the angle
         self.0.iter().skip(1)
     }
+    /// Returns the color of the gradient at `position` along its line, taking the spread into
+    /// account. This is used by the renderers that compute the gradient themselves.
+    pub fn color_at(&self, position: f32) -> Color {
+        gradient_color_at(self.stops(), self.spread(), position)
+    }
 }
 
 /// The RadialGradientBrush describes a way of filling a shape with a circular gradient
@@ -213,11 +394,70 @@ impl RadialGradientBrush {
     /// Creates a new circle radial gradient, centered in the middle and described
     /// by the provided color stops.
     pub fn new_circle(stops: impl IntoIterator<Item = GradientStop>) -> Self {
-        Self(stops.into_iter().collect())
+        let stop_iter = stops.into_iter();
+        let mut encoded_spread_and_stops = SharedVector::with_capacity(stop_iter.size_hint().0 + 1);
+        // The gradient's first stop is a fake stop to store the spread
+        encoded_spread_and_stops.push(GradientStop { color: Default::default(), position: 0. });
+        encoded_spread_and_stops.extend(stop_iter);
+        Self(encoded_spread_and_stops)
     }
-    /// Returns the color stops of the linear gradient.
+    /// Returns a copy of this gradient with the specified spread.
+    #[must_use]
+    pub fn with_spread(mut self, spread: GradientSpread) -> Self {
+        self.0.make_mut_slice()[0].color = spread.encode();
+        self
+    }
+    /// Returns how the gradient fills the shape beyond its first and last stops.
+    pub fn spread(&self) -> GradientSpread {
+        GradientSpread::decode(self.0[0].color)
+    }
+    /// Returns the color stops of the radial gradient.
+    pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
+        // skip the first fake stop that just contains the spread
+        self.0.iter().skip(1)
+    }
+    /// Returns the color of the gradient at `position`, as a fraction of the radius, taking the
+    /// spread into account. This is used by the renderers that compute the gradient themselves.
+    pub fn color_at(&self, position: f32) -> Color {
+        gradient_color_at(self.stops(), self.spread(), position)
+    }
+}
+
+/// The ConicGradientBrush describes a way of filling a shape with colors that sweep clockwise
+/// around its center. The positions of the stops are fractions of a full turn, starting at the
+/// gradient's angle from the top.
+#[derive(Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ConicGradientBrush(SharedVector<GradientStop>);
+
+impl ConicGradientBrush {
+    /// Creates a new conic gradient, which starts at the specified angle and is described by the
+    /// provided color stops.
+    ///
+    /// The angle need to be specified in degrees, clockwise from the top.
+    pub fn new(from_angle: f32, stops: impl IntoIterator<Item = GradientStop>) -> Self {
+        let stop_iter = stops.into_iter();
+        let mut encoded_angle_and_stops = SharedVector::with_capacity(stop_iter.size_hint().0 + 1);
+        // The gradient's first stop is a fake stop to store the angle
+        encoded_angle_and_stops
+            .push(GradientStop { color: Default::default(), position: from_angle });
+        encoded_angle_and_stops.extend(stop_iter);
+        Self(encoded_angle_and_stops)
+    }
+    /// Returns the angle in degrees, clockwise from the top, at which the conic gradient starts.
+    pub fn from_angle(&self) -> f32 {
+        self.0[0].position
+    }
+    /// Returns the color stops of the conic gradient.
     pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
-        self.0.iter()
+        // skip the first fake stop that just contains the angle
+        self.0.iter().skip(1)
+    }
+    /// Returns the color of the conic gradient at the specified angle in degrees, clockwise from
+    /// the top. This is used by the renderers that compute the gradient themselves.
+    pub fn color_at_angle(&self, angle: f32) -> Color {
+        let position = (angle - self.from_angle()) / 360.;
+        color_at_position(self.stops(), position - position.floor())
     }
 }
 
@@ -302,7 +542,7 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
             }
             (Brush::SolidColor(col), Brush::RadialGradient(grad)) => {
                 let mut new_grad = grad.clone();
-                for x in new_grad.0.make_mut_slice().iter_mut() {
+                for x in new_grad.0.make_mut_slice().iter_mut().skip(1) {
                     x.color = col.interpolate(&x.color, t);
                 }
                 Brush::RadialGradient(new_grad)
@@ -315,7 +555,8 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                     Self::interpolate(target_value, self, 1. - t)
                 } else {
                     let mut new_grad = lhs.clone();
-                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
+                    // skip the fake stop that contains the spread
+                    let mut iter = new_grad.0.make_mut_slice().iter_mut().skip(1);
                     let mut last_color = Color::default();
                     for s2 in rhs.stops() {
                         let s1 = iter.next().unwrap();
@@ -330,8 +571,46 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                     Brush::RadialGradient(new_grad)
                 }
             }
+            (Brush::SolidColor(col), Brush::ConicGradient(grad)) => {
+                let mut new_grad = grad.clone();
+                for x in new_grad.0.make_mut_slice().iter_mut().skip(1) {
+                    x.color = col.interpolate(&x.color, t);
+                }
+                Brush::ConicGradient(new_grad)
+            }
+            (a @ Brush::ConicGradient(_), b @ Brush::SolidColor(_)) => {
+                Self::interpolate(b, a, 1. - t)
+            }
+            (Brush::ConicGradient(lhs), Brush::ConicGradient(rhs)) => {
+                if lhs.0.len() < rhs.0.len() {
+                    Self::interpolate(target_value, self, 1. - t)
+                } else {
+                    let mut new_grad = lhs.clone();
+                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
+                    {
+                        let angle = &mut iter.next().unwrap().position;
+                        *angle = angle.interpolate(&rhs.from_angle(), t);
+                    }
+                    let mut last_color = Color::default();
+                    for s2 in rhs.stops() {
+                        let s1 = iter.next().unwrap();
+                        last_color = s2.color;
+                        s1.color = s1.color.interpolate(&s2.color, t);
+                        s1.position = s1.position.interpolate(&s2.position, t);
+                    }
+                    for x in iter {
+                        x.position = x.position.interpolate(&1.0, t);
+                        x.color = x.color.interpolate(&last_color, t);
+                    }
+                    Brush::ConicGradient(new_grad)
+                }
+            }
             (a @ Brush::LinearGradient(_), b @ Brush::RadialGradient(_))
-            | (a @ Brush::RadialGradient(_), b @ Brush::LinearGradient(_)) => {
+            | (a @ Brush::RadialGradient(_), b @ Brush::LinearGradient(_))
+            | (a @ Brush::LinearGradient(_), b @ Brush::ConicGradient(_))
+            | (a @ Brush::ConicGradient(_), b @ Brush::LinearGradient(_))
+            | (a @ Brush::RadialGradient(_), b @ Brush::ConicGradient(_))
+            | (a @ Brush::ConicGradient(_), b @ Brush::RadialGradient(_)) => {
                 // Just go to an intermediate color.
                 let color = Color::interpolate(&b.color(), &a.color(), t);
                 if t < 0.5 {
@@ -357,3 +636,47 @@ fn test_linear_gradient_encoding() {
     assert_eq!(grad.angle(), 256.);
     assert!(grad.stops().eq(stops.iter()));
 }
+
+#[test]
+#[allow(clippy::float_cmp)] // We want bit-wise equality here
+fn test_conic_gradient_encoding() {
+    let stops: SharedVector<GradientStop> = [
+        GradientStop { position: 0.0, color: Color::from_argb_u8(255, 255, 0, 0) },
+        GradientStop { position: 1.0, color: Color::from_argb_u8(255, 0, 0, 255) },
+    ]
+    .into();
+    let grad = ConicGradientBrush::new(45., stops.clone());
+    assert_eq!(grad.from_angle(), 45.);
+    assert!(grad.stops().eq(stops.iter()));
+    let darker = Brush::ConicGradient(grad).darker(0.5);
+    let Brush::ConicGradient(darker) = darker else { panic!("not a conic gradient") };
+    assert_eq!(darker.from_angle(), 45.);
+    assert_eq!(darker.stops().count(), 2);
+}
+
+#[test]
+fn test_gradient_spread() {
+    let stops = [
+        GradientStop { position: 0.2, color: Color::from_rgb_u8(0, 0, 0) },
+        GradientStop { position: 0.4, color: Color::from_rgb_u8(200, 200, 200) },
+    ];
+    let grad = LinearGradientBrush::new(90., stops);
+    assert_eq!(grad.spread(), GradientSpread::Pad);
+    assert_eq!(grad.color_at(0.9), Color::from_rgb_u8(200, 200, 200));
+
+    let grad = grad.with_spread(GradientSpread::Repeat);
+    assert_eq!(grad.spread(), GradientSpread::Repeat);
+    assert_eq!(grad.angle(), 90.);
+    assert_eq!(grad.stops().count(), 2);
+    assert_eq!(grad.color_at(0.7), Color::from_rgb_u8(100, 100, 100));
+
+    let grad = RadialGradientBrush::new_circle(stops).with_spread(GradientSpread::Reflect);
+    assert_eq!(grad.spread(), GradientSpread::Reflect);
+    assert!(grad.stops().eq(stops.iter()));
+    assert_eq!(grad.color_at(0.5), Color::from_rgb_u8(100, 100, 100));
+    assert_eq!(grad.color_at(0.6), Color::from_rgb_u8(0, 0, 0));
+
+    let brush = Brush::RadialGradient(grad).darker(0.5);
+    let Brush::RadialGradient(grad) = brush else { panic!("not a radial gradient") };
+    assert_eq!(grad.spread(), GradientSpread::Reflect);
+}
@@ -8,6 +8,7 @@
 use crate::lengths::{PhysicalPx, ScaleFactor};
 use crate::slice::Slice;
 use crate::{SharedString, SharedVector};
+use alloc::vec::Vec;
 
 use super::{IntRect, IntSize};
 use crate::items::{ImageFit, ImageHorizontalAlignment, ImageTiling, ImageVerticalAlignment};
@@ -18,6 +19,8 @@
 mod htmlimage;
 #[cfg(feature = "svg")]
 mod svg;
+mod url;
+pub use url::{image_url_error, image_url_loading, load_image_from_url};
 
 #[allow(missing_docs)]
 #[vtable::vtable]
@@ -248,6 +251,20 @@ pub fn bpp(self) -> usize {
     }
 }
 
+#[repr(u8)]
+#[derive(Clone, PartialEq, Debug, Copy, Default)]
+/// How the pixels of a StaticTexture are stored
+pub enum TextureCompression {
+    /// The rows of pixels follow each other without any compression.
+    #[default]
+    None,
+    /// Each row is run-length encoded, so that it can be decompressed while rendering the line.
+    /// The pixels start with the offset of each row from `index`, as little-endian 32 bits
+    /// integers. A row is a list of packets that start with a byte `h`: if `h & 0x80` is set,
+    /// the pixel that follows is repeated `(h & 0x7f) + 1` times, otherwise `h + 1` pixels follow.
+    Rle,
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, Debug)]
 /// Some raw pixel data which is typically stored in the binary
@@ -260,6 +277,41 @@ pub struct StaticTexture {
     pub color: crate::Color,
     /// index in the data array
     pub index: usize,
+    /// How the pixels are stored in the data array
+    pub compression: TextureCompression,
+}
+
+impl StaticTexture {
+    /// Returns the pixels of the row `y` of this texture, where `data` is the data array of the
+    /// [`StaticTextures`]. The row is decompressed in `buffer` if the texture is compressed.
+    pub fn row<'a>(&self, data: &'a [u8], y: usize, buffer: &'a mut Vec<u8>) -> &'a [u8] {
+        let bpp = self.format.bpp();
+        let row_len = self.rect.width() as usize * bpp;
+        match self.compression {
+            TextureCompression::None => &data[self.index + y * row_len..][..row_len],
+            TextureCompression::Rle => {
+                let offset = &data[self.index + y * 4..][..4];
+                let mut source = &data[self.index
+                    + u32::from_le_bytes([offset[0], offset[1], offset[2], offset[3]]) as usize..];
+                buffer.clear();
+                while buffer.len() < row_len {
+                    let header = source[0] as usize;
+                    if header & 0x80 != 0 {
+                        let pixel = &source[1..1 + bpp];
+                        for _ in 0..=(header & 0x7f) {
+                            buffer.extend_from_slice(pixel);
+                        }
+                        source = &source[1 + bpp..];
+                    } else {
+                        let len = (header + 1) * bpp;
+                        buffer.extend_from_slice(&source[1..1 + len]);
+                        source = &source[1 + len..];
+                    }
+                }
+                &buffer[..row_len]
+            }
+        }
+    }
 }
 
 #[repr(C)]
@@ -424,11 +476,12 @@ pub fn render_to_buffer(
                     SharedPixelBuffer::<Rgba8Pixel>::new(ts.size.width, ts.size.height);
                 let stride = buffer.width() as usize;
                 let slice = buffer.make_mut_slice();
+                let mut row = Vec::new();
                 for t in ts.textures.iter() {
                     let rect = t.rect.to_usize();
                     for y in 0..rect.height() {
                         let slice = &mut slice[(rect.min_y() + y) * stride..][rect.x_range()];
-                        let source = &ts.data[t.index + y * rect.width() * t.format.bpp()..];
+                        let source = t.row(&ts.data, y, &mut row);
                         match t.format {
                             PixelFormat::Rgb => {
                                 let mut iter = source.chunks_exact(3).map(|p| Rgba8Pixel {
@@ -976,6 +1029,56 @@ fn test_image_invalid_svg() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_compressed_static_texture() {
+    const fn texture(index: usize, compression: TextureCompression) -> StaticTexture {
+        StaticTexture {
+            rect: euclid::rect(1, 0, 3, 2),
+            format: PixelFormat::Rgb,
+            color: crate::Color::from_argb_encoded(0),
+            index,
+            compression,
+        }
+    }
+    #[rustfmt::skip]
+    static DATA: [u8; 36] = [
+        // Uncompressed
+        1, 2, 3, 1, 2, 3, 1, 2, 3,
+        1, 2, 3, 4, 5, 6, 7, 8, 9,
+        // Offset of the rows
+        8, 0, 0, 0, 12, 0, 0, 0,
+        // Three times the same pixel, then three different pixels
+        0x82, 1, 2, 3,
+        0x02, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+    ];
+    static TEXTURES: [StaticTextures; 2] = [
+        StaticTextures {
+            size: IntSize::new(4, 2),
+            original_size: IntSize::new(4, 2),
+            data: Slice::from_slice(&DATA),
+            textures: Slice::from_slice(&[texture(0, TextureCompression::None)]),
+        },
+        StaticTextures {
+            size: IntSize::new(4, 2),
+            original_size: IntSize::new(4, 2),
+            data: Slice::from_slice(&DATA),
+            textures: Slice::from_slice(&[texture(18, TextureCompression::Rle)]),
+        },
+    ];
+
+    let mut buffer = Vec::new();
+    let compressed = texture(18, TextureCompression::Rle);
+    assert_eq!(compressed.row(&DATA, 0, &mut buffer), &DATA[0..9]);
+    assert_eq!(compressed.row(&DATA, 1, &mut buffer), &DATA[9..18]);
+
+    let [uncompressed, compressed] =
+        TEXTURES.each_ref().map(|t| match ImageInner::StaticTextures(t).render_to_buffer(None) {
+            Some(SharedImageBuffer::RGBA8Premultiplied(buffer)) => buffer,
+            _ => panic!("the static textures should render to a premultiplied buffer"),
+        });
+    assert_eq!(compressed.as_slice(), uncompressed.as_slice());
+}
+
 /// The result of the fit function
 #[derive(Debug)]
 pub struct FitResult {
@@ -1275,6 +1378,31 @@ struct Rgba8Pixel {
         core::ptr::write(image, super::load_image_from_embedded_data(data, format));
     }
 
+    /// Writes the image at `url` and returns true if it's loaded, or returns false otherwise.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_image_load_from_url(
+        url: &SharedString,
+        image: *mut Image,
+    ) -> bool {
+        match super::load_image_from_url(url) {
+            Some(loaded) => {
+                core::ptr::write(image, loaded);
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_image_url_loading(url: &SharedString) -> bool {
+        super::image_url_loading(url)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_image_url_error(url: &SharedString) -> bool {
+        super::image_url_error(url)
+    }
+
     #[no_mangle]
     pub unsafe extern "C" fn slint_image_size(image: &Image) -> IntSize {
         image.size()
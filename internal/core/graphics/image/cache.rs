@@ -153,7 +153,9 @@ pub(crate) fn load_image_from_embedded_data(
     }
 }
 
-fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
+pub(super) fn dynamic_image_to_shared_image_buffer(
+    dynamic_image: image::DynamicImage,
+) -> SharedImageBuffer {
     if dynamic_image.color().has_alpha() {
         let rgba8image = dynamic_image.to_rgba8();
         SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
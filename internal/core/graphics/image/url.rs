@@ -0,0 +1,148 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Loading of the images whose path is only known at run-time, for the `source-url` property of the
+`Image` element.
+
+The images are decoded in a thread, so that the user interface stays responsive. The state of
+each image is held in a property, so that the bindings that query it are evaluated again when
+the decoding is over.
+*/
+
+use super::Image;
+use crate::SharedString;
+
+/// The state of the image at a path or URL
+#[derive(Clone, Default, PartialEq)]
+enum UrlImageState {
+    /// There is no path
+    #[default]
+    Empty,
+    Loading,
+    Loaded(Image),
+    Failed,
+}
+
+/// Returns the part of a `file:` URL that is a path, or the `url` itself if it's already a path.
+#[cfg(feature = "image-decoders")]
+fn url_to_path(url: &str) -> &str {
+    url.strip_prefix("file://").unwrap_or(url)
+}
+
+#[cfg(all(feature = "image-decoders", not(target_arch = "wasm32")))]
+mod threaded {
+    use super::UrlImageState;
+    use crate::graphics::image::{CachedPath, Image, ImageCacheKey, ImageInner, SharedImageBuffer};
+    use crate::{Property, SharedString};
+    use alloc::rc::Rc;
+    use alloc::string::{String, ToString};
+    use core::cell::RefCell;
+    use core::pin::Pin;
+    use std::collections::HashMap;
+
+    type UrlImages = HashMap<SharedString, Pin<Rc<Property<UrlImageState>>>>;
+
+    thread_local!(static URL_IMAGES: RefCell<UrlImages> = Default::default());
+
+    pub(super) fn state(url: &SharedString) -> UrlImageState {
+        if url.is_empty() {
+            return UrlImageState::Empty;
+        }
+        let state = URL_IMAGES.with(|images| {
+            images.borrow_mut().entry(url.clone()).or_insert_with(|| start_loading(url)).clone()
+        });
+        state.as_ref().get()
+    }
+
+    fn start_loading(url: &SharedString) -> Pin<Rc<Property<UrlImageState>>> {
+        let path = SharedString::from(super::url_to_path(url));
+        let is_svg = path.ends_with(".svg") || path.ends_with(".svgz");
+        // Without an event loop, the result of the thread couldn't be delivered
+        if is_svg
+            || !std::path::Path::new(path.as_str()).exists()
+            || crate::platform::event_loop_proxy().is_none()
+        {
+            let image = super::super::cache::IMAGE_CACHE
+                .with(|cache| cache.borrow_mut().load_image_from_path(&path));
+            return Rc::pin(Property::new(
+                image.map_or(UrlImageState::Failed, UrlImageState::Loaded),
+            ));
+        }
+
+        let url = url.clone();
+        std::thread::spawn(move || {
+            let buffer = image::open(path.as_str())
+                .map(super::super::cache::dynamic_image_to_shared_image_buffer)
+                .map_err(|err| err.to_string());
+            let _ = crate::api::invoke_from_event_loop(move || finish_loading(&url, &path, buffer));
+        });
+        Rc::pin(Property::new(UrlImageState::Loading))
+    }
+
+    fn finish_loading(
+        url: &SharedString,
+        path: &SharedString,
+        buffer: Result<SharedImageBuffer, String>,
+    ) {
+        let state = match buffer {
+            Ok(buffer) => UrlImageState::Loaded(Image(ImageInner::EmbeddedImage {
+                cache_key: ImageCacheKey::Path(CachedPath::new(path.as_str())),
+                buffer,
+            })),
+            Err(err) => {
+                crate::debug_log!("Error loading image from {}: {}", path, err);
+                UrlImageState::Failed
+            }
+        };
+        URL_IMAGES.with(|images| {
+            if let Some(property) = images.borrow().get(url) {
+                property.as_ref().set(state);
+            }
+        });
+    }
+}
+
+#[cfg(all(feature = "image-decoders", not(target_arch = "wasm32")))]
+use threaded::state;
+
+/// On the web, the browser already loads the images asynchronously.
+#[cfg(all(feature = "image-decoders", target_arch = "wasm32"))]
+fn state(url: &SharedString) -> UrlImageState {
+    if url.is_empty() {
+        return UrlImageState::Empty;
+    }
+    super::cache::IMAGE_CACHE
+        .with(|cache| cache.borrow_mut().load_image_from_path(&url_to_path(url).into()))
+        .map_or(UrlImageState::Failed, UrlImageState::Loaded)
+}
+
+#[cfg(not(feature = "image-decoders"))]
+fn state(url: &SharedString) -> UrlImageState {
+    if url.is_empty() {
+        UrlImageState::Empty
+    } else {
+        UrlImageState::Failed
+    }
+}
+
+/// Returns the image at `url`, which is a path or a `file:` URL, once it's loaded.
+///
+/// The image is decoded in a thread. The bindings that call this function are notified when the
+/// image is available.
+pub fn load_image_from_url(url: &SharedString) -> Option<Image> {
+    match state(url) {
+        UrlImageState::Loaded(image) => Some(image),
+        _ => None,
+    }
+}
+
+/// Returns true while the image at `url` is being loaded.
+pub fn image_url_loading(url: &SharedString) -> bool {
+    state(url) == UrlImageState::Loading
+}
+
+/// Returns true if the image at `url` couldn't be loaded.
+pub fn image_url_error(url: &SharedString) -> bool {
+    state(url) == UrlImageState::Failed
+}
@@ -276,6 +276,67 @@ pub enum PathData {
     Commands(crate::SharedString),
 }
 
+/// Error returned by [`validate_path_commands`] when a string of SVG path commands cannot be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathCommandsError {
+    line: u32,
+    column: u32,
+    reason: alloc::string::String,
+}
+
+impl PathCommandsError {
+    /// The line of the commands at which the error occurred
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// The column in the line at which the error occurred
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl core::fmt::Display for PathCommandsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at line {}, column {}", self.reason, self.line, self.column)
+    }
+}
+
+impl std::error::Error for PathCommandsError {}
+
+/// Checks that a string of [SVG path commands](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/d#path_commands)
+/// can be parsed.
+///
+/// Use this function to report errors in commands that are generated at run-time, or that come
+/// from the user, before setting them to a property bound to the `commands` of a `Path`. The
+/// `Path` element doesn't show anything when its commands are invalid.
+///
+/// ```
+/// # use i_slint_core::graphics::validate_path_commands;
+/// assert!(validate_path_commands("M 0 0 L 0 100 L 100 100 Z").is_ok());
+/// assert!(validate_path_commands("M 0 0 L 0 100 X").is_err());
+/// ```
+pub fn validate_path_commands(commands: &str) -> Result<(), PathCommandsError> {
+    parse_path_commands(commands).map(|_| ())
+}
+
+fn parse_path_commands(commands: &str) -> Result<lyon_path::Path, PathCommandsError> {
+    let mut builder = lyon_path::Path::builder();
+    let mut parser = lyon_extra::parser::PathParser::new();
+    parser
+        .parse(
+            &lyon_extra::parser::ParserOptions::DEFAULT,
+            &mut lyon_extra::parser::Source::new(commands.chars()),
+            &mut builder,
+        )
+        .map_err(|e| PathCommandsError {
+            line: e.line as u32,
+            column: e.column as u32,
+            reason: alloc::format!("{:?}", e.kind),
+        })?;
+    Ok(builder.build())
+}
+
 impl Default for PathData {
     fn default() -> Self {
         Self::None
@@ -294,21 +355,13 @@ pub fn iter(self) -> Option<PathDataIterator> {
                 PathData::Events(events, coordinates) => {
                     LyonPathIteratorVariant::FromEvents(events, coordinates)
                 }
-                PathData::Commands(commands) => {
-                    let mut builder = lyon_path::Path::builder();
-                    let mut parser = lyon_extra::parser::PathParser::new();
-                    match parser.parse(
-                        &lyon_extra::parser::ParserOptions::DEFAULT,
-                        &mut lyon_extra::parser::Source::new(commands.chars()),
-                        &mut builder,
-                    ) {
-                        Ok(()) => LyonPathIteratorVariant::FromPath(builder.build()),
-                        Err(e) => {
-                            eprintln!("Error while parsing path commands '{commands}': {e:?}");
-                            LyonPathIteratorVariant::FromPath(Default::default())
-                        }
+                PathData::Commands(commands) => match parse_path_commands(&commands) {
+                    Ok(path) => LyonPathIteratorVariant::FromPath(path),
+                    Err(e) => {
+                        crate::debug_log!("Error while parsing path commands '{commands}': {e}");
+                        LyonPathIteratorVariant::FromPath(Default::default())
                     }
-                }
+                },
             },
             transform: Default::default(),
         }
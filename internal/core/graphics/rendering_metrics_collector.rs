@@ -2,10 +2,12 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 /*!
-This module contains a simple helper type to measure the average number of frames rendered per second.
+This module contains a simple helper type to measure the average number of frames rendered per second,
+and to report the input latency of the window.
 */
 
 use crate::animations::Instant;
+use crate::api::InputLatencyStatistics;
 use crate::debug_log;
 use crate::timers::{Timer, TimerMode};
 use alloc::format;
@@ -14,7 +16,7 @@
 use alloc::string::String;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 
 /// The method in which we refresh the window
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -51,6 +53,8 @@ struct FrameData {
 /// Helper class that rendering backends can use to provide an FPS counter
 pub struct RenderingMetricsCollector {
     collected_frame_data_since_second_ago: RefCell<Vec<FrameData>>,
+    /// The input latency statistics of the window, as of the last rendered frame
+    input_latency: Cell<InputLatencyStatistics>,
     update_timer: Timer,
     refresh_mode: RefreshMode,
     output_console: bool,
@@ -97,6 +101,7 @@ pub fn new(winsys_info: &str) -> Option<Rc<Self>> {
 
         let collector = Rc::new(Self {
             collected_frame_data_since_second_ago: Default::default(),
+            input_latency: Default::default(),
             update_timer: Default::default(),
             refresh_mode,
             output_console,
@@ -140,6 +145,16 @@ pub fn new(winsys_info: &str) -> Option<Rc<Self>> {
                         this.collected_frame_data_since_second_ago.borrow().len(),
                         last_frame_details
                     );
+                    let input_latency = this.input_latency.get();
+                    if input_latency.count > 0 {
+                        debug_log!(
+                            "input latency over {} frames: min {}ms, average {}ms, max {}ms",
+                            input_latency.count,
+                            input_latency.min.as_millis(),
+                            input_latency.average().as_millis(),
+                            input_latency.max.as_millis()
+                        );
+                    }
                 }
             },
         );
@@ -164,6 +179,7 @@ pub fn measure_frame_rendered(
         self.collected_frame_data_since_second_ago
             .borrow_mut()
             .push(FrameData { timestamp: Instant::now(), metrics: renderer.metrics() });
+        self.input_latency.set(renderer.window().input_latency_statistics());
         if matches!(self.refresh_mode, RefreshMode::FullSpeed) {
             crate::animations::CURRENT_ANIMATION_DRIVER
                 .with(|driver| driver.set_has_active_animations());
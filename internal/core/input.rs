@@ -10,17 +10,19 @@
 pub use crate::items::PointerEventButton;
 use crate::items::{ItemRef, TextCursorDirection};
 pub use crate::items::{KeyEvent, KeyboardModifiers};
-use crate::lengths::{LogicalPoint, LogicalVector};
+use crate::lengths::{LogicalPoint, LogicalTransform, LogicalVector};
 use crate::timers::Timer;
 use crate::window::{WindowAdapter, WindowInner};
 use crate::{Coord, Property, SharedString};
-use alloc::rc::Rc;
+use alloc::rc::{Rc, Weak};
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
-use core::cell::Cell;
+use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 use core::time::Duration;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 /// A mouse or touch event
 ///
@@ -49,6 +51,16 @@ pub enum MouseEvent {
     Wheel { position: LogicalPoint, delta_x: Coord, delta_y: Coord },
     /// The mouse exited the item or component
     Exit,
+    /// The pointer moved while data is dragged, see [`crate::window::WindowInner::start_drag`].
+    /// The `DropArea` element gets the dragged data from the window.
+    DragMove { position: LogicalPoint },
+    /// The pointer was released while data is dragged, to drop the data
+    Drop { position: LogicalPoint },
+    /// Two fingers are moved on a touch screen, see [`TouchState`].
+    /// `position` is the point in the middle of the fingers.
+    /// `scale` is the ratio of the distance between the fingers to the distance when the gesture started.
+    /// `rotation` is the angle in degrees that the fingers rotated clockwise since the gesture started.
+    PinchGesture { position: LogicalPoint, scale: f32, rotation: f32, phase: GesturePhase },
 }
 
 impl MouseEvent {
@@ -60,20 +72,36 @@ pub fn position(&self) -> Option<LogicalPoint> {
             MouseEvent::Moved { position } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
             MouseEvent::Exit => None,
+            MouseEvent::DragMove { position } => Some(*position),
+            MouseEvent::Drop { position } => Some(*position),
+            MouseEvent::PinchGesture { position, .. } => Some(*position),
         }
     }
 
     /// Translate the position by the given value
     pub fn translate(&mut self, vec: LogicalVector) {
-        let pos = match self {
+        if let Some(pos) = self.position_mut() {
+            *pos += vec;
+        }
+    }
+
+    /// Map the position with the given transformation
+    pub(crate) fn transform(&mut self, transform: &LogicalTransform) {
+        if let Some(pos) = self.position_mut() {
+            *pos = transform.transform_point(pos.cast()).cast();
+        }
+    }
+
+    fn position_mut(&mut self) -> Option<&mut LogicalPoint> {
+        match self {
             MouseEvent::Pressed { position, .. } => Some(position),
             MouseEvent::Released { position, .. } => Some(position),
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
             MouseEvent::Exit => None,
-        };
-        if let Some(pos) = pos {
-            *pos += vec;
+            MouseEvent::DragMove { position } => Some(position),
+            MouseEvent::Drop { position } => Some(position),
+            MouseEvent::PinchGesture { position, .. } => Some(position),
         }
     }
 
@@ -88,6 +116,29 @@ fn set_click_count(&mut self, count: u8) {
     }
 }
 
+/// The phase of a gesture in a [`MouseEvent`]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GesturePhase {
+    /// The gesture was recognized, this is the first event of the gesture
+    Started,
+    /// The fingers moved
+    Updated,
+    /// A finger was lifted, this is the last event of the gesture
+    Ended,
+}
+
+/// The phase of a touch point, see [`WindowInner::process_touch_input`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchPhase {
+    /// The finger touched the screen
+    Started,
+    /// The finger moved
+    Moved,
+    /// The finger was lifted, or the touch was cancelled
+    Ended,
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
@@ -533,6 +584,98 @@ pub fn check_repeat(&self, mouse_event: MouseEvent, click_interval: Duration) ->
     }
 }
 
+/// This state turns the touch points of a touch screen into mouse events.
+///
+/// The first finger is forwarded as the left mouse button. When a second finger touches the
+/// screen, the interaction of the first one is cancelled with a [`MouseEvent::Exit`], so that
+/// a `Flickable` stops scrolling and a `TouchArea` isn't clicked, and the fingers are reported
+/// with [`MouseEvent::PinchGesture`] events instead. After the gesture, the remaining fingers are
+/// ignored until they're all lifted.
+#[derive(Default)]
+pub struct TouchState {
+    /// The id and position of the fingers on the screen, in the order they touched it
+    touches: RefCell<Vec<(u64, LogicalPoint)>>,
+    /// The distance and angle between the first two fingers when the pinch started
+    pinch_start: Cell<Option<(f32, f32)>>,
+    /// Set after a pinch gesture ends, until all the fingers are lifted
+    pinch_done: Cell<bool>,
+}
+
+impl TouchState {
+    /// Updates the state with the touch point and returns the mouse events to dispatch
+    pub fn process(&self, id: u64, position: LogicalPoint, phase: TouchPhase) -> Vec<MouseEvent> {
+        let mut touches = self.touches.borrow_mut();
+        let index = touches.iter().position(|(touch_id, _)| *touch_id == id);
+        let index = match (index, phase) {
+            (Some(index), _) => {
+                touches[index].1 = position;
+                index
+            }
+            (None, TouchPhase::Started) => {
+                touches.push((id, position));
+                touches.len() - 1
+            }
+            // We never saw this finger touching the screen
+            (None, _) => return Vec::new(),
+        };
+
+        let mut events = Vec::new();
+        if let Some((start_distance, start_angle)) = self.pinch_start.get() {
+            if index < 2 {
+                let (center, distance, angle) = Self::pinch_geometry(&touches);
+                let phase = if phase == TouchPhase::Ended {
+                    self.pinch_start.set(None);
+                    self.pinch_done.set(true);
+                    GesturePhase::Ended
+                } else {
+                    GesturePhase::Updated
+                };
+                let mut rotation = angle - start_angle;
+                if rotation > 180. {
+                    rotation -= 360.;
+                } else if rotation <= -180. {
+                    rotation += 360.;
+                }
+                let scale = if start_distance > 0. { distance / start_distance } else { 1. };
+                events.push(MouseEvent::PinchGesture { position: center, scale, rotation, phase });
+            }
+        } else if index == 0 && !self.pinch_done.get() {
+            let button = PointerEventButton::Left;
+            events.push(match phase {
+                TouchPhase::Started => MouseEvent::Pressed { position, button, click_count: 0 },
+                TouchPhase::Moved => MouseEvent::Moved { position },
+                TouchPhase::Ended => MouseEvent::Released { position, button, click_count: 0 },
+            });
+        } else if index == 1 && phase == TouchPhase::Started && !self.pinch_done.get() {
+            let (center, distance, angle) = Self::pinch_geometry(&touches);
+            self.pinch_start.set(Some((distance, angle)));
+            events.push(MouseEvent::Exit);
+            events.push(MouseEvent::PinchGesture {
+                position: center,
+                scale: 1.,
+                rotation: 0.,
+                phase: GesturePhase::Started,
+            });
+        }
+
+        if phase == TouchPhase::Ended {
+            touches.remove(index);
+            if touches.is_empty() {
+                self.pinch_done.set(false);
+            }
+        }
+        events
+    }
+
+    /// The center, distance and angle in degrees between the first two fingers
+    fn pinch_geometry(touches: &[(u64, LogicalPoint)]) -> (LogicalPoint, f32, f32) {
+        let (a, b) = (touches[0].1, touches[1].1);
+        let (dx, dy) = ((b.x - a.x) as f32, (b.y - a.y) as f32);
+        let center = LogicalPoint::new((a.x + b.x) / 2 as Coord, (a.y + b.y) / 2 as Coord);
+        (center, dx.hypot(dy), dy.atan2(dx).to_degrees())
+    }
+}
+
 /// The state which a window should hold for the mouse input
 #[derive(Default)]
 pub struct MouseInputState {
@@ -608,6 +751,9 @@ pub(crate) fn handle_mouse_grab(
         {
             intercept = true;
         }
+        if let Some(transform) = item.children_transform().and_then(|t| t.inverse()) {
+            event.transform(&transform);
+        }
         true
     });
     if invalid {
@@ -647,6 +793,9 @@ pub(crate) fn send_exit_events(
         let contains = pos.map_or(false, |p| g.contains(p));
         if let Some(p) = pos.as_mut() {
             *p -= g.origin.to_vector();
+            if let Some(transform) = item.children_transform().and_then(|t| t.inverse()) {
+                *p = transform.transform_point(p.cast()).cast();
+            }
         }
         if !contains || clipped {
             if crate::item_rendering::is_clipping_item(item.borrow()) {
@@ -683,7 +832,9 @@ pub fn process_mouse_input(
         mouse_input_state.top_item().as_ref(),
         false,
     );
+    // An exit event cancels the delayed press, for example when a second finger starts a gesture
     if mouse_input_state.delayed.is_some()
+        && !matches!(mouse_event, MouseEvent::Exit)
         && (!r.has_aborted()
             || Option::zip(result.item_stack.last(), mouse_input_state.item_stack.last())
                 .map_or(true, |(a, b)| a.0 != b.0))
@@ -796,6 +947,15 @@ fn send_mouse_event_to_item(
     };
 
     result.item_stack.push((item_rc.downgrade(), filter_result));
+    let forward_to_children = forward_to_children
+        && match item_rc.children_transform() {
+            // The children don't cover any area when the transformation isn't invertible
+            Some(transform) => transform.inverse().map_or(false, |inverse| {
+                event_for_children.transform(&inverse);
+                true
+            }),
+            None => true,
+        };
     if forward_to_children {
         let mut actual_visitor =
             |component: &ItemTreeRc, index: u32, _: Pin<ItemRef>| -> VisitChildrenResult {
@@ -848,67 +1008,108 @@ fn send_mouse_event_to_item(
     }
 }
 
-/// The TextCursorBlinker takes care of providing a toggled boolean property
+/// The TextCursorBlinker takes care of providing a property with the opacity of the text cursor
 /// that can be used to animate a blinking cursor. It's typically stored in the
 /// Window using a Weak and set_binding() can be used to set up a binding on a given
 /// property that'll keep it up-to-date. That binding keeps a strong reference to the
 /// blinker. If the underlying item that uses it goes away, the binding goes away and
 /// so does the blinker.
+///
+/// The items don't repaint themselves when the cursor blinks. Instead, the blinker asks the
+/// window to repaint only the cursor, see [`WindowInner::text_cursor_visibility_changed`].
 #[derive(FieldOffsets)]
 #[repr(C)]
 #[pin]
 pub(crate) struct TextCursorBlinker {
-    cursor_visible: Property<bool>,
+    cursor_opacity: Property<f32>,
     cursor_blink_timer: crate::timers::Timer,
+    /// Whether the cursor fades out and in, instead of being toggled
+    fade: Cell<bool>,
+    /// The number of timer ticks since the cursor was fully shown
+    step: Cell<u32>,
+    window_adapter_weak: Weak<dyn WindowAdapter>,
 }
 
 impl TextCursorBlinker {
-    /// Creates a new instance, wrapped in a Pin<Rc<_>> because the boolean property
+    /// The number of timer ticks in which a fading cursor goes from shown to hidden
+    const FADE_STEPS: u32 = 10;
+
+    /// Creates a new instance, wrapped in a Pin<Rc<_>> because the opacity property
     /// the blinker properties uses the property system that requires pinning.
-    pub fn new() -> Pin<Rc<Self>> {
+    pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Pin<Rc<Self>> {
         Rc::pin(Self {
-            cursor_visible: Property::new(true),
+            cursor_opacity: Property::new(1.),
             cursor_blink_timer: Default::default(),
+            fade: Cell::new(false),
+            step: Cell::new(0),
+            window_adapter_weak,
         })
     }
 
     /// Sets a binding on the provided property that will ensure that the property value
-    /// is true when the cursor should be shown and false if not.
-    pub fn set_binding(instance: Pin<Rc<TextCursorBlinker>>, prop: &Property<bool>) {
-        instance.as_ref().cursor_visible.set(true);
+    /// is the opacity of the cursor: 1 when the cursor should be shown and 0 if not.
+    /// With `fade`, the cursor fades out and in again instead of being toggled.
+    pub fn set_binding(instance: Pin<Rc<TextCursorBlinker>>, prop: &Property<f32>, fade: bool) {
+        instance.as_ref().cursor_opacity.set(1.);
+        instance.step.set(0);
+        if instance.fade.replace(fade) != fade {
+            // The interval changes
+            instance.stop();
+        }
         // Re-start timer, in case.
         Self::start(&instance);
         prop.set_binding(move || {
-            TextCursorBlinker::FIELD_OFFSETS.cursor_visible.apply_pin(instance.as_ref()).get()
+            TextCursorBlinker::FIELD_OFFSETS.cursor_opacity.apply_pin(instance.as_ref()).get()
         });
     }
 
     /// Starts the blinking cursor timer that will toggle the cursor and update all bindings that
     /// were installed on properties with set_binding call.
     pub fn start(self: &Pin<Rc<Self>>) {
-        if self.cursor_blink_timer.running() {
+        let toggle_interval = crate::settings::settings().cursor_flash_time / 2;
+        let interval =
+            if self.fade.get() { toggle_interval / Self::FADE_STEPS } else { toggle_interval };
+        if interval.is_zero() {
+            // Blinking is disabled, the cursor stays visible
+            self.cursor_blink_timer.stop();
+        } else if self.cursor_blink_timer.running() {
             self.cursor_blink_timer.restart();
         } else {
             let toggle_cursor = {
                 let weak_blinker = pin_weak::rc::PinWeak::downgrade(self.clone());
                 move || {
                     if let Some(blinker) = weak_blinker.upgrade() {
-                        let visible = TextCursorBlinker::FIELD_OFFSETS
-                            .cursor_visible
-                            .apply_pin(blinker.as_ref())
-                            .get();
-                        blinker.cursor_visible.set(!visible);
+                        blinker.as_ref().tick();
+                        if let Some(window_adapter) = blinker.window_adapter_weak.upgrade() {
+                            WindowInner::from_pub(window_adapter.window())
+                                .text_cursor_visibility_changed();
+                        }
                     }
                 }
             };
             self.cursor_blink_timer.start(
                 crate::timers::TimerMode::Repeated,
-                Duration::from_millis(500),
+                interval,
                 toggle_cursor,
             );
         }
     }
 
+    /// Updates the opacity for the next timer tick
+    fn tick(self: Pin<&Self>) {
+        let opacity = if self.fade.get() {
+            // Fade out during FADE_STEPS ticks, and then in again
+            let step = (self.step.get() + 1) % (2 * Self::FADE_STEPS);
+            self.step.set(step);
+            step.abs_diff(Self::FADE_STEPS) as f32 / Self::FADE_STEPS as f32
+        } else if Self::FIELD_OFFSETS.cursor_opacity.apply_pin(self).get_untracked() > 0. {
+            0.
+        } else {
+            1.
+        };
+        self.cursor_opacity.set(opacity);
+    }
+
     /// Stops the blinking cursor timer. This is usually used for example when the window that contains
     /// text editable elements looses the focus or is hidden.
     pub fn stop(&self) {
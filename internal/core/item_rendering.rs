@@ -6,7 +6,7 @@
 
 use super::graphics::RenderingCache;
 use super::items::*;
-use crate::graphics::{CachedGraphicsData, FontRequest, Image, IntRect};
+use crate::graphics::{BorderEdges, CachedGraphicsData, FontRequest, Image, IntRect};
 use crate::item_tree::ItemTreeRc;
 use crate::item_tree::{ItemVisitor, ItemVisitorResult, ItemVisitorVTable, VisitChildrenResult};
 use crate::lengths::{
@@ -290,6 +290,8 @@ pub trait RenderBorderRectangle {
     fn border_width(self: Pin<&Self>) -> LogicalLength;
     fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius;
     fn border_color(self: Pin<&Self>) -> Brush;
+    /// The width and color of each edge, or None when all edges use `border_width` and `border_color`
+    fn border_edges(self: Pin<&Self>) -> Option<BorderEdges>;
 }
 
 /// Trait for an item that represents an Image towards the renderer
@@ -361,6 +363,9 @@ fn draw_box_shadow(
         _self_rc: &ItemRc,
         _size: LogicalSize,
     );
+    fn draw_shader(&mut self, _shader: Pin<&Shader>, _self_rc: &ItemRc, _size: LogicalSize) {
+        // Not supported
+    }
     fn visit_opacity(
         &mut self,
         opacity_item: Pin<&Opacity>,
@@ -426,6 +431,8 @@ fn translation(&self) -> LogicalVector {
         unimplemented!()
     }
     fn rotate(&mut self, angle_in_degrees: f32);
+    /// Scale the coordinate system of all following items until the next call to restore_state.
+    fn scale(&mut self, x_factor: f32, y_factor: f32);
     /// Apply the opacity (between 0 and 1) for all following items until the next call to restore_state.
     fn apply_opacity(&mut self, opacity: f32);
 
@@ -825,6 +832,7 @@ fn filter_item(&mut self, item_rc: &ItemRc) -> (bool, LogicalRect) {
     #[cfg(feature = "std")]
     forward_rendering_call!(fn draw_path(Path));
     forward_rendering_call!(fn draw_box_shadow(BoxShadow));
+    forward_rendering_call!(fn draw_shader(Shader));
 
     forward_rendering_call!(fn visit_clip(Clip) -> RenderingResult);
     forward_rendering_call!(fn visit_opacity(Opacity) -> RenderingResult);
@@ -853,6 +861,10 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         self.actual_renderer.rotate(angle_in_degrees)
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        self.actual_renderer.scale(x_factor, y_factor)
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         self.actual_renderer.apply_opacity(opacity)
     }
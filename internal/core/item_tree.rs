@@ -10,7 +10,7 @@
 };
 use crate::items::{AccessibleRole, ItemRef, ItemVTable};
 use crate::layout::{LayoutInfo, Orientation};
-use crate::lengths::{LogicalPoint, LogicalRect};
+use crate::lengths::{LogicalPoint, LogicalRect, LogicalTransform};
 use crate::slice::Slice;
 use crate::window::WindowAdapterRc;
 use crate::SharedString;
@@ -344,6 +344,18 @@ fn absolute_clip_rect_and_geometry(&self) -> (LogicalRect, LogicalRect) {
         (clip, geometry)
     }
 
+    /// Returns the `tab-index` of the item, which is zero for the items that don't have that property.
+    pub fn tab_index(&self) -> i32 {
+        let item = self.borrow();
+        if let Some(focus_scope) = ItemRef::downcast_pin::<crate::items::FocusScope>(item) {
+            focus_scope.tab_index()
+        } else if let Some(text_input) = ItemRef::downcast_pin::<crate::items::TextInput>(item) {
+            text_input.tab_index()
+        } else {
+            0
+        }
+    }
+
     pub fn is_accessible(&self) -> bool {
         let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
         let item_tree = crate::item_tree::ItemTreeNodeArray::new(&comp_ref_pin);
@@ -420,12 +432,22 @@ pub fn geometry(&self) -> LogicalRect {
         comp_ref_pin.as_ref().item_geometry(self.index)
     }
 
+    /// Returns the transformation from the coordinates of the children of this item to the
+    /// coordinates of this item, if its children are transformed with the `transform-*` properties
+    pub fn children_transform(&self) -> Option<LogicalTransform> {
+        ItemRef::downcast_pin::<crate::items::TransformItem>(self.borrow())
+            .map(|item| item.children_transform())
+    }
+
     /// Returns an absolute position of `p` in the parent item coordinate system
     /// (does not add this item's x and y)
     pub fn map_to_window(&self, p: LogicalPoint) -> LogicalPoint {
         let mut current = self.clone();
         let mut result = p;
         while let Some(parent) = current.parent_item() {
+            if let Some(transform) = parent.children_transform() {
+                result = transform.transform_point(result.cast()).cast();
+            }
             let geometry = parent.geometry();
             result += geometry.origin.to_vector();
             current = parent.clone();
@@ -449,6 +471,9 @@ pub fn map_to_item_tree(
             if parent.is_root_item_of(item_tree) {
                 break;
             }
+            if let Some(transform) = parent.children_transform() {
+                result = transform.transform_point(result.cast()).cast();
+            }
             let geometry = parent.geometry();
             result += geometry.origin.to_vector();
             current = parent.clone();
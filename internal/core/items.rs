@@ -20,7 +20,8 @@
 #![allow(non_upper_case_globals)]
 #![allow(missing_docs)] // because documenting each property of items is redundant
 
-use crate::graphics::{Brush, Color, Point};
+use crate::api::LogicalPosition;
+use crate::graphics::{BorderEdge, BorderEdges, Brush, Color, Point};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEventResult,
     KeyEventType, MouseEvent,
@@ -29,12 +30,13 @@
 pub use crate::item_tree::ItemRc;
 use crate::layout::LayoutInfo;
 use crate::lengths::{
-    LogicalBorderRadius, LogicalLength, LogicalSize, LogicalVector, PointLengths, RectLengths,
+    LogicalBorderRadius, LogicalLength, LogicalSize, LogicalTransform, LogicalVector, PointLengths,
+    RectLengths,
 };
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::{WindowAdapter, WindowAdapterRc};
-use crate::{Coord, Property, SharedString};
+use crate::{Coord, Property, SharedString, SharedVector};
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::pin::Pin;
@@ -48,7 +50,12 @@
 mod text;
 pub use text::*;
 mod input_items;
+mod text_input_validation;
 pub use input_items::*;
+mod drag_n_drop;
+pub use drag_n_drop::*;
+mod window_areas;
+pub use window_areas::*;
 mod image;
 pub use self::image::*;
 #[cfg(feature = "std")]
@@ -65,6 +72,8 @@
 pub type KeyEventArg = (KeyEvent,);
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
+type DropEventArg = (DropEvent,);
+type BoolArg = (bool,);
 type PointArg = (Point,);
 
 #[cfg(all(feature = "ffi", windows))]
@@ -408,6 +417,9 @@ fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
     fn border_color(self: Pin<&Self>) -> Brush {
         self.border_color()
     }
+    fn border_edges(self: Pin<&Self>) -> Option<BorderEdges> {
+        None
+    }
 }
 
 impl ItemConsts for BasicBorderRectangle {
@@ -434,6 +446,14 @@ pub struct BorderRectangle {
     pub border_bottom_left_radius: Property<LogicalLength>,
     pub border_bottom_right_radius: Property<LogicalLength>,
     pub border_color: Property<Brush>,
+    pub border_top_width: Property<LogicalLength>,
+    pub border_right_width: Property<LogicalLength>,
+    pub border_bottom_width: Property<LogicalLength>,
+    pub border_left_width: Property<LogicalLength>,
+    pub border_top_color: Property<Brush>,
+    pub border_right_color: Property<Brush>,
+    pub border_bottom_color: Property<Brush>,
+    pub border_left_color: Property<Brush>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -513,6 +533,22 @@ fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
     fn border_color(self: Pin<&Self>) -> Brush {
         self.border_color()
     }
+    fn border_edges(self: Pin<&Self>) -> Option<BorderEdges> {
+        let edges = BorderEdges {
+            top: BorderEdge { width: self.border_top_width(), color: self.border_top_color() },
+            right: BorderEdge {
+                width: self.border_right_width(),
+                color: self.border_right_color(),
+            },
+            bottom: BorderEdge {
+                width: self.border_bottom_width(),
+                color: self.border_bottom_color(),
+            },
+            left: BorderEdge { width: self.border_left_width(), color: self.border_left_color() },
+        };
+        // The compiler sets all the edges as soon as one of them is set
+        (edges != BorderEdges::default()).then_some(edges)
+    }
 }
 
 impl ItemConsts for BorderRectangle {
@@ -538,6 +574,26 @@ fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
     fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
 }
 
+declare_item_vtable! {
+    fn slint_get_PinchGestureHandlerVTable() -> PinchGestureHandlerVTable for PinchGestureHandler
+}
+
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
+declare_item_vtable! {
+    fn slint_get_WindowMoveAreaVTable() -> WindowMoveAreaVTable for WindowMoveArea
+}
+
+declare_item_vtable! {
+    fn slint_get_WindowResizeAreaVTable() -> WindowResizeAreaVTable for WindowResizeArea
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -906,6 +962,123 @@ impl ItemConsts for Rotate {
     fn slint_get_RotateVTable() -> RotateVTable for Rotate
 }
 
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `TransformItem` element, which applies the `transform-*` properties
+/// to its children when rendering and when delivering input events.
+pub struct TransformItem {
+    pub transform_rotation: Property<f32>,
+    pub transform_scale_x: Property<f32>,
+    pub transform_scale_y: Property<f32>,
+    pub transform_translate_x: Property<LogicalLength>,
+    pub transform_translate_y: Property<LogicalLength>,
+    pub transform_origin_x: Property<LogicalLength>,
+    pub transform_origin_y: Property<LogicalLength>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for TransformItem {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        let origin =
+            LogicalVector::from_lengths(self.transform_origin_x(), self.transform_origin_y());
+        let translation =
+            LogicalVector::from_lengths(self.transform_translate_x(), self.transform_translate_y());
+        (*backend).translate(origin + translation);
+        let rotation = self.transform_rotation();
+        if rotation != 0. {
+            (*backend).rotate(rotation);
+        }
+        let (scale_x, scale_y) = (self.transform_scale_x(), self.transform_scale_y());
+        if scale_x != 1. || scale_y != 1. {
+            (*backend).scale(scale_x, scale_y);
+        }
+        (*backend).translate(-origin);
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for TransformItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        TransformItem,
+        CachedRenderingData,
+    > = TransformItem::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl TransformItem {
+    /// Returns the transformation from the coordinates of the children to the coordinates of this
+    /// item, matching what `render()` applies.
+    pub fn children_transform(self: Pin<&Self>) -> LogicalTransform {
+        let origin = euclid::vec2(
+            self.transform_origin_x().get() as f32,
+            self.transform_origin_y().get() as f32,
+        );
+        let translation = euclid::vec2(
+            self.transform_translate_x().get() as f32,
+            self.transform_translate_y().get() as f32,
+        );
+        LogicalTransform::translation(-origin.x, -origin.y)
+            .then_scale(self.transform_scale_x(), self.transform_scale_y())
+            .then_rotate(euclid::Angle::degrees(self.transform_rotation()))
+            .then_translate(origin + translation)
+    }
+}
+
+declare_item_vtable! {
+    fn slint_get_TransformItemVTable() -> TransformItemVTable for TransformItem
+}
+
 declare_item_vtable! {
     fn slint_get_FlickableVTable() -> FlickableVTable for Flickable
 }
@@ -953,6 +1126,7 @@ pub struct WindowItem {
     pub no_frame: Property<bool>,
     pub resize_border_width: Property<LogicalLength>,
     pub always_on_top: Property<bool>,
+    pub blur_behind: Property<bool>,
     pub icon: Property<crate::graphics::Image>,
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<LogicalLength>,
@@ -1028,7 +1202,7 @@ pub fn font_family(self: Pin<&Self>) -> Option<SharedString> {
     }
 
     pub fn font_size(self: Pin<&Self>) -> Option<LogicalLength> {
-        let font_size = self.default_font_size();
+        let font_size = self.resolved_default_font_size();
         if font_size.get() <= 0 as Coord {
             None
         } else {
@@ -1036,6 +1210,12 @@ pub fn font_size(self: Pin<&Self>) -> Option<LogicalLength> {
         }
     }
 
+    /// The `default-font-size` multiplied by the text scale factor, which is the size of the `rem` unit
+    pub fn resolved_default_font_size(self: Pin<&Self>) -> LogicalLength {
+        let font_size = self.default_font_size().get() as f32 * crate::theme::text_scale_factor();
+        LogicalLength::new(font_size as Coord)
+    }
+
     pub fn font_weight(self: Pin<&Self>) -> Option<i32> {
         let font_weight = self.default_font_weight();
         if font_weight == 0 {
@@ -1136,6 +1316,84 @@ impl ItemConsts for BoxShadow {
     fn slint_get_BoxShadowVTable() -> BoxShadowVTable for BoxShadow
 }
 
+/// The implementation of the `Shader` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct Shader {
+    pub source: Property<SharedString>,
+    /// The values of the properties declared in the element, packed by the compiler
+    pub uniform_values: Property<SharedVector<f32>>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for Shader {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        (*backend).draw_shader(self, self_rc, size);
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for Shader {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_ShaderVTable() -> ShaderVTable for Shader
+}
+
 declare_item_vtable! {
     fn slint_get_ComponentContainerVTable() -> ComponentContainerVTable for ComponentContainer
 }
@@ -1230,3 +1488,13 @@ pub struct $Name {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     self_rc.map_to_window(Default::default())
 }
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_item_is_visible(
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) -> bool {
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    self_rc.is_visible()
+}
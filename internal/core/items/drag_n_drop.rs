@@ -0,0 +1,313 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the `DragArea` and `DropArea` elements, which move data from one element
+//! to another with the pointer.
+
+use super::{
+    BoolArg, DropEvent, DropEventArg, Item, ItemConsts, ItemRc, ItemRendererRef,
+    PointerEventButton, RenderingResult, VoidArg,
+};
+use crate::api::LogicalPosition;
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{logical_position_to_api, LogicalPoint, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Coord, Property, SharedString};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DragArea {
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+
+    pub dragging: Property<bool>,
+    pub pressed_position: Property<LogicalPosition>,
+    pub current_position: Property<LogicalPosition>,
+
+    pub drag_finished: Callback<BoolArg>,
+
+    // true when the left button is pressed on the area, until the drag starts
+    pressed: Cell<bool>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() || self.dragging() {
+            self.pressed.set(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                Self::FIELD_OFFSETS
+                    .pressed_position
+                    .apply_pin(self)
+                    .set(logical_position_to_api(position));
+                self.pressed.set(true);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Moved { position } if self.pressed.get() => {
+                if self.exceeds_drag_threshold(position) {
+                    InputEventFilterResult::Intercept
+                } else {
+                    InputEventFilterResult::ForwardAndInterceptGrab
+                }
+            }
+            MouseEvent::Released { .. } | MouseEvent::Exit => {
+                self.pressed.set(false);
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            _ => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Pressed { .. } if self.pressed.get() => InputEventResult::GrabMouse,
+            MouseEvent::Moved { position } if self.pressed.get() => {
+                if !self.exceeds_drag_threshold(position) {
+                    return InputEventResult::GrabMouse;
+                }
+                self.pressed.set(false);
+                self.current_position.set(logical_position_to_api(position));
+                Self::FIELD_OFFSETS.dragging.apply_pin(self).set(true);
+                WindowInner::from_pub(window_adapter.window()).start_drag(
+                    self.mime_type(),
+                    self.data(),
+                    Some(self_rc),
+                );
+                // Release the grab so that the drag events go to the items under the pointer
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Released { .. } | MouseEvent::Exit => {
+                self.pressed.set(false);
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DragArea {
+    fn exceeds_drag_threshold(self: Pin<&Self>, position: LogicalPoint) -> bool {
+        let pressed_pos = self.pressed_position();
+        let threshold = super::flickable::distance_threshold().get();
+        (position.x - pressed_pos.x as Coord).abs() > threshold
+            || (position.y - pressed_pos.y as Coord).abs() > threshold
+    }
+
+    /// Called by the window when the pointer moved during the drag that this area started.
+    /// `position` is relative to the area.
+    pub(crate) fn drag_moved(self: Pin<&Self>, position: LogicalPoint) {
+        self.current_position.set(logical_position_to_api(position));
+    }
+
+    /// Called by the window when the drag that this area started ended
+    pub(crate) fn drag_ended(self: Pin<&Self>, dropped: bool) {
+        Self::FIELD_OFFSETS.dragging.apply_pin(self).set(false);
+        Self::FIELD_OFFSETS.drag_finished.apply_pin(self).call(&(dropped,));
+    }
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DropArea {
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub drag_entered: Callback<DropEventArg>,
+    pub drag_exited: Callback<VoidArg>,
+    pub dropped: Callback<DropEventArg>,
+
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if self.enabled() && matches!(event, MouseEvent::DragMove { .. } | MouseEvent::Drop { .. })
+        {
+            // Give nested DropArea elements the chance to accept the drop first
+            InputEventFilterResult::ForwardEvent
+        } else {
+            InputEventFilterResult::ForwardAndIgnore
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::DragMove { position } => {
+                let Some(event) = self.accepted_drop_event(window_adapter, position) else {
+                    self.exit_drag();
+                    return InputEventResult::EventIgnored;
+                };
+                if !self.contains_drag() {
+                    Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(true);
+                    Self::FIELD_OFFSETS.drag_entered.apply_pin(self).call(&(event,));
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Drop { position } => {
+                let Some(event) = self.accepted_drop_event(window_adapter, position) else {
+                    self.exit_drag();
+                    return InputEventResult::EventIgnored;
+                };
+                Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+                WindowInner::from_pub(window_adapter.window()).accept_drop();
+                Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&(event,));
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Exit => {
+                self.exit_drag();
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DropArea {
+    /// Returns the dragged data at the given position if the area accepts it. Without a
+    /// `can-drop` handler, all the data is accepted.
+    fn accepted_drop_event(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        position: LogicalPoint,
+    ) -> Option<DropEvent> {
+        let mut event = WindowInner::from_pub(window_adapter.window()).drag_data()?;
+        event.position = logical_position_to_api(position);
+        let can_drop = Self::FIELD_OFFSETS.can_drop.apply_pin(self);
+        (!can_drop.has_handler() || can_drop.call(&(event.clone(),))).then_some(event)
+    }
+
+    fn exit_drag(self: Pin<&Self>) {
+        if self.contains_drag() {
+            Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+            Self::FIELD_OFFSETS.drag_exited.apply_pin(self).call(&());
+        }
+    }
+}
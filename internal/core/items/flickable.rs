@@ -5,25 +5,26 @@
 
 //! The `Flickable` item
 
+use super::input_items::VELOCITY_RESTING_DURATION;
 use super::{
     Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult, PointerEventButton, RenderingResult,
     VoidArg,
 };
-use crate::animations::{EasingCurve, Instant};
+use crate::animations::Instant;
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent, MouseEvent,
 };
 use crate::item_rendering::CachedRenderingData;
-use crate::items::PropertyAnimation;
 use crate::layout::{LayoutInfo, Orientation};
 use crate::lengths::{
-    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
-    PointLengths, RectLengths,
+    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
+    LogicalVector, PointLengths, RectLengths,
 };
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::WindowAdapter;
 use crate::Callback;
+use crate::Coord;
 use crate::Property;
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
@@ -51,6 +52,8 @@ pub struct Flickable {
     pub viewport_height: Property<LogicalLength>,
 
     pub interactive: Property<bool>,
+    pub friction: Property<f32>,
+    pub overshoot: Property<LogicalLength>,
 
     pub flicked: Callback<VoidArg>,
 
@@ -180,11 +183,19 @@ fn deref(&self) -> &Self::Target {
 }
 
 /// The distance required before it starts flicking if there is another item intercepting the mouse.
-pub(super) const DISTANCE_THRESHOLD: LogicalLength = LogicalLength::new(8 as _);
+/// It's the drag threshold of the [`Settings`](crate::platform::Settings).
+pub(super) fn distance_threshold() -> LogicalLength {
+    LogicalLength::new(crate::settings::settings().drag_threshold as crate::Coord)
+}
+
 /// Time required before we stop caring about child event if the mouse hasn't been moved
 pub(super) const DURATION_THRESHOLD: Duration = Duration::from_millis(500);
 /// The delay to which press are forwarded to the inner item
 pub(super) const FORWARD_DELAY: Duration = Duration::from_millis(100);
+/// The velocity below which a fling stops, in logical pixels per millisecond
+const MIN_FLING_VELOCITY: f32 = 0.01;
+/// The angular frequency of the spring that brings the viewport back to an edge, per millisecond
+const BOUNCE_FREQUENCY: f32 = 1. / 40.;
 
 #[derive(Default, Debug)]
 struct FlickableDataInner {
@@ -194,6 +205,12 @@ struct FlickableDataInner {
     pressed_viewport_pos: LogicalPoint,
     /// Set to true if the flickable is flicking and capturing all mouse event, not forwarding back to the children
     capture_events: bool,
+    /// The time and position of the last motion of the pointer, to measure its velocity
+    velocity_sample: Option<(Instant, LogicalPoint)>,
+    /// The velocity of the pointer, in logical pixels per millisecond
+    velocity: euclid::Vector2D<f32, LogicalPx>,
+    /// The time at which the current fling ends
+    fling_end: Option<Instant>,
 }
 
 #[derive(Default, Debug)]
@@ -211,8 +228,19 @@ fn handle_mouse_filter(
         let mut inner = self.inner.borrow_mut();
         match event {
             MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                let now = crate::animations::current_tick();
+                if inner.fling_end.take().map_or(false, |end| now < end) {
+                    // Stop the fling where it is, and don't forward the press to the children
+                    let x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
+                    let y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
+                    x.set(x.get());
+                    y.set(y.get());
+                    inner.capture_events = true;
+                }
                 inner.pressed_pos = position;
-                inner.pressed_time = Some(crate::animations::current_tick());
+                inner.pressed_time = Some(now);
+                inner.velocity_sample = Some((now, position));
+                inner.velocity = Default::default();
                 inner.pressed_viewport_pos = LogicalPoint::from_lengths(
                     (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get(),
                     (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get(),
@@ -233,12 +261,15 @@ fn handle_mouse_filter(
                 }
             }
             MouseEvent::Moved { position } => {
+                if inner.pressed_time.is_some() {
+                    Self::update_velocity(&mut inner, position);
+                }
                 let do_intercept = inner.capture_events
                     || inner.pressed_time.map_or(false, |pressed_time| {
                         if crate::animations::current_tick() - pressed_time > DURATION_THRESHOLD {
                             return false;
                         }
-                        // Check if the mouse was moved more than the DISTANCE_THRESHOLD in a
+                        // Check if the mouse was moved more than the distance threshold in a
                         // direction in which the flickable can flick
                         let diff = position - inner.pressed_pos;
                         let geo = flick_rc.geometry();
@@ -249,8 +280,9 @@ fn handle_mouse_filter(
                         let x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get();
                         let y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get();
                         let zero = LogicalLength::zero();
-                        ((vw > w || x != zero) && abs(diff.x_length()) > DISTANCE_THRESHOLD)
-                            || ((vh > h || y != zero) && abs(diff.y_length()) > DISTANCE_THRESHOLD)
+                        let threshold = distance_threshold();
+                        ((vw > w || x != zero) && abs(diff.x_length()) > threshold)
+                            || ((vh > h || y != zero) && abs(diff.y_length()) > threshold)
                     });
                 if do_intercept {
                     InputEventFilterResult::Intercept
@@ -265,6 +297,9 @@ fn handle_mouse_filter(
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
             }
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::PinchGesture { .. } => InputEventFilterResult::ForwardAndIgnore,
         }
     }
 
@@ -292,6 +327,7 @@ fn handle_mouse(
             }
             MouseEvent::Moved { position } => {
                 if inner.pressed_time.is_some() {
+                    Self::update_velocity(&mut inner, position);
                     let new_pos = inner.pressed_viewport_pos + (position - inner.pressed_pos);
                     let x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
                     let y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
@@ -302,14 +338,15 @@ fn handle_mouse(
                         let vw = (Flickable::FIELD_OFFSETS.viewport_width).apply_pin(flick).get();
                         let vh = (Flickable::FIELD_OFFSETS.viewport_height).apply_pin(flick).get();
                         let zero = LogicalLength::zero();
+                        let threshold = distance_threshold();
                         ((vw > w || x.get() != zero)
-                            && abs(x.get() - new_pos.x_length()) > DISTANCE_THRESHOLD)
+                            && abs(x.get() - new_pos.x_length()) > threshold)
                             || ((vh > h || y.get() != zero)
-                                && abs(y.get() - new_pos.y_length()) > DISTANCE_THRESHOLD)
+                                && abs(y.get() - new_pos.y_length()) > threshold)
                     };
 
                     if inner.capture_events || should_capture() {
-                        let new_pos = ensure_in_bound(flick, new_pos, flick_rc);
+                        let new_pos = ensure_in_overshoot_bound(flick, new_pos, flick_rc);
 
                         let old_pos = (x.get(), y.get());
                         x.set(new_pos.x_length());
@@ -329,6 +366,7 @@ fn handle_mouse(
                 }
             }
             MouseEvent::Wheel { delta_x, delta_y, .. } => {
+                inner.fling_end = None;
                 let old_pos = LogicalPoint::from_lengths(
                     (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get(),
                     (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get(),
@@ -353,7 +391,31 @@ fn handle_mouse(
                 }
                 InputEventResult::EventAccepted
             }
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::PinchGesture { .. } => InputEventResult::EventIgnored,
+        }
+    }
+
+    /// Updates the velocity with the position of a pointer event. The velocity drops to zero
+    /// when the pointer rests at the same position.
+    fn update_velocity(inner: &mut FlickableDataInner, position: LogicalPoint) {
+        let now = crate::animations::current_tick();
+        let Some((time, last_position)) = inner.velocity_sample else { return };
+        let millis = (now - time).as_millis();
+        if position == last_position {
+            if millis <= VELOCITY_RESTING_DURATION.as_millis() {
+                // Keep the time of the last motion
+                return;
+            }
+            inner.velocity = Default::default();
+        } else if millis == 0 {
+            // Measure the motion from the previous sample at the next event
+            return;
+        } else {
+            inner.velocity = (position - last_position).cast::<f32>() / millis as f32;
         }
+        inner.velocity_sample = Some((now, position));
     }
 
     fn mouse_released(
@@ -362,40 +424,167 @@ fn mouse_released(
         event: MouseEvent,
         flick_rc: &ItemRc,
     ) {
-        if let (Some(pressed_time), Some(pos)) = (inner.pressed_time, event.position()) {
-            let dist = (pos - inner.pressed_pos).cast::<f32>();
-
-            let millis = (crate::animations::current_tick() - pressed_time).as_millis();
-            if inner.capture_events
-                && dist.square_length() > (DISTANCE_THRESHOLD.get() * DISTANCE_THRESHOLD.get()) as _
-                && millis > 1
-            {
-                let speed = dist / (millis as f32);
+        if inner.pressed_time.is_some() && inner.capture_events {
+            match event.position() {
+                Some(position) => Self::update_velocity(inner, position),
+                // The grab was lost, only bring the viewport back within its bounds
+                None => inner.velocity = Default::default(),
+            }
+            Self::fling(inner, flick, flick_rc);
+        }
+        inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
+        inner.pressed_time = None;
+        inner.velocity_sample = None;
+    }
 
-                let duration = 250;
-                let final_pos = ensure_in_bound(
-                    flick,
-                    (inner.pressed_viewport_pos.cast() + dist + speed * (duration as f32)).cast(),
-                    flick_rc,
-                );
-                let anim = PropertyAnimation {
-                    duration,
-                    easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
-                    ..PropertyAnimation::default()
-                };
+    /// Continues the motion of the viewport with the velocity of the pointer, until the
+    /// friction stops it or it bounces back from an edge
+    fn fling(inner: &mut FlickableDataInner, flick: Pin<&Flickable>, flick_rc: &ItemRc) {
+        let min = min_viewport_pos(flick, flick_rc);
+        let decay = flick.friction() / 1000.;
+        let overshoot = flick.overshoot().get() as f32;
+        let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
+        let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
+        let old_pos = (viewport_x.get(), viewport_y.get());
+        let fling_x = FlingAxis::new(
+            old_pos.0.get() as f32,
+            inner.velocity.x,
+            decay,
+            min.x as f32,
+            overshoot,
+        );
+        let fling_y = FlingAxis::new(
+            old_pos.1.get() as f32,
+            inner.velocity.y,
+            decay,
+            min.y as f32,
+            overshoot,
+        );
+        let duration = fling_x.duration().max(fling_y.duration());
+        if duration <= 0. {
+            return;
+        }
 
-                let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
-                let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
-                let old_pos = (viewport_x.get(), viewport_y.get());
-                viewport_x.set_animated_value(final_pos.x_length(), anim.clone());
-                viewport_y.set_animated_value(final_pos.y_length(), anim);
-                if old_pos.0 != final_pos.x_length() || old_pos.1 != final_pos.y_length() {
-                    (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
+        let start = crate::animations::current_tick();
+        inner.fling_end = Some(start + Duration::from_millis(duration.ceil() as u64));
+        let animate = move |fling: FlingAxis| {
+            move || {
+                let time = (crate::animations::current_tick() - start).as_millis() as f32;
+                if time < fling.duration() {
+                    crate::animations::animation_tick();
                 }
+                LogicalLength::new(fling.position(time) as Coord)
             }
+        };
+        if fling_x.duration() > 0. {
+            viewport_x.set_binding(animate(fling_x));
+        }
+        if fling_y.duration() > 0. {
+            viewport_y.set_binding(animate(fling_y));
+        }
+        let final_pos =
+            (LogicalLength::new(fling_x.end as Coord), LogicalLength::new(fling_y.end as Coord));
+        if old_pos != final_pos {
+            (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
+        }
+    }
+}
+
+/// A critically damped spring that brings the viewport back to an edge
+#[derive(Clone, Copy, Debug)]
+struct Spring {
+    edge: f32,
+    /// The distance from the edge and the velocity, in logical pixels per millisecond, at the start
+    displacement: f32,
+    velocity: f32,
+    /// The angular frequency, per millisecond
+    frequency: f32,
+}
+
+impl Spring {
+    fn duration(&self) -> f32 {
+        // By then, the distance to the edge is below a percent of the initial amplitude
+        8. / self.frequency
+    }
+
+    fn position(&self, time: f32) -> f32 {
+        let amplitude =
+            self.displacement + (self.velocity + self.frequency * self.displacement) * time;
+        self.edge + amplitude * (-self.frequency * time).exp()
+    }
+}
+
+/// The motion of the viewport along one axis after a fling
+///
+/// The velocity decays exponentially until it drops below [`MIN_FLING_VELOCITY`] or the viewport
+/// reaches an edge. With an overshoot, it then goes beyond the edge and a spring brings it back.
+#[derive(Clone, Copy, Debug)]
+struct FlingAxis {
+    start: f32,
+    /// In logical pixels per millisecond
+    velocity: f32,
+    /// The decay rate of the velocity, per millisecond
+    decay: f32,
+    /// The duration of the deceleration, in milliseconds
+    decelerate_duration: f32,
+    spring: Option<Spring>,
+    /// The position at the end of the motion
+    end: f32,
+}
+
+impl FlingAxis {
+    /// The viewport positions range from `min` to 0
+    fn new(start: f32, velocity: f32, decay: f32, min: f32, overshoot: f32) -> Self {
+        let mut fling =
+            Self { start, velocity: 0., decay, decelerate_duration: 0., spring: None, end: start };
+        if start < min || start > 0. {
+            // The viewport was dragged beyond an edge
+            let edge = start.clamp(min, 0.);
+            fling.spring = Some(Spring {
+                edge,
+                displacement: start - edge,
+                velocity: 0.,
+                frequency: BOUNCE_FREQUENCY,
+            });
+            fling.end = edge;
+            return fling;
+        }
+        if min >= 0. || decay <= 0. || velocity.abs() < MIN_FLING_VELOCITY {
+            return fling;
+        }
+
+        fling.velocity = velocity;
+        fling.decelerate_duration = (velocity.abs() / MIN_FLING_VELOCITY).ln() / decay;
+        fling.end = start + (velocity - velocity.signum() * MIN_FLING_VELOCITY) / decay;
+        let edge = if velocity > 0. { 0. } else { min };
+        // The fraction of the distance to the end of the deceleration at which the edge is
+        let edge_fraction = (edge - start) * decay / velocity;
+        if edge_fraction < 1. - MIN_FLING_VELOCITY / velocity.abs() {
+            fling.decelerate_duration = -(1. - edge_fraction).ln() / decay;
+            fling.end = edge;
+            if overshoot > 0. {
+                let velocity = velocity * (-decay * fling.decelerate_duration).exp();
+                // The spring goes at most `overshoot` beyond the edge
+                let frequency =
+                    BOUNCE_FREQUENCY.max(velocity.abs() / (core::f32::consts::E * overshoot));
+                fling.spring = Some(Spring { edge, displacement: 0., velocity, frequency });
+            }
+        }
+        fling
+    }
+
+    fn duration(&self) -> f32 {
+        self.decelerate_duration + self.spring.map_or(0., |spring| spring.duration())
+    }
+
+    fn position(&self, time: f32) -> f32 {
+        if time >= self.duration() {
+            self.end
+        } else if time >= self.decelerate_duration {
+            self.spring.map_or(self.end, |spring| spring.position(time - self.decelerate_duration))
+        } else {
+            self.start + self.velocity / self.decay * (1. - (-self.decay * time).exp())
         }
-        inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
-        inner.pressed_time = None;
     }
 }
 
@@ -403,19 +592,47 @@ fn abs(l: LogicalLength) -> LogicalLength {
     LogicalLength::new(l.get().abs())
 }
 
-/// Make sure that the point is within the bounds
-fn ensure_in_bound(flick: Pin<&Flickable>, p: LogicalPoint, flick_rc: &ItemRc) -> LogicalPoint {
+/// The smallest position of the viewport, the largest is zero
+fn min_viewport_pos(flick: Pin<&Flickable>, flick_rc: &ItemRc) -> LogicalPoint {
     let geo = flick_rc.geometry();
     let w = geo.width_length();
     let h = geo.height_length();
     let vw = (Flickable::FIELD_OFFSETS.viewport_width).apply_pin(flick).get();
     let vh = (Flickable::FIELD_OFFSETS.viewport_height).apply_pin(flick).get();
 
-    let min = LogicalPoint::from_lengths(w - vw, h - vh);
+    LogicalPoint::from_lengths(w - vw, h - vh).min(LogicalPoint::default())
+}
+
+/// Make sure that the point is within the bounds
+fn ensure_in_bound(flick: Pin<&Flickable>, p: LogicalPoint, flick_rc: &ItemRc) -> LogicalPoint {
+    let min = min_viewport_pos(flick, flick_rc);
     let max = LogicalPoint::default();
     p.max(min).min(max)
 }
 
+/// Make sure that the dragged point is within the bounds, or beyond them along the directions in
+/// which the viewport can scroll by at most the overshoot, with an increasing resistance
+fn ensure_in_overshoot_bound(
+    flick: Pin<&Flickable>,
+    p: LogicalPoint,
+    flick_rc: &ItemRc,
+) -> LogicalPoint {
+    let bounded = ensure_in_bound(flick, p, flick_rc);
+    let overshoot = flick.overshoot().get() as f32;
+    if overshoot <= 0. {
+        return bounded;
+    }
+    let min = min_viewport_pos(flick, flick_rc);
+    let resist = |p: Coord, bounded: Coord, min: Coord| {
+        if min >= 0 as Coord {
+            return bounded;
+        }
+        let excess = (p - bounded) as f32;
+        bounded + (excess * overshoot / (excess.abs() + overshoot)) as Coord
+    };
+    LogicalPoint::new(resist(p.x, bounded.x, min.x), resist(p.y, bounded.y, min.y))
+}
+
 /// # Safety
 /// This must be called using a non-null pointer pointing to a chunk of memory big enough to
 /// hold a FlickableDataBox
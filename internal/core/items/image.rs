@@ -22,7 +22,7 @@
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::WindowAdapter;
-use crate::{Brush, Coord, Property};
+use crate::{Brush, Coord, Property, SharedString};
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::pin::Pin;
@@ -39,6 +39,10 @@ pub struct ImageItem {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub source_url: Property<SharedString>,
+    pub fallback: Property<crate::graphics::Image>,
+    pub loading: Property<bool>,
+    pub error: Property<bool>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -162,6 +166,10 @@ pub struct ClippedImage {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub source_url: Property<SharedString>,
+    pub fallback: Property<crate::graphics::Image>,
+    pub loading: Property<bool>,
+    pub error: Property<bool>,
     pub source_clip_x: Property<i32>,
     pub source_clip_y: Property<i32>,
     pub source_clip_width: Property<i32>,
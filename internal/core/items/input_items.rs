@@ -8,7 +8,7 @@
 };
 use crate::api::LogicalPosition;
 use crate::input::{
-    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    FocusEvent, FocusEventResult, GesturePhase, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyEventResult, KeyEventType, MouseEvent,
 };
 use crate::item_rendering::CachedRenderingData;
@@ -42,6 +42,9 @@ pub struct TouchArea {
     pub mouse_x: Property<LogicalLength>,
     pub mouse_y: Property<LogicalLength>,
     pub mouse_cursor: Property<MouseCursor>,
+    pub mouse_cursor_image: Property<crate::graphics::Image>,
+    pub mouse_cursor_hotspot_x: Property<LogicalLength>,
+    pub mouse_cursor_hotspot_y: Property<LogicalLength>,
     pub clicked: Callback<VoidArg>,
     pub double_clicked: Callback<VoidArg>,
     pub moved: Callback<VoidArg>,
@@ -74,14 +77,24 @@ fn input_event_filter_before_children(
             self.has_hover.set(false);
             if self.grabbed.replace(false) {
                 self.pressed.set(false);
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button: PointerEventButton::Other,
-                    kind: PointerEventKind::Cancel,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                self.invoke_pointer_event(
+                    window_adapter,
+                    PointerEventButton::Other,
+                    PointerEventKind::Cancel,
+                    0,
+                    self.mouse_position(),
+                );
             }
             return InputEventFilterResult::ForwardAndIgnore;
         }
+        if matches!(event, MouseEvent::DragMove { .. } | MouseEvent::Drop { .. }) {
+            // Dragged data goes to the DropArea elements, and hovering stops
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        if matches!(event, MouseEvent::PinchGesture { .. }) {
+            // The pinch gesture already cancelled the press with an exit event
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
         if let Some(pos) = event.position() {
             Self::FIELD_OFFSETS.mouse_x.apply_pin(self).set(pos.x_length());
             Self::FIELD_OFFSETS.mouse_y.apply_pin(self).set(pos.y_length());
@@ -90,7 +103,16 @@ fn input_event_filter_before_children(
         Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(hovering);
         if hovering {
             if let Some(x) = window_adapter.internal(crate::InternalToken) {
-                x.set_mouse_cursor(self.mouse_cursor());
+                let image = self.mouse_cursor_image();
+                if image.size().is_empty() {
+                    x.set_mouse_cursor(self.mouse_cursor());
+                } else {
+                    let hotspot = LogicalPosition::new(
+                        self.mouse_cursor_hotspot_x().get() as f32,
+                        self.mouse_cursor_hotspot_y().get() as f32,
+                    );
+                    x.set_custom_mouse_cursor(&image, hotspot);
+                }
             }
         }
         InputEventFilterResult::ForwardAndInterceptGrab
@@ -113,29 +135,33 @@ fn input_event(
         }
 
         match event {
-            MouseEvent::Pressed { position, button, .. } => {
+            MouseEvent::Pressed { position, button, click_count } => {
                 self.grabbed.set(true);
                 if button == PointerEventButton::Left {
                     Self::FIELD_OFFSETS.pressed_x.apply_pin(self).set(position.x_length());
                     Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(position.y_length());
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
                 }
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
+                self.invoke_pointer_event(
+                    window_adapter,
                     button,
-                    kind: PointerEventKind::Down,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                    PointerEventKind::Down,
+                    click_count as i32,
+                    crate::lengths::logical_position_to_api(position),
+                );
 
                 InputEventResult::GrabMouse
             }
             MouseEvent::Exit => {
                 Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 if self.grabbed.replace(false) {
-                    Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                        button: PointerEventButton::Other,
-                        kind: PointerEventKind::Cancel,
-                        modifiers: window_adapter.window().0.modifiers.get().into(),
-                    },));
+                    self.invoke_pointer_event(
+                        window_adapter,
+                        PointerEventButton::Other,
+                        PointerEventKind::Cancel,
+                        0,
+                        self.mouse_position(),
+                    );
                 }
 
                 InputEventResult::EventAccepted
@@ -157,20 +183,24 @@ fn input_event(
                 if button == PointerEventButton::Left {
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 }
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
+                self.invoke_pointer_event(
+                    window_adapter,
                     button,
-                    kind: PointerEventKind::Up,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+                    PointerEventKind::Up,
+                    click_count as i32,
+                    crate::lengths::logical_position_to_api(position),
+                );
 
                 InputEventResult::EventAccepted
             }
-            MouseEvent::Moved { .. } => {
-                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
-                    button: PointerEventButton::Other,
-                    kind: PointerEventKind::Move,
-                    modifiers: window_adapter.window().0.modifiers.get().into(),
-                },));
+            MouseEvent::Moved { position } => {
+                self.invoke_pointer_event(
+                    window_adapter,
+                    PointerEventButton::Other,
+                    PointerEventKind::Move,
+                    0,
+                    crate::lengths::logical_position_to_api(position),
+                );
                 return if self.grabbed.get() {
                     Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
                     InputEventResult::GrabMouse
@@ -199,6 +229,9 @@ fn input_event(
                     }
                 }
             }
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::PinchGesture { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -230,6 +263,43 @@ fn render(
     }
 }
 
+impl TouchArea {
+    /// The last known position of the mouse, for the events that don't have one
+    fn mouse_position(self: Pin<&Self>) -> LogicalPosition {
+        LogicalPosition::new(self.mouse_x().get() as f32, self.mouse_y().get() as f32)
+    }
+
+    /// Invokes the `pointer-event` callback, with the state of the keyboard and of the pointer device
+    fn invoke_pointer_event(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        button: PointerEventButton,
+        kind: PointerEventKind,
+        click_count: i32,
+        position: LogicalPosition,
+    ) {
+        let window = &window_adapter.window().0;
+        let details = window.pointer_details.get();
+        // Like in the pointer events of the web, the pressure is 0.5 while pressed when it's unknown
+        let pressure = details.pressure.unwrap_or(match kind {
+            PointerEventKind::Down => 0.5,
+            PointerEventKind::Move if self.grabbed.get() => 0.5,
+            _ => 0.,
+        });
+        Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
+            button,
+            kind,
+            modifiers: window.modifiers.get().into(),
+            click_count,
+            position,
+            device: details.device,
+            pressure,
+            tilt_x: details.tilt_x,
+            tilt_y: details.tilt_y,
+        },));
+    }
+}
+
 impl ItemConsts for TouchArea {
     const cached_rendering_data_offset: const_field_offset::FieldOffset<
         TouchArea,
@@ -244,6 +314,7 @@ impl ItemConsts for TouchArea {
 pub struct FocusScope {
     pub enabled: Property<bool>,
     pub has_focus: Property<bool>,
+    pub tab_index: Property<i32>,
     pub key_pressed: Callback<KeyEventArg, EventResult>,
     pub key_released: Callback<KeyEventArg, EventResult>,
     pub focus_changed_event: Callback<VoidArg>,
@@ -348,6 +419,10 @@ impl ItemConsts for FocusScope {
     > = FocusScope::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
 
+/// When the pointer doesn't move for this long, the velocity of the swipe is zero
+pub(super) const VELOCITY_RESTING_DURATION: core::time::Duration =
+    core::time::Duration::from_millis(100);
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -365,9 +440,12 @@ pub struct SwipeGestureHandler {
     pub pressed_position: Property<LogicalPosition>,
     pub current_position: Property<LogicalPosition>,
     pub swiping: Property<bool>,
+    pub velocity: Property<LogicalPosition>,
 
     // true when the cursor is pressed down and we haven't cancelled yet for another reason
     pressed: Cell<bool>,
+    // the time and position of the last pointer event, to compute the velocity
+    velocity_sample: Cell<Option<(crate::animations::Instant, LogicalPoint)>>,
     // capture_events: Cell<bool>,
     /// FIXME: remove this
     pub cached_rendering_data: CachedRenderingData,
@@ -404,6 +482,8 @@ fn input_event_filter_before_children(
                     .apply_pin(self)
                     .set(crate::lengths::logical_position_to_api(position));
                 self.pressed.set(true);
+                Self::FIELD_OFFSETS.velocity.apply_pin(self).set(Default::default());
+                self.velocity_sample.set(Some((crate::animations::current_tick(), position)));
                 InputEventFilterResult::DelayForwarding(
                     super::flickable::FORWARD_DELAY.as_millis() as _
                 )
@@ -429,7 +509,7 @@ fn input_event_filter_before_children(
                     let pressed_pos = self.pressed_position();
                     let dx = position.x - pressed_pos.x as Coord;
                     let dy = position.y - pressed_pos.y as Coord;
-                    let threshold = super::flickable::DISTANCE_THRESHOLD.get();
+                    let threshold = super::flickable::distance_threshold().get();
                     if (self.handle_swipe_down() && dy > threshold)
                         || (self.handle_swipe_up() && dy < -threshold)
                         || (self.handle_swipe_left() && dx < -threshold)
@@ -446,6 +526,9 @@ fn input_event_filter_before_children(
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
             }
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::PinchGesture { .. } => InputEventFilterResult::ForwardAndIgnore,
         }
     }
 
@@ -466,6 +549,7 @@ fn input_event(
                     return InputEventResult::EventIgnored;
                 }
                 self.current_position.set(crate::lengths::logical_position_to_api(position));
+                self.update_velocity(position);
                 self.pressed.set(false);
                 if self.swiping() {
                     Self::FIELD_OFFSETS.swiping.apply_pin(self).set(false);
@@ -480,11 +564,12 @@ fn input_event(
                     return InputEventResult::EventIgnored;
                 }
                 self.current_position.set(crate::lengths::logical_position_to_api(position));
+                self.update_velocity(position);
                 if !self.swiping() {
                     let pressed_pos = self.pressed_position();
                     let dx = position.x - pressed_pos.x as Coord;
                     let dy = position.y - pressed_pos.y as Coord;
-                    let threshold = super::flickable::DISTANCE_THRESHOLD.get();
+                    let threshold = super::flickable::distance_threshold().get();
                     let start_swipe = (self.handle_swipe_down() && dy > threshold)
                         || (self.handle_swipe_up() && dy < -threshold)
                         || (self.handle_swipe_left() && dx < -threshold)
@@ -500,6 +585,9 @@ fn input_event(
                 InputEventResult::EventAccepted
             }
             MouseEvent::Wheel { .. } => InputEventResult::EventIgnored,
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::PinchGesture { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -541,6 +629,32 @@ pub fn copy(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc) {
         self.cancel_impl();
     }
 
+    /// Updates the velocity with the position of a pointer event. The velocity drops to zero
+    /// when the pointer rests at the same position.
+    fn update_velocity(self: Pin<&Self>, position: LogicalPoint) {
+        let now = crate::animations::current_tick();
+        let Some((time, last_position)) = self.velocity_sample.get() else { return };
+        let millis = (now - time).as_millis();
+        let velocity = Self::FIELD_OFFSETS.velocity.apply_pin(self);
+        if position == last_position {
+            if millis <= VELOCITY_RESTING_DURATION.as_millis() {
+                // Keep the time of the last motion
+                return;
+            }
+            velocity.set(Default::default());
+        } else if millis == 0 {
+            // Measure the motion from the previous sample at the next event
+            return;
+        } else {
+            let factor = 1000. / millis as f32;
+            velocity.set(LogicalPosition::new(
+                (position.x - last_position.x) as f32 * factor,
+                (position.y - last_position.y) as f32 * factor,
+            ));
+        }
+        self.velocity_sample.set(Some((now, position)));
+    }
+
     fn cancel_impl(self: Pin<&Self>) {
         if !self.pressed.replace(false) {
             debug_assert!(!self.swiping());
@@ -552,3 +666,137 @@ fn cancel_impl(self: Pin<&Self>) {
         }
     }
 }
+
+/// Recognizes the pinch gesture of two fingers on a touch screen, see
+/// [`crate::input::TouchState`].
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct PinchGestureHandler {
+    pub enabled: Property<bool>,
+
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+
+    pub active: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub center: Property<LogicalPosition>,
+
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for PinchGestureHandler {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.cancel_impl();
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        match event {
+            // A nested handler gets the gesture first
+            MouseEvent::PinchGesture { .. } => InputEventFilterResult::ForwardEvent,
+            _ => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::PinchGesture { position, scale, rotation, phase } => {
+                if phase != GesturePhase::Started && !self.active() {
+                    return InputEventResult::EventIgnored;
+                }
+                Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale);
+                Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation);
+                Self::FIELD_OFFSETS
+                    .center
+                    .apply_pin(self)
+                    .set(crate::lengths::logical_position_to_api(position));
+                match phase {
+                    GesturePhase::Started => {
+                        Self::FIELD_OFFSETS.active.apply_pin(self).set(true);
+                        Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                        InputEventResult::GrabMouse
+                    }
+                    GesturePhase::Updated => {
+                        Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+                        InputEventResult::GrabMouse
+                    }
+                    GesturePhase::Ended => {
+                        Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+                        Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+                        InputEventResult::EventAccepted
+                    }
+                }
+            }
+            MouseEvent::Exit => {
+                self.cancel_impl();
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for PinchGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl PinchGestureHandler {
+    fn cancel_impl(self: Pin<&Self>) {
+        if self.active() {
+            Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+            Self::FIELD_OFFSETS.cancelled.apply_pin(self).call(&());
+        }
+    }
+}
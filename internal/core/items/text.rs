@@ -7,19 +7,24 @@
 When adding an item or a property, it needs to be kept in sync with different place.
 Lookup the [`crate::items`] module documentation.
 */
+use super::text_input_validation;
 use super::{
     FontMetrics, InputType, Item, ItemConsts, ItemRc, ItemRef, KeyEventResult, KeyEventType,
-    PointArg, PointerEventButton, RenderingResult, TextHorizontalAlignment, TextOverflow,
-    TextStrokeStyle, TextVerticalAlignment, TextWrap, VoidArg,
+    PointArg, PointerEventButton, RenderingResult, TextCursorBlink, TextCursorShape,
+    TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment, TextWrap,
+    VoidArg,
 };
-use crate::graphics::{Brush, Color, FontRequest};
+use crate::api::LogicalPosition;
+use crate::graphics::{Brush, Color, FontRequest, FontVariation};
 use crate::input::{
     key_codes, FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyboardModifiers, MouseEvent, StandardShortcut, TextShortcut,
 };
 use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderText};
 use crate::layout::{LayoutInfo, Orientation};
-use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
+use crate::lengths::{
+    logical_position_to_api, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor,
+};
 use crate::platform::Clipboard;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
@@ -55,9 +60,11 @@ pub struct ComplexText {
     pub wrap: Property<TextWrap>,
     pub overflow: Property<TextOverflow>,
     pub letter_spacing: Property<LogicalLength>,
+    pub line_height: Property<LogicalLength>,
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_style: Property<TextStrokeStyle>,
+    pub font_variation_settings: Property<SharedString>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -169,7 +176,9 @@ fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
                 }
             },
             letter_spacing: Some(self.letter_spacing()),
+            line_height: Some(self.line_height()).filter(|height| height.get() > 0 as Coord),
             italic: self.font_italic(),
+            variations: FontVariation::parse_settings(&self.font_variation_settings()),
         }
     }
 
@@ -327,7 +336,9 @@ fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
                 }
             },
             letter_spacing: None,
+            line_height: None,
             italic: false,
+            variations: Default::default(),
         }
     }
 
@@ -473,6 +484,7 @@ pub struct TextInput {
     pub font_size: Property<LogicalLength>,
     pub font_weight: Property<i32>,
     pub font_italic: Property<bool>,
+    pub font_variation_settings: Property<SharedString>,
     pub color: Property<Brush>,
     pub selection_foreground_color: Property<Color>,
     pub selection_background_color: Property<Color>,
@@ -480,15 +492,24 @@ pub struct TextInput {
     pub vertical_alignment: Property<TextVerticalAlignment>,
     pub wrap: Property<TextWrap>,
     pub input_type: Property<InputType>,
+    pub line_height: Property<LogicalLength>,
+    pub input_mask: Property<SharedString>,
+    pub pattern: Property<SharedString>,
+    pub valid: Property<bool>,
     pub letter_spacing: Property<LogicalLength>,
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
     pub cursor_position_byte_offset: Property<i32>,
     pub anchor_position_byte_offset: Property<i32>,
     pub text_cursor_width: Property<LogicalLength>,
-    pub cursor_visible: Property<bool>,
+    pub text_cursor_color: Property<Color>,
+    pub text_cursor_shape: Property<TextCursorShape>,
+    pub text_cursor_blink: Property<TextCursorBlink>,
+    /// Set by the cursor blinker, 0 when the cursor is hidden and 1 when it is fully shown
+    pub cursor_opacity: Property<f32>,
     pub has_focus: Property<bool>,
     pub enabled: Property<bool>,
+    pub tab_index: Property<i32>,
     pub accepted: Callback<VoidArg>,
     pub cursor_position_changed: Callback<PointArg>,
     pub edited: Callback<VoidArg>,
@@ -497,18 +518,30 @@ pub struct TextInput {
     pub preedit_text: Property<SharedString>,
     /// A selection within the preedit (cursor and anchor)
     preedit_selection: Property<PreEditSelection>,
+    pub selection_handles_visible: Property<bool>,
+    pub anchor_handle_position: Property<LogicalPosition>,
+    pub cursor_handle_position: Property<LogicalPosition>,
     pub cached_rendering_data: CachedRenderingData,
     // The x position where the cursor wants to be.
     // It is not updated when moving up and down even when the line is shorter.
     preferred_x_pos: Cell<Coord>,
     /// 0 = not pressed, 1 = single press, 2 = double clicked+press , ...
     pressed: Cell<u8>,
+    /// Incremented on each press, so that the long-press timer of a previous press is ignored
+    long_press_generation: Cell<u32>,
     undo_items: Cell<SharedVector<UndoItem>>,
     redo_items: Cell<SharedVector<UndoItem>>,
 }
 
 impl Item for TextInput {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        let weak = self_rc.downgrade();
+        self.valid.set_binding(move || {
+            weak.upgrade()
+                .and_then(|self_rc| self_rc.downcast::<TextInput>())
+                .map_or(true, |text_input| text_input.as_pin_ref().is_valid())
+        });
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -586,6 +619,7 @@ fn input_event(
             MouseEvent::Pressed { position, button: PointerEventButton::Left, click_count } => {
                 let clicked_offset = self.byte_offset_for_position(position, window_adapter) as i32;
                 self.as_ref().pressed.set((click_count % 3) + 1);
+                self.selection_handles_visible.set(false);
 
                 if !window_adapter.window().0.modifiers.get().shift() {
                     self.as_ref().anchor_position_byte_offset.set(clicked_offset);
@@ -607,6 +641,15 @@ fn input_event(
                     _ => unreachable!(),
                 };
 
+                // On Android, the backend shows the native selection handles and action menu
+                #[cfg(not(target_os = "android"))]
+                if click_count == 0
+                    && WindowInner::from_pub(window_adapter.window()).interaction_mode()
+                        == super::InteractionMode::Touch
+                {
+                    self.start_long_press_timer(self_rc);
+                }
+
                 return InputEventResult::GrabMouse;
             }
             MouseEvent::Pressed { .. } => {
@@ -685,6 +728,17 @@ fn key_event(
                 match event.text_shortcut() {
                     Some(text_shortcut) if !self.read_only() => match text_shortcut {
                         TextShortcut::Move(direction) => {
+                            // The keys map to visual directions, which are reversed in a
+                            // right-to-left paragraph
+                            let text = self.text();
+                            let direction = if crate::textlayout::is_rtl_paragraph(
+                                &text,
+                                self.cursor_position(&text),
+                            ) {
+                                direction.mirrored()
+                            } else {
+                                direction
+                            };
                             TextInput::move_cursor(
                                 self,
                                 direction,
@@ -813,24 +867,28 @@ fn key_event(
                     (self.cursor_position(&text), self.anchor_position(&text))
                 };
 
+                let Some(inserted_text) = self.validate_insertion(&event.text) else {
+                    return KeyEventResult::EventIgnored;
+                };
+
                 self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
 
                 let mut text: String = self.text().into();
 
                 // FIXME: respect grapheme boundaries
                 let insert_pos = self.selection_anchor_and_cursor().1;
-                text.insert_str(insert_pos, &event.text);
+                text.insert_str(insert_pos, &inserted_text);
 
                 self.add_undo_item(UndoItem {
                     pos: insert_pos,
-                    text: event.text.clone(),
+                    text: inserted_text.clone(),
                     cursor: real_cursor,
                     anchor: real_anchor,
                     kind: UndoItemKind::TextInsert,
                 });
 
                 self.as_ref().text.set(text.into());
-                let new_cursor_pos = (insert_pos + event.text.len()) as i32;
+                let new_cursor_pos = (insert_pos + inserted_text.len()) as i32;
                 self.as_ref().anchor_position_byte_offset.set(new_cursor_pos);
                 self.set_cursor_position(
                     new_cursor_pos,
@@ -842,7 +900,7 @@ fn key_event(
 
                 // Keep the cursor visible when inserting text. Blinking should only occur when
                 // nothing is entered or the cursor isn't moved.
-                self.as_ref().show_cursor(window_adapter);
+                self.as_ref().show_cursor(window_adapter, self_rc);
 
                 Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
 
@@ -894,9 +952,12 @@ fn focus_event(
         self_rc: &ItemRc,
     ) -> FocusEventResult {
         match event {
+            FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus if !self.enabled() => {
+                return FocusEventResult::FocusIgnored;
+            }
             FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus => {
                 self.has_focus.set(true);
-                self.show_cursor(window_adapter);
+                self.show_cursor(window_adapter, self_rc);
                 WindowInner::from_pub(window_adapter.window()).set_text_input_focused(true);
                 // FIXME: This should be tracked by a PropertyTracker in window and toggled when read_only() toggles.
                 if !self.read_only() {
@@ -909,8 +970,9 @@ fn focus_event(
             }
             FocusEvent::FocusOut | FocusEvent::WindowLostFocus => {
                 self.has_focus.set(false);
-                self.hide_cursor();
+                self.hide_cursor(window_adapter, self_rc);
                 if matches!(event, FocusEvent::FocusOut) {
+                    self.selection_handles_visible.set(false);
                     self.as_ref()
                         .anchor_position_byte_offset
                         .set(self.as_ref().cursor_position_byte_offset());
@@ -971,6 +1033,21 @@ pub enum TextCursorDirection {
     EndOfText,
 }
 
+impl TextCursorDirection {
+    /// Returns the direction with forward and backward swapped, for right-to-left paragraphs
+    fn mirrored(self) -> Self {
+        match self {
+            Self::Forward => Self::Backward,
+            Self::Backward => Self::Forward,
+            Self::ForwardByWord => Self::BackwardByWord,
+            Self::BackwardByWord => Self::ForwardByWord,
+            Self::StartOfLine => Self::EndOfLine,
+            Self::EndOfLine => Self::StartOfLine,
+            other => other,
+        }
+    }
+}
+
 impl core::convert::TryFrom<char> for TextCursorDirection {
     type Error = ();
 
@@ -1054,6 +1131,8 @@ pub struct TextInputVisualRepresentation {
     pub text_color: Brush,
     /// The color of the blinking cursor
     pub cursor_color: Color,
+    /// The shape of the cursor
+    pub cursor_shape: TextCursorShape,
     text_without_password: Option<String>,
     password_character: char,
 }
@@ -1092,6 +1171,38 @@ fn apply_password_character_substitution(
         self.password_character = password_character;
     }
 
+    /// Returns the byte offset of the end of the character after the cursor, or None if the cursor
+    /// is at the end of a line. Renderers use it to find the width of the `block` and `underline`
+    /// cursor shapes.
+    pub fn cursor_character_end(&self) -> Option<usize> {
+        let cursor = self.cursor_position?;
+        let next = self.text[cursor..].graphemes(true).next()?;
+        (!next.starts_with(['\n', '\r', '\u{2028}', '\u{2029}'])).then(|| cursor + next.len())
+    }
+
+    /// Returns the rectangle to fill with the `cursor_color`, given the rectangle of a bar cursor
+    /// and the width of the character after the cursor, if any.
+    pub fn cursor_shape_rect<Unit>(
+        &self,
+        bar_rect: euclid::Rect<f32, Unit>,
+        character_width: Option<f32>,
+    ) -> euclid::Rect<f32, Unit> {
+        // At the end of a line, the cursor is as wide as half the line height
+        let width = character_width.filter(|w| *w > 0.).unwrap_or(bar_rect.height() / 2.);
+        match self.cursor_shape {
+            TextCursorShape::Bar => bar_rect,
+            TextCursorShape::Block => {
+                euclid::rect(bar_rect.min_x(), bar_rect.min_y(), width, bar_rect.height())
+            }
+            TextCursorShape::Underline => euclid::rect(
+                bar_rect.min_x(),
+                bar_rect.max_y() - bar_rect.width(),
+                width,
+                bar_rect.width(),
+            ),
+        }
+    }
+
     /// Use this function to make a byte offset in the text used for rendering back to a byte offset in the
     /// TextInput's text. The offsets might differ for example for password text input fields.
     pub fn map_byte_offset_from_byte_offset_in_visual_text(&self, byte_offset: usize) -> usize {
@@ -1107,13 +1218,27 @@ pub fn map_byte_offset_from_byte_offset_in_visual_text(&self, byte_offset: usize
 }
 
 impl TextInput {
-    fn show_cursor(&self, window_adapter: &Rc<dyn WindowAdapter>) {
-        WindowInner::from_pub(window_adapter.window())
-            .set_cursor_blink_binding(&self.cursor_visible);
+    fn show_cursor(&self, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        window_inner.set_cursor_blink_binding(
+            &self.cursor_opacity,
+            self.text_cursor_blink() == TextCursorBlink::Fade,
+        );
+        window_inner.mark_text_cursor_dirty(self_rc);
     }
 
-    fn hide_cursor(&self) {
-        self.cursor_visible.set(false);
+    fn hide_cursor(&self, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        self.cursor_opacity.set(0.);
+        WindowInner::from_pub(window_adapter.window()).mark_text_cursor_dirty(self_rc);
+    }
+
+    /// Returns the rectangle of the text cursor, relative to the element
+    pub(crate) fn cursor_rect(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LogicalRect {
+        let cursor_position = self.cursor_position(&self.text());
+        self.cursor_rect_for_byte_offset(cursor_position, window_adapter)
     }
 
     /// Moves the cursor (and/or anchor) and returns true if the cursor position changed; false otherwise.
@@ -1229,7 +1354,7 @@ fn move_cursor(
 
         // Keep the cursor visible when moving. Blinking should only occur when
         // nothing is entered or the cursor isn't moved.
-        self.as_ref().show_cursor(window_adapter);
+        self.as_ref().show_cursor(window_adapter, self_rc);
 
         new_cursor_pos != last_cursor_pos
     }
@@ -1256,6 +1381,9 @@ pub fn set_cursor_position(
                 self.update_ime(window_adapter, self_rc);
             }
         }
+        if self.selection_handles_visible() {
+            self.update_selection_handles(window_adapter);
+        }
     }
 
     fn update_ime(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
@@ -1302,6 +1430,7 @@ pub fn delete_selection(
         if anchor == cursor {
             return;
         }
+        self.selection_handles_visible.set(false);
 
         let removed_text: SharedString = text[anchor..cursor].into();
         // save real anchor/cursor for undo/redo
@@ -1392,6 +1521,45 @@ pub fn selection_anchor_and_cursor(self: Pin<&Self>) -> (usize, usize) {
         }
     }
 
+    /// Returns the text to insert in place of the selection when the user types or pastes
+    /// `text_to_insert`, with the literals of the `input-mask` that the user skipped.
+    /// Returns None if the resulting text doesn't fit the `input-mask` or can't match the `pattern`.
+    fn validate_insertion(self: Pin<&Self>, text_to_insert: &str) -> Option<SharedString> {
+        let mask = self.input_mask();
+        let pattern = self.pattern();
+        if mask.is_empty() && pattern.is_empty() {
+            return Some(text_to_insert.into());
+        }
+        let text = self.text();
+        let (anchor, cursor) = self.selection_anchor_and_cursor();
+        let text = [&text[..anchor], &text[cursor..]].concat();
+        let inserted_text = if mask.is_empty() {
+            text_to_insert.into()
+        } else {
+            text_input_validation::insert_with_input_mask(&mask, &text, anchor, text_to_insert)?
+        };
+        if !pattern.is_empty() {
+            let new_text = [&text[..anchor], &inserted_text, &text[anchor..]].concat();
+            if text_input_validation::match_pattern(&pattern, &new_text)
+                == text_input_validation::PatternMatch::Mismatch
+            {
+                return None;
+            }
+        }
+        Some(inserted_text.into())
+    }
+
+    /// Returns true if the text fills the whole `input-mask` and matches the `pattern`
+    fn is_valid(self: Pin<&Self>) -> bool {
+        let text = self.text();
+        let mask = self.input_mask();
+        let pattern = self.pattern();
+        (mask.is_empty() || text_input_validation::matches_input_mask(&mask, &text))
+            && (pattern.is_empty()
+                || text_input_validation::match_pattern(&pattern, &text)
+                    == text_input_validation::PatternMatch::Match)
+    }
+
     pub fn has_selection(self: Pin<&Self>) -> bool {
         let (anchor_pos, cursor_pos) = self.selection_anchor_and_cursor();
         anchor_pos != cursor_pos
@@ -1406,6 +1574,12 @@ fn insert(
         if text_to_insert.is_empty() {
             return;
         }
+        let mut inserted_text: SharedString = text_to_insert.into();
+        if text_to_insert.contains('\n') && self.single_line() {
+            inserted_text = text_to_insert.replace('\n', " ").into();
+        }
+        let Some(inserted_text) = self.validate_insertion(&inserted_text) else { return };
+        self.selection_handles_visible.set(false);
 
         let (real_cursor, real_anchor) = {
             let text = self.text();
@@ -1415,23 +1589,17 @@ fn insert(
         self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
         let mut text: String = self.text().into();
         let cursor_pos = self.selection_anchor_and_cursor().1;
-        let mut inserted_text: SharedString = text_to_insert.into();
-        if text_to_insert.contains('\n') && self.single_line() {
-            inserted_text = text_to_insert.replace('\n', " ").into();
-            text.insert_str(cursor_pos, &inserted_text);
-        } else {
-            text.insert_str(cursor_pos, text_to_insert);
-        }
+        text.insert_str(cursor_pos, &inserted_text);
 
         self.add_undo_item(UndoItem {
             pos: cursor_pos,
-            text: inserted_text,
+            text: inserted_text.clone(),
             cursor: real_cursor,
             anchor: real_anchor,
             kind: UndoItemKind::TextInsert,
         });
 
-        let cursor_pos = cursor_pos + text_to_insert.len();
+        let cursor_pos = cursor_pos + inserted_text.len();
         self.text.set(text.into());
         self.anchor_position_byte_offset.set(cursor_pos as i32);
         self.set_cursor_position(
@@ -1510,6 +1678,75 @@ pub fn select_word(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, sel
         );
     }
 
+    /// Moves the anchor, or the cursor, of the selection to the character at the given position,
+    /// when one of the selection handles is dragged.
+    pub fn move_selection_handle(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+        anchor: bool,
+        position: LogicalPoint,
+    ) {
+        let offset = self.byte_offset_for_position(position, window_adapter) as i32;
+        if anchor {
+            self.as_ref().anchor_position_byte_offset.set(offset);
+            if self.selection_handles_visible() {
+                self.update_selection_handles(window_adapter);
+            }
+        } else {
+            self.set_cursor_position(
+                offset,
+                true,
+                TextChangeNotify::TriggerCallbacks,
+                window_adapter,
+                self_rc,
+            );
+        }
+    }
+
+    /// Selects the word under the finger and shows the selection handles if the finger stays
+    /// pressed on the same character for the long-press duration.
+    #[cfg(not(target_os = "android"))]
+    fn start_long_press_timer(self: Pin<&Self>, self_rc: &ItemRc) {
+        let generation = self.long_press_generation.get().wrapping_add(1);
+        self.long_press_generation.set(generation);
+        let cursor = self.cursor_position_byte_offset();
+        let weak = self_rc.downgrade();
+        crate::timers::Timer::single_shot(
+            crate::settings::settings().long_press_duration,
+            move || {
+                let Some(self_rc) = weak.upgrade() else { return };
+                let Some(window_adapter) = self_rc.window_adapter() else { return };
+                let Some(text_input) = self_rc.downcast::<TextInput>() else { return };
+                let text_input = text_input.as_pin_ref();
+                // The release or the move of the finger, or another press, cancel the long press
+                if text_input.long_press_generation.get() != generation
+                    || text_input.pressed.get() == 0
+                    || text_input.cursor_position_byte_offset() != cursor
+                {
+                    return;
+                }
+                // Moving the finger after the long press must not change the selection
+                text_input.pressed.set(0);
+                text_input.select_word(&window_adapter, &self_rc);
+                text_input.selection_handles_visible.set(true);
+                text_input.update_selection_handles(&window_adapter);
+            },
+        );
+    }
+
+    /// Places the selection handles at the bottom of the text cursor, at the anchor and at the
+    /// cursor position.
+    fn update_selection_handles(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) {
+        let text = self.text();
+        let handle_position = |byte_offset| {
+            let rect = self.cursor_rect_for_byte_offset(byte_offset, window_adapter);
+            logical_position_to_api(LogicalPoint::new(rect.origin.x, rect.max_y()))
+        };
+        self.anchor_handle_position.set(handle_position(self.anchor_position(&text)));
+        self.cursor_handle_position.set(handle_position(self.cursor_position(&text)));
+    }
+
     fn select_paragraph(
         self: Pin<&Self>,
         window_adapter: &Rc<dyn WindowAdapter>,
@@ -1601,7 +1838,9 @@ pub fn font_request(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) ->
                 }
             },
             letter_spacing: Some(self.letter_spacing()),
+            line_height: Some(self.line_height()).filter(|height| height.get() > 0 as Coord),
             italic: self.font_italic(),
+            variations: FontVariation::parse_settings(&self.font_variation_settings()),
         }
     }
 
@@ -1634,7 +1873,12 @@ pub fn visual_representation(
             let (selection_anchor_pos, selection_cursor_pos) = self.selection_anchor_and_cursor();
             let selection_range = selection_anchor_pos..selection_cursor_pos;
             let cursor_position = self.cursor_position(&text);
-            let cursor_visible = self.cursor_visible() && self.enabled() && !self.read_only();
+            // Not tracked, so that the element isn't repainted when the cursor blinks. The window
+            // marks only the cursor as dirty instead, see `WindowInner::mark_text_cursor_dirty`.
+            let cursor_visible = Self::FIELD_OFFSETS.cursor_opacity.apply_pin(self).get_untracked()
+                > 0.
+                && self.enabled()
+                && !self.read_only();
             let cursor_position = if cursor_visible && selection_range.is_empty() {
                 Some(cursor_position)
             } else {
@@ -1655,6 +1899,14 @@ pub fn visual_representation(
             } else {
                 text_color.color()
             };
+        let cursor_color = match self.text_cursor_color() {
+            color if color.alpha() > 0 => color,
+            _ => cursor_color,
+        };
+        let cursor_opacity =
+            Self::FIELD_OFFSETS.cursor_opacity.apply_pin(self).get_untracked().clamp(0., 1.);
+        let cursor_color =
+            cursor_color.with_alpha(cursor_color.alpha() as f32 / 255. * cursor_opacity);
 
         let mut repr = TextInputVisualRepresentation {
             text,
@@ -1665,6 +1917,7 @@ pub fn visual_representation(
             password_character: Default::default(),
             text_color,
             cursor_color,
+            cursor_shape: self.text_cursor_shape(),
         };
         repr.apply_password_character_substitution(self, password_character_fn);
         repr
@@ -1905,6 +2158,22 @@ fn next_word_boundary(text: &str, last_cursor_pos: usize) -> usize {
     text_input.set_selection_offsets(window_adapter, &self_rc, start, end);
 }
 
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_textinput_move_selection_handle(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+    anchor: bool,
+    x: Coord,
+    y: Coord,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.move_selection_handle(window_adapter, &self_rc, anchor, LogicalPoint::new(x, y));
+}
+
 #[cfg(feature = "ffi")]
 #[no_mangle]
 pub unsafe extern "C" fn slint_textinput_select_all(
@@ -0,0 +1,164 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The `input-mask` and `pattern` of the `TextInput`, which restrict what the user can type.
+//!
+//! In an input mask, `0` stands for a digit, `a` for a letter, and `*` for any character. Every
+//! other character is a literal that is inserted automatically when the user types past it, and
+//! `\` escapes the next character so that it is a literal too.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[derive(Clone, Copy, PartialEq)]
+enum MaskToken {
+    Digit,
+    Letter,
+    Any,
+    Literal(char),
+}
+
+impl MaskToken {
+    fn accepts(self, ch: char) -> bool {
+        match self {
+            MaskToken::Digit => ch.is_ascii_digit(),
+            MaskToken::Letter => ch.is_alphabetic(),
+            MaskToken::Any => true,
+            MaskToken::Literal(literal) => ch == literal,
+        }
+    }
+}
+
+fn parse_mask(mask: &str) -> Vec<MaskToken> {
+    let mut chars = mask.chars();
+    let mut tokens = Vec::new();
+    while let Some(ch) = chars.next() {
+        tokens.push(match ch {
+            '0' => MaskToken::Digit,
+            'a' => MaskToken::Letter,
+            '*' => MaskToken::Any,
+            '\\' => match chars.next() {
+                Some(escaped) => MaskToken::Literal(escaped),
+                None => MaskToken::Literal('\\'),
+            },
+            _ => MaskToken::Literal(ch),
+        });
+    }
+    tokens
+}
+
+/// Returns true if every character of `text` fits the mask. The text may be shorter than the mask.
+fn fits_mask(tokens: &[MaskToken], text: &str) -> bool {
+    let mut tokens = tokens.iter();
+    text.chars().all(|ch| tokens.next().is_some_and(|token| token.accepts(ch)))
+}
+
+/// Returns the text to insert at the byte offset `pos` of `text` when the user types
+/// `text_to_insert`, with the literals of the mask that the user skipped. Returns None if the
+/// resulting text doesn't fit the mask.
+pub(super) fn insert_with_input_mask(
+    mask: &str,
+    text: &str,
+    pos: usize,
+    text_to_insert: &str,
+) -> Option<String> {
+    let tokens = parse_mask(mask);
+    let (before, after) = text.split_at(pos);
+    let mut index = before.chars().count();
+    let mut inserted = String::new();
+    for ch in text_to_insert.chars() {
+        while let Some(MaskToken::Literal(literal)) = tokens.get(index) {
+            if *literal == ch {
+                break;
+            }
+            inserted.push(*literal);
+            index += 1;
+        }
+        if !tokens.get(index)?.accepts(ch) {
+            return None;
+        }
+        inserted.push(ch);
+        index += 1;
+    }
+    let new_text = String::from(before) + &inserted + after;
+    fits_mask(&tokens, &new_text).then_some(inserted)
+}
+
+/// Returns true if `text` fills the whole mask.
+pub(super) fn matches_input_mask(mask: &str, text: &str) -> bool {
+    let tokens = parse_mask(mask);
+    text.chars().count() == tokens.len() && fits_mask(&tokens, text)
+}
+
+/// How a text relates to the regular expression of the `pattern` property
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(super) enum PatternMatch {
+    /// The whole text matches the pattern
+    Match,
+    /// The text doesn't match yet, but it's the beginning of a text that matches
+    Prefix,
+    /// No text that starts with this text can match
+    Mismatch,
+}
+
+/// Matches the whole `text` against the regular expression `pattern`. An invalid pattern matches
+/// every text.
+#[cfg(feature = "std")]
+pub(super) fn match_pattern(pattern: &str, text: &str) -> PatternMatch {
+    use regex_automata::hybrid::dfa::DFA;
+    use regex_automata::{Anchored, Input};
+
+    let Ok(dfa) = DFA::new(&alloc::format!("(?:{pattern})$")) else {
+        crate::debug_log!("Invalid TextInput pattern {pattern:?}");
+        return PatternMatch::Match;
+    };
+    let mut cache = dfa.create_cache();
+    let Ok(mut state) =
+        dfa.start_state_forward(&mut cache, &Input::new(text).anchored(Anchored::Yes))
+    else {
+        return PatternMatch::Match;
+    };
+    for byte in text.bytes() {
+        let Ok(next) = dfa.next_state(&mut cache, state, byte) else { return PatternMatch::Match };
+        if next.is_dead() {
+            return PatternMatch::Mismatch;
+        }
+        state = next;
+    }
+    match dfa.next_eoi_state(&mut cache, state) {
+        Ok(eoi) if !eoi.is_match() => PatternMatch::Prefix,
+        _ => PatternMatch::Match,
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(super) fn match_pattern(_pattern: &str, _text: &str) -> PatternMatch {
+    PatternMatch::Match
+}
+
+#[test]
+fn test_input_mask() {
+    assert_eq!(insert_with_input_mask("00/00", "", 0, "1").as_deref(), Some("1"));
+    assert_eq!(insert_with_input_mask("00/00", "12", 2, "3").as_deref(), Some("/3"));
+    assert_eq!(insert_with_input_mask("00/00", "12", 2, "/").as_deref(), Some("/"));
+    assert_eq!(insert_with_input_mask("00/00", "12", 2, "x"), None);
+    assert_eq!(insert_with_input_mask("00/00", "12/34", 5, "5"), None);
+    assert_eq!(insert_with_input_mask("aa-00", "", 0, "ab12").as_deref(), Some("ab-12"));
+    assert_eq!(insert_with_input_mask("\\0*", "", 0, "0x").as_deref(), Some("0x"));
+    assert_eq!(insert_with_input_mask("\\0*", "", 0, "x").as_deref(), Some("0x"));
+    assert!(matches_input_mask("00/00", "12/34"));
+    assert!(!matches_input_mask("00/00", "12/3"));
+    assert!(!matches_input_mask("00/00", "12-34"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_pattern() {
+    assert_eq!(match_pattern("[0-9]{3}", "12"), PatternMatch::Prefix);
+    assert_eq!(match_pattern("[0-9]{3}", "123"), PatternMatch::Match);
+    assert_eq!(match_pattern("[0-9]{3}", "1234"), PatternMatch::Mismatch);
+    assert_eq!(match_pattern("[0-9]{3}", "1a"), PatternMatch::Mismatch);
+    assert_eq!(match_pattern("a|ab", "a"), PatternMatch::Match);
+    assert_eq!(match_pattern("a|ab", "ab"), PatternMatch::Match);
+    assert_eq!(match_pattern("(", "anything"), PatternMatch::Match);
+}
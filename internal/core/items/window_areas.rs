@@ -0,0 +1,257 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the `WindowMoveArea` and `WindowResizeArea` elements, which let the user
+//! move and resize a window without frame with the pointer, for example from a custom title bar.
+
+use super::{
+    Item, ItemConsts, ItemRc, ItemRendererRef, MouseCursor, PointerEventButton, RenderingResult,
+    WindowResizeEdge,
+};
+use crate::api::PhysicalPosition;
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalPoint, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::Property;
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The implementation of the `WindowMoveArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct WindowMoveArea {
+    pub enabled: Property<bool>,
+    /// The position of the press, when the window is moved by setting its position because the
+    /// windowing system can't move it
+    pressed_position: Cell<Option<LogicalPoint>>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for WindowMoveArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !self.enabled() {
+            self.pressed_position.set(None);
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, click_count } => {
+                let window = WindowInner::from_pub(window_adapter.window());
+                if click_count % 2 == 1 {
+                    // A double click maximizes the window, like on a title bar
+                    window.set_maximized(!window.is_maximized());
+                    return InputEventResult::EventAccepted;
+                }
+                if window_adapter
+                    .internal(crate::InternalToken)
+                    .is_some_and(|x| x.start_window_move())
+                {
+                    // The windowing system has taken over the pointer
+                    return InputEventResult::EventAccepted;
+                }
+                self.pressed_position.set(Some(position));
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Moved { position } => {
+                let Some(pressed_position) = self.pressed_position.get() else {
+                    return InputEventResult::EventIgnored;
+                };
+                // The area moves with the window, so the pointer goes back to the pressed position
+                let Some(window_position) = window_adapter.position() else {
+                    return InputEventResult::GrabMouse;
+                };
+                let scale_factor = window_adapter.window().scale_factor();
+                let delta = position - pressed_position;
+                window_adapter.set_position(
+                    PhysicalPosition::new(
+                        window_position.x + (delta.x as f32 * scale_factor) as i32,
+                        window_position.y + (delta.y as f32 * scale_factor) as i32,
+                    )
+                    .into(),
+                );
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Released { .. } | MouseEvent::Exit => {
+                if self.pressed_position.take().is_some() {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for WindowMoveArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// The implementation of the `WindowResizeArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct WindowResizeArea {
+    pub enabled: Property<bool>,
+    pub edge: Property<WindowResizeEdge>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for WindowResizeArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        if !matches!(event, MouseEvent::Exit) {
+            if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                x.set_mouse_cursor(self.mouse_cursor());
+            }
+        }
+        InputEventFilterResult::ForwardAndInterceptGrab
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Exit => {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.set_mouse_cursor(MouseCursor::Default);
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } if self.enabled() => {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.start_window_resize(self.edge());
+                }
+                InputEventResult::EventAccepted
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for WindowResizeArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl WindowResizeArea {
+    fn mouse_cursor(self: Pin<&Self>) -> MouseCursor {
+        match self.edge() {
+            WindowResizeEdge::BottomRight | WindowResizeEdge::TopLeft => MouseCursor::NwseResize,
+            WindowResizeEdge::BottomLeft | WindowResizeEdge::TopRight => MouseCursor::NeswResize,
+            WindowResizeEdge::Top | WindowResizeEdge::Bottom => MouseCursor::NsResize,
+            WindowResizeEdge::Left | WindowResizeEdge::Right => MouseCursor::EwResize,
+        }
+    }
+}
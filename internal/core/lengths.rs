@@ -16,6 +16,8 @@
 pub type LogicalSize = euclid::Size2D<Coord, LogicalPx>;
 pub type LogicalVector = euclid::Vector2D<Coord, LogicalPx>;
 pub type LogicalBorderRadius = BorderRadius<Coord, LogicalPx>;
+/// A transformation from the coordinates of the children of an item to the coordinates of the item
+pub type LogicalTransform = euclid::Transform2D<f32, LogicalPx, LogicalPx>;
 
 pub type ScaleFactor = euclid::Scale<f32, LogicalPx, PhysicalPx>;
 
@@ -21,10 +21,13 @@
 pub mod accessibility;
 pub mod animations;
 pub mod api;
+pub mod backlight;
 pub mod callbacks;
 pub mod component_factory;
 pub mod context;
+pub mod custom_item;
 pub mod date_time;
+pub mod error_screen;
 pub mod future;
 pub mod graphics;
 pub mod input;
@@ -34,19 +37,25 @@
 pub mod items;
 pub mod layout;
 pub mod lengths;
+pub mod locale;
 pub mod model;
 pub mod platform;
 pub mod properties;
+pub mod regex;
 pub mod renderer;
 #[cfg(feature = "rtti")]
 pub mod rtti;
+pub mod settings;
 pub mod sharedvector;
 pub mod slice;
 #[cfg(feature = "software-renderer")]
 pub mod software_renderer;
 pub mod string;
+#[cfg(feature = "std")]
+pub mod telemetry;
 pub mod tests;
 pub mod textlayout;
+pub mod theme;
 pub mod timers;
 pub mod translations;
 pub mod window;
@@ -0,0 +1,262 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Formatting of numbers, dates, and times with the conventions of the active locale, which is the
+language of the translations.
+
+The functions of the `Locale` namespace of the Slint language call these, and the bindings that use
+them are evaluated again when the language changes with `select_translation_language()`.
+*/
+
+use crate::SharedString;
+use alloc::string::String;
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+
+/// How a locale writes numbers, dates, and times.
+struct Conventions {
+    decimal_separator: char,
+    /// Separates the groups of three digits of the integer part
+    group_separator: Option<char>,
+    /// The format of the short dates, for chrono
+    date_format: &'static str,
+    /// The format of the hours and minutes, for chrono
+    time_format: &'static str,
+}
+
+/// Used when the language isn't known: the ISO 8601 dates and times.
+const NEUTRAL: Conventions = Conventions {
+    decimal_separator: '.',
+    group_separator: None,
+    date_format: "%Y-%m-%d",
+    time_format: "%H:%M",
+};
+
+const NO_BREAK_SPACE: char = '\u{a0}';
+const NARROW_NO_BREAK_SPACE: char = '\u{202f}';
+
+/// Returns the conventions of a language, as returned by `translation_language()`. The region is
+/// only considered for the languages where it changes the conventions.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+fn conventions_for(language: &str) -> Conventions {
+    let (lang, region) = language.split_once('_').unwrap_or((language, ""));
+    let c = |decimal_separator, group_separator, date_format, time_format| Conventions {
+        decimal_separator,
+        group_separator,
+        date_format,
+        time_format,
+    };
+    match (lang, region) {
+        ("en", "" | "us" | "ph") => c('.', Some(','), "%-m/%-d/%Y", "%-I:%M %p"),
+        ("en", "ca") => c('.', Some(','), "%Y-%m-%d", "%-I:%M %p"),
+        ("en", "au" | "nz" | "in") => c('.', Some(','), "%d/%m/%Y", "%-I:%M %p"),
+        ("en", "za") => c(',', Some(NO_BREAK_SPACE), "%Y/%m/%d", "%H:%M"),
+        ("en", _) => c('.', Some(','), "%d/%m/%Y", "%H:%M"),
+        ("de", "ch" | "li") => c('.', Some('\u{2019}'), "%d.%m.%Y", "%H:%M"),
+        ("de", _) => c(',', Some('.'), "%d.%m.%Y", "%H:%M"),
+        ("fr", "ch") => c(',', Some(NARROW_NO_BREAK_SPACE), "%d.%m.%Y", "%H:%M"),
+        ("fr", "ca") => c(',', Some(NO_BREAK_SPACE), "%Y-%m-%d", "%H:%M"),
+        ("fr", _) => c(',', Some(NARROW_NO_BREAK_SPACE), "%d/%m/%Y", "%H:%M"),
+        ("it", "ch") => c('.', Some('\u{2019}'), "%d.%m.%Y", "%H:%M"),
+        ("es" | "it" | "pt" | "el" | "id", _) => c(',', Some('.'), "%d/%m/%Y", "%H:%M"),
+        ("nl", _) => c(',', Some('.'), "%d-%m-%Y", "%H:%M"),
+        ("da" | "tr", _) => c(',', Some('.'), "%d.%m.%Y", "%H:%M"),
+        ("ru" | "uk" | "pl" | "cs" | "sk" | "nb" | "nn" | "no", _) => {
+            c(',', Some(NO_BREAK_SPACE), "%d.%m.%Y", "%H:%M")
+        }
+        ("fi", _) => c(',', Some(NO_BREAK_SPACE), "%-d.%-m.%Y", "%H.%M"),
+        ("sv", _) => c(',', Some(NO_BREAK_SPACE), "%Y-%m-%d", "%H:%M"),
+        ("hu", _) => c(',', Some(NO_BREAK_SPACE), "%Y. %m. %d.", "%H:%M"),
+        ("ja" | "zh", _) => c('.', Some(','), "%Y/%m/%d", "%H:%M"),
+        ("ko", _) => c('.', Some(','), "%Y. %-m. %-d.", "%p %-I:%M"),
+        _ => NEUTRAL,
+    }
+}
+
+fn current_conventions() -> Conventions {
+    #[cfg(feature = "std")]
+    if let Some(language) = crate::translations::translation_language() {
+        return conventions_for(&language);
+    }
+    NEUTRAL
+}
+
+/// Formats the number with `decimals` digits after the decimal separator, and with the digits of
+/// the integer part grouped by thousands.
+pub fn format_number(value: f64, decimals: i32) -> SharedString {
+    if !value.is_finite() {
+        return crate::format!("{value}");
+    }
+    let conventions = current_conventions();
+    let digits = crate::format!("{:.*}", decimals.max(0) as usize, value.abs());
+    let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+
+    let mut result = String::new();
+    if value < 0. && digits.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        result.push('-');
+    }
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            if let Some(separator) = conventions.group_separator {
+                result.push(separator);
+            }
+        }
+        result.push(digit);
+    }
+    if !fraction.is_empty() {
+        result.push(conventions.decimal_separator);
+        result.push_str(fraction);
+    }
+    result.into()
+}
+
+/// Parses a number written with the decimal separator of the locale, and optionally its group
+/// separator. Returns None if the text isn't a number.
+pub fn parse_number(text: &str) -> Option<f64> {
+    let conventions = current_conventions();
+    let text = text
+        .trim()
+        .chars()
+        .filter(|c| match conventions.group_separator {
+            // Also accept the spaces that can be typed instead of the no-break spaces
+            Some(NO_BREAK_SPACE | NARROW_NO_BREAK_SPACE) => !c.is_whitespace(),
+            Some(separator) => *c != separator,
+            None => true,
+        })
+        .map(|c| if c == conventions.decimal_separator { '.' } else { c })
+        .collect::<String>();
+    text.parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+/// Formats the date in the short format of the locale, such as `3/8/2024` in English and
+/// `08.03.2024` in German. Returns an empty string if the date doesn't exist.
+pub fn format_date(day: u32, month: u32, year: i32) -> SharedString {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .map(|date| crate::format!("{}", date.format(current_conventions().date_format)))
+        .unwrap_or_default()
+}
+
+/// Parses a date written in the short format of the locale, and returns its day, month, and year.
+pub fn parse_date(text: &str) -> Option<[i32; 3]> {
+    NaiveDate::parse_from_str(text.trim(), current_conventions().date_format)
+        .ok()
+        .map(|date| [date.day() as i32, date.month() as i32, date.year()])
+}
+
+/// Formats the time in the format of the locale, with a 12-hour clock in the locales that use it,
+/// such as `2:05 PM` in English and `14:05` in German. Returns an empty string if the time doesn't
+/// exist.
+pub fn format_time(hour: u32, minute: u32) -> SharedString {
+    NaiveTime::from_hms_opt(hour, minute, 0)
+        .map(|time| crate::format!("{}", time.format(current_conventions().time_format)))
+        .unwrap_or_default()
+}
+
+/// Parses a time written in the format of the locale, and returns its hour, from 0 to 23, and
+/// minute.
+pub fn parse_time(text: &str) -> Option<[i32; 2]> {
+    NaiveTime::parse_from_str(text.trim(), current_conventions().time_format)
+        .ok()
+        .map(|time| [time.hour() as i32, time.minute() as i32])
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    #[no_mangle]
+    pub extern "C" fn slint_locale_format_number(
+        value: f64,
+        decimals: i32,
+        out: &mut SharedString,
+    ) {
+        *out = format_number(value, decimals)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_locale_parse_number(text: &SharedString, value: &mut f64) -> bool {
+        if let Some(x) = parse_number(text) {
+            *value = x;
+            true
+        } else {
+            false
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_locale_format_date(
+        day: u32,
+        month: u32,
+        year: i32,
+        out: &mut SharedString,
+    ) {
+        *out = format_date(day, month, year)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_locale_parse_date(
+        text: &SharedString,
+        d: &mut i32,
+        m: &mut i32,
+        y: &mut i32,
+    ) -> bool {
+        if let Some(x) = parse_date(text) {
+            [*d, *m, *y] = x;
+            true
+        } else {
+            false
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_locale_format_time(hour: u32, minute: u32, out: &mut SharedString) {
+        *out = format_time(hour, minute)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_locale_parse_time(
+        text: &SharedString,
+        h: &mut i32,
+        m: &mut i32,
+    ) -> bool {
+        if let Some(x) = parse_time(text) {
+            [*h, *m] = x;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_locale_formatting() {
+    use crate::translations::select_translation_language;
+
+    select_translation_language("en_US.UTF-8").unwrap();
+    assert_eq!(format_number(1234567.891, 2), "1,234,567.89");
+    assert_eq!(format_number(-0.001, 2), "0.00");
+    assert_eq!(format_number(-999.5, 0), "-1,000");
+    assert_eq!(parse_number("1,234.5"), Some(1234.5));
+    assert_eq!(format_date(8, 3, 2024), "3/8/2024");
+    assert_eq!(parse_date("3/8/2024"), Some([8, 3, 2024]));
+    assert_eq!(format_time(14, 5), "2:05 PM");
+    assert_eq!(parse_time("2:05 PM"), Some([14, 5]));
+
+    select_translation_language("de").unwrap();
+    assert_eq!(format_number(1234567.891, 2), "1.234.567,89");
+    assert_eq!(parse_number("1.234,5"), Some(1234.5));
+    assert_eq!(parse_number("12a"), None);
+    assert_eq!(format_date(8, 3, 2024), "08.03.2024");
+    assert_eq!(parse_date("8.3.2024"), Some([8, 3, 2024]));
+    assert_eq!(parse_date("2024-03-08"), None);
+    assert_eq!(format_time(14, 5), "14:05");
+
+    select_translation_language("fr").unwrap();
+    assert_eq!(format_number(1234.5, 1), "1\u{202f}234,5");
+    assert_eq!(parse_number("1 234,5"), Some(1234.5));
+
+    select_translation_language("").unwrap();
+}
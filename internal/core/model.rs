@@ -7,7 +7,7 @@
 
 use crate::item_tree::ItemTreeVTable;
 use crate::item_tree::TraversalOrder;
-pub use crate::items::{StandardListViewItem, TableColumn};
+pub use crate::items::{StandardListViewItem, TableColumn, TreeViewItem};
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
@@ -20,12 +20,18 @@
 use euclid::num::Zero;
 #[allow(unused)]
 use euclid::num::{Ceil, Floor};
+#[cfg(target_has_atomic = "ptr")]
+pub use lazy::LazyModel;
 pub use model_peer::*;
 use once_cell::unsync::OnceCell;
 use pin_project::pin_project;
+pub use tree::{FlattenedTreeModel, TreeModel};
 
 mod adapters;
+#[cfg(target_has_atomic = "ptr")]
+mod lazy;
 mod model_peer;
+mod tree;
 
 type ItemTreeRc<C> = vtable::VRc<crate::item_tree::ItemTreeVTable, C>;
 
@@ -790,6 +796,9 @@ pub struct RepeaterTracker<T: RepeatedItemTree> {
     /// Only used for the list view to track if the scrollbar has changed and item needs to be laid out again.
     #[pin]
     listview_geometry_tracker: crate::properties::PropertyTracker,
+    /// When true, an instance that is removed is kept in `retained_instance` to be reused
+    retain_instances: Cell<bool>,
+    retained_instance: RefCell<Option<ItemTreeRc<T>>>,
 }
 
 impl<T: RepeatedItemTree> ModelChangeListener for RepeaterTracker<T> {
@@ -875,6 +884,8 @@ fn default() -> Self {
             model: Property::new_named(ModelRc::default(), "i_slint_core::Repeater::model"),
             is_dirty: Property::new_named(false, "i_slint_core::Repeater::is_dirty"),
             listview_geometry_tracker: Default::default(),
+            retain_instances: Default::default(),
+            retained_instance: Default::default(),
         }
     }
 }
@@ -898,7 +909,12 @@ fn model(self: Pin<&Self>) -> ModelRc<C::Data> {
         let model = self.data().project_ref().model;
 
         if model.is_dirty() {
-            *self.data().inner.borrow_mut() = RepeaterInner::default();
+            let old_inner = core::mem::take(&mut *self.data().inner.borrow_mut());
+            if self.data().retain_instances.get() {
+                if let Some(instance) = old_inner.instances.into_iter().find_map(|c| c.1) {
+                    *self.data().retained_instance.borrow_mut() = Some(instance);
+                }
+            }
             self.data().is_dirty.set(true);
             let m = model.get();
             let peer = self.project_ref().0.model_peer();
@@ -931,12 +947,15 @@ fn ensure_updated_impl(
         let mut any_items_created = false;
         for (i, c) in inner.instances.iter_mut().enumerate() {
             if c.0 == RepeatedInstanceState::Dirty {
-                let created = if c.1.is_none() {
+                let created = if c.1.is_some() {
+                    false
+                } else if let Some(instance) = self.data().retained_instance.borrow_mut().take() {
+                    c.1 = Some(instance);
+                    false
+                } else {
                     any_items_created = true;
                     c.1 = Some(init());
                     true
-                } else {
-                    false
                 };
                 if let Some(data) = model.row_data(i + offset) {
                     c.1.as_ref().unwrap().update(i + offset, data);
@@ -1170,6 +1189,50 @@ pub fn ensure_updated_listview(
         }
     }
 
+    /// Returns the index of the section of the first visible row of the ListView, and the
+    /// position of the first row of the next section relative to the top of the visible area.
+    ///
+    /// `section_rows` contains the first row of each section, in increasing order. The index is -1
+    /// if the first visible row is before the first section. When the next section is not
+    /// instantiated, or when there is none, the position is the bottom of the instantiated rows.
+    /// This must be called after [`Self::ensure_updated_listview()`].
+    pub fn listview_section<T: TryInto<i32> + 'static>(
+        self: Pin<&Self>,
+        section_rows: &ModelRc<T>,
+        viewport_y: LogicalLength,
+    ) -> (i32, LogicalLength) {
+        let inner = self.0.inner.borrow();
+        let geometry = |row: usize| {
+            let instance = inner.instances.get(row.checked_sub(inner.offset)?)?.1.as_ref()?;
+            Some(instance.as_pin_ref().item_geometry(0))
+        };
+        let rows = inner.offset..inner.offset + inner.instances.len();
+        let first_visible_row = rows
+            .clone()
+            .find(|row| geometry(*row).is_some_and(|g| LogicalLength::new(g.max_y()) > -viewport_y))
+            .unwrap_or(inner.offset);
+        let bottom = rows
+            .rev()
+            .find_map(geometry)
+            .map_or(LogicalLength::zero(), |g| LogicalLength::new(g.max_y()));
+
+        let section_row =
+            |section| section_rows.row_data(section).and_then(|row| row.try_into().ok());
+        // The sections are sorted, so look for the first one that starts after the visible row
+        let (mut low, mut high) = (0, section_rows.row_count());
+        while low < high {
+            let middle = (low + high) / 2;
+            match section_row(middle) {
+                Some(row) if row > first_visible_row as i32 => high = middle,
+                _ => low = middle + 1,
+            }
+        }
+        let next_section_y = section_row(low)
+            .and_then(|row| geometry(row.max(0) as usize))
+            .map_or(bottom, |g| LogicalLength::new(g.min_y()));
+        (low as i32 - 1, next_section_y + viewport_y)
+    }
+
     /// Sets the data directly in the model
     pub fn model_set_row_data(self: Pin<&Self>, row: usize, data: C::Data) {
         let model = self.model();
@@ -1181,6 +1244,16 @@ pub fn set_model_binding(&self, binding: impl Fn() -> ModelRc<C::Data> + 'static
         self.0.model.set_binding(binding);
     }
 
+    /// When `retain` is true, the instance that is removed when the model changes is kept, and
+    /// reused for the next row that is created, without being initialized again. Used for the
+    /// conditional elements declared with `retain if`.
+    pub fn set_retain_instances(&self, retain: bool) {
+        self.0.retain_instances.set(retain);
+        if !retain {
+            self.0.retained_instance.take();
+        }
+    }
+
     /// Call the visitor for the root of each instance
     pub fn visit(
         &self,
@@ -45,7 +45,7 @@ fn reset(self: Pin<&Self>) {
 }
 
 #[cfg(test)]
-struct ModelChecker<Data: PartialEq + core::fmt::Debug + 'static> {
+pub(super) struct ModelChecker<Data: PartialEq + core::fmt::Debug + 'static> {
     model: Rc<dyn Model<Data = Data>>,
     rows_copy: RefCell<Vec<Data>>,
 }
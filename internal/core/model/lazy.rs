@@ -0,0 +1,266 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains a model that loads its rows asynchronously, page by page.
+
+use super::*;
+use crate::future::JoinHandle;
+use alloc::collections::VecDeque;
+use core::future::Future;
+use core::ops::Range;
+
+/// The number of rows that are fetched at once, unless changed with [`LazyModel::with_page_size()`]
+const DEFAULT_PAGE_SIZE: usize = 50;
+/// The number of pages that are kept in memory, unless changed with [`LazyModel::with_cached_pages()`]
+const DEFAULT_CACHED_PAGES: usize = 8;
+
+type FetchFunction<T> = Box<dyn Fn(Range<usize>) -> Pin<Box<dyn Future<Output = Vec<T>>>>>;
+
+enum PageState<T> {
+    /// The rows are being fetched by the task
+    Pending(Option<JoinHandle<()>>),
+    Loaded(Vec<T>),
+}
+
+struct Page<T> {
+    index: usize,
+    state: PageState<T>,
+}
+
+struct LazyModelInner<T> {
+    row_count: Cell<usize>,
+    page_size: Cell<usize>,
+    cached_pages: Cell<usize>,
+    /// The pages in the order of their last use, the most recently used first
+    pages: RefCell<VecDeque<Page<T>>>,
+    /// Incremented when the pages are dropped, so that the rows of tasks that were started before
+    /// are ignored
+    generation: Cell<usize>,
+    placeholder: Box<dyn Fn(usize) -> T>,
+    fetch: FetchFunction<T>,
+    notify: ModelNotify,
+}
+
+impl<T> LazyModelInner<T> {
+    fn drop_pages(&self) {
+        self.generation.set(self.generation.get() + 1);
+        for page in self.pages.take() {
+            if let PageState::Pending(Some(handle)) = page.state {
+                handle.abort();
+            }
+        }
+    }
+}
+
+impl<T: Clone + 'static> LazyModelInner<T> {
+    fn page_loaded(&self, index: usize, generation: usize, rows: Vec<T>) {
+        if generation != self.generation.get() {
+            return;
+        }
+        let range = self.page_range(index);
+        {
+            let mut pages = self.pages.borrow_mut();
+            let Some(page) = pages.iter_mut().find(|page| page.index == index) else { return };
+            page.state = PageState::Loaded(rows);
+        }
+        for row in range {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn page_range(&self, index: usize) -> Range<usize> {
+        let start = index * self.page_size.get();
+        start..(start + self.page_size.get()).min(self.row_count.get())
+    }
+}
+
+/// A [`Model`] whose rows are fetched asynchronously, page by page, when a view reads them.
+///
+/// This is meant for lists that are backed by a slow data source, such as an HTTP API or a
+/// database. The model is created with the number of rows and with a function that returns a
+/// future for the rows of a range. While the future runs, [`Model::row_data()`] returns the
+/// placeholder of the row, and once it completes, the views are notified of the fetched rows.
+///
+/// Since a `ListView` only instantiates the visible rows, it requests the pages as the viewport
+/// moves. The pages are kept in memory up to the limit set with [`Self::with_cached_pages()`],
+/// and fetched again when they are needed after they were dropped.
+///
+/// The futures are run with [`spawn_local()`](crate::SlintContext::spawn_local), so they run in
+/// the event loop, and they must be created after the platform was initialized.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # use i_slint_core::model::{LazyModel, ModelRc};
+/// # use i_slint_core::SharedString;
+/// # async fn fetch_titles(offset: usize, count: usize) -> Vec<SharedString> { Vec::new() }
+/// let model = LazyModel::new(
+///     10_000,
+///     |_row| SharedString::from("Loading…"),
+///     |range| async move { fetch_titles(range.start, range.len()).await },
+/// )
+/// .with_page_size(100);
+/// let model = ModelRc::new(model);
+/// ```
+pub struct LazyModel<T> {
+    inner: Rc<LazyModelInner<T>>,
+}
+
+impl<T: Clone + 'static> LazyModel<T> {
+    /// Creates a model with `row_count` rows.
+    ///
+    /// `placeholder` returns the data of a row that is not fetched yet, and `fetch` returns a
+    /// future that resolves to the rows of the given range. If the future resolves to fewer rows
+    /// than requested, the missing rows keep their placeholder.
+    pub fn new<Fut: Future<Output = Vec<T>> + 'static>(
+        row_count: usize,
+        placeholder: impl Fn(usize) -> T + 'static,
+        fetch: impl Fn(Range<usize>) -> Fut + 'static,
+    ) -> Self {
+        Self {
+            inner: Rc::new(LazyModelInner {
+                row_count: Cell::new(row_count),
+                page_size: Cell::new(DEFAULT_PAGE_SIZE),
+                cached_pages: Cell::new(DEFAULT_CACHED_PAGES),
+                pages: Default::default(),
+                generation: Cell::new(0),
+                placeholder: Box::new(placeholder),
+                fetch: Box::new(move |range| Box::pin(fetch(range))),
+                notify: Default::default(),
+            }),
+        }
+    }
+
+    /// Sets the number of rows that are fetched at once.
+    pub fn with_page_size(self, page_size: usize) -> Self {
+        self.inner.page_size.set(page_size.max(1));
+        self.inner.drop_pages();
+        self
+    }
+
+    /// Sets the number of pages that are kept in memory. The least recently used pages are
+    /// dropped first.
+    pub fn with_cached_pages(self, cached_pages: usize) -> Self {
+        self.inner.cached_pages.set(cached_pages.max(1));
+        self
+    }
+
+    /// Returns true if the row was fetched, and false if [`Model::row_data()`] returns its
+    /// placeholder.
+    pub fn is_row_loaded(&self, row: usize) -> bool {
+        let page_size = self.inner.page_size.get();
+        self.inner.pages.borrow().iter().any(|page| match &page.state {
+            PageState::Loaded(rows) => {
+                page.index == row / page_size && row % page_size < rows.len()
+            }
+            PageState::Pending(_) => false,
+        })
+    }
+
+    /// Changes the number of rows, and drops the fetched rows, so that they are fetched again
+    /// when they are shown.
+    pub fn set_row_count(&self, row_count: usize) {
+        self.inner.row_count.set(row_count);
+        self.reset();
+    }
+
+    /// Drops the fetched rows and cancels the pending fetches, for example after the data
+    /// source changed. The views are notified, and fetch the rows they show again.
+    pub fn reset(&self) {
+        self.inner.drop_pages();
+        self.inner.notify.reset();
+    }
+
+    /// Starts fetching the page with the given index.
+    fn request_page(&self, index: usize) {
+        let inner = &self.inner;
+        let generation = inner.generation.get();
+        {
+            let mut pages = inner.pages.borrow_mut();
+            pages.push_front(Page { index, state: PageState::Pending(None) });
+            // Drop the least recently used pages, but keep the ones that are being fetched
+            while pages.len() > inner.cached_pages.get() {
+                let Some(position) =
+                    pages.iter().rposition(|page| matches!(page.state, PageState::Loaded(_)))
+                else {
+                    break;
+                };
+                pages.remove(position);
+            }
+        }
+
+        let future = (inner.fetch)(inner.page_range(index));
+        let weak = Rc::downgrade(inner);
+        let task = async move {
+            let rows = future.await;
+            if let Some(inner) = weak.upgrade() {
+                inner.page_loaded(index, generation, rows);
+            }
+        };
+        let handle = crate::context::GLOBAL_CONTEXT.with(|ctx| match ctx.get() {
+            Some(ctx) => ctx.spawn_local(task).map_err(|err| alloc::format!("{err}")),
+            None => Err("the platform is not initialized".into()),
+        });
+        match handle {
+            Ok(handle) => {
+                if let Some(page) = inner.pages.borrow_mut().iter_mut().find(|page| {
+                    page.index == index && matches!(page.state, PageState::Pending(None))
+                }) {
+                    page.state = PageState::Pending(Some(handle));
+                }
+            }
+            Err(err) => crate::debug_log!("LazyModel: Cannot fetch the rows: {err}"),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Model for LazyModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.inner.row_count.get()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        if row >= self.inner.row_count.get() {
+            return None;
+        }
+        let page_size = self.inner.page_size.get();
+        let index = row / page_size;
+        let cached = {
+            let mut pages = self.inner.pages.borrow_mut();
+            match pages.iter().position(|page| page.index == index) {
+                Some(position) => {
+                    let page = pages.remove(position).unwrap();
+                    let data = match &page.state {
+                        PageState::Loaded(rows) => rows.get(row % page_size).cloned(),
+                        PageState::Pending(_) => None,
+                    };
+                    pages.push_front(page);
+                    Some(data)
+                }
+                None => None,
+            }
+        };
+        match cached {
+            Some(Some(data)) => return Some(data),
+            Some(None) => {}
+            None => self.request_page(index),
+        }
+        Some((self.inner.placeholder)(row))
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.inner.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+impl<T> Drop for LazyModel<T> {
+    fn drop(&mut self) {
+        self.inner.drop_pages();
+    }
+}
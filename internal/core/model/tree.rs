@@ -0,0 +1,248 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the model that shows a hierarchical model in a `TreeView`.
+
+use super::*;
+
+/// A hierarchical model, where each node has a parent and children, such as a file system.
+///
+/// It is shown in a `TreeView` through a [`FlattenedTreeModel`].
+pub trait TreeModel {
+    /// The type that identifies a node, such as a path or a database key
+    type Node: Clone + 'static;
+
+    /// Returns the children of `parent`, or the root nodes if `parent` is `None`.
+    ///
+    /// The children of a node are only requested when it is expanded, so that they can be
+    /// loaded lazily, and they are requested again when the node is expanded after it was
+    /// collapsed.
+    fn children(&self, parent: Option<&Self::Node>) -> Vec<Self::Node>;
+
+    /// Returns true if the node can be expanded. This is called before the children of the node
+    /// are loaded, so it should be cheap.
+    fn has_children(&self, node: &Self::Node) -> bool;
+
+    /// Returns the text shown for the node.
+    fn text(&self, node: &Self::Node) -> SharedString;
+}
+
+struct TreeRow<N> {
+    node: N,
+    depth: usize,
+    expanded: bool,
+}
+
+/// A [`Model`] with one [`TreeViewItem`] for each visible node of a [`TreeModel`], in the order in
+/// which a `TreeView` shows them.
+///
+/// The children of a node are inserted after it when the node is expanded, and removed when it is
+/// collapsed. The `TreeView` expands and collapses the nodes by setting the `expanded` field of
+/// their row.
+///
+/// ## Example
+///
+/// ```rust
+/// # use i_slint_core::model::{FlattenedTreeModel, ModelRc, TreeModel};
+/// # use i_slint_core::SharedString;
+/// use std::path::PathBuf;
+///
+/// struct Files;
+///
+/// impl TreeModel for Files {
+///     type Node = PathBuf;
+///
+///     fn children(&self, parent: Option<&PathBuf>) -> Vec<PathBuf> {
+///         let dir = parent.cloned().unwrap_or_else(|| PathBuf::from("."));
+///         let entries = std::fs::read_dir(dir).into_iter().flatten().flatten();
+///         entries.map(|entry| entry.path()).collect()
+///     }
+///
+///     fn has_children(&self, node: &PathBuf) -> bool {
+///         node.is_dir()
+///     }
+///
+///     fn text(&self, node: &PathBuf) -> SharedString {
+///         node.file_name().unwrap_or_default().to_string_lossy().as_ref().into()
+///     }
+/// }
+///
+/// let model = ModelRc::new(FlattenedTreeModel::new(Files));
+/// ```
+pub struct FlattenedTreeModel<M: TreeModel> {
+    tree: M,
+    rows: RefCell<Vec<TreeRow<M::Node>>>,
+    notify: ModelNotify,
+}
+
+impl<M: TreeModel> FlattenedTreeModel<M> {
+    /// Creates a model that shows the root nodes of `tree`, collapsed.
+    pub fn new(tree: M) -> Self {
+        let rows = Self::root_rows(&tree);
+        Self { tree, rows: RefCell::new(rows), notify: Default::default() }
+    }
+
+    fn root_rows(tree: &M) -> Vec<TreeRow<M::Node>> {
+        tree.children(None)
+            .into_iter()
+            .map(|node| TreeRow { node, depth: 0, expanded: false })
+            .collect()
+    }
+
+    /// Returns the tree shown by this model.
+    pub fn tree(&self) -> &M {
+        &self.tree
+    }
+
+    /// Returns the node shown in the given row.
+    pub fn node(&self, row: usize) -> Option<M::Node> {
+        self.rows.borrow().get(row).map(|row| row.node.clone())
+    }
+
+    /// Returns true if the children of the node of the given row are shown.
+    pub fn is_expanded(&self, row: usize) -> bool {
+        self.rows.borrow().get(row).map_or(false, |row| row.expanded)
+    }
+
+    /// Shows the children of the node of the given row, in the rows that follow it.
+    pub fn expand(&self, row: usize) {
+        let (node, depth) = match self.rows.borrow().get(row) {
+            Some(r) if !r.expanded && self.tree.has_children(&r.node) => (r.node.clone(), r.depth),
+            _ => return,
+        };
+        let children = self.tree.children(Some(&node));
+        let count = children.len();
+        {
+            let mut rows = self.rows.borrow_mut();
+            rows[row].expanded = true;
+            rows.splice(
+                row + 1..row + 1,
+                children.into_iter().map(|node| TreeRow {
+                    node,
+                    depth: depth + 1,
+                    expanded: false,
+                }),
+            );
+        }
+        self.notify.row_changed(row);
+        if count > 0 {
+            self.notify.row_added(row + 1, count);
+        }
+    }
+
+    /// Hides the children of the node of the given row, and the children of its children.
+    pub fn collapse(&self, row: usize) {
+        let count = {
+            let mut rows = self.rows.borrow_mut();
+            let Some(r) = rows.get_mut(row).filter(|r| r.expanded) else { return };
+            r.expanded = false;
+            let depth = r.depth;
+            let count = rows[row + 1..].iter().take_while(|r| r.depth > depth).count();
+            rows.drain(row + 1..row + 1 + count);
+            count
+        };
+        self.notify.row_changed(row);
+        if count > 0 {
+            self.notify.row_removed(row + 1, count);
+        }
+    }
+
+    /// Collapses all the nodes and requests the root nodes again, for example after the tree changed.
+    pub fn reset(&self) {
+        *self.rows.borrow_mut() = Self::root_rows(&self.tree);
+        self.notify.reset();
+    }
+}
+
+impl<M: TreeModel + 'static> Model for FlattenedTreeModel<M> {
+    type Data = TreeViewItem;
+
+    fn row_count(&self) -> usize {
+        self.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let rows = self.rows.borrow();
+        let row = rows.get(row)?;
+        Some(TreeViewItem {
+            text: self.tree.text(&row.node),
+            depth: row.depth as i32,
+            has_children: self.tree.has_children(&row.node),
+            expanded: row.expanded,
+        })
+    }
+
+    /// Expands or collapses the node of the row if the `expanded` field changed. The other fields
+    /// are given by the [`TreeModel`] and are ignored.
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        match (self.is_expanded(row), data.expanded) {
+            (false, true) => self.expand(row),
+            (true, false) => self.collapse(row),
+            _ => {}
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[test]
+fn test_flattened_tree_model() {
+    // Each node is a string of digits, and nodes shorter than 3 digits have two children
+    struct Digits(Cell<usize>);
+    impl TreeModel for Digits {
+        type Node = SharedString;
+        fn children(&self, parent: Option<&SharedString>) -> Vec<SharedString> {
+            self.0.set(self.0.get() + 1);
+            let parent = parent.map_or("", |p| p.as_str());
+            ["1", "2"].iter().map(|digit| alloc::format!("{parent}{digit}").into()).collect()
+        }
+        fn has_children(&self, node: &SharedString) -> bool {
+            node.len() < 3
+        }
+        fn text(&self, node: &SharedString) -> SharedString {
+            node.clone()
+        }
+    }
+
+    let model = Rc::new(FlattenedTreeModel::new(Digits(Cell::new(0))));
+    let _checker = adapters::ModelChecker::new(model.clone());
+    let texts = || model.iter().map(|item| item.text).collect::<Vec<_>>();
+    assert_eq!(texts(), ["1", "2"]);
+    assert_eq!(model.tree().0.get(), 1);
+
+    model.expand(0);
+    assert_eq!(texts(), ["1", "11", "12", "2"]);
+    let item = model.row_data(1).unwrap();
+    assert_eq!((item.depth, item.has_children, item.expanded), (1, true, false));
+
+    // Setting the expanded field of a row, as the TreeView does, expands it
+    model.set_row_data(2, TreeViewItem { expanded: true, ..model.row_data(2).unwrap() });
+    assert_eq!(texts(), ["1", "11", "12", "121", "122", "2"]);
+    let item = model.row_data(3).unwrap();
+    assert_eq!((item.depth, item.has_children), (2, false));
+    assert_eq!(model.tree().0.get(), 3);
+
+    // Leaves can't be expanded
+    model.expand(3);
+    assert_eq!(model.row_count(), 6);
+    assert!(!model.is_expanded(3));
+
+    model.collapse(0);
+    assert_eq!(texts(), ["1", "2"]);
+    assert!(!model.is_expanded(0));
+
+    // The children are loaded again when the node is expanded again
+    model.expand(0);
+    assert_eq!(texts(), ["1", "11", "12", "2"]);
+    assert_eq!(model.tree().0.get(), 4);
+    assert_eq!(model.node(2).unwrap(), "12");
+
+    model.reset();
+    assert_eq!(texts(), ["1", "2"]);
+}
@@ -9,11 +9,19 @@
 
 pub use crate::api::PlatformError;
 use crate::api::{LogicalPosition, LogicalSize};
+pub use crate::backlight::{
+    backlight, fade_backlight, set_backlight, set_backlight_idle_dimming, IdleDimming,
+};
+pub use crate::error_screen::{
+    has_error_screen, set_error_screen, show_error_screen, ErrorScreenInfo,
+};
 pub use crate::renderer::Renderer;
+pub use crate::settings::{reset_settings, set_settings, settings, Settings};
 #[cfg(feature = "software-renderer")]
 pub use crate::software_renderer;
 #[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
 use crate::unsafe_single_threaded::OnceCell;
+pub use crate::window::software_cursor::SoftwareCursor;
 pub use crate::window::{LayoutConstraints, WindowAdapter, WindowProperties};
 use crate::SharedString;
 #[cfg(not(feature = "std"))]
@@ -21,6 +29,8 @@
 use alloc::rc::Rc;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use once_cell::sync::OnceCell;
 #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
@@ -106,8 +116,16 @@ fn duration_since_start(&self) -> core::time::Duration {
     ///
     /// A double click event is a series of two pointer clicks.
     fn click_interval(&self) -> core::time::Duration {
-        // 500ms is the default delay according to https://en.wikipedia.org/wiki/Double-click#Speed_and_timing
-        core::time::Duration::from_millis(500)
+        Settings::default().click_interval
+    }
+
+    /// Returns the interaction settings of the operating system, such as the double-click interval,
+    /// the blink rate of the text cursor, or the distance to scroll for a step of the mouse wheel.
+    ///
+    /// The application can override them with [`set_settings()`].
+    /// The default implementation returns the default settings, with the [`Self::click_interval()`].
+    fn settings(&self) -> Settings {
+        Settings { click_interval: self.click_interval(), ..Default::default() }
     }
 
     /// Sends the given text into the system clipboard.
@@ -128,6 +146,101 @@ fn clipboard_text(&self, _clipboard: Clipboard) -> Option<String> {
     fn debug_log(&self, _arguments: core::fmt::Arguments) {
         crate::tests::default_debug_log(_arguments);
     }
+
+    /// Sets the brightness of the backlight of the screen, from `0.` (off) to `100.` percent.
+    ///
+    /// This is what is called by [`set_backlight()`] and the fading and dimming helpers.
+    /// Implement it on devices that can control the backlight, for example with a PWM output.
+    /// The default implementation returns an error.
+    fn set_backlight(&self, _percent: f32) -> Result<(), PlatformError> {
+        Err(PlatformError::Other("The platform doesn't support controlling the backlight".into()))
+    }
+
+    /// Returns which of the optional features the platform supports, so that the application can
+    /// adapt its user interface at run-time.
+    ///
+    /// The default implementation reports that none of them is supported.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
+    /// Returns the screens, such as the monitors, on which windows can be shown.
+    ///
+    /// Call [`notify_screens_changed()`] when a screen is added or removed, or when its geometry or
+    /// scale factor changes. The default implementation returns an empty list.
+    fn screens(&self) -> Vec<crate::api::Screen> {
+        Vec::new()
+    }
+
+    /// Registers the shortcut with the operating system, so that it's recognized even when the windows
+    /// of the application don't have the focus.
+    ///
+    /// Call [`notify_global_shortcut_pressed()`] from the event loop when the shortcut is pressed.
+    /// The default implementation returns an error, because global shortcuts aren't supported.
+    fn register_global_shortcut(
+        &self,
+        _shortcut: &crate::api::GlobalShortcut,
+    ) -> Result<(), PlatformError> {
+        Err("Global shortcuts are not supported by this platform".into())
+    }
+
+    /// Unregisters a shortcut that was registered with [`Self::register_global_shortcut()`].
+    fn unregister_global_shortcut(&self, _shortcut: &crate::api::GlobalShortcut) {}
+}
+
+/// Invokes the callback set with `slint::on_screens_changed()`. The [`Platform`] calls this when
+/// the list returned by [`Platform::screens()`] changed.
+pub fn notify_screens_changed() {
+    let handler = crate::context::GLOBAL_CONTEXT
+        .with(|ctx| ctx.get().and_then(|ctx| ctx.0.screens_changed_handler.take()));
+    if let Some(mut handler) = handler {
+        handler();
+        // Keep the handler, unless the callback has set a new one
+        crate::context::GLOBAL_CONTEXT.with(|ctx| {
+            if let Some(ctx) = ctx.get() {
+                let mut current = ctx.0.screens_changed_handler.borrow_mut();
+                if current.is_none() {
+                    *current = Some(handler);
+                }
+            }
+        });
+    }
+}
+
+/// Invokes the callback that was registered with `slint::register_global_shortcut()` for the shortcut.
+/// The [`Platform`] calls this from the event loop when a shortcut registered with
+/// [`Platform::register_global_shortcut()`] is pressed.
+pub fn notify_global_shortcut_pressed(shortcut: &crate::api::GlobalShortcut) {
+    let callback = crate::context::GLOBAL_CONTEXT.with(|ctx| {
+        let ctx = ctx.get()?;
+        let shortcuts = ctx.0.global_shortcuts.borrow();
+        shortcuts.iter().find(|(s, _)| s == shortcut).map(|(_, callback)| callback.clone())
+    });
+    // The callback may register or unregister shortcuts, so the list isn't borrowed while it runs
+    if let Some(Ok(mut callback)) = callback.as_ref().map(|c| c.try_borrow_mut()) {
+        callback();
+    }
+}
+
+/// The optional features that a [`Platform`] may support, as returned by [`Platform::capabilities()`].
+///
+/// Using a feature that isn't supported doesn't fail: it's ignored or emulated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// Text can be copied to, and pasted from, the clipboard.
+    pub clipboard: bool,
+    /// Text can be composed with an input method or entered with a virtual keyboard.
+    pub input_method: bool,
+    /// Several windows can be shown at the same time.
+    pub multiple_windows: bool,
+    /// Popups are shown in their own window, which can extend beyond the bounds of the window
+    /// that opens them. Otherwise they're drawn into that window, and clipped by it.
+    pub popup_windows: bool,
+    /// Windows can be switched to and from fullscreen with [`Window::set_fullscreen()`](crate::api::Window::set_fullscreen).
+    pub fullscreen: bool,
+    /// The shape of the mouse cursor follows the `mouse-cursor` property of the `TouchArea`.
+    pub mouse_cursors: bool,
 }
 
 /// The clip board, used in [`Platform::clipboard_text`] and [Platform::set_clipboard_text`]
@@ -260,6 +373,45 @@ pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
 // reexport key enum to the public api
 pub use crate::input::key_codes::Key;
 pub use crate::input::PointerEventButton;
+pub use crate::items::PointerEventDevice;
+
+/// The state of the device that generates the pointer events, such as the pressure and the tilt of a pen.
+/// It's sent with [`WindowEvent::PointerDetailsChanged`] and reported in the `PointerEvent` of the
+/// `TouchArea`'s `pointer-event` callback.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub struct PointerDetails {
+    /// The kind of device.
+    pub device: PointerEventDevice,
+    /// The pressure, between 0 and 1, or `None` if the device doesn't report it.
+    pub pressure: Option<f32>,
+    /// The angle of a pen in degrees, between -90 and 90, from the normal of the screen towards
+    /// the right side. 0 for devices that aren't tilted.
+    pub tilt_x: f32,
+    /// The angle of a pen in degrees, between -90 and 90, from the normal of the screen towards
+    /// the bottom side. 0 for devices that aren't tilted.
+    pub tilt_y: f32,
+}
+
+impl PointerDetails {
+    /// Creates the details of a device, without pressure and tilt.
+    pub fn new(device: PointerEventDevice) -> Self {
+        Self { device, ..Default::default() }
+    }
+
+    /// Returns the details with the pressure, between 0 and 1.
+    pub fn with_pressure(mut self, pressure: f32) -> Self {
+        self.pressure = Some(pressure.clamp(0., 1.));
+        self
+    }
+
+    /// Returns the details with the tilt, in degrees.
+    pub fn with_tilt(mut self, tilt_x: f32, tilt_y: f32) -> Self {
+        self.tilt_x = tilt_x.clamp(-90., 90.);
+        self.tilt_y = tilt_y.clamp(-90., 90.);
+        self
+    }
+}
 
 /// A event that describes user input or windowing system events.
 ///
@@ -299,6 +451,25 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A finger touched the screen.
+    ///
+    /// The first finger acts like the left button of a pointer, and two fingers are recognized
+    /// as a pinch gesture that the `PinchGestureHandler` element handles.
+    TouchPressed {
+        /// Tells the fingers apart, it must stay the same until the finger is released.
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger moved on the screen.
+    TouchMoved { touch_id: u64, position: LogicalPosition },
+    /// A finger was lifted from the screen, or the touch was cancelled.
+    TouchReleased { touch_id: u64, position: LogicalPosition },
+    /// The device that generates the pointer events, or its pressure or tilt, changed.
+    ///
+    /// The backend should dispatch this event before the pointer events of a pen, such as a stylus on a
+    /// graphics tablet, and to go back to [`PointerEventDevice::Mouse`] when the mouse is used again.
+    /// The touch events switch to [`PointerEventDevice::Touch`] on their own.
+    PointerDetailsChanged { details: PointerDetails },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -362,6 +533,14 @@ pub enum WindowEvent {
     /// The backend should dispatch this event with true when the window gains focus
     /// and false when the window loses focus.
     WindowActiveChanged(bool),
+
+    /// A frame was presented on the screen.
+    ///
+    /// The backend should send this event each time the rendered content of the window was successfully
+    /// put on the screen, for example after swapping buffers or after a page flip completed.
+    ///
+    /// This will have the effect of invoking the callback set in [`Window::on_frame_presented()`](`crate::api::Window::on_frame_presented()`).
+    FramePresented,
 }
 
 impl WindowEvent {
@@ -372,6 +551,9 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::TouchPressed { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchReleased { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -288,6 +288,7 @@ struct BindingVTable {
     intercept_set: unsafe fn(_self: *const BindingHolder, value: *const ()) -> bool,
     intercept_set_binding:
         unsafe fn(_self: *const BindingHolder, new_binding: *mut BindingHolder) -> bool,
+    is_animating: unsafe fn(_self: *const BindingHolder) -> bool,
 }
 
 /// A binding trait object can be used to dynamically produces values for a property.
@@ -320,6 +321,12 @@ unsafe fn intercept_set_binding(self: Pin<&Self>, _new_binding: *mut BindingHold
         false
     }
 
+    /// Returns true if the binding is animating the value of the property, in which case the
+    /// value changes with the time until the animation finishes.
+    fn is_animating(self: Pin<&Self>) -> bool {
+        false
+    }
+
     /// Set to true if and only if Self is a TwoWayBinding<T>
     const IS_TWO_WAY_BINDING: bool = false;
 }
@@ -454,6 +461,11 @@ unsafe fn intercept_set_binding<B: BindingCallable>(
             .intercept_set_binding(new_binding)
     }
 
+    /// Safety: _self must be a pointer to a `BindingHolder<B>`
+    unsafe fn is_animating<B: BindingCallable>(_self: *const BindingHolder) -> bool {
+        Pin::new_unchecked(&((*(_self as *const BindingHolder<B>)).binding)).is_animating()
+    }
+
     trait HasBindingVTable {
         const VT: &'static BindingVTable;
     }
@@ -464,6 +476,7 @@ impl<B: BindingCallable> HasBindingVTable for B {
             mark_dirty: mark_dirty::<B>,
             intercept_set: intercept_set::<B>,
             intercept_set_binding: intercept_set_binding::<B>,
+            is_animating: is_animating::<B>,
         };
     }
 
@@ -534,6 +547,16 @@ fn access<R>(&self, f: impl FnOnce(Option<Pin<&mut BindingHolder>>) -> R) -> R {
         }
     }
 
+    /// Returns true if the binding of the property is animating its value.
+    fn is_animating(&self) -> bool {
+        self.access(|binding| {
+            binding.map_or(false, |b| unsafe {
+                // Safety: b is a BindingHolder<T>
+                (b.vtable.is_animating)(&*b as *const BindingHolder)
+            })
+        })
+    }
+
     fn remove_binding(&self) {
         assert!(!self.lock_flag(), "Recursion detected");
         let val = self.handle.get();
@@ -954,6 +977,16 @@ pub fn is_dirty(&self) -> bool {
         self.handle.access(|binding| binding.map_or(false, |b| b.dirty.get()))
     }
 
+    /// Returns true if the value of the property is animated by an animated binding, or
+    /// since it was set with `set_animated_value()`, until the animation finishes.
+    ///
+    /// The property is evaluated first, so the animations that start or finish with this
+    /// evaluation are taken into account, and a binding calling this depends on the property.
+    pub fn is_animating(self: Pin<&Self>) -> bool {
+        self.get();
+        self.handle.is_animating()
+    }
+
     /// Internal function to mark the property as dirty and notify dependencies, regardless of
     /// whether the property value has actually changed or not.
     pub fn mark_dirty(&self) {
@@ -1033,6 +1066,10 @@ unsafe fn intercept_set_binding(
                 true
             }
 
+            fn is_animating(self: Pin<&Self>) -> bool {
+                self.common_property.handle.is_animating()
+            }
+
             const IS_TWO_WAY_BINDING: bool = true;
         }
 
@@ -1386,6 +1423,7 @@ fn default() -> Self {
             mark_dirty: |_, _| (),
             intercept_set: |_, _| false,
             intercept_set_binding: |_, _| false,
+            is_animating: |_| false,
         };
 
         let holder = BindingHolder {
@@ -1513,6 +1551,7 @@ impl<B: PropertyDirtyHandler> HasBindingVTable for B {
                 mark_dirty: mark_dirty::<B>,
                 intercept_set: |_, _| false,
                 intercept_set_binding: |_, _| false,
+                is_animating: |_| false,
             };
         }
 
@@ -92,6 +92,7 @@ impl<T: PartialEq, EF: Fn(&Data) -> T, NF: Fn(&Data, &T), Data> HasBindingVTable
                 mark_dirty: ChangeTracker::mark_dirty,
                 intercept_set: |_, _| false,
                 intercept_set_binding: |_, _| false,
+                is_animating: |_| false,
             };
         }
         let holder = BindingHolder {
@@ -146,6 +146,13 @@ pub extern "C" fn slint_property_is_dirty(handle: &PropertyHandleOpaque) -> bool
     handle.0.access(|binding| binding.map_or(false, |b| b.dirty.get()))
 }
 
+/// Returns whether the binding of the property behind this handle is animating its value.
+/// The property must be evaluated before.
+#[no_mangle]
+pub extern "C" fn slint_property_is_animating(handle: &PropertyHandleOpaque) -> bool {
+    handle.0.is_animating()
+}
+
 /// Marks the property as dirty and notifies dependencies.
 #[no_mangle]
 pub extern "C" fn slint_property_mark_dirty(handle: &PropertyHandleOpaque) {
@@ -371,6 +378,18 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     );
 }
 
+/// Internal function to interpolate the colors of the fields of an animated struct.
+#[no_mangle]
+pub extern "C" fn slint_interpolate_color(from: &Color, to: &Color, t: f32, out: &mut Color) {
+    *out = from.interpolate(to, t);
+}
+
+/// Internal function to interpolate the brushes of the fields of an animated struct.
+#[no_mangle]
+pub extern "C" fn slint_interpolate_brush(from: &Brush, to: &Brush, t: f32, out: &mut Brush) {
+    *out = from.interpolate(to, t);
+}
+
 /// Internal function to set up a state binding on a Property<StateInfo>.
 #[no_mangle]
 pub unsafe extern "C" fn slint_property_set_state_binding(
@@ -517,6 +536,7 @@ unsafe fn evaluate(_self: *mut BindingHolder, _value: *mut ()) -> BindingResult
         mark_dirty: ChangeTracker::mark_dirty,
         intercept_set: |_, _| false,
         intercept_set_binding: |_, _| false,
+        is_animating: |_| false,
     };
 
     ct.clear();
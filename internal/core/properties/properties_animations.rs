@@ -24,9 +24,21 @@ pub(super) struct PropertyValueAnimationData<T> {
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
-    pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
+    pub fn new(from_value: T, to_value: T, mut details: PropertyAnimation) -> Self {
         let start_time = crate::animations::current_tick();
 
+        // Without a duration, a spring animation lasts until the spring settles
+        if let crate::animations::EasingCurve::Spring { stiffness, damping, mass } = details.easing
+        {
+            if details.duration <= 0 {
+                if let Some(settling_time) =
+                    crate::animations::spring_settling_time(stiffness, damping, mass)
+                {
+                    details.duration = settling_time.as_millis() as _;
+                }
+            }
+        }
+
         Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
     }
 
@@ -183,6 +195,9 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
         };
         BindingResult::KeepBinding
     }
+    fn is_animating(self: Pin<&Self>) -> bool {
+        self.state.get() == AnimatedBindingState::Animating
+    }
     fn mark_dirty(self: Pin<&Self>) {
         if self.state.get() == AnimatedBindingState::ShouldStart {
             return;
@@ -195,6 +210,30 @@ fn mark_dirty(self: Pin<&Self>) {
     }
 }
 
+/// The binding of a property set with `set_animated_value()`, which is removed when the animation
+/// finishes.
+pub(super) struct AnimatedValueBinding<T> {
+    pub(super) animation_data: RefCell<PropertyValueAnimationData<T>>,
+}
+
+unsafe impl<T: InterpolatedPropertyValue + Clone> BindingCallable for AnimatedValueBinding<T> {
+    unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
+        let (val, finished) = self.animation_data.borrow_mut().compute_interpolated_value();
+        *(value as *mut T) = val;
+        if finished {
+            BindingResult::RemoveBinding
+        } else {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.set_has_active_animations());
+            BindingResult::KeepBinding
+        }
+    }
+
+    fn is_animating(self: Pin<&Self>) -> bool {
+        true
+    }
+}
+
 /// InterpolatedPropertyValue is a trait used to enable properties to be used with
 /// animations that interpolate values. The basic requirement is the ability to apply
 /// a progress that's typically between 0 and 1 to a range.
@@ -237,6 +276,26 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
     }
 }
 
+/// The anonymous structs of the generated code are tuples, which are interpolated field by field.
+macro_rules! impl_interpolated_property_value_for_tuple {
+    ($($T:ident: $index:tt),*) => {
+        impl<$($T: InterpolatedPropertyValue),*> InterpolatedPropertyValue for ($($T,)*) {
+            fn interpolate(&self, target_value: &Self, t: f32) -> Self {
+                ($(self.$index.interpolate(&target_value.$index, t),)*)
+            }
+        }
+    };
+}
+
+impl_interpolated_property_value_for_tuple!(A: 0);
+impl_interpolated_property_value_for_tuple!(A: 0, B: 1);
+impl_interpolated_property_value_for_tuple!(A: 0, B: 1, C: 2);
+impl_interpolated_property_value_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_interpolated_property_value_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_interpolated_property_value_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_interpolated_property_value_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_interpolated_property_value_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
 impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// Change the value of this property, by animating (interpolating) from the current property's value
     /// to the specified parameter value. The animation is done according to the parameters described by
@@ -251,20 +310,10 @@ pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
             value,
             animation_data,
         ));
-        // Safety: the BindingCallable will cast its argument to T
+        // Safety: the AnimatedValueBinding's type match the property type
         unsafe {
             self.handle.set_binding(
-                move |val: *mut ()| {
-                    let (value, finished) = d.borrow_mut().compute_interpolated_value();
-                    *(val as *mut T) = value;
-                    if finished {
-                        BindingResult::RemoveBinding
-                    } else {
-                        crate::animations::CURRENT_ANIMATION_DRIVER
-                            .with(|driver| driver.set_has_active_animations());
-                        BindingResult::KeepBinding
-                    }
-                },
+                properties_animations::AnimatedValueBinding { animation_data: d },
                 #[cfg(slint_debug_property)]
                 self.debug_name.borrow().as_str(),
             );
@@ -432,6 +481,38 @@ fn properties_test_animation_negative_delay_triggered_by_set() {
         compo.width.handle.access(|binding| assert!(binding.is_none()));
     }
 
+    #[test]
+    fn properties_test_spring_animation_without_duration() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            easing: crate::animations::EasingCurve::Spring {
+                stiffness: 100.,
+                damping: 10.,
+                mass: 1.,
+            },
+            ..PropertyAnimation::default()
+        };
+        let settling_time = crate::animations::spring_settling_time(100., 10., 1.).unwrap();
+        assert_eq!(settling_time.as_millis(), 1381);
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        // The under-damped spring overshoots the end value by 16% after half a period
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(363))
+        });
+        assert_eq!(get_prop_value(&compo.width), 216);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + settling_time));
+        assert_eq!(get_prop_value(&compo.width), 200);
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
     #[test]
     fn properties_test_animation_triggered_by_set() {
         let compo = Component::new_test_component();
@@ -853,6 +934,55 @@ fn properties_test_animation_triggered_by_binding() {
         assert_eq!(get_prop_value(&compo.width_times_two), 400);
     }
 
+    #[test]
+    fn properties_test_is_animating() {
+        let compo = Component::new_test_component();
+        let width = unsafe { Pin::new_unchecked(&compo.width) };
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+        assert!(!width.is_animating());
+
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(200, animation_details.clone());
+        assert!(width.is_animating());
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert!(width.is_animating());
+        assert_eq!(get_prop_value(&compo.width), 150);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert!(!width.is_animating());
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+        compo.feed_property.set(200);
+        assert!(!width.is_animating());
+
+        let start_time = crate::animations::current_tick();
+        compo.feed_property.set(300);
+        assert!(width.is_animating());
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert!(!width.is_animating());
+        assert_eq!(get_prop_value(&compo.width), 300);
+    }
+
     #[test]
     fn properties_test_delayed_animation_triggered_by_binding() {
         let compo = Component::new_test_component();
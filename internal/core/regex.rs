@@ -0,0 +1,159 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+The regular expressions of the `matches()`, `replace()`, and `capture()` functions of the strings in
+the Slint language.
+
+The patterns have the syntax of the `regex` crate. They are compiled the first time they're used,
+and kept for the next evaluations of the bindings. An invalid pattern matches no text. Without the
+`std` feature, no pattern matches.
+*/
+
+use crate::SharedString;
+
+#[cfg(feature = "std")]
+mod engine {
+    use regex_automata::nfa::thompson::pikevm::{Cache, PikeVM};
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    /// The number of compiled patterns after which they're all compiled again
+    const MAX_COMPILED_PATTERNS: usize = 64;
+
+    thread_local! {
+        static COMPILED_PATTERNS: RefCell<HashMap<String, Option<(PikeVM, Cache)>>> =
+            Default::default();
+    }
+
+    /// Calls `f` with the compiled pattern, or returns None if the pattern is invalid.
+    pub fn with_regex<R>(pattern: &str, f: impl FnOnce(&PikeVM, &mut Cache) -> R) -> Option<R> {
+        COMPILED_PATTERNS.with(|compiled| {
+            let mut compiled = compiled.borrow_mut();
+            if !compiled.contains_key(pattern) {
+                if compiled.len() >= MAX_COMPILED_PATTERNS {
+                    compiled.clear();
+                }
+                let regex = PikeVM::new(pattern).ok().map(|regex| {
+                    let cache = regex.create_cache();
+                    (regex, cache)
+                });
+                if regex.is_none() {
+                    crate::debug_log!("Invalid regular expression {pattern:?}");
+                }
+                compiled.insert(pattern.into(), regex);
+            }
+            let (regex, cache) = compiled.get_mut(pattern)?.as_mut()?;
+            Some(f(regex, cache))
+        })
+    }
+}
+
+/// Returns true if the regular expression matches the text or a part of it.
+pub fn regex_matches(text: &str, pattern: &str) -> bool {
+    #[cfg(feature = "std")]
+    {
+        engine::with_regex(pattern, |regex, cache| regex.is_match(cache, text)).unwrap_or(false)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = (text, pattern);
+        false
+    }
+}
+
+/// Replaces all the matches of the regular expression in the text with `replacement`, where `$1`
+/// or `${name}` stand for the text of a group of the match.
+pub fn regex_replace(text: &str, pattern: &str, replacement: &str) -> SharedString {
+    #[cfg(feature = "std")]
+    {
+        engine::with_regex(pattern, |regex, cache| {
+            let mut result = String::new();
+            let mut last_end = 0;
+            for captures in regex.captures_iter(cache, text) {
+                let Some(m) = captures.get_match() else { continue };
+                result.push_str(&text[last_end..m.start()]);
+                captures.interpolate_string_into(text, replacement, &mut result);
+                last_end = m.end();
+            }
+            result.push_str(&text[last_end..]);
+            SharedString::from(result)
+        })
+        .unwrap_or_else(|| text.into())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = (pattern, replacement);
+        text.into()
+    }
+}
+
+/// Returns the text of the group `group` of the first match of the regular expression, where the
+/// group 0 is the whole match. Returns an empty string if the regular expression doesn't match,
+/// or if the group isn't part of the match.
+pub fn regex_capture(text: &str, pattern: &str, group: i32) -> SharedString {
+    #[cfg(feature = "std")]
+    {
+        engine::with_regex(pattern, |regex, cache| {
+            let mut captures = regex.create_captures();
+            regex.captures(cache, text, &mut captures);
+            let span = captures.get_group(usize::try_from(group).ok()?)?;
+            Some(SharedString::from(&text[span.range()]))
+        })
+        .flatten()
+        .unwrap_or_default()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = (text, pattern, group);
+        SharedString::default()
+    }
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    #[no_mangle]
+    pub extern "C" fn slint_regex_matches(text: &SharedString, pattern: &SharedString) -> bool {
+        regex_matches(text, pattern)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_regex_replace(
+        text: &SharedString,
+        pattern: &SharedString,
+        replacement: &SharedString,
+        out: &mut SharedString,
+    ) {
+        *out = regex_replace(text, pattern, replacement)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_regex_capture(
+        text: &SharedString,
+        pattern: &SharedString,
+        group: i32,
+        out: &mut SharedString,
+    ) {
+        *out = regex_capture(text, pattern, group)
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_regex_functions() {
+    assert!(regex_matches("order 66", r"\d+"));
+    assert!(!regex_matches("order 66", r"^\d+$"));
+    assert!(!regex_matches("anything", "("));
+    assert_eq!(regex_replace("a-b-c", "-", "+"), "a+b+c");
+    assert_eq!(regex_replace("2024-03-08", r"(\d+)-(\d+)-(\d+)", "$3.$2.$1"), "08.03.2024");
+    assert_eq!(regex_replace("abc", "x*", "-"), "-a-b-c-");
+    assert_eq!(regex_replace("unchanged", "(", "-"), "unchanged");
+    assert_eq!(regex_capture("key=value", r"(\w+)=(\w+)", 2), "value");
+    assert_eq!(regex_capture("key=value", r"(?<key>\w+)=", 0), "key=");
+    assert_eq!(regex_capture("key=value", r"(\w+)=(\w+)", 3), "");
+    assert_eq!(regex_capture("no match", r"\d", 0), "");
+}
@@ -44,6 +44,7 @@ macro_rules! declare_ValueType_2 {
             crate::graphics::Point,
             crate::items::PointerEvent,
             crate::items::PointerScrollEvent,
+            crate::items::DropEvent,
             crate::lengths::LogicalLength,
             crate::component_factory::ComponentFactory,
             crate::api::LogicalPosition,
@@ -91,6 +92,9 @@ fn set_binding(
         animation: AnimatedBindingKind,
     ) -> Result<(), ()>;
 
+    /// Returns true if the value of the property is being animated, see `Property::is_animating()`.
+    fn is_animating(&self, item: Pin<&Item>) -> bool;
+
     /// The offset of the property in the item.
     /// The use of this is unsafe
     fn offset(&self) -> usize;
@@ -149,6 +153,9 @@ fn set_binding(
             Ok(())
         }
     }
+    fn is_animating(&self, item: Pin<&Item>) -> bool {
+        self.apply_pin(item).is_animating()
+    }
     fn offset(&self) -> usize {
         self.get_byte_offset()
     }
@@ -241,6 +248,9 @@ fn set_binding_impl<T, Value>(
         }
         set_binding_impl(self.apply_pin(item), binding, animation)
     }
+    fn is_animating(&self, item: Pin<&Item>) -> bool {
+        self.apply_pin(item).is_animating()
+    }
     fn offset(&self) -> usize {
         self.get_byte_offset()
     }
@@ -0,0 +1,89 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Interaction settings, such as the double-click interval, the distance that the pointer must
+    move before a flick or a drag starts, or the duration of a long press.
+
+    The platform provides the settings of the operating system in [`Platform::settings()`](crate::platform::Platform::settings).
+    The application can override them with [`set_settings()`].
+*/
+
+#![warn(missing_docs)]
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+use core::cell::Cell;
+use core::time::Duration;
+
+/// The interaction settings used by the input handling of Slint, see [`settings()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Settings {
+    /// The maximum duration between two clicks for them to be recognized as a double click.
+    pub click_interval: Duration,
+    /// The duration of a full blink cycle of the text cursor: the cursor is shown during the first
+    /// half, and hidden during the second half. Zero disables the blinking.
+    pub cursor_flash_time: Duration,
+    /// The number of lines that a step of the mouse wheel scrolls. A line is 20 logical pixels.
+    pub wheel_scroll_lines: u32,
+    /// The distance, in logical pixels, that the pointer must move while pressed before a flick,
+    /// a swipe, or a drag starts.
+    pub drag_threshold: f32,
+    /// The duration that a finger must stay pressed without moving for a long press, which
+    /// selects a word in a text input on touch screens.
+    pub long_press_duration: Duration,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            // 500ms is the default delay according to https://en.wikipedia.org/wiki/Double-click#Speed_and_timing
+            click_interval: Duration::from_millis(500),
+            cursor_flash_time: Duration::from_millis(1000),
+            wheel_scroll_lines: 3,
+            drag_threshold: 8.,
+            long_press_duration: Duration::from_millis(500),
+        }
+    }
+}
+
+impl Settings {
+    /// Returns the number of logical pixels that a step of the mouse wheel scrolls.
+    pub fn wheel_scroll_distance(&self) -> f32 {
+        self.wheel_scroll_lines as f32 * 20.
+    }
+}
+
+thread_local! {
+    static SETTINGS_OVERRIDE: Cell<Option<Settings>> = const { Cell::new(None) }
+}
+
+/// Returns the interaction settings in use: the ones set with [`set_settings()`], or the ones of
+/// the platform otherwise.
+pub fn settings() -> Settings {
+    SETTINGS_OVERRIDE.with(|s| s.get()).unwrap_or_else(|| {
+        crate::context::GLOBAL_CONTEXT
+            .with(|ctx| ctx.get().map(|ctx| ctx.platform().settings()))
+            .unwrap_or_default()
+    })
+}
+
+/// Overrides the interaction settings of the platform.
+///
+/// To change only some of the settings, modify the ones returned by [`settings()`]:
+///
+/// ```rust
+/// let mut settings = i_slint_core::platform::settings();
+/// settings.click_interval = core::time::Duration::from_millis(300);
+/// i_slint_core::platform::set_settings(settings);
+/// # assert_eq!(i_slint_core::platform::settings().click_interval.as_millis(), 300);
+/// ```
+pub fn set_settings(settings: Settings) {
+    SETTINGS_OVERRIDE.with(|s| s.set(Some(settings)));
+}
+
+/// Removes the override set with [`set_settings()`], so that the settings of the platform apply again.
+pub fn reset_settings() {
+    SETTINGS_OVERRIDE.with(|s| s.set(None));
+}
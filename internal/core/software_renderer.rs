@@ -15,13 +15,14 @@
 use crate::api::{PlatformError, Window};
 use crate::graphics::rendering_metrics_collector::{RefreshMode, RenderingMetricsCollector};
 use crate::graphics::{
-    BorderRadius, PixelFormat, Rgba8Pixel, SharedImageBuffer, SharedPixelBuffer,
+    BorderEdges, BorderRadius, PixelFormat, Rgba8Pixel, SharedImageBuffer, SharedPixelBuffer,
+    StaticTexture, TextureCompression,
 };
 use crate::item_rendering::{CachedRenderingData, DirtyRegion, RenderBorderRectangle, RenderImage};
-use crate::items::{ItemRc, TextOverflow, TextWrap};
+use crate::items::{ItemRc, TextCursorShape, TextOverflow, TextStrokeStyle, TextWrap};
 use crate::lengths::{
-    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
-    PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
+    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
+    LogicalVector, PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
 };
 use crate::renderer::{Renderer, RendererSealed};
 use crate::textlayout::{AbstractFont, FontMetrics, TextParagraphLayout};
@@ -92,6 +93,14 @@ fn mirror_width(self) -> bool {
     fn mirror_height(self) -> bool {
         matches!(self, Self::Rotate90 | Self::Rotate180)
     }
+    /// The rotation that reverts this one
+    fn inverse(self) -> Self {
+        match self {
+            Self::Rotate90 => Self::Rotate270,
+            Self::Rotate270 => Self::Rotate90,
+            other => other,
+        }
+    }
     /// Angle of the rotation in degrees
     fn angle(self) -> f32 {
         match self {
@@ -475,6 +484,68 @@ fn apply_dirty_region(&self, dirty_region: &mut DirtyRegion, screen_size: Logica
         .intersection(screen_region)
     }
 
+    /// Applies the dirty tracking policy to the dirty region and returns the region to draw, in
+    /// the frame of reference of the buffer. When an `area` (also in the frame of reference of
+    /// the buffer) is given, the whole area is drawn and nothing else: the dirty parts outside of
+    /// it are kept for the next frame, for which a redraw is requested.
+    fn physical_dirty_region(
+        &self,
+        dirty_region: &mut DirtyRegion,
+        size: PhysicalSize,
+        factor: ScaleFactor,
+        area: Option<PhysicalRect>,
+    ) -> PhysicalRegion {
+        let logical_size = (size.cast() / factor).cast();
+        self.apply_dirty_region(dirty_region, logical_size);
+        let rotation = RotationInfo { orientation: self.rotation.get(), screen_size: size };
+        let to_physical = |r: euclid::Box2D<Coord, LogicalPx>| -> PhysicalRect {
+            (r.cast() * factor).to_rect().round_out().cast().transformed(rotation)
+        };
+
+        let area = area.map(|area| {
+            let buffer_rect = PhysicalRect::from_size(size.transformed(rotation));
+            area.intersection(&buffer_rect).unwrap_or_default()
+        });
+        if let Some(area) = area {
+            let mut postponed = false;
+            let mut pending = self.force_dirty.borrow_mut();
+            for r in dirty_region.iter() {
+                if !area.contains_rect(&to_physical(r)) {
+                    pending.add_box(r);
+                    postponed = true;
+                }
+            }
+            drop(pending);
+            if postponed {
+                if let Some(window) =
+                    self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
+                {
+                    window.request_redraw();
+                }
+            }
+            *dirty_region = if area.is_empty() {
+                DirtyRegion::default()
+            } else {
+                let inverse = RotationInfo {
+                    orientation: self.rotation.get().inverse(),
+                    screen_size: size.transformed(rotation),
+                };
+                let window_area: LogicalRect =
+                    (area.transformed(inverse).cast::<f32>() / factor).cast();
+                DirtyRegion::from(window_area).intersection(LogicalRect::from_size(logical_size))
+            };
+        }
+
+        let mut i = dirty_region.iter().map(to_physical).map(|r| match area {
+            Some(area) => r.intersection(&area).unwrap_or_default(),
+            None => r,
+        });
+        PhysicalRegion {
+            rectangles: core::array::from_fn(|_| i.next().unwrap_or_default().to_box2d()),
+            count: dirty_region.iter().count(),
+        }
+    }
+
     /// Render the window to the given frame buffer.
     ///
     /// The renderer uses a cache internally and will only render the part of the window
@@ -490,6 +561,38 @@ fn apply_dirty_region(&self, dirty_region: &mut DirtyRegion, screen_size: Logica
     /// Returns the physical dirty region for this frame, excluding the extra_draw_region,
     /// in the window frame of reference. It is affected by the screen rotation.
     pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> PhysicalRegion {
+        self.render_impl(buffer, pixel_stride, None)
+    }
+
+    /// Render exactly the given area of the window to the given frame buffer, for displays
+    /// that can only refresh a part of the screen at once, such as e-paper displays.
+    ///
+    /// The whole area is rendered, even if it isn't dirty, and nothing outside of it. The
+    /// parts of the window that are dirty outside of the area are rendered in the next frame,
+    /// for which the window requests a redraw.
+    /// The area is in the frame of reference of the buffer, so it's affected by the screen
+    /// rotation.
+    ///
+    /// The `buffer` and the `pixel_stride` are the same as with [`Self::render()`], which should
+    /// be used with [`RepaintBufferType::ReusedBuffer`] so that the buffer keeps the content
+    /// outside of the area. This function returns the region that was rendered, which is the
+    /// area clipped to the window.
+    pub fn render_area(
+        &self,
+        buffer: &mut [impl TargetPixel],
+        pixel_stride: usize,
+        area_origin: crate::api::PhysicalPosition,
+        area_size: crate::api::PhysicalSize,
+    ) -> PhysicalRegion {
+        self.render_impl(buffer, pixel_stride, Some(physical_area(area_origin, area_size)))
+    }
+
+    fn render_impl(
+        &self,
+        buffer: &mut [impl TargetPixel],
+        pixel_stride: usize,
+        area: Option<PhysicalRect>,
+    ) -> PhysicalRegion {
         let Some(window) = self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
         else {
             return Default::default();
@@ -546,16 +649,8 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                 for (component, origin) in components {
                     renderer.compute_dirty_regions(component, *origin, logical_size);
                 }
-                self.apply_dirty_region(&mut renderer.dirty_region, logical_size);
-                let rotation = RotationInfo { orientation: rotation, screen_size: size };
-                let mut i = renderer.dirty_region.iter().map(|r| {
-                    (r.cast() * factor).to_rect().round_out().cast().transformed(rotation)
-                });
-                let dirty_region = PhysicalRegion {
-                    rectangles: core::array::from_fn(|_| i.next().unwrap_or_default().to_box2d()),
-                    count: renderer.dirty_region.iter().count(),
-                };
-                drop(i);
+                let dirty_region =
+                    self.physical_dirty_region(&mut renderer.dirty_region, size, factor, area);
 
                 let mut bg = TargetPixel::background();
                 // TODO: gradient background
@@ -586,6 +681,8 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                     );
                 }
 
+                window_inner.draw_software_cursor(&mut renderer);
+
                 if let Some(metrics) = &self.rendering_metrics_collector {
                     metrics.measure_frame_rendered(&mut renderer);
                     if metrics.refresh_mode() == RefreshMode::FullSpeed {
@@ -633,6 +730,29 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
     /// # }
     /// ```
     pub fn render_by_line(&self, line_buffer: impl LineBufferProvider) -> PhysicalRegion {
+        self.render_by_line_impl(line_buffer, None)
+    }
+
+    /// Render exactly the given area of the window, line by line, into the line buffer provided
+    /// by the [`LineBufferProvider`].
+    ///
+    /// Like with [`Self::render_area()`], the whole area is rendered, even if it isn't dirty,
+    /// and nothing outside of it. The area is in the frame of reference of the screen, so it's
+    /// affected by the screen rotation. This function returns the region that was rendered.
+    pub fn render_area_by_line(
+        &self,
+        line_buffer: impl LineBufferProvider,
+        area_origin: crate::api::PhysicalPosition,
+        area_size: crate::api::PhysicalSize,
+    ) -> PhysicalRegion {
+        self.render_by_line_impl(line_buffer, Some(physical_area(area_origin, area_size)))
+    }
+
+    fn render_by_line_impl(
+        &self,
+        line_buffer: impl LineBufferProvider,
+        area: Option<PhysicalRect>,
+    ) -> PhysicalRegion {
         let Some(window) = self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
         else {
             return Default::default();
@@ -652,6 +772,7 @@ pub fn render_by_line(&self, line_buffer: impl LineBufferProvider) -> PhysicalRe
                 size.cast(),
                 self,
                 line_buffer,
+                area,
             )
         } else {
             PhysicalRegion { ..Default::default() }
@@ -890,14 +1011,30 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
     }
 }
 
+/// Converts the area passed to [`SoftwareRenderer::render_area()`] to a rectangle in the frame of
+/// reference of the buffer, saturating the coordinates that don't fit.
+fn physical_area(
+    origin: crate::api::PhysicalPosition,
+    size: crate::api::PhysicalSize,
+) -> PhysicalRect {
+    let clamp = |v: i64| v.clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+    let min = euclid::point2(clamp(origin.x as i64), clamp(origin.y as i64));
+    let max = euclid::point2(
+        clamp(origin.x as i64 + size.width as i64),
+        clamp(origin.y as i64 + size.height as i64),
+    );
+    euclid::Box2D::new(min, max).to_rect()
+}
+
 fn render_window_frame_by_line(
     window: &WindowInner,
     background: Brush,
     size: PhysicalSize,
     renderer: &SoftwareRenderer,
     mut line_buffer: impl LineBufferProvider,
+    area: Option<PhysicalRect>,
 ) -> PhysicalRegion {
-    let mut scene = prepare_scene(window, size, renderer);
+    let mut scene = prepare_scene(window, size, renderer, area);
 
     let to_draw_tr = scene.dirty_region.bounding_rect();
 
@@ -984,6 +1121,18 @@ fn render_window_frame_by_line(
                                     extra_left_clip,
                                 );
                             }
+                            SceneCommand::PixelGradient { pixel_gradient_index } => {
+                                let g =
+                                    &scene.vectors.pixel_gradients[pixel_gradient_index as usize];
+
+                                draw_functions::draw_pixel_gradient_line(
+                                    &PhysicalRect { origin: span.pos, size: span.size },
+                                    scene.current_line,
+                                    g,
+                                    range_buffer,
+                                    extra_left_clip,
+                                );
+                            }
                         }
                     }
                 },
@@ -1003,6 +1152,7 @@ struct SceneVectors {
     rounded_rectangles: Vec<RoundedRectangle>,
     shared_buffers: Vec<SharedBufferCommand>,
     gradients: Vec<GradientCommand>,
+    pixel_gradients: Vec<PixelGradientCommand>,
 }
 
 struct Scene {
@@ -1262,6 +1412,10 @@ enum SceneCommand {
     Gradient {
         gradient_index: u16,
     },
+    /// pixel_gradient_index is an index in the [`SceneVectors::pixel_gradients`] array
+    PixelGradient {
+        pixel_gradient_index: u16,
+    },
 }
 
 struct SceneTexture<'a> {
@@ -1270,12 +1424,26 @@ struct SceneTexture<'a> {
     format: PixelFormat,
     /// number of pixels between two lines in the source
     pixel_stride: u16,
+    /// When set, `data` is the data array of a texture whose rows are compressed, and they are
+    /// decompressed while rendering each line
+    compressed: Option<CompressedTexture<'a>>,
 
     extra: SceneTextureExtra,
 }
 
+/// The part of a compressed [`StaticTexture`] to draw
+#[derive(Clone, Copy)]
+struct CompressedTexture<'a> {
+    texture: &'a StaticTexture,
+    /// The source rectangle, relative to the texture
+    rect: PhysicalRect,
+}
+
 impl<'a> SceneTexture<'a> {
     fn source_size(&self) -> PhysicalSize {
+        if let Some(compressed) = &self.compressed {
+            return compressed.rect.size;
+        }
         let len = self.data.len() / self.format.bpp();
         let stride = self.pixel_stride as usize;
         let h = len / stride;
@@ -1305,7 +1473,17 @@ struct SceneTextureExtra {
 
 enum SharedBufferData {
     SharedImage(SharedImageBuffer),
-    AlphaMap { data: Rc<[u8]>, width: u16 },
+    AlphaMap {
+        data: Rc<[u8]>,
+        width: u16,
+    },
+    /// The pixels of a compressed texture, decompressed because it's rotated and so it can't be
+    /// decompressed line by line
+    Decompressed {
+        data: Rc<[u8]>,
+        width: u16,
+        format: PixelFormat,
+    },
 }
 
 impl SharedBufferData {
@@ -1313,6 +1491,7 @@ fn width(&self) -> usize {
         match self {
             SharedBufferData::SharedImage(image) => image.width() as usize,
             SharedBufferData::AlphaMap { width, .. } => *width as usize,
+            SharedBufferData::Decompressed { width, .. } => *width as usize,
         }
     }
 }
@@ -1334,12 +1513,14 @@ fn as_texture(&self) -> SceneTexture<'_> {
                 data: &b.as_bytes()[start * 3..end * 3],
                 pixel_stride: stride as u16,
                 format: PixelFormat::Rgb,
+                compressed: None,
                 extra: self.extra,
             },
             SharedBufferData::SharedImage(SharedImageBuffer::RGBA8(b)) => SceneTexture {
                 data: &b.as_bytes()[start * 4..end * 4],
                 pixel_stride: stride as u16,
                 format: PixelFormat::Rgba,
+                compressed: None,
                 extra: self.extra,
             },
             SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(b)) => {
@@ -1347,6 +1528,7 @@ fn as_texture(&self) -> SceneTexture<'_> {
                     data: &b.as_bytes()[start * 4..end * 4],
                     pixel_stride: stride as u16,
                     format: PixelFormat::RgbaPremultiplied,
+                    compressed: None,
                     extra: self.extra,
                 }
             }
@@ -1354,6 +1536,14 @@ fn as_texture(&self) -> SceneTexture<'_> {
                 data: &data[start..end],
                 pixel_stride: *width,
                 format: PixelFormat::AlphaMap,
+                compressed: None,
+                extra: self.extra,
+            },
+            SharedBufferData::Decompressed { data, width, format } => SceneTexture {
+                data: &data[start * format.bpp()..end * format.bpp()],
+                pixel_stride: *width,
+                format: *format,
+                compressed: None,
                 extra: self.extra,
             },
         }
@@ -1408,10 +1598,37 @@ struct GradientCommand {
     bottom_clip: PhysicalLength,
 }
 
+/// A gradient whose color is computed for each pixel, for the gradients that can't be drawn
+/// with a [`GradientCommand`]
+#[derive(Debug)]
+struct PixelGradientCommand {
+    /// The gradient, with the opacity already applied to the color of the stops
+    gradient: PixelGradient,
+    /// The center of the gradient, in the coordinates of the screen
+    center: euclid::Point2D<f32, PhysicalPx>,
+    /// The rotation of the screen, which is reverted to find the position in the item's coordinates
+    rotation: RenderingRotation,
+}
+
+#[derive(Debug)]
+enum PixelGradient {
+    /// A linear gradient with a spread. The position along the gradient of a point relative to
+    /// the center is `0.5` plus its dot product with `direction`.
+    Linear {
+        gradient: crate::graphics::LinearGradientBrush,
+        direction: euclid::Vector2D<f32, PhysicalPx>,
+    },
+    /// A radial gradient with a spread, whose positions are a fraction of `radius`
+    Radial { gradient: crate::graphics::RadialGradientBrush, radius: f32 },
+    /// A conic gradient, whose color depends on the angle of each pixel around the center
+    Conic(crate::graphics::ConicGradientBrush),
+}
+
 fn prepare_scene(
     window: &WindowInner,
     size: PhysicalSize,
     software_renderer: &SoftwareRenderer,
+    area: Option<PhysicalRect>,
 ) -> Scene {
     let factor = ScaleFactor::new(window.scale_factor());
     let prepare_scene = SceneBuilder::new(
@@ -1434,22 +1651,14 @@ fn prepare_scene(
             renderer.compute_dirty_regions(component, *origin, logical_size);
         }
 
-        software_renderer.apply_dirty_region(&mut renderer.dirty_region, logical_size);
-        let rotation =
-            RotationInfo { orientation: software_renderer.rotation.get(), screen_size: size };
-        let mut i = renderer
-            .dirty_region
-            .iter()
-            .map(|r| (r.cast() * factor).to_rect().round_out().cast().transformed(rotation));
-        dirty_region = PhysicalRegion {
-            rectangles: core::array::from_fn(|_| i.next().unwrap_or_default().to_box2d()),
-            count: renderer.dirty_region.iter().count(),
-        };
-        drop(i);
+        dirty_region =
+            software_renderer.physical_dirty_region(&mut renderer.dirty_region, size, factor, area);
 
         for (component, origin) in components {
             crate::item_rendering::render_component_items(component, &mut renderer, *origin);
         }
+
+        window.draw_software_cursor(&mut renderer);
     });
 
     if let Some(metrics) = &software_renderer.rendering_metrics_collector {
@@ -1488,6 +1697,7 @@ trait ProcessScene {
     fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle);
     fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand);
     fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand);
+    fn process_pixel_gradient(&mut self, geometry: PhysicalRect, gradient: PixelGradientCommand);
 }
 
 struct RenderToBuffer<'a, TargetPixel> {
@@ -1590,6 +1800,18 @@ fn process_gradient(&mut self, geometry: PhysicalRect, g: GradientCommand) {
             );
         });
     }
+
+    fn process_pixel_gradient(&mut self, geometry: PhysicalRect, g: PixelGradientCommand) {
+        self.foreach_ranges(&geometry, |line, buffer, extra_left_clip, _extra_right_clip| {
+            draw_functions::draw_pixel_gradient_line(
+                &geometry,
+                PhysicalLength::new(line),
+                &g,
+                buffer,
+                extra_left_clip,
+            );
+        });
+    }
 }
 
 #[derive(Default)]
@@ -1663,6 +1885,20 @@ fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand
             });
         }
     }
+
+    fn process_pixel_gradient(&mut self, geometry: PhysicalRect, gradient: PixelGradientCommand) {
+        let size = geometry.size;
+        if !size.is_empty() {
+            let pixel_gradient_index = self.vectors.pixel_gradients.len() as u16;
+            self.vectors.pixel_gradients.push(gradient);
+            self.items.push(SceneItem {
+                pos: geometry.origin,
+                size,
+                z: self.items.len() as u16,
+                command: SceneCommand::PixelGradient { pixel_gradient_index },
+            });
+        }
+    }
 }
 
 struct SceneBuilder<'a, T> {
@@ -1778,11 +2014,8 @@ fn draw_image_impl(
                             * (clipped_target.origin.y - target_rect.origin.y) as i32;
 
                     let pixel_stride = t.rect.width() as u16;
-                    let core::ops::Range { start, end } = compute_range_in_buffer(
-                        &PhysicalRect::from_untyped(
-                            &src_rect.translate(-t.rect.origin.to_vector()).cast(),
-                        ),
-                        pixel_stride as usize,
+                    let texture_rect = PhysicalRect::from_untyped(
+                        &src_rect.translate(-t.rect.origin.to_vector()).cast(),
                     );
                     let bpp = t.format.bpp();
 
@@ -1793,23 +2026,70 @@ fn draw_image_impl(
                         global_alpha_u16
                     } as u8;
 
-                    self.processor.process_texture(
-                        clipped_target.cast().transformed(self.rotation),
-                        SceneTexture {
-                            data: &data.as_slice()[t.index..][start * bpp..end * bpp],
-                            pixel_stride,
-                            format: t.format,
-                            extra: SceneTextureExtra {
-                                colorize: color,
-                                alpha,
-                                rotation: self.rotation.orientation,
-                                dx,
-                                dy,
-                                off_x: Fixed::try_from_fixed(off_x).unwrap(),
-                                off_y: Fixed::try_from_fixed(off_y).unwrap(),
+                    let geometry = clipped_target.cast().transformed(self.rotation);
+                    let extra = SceneTextureExtra {
+                        colorize: color,
+                        alpha,
+                        rotation: self.rotation.orientation,
+                        dx,
+                        dy,
+                        off_x: Fixed::try_from_fixed(off_x).unwrap(),
+                        off_y: Fixed::try_from_fixed(off_y).unwrap(),
+                    };
+
+                    if t.compression == TextureCompression::None {
+                        let core::ops::Range { start, end } =
+                            compute_range_in_buffer(&texture_rect, pixel_stride as usize);
+                        self.processor.process_texture(
+                            geometry,
+                            SceneTexture {
+                                data: &data.as_slice()[t.index..][start * bpp..end * bpp],
+                                pixel_stride,
+                                format: t.format,
+                                compressed: None,
+                                extra,
                             },
-                        },
-                    );
+                        );
+                    } else if !self.rotation.orientation.is_transpose() {
+                        self.processor.process_texture(
+                            geometry,
+                            SceneTexture {
+                                data: data.as_slice(),
+                                pixel_stride,
+                                format: t.format,
+                                compressed: Some(CompressedTexture {
+                                    texture: t,
+                                    rect: texture_rect,
+                                }),
+                                extra,
+                            },
+                        );
+                    } else {
+                        // The lines of the target are columns of the texture, which would need
+                        // all its rows to be decompressed for each line
+                        let x_range = texture_rect.min_x() as usize * bpp
+                            ..texture_rect.max_x() as usize * bpp;
+                        let mut row = Vec::new();
+                        let mut pixels =
+                            Vec::with_capacity(x_range.len() * texture_rect.height() as usize);
+                        for y in texture_rect.y_range() {
+                            pixels.extend_from_slice(
+                                &t.row(data.as_slice(), y as usize, &mut row)[x_range.clone()],
+                            );
+                        }
+                        self.processor.process_shared_image_buffer(
+                            geometry,
+                            SharedBufferCommand {
+                                buffer: SharedBufferData::Decompressed {
+                                    data: pixels.into(),
+                                    width: texture_rect.width() as u16,
+                                    format: t.format,
+                                },
+                                source_rect: PhysicalRect::from_size(texture_rect.size),
+                                extra,
+                            },
+                        );
+                    }
                 }
             }
 
@@ -1882,6 +2162,7 @@ fn draw_text_paragraph<Font>(
         offset: euclid::Vector2D<f32, PhysicalPx>,
         color: Color,
         selection: Option<SelectionInfo>,
+        stroke: Option<GlyphStroke>,
     ) where
         Font: AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength> + GlyphRenderer,
     {
@@ -1905,15 +2186,8 @@ fn draw_text_paragraph<Font>(
                     }
                     for positioned_glyph in glyphs {
                         let glyph = paragraph.layout.font.render_glyph(positioned_glyph.glyph_id);
-
-                        let src_rect = PhysicalRect::new(
-                            PhysicalPoint::from_lengths(
-                                line_x + positioned_glyph.x + glyph.x,
-                                baseline_y - glyph.y - glyph.height,
-                            ),
-                            glyph.size(),
-                        )
-                        .cast();
+                        let glyph_origin =
+                            PhysicalPoint::from_lengths(line_x + positioned_glyph.x, baseline_y);
 
                         let color = match &selection {
                             Some(s) if s.selection.contains(&positioned_glyph.text_byte_offset) => {
@@ -1922,71 +2196,29 @@ fn draw_text_paragraph<Font>(
                             _ => color,
                         };
 
-                        if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
-                            let geometry = clipped_src.translate(offset).round();
-                            let origin = (geometry.origin - offset.round()).round().cast::<i16>();
-                            let actual_x = (origin.x - src_rect.origin.x as i16) as usize;
-                            let actual_y = (origin.y - src_rect.origin.y as i16) as usize;
-                            let pixel_stride = glyph.width.get() as u16;
-                            let mut geometry = geometry.cast();
-                            if geometry.size.width > glyph.width.get() - (actual_x as i16) {
-                                geometry.size.width = glyph.width.get() - (actual_x as i16)
-                            }
-                            if geometry.size.height > glyph.height.get() - (actual_y as i16) {
-                                geometry.size.height = glyph.height.get() - (actual_y as i16)
-                            }
-                            let source_size = geometry.size;
-                            if source_size.is_empty() {
-                                continue;
-                            }
-                            match &glyph.alpha_map {
-                                fonts::GlyphAlphaMap::Static(data) => {
-                                    self.processor.process_texture(
-                                        geometry.transformed(self.rotation),
-                                        SceneTexture {
-                                            data: &data
-                                                [actual_x + actual_y * pixel_stride as usize..],
-                                            pixel_stride,
-                                            format: PixelFormat::AlphaMap,
-                                            extra: SceneTextureExtra {
-                                                colorize: color,
-                                                // color already is mixed with global alpha
-                                                alpha: color.alpha(),
-                                                rotation: self.rotation.orientation,
-                                                dx: Fixed::from_integer(1),
-                                                dy: Fixed::from_integer(1),
-                                                off_x: Fixed::from_integer(0),
-                                                off_y: Fixed::from_integer(0),
-                                            },
-                                        },
-                                    );
-                                }
-                                fonts::GlyphAlphaMap::Shared(data) => {
-                                    self.processor.process_shared_image_buffer(
-                                        geometry.transformed(self.rotation),
-                                        SharedBufferCommand {
-                                            buffer: SharedBufferData::AlphaMap {
-                                                data: data.clone(),
-                                                width: pixel_stride,
-                                            },
-                                            source_rect: PhysicalRect::new(
-                                                PhysicalPoint::new(actual_x as _, actual_y as _),
-                                                source_size,
-                                            ),
-                                            extra: SceneTextureExtra {
-                                                colorize: color,
-                                                // color already is mixed with global alpha
-                                                alpha: color.alpha(),
-                                                rotation: self.rotation.orientation,
-                                                dx: Fixed::from_integer(1),
-                                                dy: Fixed::from_integer(1),
-                                                off_x: Fixed::from_integer(0),
-                                                off_y: Fixed::from_integer(0),
-                                            },
-                                        },
-                                    );
-                                }
-                            };
+                        // The outline outside of the glyph is drawn behind it, the centered one on top
+                        let outline = stroke.map(|stroke| {
+                            let centered = stroke.style == TextStrokeStyle::Center;
+                            (glyph.outline(stroke.radius, centered), stroke.color, centered)
+                        });
+                        if let Some((outline, stroke_color, false)) = &outline {
+                            self.draw_glyph(
+                                outline,
+                                glyph_origin,
+                                physical_clip,
+                                offset,
+                                *stroke_color,
+                            );
+                        }
+                        self.draw_glyph(&glyph, glyph_origin, physical_clip, offset, color);
+                        if let Some((outline, stroke_color, true)) = &outline {
+                            self.draw_glyph(
+                                outline,
+                                glyph_origin,
+                                physical_clip,
+                                offset,
+                                *stroke_color,
+                            );
                         }
                     }
                     core::ops::ControlFlow::Continue(())
@@ -1996,6 +2228,137 @@ fn draw_text_paragraph<Font>(
             .ok();
     }
 
+    /// Draws each edge of a border whose edges have different widths or colors
+    #[allow(clippy::unnecessary_cast)] // Coord
+    fn draw_border_edges(
+        &mut self,
+        geom: LogicalRect,
+        radius: LogicalBorderRadius,
+        edges: &BorderEdges,
+    ) {
+        let Some(clip) = self.current_state.clip.intersection(&geom) else { return };
+        let physical_clip = clip.cast::<f32>() * self.scale_factor;
+        let offset = self.current_state.offset.to_vector().cast::<f32>() * self.scale_factor;
+        let widths = edges.widths();
+        let polygons = crate::graphics::border_edge_polygons(
+            geom.size.cast::<f32>() * self.scale_factor,
+            radius.cast::<f32>() * self.scale_factor,
+            euclid::SideOffsets2D::new(
+                widths.top as f32,
+                widths.right as f32,
+                widths.bottom as f32,
+                widths.left as f32,
+            ) * self.scale_factor,
+        );
+        for (edge, [outer, inner]) in edges.to_array().into_iter().zip(polygons) {
+            // FIXME: gradients
+            let color = self.alpha_color(edge.color.color());
+            if color.alpha() == 0 || outer.is_empty() {
+                continue;
+            }
+            // Only rasterize the part of the rectangle that the edge covers
+            let bounds = euclid::Box2D::from_points(&outer).round_out();
+            let polygons: [Vec<_>; 2] = [outer, inner]
+                .map(|polygon| polygon.into_iter().map(|p| p - bounds.min.to_vector()).collect());
+            let (width, height) = (bounds.width() as usize, bounds.height() as usize);
+            let alpha_map = draw_functions::rasterize_polygons(&polygons, width, height);
+            let glyph = fonts::RenderableGlyph {
+                x: PhysicalLength::new(0),
+                y: PhysicalLength::new(-(height as i16)),
+                width: PhysicalLength::new(width as i16),
+                height: PhysicalLength::new(height as i16),
+                alpha_map: fonts::GlyphAlphaMap::Shared(alpha_map.into()),
+            };
+            self.draw_glyph(&glyph, bounds.min.cast(), physical_clip, offset, color);
+        }
+    }
+
+    /// Draws the glyph with its origin on the baseline at `glyph_origin`
+    fn draw_glyph(
+        &mut self,
+        glyph: &fonts::RenderableGlyph,
+        glyph_origin: PhysicalPoint,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+        color: Color,
+    ) {
+        let src_rect = PhysicalRect::new(
+            PhysicalPoint::from_lengths(
+                glyph_origin.x_length() + glyph.x,
+                glyph_origin.y_length() - glyph.y - glyph.height,
+            ),
+            glyph.size(),
+        )
+        .cast();
+
+        if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
+            let geometry = clipped_src.translate(offset).round();
+            let origin = (geometry.origin - offset.round()).round().cast::<i16>();
+            let actual_x = (origin.x - src_rect.origin.x as i16) as usize;
+            let actual_y = (origin.y - src_rect.origin.y as i16) as usize;
+            let pixel_stride = glyph.width.get() as u16;
+            let mut geometry = geometry.cast();
+            if geometry.size.width > glyph.width.get() - (actual_x as i16) {
+                geometry.size.width = glyph.width.get() - (actual_x as i16)
+            }
+            if geometry.size.height > glyph.height.get() - (actual_y as i16) {
+                geometry.size.height = glyph.height.get() - (actual_y as i16)
+            }
+            let source_size = geometry.size;
+            if source_size.is_empty() {
+                return;
+            }
+            match &glyph.alpha_map {
+                fonts::GlyphAlphaMap::Static(data) => {
+                    self.processor.process_texture(
+                        geometry.transformed(self.rotation),
+                        SceneTexture {
+                            data: &data[actual_x + actual_y * pixel_stride as usize..],
+                            pixel_stride,
+                            format: PixelFormat::AlphaMap,
+                            compressed: None,
+                            extra: SceneTextureExtra {
+                                colorize: color,
+                                // color already is mixed with global alpha
+                                alpha: color.alpha(),
+                                rotation: self.rotation.orientation,
+                                dx: Fixed::from_integer(1),
+                                dy: Fixed::from_integer(1),
+                                off_x: Fixed::from_integer(0),
+                                off_y: Fixed::from_integer(0),
+                            },
+                        },
+                    );
+                }
+                fonts::GlyphAlphaMap::Shared(data) => {
+                    self.processor.process_shared_image_buffer(
+                        geometry.transformed(self.rotation),
+                        SharedBufferCommand {
+                            buffer: SharedBufferData::AlphaMap {
+                                data: data.clone(),
+                                width: pixel_stride,
+                            },
+                            source_rect: PhysicalRect::new(
+                                PhysicalPoint::new(actual_x as _, actual_y as _),
+                                source_size,
+                            ),
+                            extra: SceneTextureExtra {
+                                colorize: color,
+                                // color already is mixed with global alpha
+                                alpha: color.alpha(),
+                                rotation: self.rotation.orientation,
+                                dx: Fixed::from_integer(1),
+                                dy: Fixed::from_integer(1),
+                                off_x: Fixed::from_integer(0),
+                                off_y: Fixed::from_integer(0),
+                            },
+                        },
+                    );
+                }
+            };
+        }
+    }
+
     /// Returns the color, mixed with the current_state's alpha
     fn alpha_color(&self, color: Color) -> Color {
         if self.current_state.alpha < 1.0 {
@@ -2011,6 +2374,15 @@ fn alpha_color(&self, color: Color) -> Color {
     }
 }
 
+/// The outline of the glyphs of a `Text`
+#[derive(Clone, Copy)]
+struct GlyphStroke {
+    color: Color,
+    /// The width of the outline, in physical pixels
+    radius: i16,
+    style: TextStrokeStyle,
+}
+
 struct SelectionInfo {
     selection_color: Color,
     selection_background: Color,
@@ -2040,6 +2412,68 @@ fn draw_rectangle(
             };
 
             let background = rect.background();
+            let physical_size = geom.size.cast::<f32>() * self.scale_factor;
+            let pixel_gradient = match &background {
+                Brush::LinearGradient(g) if g.spread() != crate::graphics::GradientSpread::Pad => {
+                    let (start, end) = crate::graphics::line_for_angle(
+                        g.angle(),
+                        [physical_size.width, physical_size.height].into(),
+                    );
+                    let line = end - start;
+                    let direction = line / line.square_length().max(f32::EPSILON);
+                    Some(PixelGradient::Linear {
+                        gradient: crate::graphics::LinearGradientBrush::new(
+                            g.angle(),
+                            g.stops().map(|s| crate::graphics::GradientStop {
+                                color: self.alpha_color(s.color),
+                                position: s.position,
+                            }),
+                        )
+                        .with_spread(g.spread()),
+                        direction: euclid::vec2(direction.x, direction.y),
+                    })
+                }
+                Brush::RadialGradient(g) if g.spread() != crate::graphics::GradientSpread::Pad => {
+                    Some(PixelGradient::Radial {
+                        gradient: crate::graphics::RadialGradientBrush::new_circle(g.stops().map(
+                            |s| crate::graphics::GradientStop {
+                                color: self.alpha_color(s.color),
+                                position: s.position,
+                            },
+                        ))
+                        .with_spread(g.spread()),
+                        radius: ((physical_size.width + physical_size.height) / 4.)
+                            .max(f32::EPSILON),
+                    })
+                }
+                Brush::ConicGradient(g) => {
+                    Some(PixelGradient::Conic(crate::graphics::ConicGradientBrush::new(
+                        g.from_angle(),
+                        g.stops().map(|s| crate::graphics::GradientStop {
+                            color: self.alpha_color(s.color),
+                            position: s.position,
+                        }),
+                    )))
+                }
+                _ => None,
+            };
+            if let Some(gradient) = pixel_gradient {
+                let act_rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
+                    * self.scale_factor)
+                    .round()
+                    .cast()
+                    .transformed(self.rotation);
+                let center = (geom.translate(self.current_state.offset.to_vector()).cast()
+                    * self.scale_factor)
+                    .transformed(self.rotation)
+                    .center();
+                self.processor.process_pixel_gradient(
+                    act_rect,
+                    PixelGradientCommand { gradient, center, rotation: self.rotation.orientation },
+                );
+                return;
+            }
+
             if let Brush::LinearGradient(g) = background {
                 let geom2 = (geom.cast() * self.scale_factor).transformed(self.rotation);
                 let clipped2 = (clipped.cast() * self.scale_factor).transformed(self.rotation);
@@ -2161,7 +2595,10 @@ fn draw_border_rectangle(
     ) {
         let geom = LogicalRect::from(size);
         if self.should_draw(&geom) {
-            let mut border = rect.border_width();
+            let edges = rect.border_edges();
+            // The edges are drawn separately, after the background
+            let mut border =
+                if edges.is_some() { LogicalLength::new(0 as _) } else { rect.border_width() };
             let radius = rect.border_radius();
             // FIXME: gradients
             let color = self.alpha_color(rect.background().color());
@@ -2235,6 +2672,9 @@ fn draw_border_rectangle(
                         },
                     );
                 }
+                if let Some(edges) = edges {
+                    self.draw_border_edges(geom, radius, &edges);
+                }
                 return;
             }
 
@@ -2270,6 +2710,10 @@ fn draw_border_rectangle(
                 add_border(euclid::rect(0 as _, b, b, geom.height() - b - b));
                 add_border(euclid::rect(geom.width() - b, b, b, geom.height() - b - b));
             }
+
+            if let Some(edges) = edges {
+                self.draw_border_edges(geom, radius, &edges);
+            }
         }
     }
 
@@ -2343,6 +2787,22 @@ fn draw_text(
         let color = self.alpha_color(text.color().color());
         let max_size = (geom.size.cast() * self.scale_factor).cast();
 
+        let (stroke_brush, stroke_width, stroke_style) = text.stroke();
+        let stroke = (!stroke_brush.is_transparent()).then(|| {
+            let stroke_width = (stroke_width.cast::<f32>() * self.scale_factor).get();
+            // Like in the other renderers, a centered stroke is half inside of the glyph
+            let radius = match stroke_style {
+                TextStrokeStyle::Outside => stroke_width,
+                TextStrokeStyle::Center => stroke_width / 2.,
+            };
+            GlyphStroke {
+                color: self.alpha_color(stroke_brush.color()),
+                // A width of zero is a hairline stroke of one physical pixel
+                radius: (radius.round() as i16).max(1),
+                style: stroke_style,
+            }
+        });
+
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
         // of its boundaries (that breaks partial rendering and the cast to usize for the item relative coordinate below).
         // FIXME: we should allow drawing outside of the Text element's boundaries.
@@ -2373,7 +2833,7 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None, stroke);
             }
             #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
             fonts::Font::VectorFont(vf) => {
@@ -2392,7 +2852,7 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None, stroke);
             }
         }
     }
@@ -2426,6 +2886,9 @@ fn draw_text_input(
 
         let text_visual_representation = text_input.visual_representation(None);
         let color = self.alpha_color(text_visual_representation.text_color.color());
+        let cursor_character_end = text_visual_representation
+            .cursor_character_end()
+            .filter(|_| text_visual_representation.cursor_shape != TextCursorShape::Bar);
 
         let selection =
             (!text_visual_representation.selection_range.is_empty()).then_some(SelectionInfo {
@@ -2448,10 +2911,16 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection, None);
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
-                    (paragraph.cursor_pos_for_byte_offset(cursor_offset), pf.height())
+                    let character_end_x =
+                        cursor_character_end.map(|end| paragraph.cursor_pos_for_byte_offset(end).0);
+                    (
+                        paragraph.cursor_pos_for_byte_offset(cursor_offset),
+                        pf.height(),
+                        character_end_x,
+                    )
                 })
             }
             #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
@@ -2468,22 +2937,33 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection, None);
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
-                    (paragraph.cursor_pos_for_byte_offset(cursor_offset), vf.height())
+                    let character_end_x =
+                        cursor_character_end.map(|end| paragraph.cursor_pos_for_byte_offset(end).0);
+                    (
+                        paragraph.cursor_pos_for_byte_offset(cursor_offset),
+                        vf.height(),
+                        character_end_x,
+                    )
                 })
             }
         };
 
-        if let Some(((cursor_x, cursor_y), cursor_height)) = cursor_pos_and_height {
-            let cursor_rect = PhysicalRect::new(
+        if let Some(((cursor_x, cursor_y), cursor_height, character_end_x)) = cursor_pos_and_height
+        {
+            let bar_rect = PhysicalRect::new(
                 PhysicalPoint::from_lengths(cursor_x, cursor_y),
                 PhysicalSize::from_lengths(
                     (text_input.text_cursor_width().cast() * self.scale_factor).cast(),
                     cursor_height,
                 ),
             );
+            let character_width = character_end_x.map(|end_x| (end_x - cursor_x).get() as f32);
+            let cursor_rect = text_visual_representation
+                .cursor_shape_rect(bar_rect.cast(), character_width)
+                .cast::<i16>();
 
             if let Some(clipped_src) = cursor_rect.intersection(&physical_clip.cast()) {
                 let geometry = clipped_src.translate(offset.cast()).transformed(self.rotation);
@@ -2556,6 +3036,10 @@ fn rotate(&mut self, _angle_in_degrees: f32) {
         todo!()
     }
 
+    fn scale(&mut self, _x_factor: f32, _y_factor: f32) {
+        // Not supported: the software renderer only draws axis-aligned, unscaled items
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         self.current_state.alpha *= opacity;
     }
@@ -2634,7 +3118,7 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None, None);
             }
             #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
             fonts::Font::VectorFont(vf) => {
@@ -2652,13 +3136,25 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None, None);
             }
         }
     }
 
-    fn draw_image_direct(&mut self, _image: crate::graphics::Image) {
-        todo!()
+    fn draw_image_direct(&mut self, image: crate::graphics::Image) {
+        let size = image.size();
+        let geom = LogicalRect::from(LogicalSize::from_untyped(size.cast()));
+        if self.should_draw(&geom) {
+            let fit = crate::graphics::fit(
+                crate::items::ImageFit::Fill,
+                geom.size_length().cast() * self.scale_factor,
+                euclid::Rect::new(Default::default(), size.cast()),
+                self.scale_factor,
+                Default::default(),
+                Default::default(),
+            );
+            self.draw_image_impl((&image).into(), fit, Default::default());
+        }
     }
 
     fn window(&self) -> &crate::window::WindowInner {
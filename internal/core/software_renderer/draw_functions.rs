@@ -8,11 +8,15 @@
 
 use super::{PhysicalLength, PhysicalRect};
 use crate::graphics::{PixelFormat, Rgb8Pixel};
-use crate::lengths::{PointLengths, SizeLengths};
+use crate::lengths::{PhysicalPx, PointLengths, SizeLengths};
 use crate::software_renderer::fixed::Fixed;
 use crate::Color;
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
 use derive_more::{Add, Mul, Sub};
 use integer_sqrt::IntegerSquareRoot;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
 
 /// Draw one line of the texture in the line buffer
 ///
@@ -28,6 +32,7 @@ pub(super) fn draw_texture_line(
         data,
         format,
         pixel_stride,
+        compressed,
         extra: super::SceneTextureExtra { colorize, alpha, rotation, dx, dy, off_x, off_y },
     } = *texture;
 
@@ -43,9 +48,21 @@ pub(super) fn draw_texture_line(
 
     if !rotation.is_transpose() {
         let mut delta = dx;
+        let row = (off_y + dy * y).truncate() % source_size.height;
+        let mut row_buffer = Vec::new();
         // The position where to start in the image array for a this row
-        let mut init = Fixed::from_integer((off_y + dy * y).truncate() % source_size.height)
-            * pixel_stride as i32;
+        let (data, mut init) = match compressed {
+            Some(super::CompressedTexture { texture, rect }) => {
+                let bpp = format.bpp();
+                let pixels =
+                    texture.row(data, (rect.min_y() as i32 + row) as usize, &mut row_buffer);
+                (
+                    &pixels[rect.min_x() as usize * bpp..rect.max_x() as usize * bpp],
+                    Fixed::default(),
+                )
+            }
+            None => (data, Fixed::from_integer(row) * pixel_stride as i32),
+        };
 
         // the size of the tile in physical pixels in the target
         let tile_len = (Fixed::from_integer(source_size.width) / delta) as usize;
@@ -482,6 +499,50 @@ fn mul(self, rhs: Self) -> Self::Output {
 }
 
 // a is between 0 and 255. When 0, we get color1, when 255 we get color2
+/// Returns the alpha map of `width` × `height` pixels covered by the `polygons` with the even-odd
+/// fill rule, anti-aliased with a few samples per pixel vertically and exact coverage horizontally.
+pub(super) fn rasterize_polygons(
+    polygons: &[Vec<euclid::Point2D<f32, PhysicalPx>>],
+    width: usize,
+    height: usize,
+) -> Vec<u8> {
+    const SAMPLES: usize = 4;
+    let mut alpha_map = vec![0; width * height];
+    let mut coverage = vec![0.; width];
+    let mut crossings = Vec::new();
+    for (y, line) in alpha_map.chunks_exact_mut(width.max(1)).enumerate() {
+        coverage.fill(0.);
+        for sample in 0..SAMPLES {
+            let sample_y = y as f32 + (sample as f32 + 0.5) / SAMPLES as f32;
+            crossings.clear();
+            for polygon in polygons {
+                for (i, from) in polygon.iter().enumerate() {
+                    let to = polygon[(i + 1) % polygon.len()];
+                    if (from.y <= sample_y) != (to.y <= sample_y) {
+                        crossings
+                            .push(from.x + (sample_y - from.y) * (to.x - from.x) / (to.y - from.y));
+                    }
+                }
+            }
+            crossings.sort_by(f32::total_cmp);
+            for span in crossings.chunks_exact(2) {
+                let end = span[1].clamp(0., width as f32);
+                let mut x = span[0].clamp(0., width as f32);
+                while x < end {
+                    let pixel = x as usize;
+                    let next = ((pixel + 1) as f32).min(end);
+                    coverage[pixel] += next - x;
+                    x = next;
+                }
+            }
+        }
+        for (alpha, coverage) in line.iter_mut().zip(&coverage) {
+            *alpha = (coverage * (255. / SAMPLES as f32) + 0.5).min(255.) as u8;
+        }
+    }
+    alpha_map
+}
+
 fn interpolate_color(
     a: u32,
     color1: PremultipliedRgbaColor,
@@ -616,6 +677,41 @@ pub(super) fn draw_gradient_line(
     }
 }
 
+pub(super) fn draw_pixel_gradient_line(
+    rect: &PhysicalRect,
+    line: PhysicalLength,
+    g: &super::PixelGradientCommand,
+    buffer: &mut [impl TargetPixel],
+    extra_left_clip: i16,
+) {
+    let y = line.get() as f32 + 0.5 - g.center.y;
+    let x0 = (rect.min_x() + extra_left_clip) as f32 + 0.5 - g.center.x;
+    for (i, pix) in buffer.iter_mut().enumerate() {
+        let x = x0 + i as f32;
+        // Revert the rotation of the screen to get the direction in the item's coordinates
+        let (mut dx, mut dy) = if g.rotation.is_transpose() { (y, x) } else { (x, y) };
+        if g.rotation.mirror_width() {
+            dx = -dx;
+        }
+        if g.rotation.mirror_height() {
+            dy = -dy;
+        }
+        let color = match &g.gradient {
+            super::PixelGradient::Linear { gradient, direction } => {
+                gradient.color_at(0.5 + dx * direction.x + dy * direction.y)
+            }
+            super::PixelGradient::Radial { gradient, radius } => {
+                gradient.color_at(dx.hypot(dy) / radius)
+            }
+            // The angle is clockwise from the top
+            super::PixelGradient::Conic(gradient) => {
+                gradient.color_at_angle(dx.atan2(-dy).to_degrees())
+            }
+        };
+        pix.blend(PremultipliedRgbaColor::from(color));
+    }
+}
+
 /// A color whose component have been pre-multiplied by alpha
 ///
 /// The renderer operates faster on pre-multiplied color since it
@@ -788,3 +884,22 @@ fn rgb565() {
     let pix888: Rgb8Pixel = pix565.into();
     assert_eq!(pix565, pix888.into());
 }
+
+#[test]
+fn rasterize_polygons_even_odd() {
+    let square = |min: f32, max: f32| {
+        [(min, min), (max, min), (max, max), (min, max)]
+            .map(|(x, y)| euclid::Point2D::new(x, y))
+            .to_vec()
+    };
+    let alpha_map = rasterize_polygons(&[square(0., 4.), square(1., 3.)], 4, 4);
+    #[rustfmt::skip]
+    assert_eq!(alpha_map, [
+        255, 255, 255, 255,
+        255, 0, 0, 255,
+        255, 0, 0, 255,
+        255, 255, 255, 255,
+    ]);
+    let alpha_map = rasterize_polygons(&[square(0.5, 1.5)], 2, 2);
+    assert_eq!(alpha_map, [64, 64, 64, 64]);
+}
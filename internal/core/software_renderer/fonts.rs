@@ -39,6 +39,45 @@ impl RenderableGlyph {
     pub fn size(&self) -> PhysicalSize {
         PhysicalSize::from_lengths(self.width, self.height)
     }
+
+    /// Returns the outline of the glyph, by dilating its alpha map by `radius` pixels in every
+    /// direction. When `centered` is true, the glyph is also eroded by `radius` and the inside is
+    /// left out, so that the outline is centered on the edges of the glyph.
+    pub fn outline(&self, radius: i16, centered: bool) -> Self {
+        let data: &[u8] = match &self.alpha_map {
+            GlyphAlphaMap::Static(data) => data,
+            GlyphAlphaMap::Shared(data) => data,
+        };
+        let (width, height) = (self.width.get() as i32, self.height.get() as i32);
+        let r = radius as i32;
+        let alpha = |x: i32, y: i32| {
+            if x < 0 || y < 0 || x >= width || y >= height {
+                0
+            } else {
+                data[(y * width + x) as usize]
+            }
+        };
+        let disc = (-r..=r)
+            .flat_map(|dy| (-r..=r).map(move |dx| (dx, dy)))
+            .filter(|(dx, dy)| dx * dx + dy * dy <= r * r + r)
+            .collect::<Vec<_>>();
+        let (outline_width, outline_height) = (width + 2 * r, height + 2 * r);
+        let mut outline = Vec::with_capacity((outline_width * outline_height) as usize);
+        for y in -r..height + r {
+            for x in -r..width + r {
+                let samples = disc.iter().map(|(dx, dy)| alpha(x + dx, y + dy));
+                let dilated = samples.clone().max().unwrap_or(0);
+                outline.push(if centered { dilated - samples.min().unwrap_or(0) } else { dilated });
+            }
+        }
+        Self {
+            x: self.x - PhysicalLength::new(radius),
+            y: self.y - PhysicalLength::new(radius),
+            width: PhysicalLength::new(outline_width as i16),
+            height: PhysicalLength::new(outline_height as i16),
+            alpha_map: GlyphAlphaMap::Shared(outline.into()),
+        }
+    }
 }
 
 pub trait GlyphRenderer {
@@ -47,6 +86,8 @@ pub trait GlyphRenderer {
 
 pub(super) const DEFAULT_FONT_SIZE: LogicalLength = LogicalLength::new(12 as Coord);
 
+#[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
+mod outlinerasterizer;
 mod pixelfont;
 #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
 pub mod vectorfont;
@@ -177,8 +218,9 @@ pub fn text_layout_for_font<'a, Font>(
 {
     let letter_spacing =
         font_request.letter_spacing.map(|spacing| (spacing.cast() * scale_factor).cast());
+    let line_height = font_request.line_height.map(|height| (height.cast() * scale_factor).cast());
 
-    TextLayout { font, letter_spacing }
+    TextLayout { font, letter_spacing, line_height }
 }
 
 pub fn register_bitmap_font(font_data: &'static BitmapFont) {
@@ -0,0 +1,221 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Rasterizes glyph outlines from ttf-parser into alpha maps.
+//!
+//! fontdue only renders the default instance of a variable font, so the glyphs of other instances
+//! are rendered from the outlines that ttf-parser computes for the variation coordinates of the face.
+
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+use super::RenderableGlyph;
+use crate::software_renderer::PhysicalLength;
+use rustybuzz::ttf_parser;
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// Flattens the outline into line segments, in pixels with the y axis pointing up.
+struct LineCollector {
+    scale: f32,
+    start: Point,
+    current: Point,
+    lines: Vec<(Point, Point)>,
+}
+
+impl LineCollector {
+    fn point(&self, x: f32, y: f32) -> Point {
+        Point { x: x * self.scale, y: y * self.scale }
+    }
+
+    fn push_line(&mut self, to: Point) {
+        self.lines.push((self.current, to));
+        self.current = to;
+    }
+
+    fn segment_count(&self, control_points: &[Point]) -> usize {
+        let length = control_points
+            .windows(2)
+            .map(|w| ((w[1].x - w[0].x).powi(2) + (w[1].y - w[0].y).powi(2)).sqrt())
+            .sum::<f32>();
+        (length / 2.).ceil().clamp(1., 32.) as usize
+    }
+}
+
+impl ttf_parser::OutlineBuilder for LineCollector {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = self.point(x, y);
+        self.current = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.push_line(to);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (p0, p1, p2) = (self.current, self.point(x1, y1), self.point(x, y));
+        let segments = self.segment_count(&[p0, p1, p2]);
+        for i in 1..=segments {
+            let t = i as f32 / segments as f32;
+            let mt = 1. - t;
+            self.push_line(Point {
+                x: mt * mt * p0.x + 2. * mt * t * p1.x + t * t * p2.x,
+                y: mt * mt * p0.y + 2. * mt * t * p1.y + t * t * p2.y,
+            });
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (p0, p1, p2, p3) =
+            (self.current, self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        let segments = self.segment_count(&[p0, p1, p2, p3]);
+        for i in 1..=segments {
+            let t = i as f32 / segments as f32;
+            let mt = 1. - t;
+            self.push_line(Point {
+                x: mt * mt * mt * p0.x
+                    + 3. * mt * mt * t * p1.x
+                    + 3. * mt * t * t * p2.x
+                    + t * t * t * p3.x,
+                y: mt * mt * mt * p0.y
+                    + 3. * mt * mt * t * p1.y
+                    + 3. * mt * t * t * p2.y
+                    + t * t * t * p3.y,
+            });
+        }
+    }
+
+    fn close(&mut self) {
+        self.push_line(self.start);
+    }
+}
+
+/// Accumulates the signed area covered by the lines in each pixel, like font-rs does.
+struct Accumulator {
+    width: usize,
+    height: usize,
+    area: Vec<f32>,
+}
+
+impl Accumulator {
+    fn draw_line(&mut self, p0: Point, p1: Point) {
+        if p0.y == p1.y {
+            return;
+        }
+        let (direction, p0, p1) = if p0.y < p1.y { (1., p0, p1) } else { (-1., p1, p0) };
+        let dxdy = (p1.x - p0.x) / (p1.y - p0.y);
+        let mut x = p0.x;
+        if p0.y < 0. {
+            x -= p0.y * dxdy;
+        }
+        for y in (p0.y.max(0.) as usize)..self.height.min(p1.y.ceil() as usize) {
+            let row = y * self.width;
+            let dy = ((y + 1) as f32).min(p1.y) - (y as f32).max(p0.y);
+            let x_next = x + dxdy * dy;
+            let d = dy * direction;
+            let (x0, x1) = if x < x_next { (x, x_next) } else { (x_next, x) };
+            let x0_floor = x0.floor();
+            let x0i = x0_floor.max(0.) as usize;
+            let x1_ceil = x1.ceil();
+            let x1i = x1_ceil.max(0.) as usize;
+            if x1i <= x0i + 1 {
+                let xmf = 0.5 * (x + x_next) - x0_floor;
+                self.area[row + x0i] += d - d * xmf;
+                self.area[row + x0i + 1] += d * xmf;
+            } else {
+                let s = (x1 - x0).recip();
+                let x0f = x0 - x0_floor;
+                let a0 = 0.5 * s * (1. - x0f) * (1. - x0f);
+                let x1f = x1 - x1_ceil + 1.;
+                let am = 0.5 * s * x1f * x1f;
+                self.area[row + x0i] += d * a0;
+                if x1i == x0i + 2 {
+                    self.area[row + x0i + 1] += d * (1. - a0 - am);
+                } else {
+                    let a1 = s * (1.5 - x0f);
+                    self.area[row + x0i + 1] += d * (a1 - a0);
+                    for xi in x0i + 2..x1i - 1 {
+                        self.area[row + xi] += d * s;
+                    }
+                    let a2 = a1 + (x1i - x0i - 3) as f32 * s;
+                    self.area[row + x1i - 1] += d * (1. - a2 - am);
+                }
+                self.area[row + x1i] += d * am;
+            }
+            x = x_next;
+        }
+    }
+
+    fn into_alpha_map(self) -> Vec<u8> {
+        let mut coverage = 0.;
+        self.area
+            .iter()
+            .take(self.width * self.height)
+            .map(|area| {
+                coverage += area;
+                (coverage.abs().min(1.) * 255.).round() as u8
+            })
+            .collect()
+    }
+}
+
+/// Renders the glyph of the face at the given pixel size. Returns an empty glyph for glyphs
+/// without an outline, such as the one of the space.
+pub fn rasterize_glyph(
+    face: &ttf_parser::Face,
+    glyph_id: core::num::NonZeroU16,
+    pixel_size: PhysicalLength,
+) -> RenderableGlyph {
+    let mut collector = LineCollector {
+        scale: pixel_size.get() as f32 / face.units_per_em() as f32,
+        start: Point { x: 0., y: 0. },
+        current: Point { x: 0., y: 0. },
+        lines: Vec::new(),
+    };
+    face.outline_glyph(ttf_parser::GlyphId(glyph_id.get()), &mut collector);
+
+    let empty_glyph = || RenderableGlyph {
+        x: PhysicalLength::new(0),
+        y: PhysicalLength::new(0),
+        width: PhysicalLength::new(0),
+        height: PhysicalLength::new(0),
+        alpha_map: Rc::<[u8]>::from([]).into(),
+    };
+    if collector.lines.is_empty() {
+        return empty_glyph();
+    }
+
+    let points = collector.lines.iter().flat_map(|(p0, p1)| [*p0, *p1]);
+    let x_min = points.clone().map(|p| p.x).fold(f32::MAX, f32::min).floor();
+    let x_max = points.clone().map(|p| p.x).fold(f32::MIN, f32::max).ceil();
+    let y_min = points.clone().map(|p| p.y).fold(f32::MAX, f32::min).floor();
+    let y_max = points.map(|p| p.y).fold(f32::MIN, f32::max).ceil();
+    let width = (x_max - x_min) as usize;
+    let height = (y_max - y_min) as usize;
+    if width == 0 || height == 0 {
+        return empty_glyph();
+    }
+
+    // Two extra cells, because the accumulation may spill over the right edge of the last row
+    let mut accumulator = Accumulator { width, height, area: alloc::vec![0.; width * height + 2] };
+    for (p0, p1) in collector.lines {
+        // Move into the alpha map, with the first row at the top
+        accumulator.draw_line(
+            Point { x: p0.x - x_min, y: y_max - p0.y },
+            Point { x: p1.x - x_min, y: y_max - p1.y },
+        );
+    }
+
+    RenderableGlyph {
+        x: PhysicalLength::new(x_min as _),
+        y: PhysicalLength::new(y_min as _),
+        width: PhysicalLength::new(width as _),
+        height: PhysicalLength::new(height as _),
+        alpha_map: Rc::<[u8]>::from(accumulator.into_alpha_map()).into(),
+    }
+}
@@ -55,7 +55,12 @@ pub fn match_font(
             let borrowed_fontdb = fonts.borrow();
             borrowed_fontdb.query_with_family(query, Some(family_str)).map(|font_id| {
                 let fontdue_font = get_or_create_fontdue_font(&borrowed_fontdb, font_id);
-                VectorFont::new(font_id, fontdue_font.clone(), requested_pixel_size)
+                VectorFont::new(
+                    font_id,
+                    fontdue_font.clone(),
+                    requested_pixel_size,
+                    &request.variations,
+                )
             })
         })
     })
@@ -73,7 +78,12 @@ pub fn fallbackfont(font_request: &super::FontRequest, scale_factor: ScaleFactor
             .expect("fatal: query for fallback font returned empty font list");
 
         let fontdue_font = get_or_create_fontdue_font(&fonts, fallback_font_id);
-        VectorFont::new(fallback_font_id, fontdue_font, requested_pixel_size)
+        VectorFont::new(
+            fallback_font_id,
+            fontdue_font,
+            requested_pixel_size,
+            &font_request.variations,
+        )
     })
 }
 
@@ -3,6 +3,7 @@
 
 use alloc::rc::Rc;
 
+use crate::graphics::FontVariation;
 use crate::lengths::PhysicalPx;
 use crate::software_renderer::PhysicalLength;
 use crate::textlayout::{Glyph, TextShaper};
@@ -15,7 +16,19 @@
 type FontLength = euclid::Length<i32, FontUnit>;
 type FontScaleFactor = euclid::Scale<f32, FontUnit, PhysicalPx>;
 
-type GlyphCacheKey = (fontdb::ID, PhysicalLength, core::num::NonZeroU16);
+/// The font, the pixel size, the variations (see [`variations_key`]), and the glyph
+type GlyphCacheKey = (fontdb::ID, PhysicalLength, u64, core::num::NonZeroU16);
+
+/// Identifies the variation coordinates, for caching the rendered glyphs.
+fn variations_key(variations: &[FontVariation]) -> u64 {
+    // FNV-1a
+    variations
+        .iter()
+        .flat_map(|variation| {
+            variation.tag.into_iter().chain(variation.value.to_bits().to_le_bytes())
+        })
+        .fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
 
 struct RenderableGlyphWeightScale;
 
@@ -54,6 +67,9 @@ pub struct VectorFont {
     pixel_size: PhysicalLength,
     x_height: PhysicalLength,
     cap_height: PhysicalLength,
+    /// The positions on the axes of a variable font. When empty, the default instance is used.
+    variations: Rc<[FontVariation]>,
+    variations_key: u64,
 }
 
 impl VectorFont {
@@ -61,11 +77,14 @@ pub fn new(
         id: fontdb::ID,
         fontdue_font: Rc<fontdue::Font>,
         pixel_size: PhysicalLength,
+        variations: &[FontVariation],
     ) -> Self {
         sharedfontdb::FONT_DB.with(|db| {
             db.borrow()
                 .with_face_data(id, |face_data, font_index| {
-                    let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    let mut face =
+                        rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    apply_variations(&mut face, variations);
 
                     let ascender = FontLength::new(face.ascender() as _);
                     let descender = FontLength::new(face.descender() as _);
@@ -85,6 +104,8 @@ pub fn new(
                         pixel_size,
                         x_height: (x_height.cast() * scale).cast(),
                         cap_height: (cap_height.cast() * scale).cast(),
+                        variations: variations.into(),
+                        variations_key: variations_key(variations),
                     }
                 })
                 .unwrap()
@@ -92,6 +113,12 @@ pub fn new(
     }
 }
 
+fn apply_variations(face: &mut rustybuzz::ttf_parser::Face, variations: &[FontVariation]) {
+    for variation in variations {
+        face.set_variation(rustybuzz::ttf_parser::Tag::from_bytes(&variation.tag), variation.value);
+    }
+}
+
 impl TextShaper for VectorFont {
     type LengthPrimitive = i16;
     type Length = PhysicalLength;
@@ -107,7 +134,19 @@ fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
             db.borrow()
                 .with_face_data(self.id, |face_data, font_index| {
                     let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
-                    let rb_face = rustybuzz::Face::from_face(face);
+                    let mut rb_face = rustybuzz::Face::from_face(face);
+                    if !self.variations.is_empty() {
+                        rb_face.set_variations(
+                            &self
+                                .variations
+                                .iter()
+                                .map(|variation| rustybuzz::Variation {
+                                    tag: rustybuzz::ttf_parser::Tag::from_bytes(&variation.tag),
+                                    value: variation.value,
+                                })
+                                .collect::<alloc::vec::Vec<_>>(),
+                        );
+                    }
 
                     let glyph_buffer = rustybuzz::shape(&rb_face, &[], buffer);
 
@@ -143,7 +182,9 @@ fn glyph_for_char(&self, ch: char) -> Option<Glyph<PhysicalLength>> {
         sharedfontdb::FONT_DB.with(|db| {
             db.borrow()
                 .with_face_data(self.id, |face_data, font_index| {
-                    let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    let mut face =
+                        rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    apply_variations(&mut face, &self.variations);
                     face.glyph_index(ch).map(|glyph_index| {
                         let mut out_glyph = Glyph::default();
 
@@ -195,10 +236,27 @@ fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> super::RenderableGlyp
         GLYPH_CACHE.with(|cache| {
             let mut cache = cache.borrow_mut();
 
-            let cache_key = (self.id, self.pixel_size, glyph_id);
+            let cache_key = (self.id, self.pixel_size, self.variations_key, glyph_id);
 
             if let Some(entry) = cache.get(&cache_key) {
                 entry.clone()
+            } else if !self.variations.is_empty() {
+                let glyph = sharedfontdb::FONT_DB.with(|db| {
+                    db.borrow()
+                        .with_face_data(self.id, |face_data, font_index| {
+                            let mut face =
+                                rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                            apply_variations(&mut face, &self.variations);
+                            super::outlinerasterizer::rasterize_glyph(
+                                &face,
+                                glyph_id,
+                                self.pixel_size,
+                            )
+                        })
+                        .unwrap()
+                });
+                cache.put_with_weight(cache_key, glyph.clone()).ok();
+                glyph
             } else {
                 let (metrics, alpha_map) =
                     self.fontdue_font.rasterize_indexed(glyph_id.get(), self.pixel_size.get() as _);
@@ -272,6 +272,55 @@ pub fn format(args: core::fmt::Arguments<'_>) -> SharedString {
     output
 }
 
+/// Formats the number with `precision` digits after the decimal point, for the `{:.2}`
+/// specifiers of the `format()` function of the Slint language.
+pub fn format_fixed(value: f64, precision: i32) -> SharedString {
+    crate::format!("{:.*}", precision.max(0) as usize, value)
+}
+
+/// Pads the text with the `fill` character to at least `width` characters, for the `{:>5}`
+/// specifiers of the `format()` function of the Slint language.
+///
+/// The `alignment` is 0 to align the text to the left, 1 to center it, and 2 to align it to the
+/// right. 3 also aligns it to the right, but puts the fill after the sign of a number, as in
+/// `-0042`.
+pub fn pad_string(text: &str, width: i32, fill: char, alignment: i32) -> SharedString {
+    let padding = (width.max(0) as usize).saturating_sub(text.chars().count());
+    let (before, after) = match alignment {
+        0 => (0, padding),
+        1 => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+    let mut result = SharedString::new();
+    let mut text = text;
+    if alignment == 3 {
+        if let Some(digits) = text.strip_prefix(['-', '+']) {
+            result.push_str(&text[..1]);
+            text = digits;
+        }
+    }
+    for _ in 0..before {
+        result.push_str(fill.encode_utf8(&mut [0; 4]));
+    }
+    result.push_str(text);
+    for _ in 0..after {
+        result.push_str(fill.encode_utf8(&mut [0; 4]));
+    }
+    result
+}
+
+#[test]
+fn test_format_fixed_and_pad_string() {
+    assert_eq!(format_fixed(3.14159, 2), "3.14");
+    assert_eq!(format_fixed(2., 0), "2");
+    assert_eq!(format_fixed(-0.5, 3), "-0.500");
+    assert_eq!(pad_string("ab", 5, ' ', 0), "ab   ");
+    assert_eq!(pad_string("ab", 5, '*', 1), "*ab**");
+    assert_eq!(pad_string("ab", 5, ' ', 2), "   ab");
+    assert_eq!(pad_string("-4.2", 6, '0', 3), "-004.2");
+    assert_eq!(pad_string("héllo", 3, ' ', 2), "héllo");
+}
+
 #[test]
 fn simple_test() {
     let x = SharedString::from("hello world!");
@@ -407,6 +456,28 @@ fn test_slint_shared_string_from_number() {
         }
     }
 
+    /// Formats the number with `precision` digits after the decimal point
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_format_fixed(
+        value: f64,
+        precision: i32,
+        out: &mut SharedString,
+    ) {
+        *out = format_fixed(value, precision);
+    }
+
+    /// Pads the text with the `fill` character to at least `width` characters
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_pad(
+        text: &SharedString,
+        width: i32,
+        fill: &SharedString,
+        alignment: i32,
+        out: &mut SharedString,
+    ) {
+        *out = pad_string(text, width, fill.chars().next().unwrap_or(' '), alignment);
+    }
+
     /// Append some bytes to an existing shared string
     ///
     /// bytes must be a valid utf8 array of size `len`, without null bytes inside
@@ -0,0 +1,325 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Helpers to connect a telemetry source, such as a CAN bus or a serial port, to the UI.
+
+    A [`TelemetryBridge`] lives in the UI thread. The thread reading the bus feeds decoded
+    signal values into a [`TelemetrySender`]. The bridge coalesces the values and applies
+    only the latest value of each signal at a fixed update interval, so a bus producing
+    thousands of frames per second doesn't flood the UI with property changes.
+    Signals can be monitored for staleness, to indicate in the UI when a value is out of date.
+
+    Commands going the other way, from UI callbacks to the bus, can be sent through the
+    channel created with [`command_channel()`].
+*/
+
+#![warn(missing_docs)]
+
+use crate::animations::Instant;
+use crate::timers::{Timer, TimerMode};
+use core::cell::RefCell;
+use core::hash::Hash;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{mpsc, Arc, Mutex};
+
+/// Decodes raw frames received from a telemetry source into signal values.
+///
+/// Implement this trait to map the frames of your protocol (for example a CAN frame
+/// with its identifier and payload) onto the signals that the UI displays.
+pub trait TelemetryDecoder {
+    /// The raw frame type, for example `[u8]` or a CAN frame structure.
+    type Frame: ?Sized;
+    /// The identifier of a signal.
+    type Signal;
+    /// The value of a signal.
+    type Value;
+
+    /// Decode `frame` and call `emit` for every signal value it contains.
+    /// A frame that can't be decoded should just be ignored.
+    fn decode(&mut self, frame: &Self::Frame, emit: &mut dyn FnMut(Self::Signal, Self::Value));
+}
+
+type PendingValues<K, V> = Arc<Mutex<HashMap<K, V>>>;
+
+/// Handle used to push signal values to a [`TelemetryBridge`].
+///
+/// This handle can be cloned and sent to the thread that reads from the telemetry source.
+pub struct TelemetrySender<K, V> {
+    pending: PendingValues<K, V>,
+}
+
+impl<K, V> Clone for TelemetrySender<K, V> {
+    fn clone(&self) -> Self {
+        Self { pending: self.pending.clone() }
+    }
+}
+
+impl<K: Eq + Hash, V> TelemetrySender<K, V> {
+    /// Queue a new value for the signal. If a value for this signal is already queued and
+    /// wasn't applied yet, it is replaced.
+    pub fn send(&self, signal: K, value: V) {
+        self.pending.lock().unwrap().insert(signal, value);
+    }
+
+    /// Decode the frame with the decoder and queue all the values it contains.
+    pub fn feed<D: TelemetryDecoder<Signal = K, Value = V>>(
+        &self,
+        decoder: &mut D,
+        frame: &D::Frame,
+    ) {
+        let mut pending = self.pending.lock().unwrap();
+        decoder.decode(frame, &mut |signal, value| {
+            pending.insert(signal, value);
+        });
+    }
+}
+
+struct Staleness {
+    timeout: Duration,
+    is_stale: bool,
+    handler: Box<dyn FnMut(bool)>,
+}
+
+struct SignalState<V> {
+    handler: Option<Box<dyn FnMut(V)>>,
+    last_update: Option<Instant>,
+    staleness: Option<Staleness>,
+}
+
+impl<V> Default for SignalState<V> {
+    fn default() -> Self {
+        Self { handler: None, last_update: None, staleness: None }
+    }
+}
+
+struct BridgeInner<K, V> {
+    pending: PendingValues<K, V>,
+    signals: RefCell<HashMap<K, SignalState<V>>>,
+}
+
+impl<K: Eq + Hash, V> BridgeInner<K, V> {
+    fn flush(&self) {
+        let values = core::mem::take(&mut *self.pending.lock().unwrap());
+        let now = crate::animations::current_tick();
+        let mut signals = self.signals.borrow_mut();
+        for (signal, value) in values {
+            let Some(state) = signals.get_mut(&signal) else { continue };
+            state.last_update = Some(now);
+            if let Some(staleness) = state.staleness.as_mut() {
+                if staleness.is_stale {
+                    staleness.is_stale = false;
+                    (staleness.handler)(false);
+                }
+            }
+            if let Some(handler) = state.handler.as_mut() {
+                handler(value);
+            }
+        }
+        for state in signals.values_mut() {
+            let Some(staleness) = state.staleness.as_mut() else { continue };
+            let outdated = state.last_update.map_or(true, |t| now - t >= staleness.timeout);
+            if outdated && !staleness.is_stale {
+                staleness.is_stale = true;
+                (staleness.handler)(true);
+            }
+        }
+    }
+}
+
+/// Applies telemetry values to the UI, rate-limited to a fixed update interval.
+///
+/// The bridge must be created and kept alive in the thread that runs the Slint event loop.
+/// Register a handler per signal with [`Self::on_signal()`] that sets the corresponding
+/// property or model row, and hand out [`TelemetrySender`]s to the threads producing the values.
+///
+/// ## Example
+/// ```rust,no_run
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::telemetry::TelemetryBridge;
+/// use std::time::Duration;
+///
+/// #[derive(PartialEq, Eq, Hash)]
+/// enum Signal { Speed, Temperature }
+///
+/// let bridge = TelemetryBridge::<Signal, f32>::new(Duration::from_millis(50));
+/// bridge.on_signal(Signal::Speed, |value| println!("speed: {value}"));
+/// bridge.set_stale_timeout(Signal::Temperature, Duration::from_secs(1), |stale| {
+///     println!("temperature sensor stale: {stale}")
+/// });
+///
+/// let sender = bridge.sender();
+/// std::thread::spawn(move || loop {
+///     // read from the bus...
+///     sender.send(Signal::Speed, 42.);
+/// #   break;
+/// });
+/// slint::run_event_loop().unwrap();
+/// ```
+pub struct TelemetryBridge<K, V> {
+    inner: Rc<BridgeInner<K, V>>,
+    timer: Timer,
+}
+
+impl<K: Eq + Hash + 'static, V: 'static> TelemetryBridge<K, V> {
+    /// Creates a new bridge which applies the queued values every `update_interval`.
+    pub fn new(update_interval: Duration) -> Self {
+        let inner = Rc::new(BridgeInner {
+            pending: Default::default(),
+            signals: RefCell::new(HashMap::new()),
+        });
+        let timer = Timer::default();
+        let inner_weak = Rc::downgrade(&inner);
+        timer.start(TimerMode::Repeated, update_interval, move || {
+            if let Some(inner) = inner_weak.upgrade() {
+                inner.flush();
+            }
+        });
+        Self { inner, timer }
+    }
+
+    /// Returns a handle that can be used from any thread to push values.
+    pub fn sender(&self) -> TelemetrySender<K, V> {
+        TelemetrySender { pending: self.inner.pending.clone() }
+    }
+
+    /// Sets the handler called with the latest value of `signal` at each update interval
+    /// in which a new value was received. Values of signals without handler are discarded.
+    pub fn on_signal(&self, signal: K, handler: impl FnMut(V) + 'static) {
+        self.inner.signals.borrow_mut().entry(signal).or_default().handler =
+            Some(Box::new(handler));
+    }
+
+    /// Monitors `signal` for staleness: `handler` is called with `true` when no value was
+    /// received for longer than `timeout`, and with `false` when a value is received again.
+    ///
+    /// The signal is considered stale until its first value arrives, so the handler is
+    /// called with `true` right away.
+    pub fn set_stale_timeout(
+        &self,
+        signal: K,
+        timeout: Duration,
+        handler: impl FnMut(bool) + 'static,
+    ) {
+        let mut handler = Box::new(handler);
+        let mut signals = self.inner.signals.borrow_mut();
+        let state = signals.entry(signal).or_default();
+        let is_stale =
+            state.last_update.map_or(true, |t| crate::animations::current_tick() - t >= timeout);
+        handler(is_stale);
+        state.staleness = Some(Staleness { timeout, is_stale, handler });
+    }
+
+    /// Changes the interval at which queued values are applied.
+    pub fn set_update_interval(&self, update_interval: Duration) {
+        self.timer.set_interval(update_interval);
+    }
+
+    /// Applies all queued values immediately, without waiting for the next update interval.
+    pub fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Handle to send commands from UI callbacks to the thread that writes to the telemetry source.
+///
+/// Created with [`command_channel()`].
+pub struct CommandSender<C> {
+    sender: mpsc::Sender<C>,
+}
+
+impl<C> Clone for CommandSender<C> {
+    fn clone(&self) -> Self {
+        Self { sender: self.sender.clone() }
+    }
+}
+
+impl<C> CommandSender<C> {
+    /// Sends a command. Returns false if the receiving side was dropped.
+    pub fn send(&self, command: C) -> bool {
+        self.sender.send(command).is_ok()
+    }
+}
+
+/// Creates a channel to send commands from UI callbacks to the thread that owns the bus.
+///
+/// ## Example
+/// ```rust
+/// enum Command { SetFanSpeed(i32) }
+/// let (commands, receiver) = slint::telemetry::command_channel::<Command>();
+/// // Typically cloned into a callback:
+/// // `ui.on_fan_speed_changed(move |speed| { commands.send(Command::SetFanSpeed(speed)); });`
+/// commands.send(Command::SetFanSpeed(3));
+/// std::thread::spawn(move || {
+///     while let Ok(command) = receiver.recv() {
+///         match command {
+///             Command::SetFanSpeed(_speed) => { /* write the frame to the bus */ }
+///         }
+///     }
+/// });
+/// ```
+pub fn command_channel<C>() -> (CommandSender<C>, mpsc::Receiver<C>) {
+    let (sender, receiver) = mpsc::channel();
+    (CommandSender { sender }, receiver)
+}
+
+/**
+```rust
+i_slint_backend_testing::init_no_event_loop();
+use slint::telemetry::{TelemetryBridge, TelemetryDecoder};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+struct Decoder;
+impl TelemetryDecoder for Decoder {
+    type Frame = [u8];
+    type Signal = u8;
+    type Value = u8;
+    fn decode(&mut self, frame: &[u8], emit: &mut dyn FnMut(u8, u8)) {
+        if let [id, value] = frame {
+            emit(*id, *value);
+        }
+    }
+}
+
+let bridge = TelemetryBridge::<u8, u8>::new(Duration::from_millis(100));
+let received = Rc::new(RefCell::new(Vec::new()));
+let stale = Rc::new(RefCell::new(Vec::new()));
+let received_ = received.clone();
+bridge.on_signal(1, move |v| received_.borrow_mut().push(v));
+let stale_ = stale.clone();
+bridge.set_stale_timeout(1, Duration::from_millis(250), move |s| stale_.borrow_mut().push(s));
+assert_eq!(*stale.borrow(), vec![true]);
+
+let sender = bridge.sender();
+std::thread::spawn(move || {
+    sender.feed(&mut Decoder, &[1, 10][..]);
+    sender.feed(&mut Decoder, &[1, 11][..]);
+    sender.feed(&mut Decoder, &[2, 99][..]);
+    sender.feed(&mut Decoder, &[7][..]);
+})
+.join()
+.unwrap();
+
+slint::platform::update_timers_and_animations();
+i_slint_core::tests::slint_mock_elapsed_time(50);
+assert!(received.borrow().is_empty());
+i_slint_core::tests::slint_mock_elapsed_time(50);
+// Only the latest value is applied
+assert_eq!(*received.borrow(), vec![11]);
+assert_eq!(*stale.borrow(), vec![true, false]);
+
+i_slint_core::tests::slint_mock_elapsed_time(200);
+assert_eq!(*stale.borrow(), vec![true, false]);
+i_slint_core::tests::slint_mock_elapsed_time(100);
+assert_eq!(*stale.borrow(), vec![true, false, true]);
+
+bridge.sender().send(1, 12);
+bridge.flush();
+assert_eq!(*received.borrow(), vec![11, 12]);
+assert_eq!(*stale.borrow(), vec![true, false, true, false]);
+```
+ */
+#[cfg(doctest)]
+const _TELEMETRY_BRIDGE: () = ();
@@ -38,6 +38,8 @@
 #[cfg(not(feature = "unicode-linebreak"))]
 use linebreak_simple::{BreakOpportunity, LineBreakIterator};
 
+mod bidi;
+pub use bidi::is_rtl_paragraph;
 mod fragments;
 mod glyphclusters;
 mod shaping;
@@ -52,9 +54,40 @@
 pub struct TextLayout<'a, Font: AbstractFont> {
     pub font: &'a Font,
     pub letter_spacing: Option<<Font as TextShaper>::Length>,
+    /// The distance between the tops of two lines. None uses the height of the font.
+    pub line_height: Option<<Font as TextShaper>::Length>,
 }
 
 impl<'a, Font: AbstractFont> TextLayout<'a, Font> {
+    /// Returns the height of a line of text
+    pub fn line_height(&self) -> Font::Length {
+        self.line_height
+            .filter(|height| *height > Font::Length::zero())
+            .unwrap_or(self.font.height())
+    }
+
+    /// Returns the distance between the top of a line and the top of its glyphs, which are
+    /// centered vertically in lines that are taller or shorter than the font
+    pub fn half_leading(&self) -> Font::Length {
+        let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+        (self.line_height() - self.font.height()) / two
+    }
+
+    /// Returns the number of lines that fit into the given height
+    fn max_lines(&self, max_height: Font::Length) -> usize {
+        let Some(line_height) = self.line_height.filter(|height| *height > Font::Length::zero())
+        else {
+            return self.font.max_lines(max_height);
+        };
+        let mut lines = 0;
+        let mut height = line_height;
+        while height <= max_height {
+            lines += 1;
+            height += line_height;
+        }
+        lines
+    }
+
     // Measures the size of the given text when rendered with the specified font and optionally constrained
     // by the provided `max_width`.
     // Returns a tuple of the width of the longest line as well as height of all lines.
@@ -76,7 +109,7 @@ pub fn text_size(
             line_count += 1;
         }
 
-        (max_line_width, self.font.height() * line_count.into())
+        (max_line_width, self.line_height() * line_count.into())
     }
 }
 
@@ -86,6 +119,8 @@ pub struct PositionedGlyph<Length> {
     pub advance: Length,
     pub glyph_id: core::num::NonZeroU16,
     pub text_byte_offset: usize,
+    /// True if the glyph is part of a right-to-left run, so that its leading edge is on the right.
+    pub is_rtl: bool,
 }
 
 pub struct TextParagraphLayout<'a, Font: AbstractFont> {
@@ -132,7 +167,7 @@ pub fn layout_lines<R>(
                 self.string,
                 &shape_buffer,
                 if wrap { Some(self.max_width) } else { None },
-                if elide { Some(self.layout.font.max_lines(self.max_height)) } else { None },
+                if elide { Some(self.layout.max_lines(self.max_height)) } else { None },
                 self.wrap,
             )
         };
@@ -140,10 +175,10 @@ pub fn layout_lines<R>(
 
         let mut text_height = || {
             if self.single_line {
-                self.layout.font.height()
+                self.layout.line_height()
             } else {
                 text_lines = Some(new_line_break_iter().collect::<Vec<_>>());
-                self.layout.font.height() * (text_lines.as_ref().unwrap().len() as i16).into()
+                self.layout.line_height() * (text_lines.as_ref().unwrap().len() as i16).into()
             }
         };
 
@@ -162,12 +197,27 @@ pub fn layout_lines<R>(
                 elide && (self.single_line || !wrap) && line.text_width > self.max_width;
             let elide_last_line = elide
                 && line.glyph_range.end < glyphs.len()
-                && y + self.layout.font.height() * two > self.max_height;
+                && y + self.layout.line_height() * two > self.max_height;
+
+            // The glyphs of the line in visual order, along with their direction
+            let visual_order = bidi::visual_glyph_order(&shape_buffer, line.glyph_range.clone());
+            let visual_order = visual_order.as_deref();
+            let glyph_range = line.glyph_range.clone();
+            let visual_glyphs = move || {
+                let start = glyph_range.start;
+                glyph_range.clone().map(move |index| match visual_order {
+                    Some(order) => {
+                        let (index, is_rtl) = order[index - start];
+                        (&glyphs[index], is_rtl)
+                    }
+                    None => (&glyphs[index], false),
+                })
+            };
 
             let text_width = || {
                 if elide_long_line || elide_last_line {
                     let mut text_width = Font::Length::zero();
-                    for glyph in &glyphs[line.glyph_range.clone()] {
+                    for (glyph, _) in visual_glyphs() {
                         if text_width + glyph.advance > max_width_without_elision {
                             break;
                         }
@@ -192,64 +242,74 @@ pub fn layout_lines<R>(
                     line.byte_range.start < selection.end && selection.start < line.byte_range.end
                 })
                 .map(|selection| {
-                    let mut begin = Font::Length::zero();
-                    let mut end = Font::Length::zero();
-                    for glyph in glyphs[line.glyph_range.clone()].iter() {
-                        if glyph.text_byte_offset < selection.start {
-                            begin += glyph.advance;
+                    // With mixed directions, this spans all selected glyphs of the line
+                    let mut selected: Option<core::ops::Range<Font::Length>> = None;
+                    let mut x = Font::Length::zero();
+                    for (glyph, _) in visual_glyphs() {
+                        if selection.contains(&glyph.text_byte_offset) {
+                            selected = Some(match selected {
+                                Some(range) => {
+                                    euclid::approxord::min(range.start, x)
+                                        ..euclid::approxord::max(range.end, x + glyph.advance)
+                                }
+                                None => x..x + glyph.advance,
+                            });
                         }
-                        if glyph.text_byte_offset >= selection.end {
-                            break;
-                        }
-                        end += glyph.advance;
+                        x += glyph.advance;
                     }
-                    begin..end
+                    selected.unwrap_or(x..x)
                 });
 
-            let glyph_it = glyphs[line.glyph_range.clone()].iter();
             let mut glyph_x = Font::Length::zero();
-            let mut positioned_glyph_it = glyph_it.enumerate().filter_map(|(index, glyph)| {
-                // TODO: cut off at grapheme boundaries
-                if glyph_x > self.max_width {
-                    return None;
-                }
-                let elide_long_line = (elide_long_line || elide_last_line)
-                    && x + glyph_x + glyph.advance > max_width_without_elision;
-                let elide_last_line =
-                    elide_last_line && line.glyph_range.start + index == line.glyph_range.end - 1;
-                if elide_long_line || elide_last_line {
-                    if let Some(elide_glyph) = elide_glyph.take() {
-                        let x = glyph_x;
-                        glyph_x += elide_glyph.advance;
-                        return Some(PositionedGlyph {
-                            x,
-                            y: Font::Length::zero(),
-                            advance: elide_glyph.advance,
-                            glyph_id: elide_glyph.glyph_id.unwrap(), // checked earlier when initializing elide_glyph
-                            text_byte_offset: glyph.text_byte_offset,
-                        });
-                    } else {
+            let mut positioned_glyph_it =
+                visual_glyphs().enumerate().filter_map(|(index, (glyph, is_rtl))| {
+                    // TODO: cut off at grapheme boundaries
+                    if glyph_x > self.max_width {
                         return None;
                     }
-                }
-                let x = glyph_x;
-                glyph_x += glyph.advance;
-
-                glyph.glyph_id.map(|existing_glyph_id| PositionedGlyph {
-                    x,
-                    y: Font::Length::zero(),
-                    advance: glyph.advance,
-                    glyph_id: existing_glyph_id,
-                    text_byte_offset: glyph.text_byte_offset,
-                })
-            });
+                    let elide_long_line = (elide_long_line || elide_last_line)
+                        && x + glyph_x + glyph.advance > max_width_without_elision;
+                    let elide_last_line = elide_last_line
+                        && line.glyph_range.start + index == line.glyph_range.end - 1;
+                    if elide_long_line || elide_last_line {
+                        if let Some(elide_glyph) = elide_glyph.take() {
+                            let x = glyph_x;
+                            glyph_x += elide_glyph.advance;
+                            return Some(PositionedGlyph {
+                                x,
+                                y: Font::Length::zero(),
+                                advance: elide_glyph.advance,
+                                glyph_id: elide_glyph.glyph_id.unwrap(), // checked earlier when initializing elide_glyph
+                                text_byte_offset: glyph.text_byte_offset,
+                                is_rtl: false,
+                            });
+                        } else {
+                            return None;
+                        }
+                    }
+                    let x = glyph_x;
+                    glyph_x += glyph.advance;
+
+                    glyph.glyph_id.map(|existing_glyph_id| PositionedGlyph {
+                        x,
+                        y: Font::Length::zero(),
+                        advance: glyph.advance,
+                        glyph_id: existing_glyph_id,
+                        text_byte_offset: glyph.text_byte_offset,
+                        is_rtl,
+                    })
+                });
 
-            if let core::ops::ControlFlow::Break(break_val) =
-                line_callback(&mut positioned_glyph_it, x, y, line, selection)
-            {
+            if let core::ops::ControlFlow::Break(break_val) = line_callback(
+                &mut positioned_glyph_it,
+                x,
+                y + self.layout.half_leading(),
+                line,
+                selection,
+            ) {
                 return core::ops::ControlFlow::Break(break_val);
             }
-            y += self.layout.font.height();
+            y += self.layout.line_height();
 
             core::ops::ControlFlow::Continue(())
         };
@@ -288,16 +348,44 @@ pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, F
                 );
                 last_line_y = line_y;
                 if byte_offset >= line.byte_range.end + line.trailing_whitespace_bytes {
+                    // A line ending with a right-to-left glyph ends on the left of that glyph
+                    if let Some(last_glyph) = glyphs.max_by_key(|glyph| glyph.text_byte_offset) {
+                        if last_glyph.is_rtl {
+                            last_glyph_right_edge = line_x + last_glyph.x;
+                        }
+                    }
                     return core::ops::ControlFlow::Continue(());
                 }
 
+                // The logically preceding glyph, used when the byte offset is at the end of a
+                // right-to-left run
+                let mut preceding_glyph: Option<(usize, bool, Font::Length)> = None;
                 for positioned_glyph in glyphs {
                     if positioned_glyph.text_byte_offset == byte_offset {
+                        let leading_edge = if positioned_glyph.is_rtl {
+                            positioned_glyph.x + positioned_glyph.advance
+                        } else {
+                            positioned_glyph.x
+                        };
                         return core::ops::ControlFlow::Break((
-                            euclid::approxord::min(self.max_width, line_x + positioned_glyph.x),
+                            euclid::approxord::min(self.max_width, line_x + leading_edge),
                             last_line_y,
                         ));
                     }
+                    if positioned_glyph.text_byte_offset < byte_offset
+                        && preceding_glyph
+                            .map_or(true, |(offset, ..)| offset < positioned_glyph.text_byte_offset)
+                    {
+                        preceding_glyph = Some((
+                            positioned_glyph.text_byte_offset,
+                            positioned_glyph.is_rtl,
+                            positioned_glyph.x,
+                        ));
+                    }
+                }
+
+                if let Some((_, true, trailing_edge)) = preceding_glyph {
+                    return core::ops::ControlFlow::Break((line_x + trailing_edge, last_line_y));
                 }
 
                 core::ops::ControlFlow::Break((last_glyph_right_edge, last_line_y))
@@ -316,7 +404,7 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
 
         match self.layout_lines(
             |glyphs, line_x, line_y, line, _| {
-                if pos_y >= line_y + self.layout.font.height() {
+                if pos_y >= line_y - self.layout.half_leading() + self.layout.line_height() {
                     byte_offset = line.byte_range.end;
                     return core::ops::ControlFlow::Continue(());
                 }
@@ -325,17 +413,35 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
                     return core::ops::ControlFlow::Break(line.byte_range.start);
                 }
 
-                while let Some(positioned_glyph) = glyphs.next() {
+                let glyphs = glyphs.collect::<Vec<_>>();
+                for positioned_glyph in &glyphs {
                     if pos_x >= line_x + positioned_glyph.x
                         && pos_x <= line_x + positioned_glyph.x + positioned_glyph.advance
                     {
-                        if pos_x < line_x + positioned_glyph.x + positioned_glyph.advance / two {
+                        let before_center =
+                            pos_x < line_x + positioned_glyph.x + positioned_glyph.advance / two;
+                        // The leading half of a right-to-left glyph is on its right
+                        if before_center != positioned_glyph.is_rtl {
                             return core::ops::ControlFlow::Break(
                                 positioned_glyph.text_byte_offset,
                             );
-                        } else if let Some(next_glyph) = glyphs.next() {
-                            return core::ops::ControlFlow::Break(next_glyph.text_byte_offset);
                         }
+                        // Otherwise the position is before the logically next glyph
+                        if let Some(next_offset) = glyphs
+                            .iter()
+                            .map(|glyph| glyph.text_byte_offset)
+                            .filter(|offset| *offset > positioned_glyph.text_byte_offset)
+                            .min()
+                        {
+                            return core::ops::ControlFlow::Break(next_offset);
+                        }
+                    }
+                }
+
+                // Beyond the right end of the line, the rightmost glyph may be right-to-left
+                if let Some(last_glyph) = glyphs.last() {
+                    if last_glyph.is_rtl && pos_x > line_x + last_glyph.x + last_glyph.advance {
+                        return core::ops::ControlFlow::Break(last_glyph.text_byte_offset);
                     }
                 }
 
@@ -432,7 +538,7 @@ fn test_elision() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -474,7 +580,7 @@ fn test_exact_fit() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 4. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -516,7 +622,7 @@ fn test_no_line_separators_characters_rendered() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -561,7 +667,7 @@ fn test_cursor_position() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -601,7 +707,7 @@ fn test_cursor_position_with_newline() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -621,7 +727,7 @@ fn byte_offset_for_empty_line() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -634,6 +740,32 @@ fn byte_offset_for_empty_line() {
     assert_eq!(paragraph.byte_offset_for_position((0., 10.)), 6);
 }
 
+#[test]
+fn test_line_height() {
+    let font = FixedTestFont;
+    let text = "Hello\nWorld";
+    let layout = TextLayout { font: &font, letter_spacing: None, line_height: Some(20.) };
+    assert_eq!(layout.text_size(text, None, TextWrap::NoWrap), (50., 40.));
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout,
+        max_width: 100. * 10.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+
+    // The glyphs are centered in their line
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(0), (0., 5.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(6), (0., 25.));
+    assert_eq!(paragraph.byte_offset_for_position((0., 19.)), 0);
+    assert_eq!(paragraph.byte_offset_for_position((0., 21.)), 6);
+}
+
 #[test]
 fn test_byte_offset() {
     let font = FixedTestFont;
@@ -643,7 +775,7 @@ fn test_byte_offset() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -700,3 +832,98 @@ fn test_byte_offset() {
     assert_eq!(paragraph.byte_offset_for_position((45., 10.)), end_offset);
     assert_eq!(paragraph.byte_offset_for_position((0., 20.)), end_offset);
 }
+
+#[test]
+#[cfg_attr(
+    not(feature = "unicode-bidi"),
+    ignore = "Not supported without the unicode-bidi feature"
+)]
+fn test_bidi_visual_order() {
+    let font = FixedTestFont;
+    // Latin followed by Hebrew alef, bet, gimel
+    let text = "abc \u{05D0}\u{05D1}\u{05D2}";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
+        max_width: 100. * 10.,
+        max_height: 10.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Clip,
+        single_line: true,
+    };
+
+    let mut rendered = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                rendered.extend(glyphs.map(|glyph| {
+                    let ch = char::from_u32(glyph.glyph_id.get() as u32).unwrap();
+                    (ch, glyph.x, glyph.is_rtl)
+                }));
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        rendered,
+        vec![
+            ('a', 0., false),
+            ('b', 10., false),
+            ('c', 20., false),
+            (' ', 30., false),
+            ('\u{05D2}', 40., true),
+            ('\u{05D1}', 50., true),
+            ('\u{05D0}', 60., true),
+        ]
+    );
+
+    // The leading edge of right-to-left glyphs is on their right
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(4), (70., 0.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(6), (60., 0.));
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(text.len()), (40., 0.));
+
+    assert_eq!(paragraph.byte_offset_for_position((66., 0.)), 4);
+    assert_eq!(paragraph.byte_offset_for_position((62., 0.)), 6);
+    assert_eq!(paragraph.byte_offset_for_position((42., 0.)), text.len());
+    assert_eq!(paragraph.byte_offset_for_position((80., 0.)), 4);
+    assert_eq!(paragraph.byte_offset_for_position((12., 0.)), 1);
+}
+
+#[test]
+#[cfg_attr(
+    not(feature = "unicode-bidi"),
+    ignore = "Not supported without the unicode-bidi feature"
+)]
+fn test_bidi_selection() {
+    let font = FixedTestFont;
+    let text = "abc \u{05D0}\u{05D1}\u{05D2}";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
+        max_width: 100. * 10.,
+        max_height: 10.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Clip,
+        single_line: true,
+    };
+
+    let mut selections = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |_, _, _, _, selection| {
+                selections.push(selection);
+                core::ops::ControlFlow::Continue(())
+            },
+            // alef and bet
+            Some(4..8),
+        )
+        .unwrap();
+    assert_eq!(selections, vec![Some(50. ..70.)]);
+}
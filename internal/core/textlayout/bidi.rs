@@ -0,0 +1,108 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Support for the unicode bidirectional algorithm.
+//!
+//! The text is shaped and broken into lines in logical order. The embedding levels computed here
+//! are stored in the text runs, and used to determine the visual order of the glyphs of each line.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use super::ShapeBuffer;
+
+/// Returns the embedding level of each byte in the text, or None if the entire text is left-to-right.
+#[cfg(feature = "unicode-bidi")]
+pub fn embedding_levels(text: &str) -> Option<Vec<u8>> {
+    let info = unicode_bidi::BidiInfo::new(text, None);
+    if !info.has_rtl() {
+        return None;
+    }
+    Some(info.levels.iter().map(|level| level.number()).collect())
+}
+
+#[cfg(not(feature = "unicode-bidi"))]
+pub fn embedding_levels(_text: &str) -> Option<Vec<u8>> {
+    None
+}
+
+/// Returns true if the paragraph of the text that contains the given byte offset has a
+/// right-to-left base direction.
+#[cfg(feature = "unicode-bidi")]
+pub fn is_rtl_paragraph(text: &str, byte_offset: usize) -> bool {
+    let info = unicode_bidi::BidiInfo::new(text, None);
+    info.paragraphs
+        .iter()
+        .find(|paragraph| byte_offset < paragraph.range.end)
+        .or(info.paragraphs.last())
+        .map_or(false, |paragraph| paragraph.level.is_rtl())
+}
+
+#[cfg(not(feature = "unicode-bidi"))]
+pub fn is_rtl_paragraph(_text: &str, _byte_offset: usize) -> bool {
+    false
+}
+
+/// Returns the glyph indices of the given range in visual order (from left to right), together with
+/// whether each glyph is part of a right-to-left run. Returns None if the logical order is also
+/// the visual order.
+pub fn visual_glyph_order<Length>(
+    shape_buffer: &ShapeBuffer<Length>,
+    glyph_range: Range<usize>,
+) -> Option<Vec<(usize, bool)>> {
+    let line_runs = shape_buffer.text_runs.iter().filter(|run| {
+        run.glyph_range.start < glyph_range.end && glyph_range.start < run.glyph_range.end
+    });
+    if line_runs.clone().all(|run| run.level == 0) {
+        return None;
+    }
+
+    // Glyph clusters of the line, with their embedding level
+    let mut clusters: Vec<(Range<usize>, u8)> = Vec::new();
+    for run in line_runs {
+        let start = run.glyph_range.start.max(glyph_range.start);
+        let end = run.glyph_range.end.min(glyph_range.end);
+        let mut index = start;
+        while index < end {
+            let text_byte_offset = shape_buffer.glyphs[index].text_byte_offset;
+            let cluster_start = index;
+            while index < end && shape_buffer.glyphs[index].text_byte_offset == text_byte_offset {
+                index += 1;
+            }
+            clusters.push((cluster_start..index, run.level));
+        }
+    }
+
+    let max_level = clusters.iter().map(|(_, level)| *level).max()?;
+    let lowest_odd_level = clusters
+        .iter()
+        .map(|(_, level)| *level)
+        .filter(|level| level % 2 == 1)
+        .min()
+        .unwrap_or(max_level + 1);
+
+    // Rule L2: from the highest level to the lowest odd level, reverse any contiguous
+    // sequence of clusters that are at that level or higher.
+    for level in (lowest_odd_level..=max_level).rev() {
+        let mut index = 0;
+        while index < clusters.len() {
+            if clusters[index].1 < level {
+                index += 1;
+                continue;
+            }
+            let sequence_start = index;
+            while index < clusters.len() && clusters[index].1 >= level {
+                index += 1;
+            }
+            clusters[sequence_start..index].reverse();
+        }
+    }
+
+    Some(
+        clusters
+            .into_iter()
+            .flat_map(|(glyphs, level)| glyphs.map(move |index| (index, level % 2 == 1)))
+            .collect(),
+    )
+}
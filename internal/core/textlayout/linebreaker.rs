@@ -199,7 +199,10 @@ fn next(&mut self) -> Option<Self::Item> {
 fn test_empty_line_break() {
     let font = FixedTestFont;
     let text = "";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -217,7 +220,10 @@ fn test_basic_line_break_char_wrap() {
     // The available width is half-way into the next word
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -235,7 +241,10 @@ fn test_basic_line_break_char_wrap() {
 fn test_basic_line_break() {
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -253,7 +262,10 @@ fn test_basic_line_break() {
 fn test_basic_line_break_max_lines() {
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -270,7 +282,10 @@ fn test_basic_line_break_max_lines() {
 fn test_linebreak_trailing_space() {
     let font = FixedTestFont;
     let text = "Hello              ";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -287,7 +302,10 @@ fn test_linebreak_trailing_space() {
 fn test_forced_break() {
     let font = FixedTestFont;
     let text = "Hello\nWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines =
         TextLineBreaker::<FixedTestFont>::new(text, &shape_buffer, None, None, TextWrap::WordWrap)
             .collect::<Vec<_>>();
@@ -300,7 +318,10 @@ fn test_forced_break() {
 fn test_forced_break_multi() {
     let font = FixedTestFont;
     let text = "Hello\n\n\nWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines =
         TextLineBreaker::<FixedTestFont>::new(text, &shape_buffer, None, None, TextWrap::WordWrap)
             .collect::<Vec<_>>();
@@ -315,7 +336,10 @@ fn test_forced_break_multi() {
 fn test_forced_break_multi_char_wrap() {
     let font = FixedTestFont;
     let text = "Hello\n\n\nWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -337,7 +361,10 @@ fn test_forced_break_multi_char_wrap() {
 fn test_forced_break_max_lines() {
     let font = FixedTestFont;
     let text = "Hello\n\n\nWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -355,7 +382,10 @@ fn test_forced_break_max_lines() {
 fn test_nbsp_break() {
     let font = FixedTestFont;
     let text = "Ok Hello\u{00a0}World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -373,7 +403,10 @@ fn test_nbsp_break() {
 fn test_single_line_multi_break_opportunity() {
     let font = FixedTestFont;
     let text = "a b c";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines =
         TextLineBreaker::<FixedTestFont>::new(text, &shape_buffer, None, None, TextWrap::WordWrap)
             .collect::<Vec<_>>();
@@ -385,7 +418,10 @@ fn test_single_line_multi_break_opportunity() {
 fn test_basic_line_break_anywhere_fallback() {
     let font = FixedTestFont;
     let text = "HelloWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -403,7 +439,10 @@ fn test_basic_line_break_anywhere_fallback() {
 fn test_basic_line_break_anywhere_fallback_multi_line() {
     let font = FixedTestFont;
     let text = "HelloWorld\nHelloWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -423,7 +462,10 @@ fn test_basic_line_break_anywhere_fallback_multi_line() {
 fn test_basic_line_break_anywhere_fallback_multi_line_char_wrap() {
     let font = FixedTestFont;
     let text = "HelloWorld\nHelloWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -443,7 +485,10 @@ fn test_basic_line_break_anywhere_fallback_multi_line_char_wrap() {
 fn test_basic_line_break_anywhere_fallback_multi_line_v2() {
     let font = FixedTestFont;
     let text = "HelloW orldHellow";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -463,7 +508,10 @@ fn test_basic_line_break_anywhere_fallback_multi_line_v2() {
 fn test_basic_line_break_anywhere_fallback_max_lines() {
     let font = FixedTestFont;
     let text = "HelloW orldHellow";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -483,7 +531,10 @@ fn test_basic_line_break_space() {
     // The available width is half-way into the trailing "W"
     let font = FixedTestFont;
     let text = "H W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -502,7 +553,10 @@ fn test_basic_line_break_space_char_wrap() {
     // The available width is half-way into the trailing "W"
     let font = FixedTestFont;
     let text = "H W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -521,7 +575,10 @@ fn test_basic_line_break_space_v2() {
     // The available width is half-way into the trailing "W"
     let font = FixedTestFont;
     let text = "B B W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -540,7 +597,10 @@ fn test_basic_line_break_space_v3() {
     // The available width is half-way into the trailing "W"
     let font = FixedTestFont;
     let text = "H   W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -559,7 +619,10 @@ fn test_basic_line_break_space_v4() {
     // The available width is half-way into the trailing space
     let font = FixedTestFont;
     let text = "H W  H  ";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -576,7 +639,10 @@ fn test_basic_line_break_space_v4() {
 fn test_line_width_with_whitespace() {
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -593,7 +659,10 @@ fn test_line_width_with_whitespace() {
 fn zero_width() {
     let font = FixedTestFont;
     let text = "He\nHe o";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -610,7 +679,10 @@ fn zero_width() {
 fn zero_width_char_wrap() {
     let font = FixedTestFont;
     let text = "He\nHe o";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -627,7 +699,10 @@ fn zero_width_char_wrap() {
 fn char_wrap_sentences() {
     let font = FixedTestFont;
     let text = "Hello world\nHow are you?";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout { font: &font, letter_spacing: None, line_height: None },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -86,8 +86,8 @@ pub struct ShapeBoundaries<'a> {
     text: &'a str,
     #[cfg(feature = "unicode-script")]
     // TODO: We should do a better analysis to find boundaries for text shaping; including
-    // boundaries when the bidi level changes or an explicit separator like
-    // paragraph/lineseparator/space is encountered.
+    // boundaries when an explicit separator like paragraph/lineseparator/space is encountered.
+    // (Boundaries where the bidi level changes are added by the ShapeBuffer)
     chars: core::str::CharIndices<'a>,
     next_boundary_start: Option<usize>,
     #[cfg(feature = "unicode-script")]
@@ -164,7 +164,8 @@ fn next(&mut self) -> Option<Self::Item> {
 pub struct TextRun {
     pub byte_range: Range<usize>,
     pub glyph_range: Range<usize>,
-    // TODO: direction, etc.
+    /// The embedding level from the unicode bidirectional algorithm. Odd levels are right-to-left.
+    pub level: u8,
 }
 
 pub struct ShapeBuffer<Length> {
@@ -179,12 +180,35 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
         Length: Copy + core::ops::AddAssign,
     {
         let mut glyphs = Vec::new();
-        let text_runs = ShapeBoundaries::new(text)
+        let levels = super::bidi::embedding_levels(text);
+        let mut boundaries = ShapeBoundaries::new(text).collect::<Vec<_>>();
+        if let Some(levels) = &levels {
+            // Also split the runs where the embedding level changes
+            boundaries.extend((1..text.len()).filter(|index| levels[*index] != levels[*index - 1]));
+            boundaries.sort_unstable();
+            boundaries.dedup();
+        }
+        let text_runs = boundaries
+            .into_iter()
             .scan(0, |run_start, run_end| {
                 let glyphs_start = glyphs.len();
+                let level = levels.as_ref().map_or(0, |levels| levels[*run_start]);
 
                 layout.font.shape_text(&text[*run_start..run_end], &mut glyphs);
 
+                for glyph in &mut glyphs[glyphs_start..] {
+                    glyph.text_byte_offset += *run_start;
+                }
+
+                // Shapers emit the glyphs of right-to-left runs in visual order. Bring them into
+                // logical order, the visual order of the line is determined after line breaking.
+                let run_glyphs = &mut glyphs[glyphs_start..];
+                if let (Some(first), Some(last)) = (run_glyphs.first(), run_glyphs.last()) {
+                    if first.text_byte_offset > last.text_byte_offset {
+                        reverse_clusters(run_glyphs);
+                    }
+                }
+
                 if let Some(letter_spacing) = layout.letter_spacing {
                     if glyphs.len() > glyphs_start {
                         let mut last_byte_offset = glyphs[glyphs_start].text_byte_offset;
@@ -204,6 +228,7 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
                 let run = TextRun {
                     byte_range: Range { start: *run_start, end: run_end },
                     glyph_range: Range { start: glyphs_start, end: glyphs.len() },
+                    level,
                 };
                 *run_start = run_end;
 
@@ -215,6 +240,20 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
     }
 }
 
+/// Reverses the order of the glyph clusters, while keeping the order of the glyphs within each cluster.
+fn reverse_clusters<Length>(glyphs: &mut [Glyph<Length>]) {
+    glyphs.reverse();
+    let mut cluster_start = 0;
+    for index in 1..=glyphs.len() {
+        if index == glyphs.len()
+            || glyphs[index].text_byte_offset != glyphs[cluster_start].text_byte_offset
+        {
+            glyphs[cluster_start..index].reverse();
+            cluster_start = index;
+        }
+    }
+}
+
 #[test]
 fn test_shape_boundaries_simple() {
     {
@@ -252,6 +291,24 @@ fn test_shape_boundaries_script_change() {
     }
 }
 
+#[test]
+fn test_reverse_clusters() {
+    let glyph = |glyph_id: u16, text_byte_offset: usize| Glyph::<f32> {
+        glyph_id: core::num::NonZeroU16::new(glyph_id),
+        text_byte_offset,
+        ..Default::default()
+    };
+    let mut glyphs = vec![glyph(1, 8), glyph(2, 6), glyph(3, 6), glyph(4, 4)];
+    reverse_clusters(&mut glyphs);
+    assert_eq!(
+        glyphs
+            .iter()
+            .map(|glyph| (glyph.glyph_id.unwrap().get(), glyph.text_byte_offset))
+            .collect::<Vec<_>>(),
+        vec![(4, 4), (2, 6), (3, 6), (1, 8)]
+    );
+}
+
 #[cfg(test)]
 impl<'a> TextShaper for &rustybuzz::Face<'a> {
     type LengthPrimitive = f32;
@@ -381,7 +438,7 @@ fn test_letter_spacing() {
             shaped_glyphs.iter().map(|g| g.advance).collect::<Vec<_>>()
         };
 
-        let layout = TextLayout { font: &face, letter_spacing: Some(20.) };
+        let layout = TextLayout { font: &face, letter_spacing: Some(20.), line_height: None };
         let buffer = ShapeBuffer::new(&layout, text);
 
         assert_eq!(buffer.glyphs.len(), advances.len());
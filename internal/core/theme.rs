@@ -0,0 +1,241 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+The design tokens of the widget styles, which an application overrides with a [`Theme`].
+
+The styles read each of their tokens, such as the colors of the `Palette` or the spacing of the
+layouts, from the active theme, with their own value as the fallback. The active theme is held in a
+property, so that all these bindings are evaluated again when it's replaced with [`set_theme()`].
+
+The text scale factor, set with [`set_text_scale_factor()`], is applied in the same way to the
+default font size of the windows, which is the size of the `rem` unit.
+*/
+
+use crate::graphics::Brush;
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::thread_local;
+use crate::{Coord, Property, SharedString};
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+use core::pin::Pin;
+
+/// A set of values for the design tokens of the widget styles, which replace the values of the
+/// style for all the widgets once the theme is set with [`set_theme()`].
+///
+/// The tokens are:
+///  - The brushes of the `Palette` global: `background`, `foreground`, `alternate-background`,
+///    `alternate-foreground`, `control-background`, `control-foreground`, `accent-background`,
+///    `accent-foreground`, `selection-background`, `selection-foreground`, and `border`.
+///  - The lengths `layout-spacing` and `layout-padding`, which are the default spacing and
+///    padding of the layouts.
+///  - The string `default-font-family`, which is the default font family of the windows.
+///
+/// The tokens that the theme doesn't set keep the value of the style.
+///
+/// ## Example
+/// ```rust,no_run
+/// use slint::{Color, Theme};
+/// slint::set_theme(
+///     Theme::new()
+///         .with_brush("accent-background", Color::from_rgb_u8(0xd0, 0x40, 0x20))
+///         .with_length("layout-spacing", 12.)
+///         .with_string("default-font-family", "Inter"),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Theme {
+    brushes: BTreeMap<SharedString, Brush>,
+    lengths: BTreeMap<SharedString, f32>,
+    strings: BTreeMap<SharedString, SharedString>,
+}
+
+impl Theme {
+    /// Returns a theme that doesn't set any token, so the widgets look like their style.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the brush of the color token `name`, such as `accent-background`.
+    pub fn with_brush(mut self, name: &str, brush: impl Into<Brush>) -> Self {
+        self.brushes.insert(name.into(), brush.into());
+        self
+    }
+
+    /// Sets the length of the token `name`, such as `layout-spacing`, in logical pixels.
+    pub fn with_length(mut self, name: &str, length: f32) -> Self {
+        self.lengths.insert(name.into(), length);
+        self
+    }
+
+    /// Sets the string of the token `name`, such as `default-font-family`.
+    pub fn with_string(mut self, name: &str, value: impl Into<SharedString>) -> Self {
+        self.strings.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns the brush of the color token `name`, if the theme sets it.
+    pub fn brush(&self, name: &str) -> Option<Brush> {
+        self.brushes.get(name).cloned()
+    }
+
+    /// Returns the length of the token `name` in logical pixels, if the theme sets it.
+    pub fn length(&self, name: &str) -> Option<f32> {
+        self.lengths.get(name).copied()
+    }
+
+    /// Returns the string of the token `name`, if the theme sets it.
+    pub fn string(&self, name: &str) -> Option<SharedString> {
+        self.strings.get(name).cloned()
+    }
+}
+
+thread_local! {
+    static ACTIVE_THEME: Pin<Box<Property<Rc<Theme>>>> =
+        Box::pin(Property::new_named(Default::default(), "theme::ACTIVE_THEME"));
+}
+
+/// Replaces the active theme, which the widgets of the styles follow: all the bindings that use
+/// a token of the previous theme are evaluated again.
+///
+/// Set `Theme::default()` to go back to the values of the style.
+pub fn set_theme(theme: Theme) {
+    ACTIVE_THEME.with(|active_theme| active_theme.as_ref().set(Rc::new(theme)));
+}
+
+/// Returns the active theme, which was set with [`set_theme()`].
+pub fn active_theme() -> Theme {
+    ACTIVE_THEME.with(|active_theme| (*active_theme.as_ref().get()).clone())
+}
+
+thread_local! {
+    static TEXT_SCALE_FACTOR: Pin<Box<Property<f32>>> =
+        Box::pin(Property::new_named(1., "theme::TEXT_SCALE_FACTOR"));
+}
+
+/// Sets the factor by which the default font size of all the windows is multiplied, such as the
+/// text scale of the accessibility settings of the system.
+///
+/// All the texts that don't set their `font-size`, as well as all the lengths in the `rem` unit,
+/// are resized, including the texts of the widgets of the styles. The lengths in `px` aren't
+/// affected. The default factor is 1.
+pub fn set_text_scale_factor(factor: f32) {
+    TEXT_SCALE_FACTOR.with(|scale_factor| scale_factor.as_ref().set(factor));
+}
+
+/// Returns the text scale factor, which was set with [`set_text_scale_factor()`].
+pub fn text_scale_factor() -> f32 {
+    TEXT_SCALE_FACTOR.with(|scale_factor| scale_factor.as_ref().get())
+}
+
+fn with_active_theme<R>(f: impl FnOnce(&Theme) -> R) -> R {
+    ACTIVE_THEME.with(|active_theme| f(&active_theme.as_ref().get()))
+}
+
+/// Returns the brush of the token `name` in the active theme, or `default` if the theme doesn't
+/// set it. Used by the styles.
+pub fn theme_brush(name: &str, default: Brush) -> Brush {
+    with_active_theme(|theme| theme.brush(name)).unwrap_or(default)
+}
+
+/// Returns the length of the token `name` in the active theme, or `default` if the theme doesn't
+/// set it. Used by the styles.
+pub fn theme_length(name: &str, default: Coord) -> Coord {
+    with_active_theme(|theme| theme.length(name)).map_or(default, |length| length as Coord)
+}
+
+/// Returns the string of the token `name` in the active theme, or `default` if the theme doesn't
+/// set it. Used by the styles.
+pub fn theme_string(name: &str, default: SharedString) -> SharedString {
+    with_active_theme(|theme| theme.string(name)).unwrap_or(default)
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+    use super::*;
+    use crate::slice::Slice;
+
+    /// Sets the active theme to the tokens of the slices, where each name has the value at the
+    /// same index.
+    #[no_mangle]
+    pub extern "C" fn slint_theme_set(
+        brush_names: Slice<SharedString>,
+        brushes: Slice<Brush>,
+        length_names: Slice<SharedString>,
+        lengths: Slice<f32>,
+        string_names: Slice<SharedString>,
+        strings: Slice<SharedString>,
+    ) {
+        let mut theme = Theme::new();
+        for (name, brush) in brush_names.iter().zip(brushes.iter()) {
+            theme = theme.with_brush(name, brush.clone());
+        }
+        for (name, length) in length_names.iter().zip(lengths.iter()) {
+            theme = theme.with_length(name, *length);
+        }
+        for (name, value) in string_names.iter().zip(strings.iter()) {
+            theme = theme.with_string(name, value.clone());
+        }
+        set_theme(theme);
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_set_text_scale_factor(factor: f32) {
+        set_text_scale_factor(factor)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_text_scale_factor() -> f32 {
+        text_scale_factor()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_theme_brush(name: &SharedString, default: &Brush, out: &mut Brush) {
+        *out = theme_brush(name, default.clone());
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_theme_length(name: &SharedString, default: Coord) -> Coord {
+        theme_length(name, default)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_theme_string(
+        name: &SharedString,
+        default: &SharedString,
+        out: &mut SharedString,
+    ) {
+        *out = theme_string(name, default.clone());
+    }
+}
+
+#[test]
+fn test_theme_bindings() {
+    use crate::graphics::Color;
+    let red = Brush::from(Color::from_rgb_u8(255, 0, 0));
+    let property = Box::pin(Property::<Brush>::default());
+    property.as_ref().set_binding(move || theme_brush("accent-background", red.clone()));
+    assert_eq!(property.as_ref().get(), Brush::from(Color::from_rgb_u8(255, 0, 0)));
+
+    let blue = Color::from_rgb_u8(0, 0, 255);
+    set_theme(Theme::new().with_brush("accent-background", blue));
+    assert_eq!(property.as_ref().get(), Brush::from(blue));
+    assert_eq!(theme_length("layout-spacing", 8 as Coord), 8 as Coord);
+
+    set_theme(Theme::default());
+    assert_eq!(property.as_ref().get(), Brush::from(Color::from_rgb_u8(255, 0, 0)));
+}
+
+#[test]
+fn test_text_scale_factor_bindings() {
+    let property = Box::pin(Property::<f32>::default());
+    property.as_ref().set_binding(|| 10. * text_scale_factor());
+    assert_eq!(property.as_ref().get(), 10.);
+    set_text_scale_factor(1.5);
+    assert_eq!(property.as_ref().get(), 15.);
+    set_text_scale_factor(1.);
+    assert_eq!(property.as_ref().get(), 10.);
+}
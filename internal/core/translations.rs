@@ -5,6 +5,33 @@
 use core::fmt::Display;
 pub use formatter::FormatArgs;
 
+#[cfg(feature = "std")]
+mod catalog;
+#[cfg(feature = "std")]
+pub use catalog::TranslationCatalog;
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// The pseudo-localization set with [`set_pseudo_localization()`], or None if it's still the
+    /// one of the `SLINT_PSEUDO_LOCALIZATION` environment variable
+    static PSEUDO_LOCALIZATION: core::cell::Cell<Option<PseudoLocalization>> = Default::default();
+    /// The strings that were shown without translation, if they are tracked
+    static UNTRANSLATED_STRINGS:
+        core::cell::RefCell<Option<std::collections::BTreeSet<UntranslatedString>>> =
+        core::cell::RefCell::new(
+            std::env::var_os("SLINT_TRANSLATION_COVERAGE").is_some().then(Default::default),
+        );
+    /// The catalogs registered with [`set_translation_catalog()`], by domain
+    static CATALOGS: core::cell::RefCell<std::collections::HashMap<String, TranslationCatalog>> =
+        Default::default();
+    /// The directories that contain the `.mo` files, by domain, as passed to `init_translations!`
+    static TRANSLATION_DIRECTORIES:
+        core::cell::RefCell<std::collections::HashMap<String, std::path::PathBuf>> =
+        Default::default();
+    /// The language selected with [`select_translation_language()`], if any
+    static SELECTED_LANGUAGE: core::cell::RefCell<Option<String>> = Default::default();
+}
+
 mod formatter {
     use core::fmt::{Display, Formatter, Result};
 
@@ -183,19 +210,363 @@ pub fn translate(
     #![allow(unused)]
     let mut output = SharedString::default();
     let translated = if plural.is_empty() || n == 1 { original } else { plural };
-    #[cfg(all(target_family = "unix", feature = "gettext-rs"))]
-    let translated = translate_gettext(original, contextid, domain, n, plural);
+    #[cfg(feature = "std")]
+    let translation = match pseudo_localization() {
+        PseudoLocalization::Disabled => {
+            let translation = translate_from_catalog(original, contextid, domain, n, plural);
+            // gettext translates into the language of the system, not the selected one. It
+            // returns the original string when it has no translation.
+            #[cfg(all(target_family = "unix", feature = "gettext-rs"))]
+            let translation = translation.or_else(|| {
+                SELECTED_LANGUAGE
+                    .with(|language| language.borrow().is_none())
+                    .then(|| translate_gettext(original, contextid, domain, n, plural))
+                    .filter(|translation| translation != translated)
+            });
+            if translation.is_none() {
+                record_untranslated_string(original, contextid, domain);
+            }
+            translation
+        }
+        mode => {
+            track_translations();
+            Some(pseudo_localize(translated, mode))
+        }
+    };
+    #[cfg(feature = "std")]
+    let translated = translation.as_deref().unwrap_or(translated);
     use core::fmt::Write;
     write!(output, "{}", formatter::format(&translated, &WithPlural(arguments, n))).unwrap();
     output
 }
 
-#[cfg(all(target_family = "unix", feature = "gettext-rs"))]
-fn translate_gettext(string: &str, ctx: &str, domain: &str, n: i32, plural: &str) -> String {
+/// Registers the dependency of the current binding on the active translations.
+#[cfg(feature = "std")]
+fn track_translations() {
     crate::context::GLOBAL_CONTEXT.with(|ctx| {
         let Some(ctx) = ctx.get() else { return };
         ctx.0.translations_dirty.as_ref().get();
     });
+}
+
+#[cfg(feature = "std")]
+fn translate_from_catalog(
+    original: &str,
+    contextid: &str,
+    domain: &str,
+    n: i32,
+    plural: &str,
+) -> Option<String> {
+    track_translations();
+    CATALOGS.with(|catalogs| {
+        let catalogs = catalogs.borrow();
+        let n = (!plural.is_empty()).then_some(n);
+        catalogs.get(domain)?.translate(contextid, original, n).map(String::from)
+    })
+}
+
+/// Sets the catalog used to translate the strings marked with `@tr(...)` in the given domain,
+/// or removes the catalog of the domain when `None`. The translations of the catalog take
+/// precedence over the ones found by gettext.
+///
+/// The domain is the name of the crate that contains the `.slint` files, unless a different
+/// translation domain was configured when compiling them.
+///
+/// This works on all platforms, without the `gettext` feature. The user interface is updated with
+/// the new translations.
+///
+/// ## Example
+/// ```rust,no_run
+/// let catalog = slint::TranslationCatalog::from_mo_file("translations/fr/LC_MESSAGES/example.mo")
+///     .expect("the French translations could not be loaded");
+/// slint::set_translation_catalog(env!("CARGO_PKG_NAME"), Some(catalog));
+/// ```
+#[cfg(feature = "std")]
+pub fn set_translation_catalog(domain: &str, catalog: Option<TranslationCatalog>) {
+    CATALOGS.with(|catalogs| match catalog {
+        Some(catalog) => catalogs.borrow_mut().insert(domain.into(), catalog),
+        None => catalogs.borrow_mut().remove(domain),
+    });
+    mark_all_translations_dirty();
+}
+
+/// Switches the language of the strings marked with `@tr(...)` at run-time, without restarting
+/// the application. The translations are loaded from the `.mo` files in the directory passed to
+/// `slint::init_translations!`, which requires the `gettext` feature, at
+/// `<dirname>/<language>/LC_MESSAGES/<crate>.mo`. If no such file exists for a language with a
+/// region, such as `fr_CH`, the one of the language without the region is used, `fr` here.
+///
+/// The strings are shown untranslated if there are no translations for the language, which is
+/// the case for the language of the source code. Pass an empty string to go back to the language
+/// of the system. All the bindings that use translated strings are evaluated again, so that the
+/// whole user interface shows the new language.
+///
+/// This replaces the catalogs set with [`set_translation_catalog()`] for the domains of the
+/// directories passed to `init_translations!`. Returns an error if a `.mo` file couldn't be read.
+///
+/// ## Example
+/// ```rust,no_run
+/// // After calling slint::init_translations!(...) at start-up
+/// slint::select_translation_language("de").expect("the German translations could not be loaded");
+/// ```
+#[cfg(feature = "std")]
+pub fn select_translation_language(language: &str) -> std::io::Result<()> {
+    let directories = TRANSLATION_DIRECTORIES.with(|directories| directories.borrow().clone());
+    let mut selected_catalogs = Vec::new();
+    for (domain, directory) in directories {
+        if language.is_empty() {
+            selected_catalogs.push((domain, None));
+            continue;
+        }
+        let language_without_region =
+            language.split(['_', '-', '.', '@']).next().unwrap_or(language);
+        let path = [language, language_without_region]
+            .iter()
+            .map(|language| {
+                directory.join(language).join("LC_MESSAGES").join(format!("{domain}.mo"))
+            })
+            .find(|path| path.exists());
+        let catalog = path.map(TranslationCatalog::from_mo_file).transpose()?;
+        selected_catalogs.push((domain, catalog));
+    }
+
+    CATALOGS.with(|catalogs| {
+        let mut catalogs = catalogs.borrow_mut();
+        for (domain, catalog) in selected_catalogs {
+            match catalog {
+                Some(catalog) => catalogs.insert(domain, catalog),
+                None => catalogs.remove(&domain),
+            };
+        }
+    });
+    SELECTED_LANGUAGE.with(|selected| {
+        *selected.borrow_mut() = (!language.is_empty()).then(|| language.into());
+    });
+    mark_all_translations_dirty();
+    Ok(())
+}
+
+/// How the strings marked with `@tr(...)` are shown by [`set_pseudo_localization()`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PseudoLocalization {
+    /// The strings are translated normally.
+    #[default]
+    Disabled,
+    /// The letters of the strings are replaced with accented ones, the strings are made about 40%
+    /// longer, and they are enclosed in brackets. This shows which strings are not marked for
+    /// translation, which labels are too small for longer translations, and which strings are
+    /// concatenated instead of formatted with placeholders.
+    Accented,
+    /// Like [`Self::Accented`], and the strings are also shown from right to left, with bidi
+    /// control characters, to find layouts that don't work with right-to-left languages.
+    RightToLeft,
+}
+
+/// Replaces all the translations of the strings marked with `@tr(...)` with pseudo-localized
+/// versions of the original strings, to test the user interface for internationalization problems
+/// before real translations exist. The user interface is updated with the new strings.
+///
+/// The default is taken from the `SLINT_PSEUDO_LOCALIZATION` environment variable, which can be
+/// set to `accented` or `rtl`, so that this can be enabled without changing the application.
+#[cfg(feature = "std")]
+pub fn set_pseudo_localization(mode: PseudoLocalization) {
+    PSEUDO_LOCALIZATION.with(|pseudo_localization| pseudo_localization.set(Some(mode)));
+    mark_all_translations_dirty();
+}
+
+#[cfg(feature = "std")]
+fn pseudo_localization() -> PseudoLocalization {
+    PSEUDO_LOCALIZATION.with(|pseudo_localization| {
+        if let Some(mode) = pseudo_localization.get() {
+            return mode;
+        }
+        let mode = match std::env::var("SLINT_PSEUDO_LOCALIZATION").as_deref() {
+            Ok("accented" | "1") => PseudoLocalization::Accented,
+            Ok("rtl") => PseudoLocalization::RightToLeft,
+            _ => PseudoLocalization::Disabled,
+        };
+        pseudo_localization.set(Some(mode));
+        mode
+    })
+}
+
+#[cfg(feature = "std")]
+fn pseudo_localize(string: &str, mode: PseudoLocalization) -> String {
+    const ACCENTED_LOWERCASE: &str = "ȧƀƈḓḗƒɠħīĵķŀḿƞǿƥɋřşŧŭṽẇẋẏẑ";
+    const ACCENTED_UPPERCASE: &str = "ȦƁƇḒḖƑƓĦĪĴĶĿḾȠǾƤɊŘŞŦŬṼẆẊẎẐ";
+    let right_to_left = mode == PseudoLocalization::RightToLeft;
+    let mut result = String::new();
+    if right_to_left {
+        // RIGHT-TO-LEFT OVERRIDE
+        result.push('\u{202E}');
+    }
+    result.push('[');
+    let mut letters = 0;
+    let mut in_placeholder = false;
+    for c in string.chars() {
+        // The placeholders, such as `{}` or `{n}`, must stay as they are to be formatted
+        match c {
+            '{' => in_placeholder = true,
+            '}' => in_placeholder = false,
+            _ => {}
+        }
+        let accented = match c {
+            'a'..='z' if !in_placeholder => {
+                ACCENTED_LOWERCASE.chars().nth((c as u8 - b'a') as usize)
+            }
+            'A'..='Z' if !in_placeholder => {
+                ACCENTED_UPPERCASE.chars().nth((c as u8 - b'A') as usize)
+            }
+            _ => None,
+        };
+        if accented.is_some() {
+            letters += 1;
+        }
+        result.push(accented.unwrap_or(c));
+    }
+    // Translations are often longer than the English strings
+    result.extend(core::iter::repeat('~').take((letters * 2 + 4) / 5));
+    result.push(']');
+    if right_to_left {
+        // POP DIRECTIONAL FORMATTING
+        result.push('\u{202C}');
+    }
+    result
+}
+
+/// A string marked with `@tr(...)` that was shown without translation, as returned by
+/// [`untranslated_strings()`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub struct UntranslatedString {
+    /// The language of the translations when the string was shown
+    pub language: String,
+    /// The translation domain of the string
+    pub domain: String,
+    /// The context of the string, or an empty string if it has none
+    pub context: String,
+    /// The string in the language of the source code
+    pub message: String,
+}
+
+/// Starts or stops tracking the strings marked with `@tr(...)` that are shown without
+/// translation, which are returned by [`untranslated_strings()`]. Stopping clears the strings
+/// that were tracked.
+///
+/// Tracking starts at start-up when the `SLINT_TRANSLATION_COVERAGE` environment variable is set.
+#[cfg(feature = "std")]
+pub fn track_untranslated_strings(enabled: bool) {
+    UNTRANSLATED_STRINGS.with(|strings| {
+        let mut strings = strings.borrow_mut();
+        if enabled {
+            strings.get_or_insert_with(Default::default);
+        } else {
+            *strings = None;
+        }
+    });
+    // Evaluate the translations again, so that the strings that are already shown are tracked
+    mark_all_translations_dirty();
+}
+
+/// Returns the strings marked with `@tr(...)` that were shown without translation since the
+/// tracking was started with [`track_untranslated_strings()`], sorted by language. This finds
+/// the strings that are missing in the translations of a language, after going through the
+/// user interface with that language selected.
+///
+/// ## Example
+/// ```rust,no_run
+/// slint::track_untranslated_strings(true);
+/// slint::select_translation_language("de").unwrap();
+/// // ... show all the screens of the application ...
+/// for string in slint::untranslated_strings() {
+///     eprintln!("{}: {:?} is not translated", string.language, string.message);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn untranslated_strings() -> Vec<UntranslatedString> {
+    UNTRANSLATED_STRINGS.with(|strings| {
+        strings.borrow().as_ref().map_or_else(Vec::new, |strings| strings.iter().cloned().collect())
+    })
+}
+
+#[cfg(feature = "std")]
+fn record_untranslated_string(original: &str, contextid: &str, domain: &str) {
+    UNTRANSLATED_STRINGS.with(|strings| {
+        let mut strings = strings.borrow_mut();
+        let Some(strings) = strings.as_mut() else { return };
+        // Without a language, the strings are meant to be shown in the language of the source code
+        let Some(language) = current_language() else { return };
+        strings.insert(UntranslatedString {
+            language,
+            domain: domain.into(),
+            context: contextid.into(),
+            message: original.into(),
+        });
+    });
+}
+
+/// Returns the language selected with [`select_translation_language()`], or the language of the
+/// system otherwise, if it's known.
+#[cfg(feature = "std")]
+fn current_language() -> Option<String> {
+    SELECTED_LANGUAGE.with(|selected| selected.borrow().clone()).or_else(|| {
+        ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find_map(|value| {
+                // LANGUAGE is a list of languages, separated by colons
+                value
+                    .split(':')
+                    .find(|language| !language.is_empty() && !["C", "POSIX"].contains(language))
+                    .map(String::from)
+            })
+    })
+}
+
+#[cfg(feature = "std")]
+fn normalize_language(language: &str) -> String {
+    let language = language.split(['.', '@']).next().unwrap_or(language);
+    language.replace('-', "_").to_lowercase()
+}
+
+/// Returns the language of the translations in lower case, with the region separated by an
+/// underscore and without the encoding, such as `de_ch`. The bindings that call it are evaluated
+/// again when the language changes.
+#[cfg(feature = "std")]
+pub(crate) fn translation_language() -> Option<String> {
+    track_translations();
+    current_language().map(|language| normalize_language(&language))
+}
+
+/// Returns true if the language of the translations is the given locale, or a variant of it with
+/// a region, so that `de` matches `de_CH`. The language is the one selected with
+/// [`select_translation_language()`], or the language of the system otherwise.
+///
+/// This is used by `@image-url(..., locale)` to pick the image of the current language, and the
+/// bindings that call it are evaluated again when the language changes.
+pub fn translation_language_matches(locale: &str) -> bool {
+    #[cfg(feature = "std")]
+    {
+        let Some(language) = translation_language() else {
+            return false;
+        };
+        let locale = normalize_language(locale);
+        !locale.is_empty()
+            && language
+                .strip_prefix(&locale)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('_'))
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = locale;
+        false
+    }
+}
+
+#[cfg(all(target_family = "unix", feature = "gettext-rs"))]
+fn translate_gettext(string: &str, ctx: &str, domain: &str, n: i32, plural: &str) -> String {
+    track_translations();
     fn mangle_context(ctx: &str, s: &str) -> String {
         format!("{}\u{4}{}", ctx, s)
     }
@@ -247,10 +618,13 @@ pub fn mark_all_translations_dirty() {
 
 #[cfg(feature = "gettext-rs")]
 /// Initialize the translation by calling the [`bindtextdomain`](https://man7.org/linux/man-pages/man3/bindtextdomain.3.html) function from gettext
-pub fn gettext_bindtextdomain(_domain: &str, _dirname: std::path::PathBuf) -> std::io::Result<()> {
+pub fn gettext_bindtextdomain(domain: &str, dirname: std::path::PathBuf) -> std::io::Result<()> {
+    TRANSLATION_DIRECTORIES.with(|directories| {
+        directories.borrow_mut().insert(domain.into(), dirname.clone());
+    });
     #[cfg(target_family = "unix")]
     {
-        gettextrs::bindtextdomain(_domain, _dirname)?;
+        gettextrs::bindtextdomain(domain, dirname)?;
         static START: std::sync::Once = std::sync::Once::new();
         START.call_once(|| {
             gettextrs::setlocale(gettextrs::LocaleCategory::LcAll, "");
@@ -285,4 +659,61 @@ pub extern "C" fn slint_translate(
     pub extern "C" fn slint_translations_mark_dirty() {
         mark_all_translations_dirty();
     }
+
+    /// Returns true if the language of the translations is the locale, or a variant of it
+    #[no_mangle]
+    pub extern "C" fn slint_translations_language_matches(locale: &SharedString) -> bool {
+        translation_language_matches(locale)
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_translation_language_matches() {
+    select_translation_language("de_CH.UTF-8").unwrap();
+    assert!(translation_language_matches("de"));
+    assert!(translation_language_matches("de-CH"));
+    assert!(!translation_language_matches("de_AT"));
+    assert!(!translation_language_matches("d"));
+    assert!(!translation_language_matches("fr"));
+    select_translation_language("").unwrap();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_pseudo_localization() {
+    assert_eq!(pseudo_localize("Hello", PseudoLocalization::Accented), "[Ħḗŀŀǿ~~]");
+    assert_eq!(
+        pseudo_localize("{n} files in {}", PseudoLocalization::Accented),
+        "[{n} ƒīŀḗş īƞ {}~~~]"
+    );
+    assert_eq!(pseudo_localize("OK", PseudoLocalization::RightToLeft), "\u{202E}[ǾĶ~]\u{202C}");
+
+    set_pseudo_localization(PseudoLocalization::Accented);
+    assert_eq!(translate("Save", "", "domain", &[] as &[&str], 0, ""), "[Şȧṽḗ~~]");
+    set_pseudo_localization(PseudoLocalization::Disabled);
+    assert_eq!(translate("Save", "", "domain", &[] as &[&str], 0, ""), "Save");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_untranslated_strings() {
+    select_translation_language("fr").unwrap();
+    translate("Before", "", "domain", &[] as &[&str], 0, "");
+    assert!(untranslated_strings().is_empty());
+
+    track_untranslated_strings(true);
+    translate("Open", "menu", "domain", &[] as &[&str], 0, "");
+    translate("Open", "menu", "domain", &[] as &[&str], 0, "");
+    assert_eq!(
+        untranslated_strings(),
+        [UntranslatedString {
+            language: "fr".into(),
+            domain: "domain".into(),
+            context: "menu".into(),
+            message: "Open".into(),
+        }]
+    );
+    track_untranslated_strings(false);
+    assert!(untranslated_strings().is_empty());
 }
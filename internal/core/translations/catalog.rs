@@ -0,0 +1,297 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Loading of translations from gettext `.mo` files, without depending on the gettext library
+//! of the system.
+
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+/// The translations of the messages of one domain into one language, as loaded from a gettext
+/// `.mo` file.
+///
+/// Register it with [`set_translation_catalog()`](super::set_translation_catalog()), so that
+/// the strings marked with `@tr(...)` are translated with it.
+#[derive(Debug, Clone, Default)]
+pub struct TranslationCatalog {
+    /// Maps the message ids, prefixed with the context and `\u{4}`, to the translations, which
+    /// contain one string per plural form
+    messages: HashMap<String, Vec<String>>,
+    plural_forms: PluralExpression,
+}
+
+impl TranslationCatalog {
+    /// Parses the content of a gettext `.mo` file.
+    pub fn from_mo(data: &[u8]) -> std::io::Result<Self> {
+        let magic = data.get(0..4).ok_or_else(|| invalid_data("The .mo file is truncated"))?;
+        let read_u32: fn([u8; 4]) -> u32 = match magic {
+            [0xde, 0x12, 0x04, 0x95] => u32::from_le_bytes,
+            [0x95, 0x04, 0x12, 0xde] => u32::from_be_bytes,
+            _ => return Err(invalid_data("The file is not a .mo file")),
+        };
+        let u32_at = |offset: usize| -> std::io::Result<usize> {
+            data.get(offset..offset + 4)
+                .map(|bytes| read_u32(bytes.try_into().unwrap()) as usize)
+                .ok_or_else(|| invalid_data("The .mo file is truncated"))
+        };
+        let string_at = |table: usize, index: usize| -> std::io::Result<&str> {
+            let length = u32_at(table + index * 8)?;
+            let offset = u32_at(table + index * 8 + 4)?;
+            let bytes = data
+                .get(offset..offset + length)
+                .ok_or_else(|| invalid_data("The .mo file is truncated"))?;
+            core::str::from_utf8(bytes).map_err(|_| invalid_data("The .mo file is not UTF-8"))
+        };
+
+        let count = u32_at(8)?;
+        let original_table = u32_at(12)?;
+        let translation_table = u32_at(16)?;
+
+        let mut catalog = Self::default();
+        for index in 0..count {
+            let original = string_at(original_table, index)?;
+            let translation = string_at(translation_table, index)?;
+            if original.is_empty() {
+                catalog.plural_forms = translation
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Plural-Forms:"))
+                    .and_then(|plural_forms| {
+                        plural_forms.split(';').find_map(|s| s.trim().strip_prefix("plural="))
+                    })
+                    .map(PluralExpression::parse)
+                    .transpose()?
+                    .unwrap_or_default();
+                continue;
+            }
+            // The plural form of the message id follows the singular form
+            let msgid = original.split('\0').next().unwrap_or_default();
+            catalog
+                .messages
+                .insert(msgid.to_string(), translation.split('\0').map(String::from).collect());
+        }
+        Ok(catalog)
+    }
+
+    /// Reads and parses the gettext `.mo` file at the given path.
+    pub fn from_mo_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        Self::from_mo(&std::fs::read(path)?)
+    }
+
+    /// Returns the translation of the message, or the form for `n` of the message with plural forms.
+    pub(super) fn translate(&self, context: &str, msgid: &str, n: Option<i32>) -> Option<&str> {
+        let forms = if context.is_empty() {
+            self.messages.get(msgid)
+        } else {
+            self.messages.get(&format!("{context}\u{4}{msgid}"))
+        }?;
+        let form = n.map_or(0, |n| self.plural_forms.evaluate(n as i64).max(0) as usize);
+        forms.get(form).map(String::as_str).filter(|translation| !translation.is_empty())
+    }
+}
+
+/// The expression of the `plural` entry of the `Plural-Forms` header, a subset of C
+/// that computes the index of the plural form from `n`.
+#[derive(Debug, Clone, PartialEq)]
+enum PluralExpression {
+    N,
+    Constant(i64),
+    Not(Box<PluralExpression>),
+    Binary(Box<PluralExpression>, &'static str, Box<PluralExpression>),
+    Conditional(Box<PluralExpression>, Box<PluralExpression>, Box<PluralExpression>),
+}
+
+impl Default for PluralExpression {
+    /// The plural rule of English and gettext's default: `n != 1`
+    fn default() -> Self {
+        Self::Binary(Box::new(Self::N), "!=", Box::new(Self::Constant(1)))
+    }
+}
+
+/// Binary operators from the lowest to the highest precedence
+const BINARY_OPERATORS: &[&[&str]] =
+    &[&["||"], &["&&"], &["==", "!="], &["<=", ">=", "<", ">"], &["+", "-"], &["*", "/", "%"]];
+
+struct PluralExpressionParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> PluralExpressionParser<'a> {
+    fn eat(&mut self, token: &str) -> bool {
+        self.rest = self.rest.trim_start();
+        if let Some(rest) = self.rest.strip_prefix(token) {
+            self.rest = rest;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn conditional(&mut self) -> std::io::Result<PluralExpression> {
+        let condition = self.binary(0)?;
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+        let true_expression = self.conditional()?;
+        if !self.eat(":") {
+            return Err(invalid_data("Expected ':' in the plural forms expression"));
+        }
+        let false_expression = self.conditional()?;
+        Ok(PluralExpression::Conditional(
+            condition.into(),
+            true_expression.into(),
+            false_expression.into(),
+        ))
+    }
+
+    fn binary(&mut self, level: usize) -> std::io::Result<PluralExpression> {
+        let Some(operators) = BINARY_OPERATORS.get(level) else { return self.unary() };
+        let mut lhs = self.binary(level + 1)?;
+        'outer: loop {
+            for operator in operators.iter().copied() {
+                if self.eat(operator) {
+                    let rhs = self.binary(level + 1)?;
+                    lhs = PluralExpression::Binary(lhs.into(), operator, rhs.into());
+                    continue 'outer;
+                }
+            }
+            return Ok(lhs);
+        }
+    }
+
+    fn unary(&mut self) -> std::io::Result<PluralExpression> {
+        if self.eat("!") {
+            return Ok(PluralExpression::Not(self.unary()?.into()));
+        }
+        if self.eat("(") {
+            let expression = self.conditional()?;
+            return if self.eat(")") {
+                Ok(expression)
+            } else {
+                Err(invalid_data("Expected ')' in the plural forms expression"))
+            };
+        }
+        if self.eat("n") {
+            return Ok(PluralExpression::N);
+        }
+        let digits =
+            self.rest.len() - self.rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let (number, rest) = self.rest.split_at(digits);
+        self.rest = rest;
+        number
+            .parse()
+            .map(PluralExpression::Constant)
+            .map_err(|_| invalid_data("Invalid plural forms expression"))
+    }
+}
+
+impl PluralExpression {
+    fn parse(source: &str) -> std::io::Result<Self> {
+        let mut parser = PluralExpressionParser { rest: source };
+        let expression = parser.conditional()?;
+        if !parser.rest.trim().is_empty() {
+            return Err(invalid_data("Invalid plural forms expression"));
+        }
+        Ok(expression)
+    }
+
+    fn evaluate(&self, n: i64) -> i64 {
+        match self {
+            Self::N => n,
+            Self::Constant(value) => *value,
+            Self::Not(expression) => (expression.evaluate(n) == 0) as i64,
+            Self::Conditional(condition, true_expression, false_expression) => {
+                if condition.evaluate(n) != 0 {
+                    true_expression.evaluate(n)
+                } else {
+                    false_expression.evaluate(n)
+                }
+            }
+            Self::Binary(lhs, operator, rhs) => {
+                let (lhs, rhs) = (lhs.evaluate(n), rhs.evaluate(n));
+                match *operator {
+                    "||" => (lhs != 0 || rhs != 0) as i64,
+                    "&&" => (lhs != 0 && rhs != 0) as i64,
+                    "==" => (lhs == rhs) as i64,
+                    "!=" => (lhs != rhs) as i64,
+                    "<=" => (lhs <= rhs) as i64,
+                    ">=" => (lhs >= rhs) as i64,
+                    "<" => (lhs < rhs) as i64,
+                    ">" => (lhs > rhs) as i64,
+                    "+" => lhs.wrapping_add(rhs),
+                    "-" => lhs.wrapping_sub(rhs),
+                    "*" => lhs.wrapping_mul(rhs),
+                    "/" => lhs.checked_div(rhs).unwrap_or(0),
+                    "%" => lhs.checked_rem(rhs).unwrap_or(0),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_plural_expression() {
+    let polish = PluralExpression::parse(
+        "(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2)",
+    )
+    .unwrap();
+    let forms = [1, 2, 5, 12, 22, 25, 104].map(|n| polish.evaluate(n));
+    assert_eq!(forms, [0, 1, 2, 2, 1, 2, 1]);
+    assert_eq!(PluralExpression::parse("0").unwrap().evaluate(42), 0);
+    assert_eq!(PluralExpression::parse("n > 1").unwrap().evaluate(1), 0);
+    assert_eq!(PluralExpression::parse("!(n == 1)").unwrap().evaluate(1), 0);
+    assert!(PluralExpression::parse("n !=").is_err());
+    assert!(PluralExpression::parse("(n == 1").is_err());
+}
+
+#[test]
+fn test_catalog_from_mo() {
+    // Builds a little endian .mo file from (original, translation) pairs, sorted by original
+    fn mo(entries: &[(&str, &str)]) -> Vec<u8> {
+        let header_size = 28;
+        let original_table = header_size;
+        let translation_table = original_table + entries.len() * 8;
+        let mut strings_offset = translation_table + entries.len() * 8;
+        let mut tables = (Vec::new(), Vec::new());
+        let mut strings = Vec::new();
+        for (original, translation) in entries {
+            for (table, string) in [(&mut tables.0, original), (&mut tables.1, translation)] {
+                table.extend((string.len() as u32).to_le_bytes());
+                table.extend((strings_offset as u32).to_le_bytes());
+                strings.extend(string.as_bytes());
+                strings.push(0);
+                strings_offset += string.len() + 1;
+            }
+        }
+        let mut data = Vec::new();
+        for value in [0x950412de, 0, entries.len(), original_table, translation_table, 0, 0] {
+            data.extend((value as u32).to_le_bytes());
+        }
+        data.extend(tables.0);
+        data.extend(tables.1);
+        data.extend(strings);
+        data
+    }
+
+    let catalog = TranslationCatalog::from_mo(&mo(&[
+        ("", "Content-Type: text/plain; charset=UTF-8\nPlural-Forms: nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"),
+        ("Hello", "Cześć"),
+        ("menu\u{4}Open", "Otwórz"),
+        ("{n} file\0{n} files", "{n} plik\0{n} pliki\0{n} plików"),
+    ]))
+    .unwrap();
+
+    assert_eq!(catalog.translate("", "Hello", None), Some("Cześć"));
+    assert_eq!(catalog.translate("menu", "Open", None), Some("Otwórz"));
+    assert_eq!(catalog.translate("", "Open", None), None);
+    assert_eq!(catalog.translate("", "{n} file", Some(1)), Some("{n} plik"));
+    assert_eq!(catalog.translate("", "{n} file", Some(3)), Some("{n} pliki"));
+    assert_eq!(catalog.translate("", "{n} file", Some(5)), Some("{n} plików"));
+
+    assert!(TranslationCatalog::from_mo(b"not a mo file").is_err());
+    assert!(TranslationCatalog::from_mo(&mo(&[("Hello", "Cześć")])[..40]).is_err());
+}
@@ -6,18 +6,22 @@
 #![warn(missing_docs)]
 //! Exposed Window API
 
+use crate::accessibility::AccessibleStringProperty;
 use crate::api::{
-    CloseRequestResponse, LogicalPosition, PhysicalPosition, PhysicalSize, PlatformError, Window,
-    WindowPosition, WindowSize,
+    CloseRequestResponse, FramePresentedInfo, InputLatencyStatistics, LogicalPosition,
+    PhysicalPosition, PhysicalSize, PlatformError, Window, WindowPosition, WindowSize,
 };
 use crate::graphics::Point;
 use crate::input::{
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, MouseEvent,
-    MouseInputState, TextCursorBlinker,
+    MouseInputState, TextCursorBlinker, TouchPhase, TouchState,
 };
-use crate::item_tree::ItemRc;
+use crate::item_tree::{ItemRc, ItemWeak};
 use crate::item_tree::{ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak};
-use crate::items::{ColorScheme, InputType, ItemRef, MouseCursor};
+use crate::items::{
+    ColorScheme, DragArea, DropEvent, InputType, InteractionMode, ItemRef, MouseCursor,
+    PointerEventDevice, PopupClosePolicy,
+};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
@@ -31,6 +35,8 @@
 use vtable::VRcMapped;
 
 pub mod popup;
+pub(crate) mod registry;
+pub mod software_cursor;
 
 fn next_focus_item(item: ItemRc) -> ItemRc {
     item.next_focus_item()
@@ -189,6 +195,13 @@ fn create_popup(&self, _geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>>
     // TODO: Make the enum public and make public
     fn set_mouse_cursor(&self, _cursor: MouseCursor) {}
 
+    /// Set a mouse cursor that shows the image, with the `hotspot` of the image, relative to its
+    /// top left corner, at the mouse position. Backends that don't support custom cursors show
+    /// the default cursor.
+    fn set_custom_mouse_cursor(&self, _image: &crate::graphics::Image, _hotspot: LogicalPosition) {
+        self.set_mouse_cursor(MouseCursor::Default)
+    }
+
     /// This method allow editable input field to communicate with the platform about input methods
     fn input_method_request(&self, _: InputMethodRequest) {}
 
@@ -229,6 +242,25 @@ fn display_handle_06_rc(
     fn bring_to_front(&self) -> Result<(), PlatformError> {
         Ok(())
     }
+
+    /// Tells the windowing system that the window belongs to `parent`, so that it stays on top
+    /// of it, or that it's a top-level window again when `parent` is None. If `modal` is true,
+    /// the windowing system may also disable the parent window while this one is shown.
+    ///
+    /// This is called before the window is shown, and when the relationship changes.
+    fn set_parent_window(&self, _parent: Option<Rc<dyn WindowAdapter>>, _modal: bool) {}
+
+    /// Lets the windowing system move the window with the pointer, while the left button that was just
+    /// pressed stays pressed. This is called by the `WindowMoveArea` element.
+    ///
+    /// Return false if it's not supported, in which case the window is moved with [`WindowAdapter::set_position`].
+    fn start_window_move(&self) -> bool {
+        false
+    }
+
+    /// Lets the windowing system resize the window from the `edge` with the pointer, while the left button
+    /// that was just pressed stays pressed. This is called by the `WindowResizeArea` element.
+    fn start_window_resize(&self, _edge: crate::items::WindowResizeEdge) {}
 }
 
 /// This is the parameter from [`WindowAdapterInternal::input_method_request()`] which lets the editable text input field
@@ -342,6 +374,12 @@ pub fn is_maximized(&self) -> bool {
     pub fn is_minimized(&self) -> bool {
         self.0.minimized.get()
     }
+
+    /// true if the window should be placed above all other windows, with the `always-on-top`
+    /// property or [`Window::set_always_on_top()`]
+    pub fn is_always_on_top(&self) -> bool {
+        self.0.is_always_on_top()
+    }
 }
 
 struct WindowPropertiesTracker {
@@ -386,9 +424,57 @@ struct PopupWindow {
     location: PopupWindowLocation,
     /// The component that is responsible for providing the popup content.
     component: ItemTreeRc,
-    /// If true, Slint will close the popup after any mouse click within the popup.
-    /// Set to false and call close() on the PopupWindow to close it manually.
-    close_on_click: bool,
+    /// When the popup closes by itself, otherwise close() is called on the PopupWindow.
+    close_policy: PopupClosePolicy,
+    /// If true, the popup closes when the escape key is pressed and not handled.
+    close_on_escape: bool,
+}
+
+/// The state of a drag and drop operation, see [`WindowInner::start_drag`]
+struct DragState {
+    /// The dragged data. Its position is set for each `DropArea` that receives it.
+    data: DropEvent,
+    /// The `DragArea` that started the drag, or `None` if the data comes from another application
+    source: Option<ItemWeak>,
+    /// Set when a `DropArea` accepted the drop
+    dropped: bool,
+}
+
+/// Correlates the input events with the frames that show their effect, to measure the input latency.
+/// The times are relative to the start of the application.
+#[derive(Default)]
+struct InputLatencyTracker {
+    /// When the oldest input event that changed the window, but wasn't rendered yet, was received
+    pending: Cell<Option<core::time::Duration>>,
+    /// When the oldest input event shown by the frame that was rendered, but not presented yet, was received
+    rendered: Cell<Option<core::time::Duration>>,
+    statistics: Cell<InputLatencyStatistics>,
+}
+
+impl InputLatencyTracker {
+    /// Called after an input event, received at `received`, was processed. `needs_redraw` tells whether
+    /// the window has to be rendered again, so the next frame shows the effect of the event.
+    fn input_processed(&self, received: core::time::Duration, needs_redraw: bool) {
+        if needs_redraw && self.pending.get().is_none() {
+            self.pending.set(Some(received));
+        }
+    }
+
+    fn frame_rendered(&self) {
+        if let Some(pending) = self.pending.take() {
+            // The previous frame wasn't reported as presented, so this one shows the older events too
+            self.rendered.set(Some(self.rendered.get().map_or(pending, |r| r.min(pending))));
+        }
+    }
+
+    /// Returns the latency of the presented frame, if it showed the effect of input events
+    fn frame_presented(&self, now: core::time::Duration) -> Option<core::time::Duration> {
+        let latency = now.saturating_sub(self.rendered.take()?);
+        let mut statistics = self.statistics.get();
+        statistics.add(latency);
+        self.statistics.set(statistics);
+        Some(latency)
+    }
 }
 
 #[pin_project::pin_project]
@@ -404,6 +490,8 @@ struct WindowPinnedFields {
     active: Property<bool>,
     #[pin]
     text_input_focused: Property<bool>,
+    #[pin]
+    interaction_mode: Property<InteractionMode>,
 }
 
 /// Inner datastructure for the [`crate::api::Window`]
@@ -414,6 +502,8 @@ pub struct WindowInner {
     strong_component_ref: RefCell<Option<ItemTreeRc>>,
     mouse_input_state: Cell<MouseInputState>,
     pub(crate) modifiers: Cell<InternalKeyboardModifierState>,
+    /// The device that generates the pointer events, with its pressure and tilt
+    pub(crate) pointer_details: Cell<crate::platform::PointerDetails>,
 
     /// ItemRC that currently have the focus. (possibly a, instance of TextInput)
     pub focus_item: RefCell<crate::item_tree::ItemWeak>,
@@ -430,11 +520,24 @@ pub struct WindowInner {
     fullscreen: Cell<bool>,
     maximized: Cell<bool>,
     minimized: Cell<bool>,
+    always_on_top: Cell<bool>,
+    /// The interaction mode set with [`WindowInner::set_interaction_mode`], or None when it is
+    /// detected from the input events
+    forced_interaction_mode: Cell<Option<InteractionMode>>,
 
     active_popup: RefCell<Option<PopupWindow>>,
+    /// The window set with [`WindowInner::set_parent_window`]
+    parent_window: RefCell<Option<Weak<dyn WindowAdapter>>>,
+    modal: Cell<bool>,
     had_popup_on_press: Cell<bool>,
+    drag: RefCell<Option<DragState>>,
     close_requested: Callback<(), CloseRequestResponse>,
+    frame_presented: Callback<FramePresentedInfo>,
+    presented_frame_count: Cell<u64>,
+    input_latency: InputLatencyTracker,
     click_state: ClickState,
+    touch_state: TouchState,
+    software_cursor: software_cursor::SoftwareCursorState,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
 
@@ -473,6 +576,7 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             strong_component_ref: Default::default(),
             mouse_input_state: Default::default(),
             modifiers: Default::default(),
+            pointer_details: Default::default(),
             pinned_fields: Box::pin(WindowPinnedFields {
                 redraw_tracker,
                 window_properties_tracker,
@@ -482,6 +586,10 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
                     false,
                     "i_slint_core::Window::text_input_focused",
                 ),
+                interaction_mode: Property::new_named(
+                    Default::default(),
+                    "i_slint_core::Window::interaction_mode",
+                ),
             }),
             #[cfg(feature = "std")]
             fullscreen: Cell::new(std::env::var("SLINT_FULLSCREEN").is_ok()),
@@ -489,13 +597,23 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             fullscreen: Cell::new(false),
             maximized: Cell::new(false),
             minimized: Cell::new(false),
+            always_on_top: Cell::new(false),
+            forced_interaction_mode: Cell::new(None),
             focus_item: Default::default(),
             last_ime_text: Default::default(),
             cursor_blinker: Default::default(),
             active_popup: Default::default(),
+            parent_window: Default::default(),
+            modal: Cell::new(false),
             had_popup_on_press: Default::default(),
+            drag: Default::default(),
             close_requested: Default::default(),
+            frame_presented: Default::default(),
+            presented_frame_count: Cell::new(0),
+            input_latency: Default::default(),
             click_state: ClickState::default(),
+            touch_state: TouchState::default(),
+            software_cursor: Default::default(),
             prevent_focus_change: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
@@ -558,10 +676,28 @@ pub fn try_component(&self) -> Option<ItemTreeRc> {
     /// * `what`: The type of mouse event.
     /// * `component`: The Slint compiled component that provides the tree of items.
     pub fn process_mouse_input(&self, mut event: MouseEvent) {
+        let received = crate::animations::Instant::duration_since_start();
         crate::animations::update_animations();
+        crate::backlight::notify_user_input();
+
+        self.update_software_cursor(|cursor| cursor.set_position(event.position()));
+
+        // While a modal child window is shown, the items see the pointer leave the window
+        if let Some(modal) = self.modal_child_window() {
+            if matches!(event, MouseEvent::Pressed { .. }) {
+                if let Some(x) = modal.internal(crate::InternalToken) {
+                    let _ = x.bring_to_front();
+                }
+            }
+            event = MouseEvent::Exit;
+        }
 
         // handle multiple press release
-        event = self.click_state.check_repeat(event, self.ctx.platform().click_interval());
+        event = self.click_state.check_repeat(event, crate::settings::settings().click_interval);
+        event = self.drag_event(event);
+        // Releasing the pointer drops the data, and leaving the window cancels the drag
+        let drag_ended = matches!(event, MouseEvent::Drop { .. })
+            || (matches!(event, MouseEvent::Exit) && self.drag.borrow().is_some());
 
         let pressed_event = matches!(event, MouseEvent::Pressed { .. });
         let released_event = matches!(event, MouseEvent::Released { .. });
@@ -578,12 +714,14 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
             self.had_popup_on_press.set(self.active_popup.borrow().is_some());
         }
 
-        let close_popup_on_click = self.close_popup_on_click();
+        let close_policy = self.active_popup.borrow().as_ref().map(|popup| popup.close_policy);
         let mut mouse_inside_popup = false;
 
         mouse_input_state = if let Some(mut event) =
             crate::input::handle_mouse_grab(event, &window_adapter, &mut mouse_input_state)
         {
+            // The grabber might have started a drag
+            event = self.drag_event(event);
             let (item_tree, offset) = if let Some(PopupWindow {
                 location: PopupWindowLocation::ChildWindow(coordinates),
                 component,
@@ -632,19 +770,109 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         if last_top_item != mouse_input_state.top_item_including_delayed() {
             self.click_state.reset();
-            self.click_state.check_repeat(event, self.ctx.platform().click_interval());
+            self.click_state.check_repeat(event, crate::settings::settings().click_interval);
         }
 
         self.mouse_input_state.set(mouse_input_state);
 
-        if close_popup_on_click
-            && ((mouse_inside_popup && released_event && self.had_popup_on_press.get())
-                || (!mouse_inside_popup && pressed_event))
-        {
+        if drag_ended {
+            self.end_drag();
+        }
+
+        let click_outside = !mouse_inside_popup && pressed_event;
+        let close_popup = match close_policy {
+            Some(PopupClosePolicy::CloseOnClick) => {
+                click_outside
+                    || (mouse_inside_popup && released_event && self.had_popup_on_press.get())
+            }
+            Some(PopupClosePolicy::CloseOnClickOutside) => click_outside,
+            Some(PopupClosePolicy::StayOpen) | None => false,
+        };
+        if close_popup {
             self.close_popup();
         }
 
         crate::properties::ChangeTracker::run_change_handlers();
+        self.input_latency.input_processed(received, self.pinned_fields.redraw_tracker.is_dirty());
+    }
+
+    /// Receive the event of a finger touching, moving on, or leaving the screen. `id` tells the
+    /// fingers apart while they touch the screen.
+    ///
+    /// The first finger acts as the left mouse button, and two fingers result in
+    /// [`MouseEvent::PinchGesture`] events, see [`TouchState`].
+    pub fn process_touch_input(&self, id: u64, position: LogicalPoint, phase: TouchPhase) {
+        self.input_device_used(InteractionMode::Touch);
+        if self.pointer_details.get().device == PointerEventDevice::Mouse {
+            // Unless the backend reported a pen, or the pressure of the finger
+            self.pointer_details
+                .set(crate::platform::PointerDetails::new(PointerEventDevice::Touch));
+        }
+        for event in self.touch_state.process(id, position, phase) {
+            self.process_mouse_input(event);
+        }
+        if phase == TouchPhase::Ended {
+            // The mouse events that come after the touch are from the mouse
+            self.pointer_details.set(Default::default());
+        }
+    }
+
+    /// Starts a drag and drop operation. Until the pointer is released, the pointer moves are sent
+    /// to the items as drag events, and the `DropArea` elements under the pointer get the data.
+    ///
+    /// `source` is the `DragArea` that started the drag. It's `None` when the backend forwards
+    /// the data that another application drags over the window, such as files from a file manager.
+    pub fn start_drag(&self, mime_type: SharedString, data: SharedString, source: Option<&ItemRc>) {
+        *self.drag.borrow_mut() = Some(DragState {
+            data: DropEvent { mime_type, data, position: Default::default() },
+            source: source.map(ItemRc::downgrade),
+            dropped: false,
+        });
+    }
+
+    /// Returns the data of the drag and drop operation in progress, if any
+    pub fn drag_data(&self) -> Option<DropEvent> {
+        self.drag.borrow().as_ref().map(|drag| drag.data.clone())
+    }
+
+    /// Called by the `DropArea` that accepted the dropped data
+    pub(crate) fn accept_drop(&self) {
+        if let Some(drag) = self.drag.borrow_mut().as_mut() {
+            drag.dropped = true;
+        }
+    }
+
+    /// Ends the drag and drop operation in progress, and notifies the `DragArea` that started it
+    fn end_drag(&self) {
+        let Some(drag) = self.drag.take() else { return };
+        if let Some(area) =
+            drag.source.and_then(|s| s.upgrade()).and_then(|s| s.downcast::<DragArea>())
+        {
+            area.as_pin_ref().drag_ended(drag.dropped);
+        }
+    }
+
+    /// Turns the pointer moves and releases into drag events while a drag and drop operation is
+    /// in progress
+    fn drag_event(&self, event: MouseEvent) -> MouseEvent {
+        let source = match self.drag.borrow().as_ref() {
+            Some(drag) => drag.source.clone(),
+            None => return event,
+        };
+        match event {
+            MouseEvent::Moved { position } => {
+                // Let the DragArea place a preview under the pointer
+                if let Some(source) = source.and_then(|s| s.upgrade()) {
+                    if let Some(area) = source.downcast::<DragArea>() {
+                        let origin = source.map_to_window(source.geometry().origin);
+                        area.as_pin_ref().drag_moved(position - origin.to_vector());
+                    }
+                }
+                MouseEvent::DragMove { position }
+            }
+            MouseEvent::Released { position, .. } => MouseEvent::Drop { position },
+            event => event,
+        }
     }
 
     /// Called by the input code's internal timer to send an event that was delayed
@@ -661,7 +889,18 @@ pub(crate) fn process_delayed_event(&self) {
     /// Arguments:
     /// * `event`: The key event received by the windowing system.
     /// * `component`: The Slint compiled component that provides the tree of items.
-    pub fn process_key_input(&self, mut event: KeyEvent) {
+    pub fn process_key_input(&self, event: KeyEvent) {
+        if self.modal_child_window().is_some() {
+            return;
+        }
+        let received = crate::animations::Instant::duration_since_start();
+        self.deliver_key_event(event);
+        self.input_latency.input_processed(received, self.pinned_fields.redraw_tracker.is_dirty());
+    }
+
+    /// Sends the key event to the focus item and its ancestors, until one accepts it
+    fn deliver_key_event(&self, mut event: KeyEvent) {
+        crate::backlight::notify_user_input();
         if let Some(updated_modifier) = self
             .modifiers
             .get()
@@ -690,6 +929,14 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
             item = focus_item.parent_item();
         }
 
+        if event.text.starts_with(key_codes::Escape)
+            && event.event_type == KeyEventType::KeyPressed
+            && self.active_popup.borrow().as_ref().map_or(false, |popup| popup.close_on_escape)
+        {
+            self.close_popup();
+            return;
+        }
+
         // Make Tab/Backtab handle keyboard focus
         let extra_mod = event.modifiers.control || event.modifiers.meta || event.modifiers.alt;
         if event.text.starts_with(key_codes::Tab)
@@ -708,18 +955,60 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
         crate::properties::ChangeTracker::run_change_handlers();
     }
 
-    /// Installs a binding on the specified property that's toggled whenever the text cursor is supposed to be visible or not.
-    pub fn set_cursor_blink_binding(&self, prop: &crate::Property<bool>) {
+    /// Installs a binding on the specified property that's set to the opacity of the text cursor: 0 when it's
+    /// supposed to be hidden, 1 when it's supposed to be visible, and in between while it fades with `fade`.
+    pub fn set_cursor_blink_binding(&self, prop: &crate::Property<f32>, fade: bool) {
         let existing_blinker = self.cursor_blinker.borrow().clone();
 
         let blinker = existing_blinker.upgrade().unwrap_or_else(|| {
-            let new_blinker = TextCursorBlinker::new();
+            let new_blinker = TextCursorBlinker::new(self.window_adapter_weak.clone());
             *self.cursor_blinker.borrow_mut() =
                 pin_weak::rc::PinWeak::downgrade(new_blinker.clone());
             new_blinker
         });
 
-        TextCursorBlinker::set_binding(blinker, prop);
+        TextCursorBlinker::set_binding(blinker, prop, fade);
+    }
+
+    /// Called by the cursor blinker when the text cursor of the focused `TextInput` was shown or hidden.
+    pub(crate) fn text_cursor_visibility_changed(&self) {
+        let focus_item = self.focus_item.borrow().upgrade();
+        if let Some(focus_item) = focus_item {
+            self.mark_text_cursor_dirty(&focus_item);
+        }
+    }
+
+    /// Marks the area of the text cursor of the `TextInput` as dirty and requests a redraw. The
+    /// `TextInput` doesn't repaint itself when its cursor is shown or hidden, so that renderers with
+    /// partial rendering only repaint the cursor when it blinks, instead of the whole element.
+    pub(crate) fn mark_text_cursor_dirty(&self, item_rc: &ItemRc) {
+        let Some(window_adapter) = self.window_adapter_weak.upgrade() else { return };
+        let Some(text_input) = item_rc.downcast::<crate::items::TextInput>() else { return };
+
+        let cursor_rect = crate::properties::evaluate_no_tracking(|| {
+            let mut rect = text_input.as_pin_ref().cursor_rect(&window_adapter);
+            if text_input.as_pin_ref().text_cursor_shape() != crate::items::TextCursorShape::Bar {
+                // The block and underline shapes are as wide as the character after the cursor,
+                // which is usually not wider than the line is high
+                rect.size.width = rect.size.width.max(rect.size.height);
+            }
+            rect.origin =
+                item_rc.map_to_window(item_rc.geometry().origin + rect.origin.to_vector());
+            if let Some(PopupWindowLocation::ChildWindow(offset)) = self
+                .active_popup
+                .borrow()
+                .as_ref()
+                .filter(|popup| ItemTreeRc::ptr_eq(item_rc.item_tree(), &popup.component))
+                .map(|popup| &popup.location)
+            {
+                rect.origin += offset.to_vector();
+            }
+            // Include the anti-aliased edges
+            rect.inflate(1 as Coord, 1 as Coord)
+        });
+
+        window_adapter.renderer().mark_dirty_region(cursor_rect.into());
+        window_adapter.request_redraw();
     }
 
     /// Sets the focus to the item pointed to by item_ptr. This will remove the focus from any
@@ -809,8 +1098,82 @@ fn move_focus(&self, start_item: ItemRc, forward: impl Fn(ItemRc) -> ItemRc) ->
         }
     }
 
+    /// Returns all the items of the window, in the order of the item tree.
+    fn items_in_focus_chain(&self) -> alloc::vec::Vec<ItemRc> {
+        let root = ItemRc::new(self.component(), 0);
+        let mut items = alloc::vec::Vec::new();
+        let mut item = root.clone();
+        loop {
+            let next = next_focus_item(item.clone());
+            items.push(item);
+            if next == root {
+                return items;
+            }
+            item = next;
+        }
+    }
+
+    /// Returns all the items of the window in the order in which the tab key moves the focus,
+    /// or None if none of the items has a `tab-index`, so that the order of the item tree applies.
+    ///
+    /// Items with a positive `tab-index` come first, by increasing `tab-index`, followed by the items
+    /// with a `tab-index` of zero in the order of the item tree. Items with a negative `tab-index`
+    /// are skipped.
+    fn tab_index_focus_chain(&self) -> Option<alloc::vec::Vec<ItemRc>> {
+        let mut chain = self
+            .items_in_focus_chain()
+            .into_iter()
+            .map(|item| {
+                let tab_index = item.tab_index();
+                (item, tab_index)
+            })
+            .collect::<alloc::vec::Vec<_>>();
+        if chain.iter().all(|(_, tab_index)| *tab_index == 0) {
+            return None;
+        }
+        chain.retain(|(_, tab_index)| *tab_index >= 0);
+        chain.sort_by_key(|(_, tab_index)| (*tab_index == 0, *tab_index));
+        Some(chain.into_iter().map(|(item, _)| item).collect())
+    }
+
+    /// Moves the focus to the next item (or the previous one if `forward` is false) of the chain
+    /// returned by [`Self::tab_index_focus_chain`] that accepts it, wrapping around.
+    /// Returns false if no item has a `tab-index` and the focus didn't change.
+    fn move_focus_by_tab_index(&self, forward: bool) -> bool {
+        let Some(chain) = self.tab_index_focus_chain() else {
+            return false;
+        };
+        let current = self.take_focus_item();
+        let len = chain.len();
+        let position =
+            current.as_ref().and_then(|current| chain.iter().position(|item| item == current));
+        let start = match (position, forward) {
+            (Some(position), true) => position + 1,
+            (Some(position), false) => position + len - 1,
+            (None, true) => 0,
+            (None, false) => len.saturating_sub(1),
+        };
+        let end_item = (0..len)
+            .map(|n| if forward { (start + n) % len } else { (start + len - n) % len })
+            .map(|index| &chain[index])
+            .find(|item| {
+                item.is_visible()
+                    && self.publish_focus_item(&Some((*item).clone()))
+                        == crate::input::FocusEventResult::FocusAccepted
+            })
+            .cloned();
+        let window_adapter = self.window_adapter();
+        if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
+            window_adapter.handle_focus_change(current, end_item);
+        }
+        true
+    }
+
     /// Move keyboard focus to the next item
     pub fn focus_next_item(&self) {
+        if self.move_focus_by_tab_index(true) {
+            return;
+        }
         let component = self.component();
         let start_item = self
             .take_focus_item()
@@ -825,6 +1188,9 @@ pub fn focus_next_item(&self) {
 
     /// Move keyboard focus to the previous item.
     pub fn focus_previous_item(&self) {
+        if self.move_focus_by_tab_index(false) {
+            return;
+        }
         let component = self.component();
         let start_item = previous_focus_item(
             self.take_focus_item().unwrap_or_else(|| ItemRc::new(component, 0)),
@@ -836,6 +1202,34 @@ pub fn focus_previous_item(&self) {
         }
     }
 
+    /// Returns the `accessible-id` of the item that has the focus, or of its closest ancestor
+    /// that has one.
+    pub fn focused_accessible_id(&self) -> Option<SharedString> {
+        let mut item = self.focus_item.borrow().upgrade();
+        while let Some(current) = item {
+            let id = current.accessible_string_property(AccessibleStringProperty::Id);
+            if let Some(id) = id.filter(|id| !id.is_empty()) {
+                return Some(id);
+            }
+            item = current.parent_item();
+        }
+        None
+    }
+
+    /// Sets the focus to the item with the given `accessible-id`, or to the first item after it
+    /// in the focus chain that accepts the focus, such as the `TextInput` of a `LineEdit`.
+    /// Returns false if no item has this id.
+    pub fn set_focus_to_accessible_id(&self, id: &str) -> bool {
+        let Some(item) = self.items_in_focus_chain().into_iter().find(|item| {
+            item.accessible_string_property(AccessibleStringProperty::Id)
+                .is_some_and(|item_id| item_id == id)
+        }) else {
+            return false;
+        };
+        self.set_focus_item(&item, true);
+        true
+    }
+
     /// Marks the window to be the active window. This typically coincides with the keyboard
     /// focus. One exception though is when a popup is shown, in which case the window may
     /// remain active but temporarily loose focus to the popup.
@@ -911,6 +1305,7 @@ pub fn draw_contents<T>(
             })
         };
 
+        self.input_latency.frame_rendered();
         self.pinned_fields
             .as_ref()
             .project_ref()
@@ -930,6 +1325,7 @@ pub fn show(&self) -> Result<(), PlatformError> {
 
         self.update_window_properties();
         self.window_adapter().set_visible(true)?;
+        self.ctx.0.window_registry.register(&self.window_adapter());
         // Make sure that the window's inner size is in sync with the root window item's
         // width/height.
         let size = self.window_adapter().size();
@@ -943,6 +1339,13 @@ pub fn show(&self) -> Result<(), PlatformError> {
 
     /// De-registers the window with the windowing system.
     pub fn hide(&self) -> Result<(), PlatformError> {
+        // The child windows are hidden with their parent
+        if let Some(ctx) = self.try_ctx() {
+            for child in ctx.0.window_registry.children_of(&self.window_adapter_weak) {
+                let _ = child.window().hide();
+            }
+            ctx.0.window_registry.unregister(&self.window_adapter());
+        }
         let result = self.window_adapter().set_visible(false);
         let was_visible = self.strong_component_ref.borrow_mut().take().is_some();
         if was_visible {
@@ -956,6 +1359,59 @@ pub fn hide(&self) -> Result<(), PlatformError> {
         result
     }
 
+    /// Sets the window that this window belongs to, or makes it a top-level window again with None.
+    ///
+    /// A child window is hidden when its parent is hidden, and when it's modal, the parent
+    /// doesn't receive input while the child is shown. The call is ignored if `parent` is this
+    /// window or one of its descendants.
+    pub fn set_parent_window(&self, parent: Option<&Rc<dyn WindowAdapter>>) {
+        if let Some(parent) = parent {
+            let mut ancestor = Some(parent.clone());
+            while let Some(window) = ancestor {
+                if Rc::downgrade(&window).ptr_eq(&self.window_adapter_weak) {
+                    return;
+                }
+                ancestor = WindowInner::from_pub(window.window()).parent_window();
+            }
+        }
+        self.parent_window.replace(parent.map(Rc::downgrade));
+        self.notify_parent_window();
+    }
+
+    /// Returns the window set with [`Self::set_parent_window`], if it's still alive.
+    pub fn parent_window(&self) -> Option<Rc<dyn WindowAdapter>> {
+        self.parent_window.borrow().as_ref().and_then(|w| w.upgrade())
+    }
+
+    /// Sets whether the window blocks the input of its parent window while it's shown.
+    pub fn set_modal(&self, modal: bool) {
+        if self.modal.replace(modal) != modal {
+            self.notify_parent_window();
+        }
+    }
+
+    /// Returns true if the window blocks the input of its parent window while it's shown.
+    pub fn is_modal(&self) -> bool {
+        self.modal.get()
+    }
+
+    fn notify_parent_window(&self) {
+        if let Some(x) = self.window_adapter().internal(crate::InternalToken) {
+            x.set_parent_window(self.parent_window(), self.modal.get());
+        }
+    }
+
+    /// Returns the shown modal child window that blocks the input of this window, if any
+    fn modal_child_window(&self) -> Option<Rc<dyn WindowAdapter>> {
+        self.try_ctx()?.0.window_registry.modal_child_of(&self.window_adapter_weak)
+    }
+
+    /// Returns the context, or None if the platform isn't initialized yet, in which case no
+    /// window is shown
+    fn try_ctx(&self) -> Option<&crate::SlintContext> {
+        crate::context::GLOBAL_CONTEXT.with(|ctx| ctx.get().is_some()).then(|| &*self.ctx)
+    }
+
     /// returns the color theme used
     pub fn color_scheme(&self) -> ColorScheme {
         self.window_adapter()
@@ -963,16 +1419,62 @@ pub fn color_scheme(&self) -> ColorScheme {
             .map_or(ColorScheme::Unknown, |x| x.color_scheme())
     }
 
-    /// Show a popup at the given position relative to the item
+    /// Returns the interaction mode that the widgets adapt to
+    pub fn interaction_mode(&self) -> InteractionMode {
+        self.pinned_fields.as_ref().project_ref().interaction_mode.get()
+    }
+
+    /// Forces the interaction mode, or lets the input events determine it again when `mode` is None
+    pub fn set_interaction_mode(&self, mode: Option<InteractionMode>) {
+        self.forced_interaction_mode.set(mode);
+        if let Some(mode) = mode {
+            self.pinned_fields.interaction_mode.set(mode);
+        }
+    }
+
+    /// Called by the backends with the mode matching the device that produced an input event,
+    /// so that the widgets adapt when the user switches between a touch screen and a mouse.
+    pub fn input_device_used(&self, mode: InteractionMode) {
+        if self.forced_interaction_mode.get().is_none() {
+            self.pinned_fields.interaction_mode.set(mode);
+        }
+        self.update_software_cursor(|cursor| cursor.set_touch(mode == InteractionMode::Touch));
+    }
+
+    /// Sets the cursor that is drawn on top of the window contents at the mouse position, or
+    /// stops drawing a cursor with None.
+    pub fn set_software_cursor(&self, cursor: Option<software_cursor::SoftwareCursor>) {
+        self.update_software_cursor(|state| state.set_cursor(cursor));
+    }
+
+    /// Called by the renderers after rendering the components, to draw the software cursor
+    /// on top of them.
+    pub fn draw_software_cursor(&self, renderer: &mut dyn crate::item_rendering::ItemRenderer) {
+        self.software_cursor.draw(renderer);
+    }
+
+    fn update_software_cursor(
+        &self,
+        change: impl FnOnce(&software_cursor::SoftwareCursorState) -> Option<LogicalRect>,
+    ) {
+        let Some(dirty_rect) = change(&self.software_cursor) else { return };
+        let Some(window_adapter) = self.window_adapter_weak.upgrade() else { return };
+        window_adapter.renderer().mark_dirty_region(dirty_rect.into());
+        window_adapter.request_redraw();
+    }
+
+    /// Show a popup at the given position relative to the item, or along an edge of the item as
+    /// set in the options
     pub fn show_popup(
         &self,
         popup_componentrc: &ItemTreeRc,
         position: Point,
-        close_on_click: bool,
+        options: popup::PopupOptions,
         parent_item: &ItemRc,
     ) {
-        let position = parent_item.map_to_window(
-            parent_item.geometry().origin + LogicalPoint::from_untyped(position).to_vector(),
+        let anchor = LogicalRect::new(
+            parent_item.map_to_window(parent_item.geometry().origin),
+            parent_item.geometry().size,
         );
         let popup_component = ItemTreeRc::borrow_pin(popup_componentrc);
         let popup_root = popup_component.as_ref().get_item_ref(0);
@@ -1000,6 +1502,17 @@ pub fn show_popup(
         h = h.max(LogicalLength::new(layout_info_v.min)).min(LogicalLength::new(layout_info_v.max));
 
         let size = crate::lengths::LogicalSize::from_lengths(w, h);
+        let clip = LogicalRect::new(
+            LogicalPoint::new(0.0 as crate::Coord, 0.0 as crate::Coord),
+            self.window_adapter().size().to_logical(self.scale_factor()).to_euclid(),
+        );
+        let rect = popup::anchored_rect(
+            anchor,
+            LogicalPoint::from_untyped(position),
+            size,
+            &options,
+            &Some(clip),
+        );
 
         if let Some(window_item) = ItemRef::downcast_pin(popup_root) {
             let width_property =
@@ -1013,17 +1526,10 @@ pub fn show_popup(
         let location = match self
             .window_adapter()
             .internal(crate::InternalToken)
-            .and_then(|x| x.create_popup(LogicalRect::new(position, size)))
+            .and_then(|x| x.create_popup(rect))
         {
             None => {
-                let clip = LogicalRect::new(
-                    LogicalPoint::new(0.0 as crate::Coord, 0.0 as crate::Coord),
-                    self.window_adapter().size().to_logical(self.scale_factor()).to_euclid(),
-                );
-                let rect = popup::place_popup(
-                    popup::Placement::Fixed(LogicalRect::new(position, size)),
-                    &Some(clip),
-                );
+                let rect = popup::place_popup(popup::Placement::Fixed(rect), &Some(clip));
                 self.window_adapter().request_redraw();
                 PopupWindowLocation::ChildWindow(rect.origin)
             }
@@ -1036,7 +1542,8 @@ pub fn show_popup(
         self.active_popup.replace(Some(PopupWindow {
             location,
             component: popup_componentrc.clone(),
-            close_on_click,
+            close_policy: options.close_policy,
+            close_on_escape: options.close_on_escape,
         }));
     }
 
@@ -1068,7 +1575,10 @@ pub fn close_popup(&self) {
 
     /// Returns true if the currently active popup is configured to close on click. None if there is no active popup.
     pub fn close_popup_on_click(&self) -> bool {
-        self.active_popup.borrow().as_ref().map_or(false, |popup| popup.close_on_click)
+        self.active_popup
+            .borrow()
+            .as_ref()
+            .map_or(false, |popup| popup.close_policy == PopupClosePolicy::CloseOnClick)
     }
 
     /// Returns the scale factor set on the window, as provided by the windowing system.
@@ -1132,6 +1642,32 @@ pub fn request_close(&self) -> bool {
         }
     }
 
+    /// Sets the frame_presented callback. The callback will be run after each frame put on the screen.
+    pub fn on_frame_presented(&self, mut callback: impl FnMut(FramePresentedInfo) + 'static) {
+        self.frame_presented.set_handler(move |info| callback(*info));
+    }
+
+    /// Called by the backend when a frame was presented on the screen. Runs the frame_presented callback.
+    pub fn frame_presented(&self) {
+        let frame_number = self.presented_frame_count.get() + 1;
+        self.presented_frame_count.set(frame_number);
+        let timestamp =
+            core::time::Duration::from_millis(crate::animations::Instant::now().as_millis());
+        let input_latency =
+            self.input_latency.frame_presented(crate::animations::Instant::duration_since_start());
+        self.frame_presented.call(&FramePresentedInfo { frame_number, timestamp, input_latency });
+    }
+
+    /// Returns the input latency statistics of this window
+    pub fn input_latency_statistics(&self) -> InputLatencyStatistics {
+        self.input_latency.statistics.get()
+    }
+
+    /// Clears the input latency statistics of this window
+    pub fn reset_input_latency_statistics(&self) {
+        self.input_latency.statistics.take();
+    }
+
     /// Returns if the window is currently maximized
     pub fn is_fullscreen(&self) -> bool {
         self.fullscreen.get()
@@ -1165,6 +1701,19 @@ pub fn set_minimized(&self, minimized: bool) {
         self.update_window_properties()
     }
 
+    /// Returns if the window is placed above all other windows, because of the `always-on-top`
+    /// property of the window item or [`Self::set_always_on_top`]
+    pub fn is_always_on_top(&self) -> bool {
+        self.always_on_top.get()
+            || self.window_item().is_some_and(|w| w.as_pin_ref().always_on_top())
+    }
+
+    /// Places the window above all other windows, in addition to the `always-on-top` property
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        self.always_on_top.set(always_on_top);
+        self.update_window_properties()
+    }
+
     /// Returns the upgraded window adapter
     pub fn window_adapter(&self) -> Rc<dyn WindowAdapter> {
         self.window_adapter_weak.upgrade().unwrap()
@@ -1325,18 +1874,29 @@ pub enum GraphicsAPI {
 
     /// Show a popup.
     #[no_mangle]
+    #[allow(clippy::too_many_arguments)]
     pub unsafe extern "C" fn slint_windowrc_show_popup(
         handle: *const WindowAdapterRcOpaque,
         popup: &ItemTreeRc,
         position: crate::graphics::Point,
-        close_on_click: bool,
+        close_policy: PopupClosePolicy,
+        close_on_escape: bool,
+        anchor_edge: crate::items::PopupAnchorEdge,
+        anchor_offset: Coord,
+        anchor_flip: bool,
         parent_item: &ItemRc,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
         WindowInner::from_pub(window_adapter.window()).show_popup(
             popup,
             position,
-            close_on_click,
+            popup::PopupOptions {
+                close_policy,
+                close_on_escape,
+                anchor_edge,
+                anchor_offset,
+                anchor_flip,
+            },
             parent_item,
         );
     }
@@ -1515,13 +2075,47 @@ fn call(&self) -> CloseRequestResponse {
             .map_or(ColorScheme::Unknown, |x| x.color_scheme())
     }
 
-    /// Return the default-font-size property of the WindowItem
+    /// Return the interaction mode that the widgets adapt to
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_interaction_mode(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> InteractionMode {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).interaction_mode()
+    }
+
+    /// Return the default-font-size property of the WindowItem, multiplied by the text scale factor
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_default_font_size(
         handle: *const WindowAdapterRcOpaque,
     ) -> f32 {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        window_adapter.window().0.window_item().unwrap().as_pin_ref().default_font_size().get()
+        window_adapter
+            .window()
+            .0
+            .window_item()
+            .unwrap()
+            .as_pin_ref()
+            .resolved_default_font_size()
+            .get()
+    }
+
+    /// Return the width property of the WindowItem
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_window_item_width(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> f32 {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().0.window_item().unwrap().as_pin_ref().width().get() as _
+    }
+
+    /// Return the height property of the WindowItem
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_window_item_height(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> f32 {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().0.window_item().unwrap().as_pin_ref().height().get() as _
     }
 
     /// Dispatch a key pressed or release event
@@ -3,7 +3,36 @@
 
 //! Pupup window handling helpers
 
+use crate::items::{PopupAnchorEdge, PopupClosePolicy};
 use crate::lengths::{LogicalPoint, LogicalRect, LogicalSize};
+use crate::Coord;
+
+/// The options of a `PopupWindow`, set with its properties
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PopupOptions {
+    /// When the popup closes by itself
+    pub close_policy: PopupClosePolicy,
+    /// If true, the popup closes when the escape key isn't handled by the focused element
+    pub close_on_escape: bool,
+    /// The edge of the parent item along which the popup is placed
+    pub anchor_edge: PopupAnchorEdge,
+    /// The distance between the popup and the edge of the parent item
+    pub anchor_offset: Coord,
+    /// If true, the popup is placed at the opposite edge when it doesn't fit at the anchor edge
+    pub anchor_flip: bool,
+}
+
+impl Default for PopupOptions {
+    fn default() -> Self {
+        Self {
+            close_policy: PopupClosePolicy::CloseOnClick,
+            close_on_escape: true,
+            anchor_edge: PopupAnchorEdge::None,
+            anchor_offset: 0 as _,
+            anchor_flip: true,
+        }
+    }
+}
 
 /// A collection of data that might influence the palcement of a `Popup`.
 pub enum Placement {
@@ -11,6 +40,71 @@ pub enum Placement {
     Fixed(LogicalRect),
 }
 
+/// Returns the rectangle of a popup of the given size, placed according to `options` next to
+/// the `anchor` rectangle of its parent item.
+/// `position` is the position of the popup relative to the anchor, it's used for the coordinate
+/// along the anchor edge, or for both coordinates when there's no anchor edge.
+/// When the popup doesn't fit in the `clip_region` at the anchor edge, but at the opposite edge, it
+/// is flipped if the options allow it.
+pub fn anchored_rect(
+    anchor: LogicalRect,
+    position: LogicalPoint,
+    size: LogicalSize,
+    options: &PopupOptions,
+    clip_region: &Option<LogicalRect>,
+) -> LogicalRect {
+    let offset = options.anchor_offset;
+    let rect_at = |edge| {
+        let origin = match edge {
+            PopupAnchorEdge::Top => LogicalPoint::new(
+                anchor.min_x() + position.x,
+                anchor.min_y() - offset - size.height,
+            ),
+            PopupAnchorEdge::Bottom => {
+                LogicalPoint::new(anchor.min_x() + position.x, anchor.max_y() + offset)
+            }
+            PopupAnchorEdge::Left => {
+                LogicalPoint::new(anchor.min_x() - offset - size.width, anchor.min_y() + position.y)
+            }
+            PopupAnchorEdge::Right => {
+                LogicalPoint::new(anchor.max_x() + offset, anchor.min_y() + position.y)
+            }
+            PopupAnchorEdge::None => anchor.origin + position.to_vector(),
+        };
+        LogicalRect::new(origin, size)
+    };
+    // Whether the rect doesn't extend beyond the clip region on the side of the given edge
+    let fits = |rect: &LogicalRect, edge| {
+        let Some(clip) = clip_region else { return true };
+        match edge {
+            PopupAnchorEdge::Top => rect.min_y() >= clip.min_y(),
+            PopupAnchorEdge::Bottom => rect.max_y() <= clip.max_y(),
+            PopupAnchorEdge::Left => rect.min_x() >= clip.min_x(),
+            PopupAnchorEdge::Right => rect.max_x() <= clip.max_x(),
+            PopupAnchorEdge::None => true,
+        }
+    };
+
+    let edge = options.anchor_edge;
+    let rect = rect_at(edge);
+    if !options.anchor_flip || fits(&rect, edge) {
+        return rect;
+    }
+    let opposite_edge = match edge {
+        PopupAnchorEdge::Top => PopupAnchorEdge::Bottom,
+        PopupAnchorEdge::Bottom => PopupAnchorEdge::Top,
+        PopupAnchorEdge::Left => PopupAnchorEdge::Right,
+        PopupAnchorEdge::Right => PopupAnchorEdge::Left,
+        PopupAnchorEdge::None => PopupAnchorEdge::None,
+    };
+    let flipped = rect_at(opposite_edge);
+    if fits(&flipped, opposite_edge) {
+        flipped
+    } else {
+        rect
+    }
+}
+
 /// Find a placement for the `Popup`, using the provided `Placement`.
 /// When a `clip_region` is provided, then the `Popup` will stay within those bounds.
 /// The `clip_region` typically is the window or the screen the window is on.
@@ -331,3 +425,35 @@ fn test_place_popup_fixed_clipped() {
         }
     }
 }
+
+#[test]
+fn test_anchored_rect() {
+    let anchor = r(100, 100, 50, 20);
+    let size = LogicalSize::new(80., 40.);
+    let clip = Some(r(0, 0, 300, 200));
+    let options = |anchor_edge, anchor_flip| PopupOptions {
+        anchor_edge,
+        anchor_offset: 5.,
+        anchor_flip,
+        ..Default::default()
+    };
+    let position = LogicalPoint::new(10., 10.);
+
+    let rect = |edge, flip, clip: &Option<LogicalRect>| {
+        anchored_rect(anchor, position, size, &options(edge, flip), clip)
+    };
+    assert_eq!(rect(PopupAnchorEdge::None, true, &clip), r(110, 110, 80, 40));
+    assert_eq!(rect(PopupAnchorEdge::Bottom, true, &clip), r(110, 125, 80, 40));
+    assert_eq!(rect(PopupAnchorEdge::Top, true, &clip), r(110, 55, 80, 40));
+    assert_eq!(rect(PopupAnchorEdge::Left, true, &clip), r(15, 110, 80, 40));
+    assert_eq!(rect(PopupAnchorEdge::Right, true, &clip), r(155, 110, 80, 40));
+
+    // Flipped when it doesn't fit at the anchor edge, but at the opposite one
+    let small_clip = Some(r(0, 0, 200, 160));
+    assert_eq!(rect(PopupAnchorEdge::Bottom, true, &small_clip), r(110, 55, 80, 40));
+    assert_eq!(rect(PopupAnchorEdge::Bottom, false, &small_clip), r(110, 125, 80, 40));
+    assert_eq!(rect(PopupAnchorEdge::Right, true, &small_clip), r(15, 110, 80, 40));
+    let tiny_clip = Some(r(60, 60, 100, 100));
+    assert_eq!(rect(PopupAnchorEdge::Bottom, true, &tiny_clip), r(110, 125, 80, 40));
+    assert_eq!(rect(PopupAnchorEdge::Bottom, true, &None), r(110, 125, 80, 40));
+}
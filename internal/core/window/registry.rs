@@ -0,0 +1,61 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The registry of the windows that are shown, with their parent and modal relationships
+
+use super::{WindowAdapter, WindowInner};
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+/// Keeps track of the visible windows of a [`crate::SlintContext`], so that the windows that
+/// have a parent can be found from that parent.
+#[derive(Default)]
+pub(crate) struct WindowRegistry {
+    windows: RefCell<Vec<Weak<dyn WindowAdapter>>>,
+}
+
+impl WindowRegistry {
+    /// Adds the window to the registry when it's shown
+    pub(crate) fn register(&self, window_adapter: &Rc<dyn WindowAdapter>) {
+        let mut windows = self.windows.borrow_mut();
+        windows.retain(|w| w.strong_count() > 0);
+        let weak = Rc::downgrade(window_adapter);
+        if !windows.iter().any(|w| w.ptr_eq(&weak)) {
+            windows.push(weak);
+        }
+    }
+
+    /// Removes the window from the registry when it's hidden
+    pub(crate) fn unregister(&self, window_adapter: &Rc<dyn WindowAdapter>) {
+        let weak = Rc::downgrade(window_adapter);
+        self.windows.borrow_mut().retain(|w| w.strong_count() > 0 && !w.ptr_eq(&weak));
+    }
+
+    /// Returns the visible windows whose parent is `parent`
+    pub(crate) fn children_of(
+        &self,
+        parent: &Weak<dyn WindowAdapter>,
+    ) -> Vec<Rc<dyn WindowAdapter>> {
+        // Collect first, because hiding a child window unregisters it
+        let windows: Vec<_> = self.windows.borrow().iter().filter_map(|w| w.upgrade()).collect();
+        windows
+            .into_iter()
+            .filter(|w| {
+                WindowInner::from_pub(w.window())
+                    .parent_window
+                    .borrow()
+                    .as_ref()
+                    .is_some_and(|p| p.ptr_eq(parent))
+            })
+            .collect()
+    }
+
+    /// Returns the visible modal window that blocks the input of `parent`, if any
+    pub(crate) fn modal_child_of(
+        &self,
+        parent: &Weak<dyn WindowAdapter>,
+    ) -> Option<Rc<dyn WindowAdapter>> {
+        self.children_of(parent).into_iter().find(|w| WindowInner::from_pub(w.window()).is_modal())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Mouse cursor drawn by the renderer on platforms that don't have a system cursor
+
+use crate::api::LogicalPosition;
+use crate::graphics::Image;
+use crate::item_rendering::ItemRenderer;
+use crate::lengths::{logical_point_from_api, LogicalPoint, LogicalRect, LogicalSize};
+use core::cell::{Cell, RefCell};
+
+/// The image of the mouse cursor that Slint draws on top of the window contents on platforms
+/// that don't provide a mouse cursor, see [`Window::set_software_cursor()`](crate::api::Window::set_software_cursor).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SoftwareCursor {
+    /// The image of the cursor, drawn at its size in logical pixels.
+    pub image: Image,
+    /// The point of the image, relative to its top left corner, that is at the mouse position.
+    pub hotspot: LogicalPosition,
+}
+
+/// The state of the software cursor of a window
+#[derive(Default)]
+pub(crate) struct SoftwareCursorState {
+    cursor: RefCell<Option<SoftwareCursor>>,
+    /// The position of the mouse, or None when the cursor is hidden
+    position: Cell<Option<LogicalPoint>>,
+    /// Set when the last input came from a touch screen, which hides the cursor
+    touch: Cell<bool>,
+}
+
+impl SoftwareCursorState {
+    /// The area covered by the cursor, if it is visible
+    fn rect(&self) -> Option<LogicalRect> {
+        let position = self.position.get()?;
+        let cursor = self.cursor.borrow();
+        let cursor = cursor.as_ref()?;
+        Some(LogicalRect::new(
+            position - logical_point_from_api(cursor.hotspot).to_vector(),
+            LogicalSize::from_untyped(cursor.image.size().cast()),
+        ))
+    }
+
+    /// Applies the change and returns the area that needs to be redrawn because of it
+    fn update(&self, change: impl FnOnce(&Self)) -> Option<LogicalRect> {
+        let old_rect = self.rect();
+        change(self);
+        let new_rect = self.rect();
+        match (old_rect, new_rect) {
+            (old, new) if old == new => None,
+            (Some(old), Some(new)) => Some(old.union(&new)),
+            (old, new) => old.or(new),
+        }
+    }
+
+    pub(crate) fn set_cursor(&self, cursor: Option<SoftwareCursor>) -> Option<LogicalRect> {
+        self.update(|state| *state.cursor.borrow_mut() = cursor)
+    }
+
+    pub(crate) fn set_touch(&self, touch: bool) -> Option<LogicalRect> {
+        self.update(|state| {
+            state.touch.set(touch);
+            if touch {
+                state.position.set(None);
+            }
+        })
+    }
+
+    pub(crate) fn set_position(&self, position: Option<LogicalPoint>) -> Option<LogicalRect> {
+        if self.touch.get() {
+            return None;
+        }
+        self.update(|state| state.position.set(position))
+    }
+
+    /// Draws the cursor, this must be done after everything else
+    pub(crate) fn draw(&self, renderer: &mut dyn ItemRenderer) {
+        let Some(rect) = self.rect() else { return };
+        let Some(image) = self.cursor.borrow().as_ref().map(|cursor| cursor.image.clone()) else {
+            return;
+        };
+        renderer.save_state();
+        renderer.translate(rect.origin.to_vector());
+        renderer.draw_image_direct(image);
+        renderer.restore_state();
+    }
+}
@@ -19,8 +19,10 @@
 #[doc(inline)]
 pub use i_slint_compiler::diagnostics::{Diagnostic, DiagnosticLevel};
 
+pub use i_slint_core::animations::{Animation, EasingCurve};
 pub use i_slint_core::api::*;
 // keep in sync with api/rs/slint/lib.rs
+pub use i_slint_core::graphics::{validate_path_commands, PathCommandsError};
 pub use i_slint_core::graphics::{
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
@@ -702,6 +704,30 @@ pub fn library_paths(&self) -> &HashMap<String, PathBuf> {
         &self.config.library_paths
     }
 
+    /// Sets the flags that are set for the `@cfg(flag)` conditions of the `.slint` files.
+    pub fn set_cfg_flags(&mut self, cfg_flags: Vec<String>) {
+        self.config.cfg_flags = cfg_flags.into_iter().collect();
+    }
+
+    /// Returns the flags that are set for the `@cfg(flag)` conditions.
+    pub fn cfg_flags(&self) -> impl Iterator<Item = &str> + '_ {
+        self.config.cfg_flags.iter().map(String::as_str)
+    }
+
+    /// Sets the pairs of a base directory and a variant directory that overlays it.
+    ///
+    /// A file of the variant directory replaces the file with the same relative path in the base
+    /// directory. A replaced `.slint` file still provides the exports that the variant file
+    /// doesn't define. The later pairs take precedence over the earlier ones.
+    pub fn set_variant_paths(&mut self, variant_paths: Vec<(PathBuf, PathBuf)>) {
+        self.config.variant_paths = variant_paths;
+    }
+
+    /// Returns the variant paths the compiler is currently configured with.
+    pub fn variant_paths(&self) -> &[(PathBuf, PathBuf)] {
+        &self.config.variant_paths
+    }
+
     /// Sets the style to be used for widgets.
     ///
     /// Use the "material" style as widget style when compiling:
@@ -1206,6 +1232,43 @@ pub fn get_property(&self, name: &str) -> Result<Value, GetPropertyError> {
 
     /// Set the value for a public property of this component.
     pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyError> {
+        self.set_property_impl(name, value, None)
+    }
+
+    /// Animate a public property of this component from its current value to the given value,
+    /// with the given animation instead of the one declared for the property in the `.slint` file.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Animation, Compiler, Value, ComponentHandle};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         in-out property <float> level;
+    ///     }
+    /// "#;
+    /// let result = spin_on::spin_on(
+    ///     Compiler::default().build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// let animation = Animation::new(std::time::Duration::from_millis(200));
+    /// instance.set_property_animated("level", Value::from(10.), animation).unwrap();
+    /// ```
+    pub fn set_property_animated(
+        &self,
+        name: &str,
+        value: Value,
+        animation: Animation,
+    ) -> Result<(), SetPropertyError> {
+        self.set_property_impl(name, value, Some(animation.into()))
+    }
+
+    fn set_property_impl(
+        &self,
+        name: &str,
+        value: Value,
+        animation: Option<i_slint_core::items::PropertyAnimation>,
+    ) -> Result<(), SetPropertyError> {
         let name = normalize_identifier(name);
         generativity::make_guard!(guard);
         let comp = self.inner.unerase(guard);
@@ -1222,7 +1285,7 @@ pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyEr
             return Err(SetPropertyError::AccessDenied);
         }
 
-        d.set_property(comp.borrow(), &name, value)
+        d.set_property_animated(comp.borrow(), &name, value, animation)
     }
 
     /// Set a handler for the callback with the given name. A callback with that
@@ -1424,6 +1487,93 @@ pub fn invoke_global(
         }
     }
 
+    /// Implements the properties and callbacks of a global singleton used by this component
+    /// in native code, with the getters, setters, and handlers of `implementation`.
+    ///
+    /// The getter of a property is called when the property is read, and again whenever a
+    /// property that it reads changes. Call [`Self::mark_global_property_dirty()`] after the
+    /// value that it returns changed for another reason. The setter is called instead of storing
+    /// the value when the property is assigned, from the `.slint` code or with
+    /// [`Self::set_global_property()`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, GlobalImplementation, Value};
+    /// use std::{cell::Cell, rc::Rc};
+    /// let code = r#"
+    ///     export global Counter {
+    ///         in-out property <int> value;
+    ///         callback reset();
+    ///     }
+    ///     export component MyWin inherits Window {
+    ///         out property <string> label: "Count: " + Counter.value;
+    ///         public function increment() { Counter.value += 1; }
+    ///     }
+    /// "#;
+    /// let result = spin_on::spin_on(
+    ///     Compiler::default().build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    ///
+    /// let count = Rc::new(Cell::new(41));
+    /// let (get, set, reset) = (count.clone(), count.clone(), count.clone());
+    /// instance.set_global_implementation("Counter", GlobalImplementation::new()
+    ///     .with_property("value",
+    ///         move || Value::Number(get.get() as f64),
+    ///         move |value| set.set(f64::try_from(value).unwrap() as i32))
+    ///     .with_callback("reset", move |_| { reset.set(0); Value::Void })).unwrap();
+    ///
+    /// assert_eq!(instance.get_property("label").unwrap(), Value::String("Count: 41".into()));
+    /// instance.invoke("increment", &[]).unwrap();
+    /// assert_eq!(count.get(), 42);
+    /// assert_eq!(instance.get_property("label").unwrap(), Value::String("Count: 42".into()));
+    ///
+    /// instance.invoke_global("Counter", "reset", &[]).unwrap();
+    /// instance.mark_global_property_dirty("Counter", "value").unwrap();
+    /// assert_eq!(instance.get_property("label").unwrap(), Value::String("Count: 0".into()));
+    /// ```
+    pub fn set_global_implementation(
+        &self,
+        global: &str,
+        implementation: GlobalImplementation,
+    ) -> Result<(), SetGlobalImplementationError> {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let g = comp
+            .description()
+            .get_global(comp.borrow(), &normalize_identifier(global))
+            .map_err(|()| SetGlobalImplementationError::NoSuchGlobal)?;
+        for (name, property) in implementation.properties {
+            g.as_ref()
+                .set_native_property(&normalize_identifier(&name), property)
+                .map_err(|()| SetGlobalImplementationError::NoSuchProperty)?;
+        }
+        for (name, handler) in implementation.callbacks {
+            g.as_ref()
+                .set_callback_handler(&normalize_identifier(&name), handler)
+                .map_err(|()| SetGlobalImplementationError::NoSuchCallback)?;
+        }
+        Ok(())
+    }
+
+    /// Calls the getter of a property of a global singleton that is implemented with
+    /// [`Self::set_global_implementation()`] again, after the value that it returns changed.
+    pub fn mark_global_property_dirty(
+        &self,
+        global: &str,
+        property: &str,
+    ) -> Result<(), GetPropertyError> {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        comp.description()
+            .get_global(comp.borrow(), &normalize_identifier(global))
+            .map_err(|()| GetPropertyError::NoSuchProperty)?
+            .as_ref()
+            .mark_native_property_dirty(&normalize_identifier(property))
+            .map_err(|()| GetPropertyError::NoSuchProperty)
+    }
+
     /// Find all positions of the components which are pointed by a given source location.
     ///
     /// WARNING: this is not part of the public API
@@ -1514,6 +1664,67 @@ fn from(value: ComponentInstance) -> Self {
     }
 }
 
+/// The native implementation of the properties and callbacks of a global singleton, for
+/// [`ComponentInstance::set_global_implementation()`].
+///
+/// The properties and callbacks that aren't implemented keep the behavior of the `.slint` code.
+#[derive(Default)]
+pub struct GlobalImplementation {
+    properties: Vec<(String, crate::global_component::NativeProperty)>,
+    callbacks: Vec<(String, Box<dyn Fn(&[Value]) -> Value>)>,
+}
+
+impl GlobalImplementation {
+    /// Creates an implementation that doesn't implement anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Implements the property `name` with a getter, which returns its value, and a setter,
+    /// which is called when the property is assigned.
+    pub fn with_property(
+        mut self,
+        name: &str,
+        getter: impl Fn() -> Value + 'static,
+        setter: impl Fn(Value) + 'static,
+    ) -> Self {
+        self.properties.push((
+            name.into(),
+            crate::global_component::NativeProperty {
+                getter: Rc::new(getter),
+                setter: Rc::new(setter),
+            },
+        ));
+        self
+    }
+
+    /// Implements the callback `name` with the `handler`, like
+    /// [`ComponentInstance::set_global_callback()`].
+    pub fn with_callback(
+        mut self,
+        name: &str,
+        handler: impl Fn(&[Value]) -> Value + 'static,
+    ) -> Self {
+        self.callbacks.push((name.into(), Box::new(handler)));
+        self
+    }
+}
+
+/// Error returned by [`ComponentInstance::set_global_implementation`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SetGlobalImplementationError {
+    /// There is no global singleton with the given name
+    #[error("no such global")]
+    NoSuchGlobal,
+    /// The global has no property with the name of an implemented property
+    #[error("no such property")]
+    NoSuchProperty,
+    /// The global has no callback with the name of an implemented callback
+    #[error("no such callback")]
+    NoSuchCallback,
+}
+
 /// Error returned by [`ComponentInstance::get_property`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[non_exhaustive]
@@ -2025,7 +2236,8 @@ fn lang_type_to_value_type() {
             fields: BTreeMap::default(),
             name: None,
             node: None,
-            rust_attributes: None
+            rust_attributes: None,
+            functions: None
         }),
         ValueType::Struct
     );
@@ -0,0 +1,320 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Loading of updated `.slint` bundles at run-time, with validation and rollback.
+
+use crate::api::{
+    Compiler, ComponentDefinition, ComponentHandle, ComponentInstance, Diagnostic, DiagnosticLevel,
+    PlatformError,
+};
+use crate::dynamic_item_tree::{DynamicComponentVRc, WindowOptions};
+use i_slint_core::window::WindowInner;
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// The stage of an update started with [`BundleLoader::load()`], reported to the callback set
+/// with [`BundleLoader::on_progress()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BundleLoadStage {
+    /// The `.slint` files of the bundle are being compiled.
+    Compiling,
+    /// The component is being instantiated and validated.
+    Validating,
+    /// The new bundle was validated and is now shown.
+    Switched,
+    /// The new bundle failed, and the previous bundle is shown again.
+    RolledBack,
+}
+
+/// Error returned by [`BundleLoader::load()`] and [`BundleLoader::rollback()`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BundleError {
+    /// The bundle failed to compile.
+    #[error("the bundle failed to compile")]
+    CompileError(Vec<Diagnostic>),
+    /// The bundle doesn't export the component with the name passed to [`BundleLoader::new()`].
+    #[error("the bundle doesn't export the component {0}")]
+    NoSuchComponent(String),
+    /// The component of the bundle couldn't be instantiated.
+    #[error("the component couldn't be instantiated: {0}")]
+    InstantiationError(PlatformError),
+    /// The callback set with [`BundleLoader::on_instantiated()`] rejected the new instance.
+    #[error("the bundle was rejected: {0}")]
+    Rejected(String),
+    /// There is no previous bundle to roll back to.
+    #[error("there is no previous bundle to roll back to")]
+    NoPreviousBundle,
+}
+
+#[derive(Clone)]
+struct LoadedBundle {
+    path: PathBuf,
+    definition: ComponentDefinition,
+}
+
+#[derive(Default)]
+struct BundleLoaderState {
+    current: Option<(LoadedBundle, ComponentInstance)>,
+    previous: Option<LoadedBundle>,
+}
+
+struct BundleLoaderInner {
+    compiler: Compiler,
+    component_name: String,
+    state: RefCell<BundleLoaderState>,
+    progress_callback: RefCell<Option<Box<dyn Fn(BundleLoadStage)>>>,
+    instantiated_callback: RefCell<Option<Box<dyn Fn(&ComponentInstance) -> Result<(), String>>>>,
+}
+
+/// Loads the user interface of a device from `.slint` bundles that can be updated at run-time,
+/// for example after downloading a new version.
+///
+/// A new bundle is compiled while the current one keeps running. Then its component is
+/// instantiated in the window of the current one, and validated with the callback set with
+/// [`Self::on_instantiated()`], which is also where callbacks and properties of the new instance
+/// are set up. Only when everything succeeded, the new bundle replaces the current one. Otherwise,
+/// the current bundle is shown again, and the error is returned.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use slint_interpreter::{BundleLoader, Compiler, ComponentHandle};
+///
+/// let loader = BundleLoader::new(Compiler::default(), "MainWindow");
+/// loader.on_instantiated(|instance| {
+///     instance.set_callback("quit", |_| Default::default()).map_err(|e| e.to_string())
+/// });
+/// let instance = spin_on::spin_on(loader.load("/var/lib/ui/a/main.slint")).unwrap();
+/// instance.show().unwrap();
+///
+/// // Later, after downloading an update
+/// let loader = loader.clone();
+/// slint_interpreter::spawn_local(async move {
+///     if let Err(err) = loader.load("/var/lib/ui/b/main.slint").await {
+///         eprintln!("Update failed: {err}");
+///     }
+/// })
+/// .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct BundleLoader {
+    inner: Rc<BundleLoaderInner>,
+}
+
+impl BundleLoader {
+    /// Creates a new loader that compiles the bundles with `compiler` and shows the exported
+    /// component called `component_name`.
+    pub fn new(compiler: Compiler, component_name: impl Into<String>) -> Self {
+        Self {
+            inner: Rc::new(BundleLoaderInner {
+                compiler,
+                component_name: component_name.into(),
+                state: Default::default(),
+                progress_callback: Default::default(),
+                instantiated_callback: Default::default(),
+            }),
+        }
+    }
+
+    /// Sets the callback that is invoked when a bundle update reaches a new stage.
+    pub fn on_progress(&self, callback: impl Fn(BundleLoadStage) + 'static) {
+        *self.inner.progress_callback.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback that is invoked with every new instance of the component, before it
+    /// replaces the current one. Use it to set up the callbacks and properties of the instance,
+    /// and to check it. Returning an error rejects the bundle.
+    pub fn on_instantiated(
+        &self,
+        callback: impl Fn(&ComponentInstance) -> Result<(), String> + 'static,
+    ) {
+        *self.inner.instantiated_callback.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Returns the instance of the component of the current bundle, if any was loaded.
+    pub fn instance(&self) -> Option<ComponentInstance> {
+        self.inner.state.borrow().current.as_ref().map(|(_, instance)| instance.clone_strong())
+    }
+
+    /// Returns the path of the current bundle, if any was loaded.
+    pub fn path(&self) -> Option<PathBuf> {
+        self.inner.state.borrow().current.as_ref().map(|(bundle, _)| bundle.path.clone())
+    }
+
+    /// Compiles the bundle with the main `.slint` file at `path`, and switches over to it when it
+    /// could be validated. The first loaded bundle creates a new window, which the following
+    /// bundles reuse.
+    ///
+    /// On error, the current bundle keeps being shown.
+    ///
+    /// This function is `async` but in practice, this is only asynchronous if
+    /// [`Compiler::set_file_loader`] was called and its future is actually asynchronous.
+    pub async fn load(&self, path: impl AsRef<Path>) -> Result<ComponentInstance, BundleError> {
+        let path = path.as_ref();
+        self.report_progress(BundleLoadStage::Compiling);
+        let result = self.inner.compiler.build_from_path(path).await;
+        if result.has_errors() {
+            return Err(BundleError::CompileError(
+                result.diagnostics().filter(|d| d.level() == DiagnosticLevel::Error).collect(),
+            ));
+        }
+        let definition = result
+            .component(&self.inner.component_name)
+            .ok_or_else(|| BundleError::NoSuchComponent(self.inner.component_name.clone()))?;
+
+        self.report_progress(BundleLoadStage::Validating);
+        match self.switch_to(LoadedBundle { path: path.into(), definition }) {
+            Ok(instance) => {
+                self.report_progress(BundleLoadStage::Switched);
+                Ok(instance)
+            }
+            Err(err) => {
+                if self.inner.state.borrow().current.is_some() {
+                    self.report_progress(BundleLoadStage::RolledBack);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// Switches back to the bundle that was shown before the current one. The current bundle
+    /// becomes the previous one, so that calling this function again switches back to it.
+    pub fn rollback(&self) -> Result<ComponentInstance, BundleError> {
+        let previous =
+            self.inner.state.borrow_mut().previous.take().ok_or(BundleError::NoPreviousBundle)?;
+        let result = self.switch_to(previous.clone());
+        if result.is_err() {
+            self.inner.state.borrow_mut().previous = Some(previous);
+        }
+        result
+    }
+
+    fn report_progress(&self, stage: BundleLoadStage) {
+        if let Some(callback) = self.inner.progress_callback.borrow().as_ref() {
+            callback(stage);
+        }
+    }
+
+    /// Instantiates the component of `bundle` in the window of the current bundle, and makes it
+    /// the current bundle if it passes the validation. Otherwise the current instance is put
+    /// back into the window.
+    fn switch_to(&self, bundle: LoadedBundle) -> Result<ComponentInstance, BundleError> {
+        let current_instance =
+            self.inner.state.borrow().current.as_ref().map(|(_, instance)| instance.clone_strong());
+
+        let instance = match &current_instance {
+            Some(current_instance) => {
+                generativity::make_guard!(guard);
+                let window_adapter =
+                    WindowInner::from_pub(current_instance.window()).window_adapter();
+                bundle
+                    .definition
+                    .inner
+                    .unerase(guard)
+                    .clone()
+                    .create(WindowOptions::UseExistingWindow(window_adapter))
+                    .map(ComponentInstance::from_inner)
+            }
+            None => bundle.definition.create(),
+        }
+        .map_err(BundleError::InstantiationError)?;
+
+        let validation = match self.inner.instantiated_callback.borrow().as_ref() {
+            Some(callback) => callback(&instance).map_err(BundleError::Rejected),
+            None => Ok(()),
+        };
+
+        if let Err(err) = validation {
+            if let Some(current_instance) = current_instance {
+                let current_item_tree: DynamicComponentVRc = current_instance.into();
+                WindowInner::from_pub(instance.window())
+                    .set_component(&vtable::VRc::into_dyn(current_item_tree));
+            }
+            return Err(err);
+        }
+
+        if let Some(current_instance) = &current_instance {
+            // Transfer the strong reference that the visible window keeps to its component
+            if current_instance.window().is_visible() {
+                instance.show().map_err(BundleError::InstantiationError)?;
+            }
+        }
+
+        let mut state = self.inner.state.borrow_mut();
+        let previous = state.current.replace((bundle, instance.clone_strong()));
+        state.previous = previous.map(|(bundle, _)| bundle);
+        Ok(instance)
+    }
+}
+
+#[test]
+fn bundle_loader_rollback() {
+    i_slint_backend_testing::init_no_event_loop();
+    use crate::Value;
+
+    let dir = std::env::temp_dir().join(format!("slint-bundle-test-{}", std::process::id()));
+    let write_bundle = |name: &str, source: &str| {
+        let path = dir.join(name).join("main.slint");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, source).unwrap();
+        path
+    };
+    let a = write_bundle(
+        "a",
+        "export component MainWindow inherits Window { out property <int> version: 1; }",
+    );
+    let b = write_bundle(
+        "b",
+        "export component MainWindow inherits Window { out property <int> version: 2; }",
+    );
+    let broken = write_bundle("broken", "export component MainWindow inherits Window { foo }");
+    let rejected = write_bundle(
+        "rejected",
+        "export component MainWindow inherits Window { out property <int> version: -1; }",
+    );
+
+    let loader = BundleLoader::new(Compiler::default(), "MainWindow");
+    loader.on_instantiated(|instance| match instance.get_property("version") {
+        Ok(Value::Number(version)) if version > 0. => Ok(()),
+        _ => Err("invalid version".into()),
+    });
+    let stages = Rc::new(RefCell::new(Vec::new()));
+    loader.on_progress({
+        let stages = stages.clone();
+        move |stage| stages.borrow_mut().push(stage)
+    });
+
+    let version = || loader.instance().unwrap().get_property("version").unwrap();
+
+    let first = spin_on::spin_on(loader.load(&a)).unwrap();
+    assert_eq!(version(), Value::Number(1.));
+    let window_adapter = WindowInner::from_pub(first.window()).window_adapter();
+
+    let second = spin_on::spin_on(loader.load(&b)).unwrap();
+    assert_eq!(version(), Value::Number(2.));
+    assert!(Rc::ptr_eq(&window_adapter, &WindowInner::from_pub(second.window()).window_adapter()));
+
+    stages.borrow_mut().clear();
+    assert!(matches!(spin_on::spin_on(loader.load(&broken)), Err(BundleError::CompileError(_))));
+    assert_eq!(*stages.borrow(), [BundleLoadStage::Compiling]);
+    assert_eq!(loader.path().unwrap(), b);
+
+    stages.borrow_mut().clear();
+    assert!(matches!(spin_on::spin_on(loader.load(&rejected)), Err(BundleError::Rejected(_))));
+    assert_eq!(
+        *stages.borrow(),
+        [BundleLoadStage::Compiling, BundleLoadStage::Validating, BundleLoadStage::RolledBack]
+    );
+    assert_eq!(version(), Value::Number(2.));
+
+    loader.rollback().unwrap();
+    assert_eq!(version(), Value::Number(1.));
+    assert_eq!(loader.path().unwrap(), a);
+    loader.rollback().unwrap();
+    assert_eq!(version(), Value::Number(2.));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -110,6 +110,8 @@ pub(crate) struct RepeaterWithinItemTree<'par_id, 'sub_id> {
     pub(crate) item_tree_to_repeat: Rc<ItemTreeDescription<'sub_id>>,
     /// The model
     pub(crate) model: Expression,
+    /// Keep the instance when the model becomes empty (`retain if`)
+    retain_instances: bool,
     /// Offset of the `Repeater`
     offset: FieldOffset<Instance<'par_id>, Repeater<ErasedItemTreeBox>>,
 }
@@ -280,6 +282,14 @@ fn drop(&mut self) {
         generativity::make_guard!(guard);
         let unerase = self.unerase(guard);
         let instance_ref = unerase.borrow_instance();
+        for destructor_code in
+            instance_ref.description.original.init_code.borrow().destructor_code.iter()
+        {
+            eval::eval_expression(
+                destructor_code,
+                &mut eval::EvalLocalContext::from_component_instance(instance_ref),
+            );
+        }
         // Do not walk out of our ItemTree here:
         if let Some(window_adapter) = instance_ref.maybe_window_adapter() {
             i_slint_core::item_tree::unregister_item_tree(
@@ -301,6 +311,9 @@ pub(crate) struct ComponentExtraData {
     pub(crate) embedding_position: OnceCell<(ItemTreeWeak, u32)>,
     #[cfg(target_arch = "wasm32")]
     pub(crate) canvas_id: OnceCell<String>,
+    /// The properties of a global that are implemented in native code
+    pub(crate) native_properties:
+        core::cell::RefCell<HashMap<String, crate::global_component::NativeProperty>>,
 }
 
 struct ErasedRepeaterWithinComponent<'id>(RepeaterWithinItemTree<'id, 'static>);
@@ -520,6 +533,18 @@ pub fn set_property(
         component: ItemTreeRefPin,
         name: &str,
         value: Value,
+    ) -> Result<(), crate::api::SetPropertyError> {
+        self.set_property_animated(component, name, value, None)
+    }
+
+    /// Same as [`Self::set_property`], but animates the property to the value with the given
+    /// animation instead of the one declared for the property, if any.
+    pub fn set_property_animated(
+        &self,
+        component: ItemTreeRefPin,
+        name: &str,
+        value: Value,
+        animation: Option<PropertyAnimation>,
     ) -> Result<(), crate::api::SetPropertyError> {
         if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
             panic!("mismatch instance and vtable");
@@ -534,9 +559,9 @@ pub fn set_property(
             .get(name)
             .and_then(|d| d.is_alias.as_ref())
         {
-            eval::store_property(c, &alias.element(), alias.name(), value)
+            eval::store_property_animated(c, &alias.element(), alias.name(), value, animation)
         } else {
-            eval::store_property(c, &self.original.root_element, name, value)
+            eval::store_property_animated(c, &self.original.root_element, name, value, animation)
         }
     }
 
@@ -567,6 +592,22 @@ pub fn set_binding(
         Ok(())
     }
 
+    /// Binds the property to the getter of its native implementation, which is evaluated again
+    /// the next time that the property is read.
+    ///
+    /// Returns an error if the property isn't implemented in native code.
+    pub(crate) fn bind_native_property(
+        &self,
+        component: ItemTreeRefPin,
+        name: &str,
+    ) -> Result<(), ()> {
+        generativity::make_guard!(guard);
+        let c = unsafe { InstanceRef::from_pin_ref(component, guard) };
+        let extra_data = self.extra_data_offset.apply(c.as_ref());
+        let getter = extra_data.native_properties.borrow().get(name).ok_or(())?.getter.clone();
+        self.set_binding(component, name, Box::new(move || getter()))
+    }
+
     /// Return the value of a property
     ///
     /// Returns an error if the component is not an instance corresponding to this ItemTreeDescription,
@@ -776,6 +817,24 @@ fn ensure_repeater_updated<'id>(
             get_prop(&lv.listview_width),
             assume_property_logical_length(get_property_ptr(&lv.listview_height, instance_ref)),
         );
+        if let Some(sections) = &lv.sections {
+            let section_rows = match eval::load_property(
+                instance_ref,
+                &sections.section_rows.element(),
+                sections.section_rows.name(),
+            ) {
+                Ok(Value::Model(model)) => model,
+                _ => i_slint_core::model::ModelRc::default(),
+            };
+            let (current_section, next_section_y) =
+                repeater.listview_section(&section_rows, get_prop(&lv.viewport_y));
+            for (nr, value) in [
+                (&sections.current_section, Value::Number(current_section as f64)),
+                (&sections.next_section_y, Value::Number(next_section_y.get() as f64)),
+            ] {
+                eval::store_property(instance_ref, &nr.element(), nr.name(), value).unwrap();
+            }
+        }
     } else {
         repeater.ensure_updated(init);
     }
@@ -934,13 +993,20 @@ pub(crate) fn generate_item_tree<'id>(
                 rtti_for::<TouchArea>(),
                 rtti_for::<FocusScope>(),
                 rtti_for::<SwipeGestureHandler>(),
+                rtti_for::<PinchGestureHandler>(),
+                rtti_for::<DragArea>(),
+                rtti_for::<DropArea>(),
+                rtti_for::<WindowMoveArea>(),
+                rtti_for::<WindowResizeArea>(),
                 rtti_for::<Path>(),
                 rtti_for::<Flickable>(),
                 rtti_for::<WindowItem>(),
                 rtti_for::<TextInput>(),
                 rtti_for::<Clip>(),
                 rtti_for::<BoxShadow>(),
+                rtti_for::<Shader>(),
                 rtti_for::<Rotate>(),
+                rtti_for::<TransformItem>(),
                 rtti_for::<Opacity>(),
                 rtti_for::<Layer>(),
             ]
@@ -1001,6 +1067,7 @@ fn push_repeated_item(
                     item_tree_to_repeat: generate_item_tree(base_component, None, guard),
                     offset: self.type_builder.add_field_type::<Repeater<ErasedItemTreeBox>>(),
                     model: item.repeated.as_ref().unwrap().model.clone(),
+                    retain_instances: item.repeated.as_ref().unwrap().is_retained,
                 }
                 .into(),
             );
@@ -1143,7 +1210,8 @@ fn property_info_for_type(
             Type::Struct { name: Some(name), .. } if name.ends_with("::StateInfo") => {
                 property_info::<i_slint_core::properties::StateInfo>()
             }
-            Type::Struct { .. } => property_info::<Value>(),
+            // The compiler only allows the animations of the structs whose fields are animated
+            Type::Struct { .. } => animated_property_info::<Value>(),
             Type::Array(_) => property_info::<Value>(),
             Type::Easing => property_info::<i_slint_core::animations::EasingCurve>(),
             Type::Percent => property_info::<f32>(),
@@ -1298,6 +1366,30 @@ macro_rules! match_enum_type {
     Rc::new(t)
 }
 
+/// The animated struct properties interpolate each field. The compiler only allows animating the
+/// structs whose fields are numbers, lengths, colors, or brushes, or such structs.
+impl InterpolatedPropertyValue for Value {
+    fn interpolate(&self, target_value: &Self, t: f32) -> Self {
+        match (self, target_value) {
+            (Value::Number(from), Value::Number(to)) => {
+                Value::Number(from + t as f64 * (to - from))
+            }
+            (Value::Brush(from), Value::Brush(to)) => Value::Brush(from.interpolate(to, t)),
+            (Value::Struct(from), Value::Struct(to)) => Value::Struct(
+                to.iter()
+                    .map(|(name, to)| {
+                        let value = from
+                            .get_field(name)
+                            .map_or_else(|| to.clone(), |from| from.interpolate(to, t));
+                        (name.to_string(), value)
+                    })
+                    .collect(),
+            ),
+            _ => target_value.clone(),
+        }
+    }
+}
+
 pub fn animation_for_property(
     component: InstanceRef,
     animation: &Option<i_slint_compiler::object_tree::PropertyAnimation>,
@@ -1616,6 +1708,9 @@ pub fn instantiate(
             let m = model_binding_closure();
             i_slint_core::model::ModelRc::new(crate::value_model::ValueModel::new(m))
         });
+        if rep_in_comp.retain_instances {
+            repeater.set_retain_instances(true);
+        }
     }
 
     update_timers(instance_ref);
@@ -2280,7 +2375,7 @@ pub fn toplevel_instance<'id2>(
 pub fn show_popup(
     popup: &object_tree::PopupWindow,
     pos_getter: impl FnOnce(InstanceRef<'_, '_>) -> i_slint_core::graphics::Point,
-    close_on_click: bool,
+    options: i_slint_core::window::popup::PopupOptions,
     parent_comp: ErasedItemTreeBoxWeak,
     parent_window_adapter: WindowAdapterRc,
     parent_item: &ItemRc,
@@ -2306,7 +2401,7 @@ pub fn show_popup(
     WindowInner::from_pub(parent_window_adapter.window()).show_popup(
         &vtable::VRc::into_dyn(inst),
         pos,
-        close_on_click,
+        options,
         parent_item,
     );
 }
@@ -4,14 +4,17 @@
 use crate::api::{SetPropertyError, Struct, Value};
 use crate::dynamic_item_tree::InstanceRef;
 use core::pin::Pin;
-use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
+use corelib::graphics::{
+    ConicGradientBrush, GradientSpread, GradientStop, LinearGradientBrush, PathElement,
+    RadialGradientBrush,
+};
 use corelib::items::{ColorScheme, ItemRef, PropertyAnimation};
-use corelib::model::{Model, ModelExt, ModelRc, VecModel};
+use corelib::model::{FilterModel, Model, ModelExt, ModelRc, SortModel, VecModel};
 use corelib::rtti::AnimatedBindingKind;
 use corelib::{Brush, Color, PathData, SharedString, SharedVector};
 use i_slint_compiler::expression_tree::{
-    BuiltinFunction, EasingCurve, Expression, MinMaxOp, Path as ExprPath,
-    PathElement as ExprPathElement,
+    BuiltinFunction, EasingCurve, Expression, GradientSpread as ExprGradientSpread, MinMaxOp,
+    ModelAdapterKind, Path as ExprPath, PathElement as ExprPathElement,
 };
 use i_slint_compiler::langtype::Type;
 use i_slint_compiler::object_tree::ElementRc;
@@ -33,6 +36,7 @@ fn set_binding(
         binding: Box<dyn Fn() -> Value>,
         animation: AnimatedBindingKind,
     );
+    fn is_animating(&self, item: Pin<ItemRef>) -> bool;
     fn offset(&self) -> usize;
 
     /// Safety: Property2 must be a (pinned) pointer to a `Property<T>`
@@ -62,6 +66,9 @@ fn set_binding(
     ) {
         (*self).set_binding(ItemRef::downcast_pin(item).unwrap(), binding, animation).unwrap();
     }
+    fn is_animating(&self, item: Pin<ItemRef>) -> bool {
+        (*self).is_animating(ItemRef::downcast_pin(item).unwrap())
+    }
     fn offset(&self) -> usize {
         (*self).offset()
     }
@@ -192,6 +199,9 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 }
                 (Value::Number(n), Type::Color) => Color::from_argb_encoded(n as u32).into(),
                 (Value::Brush(brush), Type::Color) => brush.color().into(),
+                (Value::Model(model), Type::LayoutCache) => Value::LayoutCache(
+                    model.iter().map(|v| f32::try_from(v).unwrap_or_default()).collect(),
+                ),
                 (v, _) => v,
             }
         }
@@ -341,20 +351,37 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::Steps(count) => corelib::animations::EasingCurve::Steps(*count),
+            EasingCurve::Sampled(samples) => corelib::animations::EasingCurve::sampled(samples),
+            EasingCurve::Spring(stiffness, damping, mass) => {
+                corelib::animations::EasingCurve::Spring {
+                    stiffness: *stiffness,
+                    damping: *damping,
+                    mass: *mass,
+                }
+            }
         }),
-        Expression::LinearGradient{angle, stops} => {
+        Expression::LinearGradient{angle, stops, spread} => {
             let angle = eval_expression(angle, local_context);
             Value::Brush(Brush::LinearGradient(LinearGradientBrush::new(angle.try_into().unwrap(), stops.iter().map(|(color, stop)| {
                 let color = eval_expression(color, local_context).try_into().unwrap();
                 let position = eval_expression(stop, local_context).try_into().unwrap();
                 GradientStop{ color, position }
-            }))))
+            })).with_spread(gradient_spread(*spread))))
         }
-        Expression::RadialGradient{stops} => {
+        Expression::RadialGradient{stops, spread} => {
             Value::Brush(Brush::RadialGradient(RadialGradientBrush::new_circle(stops.iter().map(|(color, stop)| {
                 let color = eval_expression(color, local_context).try_into().unwrap();
                 let position = eval_expression(stop, local_context).try_into().unwrap();
                 GradientStop{ color, position }
+            })).with_spread(gradient_spread(*spread))))
+        }
+        Expression::ConicGradient{from_angle, stops} => {
+            let from_angle = eval_expression(from_angle, local_context);
+            Value::Brush(Brush::ConicGradient(ConicGradientBrush::new(from_angle.try_into().unwrap(), stops.iter().map(|(color, stop)| {
+                let color = eval_expression(color, local_context).try_into().unwrap();
+                let position = eval_expression(stop, local_context).try_into().unwrap();
+                GradientStop{ color, position }
             }))))
         }
         Expression::EnumerationValue(value) => {
@@ -394,7 +421,100 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 MinMaxOp::Max => Value::Number(lhs.max(rhs)),
             }
         }
-        Expression::EmptyComponentFactory => Value::ComponentFactory(Default::default())
+        Expression::EmptyComponentFactory => Value::ComponentFactory(Default::default()),
+        Expression::ModelAdapter { kind, model, captures, variable, expression } => {
+            eval_model_adapter(*kind, model, captures, variable, expression, local_context)
+        }
+    }
+}
+
+fn gradient_spread(spread: ExprGradientSpread) -> GradientSpread {
+    match spread {
+        ExprGradientSpread::Pad => GradientSpread::Pad,
+        ExprGradientSpread::Repeat => GradientSpread::Repeat,
+        ExprGradientSpread::Reflect => GradientSpread::Reflect,
+    }
+}
+
+fn eval_model_adapter(
+    kind: ModelAdapterKind,
+    model: &Expression,
+    captures: &[(String, Expression)],
+    variable: &str,
+    expression: &Expression,
+    local_context: &mut EvalLocalContext,
+) -> Value {
+    let Value::Model(model) = eval_expression(model, local_context) else {
+        return Value::Model(Default::default());
+    };
+    let mut captures = captures
+        .iter()
+        .map(|(name, e)| (name.clone(), eval_expression(e, local_context)))
+        .collect::<HashMap<_, _>>();
+
+    let instance = match local_context.component_instance {
+        ComponentInstance::InstanceRef(instance) => instance,
+        ComponentInstance::GlobalComponent(_) => {
+            // The model can't keep a reference to the global, so adapt a snapshot of the rows
+            model.model_tracker().track_row_count_changes();
+            let mut rows = (0..model.row_count())
+                .filter_map(|row| model.row_data_tracked(row))
+                .map(|row| {
+                    captures.insert(variable.into(), row.clone());
+                    let mut row_context = EvalLocalContext {
+                        local_variables: captures.clone(),
+                        function_arguments: vec![],
+                        component_instance: local_context.component_instance,
+                        return_value: None,
+                    };
+                    (eval_expression(expression, &mut row_context), row)
+                })
+                .collect::<Vec<_>>();
+            match kind {
+                ModelAdapterKind::Filter => rows.retain(|(value, _)| *value == Value::Bool(true)),
+                ModelAdapterKind::SortBy => rows.sort_by(|(a, _), (b, _)| compare_values(a, b)),
+            }
+            let rows = rows.into_iter().map(|(_, row)| row).collect::<Vec<_>>();
+            return Value::Model(ModelRc::new(VecModel::from(rows)));
+        }
+    };
+
+    let self_weak = instance.self_weak().get().unwrap().clone();
+    let expression = expression.clone();
+    let variable = variable.to_string();
+    let eval_row = move |row: &Value| -> Value {
+        let Some(component) = self_weak.upgrade() else { return Value::Void };
+        generativity::make_guard!(guard);
+        let component = component.unerase(guard);
+        let mut local_variables = captures.clone();
+        local_variables.insert(variable.clone(), row.clone());
+        let mut row_context = EvalLocalContext {
+            local_variables,
+            function_arguments: vec![],
+            component_instance: ComponentInstance::InstanceRef(component.borrow_instance()),
+            return_value: None,
+        };
+        eval_expression(&expression, &mut row_context)
+    };
+    Value::Model(match kind {
+        ModelAdapterKind::Filter => {
+            ModelRc::new(FilterModel::new(model, move |row| eval_row(row) == Value::Bool(true)))
+        }
+        ModelAdapterKind::SortBy => ModelRc::new(SortModel::new(model, move |a, b| {
+            compare_values(&eval_row(a), &eval_row(b))
+        })),
+    })
+}
+
+/// Compares the keys of `sort-by()`, which are numbers, strings or bools
+fn compare_values(a: &Value, b: &Value) -> core::cmp::Ordering {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => {
+            a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal)
+        }
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => core::cmp::Ordering::Equal,
     }
 }
 
@@ -412,10 +532,28 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
+        BuiltinFunction::GetWindowWidth | BuiltinFunction::GetWindowHeight => {
+            match local_context.component_instance {
+                ComponentInstance::InstanceRef(component) => {
+                    Value::Number(component.access_window(|window| {
+                        let window_item = window.window_item().unwrap();
+                        let window_item = window_item.as_pin_ref();
+                        if matches!(f, BuiltinFunction::GetWindowWidth) {
+                            window_item.width().get()
+                        } else {
+                            window_item.height().get()
+                        }
+                    }) as _)
+                }
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot get the window from a global component")
+                }
+            }
+        }
         BuiltinFunction::GetWindowDefaultFontSize => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => {
                 Value::Number(component.access_window(|window| {
-                    window.window_item().unwrap().as_pin_ref().default_font_size().get()
+                    window.window_item().unwrap().as_pin_ref().resolved_default_font_size().get()
                 }) as _)
             }
             ComponentInstance::GlobalComponent(_) => {
@@ -618,7 +756,23 @@ fn call_builtin_function(
                             y.try_into().unwrap(),
                         )
                     },
-                    popup.close_on_click,
+                    i_slint_core::window::popup::PopupOptions {
+                        close_policy: Value::EnumerationValue(
+                            popup.close_policy.enumeration.name.clone(),
+                            popup.close_policy.to_string(),
+                        )
+                        .try_into()
+                        .unwrap(),
+                        close_on_escape: popup.close_on_escape,
+                        anchor_edge: Value::EnumerationValue(
+                            popup.anchor_edge.enumeration.name.clone(),
+                            popup.anchor_edge.to_string(),
+                        )
+                        .try_into()
+                        .unwrap(),
+                        anchor_offset: popup.anchor_offset as _,
+                        anchor_flip: popup.anchor_flip,
+                    },
                     enclosing_component.self_weak().get().unwrap().clone(),
                     component.window_adapter(),
                     &parent_item,
@@ -693,6 +847,67 @@ fn call_builtin_function(
                 panic!("internal error: first argument to set-selection-offsets must be an element")
             }
         }
+        BuiltinFunction::MoveSelectionHandle => {
+            if arguments.len() != 4 {
+                panic!("internal error: incorrect argument count to move-selection-handle")
+            }
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot invoke member function on item from a global component")
+                }
+            };
+            if let Expression::ElementReference(element) = &arguments[0] {
+                generativity::make_guard!(guard);
+
+                let elem = element.upgrade().unwrap();
+                let enclosing_component = enclosing_component_for_element(&elem, component, guard);
+                let description = enclosing_component.description;
+                let item_info = &description.items[elem.borrow().id.as_str()];
+                let item_ref =
+                    unsafe { item_info.item_from_item_tree(enclosing_component.as_ptr()) };
+
+                let item_comp = enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+                let item_rc = corelib::items::ItemRc::new(
+                    vtable::VRc::into_dyn(item_comp),
+                    item_info.item_index(),
+                );
+
+                let window_adapter = component.window_adapter();
+
+                if let Some(textinput) =
+                    ItemRef::downcast_pin::<corelib::items::TextInput>(item_ref)
+                {
+                    let anchor: bool = eval_expression(&arguments[1], local_context)
+                        .try_into()
+                        .expect(
+                        "internal error: second argument to move-selection-handle must be a bool",
+                    );
+                    let x: f64 = eval_expression(&arguments[2], local_context).try_into().expect(
+                        "internal error: third argument to move-selection-handle must be a length",
+                    );
+                    let y: f64 = eval_expression(&arguments[3], local_context).try_into().expect(
+                        "internal error: fourth argument to move-selection-handle must be a length",
+                    );
+
+                    textinput.move_selection_handle(
+                        &window_adapter,
+                        &item_rc,
+                        anchor,
+                        corelib::lengths::LogicalPoint::new(x as _, y as _),
+                    );
+                } else {
+                    panic!(
+                        "internal error: member function called on element that doesn't have it: {}",
+                        elem.borrow().original_name()
+                    )
+                }
+
+                Value::Void
+            } else {
+                panic!("internal error: first argument to move-selection-handle must be an element")
+            }
+        }
         BuiltinFunction::ItemMemberFunction(name) => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to item member function call")
@@ -796,6 +1011,35 @@ fn call_builtin_function(
                 panic!("Argument not a string");
             }
         }
+        BuiltinFunction::RegexMatches => {
+            let t: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let p: SharedString = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Bool(corelib::regex::regex_matches(&t, &p))
+        }
+        BuiltinFunction::RegexReplace => {
+            let t: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let p: SharedString = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let r: SharedString = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::regex::regex_replace(&t, &p, &r))
+        }
+        BuiltinFunction::RegexCapture => {
+            let t: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let p: SharedString = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let g: i32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::regex::regex_capture(&t, &p, g))
+        }
+        BuiltinFunction::FormatFixed => {
+            let v: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let p: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::String(corelib::string::format_fixed(v, p))
+        }
+        BuiltinFunction::PadString => {
+            let t: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let w: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let f: SharedString = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let al: i32 = eval_expression(&arguments[3], local_context).try_into().unwrap();
+            Value::String(corelib::string::pad_string(&t, w, f.chars().next().unwrap_or(' '), al))
+        }
         BuiltinFunction::ColorRgbaStruct => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to ColorRGBAComponents")
@@ -934,6 +1178,24 @@ fn call_builtin_function(
                 panic!("First argument not an image");
             }
         }
+        BuiltinFunction::ImageFromUrl => {
+            let url: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            match corelib::graphics::load_image_from_url(&url) {
+                Some(image) => Value::Image(image),
+                None => eval_expression(&arguments[1], local_context),
+            }
+        }
+        BuiltinFunction::ImageUrlLoading => {
+            let url: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Bool(corelib::graphics::image_url_loading(&url))
+        }
+        BuiltinFunction::ImageUrlError => {
+            let url: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Bool(corelib::graphics::image_url_error(&url))
+        }
         BuiltinFunction::ArrayLength => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to ArrayLength")
@@ -977,6 +1239,35 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
+        BuiltinFunction::InteractionMode => match local_context.component_instance {
+            ComponentInstance::InstanceRef(component) => {
+                corelib::window::WindowInner::from_pub(component.window_adapter().window())
+                    .interaction_mode()
+                    .into()
+            }
+            ComponentInstance::GlobalComponent(_) => {
+                panic!("Cannot get the window from a global component")
+            }
+        },
+        BuiltinFunction::ThemeBrush => {
+            let name: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let default: Brush = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Brush(corelib::theme::theme_brush(&name, default))
+        }
+        BuiltinFunction::ThemeLength => {
+            let name: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let default: f64 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Number(corelib::theme::theme_length(&name, default as _) as f64)
+        }
+        BuiltinFunction::ThemeString => {
+            let name: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let default: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::String(corelib::theme::theme_string(&name, default))
+        }
         BuiltinFunction::MonthDayCount => {
             let m: u32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
             let y: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
@@ -1021,6 +1312,46 @@ fn call_builtin_function(
                     .unwrap_or_default(),
             ))
         }
+        BuiltinFunction::LocaleFormatNumber => {
+            let v: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let d: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::String(corelib::locale::format_number(v, d))
+        }
+        BuiltinFunction::LocaleParseNumber => {
+            let t: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(corelib::locale::parse_number(&t).unwrap_or_default())
+        }
+        BuiltinFunction::LocaleFormatDate => {
+            let d: u32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let m: u32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let y: i32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::locale::format_date(d, m, y))
+        }
+        BuiltinFunction::LocaleParseDate => {
+            let t: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let [d, m, y] = corelib::locale::parse_date(&t).unwrap_or([-1; 3]);
+            Value::Struct(
+                [("day", d), ("month", m), ("year", y)]
+                    .into_iter()
+                    .map(|(name, x)| (name.to_string(), Value::Number(x as f64)))
+                    .collect(),
+            )
+        }
+        BuiltinFunction::LocaleFormatTime => {
+            let h: u32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let m: u32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::String(corelib::locale::format_time(h, m))
+        }
+        BuiltinFunction::LocaleParseTime => {
+            let t: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let [h, m] = corelib::locale::parse_time(&t).unwrap_or([-1; 2]);
+            Value::Struct(
+                [("hour", h), ("minute", m)]
+                    .into_iter()
+                    .map(|(name, x)| (name.to_string(), Value::Number(x as f64)))
+                    .collect(),
+            )
+        }
         BuiltinFunction::TextInputFocused => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => {
                 Value::Bool(component.access_window(|window| window.text_input_focused()) as _)
@@ -1101,6 +1432,58 @@ fn call_builtin_function(
                 panic!("internal error: argument to SetFocusItem must be an element")
             }
         }
+        BuiltinFunction::ItemIsVisible => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ItemIsVisible")
+            }
+
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot access the visibility of an item from a global component")
+                }
+            };
+
+            if let Expression::ElementReference(item) = &arguments[0] {
+                generativity::make_guard!(guard);
+
+                let item = item.upgrade().unwrap();
+                let enclosing_component = enclosing_component_for_element(&item, component, guard);
+                let description = enclosing_component.description;
+
+                let item_info = &description.items[item.borrow().id.as_str()];
+
+                let item_comp = enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+
+                let item_rc = corelib::items::ItemRc::new(
+                    vtable::VRc::into_dyn(item_comp),
+                    item_info.item_index(),
+                );
+
+                item_rc.is_visible().into()
+            } else {
+                panic!("internal error: argument to ItemIsVisible must be an element")
+            }
+        }
+        BuiltinFunction::PropertyIsAnimating => {
+            if let [Expression::PropertyReference(nr)] = arguments {
+                property_is_animating_helper(
+                    local_context.component_instance,
+                    &nr.element(),
+                    nr.name(),
+                )
+                .into()
+            } else {
+                panic!("internal error: argument to PropertyIsAnimating must be a property")
+            }
+        }
+        BuiltinFunction::AssertionFailed => {
+            // Only report it, so that the previews keep running
+            let message: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            corelib::debug_log!("{}", message);
+            Value::Void
+        }
         BuiltinFunction::RegisterCustomFontByPath => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to RegisterCustomFontByPath")
@@ -1154,6 +1537,11 @@ fn from_index(&self, index: usize) -> Option<SharedString> {
             ))
         }
         BuiltinFunction::Use24HourFormat => Value::Bool(corelib::date_time::use_24_hour_format()),
+        BuiltinFunction::TranslationLanguageMatches => {
+            let locale: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Bool(corelib::translations::translation_language_matches(&locale))
+        }
         BuiltinFunction::UpdateTimers => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => {
                 crate::dynamic_item_tree::update_timers(component);
@@ -1335,11 +1723,56 @@ fn load_property_helper(
     }
 }
 
+/// Returns true if the property is running an animation
+fn property_is_animating_helper(
+    component_instance: ComponentInstance,
+    element: &ElementRc,
+    name: &str,
+) -> bool {
+    generativity::make_guard!(guard);
+    match enclosing_component_instance_for_element(element, component_instance, guard) {
+        ComponentInstance::InstanceRef(enclosing_component) => {
+            let element = element.borrow();
+            if element.id == element.enclosing_component.upgrade().unwrap().root_element.borrow().id
+            {
+                if let Some(x) = enclosing_component.description.custom_properties.get(name) {
+                    return unsafe {
+                        x.prop.is_animating(Pin::new_unchecked(
+                            &*enclosing_component.as_ptr().add(x.offset),
+                        ))
+                    };
+                }
+            };
+            let item_info = enclosing_component
+                .description
+                .items
+                .get(element.id.as_str())
+                .unwrap_or_else(|| panic!("Unknown element for {}.{}", element.id, name));
+            core::mem::drop(element);
+            let item = unsafe { item_info.item_from_item_tree(enclosing_component.as_ptr()) };
+            item_info.rtti.properties.get(name).map_or(false, |p| p.is_animating(item))
+        }
+        ComponentInstance::GlobalComponent(_) => false,
+    }
+}
+
 pub fn store_property(
     component_instance: InstanceRef,
     element: &ElementRc,
     name: &str,
     value: Value,
+) -> Result<(), SetPropertyError> {
+    store_property_animated(component_instance, element, name, value, None)
+}
+
+/// Same as [`store_property`], but animates the property to the value with the given animation
+/// instead of the one declared for the property, if any.
+pub fn store_property_animated(
+    component_instance: InstanceRef,
+    element: &ElementRc,
+    name: &str,
+    value: Value,
+    animation: Option<PropertyAnimation>,
 ) -> Result<(), SetPropertyError> {
     generativity::make_guard!(guard);
     match enclosing_component_instance_for_element(
@@ -1348,12 +1781,13 @@ pub fn store_property(
         guard,
     ) {
         ComponentInstance::InstanceRef(enclosing_component) => {
-            let maybe_animation = match element.borrow().bindings.get(name) {
-                Some(b) => crate::dynamic_item_tree::animation_for_property(
+            let maybe_animation = match (animation, element.borrow().bindings.get(name)) {
+                (Some(animation), _) => AnimatedBindingKind::Animation(animation),
+                (None, Some(b)) => crate::dynamic_item_tree::animation_for_property(
                     enclosing_component,
                     &b.borrow().animation,
                 ),
-                None => {
+                (None, None) => {
                     crate::dynamic_item_tree::animation_for_property(enclosing_component, &None)
                 }
             };
@@ -1374,6 +1808,22 @@ pub fn store_property(
                             return Err(SetPropertyError::WrongType);
                         }
                     }
+                    let native_setter = enclosing_component
+                        .description
+                        .extra_data_offset
+                        .apply(enclosing_component.as_ref())
+                        .native_properties
+                        .borrow()
+                        .get(name)
+                        .map(|property| property.setter.clone());
+                    if let Some(setter) = native_setter {
+                        setter(value);
+                        // The getter gives the new value
+                        return enclosing_component
+                            .description
+                            .bind_native_property(enclosing_component.borrow(), name)
+                            .map_err(|()| SetPropertyError::NoSuchProperty);
+                    }
                     unsafe {
                         let p = Pin::new_unchecked(&*enclosing_component.as_ptr().add(x.offset));
                         return x
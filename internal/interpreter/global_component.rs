@@ -135,6 +135,23 @@ fn set_property(
     fn get_property_ptr(self: Pin<&Self>, prop_name: &str) -> *const ();
 
     fn eval_function(self: Pin<&Self>, fn_name: &str, args: Vec<Value>) -> Result<Value, ()>;
+
+    /// Implements the property with the getter and the setter of `property`.
+    fn set_native_property(
+        self: Pin<&Self>,
+        prop_name: &str,
+        property: NativeProperty,
+    ) -> Result<(), ()>;
+
+    /// Calls the getter of the property that is implemented in native code again.
+    fn mark_native_property_dirty(self: Pin<&Self>, prop_name: &str) -> Result<(), ()>;
+}
+
+/// The getter and the setter of a property of a global that is implemented in native code
+#[derive(Clone)]
+pub struct NativeProperty {
+    pub getter: Rc<dyn Fn() -> Value>,
+    pub setter: Rc<dyn Fn(Value)>,
 }
 
 /// Instantiate the global singleton and store it in `globals`
@@ -250,6 +267,33 @@ fn eval_function(self: Pin<&Self>, fn_name: &str, args: Vec<Value>) -> Result<Va
         );
         Ok(result)
     }
+
+    fn set_native_property(
+        self: Pin<&Self>,
+        prop_name: &str,
+        property: NativeProperty,
+    ) -> Result<(), ()> {
+        generativity::make_guard!(guard);
+        let comp = self.0.unerase(guard);
+        let description = comp.description();
+        if !description.custom_properties.contains_key(prop_name) {
+            return Err(());
+        }
+        let instance = comp.borrow_instance();
+        description
+            .extra_data_offset
+            .apply(instance.as_ref())
+            .native_properties
+            .borrow_mut()
+            .insert(prop_name.into(), property);
+        description.bind_native_property(comp.borrow(), prop_name)
+    }
+
+    fn mark_native_property_dirty(self: Pin<&Self>, prop_name: &str) -> Result<(), ()> {
+        generativity::make_guard!(guard);
+        let comp = self.0.unerase(guard);
+        comp.description().bind_native_property(comp.borrow(), prop_name)
+    }
 }
 
 impl<T: rtti::BuiltinItem + 'static> GlobalComponent for T {
@@ -294,6 +338,18 @@ fn set_callback_handler(
     fn eval_function(self: Pin<&Self>, _fn_name: &str, _args: Vec<Value>) -> Result<Value, ()> {
         Err(())
     }
+
+    fn set_native_property(
+        self: Pin<&Self>,
+        _prop_name: &str,
+        _property: NativeProperty,
+    ) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn mark_native_property_dirty(self: Pin<&Self>, _prop_name: &str) -> Result<(), ()> {
+        Err(())
+    }
 }
 
 fn generate(component: &Rc<Component>) -> CompiledGlobal {
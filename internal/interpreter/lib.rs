@@ -77,6 +77,7 @@
 );
 
 mod api;
+mod bundle;
 mod dynamic_item_tree;
 mod dynamic_type;
 mod eval;
@@ -88,6 +89,7 @@
 
 #[doc(inline)]
 pub use api::*;
+pub use bundle::{BundleError, BundleLoadStage, BundleLoader};
 
 #[cfg(feature = "internal")]
 #[doc(hidden)]
@@ -31,6 +31,7 @@ struct FontCacheKey {
 pub struct Font {
     fonts: SharedVector<femtovg::FontId>,
     pixel_size: PhysicalLength,
+    line_height: Option<PhysicalLength>,
     text_context: TextContext,
 }
 
@@ -82,7 +83,12 @@ pub fn text_size(
                 width = measure.width().max(width);
             }
         }
-        euclid::size2(width, lines as f32 * font_metrics.height())
+        euclid::size2(width, lines as f32 * self.line_height(&font_metrics).get())
+    }
+
+    /// Returns the height of a line of text, given the metrics of the font from `measure_font()`
+    pub fn line_height(&self, font_metrics: &femtovg::FontMetrics) -> PhysicalLength {
+        self.line_height.unwrap_or(PhysicalLength::new(font_metrics.height()))
     }
 
     pub fn height(&self) -> PhysicalLength {
@@ -311,12 +317,19 @@ pub fn font(
         //);
 
         let fallbacks = if !matches!(coverage_result, GlyphCoverageCheckResult::Complete) {
-            self.font_fallbacks_for_request(
+            // The families registered by the application take precedence over the system ones
+            let mut fallbacks = font_request
+                .fallback_families()
+                .into_iter()
+                .filter(|family| self.is_known_family(family))
+                .collect::<Vec<_>>();
+            fallbacks.extend(self.font_fallbacks_for_request(
                 font_request.family.as_ref(),
                 pixel_size,
                 &primary_font,
                 reference_text,
-            )
+            ));
+            fallbacks
         } else {
             Vec::new()
         };
@@ -346,7 +359,8 @@ pub fn font(
             }))
             .collect::<SharedVector<_>>();
 
-        Font { fonts, text_context: self.text_context.clone(), pixel_size }
+        let line_height = font_request.line_height.map(|height| height * scale_factor);
+        Font { fonts, text_context: self.text_context.clone(), pixel_size, line_height }
     }
 
     #[cfg(target_os = "macos")]
@@ -598,12 +612,15 @@ pub(crate) fn layout_text_lines(
     let text_context = FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
     let font_metrics = text_context.measure_font(paint).unwrap();
     let font_height = PhysicalLength::new(font_metrics.height());
+    let line_height = font.line_height(&font_metrics);
+    // The glyphs are centered vertically in their line
+    let half_leading = (line_height - font_height) / 2.;
 
     let mut cursor_point: Option<PhysicalPoint> = None;
 
     let text_height = || {
         if single_line {
-            font_height
+            line_height
         } else {
             // Note: this is kind of doing twice the layout because text_size also does it
             let text_height = font
@@ -618,8 +635,8 @@ pub(crate) fn layout_text_lines(
                 // If the full text doesn't fit into max_height and eliding is
                 // enabled, calculate the height of the max number of lines that
                 // fit to ensure correct vertical alignment when elided.
-                let max_lines = (max_height.get() / font_height.get()).floor();
-                font_height * max_lines
+                let max_lines = (max_height.get() / line_height.get()).floor();
+                line_height * max_lines
             } else {
                 text_height
             }
@@ -637,7 +654,7 @@ pub(crate) fn layout_text_lines(
                     max_width - max_width.min(PhysicalLength::new(line_metrics.width()))
                 }
             };
-            let line_pos = PhysicalPoint::from_lengths(x, y);
+            let line_pos = PhysicalPoint::from_lengths(x, y + half_leading);
             layout_line(text_span, line_pos, start, line_metrics);
 
             if let Some(cursor_byte_offset) = cursor_byte_offset {
@@ -676,8 +693,8 @@ pub(crate) fn layout_text_lines(
     };
     let mut y = baseline_y;
     let mut start = 0;
-    'lines: while start < string.len() && y + font_height <= max_height {
-        if wrap && (!elide || y + font_height * 2. <= max_height) {
+    'lines: while start < string.len() && y + line_height <= max_height {
+        if wrap && (!elide || y + line_height * 2. <= max_height) {
             let max_line_index = string[start..].find('\n').map_or(string.len(), |i| i + 1 + start);
             let index = text_context
                 .break_text(max_width.get(), &string[start..max_line_index], paint)
@@ -690,7 +707,7 @@ pub(crate) fn layout_text_lines(
             let line = &string[start..index];
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
             process_line(line, y, start, &text_metrics);
-            y += font_height;
+            y += line_height;
             start = index;
         } else {
             let index = if single_line {
@@ -701,7 +718,7 @@ pub(crate) fn layout_text_lines(
             let line = &string[start..index];
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
             let elide_last_line =
-                elide && index < string.len() && y + font_height * 2. > max_height;
+                elide && index < string.len() && y + line_height * 2. > max_height;
             if text_metrics.width() > max_width.get() || elide_last_line {
                 let w = max_width
                     - if elide {
@@ -722,7 +739,7 @@ pub(crate) fn layout_text_lines(
                         } else {
                             process_line(txt, y, start, &text_metrics);
                         }
-                        y += font_height;
+                        y += line_height;
                         start = index;
                         continue 'lines;
                     }
@@ -730,13 +747,13 @@ pub(crate) fn layout_text_lines(
                 if elide_last_line {
                     let elided = format!("{}…", line.strip_suffix('\n').unwrap_or(line));
                     process_line(&elided, y, start, &text_metrics);
-                    y += font_height;
+                    y += line_height;
                     start = index;
                     continue 'lines;
                 }
             }
             process_line(line, y, start, &text_metrics);
-            y += font_height;
+            y += line_height;
             start = index;
         }
     }
@@ -748,7 +765,7 @@ pub(crate) fn layout_text_lines(
                 TextHorizontalAlignment::Center => max_size.width_length() / 2.,
                 TextHorizontalAlignment::Right => max_size.width_length(),
             };
-            PhysicalPoint::from_lengths(x, y)
+            PhysicalPoint::from_lengths(x, y + half_leading)
         })
     })
 }
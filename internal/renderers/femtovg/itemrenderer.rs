@@ -10,13 +10,13 @@
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self};
 use i_slint_core::graphics::rendering_metrics_collector::RenderingMetrics;
-use i_slint_core::graphics::{IntRect, Point, Size};
+use i_slint_core::graphics::{BorderEdges, GradientSpread, IntRect, Point, Size};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderBorderRectangle, RenderImage, RenderText,
 };
 use i_slint_core::items::{
     self, Clip, FillRule, ImageRendering, ImageTiling, ItemRc, Layer, Opacity, RenderingResult,
-    TextStrokeStyle,
+    TextCursorShape, TextStrokeStyle,
 };
 use i_slint_core::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
@@ -219,6 +219,11 @@ fn draw_border_rectangle(
             return;
         }
 
+        if let Some(edges) = rect.border_edges() {
+            self.draw_border_edges(rect, &edges, geometry);
+            return;
+        }
+
         let border_color = rect.border_color();
         let opaque_border = border_color.is_opaque();
         let mut border_width = if border_color.is_transparent() {
@@ -404,7 +409,8 @@ fn draw_text_input(
             )
         });
 
-        let visual_representation = text_input.visual_representation(None);
+        let mut visual_representation = text_input.visual_representation(None);
+        let cursor_character_end = visual_representation.cursor_character_end();
 
         let paint = match self.brush_to_paint(
             visual_representation.text_color,
@@ -429,7 +435,7 @@ fn draw_text_input(
 
         let mut canvas = self.canvas.borrow_mut();
         let font_height = font.height();
-        let text: SharedString = visual_representation.text.into();
+        let text: SharedString = core::mem::take(&mut visual_representation.text).into();
 
         let cursor_point = fonts::layout_text_lines(
             text.as_str(),
@@ -526,15 +532,20 @@ fn draw_text_input(
         );
 
         if let Some(cursor_point) = cursor_point {
-            let mut cursor_rect = femtovg::Path::new();
-            cursor_rect.rect(
-                cursor_point.x,
-                cursor_point.y,
-                (text_input.text_cursor_width() * self.scale_factor).get(),
-                font_height.get(),
+            let bar_rect = PhysicalRect::new(
+                cursor_point,
+                PhysicalSize::from_lengths(
+                    text_input.text_cursor_width() * self.scale_factor,
+                    font_height,
+                ),
             );
+            let character_width = cursor_character_end
+                .filter(|_| visual_representation.cursor_shape != TextCursorShape::Bar)
+                .and_then(|end| canvas.measure_text(0., 0., &text[cursor_pos..end], &paint).ok())
+                .map(|metrics| metrics.width());
+            let cursor_rect = visual_representation.cursor_shape_rect(bar_rect, character_width);
             canvas.fill_path(
-                &cursor_rect,
+                &rect_to_path(cursor_rect),
                 &femtovg::Paint::color(to_femtovg_color(&visual_representation.cursor_color)),
             );
         }
@@ -1068,6 +1079,22 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         *clip = LogicalRect::new(origin, (end - origin).into());
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        self.canvas.borrow_mut().scale(x_factor, y_factor);
+        let clip = &mut self.state.last_mut().unwrap().scissor;
+        if x_factor == 0. || y_factor == 0. {
+            // Nothing is visible
+            *clip = LogicalRect::default();
+            return;
+        }
+        // Map the clip to the scaled coordinate system, a negative factor mirrors it
+        let (a, b) = (
+            LogicalPoint::new(clip.min_x() / x_factor, clip.min_y() / y_factor),
+            LogicalPoint::new(clip.max_x() / x_factor, clip.max_y() / y_factor),
+        );
+        *clip = LogicalRect::from_points([a, b]);
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         let state = &mut self.state.last_mut().unwrap().global_alpha;
         *state *= opacity;
@@ -1507,6 +1534,53 @@ fn draw_image_impl(
         }
     }
 
+    /// Draws the background of a rectangle whose border has edges of different widths or colors,
+    /// and then each edge on top of it.
+    fn draw_border_edges(
+        &mut self,
+        rect: Pin<&dyn RenderBorderRectangle>,
+        edges: &BorderEdges,
+        geometry: PhysicalRect,
+    ) {
+        let radius = rect.border_radius() * self.scale_factor;
+        let background_path = rect_with_radius_to_path(geometry, radius);
+        let fill_paint = self.brush_to_paint(rect.background(), &background_path);
+
+        let polygons = i_slint_core::graphics::border_edge_polygons(
+            geometry.size,
+            radius,
+            edges.widths() * self.scale_factor,
+        );
+        let edge_paths: Vec<_> = edges
+            .to_array()
+            .into_iter()
+            .zip(polygons)
+            .filter_map(|(edge, polygons)| {
+                let mut path = femtovg::Path::new();
+                for polygon in polygons {
+                    let mut points = polygon.into_iter();
+                    let Some(first) = points.next() else { continue };
+                    path.move_to(first.x, first.y);
+                    for point in points {
+                        path.line_to(point.x, point.y);
+                    }
+                    path.close();
+                }
+                let mut paint = self.brush_to_paint(edge.color.clone(), &path)?;
+                paint.set_fill_rule(femtovg::FillRule::EvenOdd);
+                Some((path, paint))
+            })
+            .collect();
+
+        let mut canvas = self.canvas.borrow_mut();
+        if let Some(paint) = fill_paint {
+            canvas.fill_path(&background_path, &paint);
+        }
+        for (path, paint) in &edge_paths {
+            canvas.fill_path(path, paint);
+        }
+    }
+
     fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::Paint> {
         if brush.is_transparent() {
             return None;
@@ -1524,6 +1598,15 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                     [path_width, path_height].into(),
                 );
 
+                if gradient.spread() != GradientSpread::Pad {
+                    // femtovg doesn't repeat gradients, so the gradient is rendered into an image
+                    let line = end - start;
+                    let line = line / line.square_length().max(f32::EPSILON);
+                    return self.gradient_to_image_paint(path_width, path_height, |x, y| {
+                        gradient.color_at((x - start.x) * line.x + (y - start.y) * line.y)
+                    });
+                }
+
                 let stops =
                     gradient.stops().map(|stop| (stop.position, to_femtovg_color(&stop.color)));
                 femtovg::Paint::linear_gradient_stops(start.x, start.y, end.x, end.y, stops)
@@ -1534,6 +1617,15 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                 let path_width = path_bounds.width();
                 let path_height = path_bounds.height();
 
+                if gradient.spread() != GradientSpread::Pad {
+                    // femtovg doesn't repeat gradients, so the gradient is rendered into an image
+                    let radius = ((path_width + path_height) / 4.).max(f32::EPSILON);
+                    return self.gradient_to_image_paint(path_width, path_height, |x, y| {
+                        let (dx, dy) = (x - path_width / 2., y - path_height / 2.);
+                        gradient.color_at(dx.hypot(dy) / radius)
+                    });
+                }
+
                 let stops =
                     gradient.stops().map(|stop| (stop.position, to_femtovg_color(&stop.color)));
                 femtovg::Paint::radial_gradient_stops(
@@ -1544,10 +1636,54 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                     stops,
                 )
             }
+            Brush::ConicGradient(gradient) => {
+                // femtovg doesn't have conic gradients, so the gradient is rendered into an image
+                let path_bounds = path_bounding_box(&self.canvas, path);
+
+                let path_width = path_bounds.width();
+                let path_height = path_bounds.height();
+
+                return self.gradient_to_image_paint(path_width, path_height, |x, y| {
+                    let (dx, dy) = (x - path_width / 2., y - path_height / 2.);
+                    gradient.color_at_angle(dx.atan2(-dy).to_degrees())
+                });
+            }
             _ => return None,
         })
     }
 
+    /// Returns a paint with an image of the gradient for the gradients that femtovg can't draw,
+    /// where `color_at` returns the color at a point of the bounding box of the path
+    fn gradient_to_image_paint(
+        &self,
+        path_width: f32,
+        path_height: f32,
+        color_at: impl Fn(f32, f32) -> Color,
+    ) -> Option<femtovg::Paint> {
+        let width = path_width.ceil().max(1.) as usize;
+        let height = path_height.ceil().max(1.) as usize;
+
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let color = color_at(x as f32 + 0.5, y as f32 + 0.5);
+                rgb::RGBA8::new(color.red(), color.green(), color.blue(), color.alpha())
+            })
+            .collect::<Vec<_>>();
+        let image_id = self
+            .canvas
+            .borrow_mut()
+            .create_image(
+                imgref::Img::new(pixels.as_slice(), width, height),
+                femtovg::ImageFlags::empty(),
+            )
+            .ok()?;
+        self.textures_to_delete_after_flush
+            .borrow_mut()
+            .push(Texture::adopt(&self.canvas, image_id));
+        Some(femtovg::Paint::image(image_id, 0., 0., width as f32, height as f32, 0., 1.))
+    }
+
     fn current_render_target(&self) -> femtovg::RenderTarget {
         self.state.last().unwrap().current_render_target
     }
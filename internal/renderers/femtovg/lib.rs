@@ -281,6 +281,8 @@ fn internal_render_with_post_callback(
                     );
                 }
 
+                window_inner.draw_software_cursor(&mut item_renderer);
+
                 if let Some(cb) = post_render_cb.as_ref() {
                     cb(&mut item_renderer)
                 }
@@ -391,7 +393,10 @@ fn text_input_byte_offset_for_position(
         let paint = font.init_paint(text_input.letter_spacing() * scale_factor, Default::default());
         let text_context =
             crate::fonts::FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
-        let font_height = text_context.measure_font(&paint).unwrap().height();
+        let font_metrics = text_context.measure_font(&paint).unwrap();
+        let line_height = font.line_height(&font_metrics).get();
+        // The position of the glyphs in the line, which is centered
+        let half_leading = (line_height - font_metrics.height()) / 2.;
         crate::fonts::layout_text_lines(
             &visual_representation.text,
             &font,
@@ -403,7 +408,8 @@ fn text_input_byte_offset_for_position(
             None,
             &paint,
             |line_text, line_pos, start, metrics| {
-                if (line_pos.y..(line_pos.y + font_height)).contains(&pos.y) {
+                let line_y = line_pos.y - half_leading;
+                if (line_y..(line_y + line_height)).contains(&pos.y) {
                     let mut current_x = 0.;
                     for glyph in &metrics.glyphs {
                         if line_pos.x + current_x + glyph.advance_x / 2. >= pos.x {
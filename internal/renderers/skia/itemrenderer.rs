@@ -9,12 +9,13 @@
 use i_slint_core::graphics::boxshadowcache::BoxShadowCache;
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self, Vector2D};
-use i_slint_core::graphics::ApproxEq;
+use i_slint_core::graphics::{ApproxEq, BorderEdges, GradientSpread};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderImage, RenderText,
 };
 use i_slint_core::items::{
-    ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult, TextStrokeStyle,
+    ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult, TextCursorShape,
+    TextStrokeStyle,
 };
 use i_slint_core::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
@@ -39,6 +40,7 @@ pub struct SkiaItemRenderer<'a> {
     current_state: RenderState,
     image_cache: &'a ItemCache<Option<skia_safe::Image>>,
     path_cache: &'a ItemCache<Option<(Vector2D<f32, PhysicalPx>, skia_safe::Path)>>,
+    shader_cache: &'a ItemCache<Option<skia_safe::RuntimeEffect>>,
     box_shadow_cache: &'a mut SkiaBoxShadowCache,
 }
 
@@ -48,6 +50,7 @@ pub fn new(
         window: &'a i_slint_core::api::Window,
         image_cache: &'a ItemCache<Option<skia_safe::Image>>,
         path_cache: &'a ItemCache<Option<(Vector2D<f32, PhysicalPx>, skia_safe::Path)>>,
+        shader_cache: &'a ItemCache<Option<skia_safe::RuntimeEffect>>,
         box_shadow_cache: &'a mut SkiaBoxShadowCache,
     ) -> Self {
         Self {
@@ -58,6 +61,7 @@ pub fn new(
             current_state: RenderState { alpha: 1.0 },
             image_cache,
             path_cache,
+            shader_cache,
             box_shadow_cache,
         }
     }
@@ -72,6 +76,51 @@ fn default_paint(&self) -> Option<skia_safe::Paint> {
         }
     }
 
+    /// Draws the background of a rectangle whose border has edges of different widths or colors,
+    /// and then each edge on top of it.
+    fn draw_border_edges(
+        &mut self,
+        rect: Pin<&dyn i_slint_core::item_rendering::RenderBorderRectangle>,
+        edges: &BorderEdges,
+        geometry: PhysicalRect,
+    ) {
+        let radius = rect.border_radius() * self.scale_factor;
+        if let Some(mut fill_paint) = self.brush_to_paint(
+            rect.background(),
+            geometry.width_length(),
+            geometry.height_length(),
+        ) {
+            let background_rect = to_skia_rrect(&geometry, &radius);
+            if !background_rect.is_rect() {
+                fill_paint.set_anti_alias(true);
+            }
+            self.canvas.draw_rrect(background_rect, &fill_paint);
+        }
+
+        let polygons = i_slint_core::graphics::border_edge_polygons(
+            geometry.size,
+            radius,
+            edges.widths() * self.scale_factor,
+        );
+        for (edge, polygons) in edges.to_array().into_iter().zip(polygons) {
+            let Some(mut paint) = self.brush_to_paint(
+                edge.color.clone(),
+                geometry.width_length(),
+                geometry.height_length(),
+            ) else {
+                continue;
+            };
+            paint.set_anti_alias(true);
+            let mut path = skia_safe::Path::new();
+            path.set_fill_type(skia_safe::PathFillType::EvenOdd);
+            for polygon in polygons {
+                let points: Vec<_> = polygon.into_iter().map(to_skia_point).collect();
+                path.add_poly(&points, true);
+            }
+            self.canvas.draw_path(&path, &paint);
+        }
+    }
+
     fn brush_to_paint(
         &self,
         brush: Brush,
@@ -103,37 +152,97 @@ fn brush_to_shader(
                     g.angle(),
                     [width.get(), height.get()].into(),
                 );
-                let (colors, pos): (Vec<_>, Vec<_>) =
-                    g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
 
                 paint.set_dither(true);
 
-                skia_safe::gradient_shader::linear(
-                    (skia_safe::Point::new(start.x, start.y), skia_safe::Point::new(end.x, end.y)),
-                    skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
-                    Some(&*pos),
-                    TileMode::Clamp,
-                    skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
-                    &skia_safe::Matrix::new_identity(),
-                )
+                if g.spread() == GradientSpread::Pad {
+                    let (colors, pos): (Vec<_>, Vec<_>) =
+                        g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                    skia_safe::gradient_shader::linear(
+                        (
+                            skia_safe::Point::new(start.x, start.y),
+                            skia_safe::Point::new(end.x, end.y),
+                        ),
+                        skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                        Some(&*pos),
+                        TileMode::Clamp,
+                        skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                        &skia_safe::Matrix::new_identity(),
+                    )
+                } else {
+                    // Skia repeats the whole line, so it must go from the first to the last stop
+                    let (first, last, stops) =
+                        i_slint_core::graphics::normalize_gradient_stops(g.stops());
+                    let (colors, pos): (Vec<_>, Vec<_>) =
+                        stops.map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                    let (start, end) = (start.lerp(end, first), start.lerp(end, last));
+                    skia_safe::gradient_shader::linear(
+                        (
+                            skia_safe::Point::new(start.x, start.y),
+                            skia_safe::Point::new(end.x, end.y),
+                        ),
+                        skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                        Some(&*pos),
+                        to_skia_tile_mode(g.spread()),
+                        skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                        &skia_safe::Matrix::new_identity(),
+                    )
+                }
             }
             Brush::RadialGradient(g) => {
+                let circle_scale = width.max(height) / 2.;
+                let matrix = skia_safe::Matrix::scale((circle_scale.get(), circle_scale.get()))
+                    .post_translate((width.get() / 2., height.get() / 2.));
+
+                paint.set_dither(true);
+
+                if g.spread() == GradientSpread::Pad {
+                    let (colors, pos): (Vec<_>, Vec<_>) =
+                        g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                    skia_safe::gradient_shader::radial(
+                        skia_safe::Point::new(0., 0.),
+                        1.,
+                        skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                        Some(&*pos),
+                        TileMode::Clamp,
+                        skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                        &matrix,
+                    )
+                } else {
+                    // The repeated range goes from the circle of the first stop to the circle of the last one
+                    let (first, last, stops) =
+                        i_slint_core::graphics::normalize_gradient_stops(g.stops());
+                    let (colors, pos): (Vec<_>, Vec<_>) =
+                        stops.map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                    skia_safe::gradient_shader::two_point_conical(
+                        skia_safe::Point::new(0., 0.),
+                        first.max(0.),
+                        skia_safe::Point::new(0., 0.),
+                        last,
+                        skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                        Some(&*pos),
+                        to_skia_tile_mode(g.spread()),
+                        skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                        &matrix,
+                    )
+                }
+            }
+            Brush::ConicGradient(g) => {
                 let (colors, pos): (Vec<_>, Vec<_>) =
                     g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
-                let circle_scale = width.max(height) / 2.;
+                let center = skia_safe::Point::new(width.get() / 2., height.get() / 2.);
 
                 paint.set_dither(true);
 
-                skia_safe::gradient_shader::radial(
-                    skia_safe::Point::new(0., 0.),
-                    1.,
+                // Skia's sweep gradients start on the right instead of the top
+                skia_safe::gradient_shader::sweep(
+                    center,
                     skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
                     Some(&*pos),
                     TileMode::Clamp,
+                    None,
                     skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
-                    skia_safe::Matrix::scale((circle_scale.get(), circle_scale.get()))
-                        .post_translate((width.get() / 2., height.get() / 2.))
-                        as &skia_safe::Matrix,
+                    &skia_safe::Matrix::rotate_deg_pivot(g.from_angle() - 90., center),
                 )
             }
             _ => None,
@@ -336,6 +445,7 @@ fn render_layer(
                 &self.window,
                 self.image_cache,
                 self.path_cache,
+                self.shader_cache,
                 self.box_shadow_cache,
             );
 
@@ -404,6 +514,11 @@ fn draw_border_rectangle(
             return;
         }
 
+        if let Some(edges) = rect.border_edges() {
+            self.draw_border_edges(rect, &edges, geometry);
+            return;
+        }
+
         let border_color = rect.border_color();
         let opaque_border = border_color.is_opaque();
         let mut border_width = if border_color.is_transparent() {
@@ -643,14 +758,23 @@ fn draw_text_input(
         layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
 
         if let Some(cursor_position) = visual_representation.cursor_position {
-            let cursor_rect = super::textlayout::cursor_rect(
-                &visual_representation.text,
-                cursor_position,
-                layout,
-                text_input.text_cursor_width() * self.scale_factor,
-                text_input.horizontal_alignment(),
-            )
-            .translate(layout_top_left.to_vector());
+            let cursor_rect_at = |byte_offset| {
+                super::textlayout::cursor_rect(
+                    &visual_representation.text,
+                    byte_offset,
+                    &layout,
+                    text_input.text_cursor_width() * self.scale_factor,
+                    text_input.horizontal_alignment(),
+                )
+            };
+            let bar_rect = cursor_rect_at(cursor_position);
+            let character_width = visual_representation
+                .cursor_character_end()
+                .filter(|_| visual_representation.cursor_shape != TextCursorShape::Bar)
+                .map(|end| cursor_rect_at(end).min_x() - bar_rect.min_x());
+            let cursor_rect = visual_representation
+                .cursor_shape_rect(bar_rect, character_width)
+                .translate(layout_top_left.to_vector());
 
             let cursor_paint = match self.brush_to_paint(
                 Brush::SolidColor(visual_representation.cursor_color),
@@ -742,6 +866,42 @@ fn draw_path(
         }
     }
 
+    fn draw_shader(
+        &mut self,
+        shader: Pin<&i_slint_core::items::Shader>,
+        item_rc: &i_slint_core::items::ItemRc,
+        size: LogicalSize,
+    ) {
+        let Some(effect) = self.shader_cache.get_or_update_cache_entry(item_rc, || {
+            skia_safe::RuntimeEffect::make_for_shader(shader.source().as_str(), None)
+                .map_err(|error| {
+                    i_slint_core::debug_log!("Error compiling the source of a Shader: {error}")
+                })
+                .ok()
+        }) else {
+            return;
+        };
+
+        let geometry = PhysicalRect::from(size * self.scale_factor);
+
+        // The size of the element in physical pixels, followed by the declared properties
+        let mut uniforms = [geometry.width(), geometry.height()]
+            .into_iter()
+            .chain(shader.uniform_values().iter().copied())
+            .flat_map(f32::to_ne_bytes)
+            .collect::<Vec<u8>>();
+        uniforms.resize(effect.uniform_size(), 0);
+
+        let Some(sk_shader) = effect.make_shader(skia_safe::Data::new_copy(&uniforms), &[], None)
+        else {
+            return;
+        };
+        let mut paint = skia_safe::Paint::default();
+        paint.set_shader(sk_shader);
+        paint.set_alpha_f(self.current_state.alpha);
+        self.canvas.draw_rect(to_skia_rect(&geometry), &paint);
+    }
+
     fn draw_box_shadow(
         &mut self,
         box_shadow: Pin<&i_slint_core::items::BoxShadow>,
@@ -848,6 +1008,10 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         self.canvas.rotate(angle_in_degrees, None);
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        self.canvas.scale((x_factor, y_factor));
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         self.current_state.alpha *= opacity;
     }
@@ -1018,6 +1182,14 @@ pub fn to_skia_color(col: &Color) -> skia_safe::Color {
     skia_safe::Color::from_argb(col.alpha(), col.red(), col.green(), col.blue())
 }
 
+fn to_skia_tile_mode(spread: GradientSpread) -> TileMode {
+    match spread {
+        GradientSpread::Repeat => TileMode::Repeat,
+        GradientSpread::Reflect => TileMode::Mirror,
+        _ => TileMode::Clamp,
+    }
+}
+
 fn adjust_rect_and_border_for_inner_drawing(
     rect: &mut PhysicalRect,
     border_width: &mut PhysicalLength,
@@ -89,6 +89,7 @@ pub struct SkiaRenderer {
     rendering_notifier: RefCell<Option<Box<dyn RenderingNotifier>>>,
     image_cache: ItemCache<Option<skia_safe::Image>>,
     path_cache: ItemCache<Option<(Vector2D<f32, PhysicalPx>, skia_safe::Path)>>,
+    shader_cache: ItemCache<Option<skia_safe::RuntimeEffect>>,
     rendering_metrics_collector: RefCell<Option<Rc<RenderingMetricsCollector>>>,
     rendering_first_time: Cell<bool>,
     surface: RefCell<Option<Box<dyn Surface>>>,
@@ -107,6 +108,7 @@ fn default() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -125,6 +127,7 @@ pub fn default_software() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -143,6 +146,7 @@ pub fn default_opengl() -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Default::default(),
             surface: Default::default(),
@@ -170,6 +174,7 @@ pub fn new_with_surface(surface: Box<dyn Surface + 'static>) -> Self {
             rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
+            shader_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Cell::new(true),
             surface: RefCell::new(Some(surface)),
@@ -184,6 +189,7 @@ pub fn new_with_surface(surface: Box<dyn Surface + 'static>) -> Self {
     pub fn set_surface(&self, surface: Box<dyn Surface + 'static>) {
         self.image_cache.clear_all();
         self.path_cache.clear_all();
+        self.shader_cache.clear_all();
         self.rendering_first_time.set(true);
         *self.surface.borrow_mut() = Some(surface);
     }
@@ -215,6 +221,7 @@ fn clear_surface(&self) {
     pub fn suspend(&self) -> Result<(), PlatformError> {
         self.image_cache.clear_all();
         self.path_cache.clear_all();
+        self.shader_cache.clear_all();
         // Destroy the old surface before allocating the new one, to work around
         // the vivante drivers using zwp_linux_explicit_synchronization_v1 and
         // trying to create a second synchronization object and that's not allowed.
@@ -306,9 +313,16 @@ fn render_to_canvas(
             let window_background_brush =
                 window_inner.window_item().map(|w| w.as_pin_ref().background());
 
-            // Clear with window background if it is a solid color otherwise it will drawn as gradient
-            if let Some(Brush::SolidColor(clear_color)) = window_background_brush {
-                skia_canvas.clear(itemrenderer::to_skia_color(&clear_color));
+            // Clear with window background if it is a solid color otherwise it will drawn as gradient.
+            // The alpha of the background ends up in the window surface, for transparent windows.
+            match &window_background_brush {
+                Some(Brush::SolidColor(clear_color)) => {
+                    skia_canvas.clear(itemrenderer::to_skia_color(clear_color));
+                }
+                Some(_) => {
+                    skia_canvas.clear(skia_safe::Color::TRANSPARENT);
+                }
+                None => {}
             }
 
             if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
@@ -336,6 +350,7 @@ fn render_to_canvas(
                 window,
                 &self.image_cache,
                 &self.path_cache,
+                &self.shader_cache,
                 &mut box_shadow_cache,
             );
 
@@ -360,6 +375,8 @@ fn render_to_canvas(
                 );
             }
 
+            window_inner.draw_software_cursor(&mut item_renderer);
+
             if let Some(collector) = &self.rendering_metrics_collector.borrow_mut().as_ref() {
                 collector.measure_frame_rendered(&mut item_renderer);
             }
@@ -516,7 +533,7 @@ fn text_input_cursor_rect_for_byte_offset(
         let physical_cursor_rect = textlayout::cursor_rect(
             string,
             byte_offset,
-            layout,
+            &layout,
             text_input.text_cursor_width() * scale_factor,
             text_input.horizontal_alignment(),
         );
@@ -566,6 +583,7 @@ fn free_graphics_resources(
     ) -> Result<(), i_slint_core::platform::PlatformError> {
         self.image_cache.component_destroyed(component);
         self.path_cache.component_destroyed(component);
+        self.shader_cache.component_destroyed(component);
         Ok(())
     }
 
@@ -573,6 +591,7 @@ fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         *self.maybe_window_adapter.borrow_mut() = Some(Rc::downgrade(window_adapter));
         self.image_cache.clear_all();
         self.path_cache.clear_all();
+        self.shader_cache.clear_all();
     }
 
     fn resize(&self, size: i_slint_core::api::PhysicalSize) -> Result<(), PlatformError> {
@@ -85,8 +85,28 @@ pub fn create_layout(
 ) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
     let mut text_style = text_style.unwrap_or_default();
 
-    if let Some(family_name) = font_request.family.as_ref() {
-        text_style.set_font_families(&[family_name.as_str()]);
+    let fallback_families = font_request.fallback_families();
+    if fallback_families.is_empty() {
+        if let Some(family_name) = font_request.family.as_ref() {
+            text_style.set_font_families(&[family_name.as_str()]);
+        }
+    } else {
+        // Skia tries the families in order for every character, so the requested family (or the
+        // system default) must come first, followed by the registered fallbacks.
+        let family_name =
+            font_request.family.as_ref().map(|family| family.to_string()).or_else(|| {
+                FONT_CACHE.with(|font_cache| {
+                    font_cache
+                        .font_mgr
+                        .legacy_make_typeface(None, skia_safe::FontStyle::default())
+                        .map(|type_face| type_face.family_name())
+                })
+            });
+        let families = family_name
+            .into_iter()
+            .chain(fallback_families.iter().map(|family| family.to_string()))
+            .collect::<Vec<_>>();
+        text_style.set_font_families(&families);
     }
 
     let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
@@ -97,13 +117,40 @@ pub fn create_layout(
     text_style.set_font_size(pixel_size.get());
     text_style.set_font_style(font_style_for_request(&font_request));
 
+    if let Some(line_height) = font_request.line_height {
+        // The height is a multiple of the font size, with the extra space split above and below
+        text_style.set_height_override(true);
+        text_style.set_height((line_height * scale_factor).get() / pixel_size.get());
+        text_style.set_half_leading(true);
+    }
+
+    if !font_request.variations.is_empty() {
+        let coordinates = font_request
+            .variations
+            .iter()
+            .map(|variation| skia_safe::font_arguments::variation_position::Coordinate {
+                axis: skia_safe::FourByteTag::new(u32::from_be_bytes(variation.tag)),
+                value: variation.value,
+            })
+            .collect::<Vec<_>>();
+        let font_arguments = skia_safe::FontArguments::new().set_variation_design_position(
+            skia_safe::font_arguments::VariationPosition { coordinates: &coordinates },
+        );
+        text_style.set_font_arguments(&font_arguments);
+    }
+
     let mut style = skia_safe::textlayout::ParagraphStyle::new();
 
     if overflow == items::TextOverflow::Elide {
         style.set_ellipsis("…");
         if wrap != items::TextWrap::NoWrap {
-            let metrics = text_style.font_metrics();
-            let line_height = metrics.descent - metrics.ascent + metrics.leading;
+            let line_height = match font_request.line_height {
+                Some(line_height) => (line_height * scale_factor).get(),
+                None => {
+                    let metrics = text_style.font_metrics();
+                    metrics.descent - metrics.ascent + metrics.leading
+                }
+            };
             style.set_max_lines((max_height.get() / line_height).floor() as usize);
         }
     }
@@ -253,7 +300,7 @@ pub fn register_font_from_path(path: &std::path::Path) -> Result<(), Box<dyn std
 pub fn cursor_rect(
     string: &str,
     cursor_pos: usize,
-    layout: skia_safe::textlayout::Paragraph,
+    layout: &skia_safe::textlayout::Paragraph,
     cursor_width: PhysicalLength,
     h_align: TextHorizontalAlignment,
 ) -> PhysicalRect {
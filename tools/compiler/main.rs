@@ -22,6 +22,16 @@ enum Embedding {
     EmbedForSoftwareRenderer,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Emit {
+    /// List the bindings that depend on frequently changing inputs, such as the animation tick or
+    /// the pointer position, with an estimation of their cost
+    BindingReport,
+    /// List the glyphs of the fonts that are embedded with `--embed-resources=embed-for-software-renderer`
+    #[cfg(feature = "software-renderer")]
+    GlyphReport,
+}
+
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
@@ -29,6 +39,10 @@ struct Cli {
     #[arg(short = 'f', long = "format", default_value = "cpp", action)]
     format: generator::OutputFormat,
 
+    /// Emit a report instead of the generated code
+    #[arg(long = "emit", name = "report", value_enum)]
+    emit: Option<Emit>,
+
     /// Include path for other .slint files
     #[arg(short = 'I', name = "include path", number_of_values = 1, action)]
     include_paths: Vec<std::path::PathBuf>,
@@ -39,6 +53,16 @@ struct Cli {
     #[arg(short = 'L', name = "library path", number_of_values = 1, action)]
     library_paths: Vec<String>,
 
+    /// The argument should be in the format `<base>=<variant>` specifying a
+    /// directory that overlays the base directory. The files of the variant
+    /// directory replace the ones with the same relative path in the base directory.
+    #[arg(long = "variant", name = "variant path", number_of_values = 1, action)]
+    variant_paths: Vec<String>,
+
+    /// Set a flag for the `@cfg(flag)` conditions of the .slint files
+    #[arg(long = "cfg", name = "cfg flag", number_of_values = 1, action)]
+    cfg_flags: Vec<String>,
+
     /// Path to .slint file ('-' for stdin)
     #[arg(name = "file", action)]
     path: std::path::PathBuf,
@@ -59,6 +83,15 @@ struct Cli {
     #[arg(long, name = "value", value_enum)]
     embed_resources: Option<Embedding>,
 
+    /// Glyphs to embed with `--embed-resources=embed-for-software-renderer`, in addition to the
+    /// ones of the string literals, in the format `[family=]U+0400-U+04FF,U+20AC,18px`
+    #[arg(long = "embed-glyphs", name = "glyphs", number_of_values = 1, action)]
+    embed_glyphs: Vec<i_slint_compiler::embedded_resources::GlyphSelection>,
+
+    /// Compress the textures embedded with `--embed-resources=embed-for-software-renderer`
+    #[arg(long, action)]
+    compress_textures: bool,
+
     /// Sets the output file ('-' for stdout)
     #[arg(name = "file to generate", short = 'o', default_value = "-", action)]
     output: std::path::PathBuf,
@@ -99,6 +132,13 @@ fn main() -> std::io::Result<()> {
         });
     }
 
+    if let generator::OutputFormat::Cpp(config) = &mut format {
+        if args.output != std::path::Path::new("-") {
+            // Also names the header in the `#line` directives of the debug information
+            config.header_include = args.output.to_string_lossy().to_string();
+        }
+    }
+
     if !args.cpp_files.is_empty() {
         match &mut format {
             generator::OutputFormat::Cpp(ref mut config) => {
@@ -108,8 +148,6 @@ fn main() -> std::io::Result<()> {
                     eprintln!("--cpp-file can only be used together with -o");
                     std::process::exit(1);
                 }
-
-                config.header_include = args.output.to_string_lossy().to_string();
             }
             _ => {
                 eprintln!("C++ files option was set but the output format is not C++ - ignorning");
@@ -136,27 +174,61 @@ fn main() -> std::io::Result<()> {
         .iter()
         .filter_map(|entry| entry.split('=').collect_tuple().map(|(k, v)| (k.into(), v.into())))
         .collect();
+    compiler_config.cfg_flags.extend(args.cfg_flags);
+    compiler_config.variant_paths = args
+        .variant_paths
+        .iter()
+        .filter_map(|entry| entry.split('=').collect_tuple().map(|(k, v)| (k.into(), v.into())))
+        .collect();
     if let Some(style) = args.style {
         compiler_config.style = Some(style);
     }
     if let Some(constant_scale_factor) = args.scale_factor {
         compiler_config.const_scale_factor = constant_scale_factor;
     }
+    if args.compress_textures {
+        compiler_config.compress_textures = true;
+    }
+    compiler_config.glyph_selections = args.embed_glyphs;
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
     let (doc, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
     let diag = diag.check_and_exit_on_error();
 
-    if args.output == std::path::Path::new("-") {
-        generator::generate(format, &mut std::io::stdout(), &doc, &loader.compiler_config)?;
-    } else {
-        generator::generate(
-            format,
-            &mut BufWriter::new(std::fs::File::create(&args.output)?),
-            &doc,
-            &loader.compiler_config,
-        )?;
+    let stdout = args.output == std::path::Path::new("-");
+    match args.emit {
+        Some(Emit::BindingReport) if stdout => {
+            binding_report::generate(&doc, &mut std::io::stdout())?;
+        }
+        Some(Emit::BindingReport) => {
+            binding_report::generate(
+                &doc,
+                &mut BufWriter::new(std::fs::File::create(&args.output)?),
+            )?;
+        }
+        #[cfg(feature = "software-renderer")]
+        Some(Emit::GlyphReport) if stdout => {
+            embedded_resources::write_glyph_report(&doc, &mut std::io::stdout())?;
+        }
+        #[cfg(feature = "software-renderer")]
+        Some(Emit::GlyphReport) => {
+            embedded_resources::write_glyph_report(
+                &doc,
+                &mut BufWriter::new(std::fs::File::create(&args.output)?),
+            )?;
+        }
+        None if stdout => {
+            generator::generate(format, &mut std::io::stdout(), &doc, &loader.compiler_config)?;
+        }
+        None => {
+            generator::generate(
+                format,
+                &mut BufWriter::new(std::fs::File::create(&args.output)?),
+                &doc,
+                &loader.compiler_config,
+            )?;
+        }
     }
 
     if let Some(depfile) = args.depfile {
@@ -120,6 +120,9 @@ fn format_node(
         SyntaxKind::ReturnStatement => {
             return format_return_statement(node, writer, state);
         }
+        SyntaxKind::AssertStatement => {
+            return format_assert_statement(node, writer, state);
+        }
         SyntaxKind::AtGradient => {
             return format_at_gradient(node, writer, state);
         }
@@ -159,6 +162,9 @@ fn format_node(
         SyntaxKind::MemberAccess => {
             return format_member_access(node, writer, state);
         }
+        SyntaxKind::GenericParameters | SyntaxKind::GenericArguments => {
+            return format_generic_list(node, writer, state);
+        }
 
         _ => (),
     }
@@ -291,7 +297,9 @@ fn format_component(
     } else {
         let mut sub = node.children_with_tokens();
         let _ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
-            && whitespace_to(&mut sub, SyntaxKind::DeclaredIdentifier, writer, state, " ")?;
+            && whitespace_to(&mut sub, SyntaxKind::DeclaredIdentifier, writer, state, " ")?
+            && (node.child_node(SyntaxKind::GenericParameters).is_none()
+                || whitespace_to(&mut sub, SyntaxKind::GenericParameters, writer, state, "")?);
         let r = whitespace_to_one_of(
             &mut sub,
             &[SyntaxKind::Identifier, SyntaxKind::Element],
@@ -319,6 +327,8 @@ fn format_element(
 
     let ok = if node.child_node(SyntaxKind::QualifiedName).is_some() {
         whitespace_to(&mut sub, SyntaxKind::QualifiedName, writer, state, "")?
+            && (node.child_node(SyntaxKind::GenericArguments).is_none()
+                || whitespace_to(&mut sub, SyntaxKind::GenericArguments, writer, state, "")?)
             && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?
     } else {
         whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, "")?
@@ -803,6 +813,24 @@ fn format_return_statement(
     Ok(())
 }
 
+fn format_assert_statement(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens();
+    let _ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::Expression, writer, state, " ")?;
+    if node.child_token(SyntaxKind::Comma).is_some() {
+        let _ok = whitespace_to(&mut sub, SyntaxKind::Comma, writer, state, "")?
+            && whitespace_to(&mut sub, SyntaxKind::StringLiteral, writer, state, " ")?;
+    }
+    whitespace_to(&mut sub, SyntaxKind::Semicolon, writer, state, "")?;
+    state.new_line();
+    finish_node(sub, writer, state)?;
+    Ok(())
+}
+
 fn format_at_gradient(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -878,6 +906,40 @@ fn format_repeated_index(
     Ok(())
 }
 
+fn format_generic_list(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let item = if node.kind() == SyntaxKind::GenericParameters {
+        SyntaxKind::DeclaredIdentifier
+    } else {
+        SyntaxKind::Type
+    };
+    let count = node.children().filter(|n| n.kind() == item).count();
+    let has_trailing_comma = node
+        .children_with_tokens()
+        .filter(|n| !matches!(n.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment))
+        .filter(|n| n.kind() != SyntaxKind::RAngle)
+        .last()
+        .is_some_and(|n| n.kind() == SyntaxKind::Comma);
+    let mut sub = node.children_with_tokens();
+    whitespace_to(&mut sub, SyntaxKind::LAngle, writer, state, "")?;
+    for index in 0..count {
+        let prefix = if index == 0 { "" } else { " " };
+        if !whitespace_to(&mut sub, item, writer, state, prefix)? {
+            return finish_node(sub, writer, state).map(|_| ());
+        }
+        if (index + 1 < count || has_trailing_comma)
+            && !whitespace_to(&mut sub, SyntaxKind::Comma, writer, state, "")?
+        {
+            return finish_node(sub, writer, state).map(|_| ());
+        }
+    }
+    whitespace_to(&mut sub, SyntaxKind::RAngle, writer, state, "")?;
+    Ok(())
+}
+
 fn format_array(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -1142,7 +1204,7 @@ fn format_property_animation(
     }
 
     let bindings = node.children().fold(0, |acc, e| {
-        if e.kind() == SyntaxKind::Binding {
+        if matches!(e.kind(), SyntaxKind::Binding | SyntaxKind::CallbackConnection) {
             return acc + 1;
         }
         acc
@@ -1329,6 +1391,14 @@ fn components() {
         );
     }
 
+    #[test]
+    fn generic_components() {
+        assert_formatting(
+            "component   A < T ,U >  inherits  B<T>{ C <int,[T]>{ } }",
+            "component A<T, U> inherits B<T> {\n    C<int, [T]> { }\n}\n",
+        );
+    }
+
     #[test]
     fn with_comments() {
         assert_formatting(
@@ -2129,6 +2199,19 @@ fn changed() {
         );
     }
 
+    #[test]
+    fn assert_statement() {
+        assert_formatting(
+            r#"component X { assert   value>=0 ;assert value<=100,"too big"  ; required   callback clicked( ); }"#,
+            r#"component X {
+    assert value >= 0;
+    assert value <= 100, "too big";
+    required callback clicked();
+}
+"#,
+        );
+    }
+
     #[test]
     fn access_member() {
         assert_formatting(
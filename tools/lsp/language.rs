@@ -7,6 +7,8 @@
 mod formatting;
 mod goto;
 mod hover;
+#[cfg(not(target_arch = "wasm32"))]
+mod library_crates;
 mod semantic_tokens;
 #[cfg(test)]
 pub mod test;
@@ -1087,10 +1089,11 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
         )?
         .await?;
 
-    let (hide_ui, include_paths, library_paths, style) = {
+    let (hide_ui, include_paths, library_paths, library_crates, style) = {
         let mut hide_ui = None;
         let mut include_paths = None;
         let mut library_paths = None;
+        let mut library_crates = HashMap::new();
         let mut style = None;
 
         for v in r {
@@ -1112,6 +1115,12 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
                         );
                     }
                 }
+                if let Some(lc) = o.get("libraryCrates").and_then(|v| v.as_object()) {
+                    library_crates = lc
+                        .iter()
+                        .filter_map(|(k, v)| v.as_str().map(|v| (k.to_string(), v.to_string())))
+                        .collect();
+                }
                 if let Some(s) =
                     o.get("preview").and_then(|v| v.as_object()?.get("style")?.as_str())
                 {
@@ -1122,8 +1131,36 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
                 hide_ui = o.get("preview").and_then(|v| v.as_object()?.get("hide_ui")?.as_bool());
             }
         }
-        (hide_ui, include_paths, library_paths, style)
+        (hide_ui, include_paths, library_paths, library_crates, style)
+    };
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let library_paths = if library_crates.is_empty() {
+        library_paths
+    } else {
+        let workspace_dir = ctx
+            .init_param
+            .workspace_folders
+            .as_ref()
+            .and_then(|folders| folders.first())
+            .and_then(|folder| folder.uri.to_file_path().ok())
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        match library_crates::resolve(&workspace_dir, &library_crates) {
+            Ok(paths) => Some(library_paths.unwrap_or_default().into_iter().chain(paths).collect()),
+            Err(message) => {
+                ctx.server_notifier.send_notification::<lsp_types::notification::ShowMessage>(
+                    lsp_types::ShowMessageParams {
+                        typ: lsp_types::MessageType::WARNING,
+                        message: format!("Cannot resolve the library crates: {message}"),
+                    },
+                )?;
+                library_paths
+            }
+        }
     };
+    #[cfg(target_arch = "wasm32")]
+    let _ = library_crates;
 
     let document_cache = &mut ctx.document_cache.borrow_mut();
     let cc = document_cache.reconfigure(style, include_paths, library_paths).await?;
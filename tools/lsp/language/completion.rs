@@ -206,6 +206,7 @@ pub(crate) fn completion_at(
         SyntaxKind::BindingExpression
             | SyntaxKind::CodeBlock
             | SyntaxKind::ReturnStatement
+            | SyntaxKind::AssertStatement
             | SyntaxKind::Expression
             | SyntaxKind::FunctionCallExpression
             | SyntaxKind::SelfAssignment
@@ -225,8 +226,10 @@ pub(crate) fn completion_at(
                 [
                     ("tr", "tr(\"$1\")"),
                     ("image-url", "image-url(\"$1\")"),
+                    ("cfg", "cfg($1)"),
                     ("linear-gradient", "linear-gradient($1)"),
                     ("radial-gradient", "radial-gradient(circle, $1)"),
+                    ("conic-gradient", "conic-gradient($1)"),
                 ]
                 .into_iter()
                 .map(|(label, insert)| {
@@ -0,0 +1,60 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Lookup of the libraries that the dependencies of a Cargo package provide, for the
+//! `slint.libraryCrates` setting. This matches `slint_build::CompilerConfiguration::with_library_crates()`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Returns the path of the library of the crates in `library_crates`, by library name. The crates
+/// are the dependencies of the Cargo package in `package_dir` or one of its parents, in the version
+/// that Cargo resolved for it, and they declare their library in `package.metadata.slint.library`.
+pub fn resolve(
+    package_dir: &Path,
+    library_crates: &HashMap<String, String>,
+) -> Result<HashMap<String, PathBuf>, String> {
+    let output = std::process::Command::new("cargo")
+        .args(["metadata", "--format-version", "1"])
+        .current_dir(package_dir)
+        .output()
+        .map_err(|e| format!("Cannot run cargo metadata: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "cargo metadata failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Cannot parse the output of cargo metadata: {e}"))?;
+
+    let packages = metadata["packages"].as_array().map(Vec::as_slice).unwrap_or_default();
+    let package = |id: &serde_json::Value| packages.iter().find(|p| p["id"] == *id);
+    let root = &metadata["resolve"]["root"];
+    let dependencies = metadata["resolve"]["nodes"]
+        .as_array()
+        .and_then(|nodes| nodes.iter().find(|node| node["id"] == *root))
+        .and_then(|node| node["deps"].as_array())
+        .ok_or_else(|| format!("There is no Cargo package in {}", package_dir.display()))?;
+
+    library_crates
+        .iter()
+        .map(|(library, crate_name)| {
+            let dependency = dependencies
+                .iter()
+                .filter_map(|dependency| package(&dependency["pkg"]))
+                .find(|p| p["name"].as_str() == Some(crate_name))
+                .ok_or_else(|| {
+                    format!("The crate '{crate_name}' of the library '{library}' isn't a dependency")
+                })?;
+            let path = dependency["metadata"]["slint"]["library"].as_str().ok_or_else(|| {
+                format!(
+                    "The crate '{crate_name}' doesn't declare a library in `package.metadata.slint.library`"
+                )
+            })?;
+            let manifest_path = Path::new(dependency["manifest_path"].as_str().unwrap_or_default());
+            let crate_dir = manifest_path.parent().unwrap_or(manifest_path);
+            Ok((library.clone(), crate_dir.join(path)))
+        })
+        .collect()
+}
@@ -90,14 +90,19 @@ pub fn get_semantic_tokens(
                         _ => None,
                     }
                 }
-                SyntaxKind::ChildrenPlaceholder => Some((self::MACRO, 0)),
+                SyntaxKind::ChildrenPlaceholder | SyntaxKind::ChildrenSlot => {
+                    Some((self::MACRO, 0))
+                }
                 SyntaxKind::Binding | SyntaxKind::TwoWayBinding => Some((self::PROPERTY, 0)),
                 SyntaxKind::ReturnStatement => Some((self::KEYWORD, 0)),
+                SyntaxKind::AssertStatement => Some((self::KEYWORD, 0)),
                 SyntaxKind::AtImageUrl => Some((self::MACRO, 0)),
                 SyntaxKind::AtGradient => Some((self::MACRO, 0)),
                 SyntaxKind::AtTr => Some((self::MACRO, 0)),
+                SyntaxKind::AtCfg => Some((self::MACRO, 0)),
                 SyntaxKind::ConditionalExpression => Some((self::KEYWORD, 0)),
                 SyntaxKind::ObjectMember => Some((self::PROPERTY, 1 << self::DECLARATION)),
+                SyntaxKind::NamedArgument => Some((self::PARAMETER, 0)),
                 SyntaxKind::States => Some((self::KEYWORD, 0)),
                 SyntaxKind::State => Some((self::KEYWORD, 0)),
                 SyntaxKind::Transitions => Some((self::KEYWORD, 0)),
@@ -118,6 +123,7 @@ pub fn get_semantic_tokens(
                 }
                 SyntaxKind::ExportName => Some((self::TYPE, 1 << self::DECLARATION)),
                 SyntaxKind::ImportSpecifier => Some((self::KEYWORD, 0)),
+                SyntaxKind::ImportStyle => Some((self::KEYWORD, 0)),
                 SyntaxKind::ImportIdentifier => Some((self::KEYWORD, 0)),
                 SyntaxKind::ExternalName => Some((
                     self::TYPE,
@@ -134,6 +140,7 @@ pub fn get_semantic_tokens(
                 SyntaxKind::StructDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::EnumDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyChangedCallback => Some((self::KEYWORD, 0)),
+                SyntaxKind::EventDeclaration | SyntaxKind::EventHandler => Some((self::KEYWORD, 0)),
                 _ => None,
             },
             SyntaxKind::PlusEqual
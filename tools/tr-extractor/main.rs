@@ -44,6 +44,21 @@ struct Cli {
     #[arg(long = "join-existing", short = 'j')]
     /// Join messages with existing file
     join_existing: bool,
+
+    #[arg(
+        long = "format",
+        value_enum,
+        help = "The format of the output. Defaults to json if the output file has the .json extension, and to po otherwise"
+    )]
+    format: Option<Format>,
+}
+
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+enum Format {
+    /// A gettext `.po` or `.pot` file
+    Po,
+    /// A JSON catalog, with the context, the comments, and the locations of each message
+    Json,
 }
 
 fn main() -> std::io::Result<()> {
@@ -53,6 +68,18 @@ fn main() -> std::io::Result<()> {
         format!("{}.po", args.domain.as_ref().map(String::as_str).unwrap_or("messages")).into()
     });
 
+    let format = args.format.unwrap_or_else(|| {
+        if output.extension().map_or(false, |extension| extension == "json") {
+            Format::Json
+        } else {
+            Format::Po
+        }
+    });
+    if args.join_existing && format == Format::Json {
+        eprintln!("--join-existing can only be used with the po format");
+        std::process::exit(1);
+    }
+
     let mut messages = if args.join_existing {
         polib::po_file::parse(&output)
             .map_err(|x| std::io::Error::new(std::io::ErrorKind::Other, x))?
@@ -76,14 +103,63 @@ fn main() -> std::io::Result<()> {
         Messages::new(metadata)
     };
 
+    let mut files = Vec::new();
     for path in args.paths {
+        collect_slint_files(path, &mut files)?;
+    }
+    for path in files {
         process_file(path, &mut messages)?
     }
 
-    polib::po_file::write(&messages, &output)?;
+    match format {
+        Format::Po => polib::po_file::write(&messages, &output)?,
+        Format::Json => {
+            let json = serde_json::to_string_pretty(&to_json(&messages))?;
+            std::fs::write(&output, json + "\n")?;
+        }
+    }
     Ok(())
 }
 
+/// Adds `path` to `files`, or the `.slint` files in it and its sub-directories if it's a directory
+fn collect_slint_files(
+    path: std::path::PathBuf,
+    files: &mut Vec<std::path::PathBuf>,
+) -> std::io::Result<()> {
+    if !path.is_dir() {
+        files.push(path);
+        return Ok(());
+    }
+    let mut entries = std::fs::read_dir(&path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    // Sort the files, so that the messages are always in the same order
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() || entry.extension().map_or(false, |extension| extension == "slint") {
+            collect_slint_files(entry, files)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the JSON catalog of the messages
+fn to_json(messages: &Messages) -> serde_json::Value {
+    let messages = messages
+        .messages()
+        .map(|message| {
+            serde_json::json!({
+                "msgctxt": Some(message.msgctxt()).filter(|context| !context.is_empty()),
+                "msgid": message.msgid(),
+                "msgid_plural": message.msgid_plural().ok(),
+                "comments": message.comments(),
+                "locations": message.source().split_whitespace().collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({ "messages": messages })
+}
+
 fn process_file(path: std::path::PathBuf, messages: &mut Messages) -> std::io::Result<()> {
     let mut diag = BuildDiagnostics::default();
     let syntax_node = i_slint_compiler::parser::parse_file(path, &mut diag).ok_or_else(|| {
@@ -304,3 +380,41 @@ pub fn new(
     }
     assert_eq!(r.len(), messages.count());
 }
+
+#[test]
+fn json_catalog() {
+    let source = r#"export component Foo {
+        // comment
+        x: @tr("Message");
+        y: @tr("ctx" => "{n} file" | "{n} files" % 2);
+    }"#;
+
+    let mut diag = BuildDiagnostics::default();
+    let syntax_node = i_slint_compiler::parser::parse(
+        source.into(),
+        Some(std::path::Path::new("test.slint")),
+        &mut diag,
+    );
+    let mut messages = polib::catalog::Catalog::new(Default::default());
+    visit_node(syntax_node, &mut messages, None);
+
+    assert_eq!(
+        to_json(&messages),
+        serde_json::json!({ "messages": [
+            {
+                "msgctxt": "Foo",
+                "msgid": "Message",
+                "msgid_plural": null,
+                "comments": "comment",
+                "locations": ["test.slint:3"],
+            },
+            {
+                "msgctxt": "ctx",
+                "msgid": "{n} file",
+                "msgid_plural": "{n} files",
+                "comments": "",
+                "locations": ["test.slint:4"],
+            },
+        ]})
+    );
+}
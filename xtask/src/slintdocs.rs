@@ -187,6 +187,9 @@ macro_rules! map_type {
         (Coord) => {
             stringify!(length)
         };
+        (LogicalPosition) => {
+            stringify!(Point)
+        };
         ($pub_type:ident) => {
             stringify!($pub_type)
         };